@@ -3,7 +3,10 @@ use geo_types::{
     Polygon,
 };
 
-use postgis::ewkb::{GeometryCollectionT, GeometryT};
+use postgis::ewkb::{self, GeometryCollectionT, GeometryT};
+use std::{error, fmt};
+
+use crate::WithSrid;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "postgis")))]
 /// Creates geometry from a PostGIS type.
@@ -11,10 +14,67 @@ use postgis::ewkb::{GeometryCollectionT, GeometryT};
 /// Note that PostGIS databases can store data under any spatial
 /// reference system - not just WGS84. No attempt is made to convert
 /// data between reference systems.
+///
+/// Some PostGIS geometries can't be represented by `geo-types`. The
+/// conversions to `Option` return `None` for them, and those to collections
+/// discard them; use [`TryFromPostgis`] to get an error instead.
 pub trait FromPostgis<T> {
     fn from_postgis(_: T) -> Self;
 }
 
+/// Creates geometry from a PostGIS type, returning an error for PostGIS
+/// geometries which can't be represented by `geo-types`, rather than
+/// discarding them.
+///
+/// Converting to [`WithSrid`] keeps the SRID of the PostGIS geometry.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_postgis::{FromPostgisError, TryFromPostgis, WithSrid};
+/// use postgis::ewkb;
+///
+/// let point = ewkb::Point::new(1., 2., Some(4326));
+/// let polygon = ewkb::Polygon { rings: vec![], srid: Some(4326) };
+/// let collection = ewkb::GeometryCollection {
+///     geometries: vec![ewkb::GeometryT::Point(point), ewkb::GeometryT::Polygon(polygon)],
+///     srid: Some(4326),
+/// };
+///
+/// assert_eq!(
+///     geo_types::GeometryCollection::try_from_postgis(&collection),
+///     Err(FromPostgisError::PolygonWithoutRings),
+/// );
+///
+/// let geometry = ewkb::GeometryT::Point(point);
+/// let with_srid = WithSrid::<geo_types::Geometry>::try_from_postgis(&geometry).unwrap();
+/// assert_eq!(with_srid.srid, Some(4326));
+/// assert_eq!(with_srid.geometry, geo_types::point!(x: 1., y: 2.).into());
+/// ```
+pub trait TryFromPostgis<T>: Sized {
+    fn try_from_postgis(_: T) -> Result<Self, FromPostgisError>;
+}
+
+/// The reason a PostGIS geometry can't be represented by `geo-types`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FromPostgisError {
+    /// A `Polygon` has no rings, so no exterior.
+    PolygonWithoutRings,
+}
+
+impl fmt::Display for FromPostgisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromPostgisError::PolygonWithoutRings => {
+                write!(f, "PostGIS polygon has no rings")
+            }
+        }
+    }
+}
+
+impl error::Error for FromPostgisError {}
+
 impl<'a, T> FromPostgis<&'a T> for Point
 where
     T: postgis::Point,
@@ -119,3 +179,115 @@ where
         })
     }
 }
+
+macro_rules! try_from_postgis_impl {
+    ($to:ident, $trait:ident) => {
+        impl<'a, T> TryFromPostgis<&'a T> for $to
+        where
+            T: postgis::$trait<'a>,
+        {
+            fn try_from_postgis(geom: &'a T) -> Result<Self, FromPostgisError> {
+                Ok($to::from_postgis(geom))
+            }
+        }
+    };
+}
+try_from_postgis_impl!(LineString, LineString);
+try_from_postgis_impl!(MultiPoint, MultiPoint);
+try_from_postgis_impl!(MultiLineString, MultiLineString);
+
+impl<'a, T> TryFromPostgis<&'a T> for Point
+where
+    T: postgis::Point,
+{
+    fn try_from_postgis(pt: &'a T) -> Result<Self, FromPostgisError> {
+        Ok(Point::from_postgis(pt))
+    }
+}
+impl<'a, T> TryFromPostgis<&'a T> for Polygon
+where
+    T: postgis::Polygon<'a>,
+{
+    fn try_from_postgis(poly: &'a T) -> Result<Self, FromPostgisError> {
+        Option::from_postgis(poly).ok_or(FromPostgisError::PolygonWithoutRings)
+    }
+}
+impl<'a, T> TryFromPostgis<&'a T> for MultiPolygon
+where
+    T: postgis::MultiPolygon<'a>,
+{
+    fn try_from_postgis(mp: &'a T) -> Result<Self, FromPostgisError> {
+        let ret = mp
+            .polygons()
+            .map(Polygon::try_from_postgis)
+            .collect::<Result<_, _>>()?;
+        Ok(MultiPolygon::new(ret))
+    }
+}
+impl<'a, T> TryFromPostgis<&'a GeometryCollectionT<T>> for GeometryCollection
+where
+    T: postgis::Point + postgis::ewkb::EwkbRead,
+{
+    fn try_from_postgis(gc: &'a GeometryCollectionT<T>) -> Result<Self, FromPostgisError> {
+        let geoms = gc
+            .geometries
+            .iter()
+            .map(Geometry::try_from_postgis)
+            .collect::<Result<_, _>>()?;
+        Ok(GeometryCollection::new_from(geoms))
+    }
+}
+impl<'a, T> TryFromPostgis<&'a GeometryT<T>> for Geometry
+where
+    T: postgis::Point + postgis::ewkb::EwkbRead,
+{
+    fn try_from_postgis(geo: &'a GeometryT<T>) -> Result<Self, FromPostgisError> {
+        Ok(match *geo {
+            GeometryT::Point(ref p) => Geometry::Point(Point::from_postgis(p)),
+            GeometryT::LineString(ref ls) => Geometry::LineString(LineString::from_postgis(ls)),
+            GeometryT::Polygon(ref p) => Geometry::Polygon(Polygon::try_from_postgis(p)?),
+            GeometryT::MultiPoint(ref p) => Geometry::MultiPoint(MultiPoint::from_postgis(p)),
+            GeometryT::MultiLineString(ref p) => {
+                Geometry::MultiLineString(MultiLineString::from_postgis(p))
+            }
+            GeometryT::MultiPolygon(ref p) => {
+                Geometry::MultiPolygon(MultiPolygon::try_from_postgis(p)?)
+            }
+            GeometryT::GeometryCollection(ref p) => {
+                Geometry::GeometryCollection(GeometryCollection::try_from_postgis(p)?)
+            }
+        })
+    }
+}
+
+macro_rules! try_from_postgis_with_srid_impl {
+    ($from:path, $to:ident) => {
+        impl<'a> TryFromPostgis<&'a $from> for WithSrid<$to> {
+            fn try_from_postgis(geom: &'a $from) -> Result<Self, FromPostgisError> {
+                Ok(WithSrid::new($to::try_from_postgis(geom)?, geom.srid))
+            }
+        }
+    };
+}
+try_from_postgis_with_srid_impl!(ewkb::Point, Point);
+try_from_postgis_with_srid_impl!(ewkb::LineString, LineString);
+try_from_postgis_with_srid_impl!(ewkb::Polygon, Polygon);
+try_from_postgis_with_srid_impl!(ewkb::MultiPoint, MultiPoint);
+try_from_postgis_with_srid_impl!(ewkb::MultiLineString, MultiLineString);
+try_from_postgis_with_srid_impl!(ewkb::MultiPolygon, MultiPolygon);
+try_from_postgis_with_srid_impl!(ewkb::GeometryCollection, GeometryCollection);
+
+impl<'a> TryFromPostgis<&'a ewkb::Geometry> for WithSrid<Geometry> {
+    fn try_from_postgis(geo: &'a ewkb::Geometry) -> Result<Self, FromPostgisError> {
+        let srid = match *geo {
+            GeometryT::Point(ref p) => p.srid,
+            GeometryT::LineString(ref p) => p.srid,
+            GeometryT::Polygon(ref p) => p.srid,
+            GeometryT::MultiPoint(ref p) => p.srid,
+            GeometryT::MultiLineString(ref p) => p.srid,
+            GeometryT::MultiPolygon(ref p) => p.srid,
+            GeometryT::GeometryCollection(ref p) => p.srid,
+        };
+        Ok(WithSrid::new(Geometry::try_from_postgis(geo)?, srid))
+    }
+}
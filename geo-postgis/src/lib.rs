@@ -40,4 +40,7 @@ mod to_postgis;
 pub use to_postgis::ToPostgis;
 
 mod from_postgis;
-pub use from_postgis::FromPostgis;
+pub use from_postgis::{FromPostgis, FromPostgisError, TryFromPostgis};
+
+mod with_srid;
+pub use with_srid::WithSrid;
@@ -1,9 +1,11 @@
 use geo_types::{
     Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
-    MultiPolygon, Point, Polygon,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 use postgis::ewkb;
 
+use crate::WithSrid;
+
 /// Converts geometry to a PostGIS type.
 ///
 /// Note that PostGIS databases can include a SRID (spatial reference
@@ -48,6 +50,16 @@ impl ToPostgis<ewkb::Polygon> for Polygon<f64> {
         ewkb::Polygon { rings, srid }
     }
 }
+impl ToPostgis<ewkb::Polygon> for Rect<f64> {
+    fn to_postgis_with_srid(&self, srid: Option<i32>) -> ewkb::Polygon {
+        self.to_polygon().to_postgis_with_srid(srid)
+    }
+}
+impl ToPostgis<ewkb::Polygon> for Triangle<f64> {
+    fn to_postgis_with_srid(&self, srid: Option<i32>) -> ewkb::Polygon {
+        self.to_polygon().to_postgis_with_srid(srid)
+    }
+}
 macro_rules! to_postgis_impl {
     ($from:ident, $to:path, $name:ident) => {
         impl ToPostgis<$to> for $from<f64> {
@@ -97,3 +109,13 @@ impl ToPostgis<ewkb::Geometry> for Geometry {
         }
     }
 }
+
+impl<G> WithSrid<G> {
+    /// Converts the geometry to a PostGIS type, with its SRID.
+    pub fn to_postgis<T>(&self) -> T
+    where
+        G: ToPostgis<T>,
+    {
+        self.geometry.to_postgis_with_srid(self.srid)
+    }
+}
@@ -0,0 +1,32 @@
+/// A geometry along with the SRID (spatial reference system identifier) of
+/// its coordinates, which `geo-types` geometries don't track.
+///
+/// Converting a PostGIS geometry to a `WithSrid` with
+/// [`TryFromPostgis`](crate::TryFromPostgis) keeps its SRID, and
+/// [`WithSrid::to_postgis`] converts it back with the same SRID.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo_postgis::WithSrid;
+/// use postgis::ewkb;
+///
+/// let point = WithSrid::new(geo_types::point!(x: 1., y: -2.), Some(3857));
+/// let postgis_point: ewkb::Point = point.to_postgis();
+///
+/// assert_eq!(ewkb::Point { x: 1., y: -2., srid: Some(3857) }, postgis_point);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WithSrid<G> {
+    /// The geometry.
+    pub geometry: G,
+    /// The SRID of the geometry, if it has one.
+    pub srid: Option<i32>,
+}
+
+impl<G> WithSrid<G> {
+    /// Pairs `geometry` with `srid`.
+    pub fn new(geometry: G, srid: Option<i32>) -> Self {
+        Self { geometry, srid }
+    }
+}
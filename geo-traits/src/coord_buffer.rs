@@ -0,0 +1,275 @@
+use crate::{CoordTrait, Dimensions, LineStringTrait, MultiPointTrait, PointTrait};
+
+/// A buffer of coordinates whose ordinates are interleaved in a single slice, e.g. `[x0, y0, x1,
+/// y1, ...]` for two dimensions, as in a numeric array or a flat column of Arrow data.
+///
+/// It implements [LineStringTrait] and [MultiPointTrait] without copying the coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo_traits::{CoordTrait, Dimensions, InterleavedCoords, LineStringTrait};
+///
+/// let buffer = [0., 0., 1., 2., 3., 4.];
+/// let line_string = InterleavedCoords::new(&buffer, Dimensions::Xy);
+/// assert_eq!(line_string.num_coords(), 3);
+/// assert_eq!(line_string.coord(1).unwrap().x_y(), (1., 2.));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InterleavedCoords<'a, T> {
+    buffer: &'a [T],
+    dim: Dimensions,
+}
+
+impl<'a, T> InterleavedCoords<'a, T> {
+    /// The coordinates of `dim` dimensions interleaved in `buffer`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `dim` has fewer than two dimensions, or the length of `buffer` isn't a
+    /// multiple of its size.
+    pub fn new(buffer: &'a [T], dim: Dimensions) -> Self {
+        assert!(dim.size() >= 2, "coordinates need at least two dimensions");
+        assert!(
+            buffer.len() % dim.size() == 0,
+            "buffer length is not a multiple of the number of dimensions"
+        );
+        Self { buffer, dim }
+    }
+
+    /// The underlying buffer.
+    pub fn buffer(&self) -> &'a [T] {
+        self.buffer
+    }
+
+    fn get(&self, i: usize) -> InterleavedCoord<'a, T> {
+        let size = self.dim.size();
+        InterleavedCoord {
+            ordinates: &self.buffer[i * size..(i + 1) * size],
+            dim: self.dim,
+        }
+    }
+}
+
+/// A coordinate of [InterleavedCoords], which is also a point of it as a [MultiPointTrait].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InterleavedCoord<'a, T> {
+    ordinates: &'a [T],
+    dim: Dimensions,
+}
+
+impl<T: Copy> CoordTrait for InterleavedCoord<'_, T> {
+    type T = T;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        self.ordinates[n]
+    }
+
+    fn x(&self) -> Self::T {
+        self.ordinates[0]
+    }
+
+    fn y(&self) -> Self::T {
+        self.ordinates[1]
+    }
+}
+
+impl<'a, T: Copy> PointTrait for InterleavedCoord<'a, T> {
+    type T = T;
+    type CoordType<'b>
+        = InterleavedCoord<'a, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        Some(*self)
+    }
+}
+
+impl<'a, T: Copy> LineStringTrait for InterleavedCoords<'a, T> {
+    type T = T;
+    type CoordType<'b>
+        = InterleavedCoord<'a, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn num_coords(&self) -> usize {
+        self.buffer.len() / self.dim.size()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.get(i)
+    }
+}
+
+impl<'a, T: Copy> MultiPointTrait for InterleavedCoords<'a, T> {
+    type T = T;
+    type PointType<'b>
+        = InterleavedCoord<'a, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn num_points(&self) -> usize {
+        self.buffer.len() / self.dim.size()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        self.get(i)
+    }
+}
+
+/// A buffer of two-dimensional coordinates whose `x` and `y` values are in separate slices, as
+/// in the columns of a table or of Arrow data.
+///
+/// It implements [LineStringTrait] and [MultiPointTrait] without copying the coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo_traits::{CoordTrait, MultiPointTrait, PointTrait, SeparatedCoords};
+///
+/// let x = [0., 1., 3.];
+/// let y = [0., 2., 4.];
+/// let multi_point = SeparatedCoords::new(&x, &y);
+/// assert_eq!(multi_point.num_points(), 3);
+/// assert_eq!(multi_point.point(2).unwrap().coord().unwrap().x_y(), (3., 4.));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeparatedCoords<'a, T> {
+    x: &'a [T],
+    y: &'a [T],
+}
+
+impl<'a, T> SeparatedCoords<'a, T> {
+    /// The coordinates with the `x` and `y` values of the same index.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `x` and `y` have different lengths.
+    pub fn new(x: &'a [T], y: &'a [T]) -> Self {
+        assert_eq!(x.len(), y.len(), "x and y have different lengths");
+        Self { x, y }
+    }
+
+    /// The `x` values.
+    pub fn x(&self) -> &'a [T] {
+        self.x
+    }
+
+    /// The `y` values.
+    pub fn y(&self) -> &'a [T] {
+        self.y
+    }
+}
+
+impl<T: Copy> SeparatedCoords<'_, T> {
+    fn get(&self, i: usize) -> SeparatedCoord<T> {
+        SeparatedCoord {
+            x: self.x[i],
+            y: self.y[i],
+        }
+    }
+}
+
+/// A coordinate of [SeparatedCoords], which is also a point of it as a [MultiPointTrait].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeparatedCoord<T> {
+    x: T,
+    y: T,
+}
+
+impl<T: Copy> CoordTrait for SeparatedCoord<T> {
+    type T = T;
+
+    fn dim(&self) -> Dimensions {
+        Dimensions::Xy
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        match n {
+            0 => self.x,
+            1 => self.y,
+            _ => panic!("SeparatedCoord only supports 2 dimensions"),
+        }
+    }
+
+    fn x(&self) -> Self::T {
+        self.x
+    }
+
+    fn y(&self) -> Self::T {
+        self.y
+    }
+}
+
+impl<T: Copy> PointTrait for SeparatedCoord<T> {
+    type T = T;
+    type CoordType<'a>
+        = SeparatedCoord<T>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> Dimensions {
+        Dimensions::Xy
+    }
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        Some(*self)
+    }
+}
+
+impl<T: Copy> LineStringTrait for SeparatedCoords<'_, T> {
+    type T = T;
+    type CoordType<'b>
+        = SeparatedCoord<T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        Dimensions::Xy
+    }
+
+    fn num_coords(&self) -> usize {
+        self.x.len()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.get(i)
+    }
+}
+
+impl<T: Copy> MultiPointTrait for SeparatedCoords<'_, T> {
+    type T = T;
+    type PointType<'b>
+        = SeparatedCoord<T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        Dimensions::Xy
+    }
+
+    fn num_points(&self) -> usize {
+        self.x.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        self.get(i)
+    }
+}
@@ -18,6 +18,7 @@
 #![deny(missing_docs)]
 
 pub use coord::{CoordTrait, UnimplementedCoord};
+pub use coord_buffer::{InterleavedCoord, InterleavedCoords, SeparatedCoord, SeparatedCoords};
 pub use dimension::Dimensions;
 pub use geometry::{GeometryTrait, GeometryType, UnimplementedGeometry};
 pub use geometry_collection::{GeometryCollectionTrait, UnimplementedGeometryCollection};
@@ -32,6 +33,7 @@ pub use rect::{RectTrait, UnimplementedRect};
 pub use triangle::{TriangleTrait, UnimplementedTriangle};
 
 mod coord;
+mod coord_buffer;
 mod dimension;
 mod geometry;
 mod geometry_collection;
@@ -6,9 +6,9 @@ use geo_types::{
 };
 
 use crate::{
-    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
-    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
-    TriangleTrait,
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    RectTrait, TriangleTrait,
 };
 
 /// Convert any coordinate to a [`Coord`].
@@ -230,6 +230,33 @@ pub trait ToGeoGeometry<T: CoordNum> {
     ///
     /// Empty Geometrys will return `None`.
     fn try_to_geometry(&self) -> Option<Geometry<T>>;
+
+    /// Convert a two-dimensional geometry to a geo_types [`Geometry`].
+    ///
+    /// Unlike [`try_to_geometry`](Self::try_to_geometry), this returns `None` for a geometry with
+    /// Z or M values, rather than dropping them, as well as for empty points.
+    fn try_to_geometry_2d(&self) -> Option<Geometry<T>>;
+
+    /// Convert to a geo_types [`Geometry`], along with the Z and M values which it can't hold.
+    ///
+    /// The [`Ordinates`] are in the order of the coordinates of the geometry, so that e.g.
+    /// elevations can be written back out after processing the geometry. This returns `None` for
+    /// empty points, and for coordinates lacking the Z or M values of the geometry's dimensions.
+    fn try_to_geometry_with_ordinates(&self) -> Option<(Geometry<T>, Ordinates<T>)>;
+}
+
+/// The Z and M values of the coordinates of a geometry, which geo-types can't hold.
+///
+/// Values are in the order of the coordinates: those of points, then of line strings, of the
+/// exterior and then the interiors of polygons, of the start and end of lines, of the vertices of
+/// triangles, of the minimum and maximum of rects, and of the members of multi geometries and
+/// collections in turn.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Ordinates<T> {
+    /// The Z values, if the geometry has a Z dimension.
+    pub z: Option<Vec<T>>,
+    /// The M values, if the geometry has an M dimension.
+    pub m: Option<Vec<T>>,
 }
 
 impl<T: CoordNum, G: GeometryTrait<T = T>> ToGeoGeometry<T> for G {
@@ -251,6 +278,107 @@ impl<T: CoordNum, G: GeometryTrait<T = T>> ToGeoGeometry<T> for G {
             Triangle(geom) => Some(Geometry::Triangle(geom.to_triangle())),
         }
     }
+
+    fn try_to_geometry_2d(&self) -> Option<Geometry<T>> {
+        match self.dim() {
+            Dimensions::Xy | Dimensions::Unknown(2) => self.try_to_geometry(),
+            _ => None,
+        }
+    }
+
+    fn try_to_geometry_with_ordinates(&self) -> Option<(Geometry<T>, Ordinates<T>)> {
+        let geometry = self.try_to_geometry()?;
+        let mut collector = OrdinateCollector::new(self.dim());
+        collector.push_geometry(self)?;
+        Some((geometry, collector.ordinates))
+    }
+}
+
+/// Collects the Z and M values of the coordinates of a geometry, at the indices given by its
+/// dimensions.
+struct OrdinateCollector<T> {
+    z_index: Option<usize>,
+    m_index: Option<usize>,
+    ordinates: Ordinates<T>,
+}
+
+impl<T: CoordNum> OrdinateCollector<T> {
+    fn new(dim: Dimensions) -> Self {
+        let (z_index, m_index) = match dim {
+            Dimensions::Xyz => (Some(2), None),
+            Dimensions::Xym => (None, Some(2)),
+            Dimensions::Xyzm => (Some(2), Some(3)),
+            Dimensions::Xy | Dimensions::Unknown(_) => (None, None),
+        };
+        Self {
+            z_index,
+            m_index,
+            ordinates: Ordinates {
+                z: z_index.map(|_| vec![]),
+                m: m_index.map(|_| vec![]),
+            },
+        }
+    }
+
+    fn push_coord(&mut self, coord: &impl CoordTrait<T = T>) -> Option<()> {
+        if let (Some(index), Some(z)) = (self.z_index, &mut self.ordinates.z) {
+            z.push(coord.nth(index)?);
+        }
+        if let (Some(index), Some(m)) = (self.m_index, &mut self.ordinates.m) {
+            m.push(coord.nth(index)?);
+        }
+        Some(())
+    }
+
+    fn push_line_string(&mut self, line_string: &impl LineStringTrait<T = T>) -> Option<()> {
+        line_string
+            .coords()
+            .try_for_each(|coord| self.push_coord(&coord))
+    }
+
+    fn push_polygon(&mut self, polygon: &impl PolygonTrait<T = T>) -> Option<()> {
+        if let Some(exterior) = polygon.exterior() {
+            self.push_line_string(&exterior)?;
+        }
+        polygon
+            .interiors()
+            .try_for_each(|interior| self.push_line_string(&interior))
+    }
+
+    fn push_geometry(&mut self, geometry: &impl GeometryTrait<T = T>) -> Option<()> {
+        use GeometryType::*;
+
+        match geometry.as_type() {
+            Point(geom) => self.push_coord(&geom.coord()?),
+            LineString(geom) => self.push_line_string(geom),
+            Polygon(geom) => self.push_polygon(geom),
+            MultiPoint(geom) => geom
+                .points()
+                .try_for_each(|point| self.push_coord(&point.coord()?)),
+            MultiLineString(geom) => geom
+                .line_strings()
+                .try_for_each(|line_string| self.push_line_string(&line_string)),
+            MultiPolygon(geom) => geom
+                .polygons()
+                .try_for_each(|polygon| self.push_polygon(&polygon)),
+            GeometryCollection(geom) => geom
+                .geometries()
+                .try_for_each(|geometry| self.push_geometry(&geometry)),
+            Rect(geom) => {
+                self.push_coord(&geom.min())?;
+                self.push_coord(&geom.max())
+            }
+            Line(geom) => {
+                self.push_coord(&geom.start())?;
+                self.push_coord(&geom.end())
+            }
+            Triangle(geom) => {
+                self.push_coord(&geom.first())?;
+                self.push_coord(&geom.second())?;
+                self.push_coord(&geom.third())
+            }
+        }
+    }
 }
 
 /// Convert any GeometryCollection to a [`GeometryCollection`].
@@ -0,0 +1,330 @@
+use crate::{Coord, CoordNum, LineString, Polygon};
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FromIterator;
+
+/// The minimum number of distinct coordinates of a valid [`LineString`].
+const MIN_LINE_STRING_COORDS: usize = 2;
+
+/// The minimum number of distinct coordinates of a valid ring, before closing it.
+const MIN_RING_COORDS: usize = 3;
+
+/// Why a [`LineStringBuilder`] or [`PolygonBuilder`] couldn't build a valid geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// A `LineString` had fewer than 2 distinct coordinates.
+    TooFewCoords { found: usize },
+    /// A ring of a `Polygon` had fewer than 3 distinct coordinates, besides the one closing it.
+    ///
+    /// `interior` is the index of the interior ring, or `None` for the exterior ring.
+    TooFewCoordsInRing {
+        interior: Option<usize>,
+        found: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::TooFewCoords { found } => write!(
+                f,
+                "a LineString needs at least {MIN_LINE_STRING_COORDS} distinct coordinates, but found {found}"
+            ),
+            BuildError::TooFewCoordsInRing { interior, found } => {
+                match interior {
+                    Some(index) => write!(f, "interior ring {index}")?,
+                    None => write!(f, "exterior ring")?,
+                }
+                write!(
+                    f,
+                    " needs at least {MIN_RING_COORDS} distinct coordinates, but found {found}"
+                )
+            }
+        }
+    }
+}
+
+/// Accumulates coordinates into a valid [`LineString`], e.g. as read one at a time from a stream.
+///
+/// Consecutive repeated coordinates are dropped as they are added, and [`build`](Self::build)
+/// fails unless at least 2 remain. [`build_ring`](Self::build_ring) closes the coordinates into
+/// a ring instead.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{line_string, LineStringBuilder};
+///
+/// let mut builder = LineStringBuilder::new();
+/// builder.push((0., 0.)).push((1., 1.)).push((1., 1.));
+/// builder.extend([(2., 0.)]);
+///
+/// assert_eq!(
+///     builder.build().unwrap(),
+///     line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)]
+/// );
+///
+/// let mut builder = LineStringBuilder::new();
+/// builder.push((0., 0.)).push((0., 0.));
+/// assert!(builder.build().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LineStringBuilder<T: CoordNum = f64> {
+    coords: Vec<Coord<T>>,
+}
+
+impl<T: CoordNum> LineStringBuilder<T> {
+    pub fn new() -> Self {
+        Self { coords: Vec::new() }
+    }
+
+    /// A builder with room for `capacity` coordinates before reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            coords: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Add a coordinate, unless it repeats the last one.
+    pub fn push(&mut self, coord: impl Into<Coord<T>>) -> &mut Self {
+        let coord = coord.into();
+        if self.coords.last() != Some(&coord) {
+            self.coords.push(coord);
+        }
+        self
+    }
+
+    /// The number of coordinates added so far, without consecutive repeats.
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+
+    /// Build a `LineString` of the coordinates added.
+    ///
+    /// # Errors
+    ///
+    /// [`BuildError::TooFewCoords`] if fewer than 2 distinct coordinates were added.
+    pub fn build(self) -> Result<LineString<T>, BuildError> {
+        if self.coords.len() < MIN_LINE_STRING_COORDS {
+            return Err(BuildError::TooFewCoords {
+                found: self.coords.len(),
+            });
+        }
+        Ok(LineString::new(self.coords))
+    }
+
+    /// Build a closed ring of the coordinates added, appending the first coordinate to the end
+    /// if it isn't already there.
+    ///
+    /// # Errors
+    ///
+    /// [`BuildError::TooFewCoordsInRing`], with no `interior` index, if fewer than 3 distinct
+    /// coordinates were added besides the one closing the ring.
+    pub fn build_ring(self) -> Result<LineString<T>, BuildError> {
+        self.build_ring_at(None)
+    }
+
+    fn build_ring_at(mut self, interior: Option<usize>) -> Result<LineString<T>, BuildError> {
+        if self.coords.len() > 1 && self.coords.first() == self.coords.last() {
+            self.coords.pop();
+        }
+        if self.coords.len() < MIN_RING_COORDS {
+            return Err(BuildError::TooFewCoordsInRing {
+                interior,
+                found: self.coords.len(),
+            });
+        }
+        self.coords.push(self.coords[0]);
+        Ok(LineString::new(self.coords))
+    }
+}
+
+impl<T: CoordNum> Default for LineStringBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum, C: Into<Coord<T>>> Extend<C> for LineStringBuilder<T> {
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        for coord in iter {
+            self.push(coord);
+        }
+    }
+}
+
+impl<T: CoordNum, C: Into<Coord<T>>> FromIterator<C> for LineStringBuilder<T> {
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
+
+/// Accumulates coordinates into the rings of a valid [`Polygon`], e.g. as read one at a time
+/// from a stream.
+///
+/// Coordinates are added to the exterior ring until [`start_interior`](Self::start_interior) is
+/// called, and then to each interior ring in turn. Each ring drops consecutive repeated
+/// coordinates, and is closed by [`build`](Self::build), which fails unless it has at least 3
+/// distinct coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{polygon, PolygonBuilder};
+///
+/// let mut builder = PolygonBuilder::new();
+/// builder.extend([(0., 0.), (4., 0.), (4., 4.), (0., 4.)]);
+/// builder.start_interior();
+/// builder.push((1., 1.)).push((2., 1.)).push((2., 1.)).push((1., 2.));
+///
+/// assert_eq!(
+///     builder.build().unwrap(),
+///     polygon!(
+///         exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///         interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)]],
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PolygonBuilder<T: CoordNum = f64> {
+    exterior: LineStringBuilder<T>,
+    interiors: Vec<LineStringBuilder<T>>,
+}
+
+impl<T: CoordNum> PolygonBuilder<T> {
+    pub fn new() -> Self {
+        Self {
+            exterior: LineStringBuilder::new(),
+            interiors: Vec::new(),
+        }
+    }
+
+    /// Add a coordinate to the ring being built, unless it repeats the last one.
+    pub fn push(&mut self, coord: impl Into<Coord<T>>) -> &mut Self {
+        self.current_ring().push(coord);
+        self
+    }
+
+    /// Finish the ring being built, and add the following coordinates to a new interior ring.
+    pub fn start_interior(&mut self) -> &mut Self {
+        self.interiors.push(LineStringBuilder::new());
+        self
+    }
+
+    /// The number of interior rings started so far.
+    pub fn num_interiors(&self) -> usize {
+        self.interiors.len()
+    }
+
+    fn current_ring(&mut self) -> &mut LineStringBuilder<T> {
+        self.interiors.last_mut().unwrap_or(&mut self.exterior)
+    }
+
+    /// Build a `Polygon` of the rings added, closing each of them.
+    ///
+    /// # Errors
+    ///
+    /// [`BuildError::TooFewCoordsInRing`] for the first ring with fewer than 3 distinct
+    /// coordinates besides the one closing it.
+    pub fn build(self) -> Result<Polygon<T>, BuildError> {
+        let exterior = self.exterior.build_ring_at(None)?;
+        let interiors = self
+            .interiors
+            .into_iter()
+            .enumerate()
+            .map(|(index, ring)| ring.build_ring_at(Some(index)))
+            .collect::<Result<_, _>>()?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+}
+
+impl<T: CoordNum> Default for PolygonBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum, C: Into<Coord<T>>> Extend<C> for PolygonBuilder<T> {
+    /// Add coordinates to the ring being built.
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        self.current_ring().extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+    use alloc::string::ToString;
+
+    #[test]
+    fn ring_closing() {
+        // already closed, with a repeat
+        let ring: LineStringBuilder = [(0., 0.), (1., 0.), (1., 1.), (1., 1.), (0., 0.)]
+            .into_iter()
+            .collect();
+        let expected = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+        assert_eq!(ring.build_ring().unwrap(), expected);
+
+        let ring: LineStringBuilder = [(0., 0.), (1., 0.), (1., 1.)].into_iter().collect();
+        assert_eq!(ring.build_ring().unwrap(), expected);
+
+        let ring: LineStringBuilder = [(0., 0.), (1., 0.), (0., 0.)].into_iter().collect();
+        assert_eq!(
+            ring.build_ring(),
+            Err(BuildError::TooFewCoordsInRing {
+                interior: None,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn polygon_errors() {
+        let mut builder = PolygonBuilder::new();
+        builder.extend([(0, 0), (4, 0), (4, 4)]);
+        builder.start_interior().extend([(1, 1), (2, 1), (2, 2)]);
+        builder.start_interior().extend([(3, 3), (3, 3)]);
+        assert_eq!(builder.num_interiors(), 2);
+
+        let err = builder.build().unwrap_err();
+        assert_eq!(
+            err,
+            BuildError::TooFewCoordsInRing {
+                interior: Some(1),
+                found: 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "interior ring 1 needs at least 3 distinct coordinates, but found 1"
+        );
+
+        assert_eq!(
+            PolygonBuilder::<f64>::new().build(),
+            Err(BuildError::TooFewCoordsInRing {
+                interior: None,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn polygon_without_interiors() {
+        let mut builder = PolygonBuilder::new();
+        builder.push((0., 0.)).push((1., 0.)).push((0., 1.));
+        assert_eq!(
+            builder.build().unwrap(),
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0., y: 1.)]
+        );
+    }
+}
@@ -194,7 +194,11 @@ impl<T: CoordNum> DoubleEndedIterator for CoordinatesIter<'_, T> {
 
 impl<T: CoordNum> LineString<T> {
     /// Instantiate Self from the raw content value
+    ///
+    /// With the `strict-construct` feature, debug builds panic if any coordinate isn't finite.
     pub fn new(value: Vec<Coord<T>>) -> Self {
+        #[cfg(all(feature = "strict-construct", debug_assertions))]
+        assert_finite_coords(&value);
         Self(value)
     }
 
@@ -548,6 +552,21 @@ impl_rstar_line_string!(rstar_0_11);
 #[cfg(feature = "rstar_0_12")]
 impl_rstar_line_string!(rstar_0_12);
 
+/// Panic if any of `coords` isn't finite, for the `strict-construct` feature.
+#[cfg(all(feature = "strict-construct", debug_assertions))]
+pub(crate) fn assert_finite_coords<T: CoordNum>(coords: &[Coord<T>]) {
+    let is_finite = |value: T| value.to_f64().is_some_and(f64::is_finite);
+    if let Some(index) = coords
+        .iter()
+        .position(|coord| !is_finite(coord.x) || !is_finite(coord.y))
+    {
+        panic!(
+            "strict-construct: coordinate {index} is not finite: {:?}",
+            coords[index]
+        );
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -124,11 +124,23 @@ impl<T: CoordNum> Polygon<T> {
     ///     &LineString::from(vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.),])
     /// );
     /// ```
+    ///
+    /// With the `strict-construct` feature, debug builds panic if any coordinate isn't finite,
+    /// or if a non-empty ring has fewer than 4 coordinates once closed.
     pub fn new(mut exterior: LineString<T>, mut interiors: Vec<LineString<T>>) -> Self {
         exterior.close();
         for interior in &mut interiors {
             interior.close();
         }
+        #[cfg(all(feature = "strict-construct", debug_assertions))]
+        for ring in core::iter::once(&exterior).chain(&interiors) {
+            crate::geometry::line_string::assert_finite_coords(&ring.0);
+            assert!(
+                ring.0.is_empty() || ring.0.len() >= 4,
+                "strict-construct: polygon ring of {} coordinates, rather than at least 4",
+                ring.0.len()
+            );
+        }
         Self {
             exterior,
             interiors,
@@ -408,6 +420,145 @@ impl<T: CoordNum> Polygon<T> {
         self.interiors.push(new_interior);
     }
 
+    /// Remove and return the interior ring at `index`, shifting the following rings down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::wkt;
+    ///
+    /// let mut polygon = wkt!(POLYGON(
+    ///     (0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),
+    ///     (1. 1.,2. 1.,2. 2.,1. 1.),
+    ///     (3. 3.,3.5 3.,3.5 3.5,3. 3.)
+    /// ));
+    ///
+    /// let removed = polygon.remove_interior(0);
+    ///
+    /// assert_eq!(removed, wkt!(LINESTRING(1. 1.,2. 1.,2. 2.,1. 1.)));
+    /// assert_eq!(polygon.interiors(), &[wkt!(LINESTRING(3. 3.,3.5 3.,3.5 3.5,3. 3.))]);
+    /// ```
+    pub fn remove_interior(&mut self, index: usize) -> LineString<T> {
+        self.interiors.remove(index)
+    }
+
+    /// Replace the interior ring at `index` with `new_interior`, returning the old ring.
+    ///
+    /// The new `LineString` interior ring [will be closed].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::wkt;
+    ///
+    /// let mut polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.)));
+    ///
+    /// let old = polygon.replace_interior(0, vec![(3., 3.), (3.5, 3.), (3.5, 3.5)]);
+    ///
+    /// assert_eq!(old, wkt!(LINESTRING(1. 1.,2. 1.,2. 2.,1. 1.)));
+    /// assert_eq!(polygon.interiors(), &[wkt!(LINESTRING(3. 3.,3.5 3.,3.5 3.5,3. 3.))]);
+    /// ```
+    ///
+    /// [will be closed]: #linestring-closing-operation
+    pub fn replace_interior(
+        &mut self,
+        index: usize,
+        new_interior: impl Into<LineString<T>>,
+    ) -> LineString<T> {
+        let mut new_interior = new_interior.into();
+        new_interior.close();
+        core::mem::replace(&mut self.interiors[index], new_interior)
+    }
+
+    /// Keep only the interior rings for which `f` returns `true`, in their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::wkt;
+    ///
+    /// let mut polygon = wkt!(POLYGON(
+    ///     (0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),
+    ///     (1. 1.,2. 1.,2. 2.,1. 1.),
+    ///     (3. 3.,3.5 3.,3.5 3.5,3. 3.)
+    /// ));
+    ///
+    /// // drop the holes near the top right corner
+    /// polygon.retain_interiors(|interior| interior.0[0].x < 3.);
+    ///
+    /// assert_eq!(polygon.interiors(), &[wkt!(LINESTRING(1. 1.,2. 1.,2. 2.,1. 1.))]);
+    /// ```
+    pub fn retain_interiors<F>(&mut self, f: F)
+    where
+        F: FnMut(&LineString<T>) -> bool,
+    {
+        self.interiors.retain(f);
+    }
+
+    /// Replace the exterior ring with `new_exterior`, returning the old ring.
+    ///
+    /// The new `LineString` exterior ring [will be closed].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::wkt;
+    ///
+    /// let mut polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)));
+    ///
+    /// let old = polygon.replace_exterior(vec![(0., 0.), (2., 0.), (2., 2.)]);
+    ///
+    /// assert_eq!(old, wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.)));
+    /// assert_eq!(polygon.exterior(), &wkt!(LINESTRING(0. 0.,2. 0.,2. 2.,0. 0.)));
+    /// ```
+    ///
+    /// [will be closed]: #linestring-closing-operation
+    pub fn replace_exterior(&mut self, new_exterior: impl Into<LineString<T>>) -> LineString<T> {
+        let mut new_exterior = new_exterior.into();
+        new_exterior.close();
+        core::mem::replace(&mut self.exterior, new_exterior)
+    }
+
+    /// Take the exterior ring, leaving an empty one in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{wkt, LineString};
+    ///
+    /// let mut polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)));
+    ///
+    /// assert_eq!(polygon.take_exterior(), wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.)));
+    /// assert_eq!(polygon.exterior(), &LineString::new(vec![]));
+    /// ```
+    pub fn take_exterior(&mut self) -> LineString<T> {
+        core::mem::replace(&mut self.exterior, LineString::new(vec![]))
+    }
+
+    /// Take the interior rings, leaving none in their place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::wkt;
+    ///
+    /// let mut polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.)));
+    ///
+    /// assert_eq!(polygon.take_interiors(), vec![wkt!(LINESTRING(1. 1.,2. 1.,2. 2.,1. 1.))]);
+    /// assert!(polygon.interiors().is_empty());
+    /// ```
+    pub fn take_interiors(&mut self) -> Vec<LineString<T>> {
+        core::mem::take(&mut self.interiors)
+    }
+
     /// Wrap-around previous-vertex
     fn previous_vertex(&self, current_vertex: usize) -> usize
     where
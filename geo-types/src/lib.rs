@@ -24,6 +24,9 @@
 //! - **[`GeometryCollection`]**: A collection of [`Geometry`]s
 //! - **[`Geometry`]**: An enumeration of all geometry types, excluding [`Coord`]
 //!
+//! [`LineStringBuilder`] and [`PolygonBuilder`] accumulate coordinates, e.g. from a stream, into
+//! valid [`LineString`]s and [`Polygon`]s, closing rings and dropping repeated coordinates.
+//!
 //! ## Coordinates and Numeric Types
 //!
 //! - **[`Coord`]**: A two-dimensional coordinate. All geometry types are composed of [`Coord`]s, though [`Coord`] itself is not a [`Geometry`] type. See [`Point`] for a single coordinate geometry.
@@ -67,6 +70,10 @@
 //! - `approx`: Allows geometry types to be checked for approximate equality with [approx]
 //! - `arbitrary`: Allows geometry types to be created from unstructured input with [arbitrary]
 //! - `serde`: Allows geometry types to be serialized and deserialized with [Serde]
+//! - `strict-construct`: Checks cheap invariants in [`LineString::new`] and [`Polygon::new`] in
+//!   debug builds: that coordinates are finite, and that rings have at least 4 coordinates. This
+//!   catches bad data early, at no cost in release builds; see `geo`'s `Validation` for full
+//!   validity checks
 //! - `use-rstar_0_8`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.8`)
 //! - `use-rstar_0_9`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.9`)
 //! - `use-rstar_0_10`: Allows geometry types to be inserted into [rstar] R*-trees (`rstar v0.10`)
@@ -130,6 +137,9 @@ pub use geometry::rect::InvalidRectCoordinatesError;
 mod error;
 pub use error::Error;
 
+mod builder;
+pub use builder::{BuildError, LineStringBuilder, PolygonBuilder};
+
 #[macro_use]
 mod macros;
 
@@ -192,6 +202,23 @@ mod tests {
         assert_eq!(p1, p2);
     }
 
+    #[cfg(all(feature = "strict-construct", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "coordinate 1 is not finite")]
+    fn strict_construct_non_finite() {
+        LineString::new(vec![coord! { x: 0., y: 0. }, coord! { x: f64::NAN, y: 1. }]);
+    }
+
+    #[cfg(all(feature = "strict-construct", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "polygon ring of 3 coordinates")]
+    fn strict_construct_short_ring() {
+        Polygon::new(
+            LineString::new(vec![coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. }]),
+            vec![],
+        );
+    }
+
     #[test]
     fn polygon_new_test() {
         let exterior = LineString::new(vec![
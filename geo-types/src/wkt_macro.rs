@@ -183,6 +183,7 @@ mod test {
         // wkt! { POLYGON() }
     }
 
+    #[cfg_attr(feature = "strict-construct", ignore = "builds degenerate polygons")]
     #[test]
     fn polygon() {
         let polygon = wkt! { POLYGON((1.0 2.0)) };
@@ -259,6 +260,7 @@ mod test {
         // wkt! { MULTIPOLYGON() }
     }
 
+    #[cfg_attr(feature = "strict-construct", ignore = "builds degenerate polygons")]
     #[test]
     fn multi_line_polygon() {
         let multi_polygon = wkt! { MULTIPOLYGON (((1.0 2.0))) };
@@ -0,0 +1,39 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::algorithm::{unary_union, BooleanOps, Translate};
+use geo::geometry::Polygon;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    // Small polygons, as in most real datasets, whose operations allocate many short
+    // temporary rings.
+    let plots: Vec<Polygon<f64>> = geo_test_fixtures::nl_plots_epsg_28992::<f64>()
+        .0
+        .into_iter()
+        .take(500)
+        .collect();
+    let shifted: Vec<Polygon<f64>> = plots.iter().map(|plot| plot.translate(1.0, 1.0)).collect();
+
+    c.bench_function("intersection of small overlapping polygons", |bencher| {
+        bencher.iter(|| {
+            for (a, b) in plots.iter().zip(&shifted) {
+                criterion::black_box(criterion::black_box(a).intersection(criterion::black_box(b)));
+            }
+        });
+    });
+
+    c.bench_function("union of small overlapping polygons", |bencher| {
+        bencher.iter(|| {
+            for (a, b) in plots.iter().zip(&shifted) {
+                criterion::black_box(criterion::black_box(a).union(criterion::black_box(b)));
+            }
+        });
+    });
+
+    c.bench_function("unary union of small adjacent polygons", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(unary_union(criterion::black_box(&plots)));
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{BoundingRect, ContainmentMask, Contains, LineString, Point, Polygon};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let norway: LineString = geo_test_fixtures::norway_main();
+    let polygon = Polygon::new(norway, vec![]);
+    let extent = polygon.bounding_rect().unwrap();
+    let (width, height) = (256, 256);
+
+    c.bench_function("ContainmentMask norway_main 256x256", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(polygon.containment_mask(extent, width, height));
+        });
+    });
+
+    c.bench_function("Contains per cell norway_main 256x256", |bencher| {
+        let (cell_width, cell_height) = (
+            extent.width() / width as f64,
+            extent.height() / height as f64,
+        );
+        bencher.iter(|| {
+            let mut count = 0;
+            for row in 0..height {
+                for column in 0..width {
+                    let center = Point::new(
+                        extent.min().x + (column as f64 + 0.5) * cell_width,
+                        extent.max().y - (row as f64 + 0.5) * cell_height,
+                    );
+                    count += polygon.contains(&center) as usize;
+                }
+            }
+            criterion::black_box(count);
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
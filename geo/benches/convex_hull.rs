@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use geo::prelude::*;
-use geo::{Coord, CoordNum};
+use geo::{ConvexHullStrategy, Coord, CoordNum, MultiPoint, Point};
 
 use num_traits::Signed;
 use rand::distributions::uniform::SampleUniform;
@@ -42,6 +42,29 @@ fn criterion_benchmark(c: &mut Criterion) {
             ));
         });
     });
+
+    let mut group = c.benchmark_group("convex hull strategies random f64");
+    let points: MultiPoint =
+        uniform_points_in_range(10_000_f64, 1_000_000, &mut rand::thread_rng())
+            .into_iter()
+            .map(Point::from)
+            .collect();
+    for strategy in [
+        ConvexHullStrategy::QuickHull,
+        ConvexHullStrategy::MonotoneChain,
+        ConvexHullStrategy::Graham,
+        #[cfg(feature = "multithreading")]
+        ConvexHullStrategy::ParallelQuickHull,
+    ] {
+        group.bench_function(format!("{strategy:?}"), |bencher| {
+            bencher.iter(|| {
+                criterion::black_box(
+                    criterion::black_box(&points).convex_hull_with_strategy(strategy),
+                );
+            });
+        });
+    }
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -7,7 +7,7 @@ fn criterion_benchmark(c: &mut criterion::Criterion) {
         let b = geo::Point::new(16.372477, 48.208810);
 
         bencher.iter(|| {
-            criterion::black_box(criterion::black_box(Geodesic::distance(a, b)));
+            criterion::black_box(criterion::black_box(Geodesic.distance(a, b)));
         });
     });
 }
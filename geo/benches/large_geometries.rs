@@ -0,0 +1,55 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::algorithm::{Area, Intersects, Scale, Translate};
+use geo::geometry::{Coord, LineString, Polygon, Rect};
+
+const NUM_COORDS: usize = 100_000;
+
+fn circle(num_coords: usize) -> LineString<f64> {
+    (0..num_coords)
+        .map(|i| {
+            let angle = i as f64 / num_coords as f64 * std::f64::consts::TAU;
+            Coord {
+                x: 1000. * angle.cos(),
+                y: 1000. * angle.sin(),
+            }
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let line_string = circle(NUM_COORDS);
+    let polygon = Polygon::new(line_string.clone(), vec![]);
+    let rect = Rect::new((-500., -500.), (500., 1000.));
+
+    c.bench_function("area of a 100k point polygon", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&polygon).unsigned_area()));
+    });
+
+    #[allow(deprecated)]
+    c.bench_function("EuclideanLength of a 100k point line string", |bencher| {
+        use geo::algorithm::EuclideanLength;
+        bencher
+            .iter(|| criterion::black_box(criterion::black_box(&line_string).euclidean_length()));
+    });
+
+    c.bench_function("translate a 100k point line string", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&line_string).translate(1., 2.)));
+    });
+
+    c.bench_function("scale a 100k point line string in place", |bencher| {
+        let mut line_string = line_string.clone();
+        bencher.iter(|| line_string.scale_mut(criterion::black_box(1.000001)));
+    });
+
+    c.bench_function("count 100k coords in a rect", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(&line_string)
+                .coords()
+                .filter(|coord| rect.intersects(*coord))
+                .count()
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
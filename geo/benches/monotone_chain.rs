@@ -0,0 +1,36 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{Distance, Euclidean, Intersects, LineString, MonotoneChains, Polygon, Translate};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let norway: LineString = geo_test_fixtures::norway_main();
+    let polygon = Polygon::new(norway.clone(), vec![]);
+    // crosses the coastline many times
+    let shifted = norway.translate(0.05, 0.05);
+    // close to, but not crossing, the coastline
+    let distant = norway.translate(40., 0.);
+
+    c.bench_function("Polygon intersects LineString norway_main", |bencher| {
+        bencher.iter(|| {
+            assert!(criterion::black_box(polygon.intersects(&shifted)));
+            assert!(!criterion::black_box(polygon.intersects(&distant)));
+        });
+    });
+
+    c.bench_function("Euclidean distance LineString norway_main", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(Euclidean.distance(&norway, &shifted));
+        });
+    });
+
+    c.bench_function("MonotoneChains intersects norway_main", |bencher| {
+        bencher.iter(|| {
+            let chains = MonotoneChains::new(&norway);
+            assert!(criterion::black_box(
+                chains.intersects(&MonotoneChains::new(&shifted))
+            ));
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,110 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use geo::{
+    Area, BoundingRect, Densify, Euclidean, Length, MultiLineString, MultiPolygon, ParallelArea,
+    ParallelBoundingRect, ParallelDensify, ParallelLength, ParallelSimplify, Simplify,
+};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let plots: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
+    let rings: MultiLineString = plots
+        .iter()
+        .map(|polygon| polygon.exterior().clone())
+        .collect();
+
+    // Run each parallel operation on thread pools of increasing size, to show how it scales
+    let thread_counts = [1, 2, 4, 8];
+
+    let mut group = c.benchmark_group("area");
+    group.bench_function("sequential", |bencher| {
+        bencher.iter(|| criterion::black_box(&plots).unsigned_area());
+    });
+    for threads in thread_counts {
+        let pool = thread_pool(threads);
+        group.bench_with_input(
+            BenchmarkId::new("parallel", threads),
+            &plots,
+            |bencher, plots| {
+                pool.install(|| bencher.iter(|| criterion::black_box(plots).par_unsigned_area()));
+            },
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("length");
+    group.bench_function("sequential", |bencher| {
+        bencher.iter(|| criterion::black_box(&rings).length(&Euclidean));
+    });
+    for threads in thread_counts {
+        let pool = thread_pool(threads);
+        group.bench_with_input(
+            BenchmarkId::new("parallel", threads),
+            &rings,
+            |bencher, rings| {
+                pool.install(|| {
+                    bencher.iter(|| criterion::black_box(rings).par_length(&Euclidean))
+                });
+            },
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("bounding_rect");
+    group.bench_function("sequential", |bencher| {
+        bencher.iter(|| criterion::black_box(&plots).bounding_rect());
+    });
+    for threads in thread_counts {
+        let pool = thread_pool(threads);
+        group.bench_with_input(
+            BenchmarkId::new("parallel", threads),
+            &plots,
+            |bencher, plots| {
+                pool.install(|| bencher.iter(|| criterion::black_box(plots).par_bounding_rect()));
+            },
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("densify");
+    group.bench_function("sequential", |bencher| {
+        bencher.iter(|| criterion::black_box(&plots).densify(&Euclidean, 1.0));
+    });
+    for threads in thread_counts {
+        let pool = thread_pool(threads);
+        group.bench_with_input(
+            BenchmarkId::new("parallel", threads),
+            &plots,
+            |bencher, plots| {
+                pool.install(|| {
+                    bencher.iter(|| criterion::black_box(plots).par_densify(&Euclidean, 1.0))
+                });
+            },
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("simplify");
+    group.bench_function("sequential", |bencher| {
+        bencher.iter(|| criterion::black_box(&plots).simplify(&0.5));
+    });
+    for threads in thread_counts {
+        let pool = thread_pool(threads);
+        group.bench_with_input(
+            BenchmarkId::new("parallel", threads),
+            &plots,
+            |bencher, plots| {
+                pool.install(|| bencher.iter(|| criterion::black_box(plots).par_simplify(&0.5)));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn thread_pool(threads: usize) -> rayon::ThreadPool {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap()
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,7 +1,21 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use geo::algorithm::Relate;
-use geo::PreparedGeometry;
-use geo_types::MultiPolygon;
+use geo::{BoundingRect, Contains, PreparedGeometry};
+use geo_types::{coord, LineString, MultiPolygon, Polygon, Rect};
+
+fn tiles(bounding_rect: Rect, n: usize) -> Vec<Rect> {
+    let width = bounding_rect.width() / n as f64;
+    let height = bounding_rect.height() / n as f64;
+    let min = bounding_rect.min();
+    (0..n)
+        .flat_map(|i| {
+            (0..n).map(move |j| {
+                let corner = coord! { x: min.x + i as f64 * width, y: min.y + j as f64 * height };
+                Rect::new(corner, corner + coord! { x: width, y: height })
+            })
+        })
+        .collect()
+}
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("relate prepared polygons", |bencher| {
@@ -61,5 +75,71 @@ fn criterion_benchmark(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, criterion_benchmark);
+fn tile_benchmark(c: &mut Criterion) {
+    let polygon = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+    let tiles = tiles(polygon.bounding_rect().unwrap(), 64);
+    let prepared = PreparedGeometry::from(&polygon);
+
+    c.bench_function("relate_rect prepared polygon tiles", |bencher| {
+        bencher.iter(|| {
+            tiles
+                .iter()
+                .filter(|tile| criterion::black_box(prepared.relate_rect(tile).is_intersects()))
+                .count()
+        });
+    });
+
+    c.bench_function("relate prepared polygon tiles", |bencher| {
+        bencher.iter(|| {
+            tiles
+                .iter()
+                .filter(|tile| criterion::black_box(prepared.relate(*tile).is_intersects()))
+                .count()
+        });
+    });
+}
+
+fn holes_benchmark(c: &mut Criterion) {
+    // a 64x64 grid of square holes, like a lake district
+    let exterior = LineString::from(vec![(0., 0.), (129., 0.), (129., 129.), (0., 129.)]);
+    let interiors = (0..64)
+        .flat_map(|i| {
+            (0..64).map(move |j| {
+                let (x, y) = (2. * i as f64 + 1., 2. * j as f64 + 1.);
+                LineString::from(vec![(x, y), (x, y + 1.), (x + 1., y + 1.), (x + 1., y)])
+            })
+        })
+        .collect();
+    let polygon = Polygon::new(exterior, interiors);
+    let prepared = PreparedGeometry::from(&polygon);
+    let coords: Vec<_> = tiles(polygon.bounding_rect().unwrap(), 100)
+        .iter()
+        .map(|tile| tile.center())
+        .collect();
+
+    c.bench_function("contains prepared polygon with holes", |bencher| {
+        bencher.iter(|| {
+            coords
+                .iter()
+                .filter(|coord| criterion::black_box(prepared.contains(*coord)))
+                .count()
+        });
+    });
+
+    c.bench_function("contains unprepared polygon with holes", |bencher| {
+        bencher.iter(|| {
+            coords
+                .iter()
+                .filter(|coord| criterion::black_box(polygon.contains(*coord)))
+                .count()
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    criterion_benchmark,
+    tile_benchmark,
+    holes_benchmark
+);
 criterion_main!(benches);
@@ -0,0 +1,29 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{Area, BooleanOps, Polygon, PromotedArea, PromotedBooleanOps, Translate};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let norway = Polygon::new(geo_test_fixtures::norway_main::<f32>(), vec![]);
+
+    c.bench_function("area f32", |bencher| {
+        bencher.iter(|| criterion::black_box(&norway).signed_area());
+    });
+
+    c.bench_function("area f32 promoted", |bencher| {
+        bencher.iter(|| criterion::black_box(&norway).signed_area_promoted());
+    });
+
+    let shifted = norway.translate(0.5, 0.5);
+
+    c.bench_function("intersection f32", |bencher| {
+        bencher.iter(|| criterion::black_box(&norway).intersection(criterion::black_box(&shifted)));
+    });
+
+    c.bench_function("intersection f32 promoted", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(&norway).intersection_promoted(criterion::black_box(&shifted))
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::spatial_join::points_in_polygons;
+use geo::{BoundingRect, Contains, MultiPolygon, Point};
+
+#[path = "utils/random.rs"]
+mod random;
+use rand::thread_rng;
+use random::*;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let zones: MultiPolygon = geo_test_fixtures::nl_zones();
+    let bounds = zones.bounding_rect().unwrap();
+    let points: Vec<Point> = (0..10_000)
+        .map(|_| uniform_point(&mut thread_rng(), bounds).into())
+        .collect();
+
+    c.bench_function("points_in_polygons nl_zones", |bencher| {
+        bencher.iter(|| criterion::black_box(points_in_polygons(&points, &zones.0)));
+    });
+
+    c.bench_function("points in polygons with contains nl_zones", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                points
+                    .iter()
+                    .map(|point| zones.iter().position(|zone| zone.contains(point)))
+                    .collect::<Vec<_>>(),
+            )
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
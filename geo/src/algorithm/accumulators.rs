@@ -0,0 +1,342 @@
+use crate::bounding_rect::bounding_rect_merge;
+use crate::centroid::CentroidOperation;
+use crate::convex_hull::quick_hull;
+use crate::geometry_trait_ext;
+use crate::{Area, Coord, CoordFloat, CoordNum, GeoFloat, GeoNum, Point, Polygon, Rect};
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::GeometryTrait;
+
+/// Accumulates the total area of a stream of geometries, without storing them.
+///
+/// Geometries may be of any type implementing [`GeometryTrait`], e.g. as decoded one at a time
+/// from a file or database cursor. Each is converted to a [`Geometry`](crate::Geometry) only
+/// while it is added.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, AreaAccumulator};
+///
+/// let mut accumulator = AreaAccumulator::new();
+/// accumulator.add(&wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.))));
+/// accumulator.add(&wkt!(POLYGON((0. 0.,0. 1.,1. 1.,1. 0.,0. 0.))));
+/// accumulator.add(&wkt!(POINT(5. 5.)));
+///
+/// assert_eq!(accumulator.unsigned_area(), 5.);
+/// assert_eq!(accumulator.signed_area(), 3.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AreaAccumulator<T: CoordFloat = f64> {
+    signed_area: T,
+    unsigned_area: T,
+}
+
+impl<T: CoordFloat> AreaAccumulator<T> {
+    pub fn new() -> Self {
+        Self {
+            signed_area: T::zero(),
+            unsigned_area: T::zero(),
+        }
+    }
+
+    /// Add the area of `geometry`.
+    pub fn add(&mut self, geometry: &impl GeometryTrait<T = T>) {
+        if let Some(geometry) = geometry.try_to_geometry() {
+            self.signed_area = self.signed_area + geometry.signed_area();
+            self.unsigned_area = self.unsigned_area + geometry.unsigned_area();
+        }
+    }
+
+    /// The sum of the [signed areas](Area::signed_area) of the geometries added so far.
+    pub fn signed_area(&self) -> T {
+        self.signed_area
+    }
+
+    /// The sum of the [unsigned areas](Area::unsigned_area) of the geometries added so far.
+    pub fn unsigned_area(&self) -> T {
+        self.unsigned_area
+    }
+}
+
+impl<T: CoordFloat> Default for AreaAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordFloat, G: GeometryTrait<T = T>> Extend<G> for AreaAccumulator<T> {
+    fn extend<I: IntoIterator<Item = G>>(&mut self, geometries: I) {
+        geometries
+            .into_iter()
+            .for_each(|geometry| self.add(&geometry));
+    }
+}
+
+/// Accumulates the bounding rectangle of a stream of geometries, without storing them.
+///
+/// Geometries may be of any type implementing [`GeometryTrait`], e.g. as decoded one at a time
+/// from a file or database cursor. Each is converted to a [`Geometry`](crate::Geometry) only
+/// while it is added.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, BboxAccumulator, Rect};
+///
+/// let mut accumulator = BboxAccumulator::new();
+/// assert_eq!(accumulator.bounding_rect(), None);
+///
+/// accumulator.extend([wkt!(POINT(1. 5.)), wkt!(POINT(-2. 3.))]);
+/// accumulator.add(&wkt!(LINESTRING(0. 0.,4. 1.)));
+///
+/// assert_eq!(
+///     accumulator.bounding_rect(),
+///     Some(Rect::new((-2., 0.), (4., 5.)))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BboxAccumulator<T: CoordNum = f64> {
+    bounding_rect: Option<Rect<T>>,
+}
+
+impl<T: CoordNum> BboxAccumulator<T> {
+    pub fn new() -> Self {
+        Self {
+            bounding_rect: None,
+        }
+    }
+
+    /// Extend the bounding rectangle to cover `geometry`.
+    pub fn add(&mut self, geometry: &impl GeometryTrait<T = T>) {
+        let Some(rect) = geometry_trait_ext::GeometryTraitExt::bounding_rect(geometry) else {
+            return;
+        };
+        self.bounding_rect = Some(match self.bounding_rect {
+            Some(bounding_rect) => bounding_rect_merge(bounding_rect, rect),
+            None => rect,
+        });
+    }
+
+    /// The bounding rectangle of the geometries added so far, or `None` if they are all empty.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        self.bounding_rect
+    }
+}
+
+impl<T: CoordNum> Default for BboxAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum, G: GeometryTrait<T = T>> Extend<G> for BboxAccumulator<T> {
+    fn extend<I: IntoIterator<Item = G>>(&mut self, geometries: I) {
+        geometries
+            .into_iter()
+            .for_each(|geometry| self.add(&geometry));
+    }
+}
+
+/// Accumulates the centroid of a stream of geometries, without storing them.
+///
+/// The result is the same as the [`Centroid`](crate::Centroid) of a
+/// [`GeometryCollection`](crate::GeometryCollection) of all the geometries: only those of the
+/// highest dimension contribute, e.g. points are ignored once a polygon has been added.
+///
+/// Geometries may be of any type implementing [`GeometryTrait`], e.g. as decoded one at a time
+/// from a file or database cursor. Each is converted to a [`Geometry`](crate::Geometry) only
+/// while it is added.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, CentroidAccumulator};
+///
+/// let mut accumulator = CentroidAccumulator::new();
+/// accumulator.add(&wkt!(POINT(100. 100.)));
+/// assert_eq!(accumulator.centroid(), Some(point!(x: 100., y: 100.)));
+///
+/// accumulator.add(&wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.))));
+/// accumulator.add(&wkt!(POLYGON((2. 0.,4. 0.,4. 2.,2. 2.,2. 0.))));
+/// assert_eq!(accumulator.centroid(), Some(point!(x: 2., y: 1.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CentroidAccumulator<T: GeoFloat = f64> {
+    operation: CentroidOperation<T>,
+}
+
+impl<T: GeoFloat> CentroidAccumulator<T> {
+    pub fn new() -> Self {
+        Self {
+            operation: CentroidOperation::new(),
+        }
+    }
+
+    /// Add `geometry` to the centroid.
+    pub fn add(&mut self, geometry: &impl GeometryTrait<T = T>) {
+        if let Some(geometry) = geometry.try_to_geometry() {
+            self.operation.add_geometry(&geometry);
+        }
+    }
+
+    /// The centroid of the geometries added so far, or `None` if they are all empty.
+    pub fn centroid(&self) -> Option<Point<T>> {
+        self.operation.centroid()
+    }
+}
+
+impl<T: GeoFloat> Default for CentroidAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: GeoFloat, G: GeometryTrait<T = T>> Extend<G> for CentroidAccumulator<T> {
+    fn extend<I: IntoIterator<Item = G>>(&mut self, geometries: I) {
+        geometries
+            .into_iter()
+            .for_each(|geometry| self.add(&geometry));
+    }
+}
+
+/// Computes the convex hull of a point set too large to hold in memory, from batches of points.
+///
+/// Each batch is merged with the running hull with [`quick_hull`], after which only the hull's
+/// points are kept, so memory use is bounded by the largest batch plus the hull. The points may
+/// be split into batches arbitrarily, e.g. as read a chunk at a time from a file, and the final
+/// hull has the same vertices as the [`ConvexHull`](crate::ConvexHull) of all of them at once.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, ConvexHullAccumulator};
+///
+/// let mut accumulator = ConvexHullAccumulator::new();
+/// accumulator.push_batch([coord! { x: 0., y: 0. }, coord! { x: 4., y: 0. }, coord! { x: 1., y: 1. }]);
+/// accumulator.push_batch([coord! { x: 2., y: 1. }, coord! { x: 4., y: 4. }, coord! { x: 0., y: 4. }]);
+///
+/// assert_eq!(
+///     accumulator.finish(),
+///     polygon![(x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.), (x: 4., y: 0.)]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConvexHullAccumulator<T: GeoNum = f64> {
+    hull: Vec<Coord<T>>,
+}
+
+impl<T: GeoNum> ConvexHullAccumulator<T> {
+    pub fn new() -> Self {
+        Self { hull: vec![] }
+    }
+
+    /// Merge a batch of points into the running hull.
+    pub fn push_batch<I>(&mut self, batch: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<Coord<T>>,
+    {
+        let mut points = std::mem::take(&mut self.hull);
+        points.extend(batch.into_iter().map(Into::into));
+        let mut hull = quick_hull(&mut points).0;
+        // the running hull is kept open
+        hull.pop();
+        self.hull = hull;
+    }
+
+    /// The points of the running hull, counter-clockwise, without repeating the first.
+    pub fn hull_points(&self) -> &[Coord<T>] {
+        &self.hull
+    }
+
+    /// The convex hull of all the batches.
+    pub fn finish(self) -> Polygon<T> {
+        let mut points = self.hull;
+        Polygon::new(quick_hull(&mut points), vec![])
+    }
+}
+
+impl<T: GeoNum> Default for ConvexHullAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{wkt, BoundingRect, Centroid, Geometry, GeometryCollection, Triangle};
+
+    #[test]
+    fn accumulators_match_collection() {
+        let geometries: Vec<Geometry> = vec![
+            wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.))).into(),
+            wkt!(MULTIPOLYGON(((5. 5.,6. 5.,6. 7.,5. 5.)),((-1. -1.,-1. -3.,-4. -1.,-1. -1.))))
+                .into(),
+            wkt!(LINESTRING(10. 10.,20. 30.)).into(),
+            wkt!(POINT(-50. 3.)).into(),
+            Triangle::new((1., 1.).into(), (2., 1.).into(), (1., 8.).into()).into(),
+        ];
+        let collection = GeometryCollection::new_from(geometries.clone());
+
+        let mut area = AreaAccumulator::new();
+        let mut bbox = BboxAccumulator::new();
+        let mut centroid = CentroidAccumulator::new();
+        for geometry in &geometries {
+            area.add(geometry);
+            bbox.add(geometry);
+            centroid.add(geometry);
+        }
+
+        assert_eq!(area.signed_area(), collection.signed_area());
+        assert_eq!(area.unsigned_area(), collection.unsigned_area());
+        assert_eq!(bbox.bounding_rect(), collection.bounding_rect());
+        assert_relative_eq!(centroid.centroid().unwrap(), collection.centroid().unwrap());
+    }
+
+    #[test]
+    fn convex_hull_batches() {
+        use crate::{ConvexHull, CoordsIter, MultiPoint};
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let points: Vec<Coord> = (0..10_000)
+            .map(|_| (rng.gen_range(-100. ..100.), rng.gen_range(-50. ..50.)).into())
+            .collect();
+        let mut accumulator = ConvexHullAccumulator::new();
+        for batch in points.chunks(777) {
+            accumulator.push_batch(batch.iter().copied());
+            assert!(accumulator.hull_points().len() < 100);
+        }
+        let hull = accumulator.finish();
+
+        let mut expected: Vec<Coord> = MultiPoint::from(points)
+            .convex_hull()
+            .exterior_coords_iter()
+            .collect();
+        let mut got: Vec<Coord> = hull.exterior_coords_iter().collect();
+        expected.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        got.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn empty() {
+        let mut centroid = CentroidAccumulator::<f64>::new();
+        let mut bbox = BboxAccumulator::<f64>::new();
+        let empty = wkt!(GEOMETRYCOLLECTION EMPTY);
+        centroid.add(&empty);
+        bbox.add(&empty);
+        assert_eq!(centroid.centroid(), None);
+        assert_eq!(bbox.bounding_rect(), None);
+        assert_eq!(AreaAccumulator::<f64>::default().unsigned_area(), 0.);
+
+        let mut hull = ConvexHullAccumulator::<f64>::new();
+        hull.push_batch(Vec::<Coord>::new());
+        assert_eq!(hull.finish().exterior().0, vec![]);
+        let mut hull = ConvexHullAccumulator::<f64>::new();
+        hull.push_batch([(1., 1.), (1., 1.)]);
+        hull.push_batch([(1., 1.)]);
+        assert_eq!(hull.hull_points(), [(1., 1.).into()]);
+    }
+}
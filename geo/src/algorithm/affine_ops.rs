@@ -1,6 +1,6 @@
-use num_traits::ToPrimitive;
+use num_traits::{Signed, ToPrimitive};
 
-use crate::{Coord, CoordFloat, CoordNum, MapCoords, MapCoordsInPlace};
+use crate::{Coord, CoordFloat, CoordNum, Line, MapCoords, MapCoordsInPlace};
 use std::{fmt, ops::Mul, ops::Neg};
 
 /// Apply an [`AffineTransform`] like [`scale`](AffineTransform::scale),
@@ -338,6 +338,116 @@ impl<T: CoordNum> AffineTransform<T> {
     }
 }
 
+impl<T: CoordNum + Signed> AffineTransform<T> {
+    /// **Create** an affine transform for rotating by `turns` quarter turns (multiples of 90°)
+    /// counter-clockwise about `origin`, or clockwise if `turns` is negative.
+    ///
+    /// Unlike [`rotate`](Self::rotate), this is available for integer coordinates, which it
+    /// keeps exact: the matrix only has entries of 0 and ±1, e.g. for one quarter turn:
+    /// ```ignore
+    /// [[0, -1, origin.x + origin.y],
+    /// [1, 0, origin.y - origin.x],
+    /// [0, 0, 1]]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{AffineOps, AffineTransform, wkt};
+    ///
+    /// // e.g. nanodegrees
+    /// let line_string = wkt!(LINESTRING(10_000_000_000i64 0,20_000_000_000 5));
+    /// let transform = AffineTransform::rotate_quarter_turns(1, (10_000_000_000, 0));
+    ///
+    /// assert_eq!(
+    ///     line_string.affine_transform(&transform),
+    ///     wkt!(LINESTRING(10_000_000_000i64 0,9_999_999_995 10_000_000_000))
+    /// );
+    /// ```
+    pub fn rotate_quarter_turns(turns: i32, origin: impl Into<Coord<T>>) -> Self {
+        let Coord { x: x0, y: y0 } = origin.into();
+        let (zero, one) = (T::zero(), T::one());
+        match turns.rem_euclid(4) {
+            0 => Self::identity(),
+            1 => Self::new(zero, -one, x0 + y0, one, zero, y0 - x0),
+            2 => Self::new(-one, zero, x0 + x0, zero, -one, y0 + y0),
+            _ => Self::new(zero, one, x0 - y0, -one, zero, x0 + y0),
+        }
+    }
+
+    /// **Add** an affine transform for rotating by `turns` quarter turns about `origin`. See
+    /// [`rotate_quarter_turns`](Self::rotate_quarter_turns).
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn rotated_quarter_turns(mut self, turns: i32, origin: impl Into<Coord<T>>) -> Self {
+        self.0 = self.compose(&Self::rotate_quarter_turns(turns, origin)).0;
+        self
+    }
+
+    /// **Create** an affine transform mirroring coordinates across the vertical line through
+    /// `origin`, negating their `x` offsets from it.
+    ///
+    /// This is available for integer coordinates, which it keeps exact. The matrix is:
+    /// ```ignore
+    /// [[-1, 0, 2 * origin.x],
+    /// [0, 1, 0],
+    /// [0, 0, 1]]
+    /// ```
+    pub fn mirror_x(origin: impl Into<Coord<T>>) -> Self {
+        let x0 = origin.into().x;
+        Self::new(
+            -T::one(),
+            T::zero(),
+            x0 + x0,
+            T::zero(),
+            T::one(),
+            T::zero(),
+        )
+    }
+
+    /// **Add** an affine transform mirroring coordinates across the vertical line through
+    /// `origin`. See [`mirror_x`](Self::mirror_x).
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn mirrored_x(mut self, origin: impl Into<Coord<T>>) -> Self {
+        self.0 = self.compose(&Self::mirror_x(origin)).0;
+        self
+    }
+
+    /// **Create** an affine transform mirroring coordinates across the horizontal line through
+    /// `origin`, negating their `y` offsets from it.
+    ///
+    /// This is available for integer coordinates, which it keeps exact. The matrix is:
+    /// ```ignore
+    /// [[1, 0, 0],
+    /// [0, -1, 2 * origin.y],
+    /// [0, 0, 1]]
+    /// ```
+    pub fn mirror_y(origin: impl Into<Coord<T>>) -> Self {
+        let y0 = origin.into().y;
+        Self::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            -T::one(),
+            y0 + y0,
+        )
+    }
+
+    /// **Add** an affine transform mirroring coordinates across the horizontal line through
+    /// `origin`. See [`mirror_y`](Self::mirror_y).
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn mirrored_y(mut self, origin: impl Into<Coord<T>>) -> Self {
+        self.0 = self.compose(&Self::mirror_y(origin)).0;
+        self
+    }
+}
+
 impl<T: CoordNum + Neg> AffineTransform<T> {
     /// Return the inverse of a given transform. Composing a transform with its inverse yields
     /// the [identity matrix](Self::identity)
@@ -481,6 +591,224 @@ impl<U: CoordFloat> AffineTransform<U> {
         self.0 = self.compose(&Self::skew(xs, ys, origin)).0;
         self
     }
+
+    /// **Create** an affine transform for skewing parallel to an arbitrary `axis`.
+    ///
+    /// Note that this operation is only available for geometries with floating point coordinates.
+    ///
+    /// Geometries are sheared by an angle of `degrees`: each coordinate moves parallel to the
+    /// line through `axis`, by the tangent of the angle times its distance from the line, in
+    /// the direction of `axis` for coordinates to its left. Coordinates on the line don't move.
+    /// An `axis` along the x dimension gives the same transform as [`skew`](Self::skew) by
+    /// `degrees` along x. If `axis` has no length, this is the identity transform.
+    ///
+    /// With `(ux, uy)` the unit vector along `axis`, `k` the tangent of `degrees` and `c` the
+    /// cross product of `(ux, uy)` and `axis.start`, the matrix is:
+    /// ```ignore
+    /// [[1 - k * ux * uy, k * ux * ux, -k * ux * c],
+    /// [-k * uy * uy, 1 + k * ux * uy, -k * uy * c],
+    /// [0, 0, 1]]
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{AffineOps, AffineTransform, Line, wkt};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // shear parallel to the diagonal y = x
+    /// let transform = AffineTransform::skew_along(45.0, Line::new((0.0, 0.0), (1.0, 1.0)));
+    ///
+    /// let point = wkt!(POINT(0.0 2.0));
+    /// // the point is 2.sqrt() to the left of the diagonal, so it moves that far along it
+    /// assert_relative_eq!(point.affine_transform(&transform), wkt!(POINT(1.0 3.0)), epsilon = 1e-12);
+    /// // points on the diagonal stay put
+    /// let point = wkt!(POINT(5.0 5.0));
+    /// assert_relative_eq!(point.affine_transform(&transform), point, epsilon = 1e-12);
+    /// ```
+    pub fn skew_along(degrees: U, axis: Line<U>) -> Self {
+        let delta = axis.delta();
+        let length = delta.x.hypot(delta.y);
+        if length == U::zero() {
+            return Self::identity();
+        }
+        let (ux, uy) = (delta.x / length, delta.y / length);
+        let k = degrees.to_radians().tan();
+        let c = ux * axis.start.y - uy * axis.start.x;
+        Self::new(
+            U::one() - k * ux * uy,
+            k * ux * ux,
+            -k * ux * c,
+            -k * uy * uy,
+            U::one() + k * ux * uy,
+            -k * uy * c,
+        )
+    }
+
+    /// **Add** an affine transform for skewing parallel to an arbitrary `axis`, by an angle of
+    /// `degrees`. See [`skew_along`](Self::skew_along).
+    ///
+    /// This is a **cumulative** operation; the new transform is *added* to the existing transform.
+    #[must_use]
+    pub fn skewed_along(mut self, degrees: U, axis: Line<U>) -> Self {
+        self.0 = self.compose(&Self::skew_along(degrees, axis)).0;
+        self
+    }
+}
+
+/// Build an [`AffineTransform`] from a sequence of operations, each applied after the previous
+/// ones.
+///
+/// Unlike the mutation methods of `AffineTransform`, scaling, rotating and skewing are about
+/// the origin `(0, 0)` unless an anchor point is given with the `_around` variant of the
+/// operation.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, TransformBuilder};
+/// use approx::assert_relative_eq;
+///
+/// let transform = TransformBuilder::new()
+///     .rotate_around(point!(x: 1.0, y: 1.0), 90.0)
+///     .scale(2.0, 3.0)
+///     .translate(10.0, 0.0);
+///
+/// let line_string = wkt!(LINESTRING(1.0 1.0,2.0 1.0));
+/// let transformed = transform.apply_to(&line_string);
+/// assert_relative_eq!(transformed, wkt!(LINESTRING(12.0 3.0,12.0 6.0)));
+///
+/// // and back
+/// let inverse = transform.inverse().unwrap();
+/// assert_relative_eq!(inverse.apply_to(&transformed), line_string);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransformBuilder<T: CoordNum = f64> {
+    transform: AffineTransform<T>,
+}
+
+impl<T: CoordNum> TransformBuilder<T> {
+    /// A builder of the [identity](AffineTransform::identity) transform, which operations are
+    /// then added to.
+    pub fn new() -> Self {
+        Self {
+            transform: AffineTransform::identity(),
+        }
+    }
+
+    /// Scale by factors along the `x` and `y` dimensions, about the origin.
+    #[must_use]
+    pub fn scale(self, xfact: T, yfact: T) -> Self {
+        self.scale_around(Coord::zero(), xfact, yfact)
+    }
+
+    /// Scale by factors along the `x` and `y` dimensions, about `anchor`.
+    #[must_use]
+    pub fn scale_around(self, anchor: impl Into<Coord<T>>, xfact: T, yfact: T) -> Self {
+        self.then(&AffineTransform::scale(xfact, yfact, anchor))
+    }
+
+    /// Translate by offsets along the `x` and `y` dimensions.
+    #[must_use]
+    pub fn translate(self, xoff: T, yoff: T) -> Self {
+        self.then(&AffineTransform::translate(xoff, yoff))
+    }
+
+    /// Apply `transform` after the operations so far.
+    #[must_use]
+    pub fn then(self, transform: &AffineTransform<T>) -> Self {
+        Self {
+            transform: self.transform.compose(transform),
+        }
+    }
+
+    /// The transform composed of the operations so far.
+    pub fn build(&self) -> AffineTransform<T> {
+        self.transform
+    }
+
+    /// Apply the transform to `geometry`, outputting a new geometry.
+    #[must_use]
+    pub fn apply_to<G: AffineOps<T>>(&self, geometry: &G) -> G {
+        geometry.affine_transform(&self.transform)
+    }
+}
+
+impl<T: CoordFloat> TransformBuilder<T> {
+    /// Rotate by `degrees` counter-clockwise about the origin.
+    #[must_use]
+    pub fn rotate(self, degrees: T) -> Self {
+        self.rotate_around(Coord::zero(), degrees)
+    }
+
+    /// Rotate by `degrees` counter-clockwise about `anchor`.
+    #[must_use]
+    pub fn rotate_around(self, anchor: impl Into<Coord<T>>, degrees: T) -> Self {
+        self.then(&AffineTransform::rotate(degrees, anchor))
+    }
+
+    /// Skew by angles along the `x` and `y` dimensions, in degrees, about the origin.
+    #[must_use]
+    pub fn skew(self, xs: T, ys: T) -> Self {
+        self.skew_around(Coord::zero(), xs, ys)
+    }
+
+    /// Skew by angles along the `x` and `y` dimensions, in degrees, about `anchor`.
+    #[must_use]
+    pub fn skew_around(self, anchor: impl Into<Coord<T>>, xs: T, ys: T) -> Self {
+        self.then(&AffineTransform::skew(xs, ys, anchor))
+    }
+
+    /// Skew by `degrees` parallel to `axis`. See [`AffineTransform::skew_along`].
+    #[must_use]
+    pub fn skew_along(self, axis: Line<T>, degrees: T) -> Self {
+        self.then(&AffineTransform::skew_along(degrees, axis))
+    }
+
+    /// A builder of the transform undoing the operations so far, or `None` if they can't be
+    /// undone, e.g. after scaling by 0.
+    pub fn inverse(&self) -> Option<Self> {
+        self.transform.inverse().map(|transform| Self { transform })
+    }
+}
+
+impl<T: CoordNum + Signed> TransformBuilder<T> {
+    /// Rotate by `turns` quarter turns counter-clockwise about the origin, exactly for integer
+    /// coordinates. See [`AffineTransform::rotate_quarter_turns`].
+    #[must_use]
+    pub fn rotate_quarter_turns(self, turns: i32) -> Self {
+        self.rotate_quarter_turns_around(Coord::zero(), turns)
+    }
+
+    /// Rotate by `turns` quarter turns counter-clockwise about `anchor`.
+    #[must_use]
+    pub fn rotate_quarter_turns_around(self, anchor: impl Into<Coord<T>>, turns: i32) -> Self {
+        self.then(&AffineTransform::rotate_quarter_turns(turns, anchor))
+    }
+
+    /// Mirror across the vertical line through `anchor`. See [`AffineTransform::mirror_x`].
+    #[must_use]
+    pub fn mirror_x(self, anchor: impl Into<Coord<T>>) -> Self {
+        self.then(&AffineTransform::mirror_x(anchor))
+    }
+
+    /// Mirror across the horizontal line through `anchor`. See [`AffineTransform::mirror_y`].
+    #[must_use]
+    pub fn mirror_y(self, anchor: impl Into<Coord<T>>) -> Self {
+        self.then(&AffineTransform::mirror_y(anchor))
+    }
+}
+
+impl<T: CoordNum> Default for TransformBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum> From<TransformBuilder<T>> for AffineTransform<T> {
+    fn from(builder: TransformBuilder<T>) -> Self {
+        builder.build()
+    }
 }
 
 #[cfg(test)]
@@ -642,4 +970,83 @@ mod tests {
 
         assert_eq!(point.affine_transform(&composed), Point::new(8., 0.));
     }
+    #[test]
+    fn test_transform_builder() {
+        let builder = TransformBuilder::new()
+            .scale(2., 2.)
+            .rotate_around((1., 1.), 45.)
+            .skew(10., 0.)
+            .translate(3., -1.);
+        let expected = AffineTransform::scale(2., 2., (0., 0.))
+            .rotated(45., (1., 1.))
+            .skewed(10., 0., (0., 0.))
+            .translated(3., -1.);
+        assert_eq!(builder.build(), expected);
+        assert_eq!(AffineTransform::from(builder), expected);
+
+        let point = Point::new(5., 7.);
+        assert_eq!(builder.apply_to(&point), point.affine_transform(&expected));
+        assert_relative_eq!(
+            builder
+                .inverse()
+                .unwrap()
+                .apply_to(&builder.apply_to(&point)),
+            point,
+            epsilon = 1e-12
+        );
+
+        let axis = crate::Line::new((0., 0.), (1., 2.));
+        assert_eq!(
+            TransformBuilder::new().skew_along(axis, 30.).build(),
+            AffineTransform::identity().skewed_along(30., axis)
+        );
+
+        assert!(TransformBuilder::new().scale(0., 1.).inverse().is_none());
+        assert!(TransformBuilder::<f64>::default().build().is_identity());
+    }
+
+    #[test]
+    fn test_exact_integer_ops() {
+        let origin = (3_i64, -2);
+        let quarter = AffineTransform::rotate_quarter_turns(1, origin);
+        assert_eq!(
+            quarter
+                .rotated_quarter_turns(1, origin)
+                .rotated_quarter_turns(2, origin),
+            AffineTransform::identity()
+        );
+        assert_eq!(
+            AffineTransform::rotate_quarter_turns(-1, origin),
+            AffineTransform::rotate_quarter_turns(3, origin)
+        );
+        assert_eq!(
+            AffineTransform::rotate_quarter_turns(2, origin),
+            AffineTransform::mirror_x(origin).mirrored_y(origin)
+        );
+        assert!(AffineTransform::mirror_y(origin)
+            .mirrored_y(origin)
+            .is_identity());
+
+        let polygon = wkt!(POLYGON((0 0,7 0,7 3,0 0)));
+        assert_eq!(
+            polygon.affine_transform(&quarter),
+            wkt!(POLYGON((1 -5,1 2,-2 2,1 -5)))
+        );
+        assert_eq!(
+            TransformBuilder::new()
+                .rotate_quarter_turns_around(origin, 1)
+                .mirror_x((1, 0))
+                .apply_to(&polygon),
+            wkt!(POLYGON((1 -5,1 2,4 2,1 -5)))
+        );
+
+        // the same as rotating floats, up to rounding
+        for turns in -4..=4 {
+            assert_relative_eq!(
+                AffineTransform::rotate_quarter_turns(turns, (3.0, -2.0)),
+                AffineTransform::rotate(turns as f64 * 90.0, (3.0, -2.0)),
+                epsilon = 1e-12
+            );
+        }
+    }
 }
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use spade::handles::{DirectedEdgeHandle, FaceHandle, PossiblyOuterTag, VertexHandle};
+use spade::{DelaunayTriangulation, Point2, Triangulation};
+
+use crate::triangulate_spade::SpadeTriangulationFloat;
+use crate::{Area, Contains, Coord, CoordsIter, LineString, MultiPolygon, Point, Polygon};
+
+/// Returns the [alpha shape](https://en.wikipedia.org/wiki/Alpha_shape) of a geometry's
+/// coordinates, a generalization of the concave hull.
+///
+/// The alpha shape is the union of the triangles of the Delaunay triangulation of the
+/// coordinates whose circumcircle has a radius of at most `alpha`. Small values of `alpha`
+/// carve out the gaps between sparse points, leaving holes and splitting the shape into
+/// several polygons, while an infinite `alpha` gives the convex hull.
+///
+/// Unlike [`ConcaveHull`](crate::ConcaveHull), the result is never self-intersecting. Where
+/// the shape is pinched to a single vertex, the polygons on either side touch at that vertex.
+/// The edges and points of the alpha complex which don't belong to a triangle are omitted, so
+/// that fewer than three coordinates give an empty `MultiPolygon`.
+///
+/// Exterior rings are counter-clockwise and interior rings are clockwise. Coordinates which
+/// can't be triangulated, such as NaN, are skipped.
+///
+/// # Examples
+/// ```
+/// use geo::{AlphaShape, Area, MultiPoint};
+///
+/// // A 2x2 square of points, and a distant outlier
+/// let points: MultiPoint = vec![
+///     (0., 0.), (1., 0.), (2., 0.),
+///     (0., 1.), (1., 1.), (2., 1.),
+///     (0., 2.), (1., 2.), (2., 2.),
+///     (10., 0.),
+/// ]
+/// .into();
+///
+/// // The convex hull
+/// let hull = points.alpha_shape(f64::INFINITY);
+/// assert_eq!(hull.unsigned_area(), 12.0);
+///
+/// // The triangles of the square have a circumradius of √2 / 2, while those reaching the
+/// // outlier are much larger
+/// let shape = points.alpha_shape(1.0);
+/// assert_eq!(shape.0.len(), 1);
+/// assert_eq!(shape.unsigned_area(), 4.0);
+/// ```
+pub trait AlphaShape<T: SpadeTriangulationFloat> {
+    fn alpha_shape(&self, alpha: T) -> MultiPolygon<T>;
+}
+
+impl<T, G> AlphaShape<T> for G
+where
+    T: SpadeTriangulationFloat,
+    G: CoordsIter<Scalar = T>,
+{
+    fn alpha_shape(&self, alpha: T) -> MultiPolygon<T> {
+        let mut triangulation = DelaunayTriangulation::<Point2<T>>::new();
+        for coord in self.coords_iter() {
+            let _ = triangulation.insert(Point2::new(coord.x, coord.y));
+        }
+        alpha_shape(&triangulation, alpha)
+    }
+}
+
+fn alpha_shape<T: SpadeTriangulationFloat>(
+    triangulation: &DelaunayTriangulation<Point2<T>>,
+    alpha: T,
+) -> MultiPolygon<T> {
+    // `circumcircle` gives the squared radius
+    let max_radius_2 = alpha * alpha;
+    let mut kept = vec![false; triangulation.num_all_faces()];
+    for face in triangulation.inner_faces() {
+        kept[face.fix().index()] = face.circumcircle().1 <= max_radius_2;
+    }
+    let is_kept = |face: FaceHandle<PossiblyOuterTag, _, _, _, _>| kept[face.fix().index()];
+    let is_boundary = |edge: DirectedEdgeHandle<Point2<T>, _, _, _>| {
+        is_kept(edge.face()) && !is_kept(edge.rev().face())
+    };
+
+    // Each boundary edge has a kept triangle on its left, so walking the boundary edges gives
+    // counter-clockwise exteriors and clockwise interiors. At a vertex, the walk continues
+    // along the boundary of the fan of kept triangles it arrived through.
+    let mut visited = vec![false; triangulation.num_directed_edges()];
+    let mut exteriors = Vec::new();
+    let mut interiors = Vec::new();
+    for start in triangulation.directed_edges() {
+        if visited[start.fix().index()] || !is_boundary(start) {
+            continue;
+        }
+        let mut vertices = Vec::new();
+        let mut edge = start;
+        while !visited[edge.fix().index()] {
+            visited[edge.fix().index()] = true;
+            vertices.push(edge.from());
+            edge = edge.next();
+            while is_kept(edge.rev().face()) {
+                edge = edge.rev().next();
+            }
+        }
+        for ring in split_ring(vertices) {
+            let polygon = Polygon::new(ring, vec![]);
+            if polygon.signed_area() > T::zero() {
+                exteriors.push(polygon);
+            } else {
+                interiors.push(polygon.into_inner().0);
+            }
+        }
+    }
+
+    // A hole belongs to the smallest exterior around it. The midpoint of one of its edges is
+    // strictly inside that exterior, and outside any exterior nested within the hole.
+    exteriors.sort_by(|a, b| a.unsigned_area().total_cmp(&b.unsigned_area()));
+    let mut holes = vec![Vec::new(); exteriors.len()];
+    for interior in interiors {
+        let line = interior
+            .lines()
+            .next()
+            .expect("rings have at least 3 edges");
+        let two = T::one() + T::one();
+        let midpoint = Point::from((line.start + line.end) / two);
+        if let Some(index) = exteriors
+            .iter()
+            .position(|exterior| exterior.contains(&midpoint))
+        {
+            holes[index].push(interior);
+        }
+    }
+
+    exteriors
+        .into_iter()
+        .zip(holes)
+        .map(|(exterior, holes)| Polygon::new(exterior.into_inner().0, holes))
+        .collect()
+}
+
+/// Splits a ring which passes through a vertex more than once into simple rings, where the
+/// shape is pinched to the vertex.
+fn split_ring<T: SpadeTriangulationFloat>(
+    vertices: Vec<VertexHandle<Point2<T>>>,
+) -> Vec<LineString<T>> {
+    let to_coord = |vertex: VertexHandle<Point2<T>>| {
+        let position = vertex.position();
+        Coord {
+            x: position.x,
+            y: position.y,
+        }
+    };
+    let mut rings = Vec::new();
+    let mut stack: Vec<VertexHandle<Point2<T>>> = Vec::new();
+    let mut positions = HashMap::new();
+    for vertex in vertices {
+        if let Some(position) = positions.get(&vertex.fix()) {
+            let ring: Vec<_> = stack.drain(*position..).collect();
+            for vertex in &ring {
+                positions.remove(&vertex.fix());
+            }
+            rings.push(ring.into_iter().map(to_coord).collect());
+        }
+        positions.insert(vertex.fix(), stack.len());
+        stack.push(vertex);
+    }
+    rings.push(stack.into_iter().map(to_coord).collect());
+    rings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point, wkt, ConvexHull, Intersects, MultiPoint, Validation, Winding};
+
+    fn grid(width: usize, height: usize) -> Vec<Point> {
+        (0..width)
+            .flat_map(|x| (0..height).map(move |y| point!(x: x as f64, y: y as f64)))
+            .collect()
+    }
+
+    #[test]
+    fn infinite_alpha_is_convex_hull() {
+        let points: MultiPoint = geo_test_fixtures::norway_main::<f64>()
+            .0
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        let shape = points.alpha_shape(f64::INFINITY);
+        assert_eq!(shape.0.len(), 1);
+        assert!(shape.0[0].interiors().is_empty());
+        approx::assert_relative_eq!(
+            shape.unsigned_area(),
+            points.convex_hull().unsigned_area(),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn shrinking_alpha() {
+        let points: MultiPoint = geo_test_fixtures::norway_main::<f64>()
+            .0
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        let mut previous_area = f64::INFINITY;
+        for alpha in [10.0, 1.0, 0.5, 0.2] {
+            let shape = points.alpha_shape(alpha);
+            assert!(shape.is_valid());
+            assert!(shape.unsigned_area() <= previous_area);
+            previous_area = shape.unsigned_area();
+        }
+        assert!(previous_area > 0.0);
+    }
+
+    #[test]
+    fn hole() {
+        // A square of points with an empty middle
+        let points: MultiPoint = grid(6, 6)
+            .into_iter()
+            .filter(|p| !(2.0..=3.0).contains(&p.x()) || !(2.0..=3.0).contains(&p.y()))
+            .collect();
+        let shape = points.alpha_shape(1.0);
+        assert_eq!(shape.0.len(), 1);
+        let polygon = &shape.0[0];
+        assert_eq!(polygon.interiors().len(), 1);
+        assert!(polygon.exterior().is_ccw());
+        assert!(polygon.interiors()[0].is_cw());
+        assert!(polygon.unsigned_area() >= 16.0);
+        assert!(polygon.unsigned_area() < 25.0);
+        assert!(!polygon.intersects(&point!(x: 2.5, y: 2.5)));
+
+        // A large alpha fills the hole
+        assert_eq!(points.alpha_shape(10.0).unsigned_area(), 25.0);
+    }
+
+    #[test]
+    fn island_in_hole() {
+        let mut points: MultiPoint = grid(10, 10)
+            .into_iter()
+            .filter(|p| !(2.0..=7.0).contains(&p.x()) || !(2.0..=7.0).contains(&p.y()))
+            .collect();
+        points.0.extend(
+            grid(2, 2)
+                .into_iter()
+                .map(|p| point!(x: p.x() + 4.0, y: p.y() + 4.0)),
+        );
+        let shape = points.alpha_shape(1.0);
+        assert_eq!(shape.0.len(), 2);
+        assert!(shape.is_valid());
+        let (island, outer) = if shape.0[0].unsigned_area() < shape.0[1].unsigned_area() {
+            (&shape.0[0], &shape.0[1])
+        } else {
+            (&shape.0[1], &shape.0[0])
+        };
+        assert_eq!(island.unsigned_area(), 1.0);
+        assert!(island.interiors().is_empty());
+        assert_eq!(outer.interiors().len(), 1);
+        assert!(outer.contains(&point!(x: 0.5, y: 0.5)));
+        assert!(!outer.intersects(&point!(x: 3.5, y: 3.5)));
+    }
+
+    #[test]
+    fn pinched() {
+        // Two triangles meeting at (2, 0), with wide triangles above and below
+        let points: MultiPoint = wkt!(MULTIPOINT(0. -1.,0. 1.,2. 0.,4. -1.,4. 1.));
+        let shape = points.alpha_shape(1.5);
+        assert_eq!(shape.0.len(), 2);
+        assert_eq!(shape.unsigned_area(), 4.0);
+        for polygon in &shape {
+            assert_eq!(polygon.exterior().0.len(), 4);
+            assert!(polygon.exterior().is_ccw());
+        }
+        assert_eq!(points.alpha_shape(2.5).unsigned_area(), 8.0);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: MultiPoint = wkt!(MULTIPOINT EMPTY);
+        assert!(empty.alpha_shape(1.0).0.is_empty());
+        let collinear: MultiPoint = wkt!(MULTIPOINT(0. 0.,1. 1.,2. 2.));
+        assert!(collinear.alpha_shape(f64::INFINITY).0.is_empty());
+        let points = MultiPoint::from(vec![(0., 0.), (1., 0.), (0., 1.), (f64::NAN, 0.)]);
+        assert_eq!(points.alpha_shape(1.0).unsigned_area(), 0.5);
+    }
+}
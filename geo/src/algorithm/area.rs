@@ -1,5 +1,5 @@
 use crate::geometry::*;
-use crate::{CoordFloat, CoordNum};
+use crate::{CoordFloat, CoordNum, GeometryCow};
 
 pub(crate) fn twice_signed_ring_area<T>(linestring: &LineString<T>) -> T
 where
@@ -29,14 +29,22 @@ where
     // line-string with T = u8)
     let shift = linestring.0[0];
 
-    let mut tmp = T::zero();
-    for line in linestring.lines() {
-        use crate::MapCoords;
-        let line = line.map_coords(|c| c - shift);
-        tmp = tmp + line.determinant();
+    #[cfg(feature = "simd")]
+    {
+        crate::lanes::twice_signed_ring_area(&linestring.0, shift)
     }
 
-    tmp
+    #[cfg(not(feature = "simd"))]
+    {
+        let mut tmp = T::zero();
+        for line in linestring.lines() {
+            use crate::MapCoords;
+            let line = line.map_coords(|c| c - shift);
+            tmp = tmp + line.determinant();
+        }
+
+        tmp
+    }
 }
 
 /// Signed and unsigned planar area of a geometry.
@@ -240,6 +248,16 @@ where
     }
 }
 
+impl<T> Area<T> for GeometryCow<'_, T>
+where
+    T: CoordFloat,
+{
+    crate::geometry_cow_delegate_impl! {
+        fn signed_area(&self) -> T;
+        fn unsigned_area(&self) -> T;
+    }
+}
+
 impl<T> Area<T> for GeometryCollection<T>
 where
     T: CoordFloat,
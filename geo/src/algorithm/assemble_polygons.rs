@@ -0,0 +1,202 @@
+use crate::area::get_linestring_area;
+use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::geometry::*;
+use crate::{GeoFloat, Winding};
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{Envelope, RTree, RTreeObject};
+
+/// Assemble polygons from closed rings which don't say which rings are shells and which are
+/// holes, or which shell each hole belongs to.
+///
+/// Formats such as shapefiles, and the output of some triangulation or contouring algorithms,
+/// only provide a bag of rings. Rings are nested by containment: a ring inside an even number of
+/// other rings is the exterior of a polygon, and a ring inside an odd number of rings is a hole of
+/// the smallest ring containing it. This allows islands within the holes of other polygons.
+///
+/// The winding order of the input rings is ignored, as it is often inconsistent. The exteriors of
+/// the returned polygons are wound counter-clockwise and their interiors clockwise. Polygons, and
+/// the interiors of each polygon, are in the order of the input rings.
+///
+/// The rings must not cross each other, though they may touch. Rings which aren't closed are
+/// closed, and rings with no area are discarded.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, assemble_polygons, LineString};
+///
+/// let rings: Vec<LineString> = vec![
+///     // an island inside the hole
+///     wkt!(LINESTRING(4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)),
+///     // the hole
+///     wkt!(LINESTRING(2. 2.,8. 2.,8. 8.,2. 8.,2. 2.)),
+///     // the exterior, wound clockwise
+///     wkt!(LINESTRING(0. 0.,0. 10.,10. 10.,10. 0.,0. 0.)),
+/// ];
+///
+/// assert_eq!(
+///     assemble_polygons(rings),
+///     wkt!(MULTIPOLYGON(
+///         ((4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)),
+///         ((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(2. 2.,2. 8.,8. 8.,8. 2.,2. 2.))
+///     ))
+/// );
+/// ```
+pub fn assemble_polygons<T: GeoFloat>(
+    rings: impl IntoIterator<Item = LineString<T>>,
+) -> MultiPolygon<T> {
+    let rings: Vec<_> = rings
+        .into_iter()
+        .filter_map(|mut ring| {
+            ring.close();
+            let area = get_linestring_area(&ring).abs();
+            (area > T::zero()).then_some((ring, area))
+        })
+        .collect();
+
+    // Any ring containing another has a larger area, so visiting the rings by decreasing area
+    // visits every ring after the rings containing it.
+    let mut by_area: Vec<usize> = (0..rings.len()).collect();
+    by_area.sort_by(|&a, &b| rings[b].1.total_cmp(&rings[a].1));
+    let mut rank = vec![0; rings.len()];
+    for (ring_rank, &ring_idx) in by_area.iter().enumerate() {
+        rank[ring_idx] = ring_rank;
+    }
+
+    let tree = RTree::bulk_load(
+        rings
+            .iter()
+            .enumerate()
+            .map(|(idx, (ring, _))| GeomWithData::new(Rectangle::from_aabb(ring.envelope()), idx))
+            .collect(),
+    );
+
+    // The rings containing a ring form a chain, so its parent is the smallest of them
+    let mut parent = vec![None; rings.len()];
+    let mut is_exterior = vec![true; rings.len()];
+    for &ring_idx in &by_area {
+        let ring = &rings[ring_idx].0;
+        let envelope = ring.envelope();
+        parent[ring_idx] = tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|candidate| candidate.data)
+            .filter(|&candidate_idx| {
+                rank[candidate_idx] < rank[ring_idx]
+                    && rings[candidate_idx]
+                        .0
+                        .envelope()
+                        .contains_envelope(&envelope)
+                    && ring_contains(&rings[candidate_idx].0, ring)
+            })
+            .max_by_key(|&candidate_idx| rank[candidate_idx]);
+        if let Some(parent_idx) = parent[ring_idx] {
+            is_exterior[ring_idx] = !is_exterior[parent_idx];
+        }
+    }
+
+    let mut interiors: Vec<Vec<usize>> = vec![Vec::new(); rings.len()];
+    for ring_idx in 0..rings.len() {
+        if let (false, Some(parent_idx)) = (is_exterior[ring_idx], parent[ring_idx]) {
+            interiors[parent_idx].push(ring_idx);
+        }
+    }
+
+    let mut rings: Vec<Option<LineString<T>>> =
+        rings.into_iter().map(|(ring, _)| Some(ring)).collect();
+    let polygons = (0..rings.len())
+        .filter(|&ring_idx| is_exterior[ring_idx])
+        .map(|ring_idx| {
+            let mut exterior = rings[ring_idx].take().expect("each ring is used once");
+            exterior.make_ccw_winding();
+            let interiors = interiors[ring_idx]
+                .iter()
+                .map(|&interior_idx| {
+                    let mut interior = rings[interior_idx].take().expect("each ring is used once");
+                    interior.make_cw_winding();
+                    interior
+                })
+                .collect();
+            Polygon::new(exterior, interiors)
+        })
+        .collect();
+    MultiPolygon::new(polygons)
+}
+
+/// Whether `inner` lies inside `outer`, given that the two rings don't cross.
+fn ring_contains<T: GeoFloat>(outer: &LineString<T>, inner: &LineString<T>) -> bool {
+    // The rings may touch, so the first vertex, or else edge midpoint, of `inner` which isn't on
+    // `outer` determines which side of `outer` all of `inner` lies on.
+    let vertices = inner.0.iter().copied();
+    let midpoints = inner
+        .lines()
+        .map(|line| (line.start + line.end) / (T::one() + T::one()));
+    for coord in vertices.chain(midpoints) {
+        match coord_pos_relative_to_ring(coord, outer) {
+            CoordPos::Inside => return true,
+            CoordPos::Outside => return false,
+            CoordPos::OnBoundary => {}
+        }
+    }
+    // The rings coincide
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Validation};
+
+    #[test]
+    fn nested_rings() {
+        let rings: Vec<LineString> = vec![
+            wkt!(LINESTRING(21. 1.,22. 1.,22. 2.,21. 2.,21. 1.)),
+            wkt!(LINESTRING(4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)),
+            wkt!(LINESTRING(0. 0.,10. 0.,10. 10.,0. 10.,0. 0.)),
+            wkt!(LINESTRING(20. 0.,20. 5.,25. 5.,25. 0.,20. 0.)),
+            wkt!(LINESTRING(2. 2.,8. 2.,8. 8.,2. 8.,2. 2.)),
+            wkt!(LINESTRING(4.5 4.5,5.5 4.5,5.5 5.5,4.5 5.5,4.5 4.5)),
+            // a second hole in the first square, touching the hole above at a vertex
+            wkt!(LINESTRING(8. 8.,9. 8.,9. 9.,8. 9.,8. 8.)),
+        ];
+
+        let multi_polygon = assemble_polygons(rings);
+        assert_eq!(
+            multi_polygon,
+            wkt!(MULTIPOLYGON(
+                ((4. 4.,6. 4.,6. 6.,4. 6.,4. 4.),(4.5 4.5,4.5 5.5,5.5 5.5,5.5 4.5,4.5 4.5)),
+                ((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(2. 2.,2. 8.,8. 8.,8. 2.,2. 2.),(8. 8.,8. 9.,9. 9.,9. 8.,8. 8.)),
+                ((20. 0.,25. 0.,25. 5.,20. 5.,20. 0.),(21. 1.,21. 2.,22. 2.,22. 1.,21. 1.))
+            ))
+        );
+        assert!(multi_polygon.is_valid());
+    }
+
+    #[test]
+    fn touching_rings() {
+        // the hole touches the exterior from the inside
+        let rings: Vec<LineString> = vec![
+            wkt!(LINESTRING(0. 0.,5. 5.,5. 0.,0. 0.)),
+            wkt!(LINESTRING(0. 0.,10. 0.,10. 10.,0. 10.,0. 0.)),
+            // a neighbour sharing an edge
+            wkt!(LINESTRING(10. 0.,20. 0.,20. 10.,10. 10.,10. 0.)),
+        ];
+        let multi_polygon = assemble_polygons(rings);
+        assert_eq!(multi_polygon.0.len(), 2);
+        assert_eq!(multi_polygon.0[0].interiors().len(), 1);
+        assert_eq!(multi_polygon.0[1].interiors().len(), 0);
+    }
+
+    #[test]
+    fn degenerate_rings() {
+        let rings: Vec<LineString> = vec![
+            wkt!(LINESTRING EMPTY),
+            wkt!(LINESTRING(0. 0.,1. 1.,0. 0.)),
+            // not closed
+            wkt!(LINESTRING(0. 0.,1. 0.,1. 1.)),
+        ];
+        assert_eq!(
+            assemble_polygons(rings),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.))))
+        );
+    }
+}
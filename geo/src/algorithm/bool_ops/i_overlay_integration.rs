@@ -1,5 +1,6 @@
 use crate::geometry::Coord;
 use crate::GeoNum;
+use i_overlay::float::source::resource::OverlayResource;
 use i_overlay::i_float::float::compatible::FloatPointCompatible;
 use i_overlay::i_float::float::number::FloatNumber;
 
@@ -9,9 +10,20 @@ impl<T: GeoNum + FloatNumber> BoolOpsNum for T {}
 
 /// New type for `Coord` that implements `FloatPointCompatible` for `BoolOpsNum` to
 /// circumvent orphan rule, since Coord is defined in geo_types.
+///
+/// It has the layout of `Coord`, so slices of coordinates can be passed to `i_overlay` without
+/// copying them, and the paths it returns converted in place.
 #[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
 pub struct BoolOpsCoord<T: BoolOpsNum>(pub(crate) Coord<T>);
 
+impl<T: BoolOpsNum> BoolOpsCoord<T> {
+    pub(crate) fn slice_from_coords(coords: &[Coord<T>]) -> &[Self] {
+        // SAFETY: `BoolOpsCoord` is a `repr(transparent)` wrapper of `Coord`.
+        unsafe { std::slice::from_raw_parts(coords.as_ptr().cast(), coords.len()) }
+    }
+}
+
 impl<T: BoolOpsNum> FloatPointCompatible<T> for BoolOpsCoord<T> {
     fn from_xy(x: T, y: T) -> Self {
         Self(Coord { x, y })
@@ -26,6 +38,27 @@ impl<T: BoolOpsNum> FloatPointCompatible<T> for BoolOpsCoord<T> {
     }
 }
 
+/// Paths borrowing the coordinates of rings or line strings, to pass them to `i_overlay` without
+/// allocating and copying each into a path of its own.
+pub(super) struct BorrowedPaths<'a, T: BoolOpsNum>(pub(super) Vec<&'a [BoolOpsCoord<T>]>);
+
+impl<'a, T: BoolOpsNum> OverlayResource<BoolOpsCoord<T>, T> for BorrowedPaths<'a, T> {
+    type ResourceIter<'b>
+        = std::iter::Copied<std::slice::Iter<'b, &'b [BoolOpsCoord<T>]>>
+    where
+        Self: 'b;
+
+    fn iter_paths(&self) -> Self::ResourceIter<'_> {
+        self.0.iter().copied()
+    }
+}
+
+impl<'a, T: BoolOpsNum> FromIterator<&'a [BoolOpsCoord<T>]> for BorrowedPaths<'a, T> {
+    fn from_iter<I: IntoIterator<Item = &'a [BoolOpsCoord<T>]>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 pub(super) mod convert {
     use super::super::OpType;
     use super::BoolOpsNum;
@@ -58,14 +91,14 @@ pub(super) mod convert {
         MultiPolygon(polygons.collect())
     }
 
-    pub fn ring_to_shape_path<T: BoolOpsNum>(line_string: &LineString<T>) -> Vec<BoolOpsCoord<T>> {
+    pub fn ring_to_shape_path<T: BoolOpsNum>(line_string: &LineString<T>) -> &[BoolOpsCoord<T>] {
         if line_string.0.is_empty() {
-            return vec![];
+            return &[];
         }
         // In geo, Polygon rings are explicitly closed LineStrings — their final coordinate is the same as their first coordinate,
         // however in i_overlay, shape paths are implicitly closed, so we skip the last coordinate.
         let coords = &line_string.0[..line_string.0.len() - 1];
-        coords.iter().copied().map(BoolOpsCoord).collect()
+        BoolOpsCoord::slice_from_coords(coords)
     }
 
     impl From<OpType> for OverlayRule {
@@ -3,12 +3,16 @@ mod i_overlay_integration;
 mod tests;
 
 use i_overlay_integration::convert::{multi_polygon_from_shapes, ring_to_shape_path};
-use i_overlay_integration::BoolOpsCoord;
 pub use i_overlay_integration::BoolOpsNum;
+use i_overlay_integration::{BoolOpsCoord, BorrowedPaths};
 
+use crate::cancellation::{CancellationToken, Cancelled};
 use crate::geometry::{LineString, MultiLineString, MultiPolygon, Polygon};
 use crate::winding_order::{Winding, WindingOrder};
 
+use std::collections::HashMap;
+use std::hash::Hash;
+
 use i_overlay::core::fill_rule::FillRule;
 use i_overlay::core::overlay_rule::OverlayRule;
 use i_overlay::float::clip::FloatClip;
@@ -58,12 +62,43 @@ pub trait BooleanOps {
         other: &impl BooleanOps<Scalar = Self::Scalar>,
         op: OpType,
     ) -> MultiPolygon<Self::Scalar> {
-        let subject = self.rings().map(ring_to_shape_path).collect::<Vec<_>>();
-        let clip = other.rings().map(ring_to_shape_path).collect::<Vec<_>>();
+        let subject = self
+            .rings()
+            .map(ring_to_shape_path)
+            .collect::<BorrowedPaths<_>>();
+        let clip = other
+            .rings()
+            .map(ring_to_shape_path)
+            .collect::<BorrowedPaths<_>>();
         let shapes = subject.overlay(&clip, op.into(), FillRule::EvenOdd);
         multi_polygon_from_shapes(shapes)
     }
 
+    /// Like [`boolean_op`](Self::boolean_op), but returns [`Cancelled`] if `token` is set.
+    ///
+    /// The overlay itself can't be interrupted, so `token` is checked while preparing the
+    /// input and the output.
+    fn boolean_op_cancellable(
+        &self,
+        other: &impl BooleanOps<Scalar = Self::Scalar>,
+        op: OpType,
+        token: &impl CancellationToken,
+    ) -> Result<MultiPolygon<Self::Scalar>, Cancelled> {
+        token.check_cancelled()?;
+        let subject = self
+            .rings()
+            .map(ring_to_shape_path)
+            .collect::<BorrowedPaths<_>>();
+        let clip = other
+            .rings()
+            .map(ring_to_shape_path)
+            .collect::<BorrowedPaths<_>>();
+        token.check_cancelled()?;
+        let shapes = subject.overlay(&clip, op.into(), FillRule::EvenOdd);
+        token.check_cancelled()?;
+        Ok(multi_polygon_from_shapes(shapes))
+    }
+
     /// Returns the overlapping regions shared by both `self` and `other`.
     fn intersection(
         &self,
@@ -83,6 +118,17 @@ pub trait BooleanOps {
         self.boolean_op(other, OpType::Xor)
     }
 
+    /// The regions that are in either `self` or `other`, but not in both.
+    ///
+    /// This is the same as [`xor`](Self::xor), under its name in the OGC Simple Features
+    /// specification.
+    fn symmetric_difference(
+        &self,
+        other: &impl BooleanOps<Scalar = Self::Scalar>,
+    ) -> MultiPolygon<Self::Scalar> {
+        self.xor(other)
+    }
+
     /// The regions of `self` which are not in `other`.
     fn difference(
         &self,
@@ -100,22 +146,80 @@ pub trait BooleanOps {
         multi_line_string: &MultiLineString<Self::Scalar>,
         invert: bool,
     ) -> MultiLineString<Self::Scalar> {
-        let subject: Vec<Vec<_>> = multi_line_string
-            .iter()
-            .map(|line_string| line_string.coords().map(|c| BoolOpsCoord(*c)).collect())
-            .collect();
+        let clip = self
+            .rings()
+            .map(ring_to_shape_path)
+            .collect::<BorrowedPaths<_>>();
+        clip_line_strings(&clip, &multi_line_string.0, invert)
+    }
 
-        let clip = self.rings().map(ring_to_shape_path).collect::<Vec<_>>();
+    /// Like [`clip`](Self::clip), but calls `progress` with the number of line strings clipped
+    /// so far and the total number of line strings.
+    ///
+    /// The line strings are clipped in batches, with `progress` called after each batch. Since
+    /// overlapping pieces are only merged within a batch, the output may differ from `clip`
+    /// where line strings of the input overlap each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, wkt, BooleanOps, MultiLineString};
+    ///
+    /// let square = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+    /// let lines: MultiLineString = (0..1000)
+    ///     .map(|i| {
+    ///         let y = i as f64 / 1000.;
+    ///         line_string![(x: 2., y: y), (x: 6., y: y)]
+    ///     })
+    ///     .collect();
+    ///
+    /// let mut reported = vec![];
+    /// let clipped = square.clip_with_progress(&lines, false, |done, total| {
+    ///     reported.push((done, total))
+    /// });
+    /// assert_eq!(clipped.0.len(), 1000);
+    /// assert_eq!(reported, vec![(256, 1000), (512, 1000), (768, 1000), (1000, 1000)]);
+    /// ```
+    fn clip_with_progress(
+        &self,
+        multi_line_string: &MultiLineString<Self::Scalar>,
+        invert: bool,
+        mut progress: impl FnMut(usize, usize),
+    ) -> MultiLineString<Self::Scalar> {
+        const BATCH_SIZE: usize = 256;
 
-        let clip_rule = ClipRule {
-            invert,
-            boundary_included: true,
-        };
-        let paths = subject.clip_by(&clip, FillRule::EvenOdd, clip_rule);
-        i_overlay_integration::convert::multi_line_string_from_paths(paths)
+        let clip = self
+            .rings()
+            .map(ring_to_shape_path)
+            .collect::<BorrowedPaths<_>>();
+        let total = multi_line_string.0.len();
+        let mut clipped = Vec::new();
+        for (i, batch) in multi_line_string.0.chunks(BATCH_SIZE).enumerate() {
+            clipped.extend(clip_line_strings(&clip, batch, invert));
+            progress((BATCH_SIZE * (i + 1)).min(total), total);
+        }
+        MultiLineString::new(clipped)
     }
 }
 
+fn clip_line_strings<T: BoolOpsNum>(
+    clip: &BorrowedPaths<T>,
+    line_strings: &[LineString<T>],
+    invert: bool,
+) -> MultiLineString<T> {
+    let subject: BorrowedPaths<_> = line_strings
+        .iter()
+        .map(|line_string| BoolOpsCoord::slice_from_coords(&line_string.0))
+        .collect();
+
+    let clip_rule = ClipRule {
+        invert,
+        boundary_included: true,
+    };
+    let paths = subject.clip_by(clip, FillRule::EvenOdd, clip_rule);
+    i_overlay_integration::convert::multi_line_string_from_paths(paths)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum OpType {
     Intersection,
@@ -168,20 +272,15 @@ pub fn unary_union<'a, B: BooleanOps + 'a>(
     boppables: impl IntoIterator<Item = &'a B>,
 ) -> MultiPolygon<B::Scalar> {
     let mut winding_order: Option<WindingOrder> = None;
-    let subject = boppables
-        .into_iter()
-        .flat_map(|boppable| {
-            let rings = boppable.rings();
-            rings
-                .map(|ring| {
-                    if winding_order.is_none() {
-                        winding_order = ring.winding_order();
-                    }
-                    ring_to_shape_path(ring)
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+    let mut subject = BorrowedPaths(Vec::new());
+    for boppable in boppables {
+        for ring in boppable.rings() {
+            if winding_order.is_none() {
+                winding_order = ring.winding_order();
+            }
+            subject.0.push(ring_to_shape_path(ring));
+        }
+    }
 
     let fill_rule = if winding_order == Some(WindingOrder::Clockwise) {
         FillRule::Positive
@@ -193,6 +292,289 @@ pub fn unary_union<'a, B: BooleanOps + 'a>(
     multi_polygon_from_shapes(shapes)
 }
 
+/// Like [`unary_union`], but returns [`Cancelled`] if `token` is set.
+///
+/// Rather than unioning all the inputs at once, they are unioned in batches, and the partial
+/// unions are then merged pairwise. `token` is checked before each of these steps, so even a
+/// union of a huge number of geometries can be aborted promptly.
+///
+/// Because of the batching, the output is topologically equal to that of [`unary_union`], but
+/// may be noded differently, or have its polygons and vertices in a different order.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{unary_union_cancellable, wkt};
+/// use std::sync::atomic::AtomicBool;
+///
+/// let polygons = vec![
+///     wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))),
+///     wkt!(POLYGON((4. 0.,8. 0.,8. 4.,4. 4.,4. 0.))),
+/// ];
+/// let cancel = AtomicBool::new(false);
+/// let union = unary_union_cancellable(&polygons, &cancel).unwrap();
+/// assert_eq!(union, wkt!(MULTIPOLYGON(((0. 0.,0. 4.,8. 4.,8. 0.,0. 0.)))));
+/// ```
+pub fn unary_union_cancellable<'a, B: BooleanOps + 'a>(
+    boppables: impl IntoIterator<Item = &'a B>,
+    token: &impl CancellationToken,
+) -> Result<MultiPolygon<B::Scalar>, Cancelled> {
+    unary_union_batched(boppables, token, |_, _| {})
+}
+
+/// Like [`unary_union`], but calls `progress` with the number of steps completed so far and
+/// the total number of steps.
+///
+/// As in [`unary_union_cancellable`], the inputs are unioned in batches and the partial unions
+/// then merged pairwise, each of which counts as one step.
+///
+/// So the output is identical to that of [`unary_union_cancellable`], and only topologically
+/// equal to that of [`unary_union`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{unary_union_with_progress, wkt};
+///
+/// let polygons = vec![wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))); 100];
+/// let mut reported = vec![];
+/// let union = unary_union_with_progress(&polygons, |done, total| reported.push((done, total)));
+/// assert_eq!(union, wkt!(MULTIPOLYGON(((0. 0.,0. 4.,4. 4.,4. 0.,0. 0.)))));
+///
+/// // two batches, then one merge
+/// assert_eq!(reported, vec![(1, 3), (2, 3), (3, 3)]);
+/// ```
+pub fn unary_union_with_progress<'a, B: BooleanOps + 'a>(
+    boppables: impl IntoIterator<Item = &'a B>,
+    progress: impl FnMut(usize, usize),
+) -> MultiPolygon<B::Scalar> {
+    unary_union_batched(boppables, &|| false, progress).expect("never cancelled")
+}
+
+/// Like [`unary_union`], but unions batches of the inputs, and merges the partial unions, in
+/// parallel.
+///
+/// The batches, and the tree in which they are merged, depend only on the number of inputs, not
+/// on the number of threads or how the work is scheduled. So the output is reproducible
+/// (identical to that of [`unary_union_cancellable`]) on any machine.
+///
+/// As with [`unary_union_cancellable`], it is only topologically equal to the output of
+/// [`unary_union`], which may be noded differently, or have its polygons and vertices in a
+/// different order.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{unary_union_parallel, wkt};
+///
+/// let polygons = vec![
+///     wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))),
+///     wkt!(POLYGON((4. 0.,8. 0.,8. 4.,4. 4.,4. 0.))),
+/// ];
+/// let union = unary_union_parallel(&polygons);
+/// assert_eq!(union, wkt!(MULTIPOLYGON(((0. 0.,0. 4.,8. 4.,8. 0.,0. 0.)))));
+/// ```
+#[cfg(feature = "multithreading")]
+pub fn unary_union_parallel<'a, B: BooleanOps + Sync + 'a>(
+    boppables: impl IntoIterator<Item = &'a B>,
+) -> MultiPolygon<B::Scalar>
+where
+    B::Scalar: Send + Sync,
+{
+    use rayon::prelude::*;
+
+    let boppables = boppables.into_iter().collect::<Vec<_>>();
+    let mut unions = boppables
+        .par_chunks(UNARY_UNION_BATCH_SIZE)
+        .map(|batch| unary_union(batch.iter().copied()))
+        .collect::<Vec<_>>();
+
+    // Merge in the same fixed tree as `unary_union_batched`
+    while unions.len() > 1 {
+        unions = unions.par_chunks(2).map(unary_union).collect();
+    }
+    unions.pop().unwrap_or_else(|| MultiPolygon::new(vec![]))
+}
+
+/// Union the polygons sharing each key, the GIS "dissolve" operation.
+///
+/// Each key maps to the [`unary_union`] of the polygons given with it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{dissolve_by_key, wkt};
+///
+/// let parcels = vec![
+///     ("north", wkt!(POLYGON((0. 4.,4. 4.,4. 8.,0. 8.,0. 4.)))),
+///     ("south", wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)))),
+///     ("north", wkt!(POLYGON((4. 4.,8. 4.,8. 8.,4. 8.,4. 4.)))),
+/// ];
+/// let districts = dissolve_by_key(parcels);
+/// assert_eq!(districts.len(), 2);
+/// assert_eq!(
+///     districts["north"],
+///     wkt!(MULTIPOLYGON(((0. 4.,0. 8.,8. 8.,8. 4.,0. 4.))))
+/// );
+/// ```
+pub fn dissolve_by_key<K: Eq + Hash, T: BoolOpsNum>(
+    polygons: impl IntoIterator<Item = (K, Polygon<T>)>,
+) -> HashMap<K, MultiPolygon<T>> {
+    group_by_key(polygons)
+        .into_iter()
+        .map(|(key, group)| (key, unary_union(&group)))
+        .collect()
+}
+
+/// Like [`dissolve_by_key`], but unions the groups of polygons in parallel.
+///
+/// Each group is unioned with [`unary_union_parallel`], so a few large groups are split up too.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{dissolve_by_key_parallel, wkt};
+///
+/// let parcels = vec![
+///     (1, wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)))),
+///     (1, wkt!(POLYGON((4. 0.,8. 0.,8. 4.,4. 4.,4. 0.)))),
+/// ];
+/// let districts = dissolve_by_key_parallel(parcels);
+/// assert_eq!(
+///     districts[&1],
+///     wkt!(MULTIPOLYGON(((0. 0.,0. 4.,8. 4.,8. 0.,0. 0.))))
+/// );
+/// ```
+#[cfg(feature = "multithreading")]
+pub fn dissolve_by_key_parallel<K: Eq + Hash + Send, T: BoolOpsNum + Send + Sync>(
+    polygons: impl IntoIterator<Item = (K, Polygon<T>)>,
+) -> HashMap<K, MultiPolygon<T>> {
+    use rayon::prelude::*;
+
+    group_by_key(polygons)
+        .into_par_iter()
+        .map(|(key, group)| (key, unary_union_parallel(&group)))
+        .collect()
+}
+
+fn group_by_key<K: Eq + Hash, T: BoolOpsNum>(
+    polygons: impl IntoIterator<Item = (K, Polygon<T>)>,
+) -> HashMap<K, Vec<Polygon<T>>> {
+    let mut groups: HashMap<K, Vec<Polygon<T>>> = HashMap::new();
+    for (key, polygon) in polygons {
+        groups.entry(key).or_default().push(polygon);
+    }
+    groups
+}
+
+/// A face of a [`union_overlay`]: a polygon, and the layers which cover it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayFace<T: BoolOpsNum> {
+    pub polygon: Polygon<T>,
+    /// The indices of the input layers covering `polygon`, in increasing order.
+    pub layers: Vec<usize>,
+}
+
+/// Overlay many polygon layers, splitting their union into the faces of the arrangement, each
+/// with the layers covering it.
+///
+/// This is the "union" or "identity" overlay of GIS packages: every point of the union of the
+/// layers is in exactly one face, and every point of a face is covered by the same layers. So
+/// e.g. the faces covered by both layers `0` and `2` make up the intersection of those layers.
+///
+/// The polygons of each layer must not overlap each other, as for the other [`BooleanOps`].
+/// The output is ordered by the layers of the faces.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{union_overlay, wkt, Area};
+///
+/// let zoning = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+/// let flood_zone = wkt!(POLYGON((2. 0.,6. 0.,6. 4.,2. 4.,2. 0.)));
+///
+/// let faces = union_overlay(&[zoning, flood_zone]);
+/// let layers: Vec<_> = faces.iter().map(|face| face.layers.clone()).collect();
+/// assert_eq!(layers, vec![vec![0], vec![0, 1], vec![1]]);
+/// assert_eq!(faces[1].polygon.unsigned_area(), 8.);
+/// ```
+pub fn union_overlay<'a, B: BooleanOps + 'a>(
+    layers: impl IntoIterator<Item = &'a B>,
+) -> Vec<OverlayFace<B::Scalar>> {
+    // The regions covered by exactly each set of layers, and the union of the layers so far
+    let mut regions: Vec<(MultiPolygon<B::Scalar>, Vec<usize>)> = vec![];
+    let mut covered = MultiPolygon::new(vec![]);
+    for (index, layer) in layers.into_iter().enumerate() {
+        let mut split = Vec::with_capacity(2 * regions.len() + 1);
+        for (region, mut region_layers) in regions {
+            let outside = region.difference(layer);
+            if !outside.0.is_empty() {
+                split.push((outside, region_layers.clone()));
+            }
+            let inside = region.intersection(layer);
+            if !inside.0.is_empty() {
+                region_layers.push(index);
+                split.push((inside, region_layers));
+            }
+        }
+        let uncovered = layer.difference(&covered);
+        if !uncovered.0.is_empty() {
+            split.push((uncovered, vec![index]));
+        }
+        covered = covered.union(layer);
+        regions = split;
+    }
+
+    regions.sort_by(|(_, a), (_, b)| a.cmp(b));
+    regions
+        .into_iter()
+        .flat_map(|(region, layers)| {
+            region.0.into_iter().map(move |polygon| OverlayFace {
+                polygon,
+                layers: layers.clone(),
+            })
+        })
+        .collect()
+}
+
+/// The number of inputs unioned at once, before the partial unions are merged pairwise.
+const UNARY_UNION_BATCH_SIZE: usize = 64;
+
+fn unary_union_batched<'a, B: BooleanOps + 'a>(
+    boppables: impl IntoIterator<Item = &'a B>,
+    token: &impl CancellationToken,
+    mut progress: impl FnMut(usize, usize),
+) -> Result<MultiPolygon<B::Scalar>, Cancelled> {
+    let boppables = boppables.into_iter().collect::<Vec<_>>();
+    let batch_count = boppables.len().div_ceil(UNARY_UNION_BATCH_SIZE);
+    // Merging n partial unions pairwise takes n - 1 steps
+    let total_steps = (2 * batch_count).saturating_sub(1);
+    let mut completed_steps = 0;
+
+    let mut unions = Vec::with_capacity(batch_count);
+    for batch in boppables.chunks(UNARY_UNION_BATCH_SIZE) {
+        token.check_cancelled()?;
+        unions.push(unary_union(batch.iter().copied()));
+        completed_steps += 1;
+        progress(completed_steps, total_steps);
+    }
+
+    while unions.len() > 1 {
+        let mut merged = Vec::with_capacity(unions.len().div_ceil(2));
+        for pair in unions.chunks(2) {
+            token.check_cancelled()?;
+            merged.push(unary_union(pair));
+            if pair.len() == 2 {
+                completed_steps += 1;
+                progress(completed_steps, total_steps);
+            }
+        }
+        unions = merged;
+    }
+    token.check_cancelled()?;
+    Ok(unions.pop().unwrap_or_else(|| MultiPolygon::new(vec![])))
+}
+
 impl<T: BoolOpsNum> BooleanOps for Polygon<T> {
     type Scalar = T;
 
@@ -1,5 +1,9 @@
-use super::{unary_union, BooleanOps};
+use super::{
+    dissolve_by_key, unary_union, unary_union_cancellable, unary_union_with_progress,
+    union_overlay, BooleanOps, OpType,
+};
 use crate::{wkt, Convert, MultiPolygon, Polygon, Relate};
+use crate::{Area, Cancelled, Translate};
 use std::time::Instant;
 use wkt::ToWkt;
 
@@ -20,6 +24,80 @@ fn test_unary_union() {
     assert_eq!(multi_poly_union.0.len(), 1);
 }
 
+#[test]
+fn test_dissolve_by_key() {
+    let input: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
+    // key the plots by the parity of their index
+    let keyed = input
+        .iter()
+        .enumerate()
+        .map(|(i, polygon)| (i % 2 == 0, polygon.clone()));
+    let dissolved = dissolve_by_key(keyed);
+    assert_eq!(dissolved.len(), 2);
+
+    let evens: Vec<Polygon> = input.iter().step_by(2).cloned().collect();
+    assert_eq!(dissolved[&true], unary_union(&evens));
+    assert_relative_eq!(
+        dissolved[&true].unsigned_area() + dissolved[&false].unsigned_area(),
+        input.unsigned_area(),
+        max_relative = 1e-6
+    );
+
+    #[cfg(feature = "multithreading")]
+    {
+        let keyed = input
+            .iter()
+            .enumerate()
+            .map(|(i, polygon)| (i % 2 == 0, polygon.clone()));
+        let parallel = super::dissolve_by_key_parallel(keyed);
+        assert_relative_eq!(
+            parallel[&true].unsigned_area(),
+            dissolved[&true].unsigned_area(),
+            max_relative = 1e-6
+        );
+    }
+
+    assert!(dissolve_by_key(Vec::<(u8, Polygon)>::new()).is_empty());
+}
+
+#[test]
+fn test_union_overlay() {
+    let a = wkt!(MULTIPOLYGON(((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))));
+    let b = wkt!(MULTIPOLYGON(((2. 2.,6. 2.,6. 6.,2. 6.,2. 2.))));
+    // overlapping both a and b, and with a separate part
+    let c = wkt!(MULTIPOLYGON(
+        ((3. 0.,5. 0.,5. 5.,3. 5.,3. 0.)),
+        ((10. 10.,11. 10.,11. 11.,10. 11.,10. 10.))
+    ));
+    let layers = [a.clone(), b.clone(), c.clone()];
+    let faces = union_overlay(&layers);
+
+    let area_of = |covering: &[usize]| -> f64 {
+        faces
+            .iter()
+            .filter(|face| face.layers == covering)
+            .map(|face| face.polygon.unsigned_area())
+            .sum()
+    };
+    assert_relative_eq!(area_of(&[0]), 16. - 4. - 4. + 2.);
+    assert_relative_eq!(area_of(&[1]), 16. - 4. - 6. + 2.);
+    assert_relative_eq!(area_of(&[2]), 11. - 4. - 6. + 2.);
+    assert_relative_eq!(area_of(&[0, 1]), 4. - 2.);
+    assert_relative_eq!(area_of(&[0, 2]), 4. - 2.);
+    assert_relative_eq!(area_of(&[1, 2]), 6. - 2.);
+    assert_relative_eq!(area_of(&[0, 1, 2]), 2.);
+
+    // the faces tile the union, without overlaps
+    let total: f64 = faces.iter().map(|face| face.polygon.unsigned_area()).sum();
+    assert_relative_eq!(total, unary_union(&layers).unsigned_area());
+    assert!(faces
+        .windows(2)
+        .all(|pair| pair[0].layers <= pair[1].layers));
+
+    assert!(union_overlay(&Vec::<Polygon>::new()).is_empty());
+    assert_eq!(a.symmetric_difference(&b), a.xor(&b));
+}
+
 #[test]
 fn test_unary_union_errors() {
     let input: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
@@ -89,6 +167,110 @@ fn test_unary_union_winding() {
     assert_eq!(default_winding_union, reversed_winding_union);
 }
 
+#[test]
+fn test_unary_union_cancellable() {
+    let input: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
+    let input_area = input.signed_area();
+
+    let union = unary_union_cancellable(input.iter(), &|| false).unwrap();
+    assert_eq!(union.0.len(), 1);
+    assert_relative_eq!(union.unsigned_area(), input_area, max_relative = 1e-5);
+
+    // cancel part way through
+    let checks = std::cell::Cell::new(0);
+    let cancel_after_three_checks = || {
+        checks.set(checks.get() + 1);
+        checks.get() > 3
+    };
+    assert_eq!(
+        unary_union_cancellable(input.iter(), &cancel_after_three_checks),
+        Err(Cancelled)
+    );
+    assert_eq!(checks.get(), 4);
+
+    let empty: Vec<Polygon> = vec![];
+    assert_eq!(
+        unary_union_cancellable(&empty, &|| false),
+        Ok(MultiPolygon::new(vec![]))
+    );
+}
+
+#[test]
+fn test_unary_union_with_progress() {
+    let input: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
+
+    let mut reported = vec![];
+    let union = unary_union_with_progress(input.iter(), |done, total| reported.push((done, total)));
+    assert_eq!(
+        union,
+        unary_union_cancellable(input.iter(), &|| false).unwrap()
+    );
+    // 316 polygons make 5 batches, which take 4 merges
+    assert_eq!(reported, (1..=9).map(|done| (done, 9)).collect::<Vec<_>>());
+
+    let empty: Vec<Polygon> = vec![];
+    let union = unary_union_with_progress(&empty, |_, _| panic!("nothing to report"));
+    assert_eq!(union, MultiPolygon::new(vec![]));
+}
+
+#[test]
+fn test_batched_unary_unions_are_topologically_equal() {
+    // more overlapping squares than fit in one batch, in several separate clusters
+    let squares: Vec<Polygon> = (0..200)
+        .map(|i| {
+            let cluster = f64::from(i / 50) * 100.;
+            let (x, y) = (
+                cluster + f64::from(i % 10) * 0.75,
+                f64::from(i % 50 / 10) * 0.6,
+            );
+            wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.))).translate(x, y)
+        })
+        .collect();
+    let expected = unary_union(&squares);
+    assert_eq!(expected.0.len(), 4);
+
+    let batched = unary_union_cancellable(&squares, &|| false).unwrap();
+    assert!(batched.relate(&expected).is_equal_topo());
+    let batched = unary_union_with_progress(&squares, |_, _| {});
+    assert!(batched.relate(&expected).is_equal_topo());
+    #[cfg(feature = "multithreading")]
+    {
+        let batched = super::unary_union_parallel(&squares);
+        assert!(batched.relate(&expected).is_equal_topo());
+    }
+}
+
+#[cfg(feature = "multithreading")]
+#[test]
+fn test_unary_union_parallel_is_deterministic() {
+    use super::unary_union_parallel;
+
+    let input: MultiPolygon = geo_test_fixtures::nl_plots_epsg_28992();
+    let expected = unary_union_cancellable(input.iter(), &|| false).unwrap();
+    for num_threads in [1, 2, 7] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .unwrap();
+        let union = pool.install(|| unary_union_parallel(input.iter()));
+        assert_eq!(union, expected, "{num_threads} threads");
+    }
+}
+
+#[test]
+fn test_boolean_op_cancellable() {
+    let a: Polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+    let b: Polygon = wkt!(POLYGON((2. 2.,6. 2.,6. 6.,2. 6.,2. 2.)));
+    assert_eq!(
+        a.boolean_op_cancellable(&b, OpType::Intersection, &|| false),
+        Ok(a.intersection(&b))
+    );
+    assert_eq!(
+        a.boolean_op_cancellable(&b, OpType::Intersection, &|| true),
+        Err(Cancelled)
+    );
+}
+
 #[test]
 fn jts_overlay_tests() {
     jts_test_runner::assert_jts_tests_succeed("*Overlay*.xml");
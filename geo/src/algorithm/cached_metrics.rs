@@ -0,0 +1,214 @@
+use std::any::Any;
+use std::cell::{OnceCell, RefCell};
+use std::fmt;
+
+use crate::line_measures::{Distance, Length};
+use crate::{Area, BoundingRect, Centroid, CoordFloat, Point, Rect};
+
+/// A geometry which computes its area, length, bounding rectangle and centroid only once.
+///
+/// Each metric is computed the first time it's requested, and then reused until the geometry is
+/// mutated through [`geometry_mut`](Self::geometry_mut), which clears every cached metric. This
+/// avoids walking the coordinates of a large geometry again every time a metric is needed, e.g.
+/// by several stages of a pipeline.
+///
+/// Lengths are cached separately for each [metric space](crate::line_measures::metric_spaces),
+/// so that e.g. the [`Euclidean`](crate::Euclidean) and [`Haversine`](crate::Haversine) lengths
+/// of a geometry can both be cached. Metric spaces with parameters, such as
+/// [`HaversineMeasure`](crate::HaversineMeasure)s of different radii, are cached separately too.
+///
+/// The cache uses interior mutability, so a `CachedMetrics` can be sent to another thread, but
+/// not shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, CachedMetrics, Euclidean, Haversine, LineString};
+///
+/// let mut line_string: CachedMetrics<LineString> = CachedMetrics::new(wkt!(LINESTRING(0. 0.,3. 4.,3. 5.)));
+/// assert_eq!(line_string.length(&Euclidean), 6.);
+/// assert_eq!(line_string.length(&Haversine).round(), 667_008.);
+///
+/// // mutating the geometry clears the cached metrics
+/// line_string.geometry_mut().0.pop();
+/// assert_eq!(line_string.length(&Euclidean), 5.);
+/// ```
+pub struct CachedMetrics<G, T: CoordFloat = f64> {
+    geometry: G,
+    signed_area: OnceCell<T>,
+    unsigned_area: OnceCell<T>,
+    bounding_rect: OnceCell<Option<Rect<T>>>,
+    centroid: OnceCell<Option<Point<T>>>,
+    // The length in each metric space, which is compared by value
+    lengths: RefCell<Vec<(Box<dyn Any + Send>, T)>>,
+}
+
+impl<G, T: CoordFloat> CachedMetrics<G, T> {
+    pub fn new(geometry: G) -> Self {
+        Self {
+            geometry,
+            signed_area: OnceCell::new(),
+            unsigned_area: OnceCell::new(),
+            bounding_rect: OnceCell::new(),
+            centroid: OnceCell::new(),
+            lengths: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// The wrapped geometry.
+    pub fn geometry(&self) -> &G {
+        &self.geometry
+    }
+
+    /// The wrapped geometry, for mutation. This clears every cached metric.
+    pub fn geometry_mut(&mut self) -> &mut G {
+        self.signed_area = OnceCell::new();
+        self.unsigned_area = OnceCell::new();
+        self.bounding_rect = OnceCell::new();
+        self.centroid = OnceCell::new();
+        self.lengths.get_mut().clear();
+        &mut self.geometry
+    }
+
+    /// Unwrap the geometry, discarding the cached metrics.
+    pub fn into_inner(self) -> G {
+        self.geometry
+    }
+
+    /// The [signed area](Area::signed_area) of the geometry.
+    pub fn signed_area(&self) -> T
+    where
+        G: Area<T>,
+    {
+        *self.signed_area.get_or_init(|| self.geometry.signed_area())
+    }
+
+    /// The [unsigned area](Area::unsigned_area) of the geometry.
+    pub fn unsigned_area(&self) -> T
+    where
+        G: Area<T>,
+    {
+        *self
+            .unsigned_area
+            .get_or_init(|| self.geometry.unsigned_area())
+    }
+
+    /// The [bounding rectangle](BoundingRect) of the geometry, or `None` if it's empty.
+    pub fn bounding_rect(&self) -> Option<Rect<T>>
+    where
+        G: BoundingRect<T>,
+    {
+        *self
+            .bounding_rect
+            .get_or_init(|| self.geometry.bounding_rect().into())
+    }
+
+    /// The [centroid](Centroid) of the geometry, or `None` if it's empty.
+    pub fn centroid(&self) -> Option<Point<T>>
+    where
+        G: Centroid,
+        G::Output: Into<Option<Point<T>>>,
+    {
+        *self
+            .centroid
+            .get_or_init(|| self.geometry.centroid().into())
+    }
+
+    /// The [length](Length) of the geometry in `metric_space`.
+    pub fn length<M>(&self, metric_space: &M) -> T
+    where
+        G: Length<T>,
+        M: Distance<T, Point<T>, Point<T>> + PartialEq + Clone + Send + 'static,
+    {
+        let cached_length = self.lengths.borrow().iter().find_map(|(cached, length)| {
+            (cached.downcast_ref::<M>() == Some(metric_space)).then_some(*length)
+        });
+        if let Some(length) = cached_length {
+            return length;
+        }
+
+        let length = self.geometry.length(metric_space);
+        self.lengths
+            .borrow_mut()
+            .push((Box::new(metric_space.clone()), length));
+        length
+    }
+}
+
+impl<G, T: CoordFloat> From<G> for CachedMetrics<G, T> {
+    fn from(geometry: G) -> Self {
+        Self::new(geometry)
+    }
+}
+
+impl<G: fmt::Debug, T: CoordFloat> fmt::Debug for CachedMetrics<G, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedMetrics")
+            .field("geometry", &self.geometry)
+            .field("signed_area", &self.signed_area)
+            .field("unsigned_area", &self.unsigned_area)
+            .field("bounding_rect", &self.bounding_rect)
+            .field("centroid", &self.centroid)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Euclidean, HaversineMeasure, Polygon};
+    use std::cell::Cell;
+
+    #[test]
+    fn caches_until_mutated() {
+        let mut polygon: CachedMetrics<Polygon> =
+            CachedMetrics::new(wkt!(POLYGON((0. 0.,0. 2.,2. 2.,2. 0.,0. 0.))));
+        assert_eq!(polygon.signed_area(), -4.);
+        assert_eq!(polygon.unsigned_area(), 4.);
+        assert_eq!(polygon.centroid(), Some(point!(x: 1., y: 1.)));
+        assert_eq!(polygon.bounding_rect(), Some(Rect::new((0., 0.), (2., 2.))));
+
+        polygon.geometry_mut().exterior_mut(|exterior| {
+            exterior.0.reverse();
+            exterior.0[1].x = 4.;
+            exterior.0[2].x = 4.;
+        });
+        assert_eq!(polygon.signed_area(), 8.);
+        assert_eq!(polygon.centroid(), Some(point!(x: 2., y: 1.)));
+        assert_eq!(polygon.bounding_rect(), Some(Rect::new((0., 0.), (4., 2.))));
+
+        let empty: CachedMetrics<Polygon> = CachedMetrics::new(wkt!(POLYGON EMPTY));
+        assert_eq!(empty.bounding_rect(), None);
+        assert_eq!(empty.centroid(), None);
+    }
+
+    // A metric space which counts how often it's used
+    #[derive(Clone, PartialEq)]
+    struct CountingMetric;
+
+    impl Distance<f64, Point, Point> for CountingMetric {
+        fn distance(&self, origin: Point, destination: Point) -> f64 {
+            COUNT.with(|count| count.set(count.get() + 1));
+            Euclidean.distance(origin, destination)
+        }
+    }
+
+    thread_local! {
+        static COUNT: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[test]
+    fn lengths_per_metric_space() {
+        let line_string = CachedMetrics::new(wkt!(LINESTRING(0. 0.,3. 4.,3. 5.)));
+        assert_eq!(line_string.length(&CountingMetric), 6.);
+        assert_eq!(line_string.length(&CountingMetric), 6.);
+        assert_eq!(COUNT.with(Cell::get), 2);
+
+        let unit_sphere = HaversineMeasure::new(1.);
+        let double_sphere = HaversineMeasure::new(2.);
+        let unit_length = line_string.length(&unit_sphere);
+        assert_eq!(line_string.length(&double_sphere), 2. * unit_length);
+        assert_eq!(line_string.length(&unit_sphere), unit_length);
+        assert_eq!(line_string.length(&Euclidean), 6.);
+    }
+}
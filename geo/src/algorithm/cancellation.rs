@@ -0,0 +1,71 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A cooperative signal to abort a long-running algorithm.
+///
+/// Algorithms accepting a token check it at safe points and return [`Cancelled`] as soon as
+/// they notice it has been set. Cancellation is cooperative: work in progress between two safe
+/// points runs to completion, so an algorithm may keep running briefly after the token is set.
+///
+/// Implemented for [`AtomicBool`], which can be shared with another thread (e.g. in an `Arc`),
+/// and for closures returning `bool`, e.g. to implement a deadline.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{unary_union_cancellable, wkt, Cancelled};
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// let polygons = vec![
+///     wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))),
+///     wkt!(POLYGON((4. 0.,8. 0.,8. 4.,4. 4.,4. 0.))),
+/// ];
+///
+/// let cancel = AtomicBool::new(false);
+/// assert!(unary_union_cancellable(&polygons, &cancel).is_ok());
+///
+/// cancel.store(true, Ordering::Relaxed);
+/// assert_eq!(unary_union_cancellable(&polygons, &cancel), Err(Cancelled));
+///
+/// // Give up after a deadline
+/// let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+/// let union = unary_union_cancellable(&polygons, &|| std::time::Instant::now() > deadline);
+/// assert!(union.is_ok());
+/// ```
+pub trait CancellationToken {
+    /// Whether the algorithm should stop.
+    fn is_cancelled(&self) -> bool;
+
+    /// Returns `Err(Cancelled)` if the algorithm should stop.
+    fn check_cancelled(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl CancellationToken for AtomicBool {
+    fn is_cancelled(&self) -> bool {
+        self.load(Ordering::Relaxed)
+    }
+}
+
+impl<F: Fn() -> bool> CancellationToken for F {
+    fn is_cancelled(&self) -> bool {
+        self()
+    }
+}
+
+/// The error returned by an algorithm which was aborted via a [`CancellationToken`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the operation was cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
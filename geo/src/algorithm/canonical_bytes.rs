@@ -0,0 +1,316 @@
+use crate::geometry::*;
+use crate::normalize_geometry::{canonical_f64, normalized_ring};
+use crate::winding_order::WindingOrder;
+use crate::GeoNum;
+
+/// The version of the encoding, which is the first byte of every encoding. It will only change
+/// if the encoding does.
+pub const CANONICAL_BYTES_VERSION: u8 = 1;
+
+/// A deterministic byte encoding of a geometry, for use as a hashing or equality key, e.g. of a
+/// cache.
+///
+/// Geometries which differ only in the order, winding or starting vertex of their rings, or in
+/// the order of the members of a multi-geometry, have the same encoding:
+///
+/// - Polygon and triangle exteriors are wound counter-clockwise, and interiors clockwise.
+/// - Rings start at their least coordinate, ordering by `x` and then `y`.
+/// - The interiors of a polygon, and the members of a `MultiPoint`, `MultiLineString` or
+///   `MultiPolygon`, are sorted by their encodings.
+///
+/// The direction of a `LineString`, and the order of the members of a `GeometryCollection`,
+/// are kept. Coordinates are compared exactly, without a tolerance, so geometries which differ
+/// by rounding have different encodings.
+///
+/// Unlike `serde` output, the encoding doesn't depend on the layout of the types, the numeric
+/// type of the coordinates, or the platform, so it is stable across versions of `geo` with the
+/// same [`CANONICAL_BYTES_VERSION`]. It starts with that version, followed by the geometry:
+///
+/// - a type tag byte: `1` for `Point`, `2` `Line`, `3` `LineString`, `4` `Polygon`,
+///   `5` `MultiPoint`, `6` `MultiLineString`, `7` `MultiPolygon`, `8` `GeometryCollection`,
+///   `9` `Rect` and `10` `Triangle`
+/// - for a `LineString` or ring, the number of coordinates, and for a polygon or collection, the
+///   number of rings or members, as a little endian `u64`
+/// - the coordinates as pairs of little endian IEEE 754 `f64`s, with `-0.0` written as `0.0` and
+///   every NaN as the same NaN, or the rings or members
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, CanonicalBytes};
+///
+/// let polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+/// // the same square, wound clockwise and starting elsewhere
+/// let reversed = wkt!(POLYGON((4. 4.,4. 0.,0. 0.,0. 4.,4. 4.)));
+/// assert_eq!(polygon.canonical_bytes(), reversed.canonical_bytes());
+///
+/// let moved = wkt!(POLYGON((0. 0.,4. 0.,4. 5.,0. 4.,0. 0.)));
+/// assert_ne!(polygon.canonical_bytes(), moved.canonical_bytes());
+/// ```
+pub trait CanonicalBytes {
+    /// The canonical encoding of the geometry.
+    fn canonical_bytes(&self) -> Vec<u8>;
+}
+
+const POINT: u8 = 1;
+const LINE: u8 = 2;
+const LINE_STRING: u8 = 3;
+const POLYGON: u8 = 4;
+const MULTI_POINT: u8 = 5;
+const MULTI_LINE_STRING: u8 = 6;
+const MULTI_POLYGON: u8 = 7;
+const GEOMETRY_COLLECTION: u8 = 8;
+const RECT: u8 = 9;
+const TRIANGLE: u8 = 10;
+
+macro_rules! impl_canonical_bytes {
+    ($type:ident, $write:ident) => {
+        impl<T: GeoNum> CanonicalBytes for $type<T> {
+            fn canonical_bytes(&self) -> Vec<u8> {
+                let mut bytes = vec![CANONICAL_BYTES_VERSION];
+                $write(self, &mut bytes);
+                bytes
+            }
+        }
+    };
+}
+
+impl_canonical_bytes!(Point, write_point);
+impl_canonical_bytes!(Line, write_line);
+impl_canonical_bytes!(LineString, write_line_string);
+impl_canonical_bytes!(Polygon, write_polygon);
+impl_canonical_bytes!(MultiPoint, write_multi_point);
+impl_canonical_bytes!(MultiLineString, write_multi_line_string);
+impl_canonical_bytes!(MultiPolygon, write_multi_polygon);
+impl_canonical_bytes!(GeometryCollection, write_geometry_collection);
+impl_canonical_bytes!(Rect, write_rect);
+impl_canonical_bytes!(Triangle, write_triangle);
+impl_canonical_bytes!(Geometry, write_geometry);
+
+fn write_len(len: usize, bytes: &mut Vec<u8>) {
+    bytes.extend_from_slice(&(len as u64).to_le_bytes());
+}
+
+fn write_coord<T: GeoNum>(coord: Coord<T>, bytes: &mut Vec<u8>) {
+    bytes.extend_from_slice(&canonical_f64(coord.x).to_le_bytes());
+    bytes.extend_from_slice(&canonical_f64(coord.y).to_le_bytes());
+}
+
+fn write_coords<T: GeoNum>(coords: impl ExactSizeIterator<Item = Coord<T>>, bytes: &mut Vec<u8>) {
+    write_len(coords.len(), bytes);
+    for coord in coords {
+        write_coord(coord, bytes);
+    }
+}
+
+// Write the encodings of `members` in order of their encodings
+fn write_sorted<G>(members: &[G], write: impl Fn(&G, &mut Vec<u8>), bytes: &mut Vec<u8>) {
+    let mut encodings: Vec<Vec<u8>> = members
+        .iter()
+        .map(|member| {
+            let mut encoding = Vec::new();
+            write(member, &mut encoding);
+            encoding
+        })
+        .collect();
+    encodings.sort_unstable();
+    write_len(encodings.len(), bytes);
+    for encoding in encodings {
+        bytes.extend_from_slice(&encoding);
+    }
+}
+
+fn write_point<T: GeoNum>(point: &Point<T>, bytes: &mut Vec<u8>) {
+    bytes.push(POINT);
+    write_coord(point.0, bytes);
+}
+
+fn write_line<T: GeoNum>(line: &Line<T>, bytes: &mut Vec<u8>) {
+    bytes.push(LINE);
+    write_coord(line.start, bytes);
+    write_coord(line.end, bytes);
+}
+
+fn write_line_string<T: GeoNum>(line_string: &LineString<T>, bytes: &mut Vec<u8>) {
+    bytes.push(LINE_STRING);
+    write_coords(line_string.0.iter().copied(), bytes);
+}
+
+// Write a ring wound in `winding_order`, starting and ending at its least coordinate
+fn write_ring<T: GeoNum>(ring: &LineString<T>, winding_order: WindingOrder, bytes: &mut Vec<u8>) {
+    write_coords(normalized_ring(ring, winding_order).0.into_iter(), bytes);
+}
+
+fn write_polygon<T: GeoNum>(polygon: &Polygon<T>, bytes: &mut Vec<u8>) {
+    bytes.push(POLYGON);
+    write_ring(polygon.exterior(), WindingOrder::CounterClockwise, bytes);
+    write_sorted(
+        polygon.interiors(),
+        |ring, bytes| write_ring(ring, WindingOrder::Clockwise, bytes),
+        bytes,
+    );
+}
+
+fn write_multi_point<T: GeoNum>(multi_point: &MultiPoint<T>, bytes: &mut Vec<u8>) {
+    bytes.push(MULTI_POINT);
+    write_sorted(&multi_point.0, write_point, bytes);
+}
+
+fn write_multi_line_string<T: GeoNum>(multi_line_string: &MultiLineString<T>, bytes: &mut Vec<u8>) {
+    bytes.push(MULTI_LINE_STRING);
+    write_sorted(&multi_line_string.0, write_line_string, bytes);
+}
+
+fn write_multi_polygon<T: GeoNum>(multi_polygon: &MultiPolygon<T>, bytes: &mut Vec<u8>) {
+    bytes.push(MULTI_POLYGON);
+    write_sorted(&multi_polygon.0, write_polygon, bytes);
+}
+
+fn write_geometry_collection<T: GeoNum>(collection: &GeometryCollection<T>, bytes: &mut Vec<u8>) {
+    bytes.push(GEOMETRY_COLLECTION);
+    write_len(collection.len(), bytes);
+    for geometry in collection {
+        write_geometry(geometry, bytes);
+    }
+}
+
+fn write_rect<T: GeoNum>(rect: &Rect<T>, bytes: &mut Vec<u8>) {
+    bytes.push(RECT);
+    write_coord(rect.min(), bytes);
+    write_coord(rect.max(), bytes);
+}
+
+fn write_triangle<T: GeoNum>(triangle: &Triangle<T>, bytes: &mut Vec<u8>) {
+    bytes.push(TRIANGLE);
+    write_ring(
+        &LineString::new(triangle.to_array().to_vec()),
+        WindingOrder::CounterClockwise,
+        bytes,
+    );
+}
+
+fn write_geometry<T: GeoNum>(geometry: &Geometry<T>, bytes: &mut Vec<u8>) {
+    match geometry {
+        Geometry::Point(g) => write_point(g, bytes),
+        Geometry::Line(g) => write_line(g, bytes),
+        Geometry::LineString(g) => write_line_string(g, bytes),
+        Geometry::Polygon(g) => write_polygon(g, bytes),
+        Geometry::MultiPoint(g) => write_multi_point(g, bytes),
+        Geometry::MultiLineString(g) => write_multi_line_string(g, bytes),
+        Geometry::MultiPolygon(g) => write_multi_polygon(g, bytes),
+        Geometry::GeometryCollection(g) => write_geometry_collection(g, bytes),
+        Geometry::Rect(g) => write_rect(g, bytes),
+        Geometry::Triangle(g) => write_triangle(g, bytes),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Orient};
+
+    #[test]
+    fn normalization() {
+        let polygon: Polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (1. 1.,2. 1.,2. 2.,1. 1.),
+            (5. 5.,6. 5.,6. 6.,5. 5.)
+        ));
+        // wound the other way, starting elsewhere, with the interiors swapped
+        let equivalent: Polygon = wkt!(POLYGON(
+            (10. 10.,10. 0.,0. 0.,0. 10.,10. 10.),
+            (6. 6.,5. 5.,6. 5.,6. 6.),
+            (2. 1.,2. 2.,1. 1.,2. 1.)
+        ));
+        assert_eq!(polygon.canonical_bytes(), equivalent.canonical_bytes());
+        assert_eq!(
+            polygon.canonical_bytes(),
+            polygon
+                .orient(crate::orient::Direction::Reversed)
+                .canonical_bytes()
+        );
+        assert_eq!(
+            Geometry::from(polygon.clone()).canonical_bytes(),
+            polygon.canonical_bytes()
+        );
+
+        let multi_point = wkt!(MULTIPOINT(1. 2.,-0. 0.,3. 4.));
+        assert_eq!(
+            multi_point.canonical_bytes(),
+            wkt!(MULTIPOINT(3. 4.,0. 0.,1. 2.)).canonical_bytes()
+        );
+
+        let triangle = Triangle::from([(0., 0.), (1., 0.), (0., 1.)]);
+        assert_eq!(
+            triangle.canonical_bytes(),
+            Triangle::from([(1., 0.), (0., 0.), (0., 1.)]).canonical_bytes()
+        );
+
+        // the coordinate type doesn't matter
+        let integers: MultiPoint<i32> = wkt!(MULTIPOINT(3 4,0 0,1 2));
+        assert_eq!(integers.canonical_bytes(), multi_point.canonical_bytes());
+    }
+
+    #[test]
+    fn distinctions() {
+        // a line string's direction is kept
+        let line_string = wkt!(LINESTRING(0. 0.,1. 1.));
+        assert_ne!(
+            line_string.canonical_bytes(),
+            wkt!(LINESTRING(1. 1.,0. 0.)).canonical_bytes()
+        );
+
+        // as is the order of a collection
+        let a = Geometry::from(wkt!(POINT(0. 0.)));
+        let b = Geometry::from(wkt!(POINT(1. 1.)));
+        assert_ne!(
+            GeometryCollection::new_from(vec![a.clone(), b.clone()]).canonical_bytes(),
+            GeometryCollection::new_from(vec![b, a]).canonical_bytes()
+        );
+
+        // the same coordinates as different types
+        let line = Line::new((0., 0.), (1., 1.));
+        assert_ne!(line.canonical_bytes(), line_string.canonical_bytes());
+        let rect = Rect::new((0., 0.), (1., 1.));
+        assert_ne!(rect.canonical_bytes(), line.canonical_bytes());
+        assert_ne!(rect.canonical_bytes(), rect.to_polygon().canonical_bytes());
+    }
+
+    #[test]
+    fn encoding() {
+        let bytes = wkt!(POINT(1. - 0.)).canonical_bytes();
+        let mut expected = vec![CANONICAL_BYTES_VERSION, POINT];
+        expected.extend_from_slice(&1f64.to_le_bytes());
+        expected.extend_from_slice(&0f64.to_le_bytes());
+        assert_eq!(bytes, expected);
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(
+            empty.canonical_bytes(),
+            vec![
+                CANONICAL_BYTES_VERSION,
+                POLYGON,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0
+            ]
+        );
+        assert_eq!(
+            Point::new(f64::NAN, 0.).canonical_bytes(),
+            Point::new(-f64::NAN, 0.).canonical_bytes()
+        );
+    }
+}
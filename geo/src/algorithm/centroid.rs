@@ -4,7 +4,7 @@ use crate::area::{get_linestring_area, Area};
 use crate::dimensions::{Dimensions, Dimensions::*, HasDimensions};
 use crate::geometry::*;
 use crate::line_measures::{Euclidean, Length};
-use crate::GeoFloat;
+use crate::{GeoFloat, GeometryCow};
 
 /// Calculation of the centroid.
 /// The centroid is the arithmetic mean position of all points in the shape.
@@ -386,6 +386,17 @@ where
     }
 }
 
+impl<T> Centroid for GeometryCow<'_, T>
+where
+    T: GeoFloat,
+{
+    type Output = Option<Point<T>>;
+
+    crate::geometry_cow_delegate_impl! {
+        fn centroid(&self) -> Self::Output;
+    }
+}
+
 impl<T> Centroid for GeometryCollection<T>
 where
     T: GeoFloat,
@@ -439,13 +450,109 @@ where
     }
 }
 
-struct CentroidOperation<T: GeoFloat>(Option<WeightedCentroid<T>>);
+/// Calculation of the centroid of a collection, where each member has a weight, such as the
+/// population of each place in a demographic centroid.
+///
+/// The weighted centroid is the mean of the centroids of the members, weighted by `weights`,
+/// which has one weight for each member. Unlike [`Centroid`], the dimensions of the members are
+/// ignored: e.g. the centroid of a point with a weight of `2` counts twice as much as the
+/// centroid of a polygon with a weight of `1`, regardless of the polygon's area. Empty members
+/// are ignored.
+///
+/// Returns `None` if the collection is empty, or the total weight of its non-empty members is
+/// zero.
+///
+/// # Panics
+///
+/// If the number of weights isn't the number of members.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, MultiPoint, WeightedCentroid};
+///
+/// let towns = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 10., y: 0.)]);
+/// let populations = [3_000., 1_000.];
+///
+/// assert_eq!(
+///     towns.weighted_centroid(&populations),
+///     Some(point!(x: 2.5, y: 0.))
+/// );
+/// ```
+pub trait WeightedCentroid<T: GeoFloat> {
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>>;
+}
+
+impl<T> WeightedCentroid<T> for MultiPoint<T>
+where
+    T: GeoFloat,
+{
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        weighted_mean(self.0.iter().map(|point| Some(*point)), weights)
+    }
+}
+
+impl<T> WeightedCentroid<T> for GeometryCollection<T>
+where
+    T: GeoFloat,
+{
+    /// The weighted centroid of a [`GeometryCollection`] is the mean of the [centroids](Centroid)
+    /// of its members, weighted by `weights`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, wkt, Geometry, GeometryCollection, WeightedCentroid};
+    ///
+    /// let districts = GeometryCollection::new_from(vec![
+    ///     // centroid: (1, 1)
+    ///     wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.))).into(),
+    ///     // centroid: (5, 1)
+    ///     wkt!(POLYGON((4. 0.,6. 0.,6. 2.,4. 2.,4. 0.))).into(),
+    ///     wkt!(POINT(3. 5.)).into(),
+    /// ]);
+    /// let populations = [1_000., 1_000., 2_000.];
+    ///
+    /// assert_eq!(
+    ///     districts.weighted_centroid(&populations),
+    ///     Some(point!(x: 3., y: 3.))
+    /// );
+    /// ```
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        weighted_mean(self.0.iter().map(Centroid::centroid), weights)
+    }
+}
+
+// The mean of `centroids`, weighted by `weights`, ignoring missing centroids
+fn weighted_mean<T: GeoFloat>(
+    centroids: impl ExactSizeIterator<Item = Option<Point<T>>>,
+    weights: &[T],
+) -> Option<Point<T>> {
+    assert_eq!(
+        centroids.len(),
+        weights.len(),
+        "there must be one weight for each member"
+    );
+
+    let mut accumulated = Coord::zero();
+    let mut total_weight = T::zero();
+    for (centroid, &weight) in centroids.zip(weights) {
+        if let Some(centroid) = centroid {
+            accumulated = accumulated + centroid.0 * weight;
+            total_weight = total_weight + weight;
+        }
+    }
+    (!total_weight.is_zero()).then(|| Point::from(accumulated / total_weight))
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CentroidOperation<T: GeoFloat>(Option<AccumulatedCentroid<T>>);
 impl<T: GeoFloat> CentroidOperation<T> {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         CentroidOperation(None)
     }
 
-    fn centroid(&self) -> Option<Point<T>> {
+    pub(crate) fn centroid(&self) -> Option<Point<T>> {
         self.0.as_ref().map(|weighted_centroid| {
             Point::from(weighted_centroid.accumulated / weighted_centroid.weight)
         })
@@ -465,11 +572,9 @@ impl<T: GeoFloat> CentroidOperation<T> {
     fn add_line(&mut self, line: &Line<T>) {
         match line.dimensions() {
             ZeroDimensional => self.add_coord(line.start),
-            OneDimensional => self.add_centroid(
-                OneDimensional,
-                line.centroid().0,
-                line.length::<Euclidean>(),
-            ),
+            OneDimensional => {
+                self.add_centroid(OneDimensional, line.centroid().0, line.length(&Euclidean))
+            }
             _ => unreachable!("Line must be zero or one dimensional"),
         }
     }
@@ -587,7 +692,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
         }
     }
 
-    fn add_geometry(&mut self, geometry: &Geometry<T>) {
+    pub(crate) fn add_geometry(&mut self, geometry: &Geometry<T>) {
         match geometry {
             Geometry::Point(g) => self.add_coord(g.0),
             Geometry::Line(g) => self.add_line(g),
@@ -634,7 +739,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
     }
 
     fn add_centroid(&mut self, dimensions: Dimensions, centroid: Coord<T>, weight: T) {
-        let weighted_centroid = WeightedCentroid {
+        let weighted_centroid = AccumulatedCentroid {
             dimensions,
             weight,
             accumulated: centroid * weight,
@@ -642,7 +747,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
         self.add_weighted_centroid(weighted_centroid);
     }
 
-    fn add_weighted_centroid(&mut self, other: WeightedCentroid<T>) {
+    fn add_weighted_centroid(&mut self, other: AccumulatedCentroid<T>) {
         match self.0.as_mut() {
             Some(centroid) => centroid.add_assign(other),
             None => self.0 = Some(other),
@@ -651,7 +756,8 @@ impl<T: GeoFloat> CentroidOperation<T> {
 }
 
 // Aggregated state for accumulating the centroid of a geometry or collection of geometries.
-struct WeightedCentroid<T: GeoFloat> {
+#[derive(Debug, Clone)]
+struct AccumulatedCentroid<T: GeoFloat> {
     weight: T,
     accumulated: Coord<T>,
     /// Collections of Geometries can have different dimensionality. Centroids must be considered
@@ -667,8 +773,8 @@ struct WeightedCentroid<T: GeoFloat> {
     dimensions: Dimensions,
 }
 
-impl<T: GeoFloat> WeightedCentroid<T> {
-    fn add_assign(&mut self, b: WeightedCentroid<T>) {
+impl<T: GeoFloat> AccumulatedCentroid<T> {
+    fn add_assign(&mut self, b: AccumulatedCentroid<T>) {
         match self.dimensions.cmp(&b.dimensions) {
             Ordering::Less => *self = b,
             Ordering::Greater => {}
@@ -679,7 +785,7 @@ impl<T: GeoFloat> WeightedCentroid<T> {
         }
     }
 
-    fn sub_assign(&mut self, b: WeightedCentroid<T>) {
+    fn sub_assign(&mut self, b: AccumulatedCentroid<T>) {
         match self.dimensions.cmp(&b.dimensions) {
             Ordering::Less => *self = b,
             Ordering::Greater => {}
@@ -1010,6 +1116,41 @@ mod test {
 
         assert_eq!(collection.centroid().unwrap(), point!(x: 1.0, y: 1.0));
     }
+    #[test]
+    fn weighted_centroids() {
+        let multi_point = MultiPoint::new(vec![p(0., 0.), p(4., 0.), p(4., 4.)]);
+        assert_eq!(
+            multi_point.weighted_centroid(&[1., 1., 2.]),
+            Some(point!(x: 3., y: 2.))
+        );
+        // equal weights give the unweighted centroid
+        assert_eq!(
+            multi_point.weighted_centroid(&[5., 5., 5.]),
+            multi_point.centroid()
+        );
+        assert_eq!(multi_point.weighted_centroid(&[0., 0., 0.]), None);
+        assert_eq!(MultiPoint::<f64>::new(vec![]).weighted_centroid(&[]), None);
+
+        let collection = GeometryCollection::new_from(vec![
+            wkt!(LINESTRING(0. 0.,2. 0.)).into(),
+            wkt!(POLYGON EMPTY).into(),
+            wkt!(POLYGON((0. 0.,6. 0.,6. 6.,0. 6.,0. 0.))).into(),
+        ]);
+        // the empty polygon is ignored
+        assert_eq!(
+            collection.weighted_centroid(&[3., 100., 1.]),
+            Some(point!(x: 1.5, y: 0.75))
+        );
+        assert_eq!(collection.weighted_centroid(&[0., 100., 0.]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_centroid_mismatched_weights() {
+        let multi_point = MultiPoint::new(vec![p(0., 0.), p(4., 0.)]);
+        multi_point.weighted_centroid(&[1.]);
+    }
+
     #[test]
     fn triangles() {
         // boring triangle
@@ -3,11 +3,17 @@ use std::ops::Mul;
 use num_traits::FromPrimitive;
 
 use crate::{
-    coord, Coord, CoordFloat, Geometry, LineString, MultiLineString, MultiPolygon, Polygon,
+    coord, Coord, CoordFloat, Geometry, GeometryCollection, LineString, MultiLineString,
+    MultiPolygon, Polygon,
 };
 
 /// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
 ///
+/// A `GeometryCollection` is smoothed member by member.
+///
+/// A `Rect` or `Triangle` in a `Geometry` is smoothed as a `Polygon`, since it no longer has its
+/// shape once its corners are cut.
+///
 /// [Chaikins smoothing algorithm](http://www.idav.ucdavis.edu/education/CAGDNotes/Chaikins-Algorithm/Chaikins-Algorithm.html)
 ///
 /// Each iteration of the smoothing doubles the number of vertices of the geometry, so in some
@@ -85,6 +91,19 @@ where
     }
 }
 
+impl<T> ChaikinSmoothing<T> for GeometryCollection<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn chaikin_smoothing(&self, n_iterations: usize) -> Self {
+        GeometryCollection::new_from(
+            self.iter()
+                .map(|geometry| geometry.chaikin_smoothing(n_iterations))
+                .collect(),
+        )
+    }
+}
+
 macro_rules! blanket_run_chaikin_smoothing {
     ($geo:expr, $n_iter:expr) => {{
         let smooth = $geo.chaikin_smoothing($n_iter);
@@ -103,6 +122,16 @@ where
             Geometry::MultiLineString(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
             Geometry::Polygon(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
             Geometry::MultiPolygon(child) => blanket_run_chaikin_smoothing!(child, n_iterations),
+            Geometry::GeometryCollection(child) => {
+                Geometry::GeometryCollection(child.chaikin_smoothing(n_iterations))
+            }
+            // Smoothing cuts the corners, so the result is no longer a rect or triangle
+            Geometry::Rect(child) => {
+                blanket_run_chaikin_smoothing!(child.to_polygon(), n_iterations)
+            }
+            Geometry::Triangle(child) => {
+                blanket_run_chaikin_smoothing!(child.to_polygon(), n_iterations)
+            }
             _ => self.clone(),
         }
     }
@@ -159,13 +188,13 @@ where
 #[cfg(test)]
 mod test {
     use crate::ChaikinSmoothing;
-    use crate::{Geometry, LineString, Point, Polygon};
+    use crate::{Geometry, GeometryCollection, LineString, Point, Polygon, Rect, Triangle};
 
     #[test]
     fn geometry() {
         // Test implemented geometry
         let ls = LineString::from(vec![(3.0, 0.0), (6.0, 3.0), (3.0, 6.0), (0.0, 3.0)]);
-        let ls_geo: Geometry = ls.into();
+        let ls_geo: Geometry = ls.clone().into();
         let ls_geo_out = ls_geo.chaikin_smoothing(1);
         let ls_out: LineString = ls_geo_out.try_into().unwrap();
         assert_eq!(
@@ -188,6 +217,31 @@ mod test {
         let pt_geo_out = pt_geo.chaikin_smoothing(1);
         let pt_out: Point = pt_geo_out.try_into().unwrap();
         assert_eq!(pt_out, Point::from((3.0, 0.0)));
+
+        // Rects and triangles are smoothed as polygons
+        let rect = Rect::new((0.0, 0.0), (4.0, 4.0));
+        let rect_geo_out = Geometry::from(rect).chaikin_smoothing(1);
+        assert_eq!(
+            rect_geo_out,
+            Geometry::from(rect.to_polygon().chaikin_smoothing(1))
+        );
+        let triangle = Triangle::from([(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)]);
+        let triangle_geo_out = Geometry::from(triangle).chaikin_smoothing(1);
+        assert_eq!(
+            triangle_geo_out,
+            Geometry::from(triangle.to_polygon().chaikin_smoothing(1))
+        );
+
+        // Collections are smoothed member by member
+        let collection = GeometryCollection::new_from(vec![ls.clone().into(), pt.into()]);
+        let collection_out = Geometry::GeometryCollection(collection).chaikin_smoothing(1);
+        assert_eq!(
+            collection_out,
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                ls.chaikin_smoothing(1).into(),
+                pt.into()
+            ]))
+        );
     }
 
     #[test]
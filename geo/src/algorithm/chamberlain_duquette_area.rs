@@ -1,5 +1,5 @@
 use crate::geometry::*;
-use crate::{CoordFloat, EQUATORIAL_EARTH_RADIUS};
+use crate::{coord, CoordFloat, EQUATORIAL_EARTH_RADIUS};
 
 /// Calculate the signed approximate geodesic area of a `Geometry`.
 ///
@@ -60,8 +60,8 @@ where
     fn chamberlain_duquette_signed_area(&self) -> T {
         self.interiors()
             .iter()
-            .fold(ring_area(self.exterior()), |total, next| {
-                total - ring_area(next)
+            .fold(ring_area(&self.exterior().0), |total, next| {
+                total - ring_area(&next.0)
             })
     }
 
@@ -70,12 +70,12 @@ where
     }
 }
 
-fn ring_area<T>(coords: &LineString<T>) -> T
+fn ring_area<T>(coords: &[Coord<T>]) -> T
 where
     T: CoordFloat,
 {
     let mut total = T::zero();
-    let coords_len = coords.0.len();
+    let coords_len = coords.len();
 
     if coords_len > 2 {
         for i in 0..coords_len {
@@ -121,20 +121,21 @@ macro_rules! zero_impl {
     };
 }
 
-/// Generate a `ChamberlainDuquetteArea` implementation which delegates to the `Polygon`
-/// implementation.
-macro_rules! to_polygon_impl {
-    ($type:ident) => {
+/// Generate a `ChamberlainDuquetteArea` implementation from the coordinates of the closed ring of
+/// the `Polygon` the type converts to, without allocating it.
+macro_rules! ring_impl {
+    ($type:ident, |$geometry:ident| $ring:expr) => {
         impl<T> ChamberlainDuquetteArea<T> for $type<T>
         where
             T: CoordFloat,
         {
             fn chamberlain_duquette_signed_area(&self) -> T {
-                self.to_polygon().chamberlain_duquette_signed_area()
+                let $geometry = self;
+                ring_area(&$ring)
             }
 
             fn chamberlain_duquette_unsigned_area(&self) -> T {
-                self.to_polygon().chamberlain_duquette_unsigned_area()
+                self.chamberlain_duquette_signed_area().abs()
             }
         }
     };
@@ -168,8 +169,17 @@ zero_impl!(Line);
 zero_impl!(LineString);
 zero_impl!(MultiPoint);
 zero_impl!(MultiLineString);
-to_polygon_impl!(Rect);
-to_polygon_impl!(Triangle);
+// In the order of `Rect::to_polygon`
+ring_impl!(Rect, |rect| [
+    rect.min(),
+    coord! { x: rect.min().x, y: rect.max().y },
+    rect.max(),
+    coord! { x: rect.max().x, y: rect.min().y },
+    rect.min(),
+]);
+ring_impl!(Triangle, |triangle| [
+    triangle.0, triangle.1, triangle.2, triangle.0
+]);
 sum_impl!(GeometryCollection);
 sum_impl!(MultiPolygon);
 
@@ -253,4 +263,23 @@ mod test {
         ];
         assert_relative_eq!(1208198651182.4727, poly.chamberlain_duquette_signed_area());
     }
+
+    #[test]
+    fn test_rect_and_triangle() {
+        let rect = Rect::new(coord! { x: 10., y: 20. }, coord! { x: 12., y: 25. });
+        assert_eq!(
+            rect.chamberlain_duquette_signed_area(),
+            rect.to_polygon().chamberlain_duquette_signed_area()
+        );
+        let triangle = Triangle::new(
+            coord! { x: 10., y: 20. },
+            coord! { x: 12., y: 20. },
+            coord! { x: 10., y: 25. },
+        );
+        assert_eq!(
+            triangle.chamberlain_duquette_signed_area(),
+            triangle.to_polygon().chamberlain_duquette_signed_area()
+        );
+        assert!(triangle.chamberlain_duquette_unsigned_area() > 0.);
+    }
 }
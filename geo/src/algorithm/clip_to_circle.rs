@@ -0,0 +1,341 @@
+use crate::bool_ops::{BoolOpsNum, BooleanOps};
+use crate::geometry::*;
+use crate::{BoundingRect, GeoFloat};
+
+/// The number of segments of the polygon approximating the circle which polygons are clipped to.
+const CIRCLE_SEGMENTS: usize = 256;
+
+/// Clip a geometry to a circle, keeping the parts of it within `radius` of `center`.
+///
+/// This extracts everything within a distance of a point without building a buffer polygon
+/// and running a full overlay by hand. Distances are Euclidean, in the units of the
+/// coordinates, so geographic coordinates should be projected first.
+///
+/// Lines are clipped exactly, at their intersections with the circle, and are returned as a
+/// `MultiLineString`. Points on the circle are within it, but parts of a line which only touch
+/// the circle are omitted.
+///
+/// Polygons, rects and triangles are intersected with a polygon of 256 vertices on the circle,
+/// and are returned as a `MultiPolygon`. This approximation lies within the circle, and is at most
+/// `radius * (1 - cos(π / 256))`, about `radius / 13_000`, inside it. Polygons which lie
+/// entirely within, or entirely outside, the circle are returned unchanged, or as an empty
+/// `MultiPolygon`, without an overlay.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, wkt, ClipToCircle, LineString, Polygon};
+///
+/// let line_string: LineString = wkt!(LINESTRING(-10. 0.,0. 0.,0. 10.,10. 10.));
+/// assert_eq!(
+///     line_string.clip_to_circle(coord! { x: 0., y: 0. }, 5.),
+///     wkt!(MULTILINESTRING((-5. 0.,0. 0.,0. 5.)))
+/// );
+///
+/// let square: Polygon = wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.)));
+/// let clipped = square.clip_to_circle(coord! { x: 0., y: 0. }, 5.);
+/// assert_eq!(clipped.0.len(), 1);
+/// ```
+pub trait ClipToCircle<T: GeoFloat> {
+    type Output;
+
+    fn clip_to_circle(&self, center: Coord<T>, radius: T) -> Self::Output;
+}
+
+impl<T: GeoFloat> ClipToCircle<T> for Line<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_circle(&self, center: Coord<T>, radius: T) -> Self::Output {
+        LineString::from(*self).clip_to_circle(center, radius)
+    }
+}
+
+impl<T: GeoFloat> ClipToCircle<T> for LineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_circle(&self, center: Coord<T>, radius: T) -> Self::Output {
+        let mut clipped = Vec::new();
+        clip_line_string(self, center, radius, &mut clipped);
+        MultiLineString::new(clipped)
+    }
+}
+
+impl<T: GeoFloat> ClipToCircle<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_circle(&self, center: Coord<T>, radius: T) -> Self::Output {
+        let mut clipped = Vec::new();
+        for line_string in self {
+            clip_line_string(line_string, center, radius, &mut clipped);
+        }
+        MultiLineString::new(clipped)
+    }
+}
+
+impl<T: GeoFloat + BoolOpsNum> ClipToCircle<T> for Polygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_circle(&self, center: Coord<T>, radius: T) -> Self::Output {
+        match self
+            .bounding_rect()
+            .map(|rect| rect_position(rect, center, radius))
+        {
+            None | Some(RectPosition::Outside) => MultiPolygon::new(vec![]),
+            Some(RectPosition::Inside) => MultiPolygon::new(vec![self.clone()]),
+            Some(RectPosition::Crossing) => self.intersection(&circle_polygon(center, radius)),
+        }
+    }
+}
+
+impl<T: GeoFloat + BoolOpsNum> ClipToCircle<T> for Rect<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_circle(&self, center: Coord<T>, radius: T) -> Self::Output {
+        match rect_position(*self, center, radius) {
+            RectPosition::Outside => MultiPolygon::new(vec![]),
+            RectPosition::Inside => MultiPolygon::new(vec![self.to_polygon()]),
+            RectPosition::Crossing => self
+                .to_polygon()
+                .intersection(&circle_polygon(center, radius)),
+        }
+    }
+}
+
+impl<T: GeoFloat + BoolOpsNum> ClipToCircle<T> for Triangle<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_circle(&self, center: Coord<T>, radius: T) -> Self::Output {
+        self.to_polygon().clip_to_circle(center, radius)
+    }
+}
+
+impl<T: GeoFloat + BoolOpsNum> ClipToCircle<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_circle(&self, center: Coord<T>, radius: T) -> Self::Output {
+        match self
+            .bounding_rect()
+            .map(|rect| rect_position(rect, center, radius))
+        {
+            None | Some(RectPosition::Outside) => MultiPolygon::new(vec![]),
+            Some(RectPosition::Inside) => self.clone(),
+            Some(RectPosition::Crossing) => self.intersection(&circle_polygon(center, radius)),
+        }
+    }
+}
+
+/// Appends the pieces of `line_string` within the circle to `clipped`.
+fn clip_line_string<T: GeoFloat>(
+    line_string: &LineString<T>,
+    center: Coord<T>,
+    radius: T,
+    clipped: &mut Vec<LineString<T>>,
+) {
+    let mut coords = Vec::new();
+    let mut flush = |coords: &mut Vec<Coord<T>>| {
+        if coords.len() > 1 {
+            clipped.push(LineString::new(std::mem::take(coords)));
+        }
+        coords.clear();
+    };
+
+    for line in line_string.lines() {
+        let Some((start, end)) = clip_line(line, center, radius) else {
+            flush(&mut coords);
+            continue;
+        };
+        // A piece continues from the previous line if both pass through its start
+        if start > T::zero() || coords.is_empty() {
+            flush(&mut coords);
+            coords.push(point_at(line, start));
+        }
+        coords.push(point_at(line, end));
+        if end < T::one() {
+            flush(&mut coords);
+        }
+    }
+    flush(&mut coords);
+}
+
+/// The fractions along `line` at which it enters and leaves the circle, or `None` if it
+/// doesn't cross the circle.
+fn clip_line<T: GeoFloat>(line: Line<T>, center: Coord<T>, radius: T) -> Option<(T, T)> {
+    let radius_2 = radius * radius;
+    let offset = line.start - center;
+    let start_inside = offset.x * offset.x + offset.y * offset.y <= radius_2;
+    let end_offset = line.end - center;
+    let end_inside = end_offset.x * end_offset.x + end_offset.y * end_offset.y <= radius_2;
+    if start_inside && end_inside {
+        return Some((T::zero(), T::one()));
+    }
+
+    // Solve |offset + t * delta|² = radius² for t
+    let delta = line.delta();
+    let a = delta.x * delta.x + delta.y * delta.y;
+    if a == T::zero() {
+        return None;
+    }
+    let half_b = offset.x * delta.x + offset.y * delta.y;
+    let c = offset.x * offset.x + offset.y * offset.y - radius_2;
+    let discriminant = half_b * half_b - a * c;
+    if discriminant <= T::zero() {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let start = if start_inside {
+        T::zero()
+    } else {
+        ((-half_b - root) / a).max(T::zero())
+    };
+    let end = if end_inside {
+        T::one()
+    } else {
+        ((-half_b + root) / a).min(T::one())
+    };
+    (start < end).then_some((start, end))
+}
+
+fn point_at<T: GeoFloat>(line: Line<T>, fraction: T) -> Coord<T> {
+    if fraction == T::zero() {
+        line.start
+    } else if fraction == T::one() {
+        line.end
+    } else {
+        line.start + line.delta() * fraction
+    }
+}
+
+enum RectPosition {
+    Inside,
+    Outside,
+    Crossing,
+}
+
+fn rect_position<T: GeoFloat>(rect: Rect<T>, center: Coord<T>, radius: T) -> RectPosition {
+    let radius_2 = radius * radius;
+    let distance_2 = |x: T, y: T| {
+        let (dx, dy) = (x - center.x, y - center.y);
+        dx * dx + dy * dy
+    };
+    let (min, max) = (rect.min(), rect.max());
+    let farthest_x = if center.x - min.x > max.x - center.x {
+        min.x
+    } else {
+        max.x
+    };
+    let farthest_y = if center.y - min.y > max.y - center.y {
+        min.y
+    } else {
+        max.y
+    };
+    if distance_2(farthest_x, farthest_y) <= radius_2 {
+        return RectPosition::Inside;
+    }
+    let nearest_x = center.x.max(min.x).min(max.x);
+    let nearest_y = center.y.max(min.y).min(max.y);
+    if distance_2(nearest_x, nearest_y) >= radius_2 {
+        RectPosition::Outside
+    } else {
+        RectPosition::Crossing
+    }
+}
+
+/// A counter-clockwise polygon with its vertices on the circle.
+fn circle_polygon<T: GeoFloat>(center: Coord<T>, radius: T) -> Polygon<T> {
+    let step = T::from(std::f64::consts::TAU / CIRCLE_SEGMENTS as f64).unwrap();
+    let exterior = (0..CIRCLE_SEGMENTS)
+        .map(|idx| {
+            let (sin, cos) = (step * T::from(idx).unwrap()).sin_cos();
+            Coord {
+                x: center.x + radius * cos,
+                y: center.y + radius * sin,
+            }
+        })
+        .collect();
+    Polygon::new(exterior, vec![])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, Area, Euclidean, Length};
+
+    #[test]
+    fn clip_line_strings() {
+        let center = coord! { x: 0., y: 0. };
+
+        // entering and leaving the circle several times
+        let zigzag: LineString = wkt!(LINESTRING(-10. 0.,-3. 0.,-3. 10.,3. 10.,3. 0.,10. 0.));
+        assert_eq!(
+            zigzag.clip_to_circle(center, 5.),
+            wkt!(MULTILINESTRING((-5. 0.,-3. 0.,-3. 4.),(3. 4.,3. 0.,5. 0.)))
+        );
+
+        // lines which touch the circle, or lie outside it
+        let tangent: LineString = wkt!(LINESTRING(-10. 5.,10. 5.));
+        assert!(tangent.clip_to_circle(center, 5.).0.is_empty());
+        let outside: LineString = wkt!(LINESTRING(-10. 6.,10. 6.));
+        assert!(outside.clip_to_circle(center, 5.).0.is_empty());
+
+        // a line ending on the circle, and a line inside it
+        let lines: MultiLineString = wkt!(MULTILINESTRING((0. 0.,0. 5.,0. 10.),(1. 1.,2. 2.)));
+        assert_eq!(
+            lines.clip_to_circle(center, 5.),
+            wkt!(MULTILINESTRING((0. 0.,0. 5.),(1. 1.,2. 2.)))
+        );
+
+        let line = Line::new(coord! { x: 10., y: 10. }, coord! { x: -10., y: -10. });
+        let clipped = line.clip_to_circle(center, 5.);
+        assert_eq!(clipped.0.len(), 1);
+        approx::assert_relative_eq!(clipped.length(&Euclidean), 10.);
+    }
+
+    #[test]
+    fn clip_polygons() {
+        let center = coord! { x: 0., y: 0. };
+        let square: Polygon = wkt!(POLYGON((-10. -10.,10. -10.,10. 10.,-10. 10.,-10. -10.)));
+        let disc = square.clip_to_circle(center, 5.);
+        assert_eq!(disc.0.len(), 1);
+        approx::assert_relative_eq!(
+            disc.unsigned_area(),
+            std::f64::consts::PI * 25.,
+            max_relative = 1e-3
+        );
+
+        let inside: Polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)));
+        assert_eq!(
+            inside.clip_to_circle(center, 5.),
+            MultiPolygon::new(vec![inside.clone()])
+        );
+        let outside: MultiPolygon = wkt!(MULTIPOLYGON(((4. 4.,5. 4.,5. 5.,4. 4.))));
+        assert!(outside.clip_to_circle(center, 5.).0.is_empty());
+
+        // the parts of a multi polygon within the circle
+        let multi_polygon: MultiPolygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,1. 0.,1. 1.,0. 0.)),
+            ((4. 0.,10. 0.,10. 1.,4. 1.,4. 0.)),
+            ((20. 0.,21. 0.,21. 1.,20. 0.))
+        ));
+        let clipped = multi_polygon.clip_to_circle(center, 5.);
+        assert_eq!(clipped.0.len(), 2);
+        assert!(clipped.unsigned_area() > 0.5 + 0.9 && clipped.unsigned_area() < 0.5 + 1.);
+
+        let rect = Rect::new(coord! { x: -10., y: -10. }, coord! { x: 10., y: 10. });
+        assert_eq!(rect.clip_to_circle(center, 5.), disc);
+        let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        assert_eq!(
+            rect.clip_to_circle(center, 5.),
+            MultiPolygon::new(vec![rect.to_polygon()])
+        );
+        let triangle = Triangle::new(
+            coord! { x: -10., y: -10. },
+            coord! { x: 10., y: -10. },
+            coord! { x: 10., y: 10. },
+        );
+        approx::assert_relative_eq!(
+            triangle.clip_to_circle(center, 5.).unsigned_area(),
+            disc.unsigned_area() / 2.,
+            max_relative = 1e-3
+        );
+    }
+}
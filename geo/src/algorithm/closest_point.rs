@@ -52,7 +52,7 @@ impl<F: GeoFloat> ClosestPoint<F> for Point<F> {
 #[allow(clippy::many_single_char_names)]
 impl<F: GeoFloat> ClosestPoint<F> for Line<F> {
     fn closest_point(&self, p: &Point<F>) -> Closest<F> {
-        let line_length = self.length::<Euclidean>();
+        let line_length = self.length(&Euclidean);
         if line_length == F::zero() {
             // if we've got a zero length line, technically the entire line
             // is the closest point...
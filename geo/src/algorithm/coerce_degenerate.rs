@@ -0,0 +1,335 @@
+use std::cmp::Ordering;
+
+use crate::geometry::*;
+use crate::{Area, BoundingRect, Euclidean, GeoFloat, Length};
+
+/// Collapse degenerate geometries into geometries of lower dimension.
+///
+/// Clipping and snapping often leave lines of zero length and polygons of zero area, which some
+/// algorithms, such as [`BooleanOps`](crate::BooleanOps), don't handle. This replaces them with
+/// the points and lines they really are, so that they can be dispatched on their type.
+///
+/// A geometry is degenerate within `tolerance` by these rules:
+///
+/// - A `Line` or `LineString` collapses to a `Point` if its length is at most `tolerance`.
+/// - A `Polygon` or `Triangle` collapses if its area is at most `tolerance` times the length of
+///   its exterior, i.e. it is at most about `tolerance` wide. It becomes a `Point` if its
+///   bounding rectangle's diagonal is at most `tolerance`, and otherwise a two coordinate
+///   `LineString` between its extreme coordinates along the longer side of its bounding
+///   rectangle. The degenerate interiors of a polygon which doesn't collapse are removed.
+/// - A `Rect` becomes a `Point` if both its width and height are at most `tolerance`, and a
+///   `Line` through its center if one of them is.
+/// - The members of a multi-geometry are collapsed individually. If they all collapse to the
+///   same type, the result is the matching multi-geometry, e.g. a `MultiPoint`, and otherwise
+///   a `GeometryCollection`.
+///
+/// A collapsed geometry becomes the `Point` at the center of its bounding rectangle. Empty
+/// geometries, points and geometries which aren't degenerate are unchanged. With a tolerance of
+/// zero, only geometries with exactly zero length or area collapse.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, CoerceDegenerate, Geometry};
+///
+/// let sliver = wkt!(POLYGON((0. 0.,4. 0.,2. 0.000001,0. 0.)));
+/// assert_eq!(
+///     sliver.coerce_degenerate(0.001),
+///     Geometry::LineString(wkt!(LINESTRING(0. 0.,4. 0.)))
+/// );
+///
+/// let speck = wkt!(LINESTRING(1. 1.,1. 1.));
+/// assert_eq!(speck.coerce_degenerate(0.), Geometry::Point(wkt!(POINT(1. 1.))));
+///
+/// let square = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)));
+/// assert_eq!(square.coerce_degenerate(0.001), Geometry::Polygon(square));
+/// ```
+pub trait CoerceDegenerate<T: GeoFloat> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T>;
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for Point<T> {
+    fn coerce_degenerate(&self, _tolerance: T) -> Geometry<T> {
+        Geometry::Point(*self)
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for Line<T> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T> {
+        if self.length(&Euclidean) <= tolerance {
+            Geometry::Point(center(self.bounding_rect()))
+        } else {
+            Geometry::Line(*self)
+        }
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for LineString<T> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T> {
+        match self.bounding_rect() {
+            Some(rect) if self.length(&Euclidean) <= tolerance => Geometry::Point(center(rect)),
+            _ => Geometry::LineString(self.clone()),
+        }
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for Polygon<T> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T> {
+        if let Some(collapsed) = collapse_ring(self.exterior(), self.unsigned_area(), tolerance) {
+            return collapsed;
+        }
+        let interiors: Vec<_> = self
+            .interiors()
+            .iter()
+            .filter(|ring| {
+                let area = Polygon::new((*ring).clone(), vec![]).unsigned_area();
+                collapse_ring(ring, area, tolerance).is_none()
+            })
+            .cloned()
+            .collect();
+        Geometry::Polygon(Polygon::new(self.exterior().clone(), interiors))
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for Triangle<T> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T> {
+        let polygon = self.to_polygon();
+        collapse_ring(polygon.exterior(), self.unsigned_area(), tolerance)
+            .unwrap_or(Geometry::Triangle(*self))
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for Rect<T> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T> {
+        let (min, max, center) = (self.min(), self.max(), self.center());
+        match (self.width() <= tolerance, self.height() <= tolerance) {
+            (true, true) => Geometry::Point(center.into()),
+            (true, false) => Geometry::Line(Line::new((center.x, min.y), (center.x, max.y))),
+            (false, true) => Geometry::Line(Line::new((min.x, center.y), (max.x, center.y))),
+            (false, false) => Geometry::Rect(*self),
+        }
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for MultiPoint<T> {
+    fn coerce_degenerate(&self, _tolerance: T) -> Geometry<T> {
+        Geometry::MultiPoint(self.clone())
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for MultiLineString<T> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T> {
+        if self.0.is_empty() {
+            return Geometry::MultiLineString(self.clone());
+        }
+        collect_members(
+            self.iter()
+                .map(|member| member.coerce_degenerate(tolerance)),
+        )
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for MultiPolygon<T> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T> {
+        if self.0.is_empty() {
+            return Geometry::MultiPolygon(self.clone());
+        }
+        collect_members(
+            self.iter()
+                .map(|member| member.coerce_degenerate(tolerance)),
+        )
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for GeometryCollection<T> {
+    fn coerce_degenerate(&self, tolerance: T) -> Geometry<T> {
+        Geometry::GeometryCollection(
+            self.iter()
+                .map(|member| member.coerce_degenerate(tolerance))
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> CoerceDegenerate<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn coerce_degenerate(&self, tolerance: T) -> Geometry<T>;
+    }
+}
+
+fn center<T: GeoFloat>(rect: Rect<T>) -> Point<T> {
+    rect.center().into()
+}
+
+// The geometry a ring of a polygon with `area` collapses to, or `None` if it doesn't
+fn collapse_ring<T: GeoFloat>(ring: &LineString<T>, area: T, tolerance: T) -> Option<Geometry<T>> {
+    let rect = ring.bounding_rect()?;
+    if area > tolerance * ring.length(&Euclidean) {
+        return None;
+    }
+    let diagonal = Line::new(rect.min(), rect.max());
+    if diagonal.length(&Euclidean) <= tolerance {
+        return Some(Geometry::Point(center(rect)));
+    }
+    // the extreme coordinates along the longer side of the bounding rectangle
+    let key = |coord: &&Coord<T>| {
+        if rect.width() >= rect.height() {
+            (coord.x, coord.y)
+        } else {
+            (coord.y, coord.x)
+        }
+    };
+    let compare =
+        |a: &&Coord<T>, b: &&Coord<T>| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal);
+    let start = ring.0.iter().min_by(compare)?;
+    let end = ring.0.iter().max_by(compare)?;
+    Some(Geometry::LineString(LineString::new(vec![*start, *end])))
+}
+
+// The multi-geometry of `members`, if they're all points, line strings or polygons, and
+// otherwise a geometry collection
+fn collect_members<T: GeoFloat>(members: impl Iterator<Item = Geometry<T>>) -> Geometry<T> {
+    let members: Vec<_> = members.collect();
+    if members
+        .iter()
+        .all(|member| matches!(member, Geometry::Point(_)))
+    {
+        let points = members
+            .into_iter()
+            .filter_map(|member| Point::try_from(member).ok());
+        return Geometry::MultiPoint(points.collect());
+    }
+    if members
+        .iter()
+        .all(|member| matches!(member, Geometry::LineString(_)))
+    {
+        let line_strings = members
+            .into_iter()
+            .filter_map(|member| LineString::try_from(member).ok());
+        return Geometry::MultiLineString(line_strings.collect());
+    }
+    if members
+        .iter()
+        .all(|member| matches!(member, Geometry::Polygon(_)))
+    {
+        let polygons = members
+            .into_iter()
+            .filter_map(|member| Polygon::try_from(member).ok());
+        return Geometry::MultiPolygon(polygons.collect());
+    }
+    Geometry::GeometryCollection(GeometryCollection::new_from(members))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn lines() {
+        let line = Line::new((0., 0.), (0., 0.5));
+        assert_eq!(line.coerce_degenerate(0.1), Geometry::Line(line));
+        assert_eq!(
+            line.coerce_degenerate(0.5),
+            Geometry::Point(wkt!(POINT(0. 0.25)))
+        );
+
+        let line_string = wkt!(LINESTRING(0. 0.,0.1 0.,0. 0.));
+        assert_eq!(
+            line_string.coerce_degenerate(0.2),
+            Geometry::Point(wkt!(POINT(0.05 0.)))
+        );
+        assert_eq!(
+            line_string.coerce_degenerate(0.1),
+            Geometry::LineString(line_string)
+        );
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.coerce_degenerate(1.), Geometry::LineString(empty));
+    }
+
+    #[test]
+    fn polygons() {
+        // a vertical sliver
+        let sliver = wkt!(POLYGON((0. 0.,0.01 5.,0. 10.,0. 0.)));
+        assert_eq!(
+            sliver.coerce_degenerate(0.01),
+            Geometry::LineString(wkt!(LINESTRING(0. 0.,0. 10.)))
+        );
+        assert_eq!(
+            sliver.coerce_degenerate(0.),
+            Geometry::Polygon(sliver.clone())
+        );
+        assert_eq!(
+            sliver.coerce_degenerate(20.),
+            Geometry::Point(wkt!(POINT(0.005 5.)))
+        );
+
+        // exactly zero area, with no tolerance
+        let flat = wkt!(POLYGON((0. 0.,2. 2.,1. 1.,0. 0.)));
+        assert_eq!(
+            flat.coerce_degenerate(0.),
+            Geometry::LineString(wkt!(LINESTRING(0. 0.,2. 2.)))
+        );
+
+        // a degenerate hole is removed
+        let polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (1. 1.,2. 1.,1.5 1.,1. 1.),
+            (5. 5.,6. 5.,6. 6.,5. 5.)
+        ));
+        assert_eq!(
+            polygon.coerce_degenerate(0.),
+            Geometry::Polygon(wkt!(POLYGON(
+                (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+                (5. 5.,6. 5.,6. 6.,5. 5.)
+            )))
+        );
+
+        let triangle = Triangle::from([(0., 0.), (1., 0.), (2., 0.)]);
+        assert_eq!(
+            triangle.coerce_degenerate(0.),
+            Geometry::LineString(wkt!(LINESTRING(0. 0.,2. 0.)))
+        );
+
+        let rect = Rect::new((0., 0.), (4., 0.));
+        assert_eq!(
+            rect.coerce_degenerate(0.),
+            Geometry::Line(Line::new((0., 0.), (4., 0.)))
+        );
+        assert_eq!(
+            Rect::new((1., 1.), (1., 1.)).coerce_degenerate(0.),
+            Geometry::Point(wkt!(POINT(1. 1.)))
+        );
+    }
+
+    #[test]
+    fn collections() {
+        let multi_polygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,1. 0.,0. 0.)),
+            ((5. 5.,5. 5.,5. 5.,5. 5.))
+        ));
+        assert_eq!(
+            multi_polygon.coerce_degenerate(0.),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                wkt!(LINESTRING(0. 0.,1. 0.)).into(),
+                wkt!(POINT(5. 5.)).into(),
+            ]))
+        );
+
+        let multi_line_string = wkt!(MULTILINESTRING((0. 0.,0. 0.),(1. 1.,1. 1.)));
+        assert_eq!(
+            multi_line_string.coerce_degenerate(0.),
+            Geometry::MultiPoint(wkt!(MULTIPOINT(0. 0.,1. 1.)))
+        );
+
+        let geometry: Geometry = multi_line_string.into();
+        assert_eq!(
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![geometry.clone()]))
+                .coerce_degenerate(0.),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                geometry.coerce_degenerate(0.)
+            ]))
+        );
+    }
+}
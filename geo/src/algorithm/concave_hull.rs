@@ -1,8 +1,9 @@
 use crate::convex_hull::qhull;
 use crate::utils::partial_min;
 use crate::{
-    coord, Centroid, Coord, CoordNum, Distance, Euclidean, GeoFloat, Length, Line, LineString,
-    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+    coord, Area, Centroid, Coord, CoordNum, CoordsIter, Distance, Euclidean, GeoFloat, Geometry,
+    GeometryCollection, Length, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon, Rect, Triangle,
 };
 use rstar::{RTree, RTreeNum};
 use std::collections::VecDeque;
@@ -44,6 +45,85 @@ use std::collections::VecDeque;
 pub trait ConcaveHull {
     type Scalar: CoordNum;
     fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar>;
+
+    /// Returns the concave hull, digging into the convex hull until the stop conditions of
+    /// `params` are met. See [`ConcaveHullParams`].
+    ///
+    /// `self.concave_hull(concavity)` is equivalent to
+    /// `self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use geo::{Area, ConcaveHull, ConcaveHullParams, ConvexHull, MultiPoint};
+    ///
+    /// let points: MultiPoint = vec![
+    ///     (0.0, 0.0),
+    ///     (10.0, 0.0),
+    ///     (10.0, 10.0),
+    ///     (0.0, 10.0),
+    ///     (5.0, 8.0),
+    ///     (5.0, 2.0),
+    /// ]
+    /// .into();
+    /// let convex_area = points.convex_hull().unsigned_area();
+    ///
+    /// // Keep at least 80% of the convex hull's area
+    /// let params = ConcaveHullParams {
+    ///     min_area_ratio: Some(0.8),
+    ///     ..Default::default()
+    /// };
+    /// let hull = points.concave_hull_with_params(params);
+    /// assert!(hull.unsigned_area() >= 0.8 * convex_area);
+    /// assert!(hull.unsigned_area() < convex_area);
+    ///
+    /// // Only dig into edges longer than 10
+    /// let params = ConcaveHullParams {
+    ///     max_edge_length: Some(10.0),
+    ///     ..Default::default()
+    /// };
+    /// let hull = points.concave_hull_with_params(params);
+    /// assert_eq!(hull.unsigned_area(), convex_area);
+    /// ```
+    fn concave_hull_with_params(
+        &self,
+        params: ConcaveHullParams<Self::Scalar>,
+    ) -> Polygon<Self::Scalar>;
+}
+
+/// The stop conditions of a concave hull, used by
+/// [`ConcaveHull::concave_hull_with_params`].
+///
+/// The concave hull starts from the convex hull, and repeatedly replaces an edge with two edges
+/// through the nearest remaining point, digging into the hull. An edge is kept once any of the
+/// conditions which are set rejects the dig. With no conditions set, the hull digs as deep as
+/// possible.
+///
+/// Different datasets suit different conditions: `concavity` adapts to the local density of
+/// points, while `max_edge_length` gives a uniform resolution, and `min_area_ratio` bounds how
+/// much of the convex hull is carved away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConcaveHullParams<T> {
+    /// A point is only dug in if the length of the edge, divided by the distance from the point
+    /// to the nearest end of the edge, is greater than `concavity`. Larger values give
+    /// smoother, more convex hulls.
+    pub concavity: Option<T>,
+    /// Edges no longer than `max_edge_length` aren't dug into.
+    pub max_edge_length: Option<T>,
+    /// A point isn't dug in if it would make the area of the hull less than `min_area_ratio`
+    /// times the area of the convex hull.
+    pub min_area_ratio: Option<T>,
+}
+
+impl<T> ConcaveHullParams<T> {
+    /// Parameters with only the `concavity` condition, as used by
+    /// [`ConcaveHull::concave_hull`].
+    pub fn with_concavity(concavity: T) -> Self {
+        Self {
+            concavity: Some(concavity),
+            max_edge_length: None,
+            min_area_ratio: None,
+        }
+    }
 }
 
 impl<T> ConcaveHull for Polygon<T>
@@ -52,8 +132,118 @@ where
 {
     type Scalar = T;
     fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar> {
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(
+        &self,
+        params: ConcaveHullParams<Self::Scalar>,
+    ) -> Polygon<Self::Scalar> {
         let mut points: Vec<_> = self.exterior().0.clone();
-        Polygon::new(concave_hull(&mut points, concavity), vec![])
+        Polygon::new(concave_hull_with_params(&mut points, &params), vec![])
+    }
+}
+
+impl<T> ConcaveHull for Rect<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Scalar = T;
+    fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar> {
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(
+        &self,
+        params: ConcaveHullParams<Self::Scalar>,
+    ) -> Polygon<Self::Scalar> {
+        let (min, max) = (self.min(), self.max());
+        // The closed ring of `Rect::to_polygon`
+        let mut corners = [
+            min,
+            coord! { x: min.x, y: max.y },
+            max,
+            coord! { x: max.x, y: min.y },
+            min,
+        ];
+        Polygon::new(concave_hull_with_params(&mut corners, &params), vec![])
+    }
+}
+
+impl<T> ConcaveHull for Triangle<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Scalar = T;
+    fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar> {
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(
+        &self,
+        params: ConcaveHullParams<Self::Scalar>,
+    ) -> Polygon<Self::Scalar> {
+        let mut corners = [self.0, self.1, self.2, self.0];
+        Polygon::new(concave_hull_with_params(&mut corners, &params), vec![])
+    }
+}
+
+impl<T> ConcaveHull for Geometry<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Scalar = T;
+    fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar> {
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(
+        &self,
+        params: ConcaveHullParams<Self::Scalar>,
+    ) -> Polygon<Self::Scalar> {
+        let mut coords = vec![];
+        push_hull_coords(self, &mut coords);
+        Polygon::new(concave_hull_with_params(&mut coords, &params), vec![])
+    }
+}
+
+impl<T> ConcaveHull for GeometryCollection<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Scalar = T;
+    fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar> {
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(
+        &self,
+        params: ConcaveHullParams<Self::Scalar>,
+    ) -> Polygon<Self::Scalar> {
+        let mut coords = vec![];
+        for geometry in self {
+            push_hull_coords(geometry, &mut coords);
+        }
+        Polygon::new(concave_hull_with_params(&mut coords, &params), vec![])
+    }
+}
+
+// Push the coordinates which the hull of `geometry` is built from: as for the other impls, only
+// the exteriors of polygons
+fn push_hull_coords<T: GeoFloat>(geometry: &Geometry<T>, coords: &mut Vec<Coord<T>>) {
+    match geometry {
+        Geometry::Polygon(polygon) => coords.extend(polygon.exterior().coords()),
+        Geometry::MultiPolygon(multi_polygon) => coords.extend(
+            multi_polygon
+                .iter()
+                .flat_map(|polygon| polygon.exterior().coords()),
+        ),
+        Geometry::GeometryCollection(collection) => {
+            for geometry in collection {
+                push_hull_coords(geometry, coords);
+            }
+        }
+        _ => coords.extend(geometry.coords_iter()),
     }
 }
 
@@ -63,12 +253,19 @@ where
 {
     type Scalar = T;
     fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar> {
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(
+        &self,
+        params: ConcaveHullParams<Self::Scalar>,
+    ) -> Polygon<Self::Scalar> {
         let mut aggregated: Vec<Coord<Self::Scalar>> = self
             .0
             .iter()
             .flat_map(|elem| elem.exterior().0.clone())
             .collect();
-        Polygon::new(concave_hull(&mut aggregated, concavity), vec![])
+        Polygon::new(concave_hull_with_params(&mut aggregated, &params), vec![])
     }
 }
 
@@ -78,7 +275,17 @@ where
 {
     type Scalar = T;
     fn concave_hull(&self, concavity: Self::Scalar) -> Polygon<Self::Scalar> {
-        Polygon::new(concave_hull(&mut self.0.clone(), concavity), vec![])
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(
+        &self,
+        params: ConcaveHullParams<Self::Scalar>,
+    ) -> Polygon<Self::Scalar> {
+        Polygon::new(
+            concave_hull_with_params(&mut self.0.clone(), &params),
+            vec![],
+        )
     }
 }
 
@@ -88,8 +295,12 @@ where
 {
     type Scalar = T;
     fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(&self, params: ConcaveHullParams<T>) -> Polygon<T> {
         let mut aggregated: Vec<Coord<T>> = self.iter().flat_map(|elem| elem.0.clone()).collect();
-        Polygon::new(concave_hull(&mut aggregated, concavity), vec![])
+        Polygon::new(concave_hull_with_params(&mut aggregated, &params), vec![])
     }
 }
 
@@ -99,8 +310,12 @@ where
 {
     type Scalar = T;
     fn concave_hull(&self, concavity: T) -> Polygon<T> {
+        self.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity))
+    }
+
+    fn concave_hull_with_params(&self, params: ConcaveHullParams<T>) -> Polygon<T> {
         let mut coordinates: Vec<Coord<T>> = self.iter().map(|point| point.0).collect();
-        Polygon::new(concave_hull(&mut coordinates, concavity), vec![])
+        Polygon::new(concave_hull_with_params(&mut coordinates, &params), vec![])
     }
 }
 
@@ -109,14 +324,14 @@ fn find_point_closest_to_line<T>(
     line: Line<T>,
     max_dist: T,
     edge_length: T,
-    concavity: T,
+    concavity: Option<T>,
     line_tree: &RTree<Line<T>>,
 ) -> Option<Coord<T>>
 where
     T: GeoFloat + RTreeNum,
 {
     let h = max_dist + max_dist;
-    let w = line.length::<Euclidean>() + h;
+    let w = line.length(&Euclidean) + h;
     let two = T::add(T::one(), T::one());
     let search_dist = T::div(T::sqrt(T::powi(w, 2) + T::powi(h, 2)), two);
     let centroid = line.centroid();
@@ -134,8 +349,8 @@ where
             let closest_point =
                 candidates.fold(Point::new(point.x, point.y), |acc_point, candidate| {
                     let candidate_point = Point::new(candidate.x, candidate.y);
-                    if Euclidean::distance(&line, &acc_point)
-                        > Euclidean::distance(&line, &candidate_point)
+                    if Euclidean.distance(&line, &acc_point)
+                        > Euclidean.distance(&line, &candidate_point)
                     {
                         candidate_point
                     } else {
@@ -154,8 +369,8 @@ where
             let closest_edge_option = match peeked_edge {
                 None => None,
                 Some(&edge) => Some(edges_nearby_point.fold(*edge, |acc, candidate| {
-                    if Euclidean::distance(&closest_point, &acc)
-                        > Euclidean::distance(&closest_point, candidate)
+                    if Euclidean.distance(&closest_point, &acc)
+                        > Euclidean.distance(&closest_point, candidate)
                     {
                         *candidate
                     } else {
@@ -164,11 +379,13 @@ where
                 })),
             };
             let decision_distance = partial_min(
-                Euclidean::distance(&closest_point, &line.start_point()),
-                Euclidean::distance(&closest_point, &line.end_point()),
+                Euclidean.distance(&closest_point, &line.start_point()),
+                Euclidean.distance(&closest_point, &line.end_point()),
             );
             if let Some(closest_edge) = closest_edge_option {
-                let far_enough = edge_length / decision_distance > concavity;
+                let far_enough = concavity.map_or(true, |concavity| {
+                    edge_length / decision_distance > concavity
+                });
                 let are_edges_equal = closest_edge == line;
                 if far_enough && are_edges_equal {
                     Some(coord! {
@@ -187,7 +404,18 @@ where
 
 // This takes significant inspiration from:
 // https://github.com/mapbox/concaveman/blob/54838e1/index.js#L11
+#[cfg(test)]
 fn concave_hull<T>(coords: &mut [Coord<T>], concavity: T) -> LineString<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    concave_hull_with_params(coords, &ConcaveHullParams::with_concavity(concavity))
+}
+
+fn concave_hull_with_params<T>(
+    coords: &mut [Coord<T>],
+    params: &ConcaveHullParams<T>,
+) -> LineString<T>
 where
     T: GeoFloat + RTreeNum,
 {
@@ -216,17 +444,43 @@ where
         line_queue.push_back(line);
         line_tree.insert(line);
     }
+
+    // The current area of the hull, and the area it must keep
+    let mut area = Polygon::new(hull, vec![]).unsigned_area();
+    let min_area = params.min_area_ratio.map(|ratio| ratio * area);
+
     while let Some(line) = line_queue.pop_front() {
-        let edge_length = line.length::<Euclidean>();
-        let dist = edge_length / concavity;
-        let possible_closest_point = find_point_closest_to_line(
-            &interior_points_tree,
-            line,
-            dist,
-            edge_length,
-            concavity,
-            &line_tree,
-        );
+        let edge_length = line.length(&Euclidean);
+        let too_short = params
+            .max_edge_length
+            .is_some_and(|max_edge_length| edge_length <= max_edge_length);
+        let possible_closest_point = if too_short {
+            None
+        } else {
+            let dist = params
+                .concavity
+                .map_or(T::infinity(), |concavity| edge_length / concavity);
+            find_point_closest_to_line(
+                &interior_points_tree,
+                line,
+                dist,
+                edge_length,
+                params.concavity,
+                &line_tree,
+            )
+        };
+        // Digging in a point removes the triangle it forms with the edge from the hull
+        let possible_closest_point = possible_closest_point.filter(|&closest_point| {
+            let Some(min_area) = min_area else {
+                return true;
+            };
+            let removed = Triangle::new(line.start, closest_point, line.end).unsigned_area();
+            if area - removed < min_area {
+                return false;
+            }
+            area = area - removed;
+            true
+        });
 
         if let Some(closest_point) = possible_closest_point {
             interior_points_tree.remove(&closest_point);
@@ -253,7 +507,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{line_string, polygon};
+    use crate::{line_string, polygon, wkt, ConvexHull};
     use geo_types::Coord;
 
     #[test]
@@ -298,6 +552,42 @@ mod test {
         assert_eq!(res, correct);
     }
 
+    #[test]
+    fn geometry_collection_test() {
+        let points = wkt!(MULTIPOINT(0.0 0.0,10.0 0.0,10.0 10.0,0.0 10.0,5.0 8.0));
+        let polygon = wkt!(POLYGON((4.0 1.0,6.0 1.0,5.0 2.0,4.0 1.0)));
+        let collection = GeometryCollection::new_from(vec![
+            points.clone().into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![polygon
+                .clone()
+                .into()])),
+        ]);
+        let mut all_points = points.0.clone();
+        all_points.extend(polygon.exterior().points());
+        let expected = MultiPoint::new(all_points).concave_hull(2.0);
+
+        assert_eq!(collection.concave_hull(2.0), expected);
+        assert_eq!(
+            Geometry::GeometryCollection(collection).concave_hull(2.0),
+            expected
+        );
+    }
+
+    #[test]
+    fn rect_and_triangle_test() {
+        let rect = Rect::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 4.0, y: 4.0 });
+        assert_eq!(rect.concave_hull(2.0), rect.to_polygon().concave_hull(2.0));
+        let triangle = Triangle::new(
+            coord! { x: 0.0, y: 0.0 },
+            coord! { x: 4.0, y: 0.0 },
+            coord! { x: 2.0, y: 2.0 },
+        );
+        assert_eq!(
+            triangle.concave_hull(2.0),
+            triangle.to_polygon().concave_hull(2.0)
+        );
+    }
+
     #[test]
     fn one_flex_test() {
         let mut v = vec![
@@ -446,4 +736,76 @@ mod test {
         ];
         assert_eq!(res.exterior().0, correct);
     }
+
+    #[test]
+    fn params_default_to_concavity() {
+        let poly: Polygon = crate::wkt!(POLYGON((0. 0.,1. 0.,2. 0.,3. 0.,3. 1.,3. 2.,3. 3.,2. 3.,1. 3.,0. 3.,0. 2.,1. 1.5,0. 1.,0. 0.)));
+        for concavity in [0.5, 1.0, 2.0, 4.0] {
+            assert_eq!(
+                poly.concave_hull_with_params(ConcaveHullParams::with_concavity(concavity)),
+                poly.concave_hull(concavity)
+            );
+        }
+    }
+
+    #[test]
+    fn max_edge_length_test() {
+        let points: MultiPoint = geo_test_fixtures::norway_main::<f64>()
+            .0
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        let convex_area = points.convex_hull().unsigned_area();
+        let mut previous_area = 0.0;
+        for max_edge_length in [0.05, 0.2, 1.0] {
+            let params = ConcaveHullParams {
+                max_edge_length: Some(max_edge_length),
+                ..Default::default()
+            };
+            let hull = points.concave_hull_with_params(params);
+            assert!(hull.unsigned_area() > previous_area);
+            assert!(hull.unsigned_area() <= convex_area);
+            previous_area = hull.unsigned_area();
+        }
+
+        // No edge of the convex hull is long enough to dig into
+        let params = ConcaveHullParams {
+            max_edge_length: Some(100.0),
+            ..Default::default()
+        };
+        let hull = points.concave_hull_with_params(params);
+        assert_eq!(hull.unsigned_area(), convex_area);
+    }
+
+    #[test]
+    fn min_area_ratio_test() {
+        let points: MultiPoint = geo_test_fixtures::norway_main::<f64>()
+            .0
+            .into_iter()
+            .map(Point::from)
+            .collect();
+        let convex_area = points.convex_hull().unsigned_area();
+        for ratio in [0.3, 0.6, 0.9] {
+            let params = ConcaveHullParams {
+                min_area_ratio: Some(ratio),
+                ..Default::default()
+            };
+            let hull = points.concave_hull_with_params(params);
+            let area = hull.unsigned_area();
+            assert!(area >= ratio * convex_area - 1e-9);
+            assert!(area < convex_area);
+            assert!(hull.exterior().is_closed());
+        }
+
+        // Combined with concavity, the hull is no deeper than with concavity alone
+        let concave_area = points.concave_hull(2.0).unsigned_area();
+        let params = ConcaveHullParams {
+            concavity: Some(2.0),
+            min_area_ratio: Some(0.99),
+            ..Default::default()
+        };
+        let area = points.concave_hull_with_params(params).unsigned_area();
+        assert!(area >= concave_area);
+        assert!(area >= 0.99 * convex_area - 1e-9);
+    }
 }
@@ -0,0 +1,366 @@
+use crate::{Coord, GeoFloat, Line, MultiPolygon, Polygon, Rect, Triangle};
+
+/// Which cells of a raster grid have their center inside a polygon, e.g. to mask a raster for
+/// rasterization or zonal statistics.
+///
+/// The grid covers `extent` with `width` columns and `height` rows of equal cells. Following
+/// the usual raster convention, row `0` is at the top of the extent, i.e. at its largest `y`,
+/// and column `0` is at its smallest `x`.
+///
+/// Rather than testing each cell with [`Contains`](crate::Contains), each row is filled between
+/// the crossings of the polygon's rings with the line through the centers of its cells, so the
+/// cost grows with the number of rows and edges, not with the number of cells. Overlapping parts
+/// of a `MultiPolygon` are filled by the even-odd rule, so should be unioned first.
+///
+/// A cell whose center lies on the boundary is in the mask if the polygon's interior is to the
+/// right of it, or, on a horizontal edge, above it. So where polygons share an edge, such a cell
+/// is in the mask of exactly one of them.
+///
+/// The mask is empty if `extent` has no area.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, wkt, ContainmentMask, Polygon, Rect};
+///
+/// let triangle: Polygon = wkt!(POLYGON((0. 0.,4.5 0.,0. 4.5,0. 0.)));
+/// let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+/// let mask = triangle.containment_mask(extent, 4, 4);
+///
+/// // the cell centers below the diagonal, starting from the top row
+/// assert_eq!(mask.count(), 10);
+/// assert!(mask.get(0, 0) && !mask.get(1, 0));
+/// assert!((0..4).all(|column| mask.get(column, 3)));
+/// ```
+pub trait ContainmentMask<T: GeoFloat> {
+    fn containment_mask(&self, extent: Rect<T>, width: usize, height: usize) -> GridMask;
+}
+
+impl<T: GeoFloat> ContainmentMask<T> for Polygon<T> {
+    fn containment_mask(&self, extent: Rect<T>, width: usize, height: usize) -> GridMask {
+        let rings = std::iter::once(self.exterior()).chain(self.interiors());
+        scanline_fill(rings.flat_map(|ring| ring.lines()), extent, width, height)
+    }
+}
+
+impl<T: GeoFloat> ContainmentMask<T> for MultiPolygon<T> {
+    fn containment_mask(&self, extent: Rect<T>, width: usize, height: usize) -> GridMask {
+        let rings = self
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()));
+        scanline_fill(rings.flat_map(|ring| ring.lines()), extent, width, height)
+    }
+}
+
+impl<T: GeoFloat> ContainmentMask<T> for Rect<T> {
+    fn containment_mask(&self, extent: Rect<T>, width: usize, height: usize) -> GridMask {
+        scanline_fill(self.to_lines().into_iter(), extent, width, height)
+    }
+}
+
+impl<T: GeoFloat> ContainmentMask<T> for Triangle<T> {
+    fn containment_mask(&self, extent: Rect<T>, width: usize, height: usize) -> GridMask {
+        scanline_fill(self.to_lines().into_iter(), extent, width, height)
+    }
+}
+
+/// A grid of cells which are each in or out of a mask, stored as a bitset in row-major order.
+///
+/// See [`ContainmentMask`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridMask {
+    width: usize,
+    height: usize,
+    words: Vec<u64>,
+}
+
+impl GridMask {
+    /// A mask with no cells in it.
+    pub fn new(width: usize, height: usize) -> Self {
+        let cells = width.checked_mul(height).expect("too many cells");
+        Self {
+            width,
+            height,
+            words: vec![0; cells.div_ceil(64)],
+        }
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the cell in `column` and `row` is in the mask.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn get(&self, column: usize, row: usize) -> bool {
+        assert!(
+            column < self.width && row < self.height,
+            "cell out of bounds"
+        );
+        let idx = row * self.width + column;
+        self.words[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    /// Adds or removes the cell in `column` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn set(&mut self, column: usize, row: usize, value: bool) {
+        assert!(
+            column < self.width && row < self.height,
+            "cell out of bounds"
+        );
+        let idx = row * self.width + column;
+        if value {
+            self.words[idx / 64] |= 1 << (idx % 64);
+        } else {
+            self.words[idx / 64] &= !(1 << (idx % 64));
+        }
+    }
+
+    /// The number of cells in the mask.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The `(column, row)` of each cell in the mask, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(word_idx, &word)| {
+                let mut word = word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    let idx = word_idx * 64 + bit;
+                    Some((idx % self.width, idx / self.width))
+                })
+            })
+    }
+
+    /// The bits of the mask, 64 cells per word, in row-major order from the least significant
+    /// bit. Bits past the last cell are zero.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Adds the cells `start..end` of `row`.
+    fn fill(&mut self, row: usize, start: usize, end: usize) {
+        let (mut idx, end) = (row * self.width + start, row * self.width + end);
+        while idx < end {
+            let bit = idx % 64;
+            let bits = (64 - bit).min(end - idx);
+            let mask = if bits == 64 {
+                u64::MAX
+            } else {
+                ((1 << bits) - 1) << bit
+            };
+            self.words[idx / 64] |= mask;
+            idx += bits;
+        }
+    }
+}
+
+/// An edge of a ring, with the range of rows whose cell centers may cross it.
+struct Edge<T: GeoFloat> {
+    start: Coord<T>,
+    end: Coord<T>,
+    first_row: usize,
+    last_row: usize,
+}
+
+fn scanline_fill<T: GeoFloat>(
+    lines: impl Iterator<Item = Line<T>>,
+    extent: Rect<T>,
+    width: usize,
+    height: usize,
+) -> GridMask {
+    let mut mask = GridMask::new(width, height);
+    if width == 0 || height == 0 || extent.width() <= T::zero() || extent.height() <= T::zero() {
+        return mask;
+    }
+    let cell_width = extent.width() / T::from(width).unwrap();
+    let cell_height = extent.height() / T::from(height).unwrap();
+    let half = T::from(0.5).unwrap();
+    let (min_x, max_y) = (extent.min().x, extent.max().y);
+    let row_y = |row: usize| max_y - (T::from(row).unwrap() + half) * cell_height;
+    // A whole number of rows or columns as an index, clamped to `0..=max`
+    let to_index = |value: T, max: usize| -> usize {
+        if value <= T::zero() {
+            0
+        } else {
+            value.to_usize().unwrap_or(max).min(max)
+        }
+    };
+
+    let mut edges: Vec<Edge<T>> = lines
+        .filter(|line| line.start.y != line.end.y)
+        .filter_map(|line| {
+            let (low, high) = if line.start.y < line.end.y {
+                (line.start.y, line.end.y)
+            } else {
+                (line.end.y, line.start.y)
+            };
+            // A conservative range of rows; the crossings are checked exactly below
+            let first_row = to_index(((max_y - high) / cell_height - half).floor(), height);
+            let last_row = to_index(((max_y - low) / cell_height - half).ceil(), height - 1);
+            (first_row < height && first_row <= last_row).then_some(Edge {
+                start: line.start,
+                end: line.end,
+                first_row,
+                last_row,
+            })
+        })
+        .collect();
+    edges.sort_by_key(|edge| std::cmp::Reverse(edge.first_row));
+
+    // The first column whose center is at or after `x`
+    let column_at = |x: T| to_index(((x - min_x) / cell_width - half).ceil(), width);
+
+    let mut active: Vec<Edge<T>> = Vec::new();
+    let mut crossings: Vec<T> = Vec::new();
+    for row in 0..height {
+        active.retain(|edge| edge.last_row >= row);
+        while edges.last().is_some_and(|edge| edge.first_row == row) {
+            active.push(edges.pop().expect("edges isn't empty"));
+        }
+        let y = row_y(row);
+        crossings.clear();
+        crossings.extend(
+            active
+                .iter()
+                // Each crossing counts once, even at a vertex shared by two edges
+                .filter(|edge| (edge.start.y <= y) != (edge.end.y <= y))
+                .map(|edge| {
+                    edge.start.x
+                        + (y - edge.start.y) * (edge.end.x - edge.start.x)
+                            / (edge.end.y - edge.start.y)
+                }),
+        );
+        crossings.sort_by(|a, b| a.total_cmp(b));
+
+        for span in crossings.chunks_exact(2) {
+            let (start, end) = (column_at(span[0]), column_at(span[1]));
+            if start < end {
+                mask.fill(row, start, end);
+            }
+        }
+    }
+    mask
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, wkt, Contains, Point};
+
+    #[test]
+    fn matches_contains() {
+        let polygon: Polygon = wkt!(POLYGON(
+            (0.13 0.21,9.77 1.03,8.41 9.62,4.99 5.07,1.02 8.88,0.13 0.21),
+            (2.11 2.04,6.93 2.48,3.37 4.16,2.11 2.04)
+        ));
+        let extent = Rect::new(coord! { x: -1., y: -1. }, coord! { x: 11., y: 10. });
+        let (width, height) = (97, 83);
+        let mask = polygon.containment_mask(extent, width, height);
+        let mut count = 0;
+        for row in 0..height {
+            for column in 0..width {
+                let center = Point::new(
+                    -1. + (column as f64 + 0.5) * 12. / width as f64,
+                    10. - (row as f64 + 0.5) * 11. / height as f64,
+                );
+                assert_eq!(
+                    mask.get(column, row),
+                    polygon.contains(&center),
+                    "{column} {row}"
+                );
+                count += polygon.contains(&center) as usize;
+            }
+        }
+        assert_eq!(mask.count(), count);
+        assert_eq!(mask.iter().count(), count);
+        assert!(mask.iter().all(|(column, row)| mask.get(column, row)));
+    }
+
+    #[test]
+    fn shared_edges() {
+        // two squares sharing an edge through the centers of a column and a row of cells
+        let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 4. });
+        let left: Polygon = wkt!(POLYGON((0. 0.,1.5 0.,1.5 2.5,0. 2.5,0. 0.)));
+        let right: Polygon = wkt!(POLYGON((1.5 0.,4. 0.,4. 2.5,1.5 2.5,1.5 0.)));
+        let above: Polygon = wkt!(POLYGON((0. 2.5,4. 2.5,4. 4.,0. 4.,0. 2.5)));
+        let masks = [left, right, above].map(|polygon| polygon.containment_mask(extent, 4, 4));
+        for row in 0..4 {
+            for column in 0..4 {
+                let containing = masks.iter().filter(|mask| mask.get(column, row)).count();
+                assert_eq!(containing, 1, "{column} {row}");
+            }
+        }
+
+        let multi_polygon = MultiPolygon::new(vec![
+            wkt!(POLYGON((0. 0.,1.5 0.,1.5 2.5,0. 2.5,0. 0.))),
+            wkt!(POLYGON((1.5 0.,4. 0.,4. 2.5,1.5 2.5,1.5 0.))),
+        ]);
+        // the cells whose centers are on the top edge are above the polygons
+        assert_eq!(multi_polygon.containment_mask(extent, 4, 4).count(), 8);
+    }
+
+    #[test]
+    fn edge_cases() {
+        let polygon: Polygon = wkt!(POLYGON((-10. -10.,10. -10.,10. 10.,-10. 10.,-10. -10.)));
+        let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
+        // the polygon covers the whole extent, across word boundaries
+        let mask = polygon.containment_mask(extent, 100, 3);
+        assert_eq!(mask.count(), 300);
+        assert_eq!(mask.as_words().len(), 5);
+        assert_eq!(mask.as_words()[4], (1 << 44) - 1);
+
+        assert_eq!(polygon.containment_mask(extent, 0, 5).count(), 0);
+        let flat = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 0. });
+        assert_eq!(polygon.containment_mask(flat, 3, 3).count(), 0);
+        let outside = Rect::new(coord! { x: 20., y: 20. }, coord! { x: 30., y: 30. });
+        assert_eq!(polygon.containment_mask(outside, 3, 3).count(), 0);
+
+        let mut mask = GridMask::new(3, 2);
+        mask.set(2, 1, true);
+        assert_eq!(mask.iter().collect::<Vec<_>>(), vec![(2, 1)]);
+        mask.set(2, 1, false);
+        assert_eq!(mask, GridMask::new(3, 2));
+    }
+
+    #[test]
+    fn rect_and_triangle() {
+        let extent = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 10. });
+        let rect = Rect::new(coord! { x: 1.5, y: 2.5 }, coord! { x: 7., y: 4. });
+        let mask = rect.containment_mask(extent, 10, 10);
+        assert_eq!(mask, rect.to_polygon().containment_mask(extent, 10, 10));
+        assert_eq!(mask.count(), 12);
+
+        let triangle = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 9., y: 0. },
+            coord! { x: 0., y: 9. },
+        );
+        assert_eq!(
+            triangle.containment_mask(extent, 10, 10),
+            triangle.to_polygon().containment_mask(extent, 10, 10)
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Coord, CoordNum, MapCoords};
+use crate::{Coord, CoordFloat, CoordNum, MapCoords};
+use std::cell::Cell;
+use std::fmt;
 
 /// Convert (infalliby) the type of a geometry’s coordinate value.
 ///
@@ -73,3 +75,230 @@ where
         })
     }
 }
+
+/// How [`TryConvertWith`] rounds coordinate values with a fractional part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingPolicy {
+    /// Round towards zero, like an `as` cast: `2.7` becomes `2` and `-2.7` becomes `-2`.
+    Truncate,
+    /// Round to the nearest integer, and ties to the even one: `2.5` becomes `2` and `3.5`
+    /// becomes `4`, so that rounding many values doesn't bias them upwards.
+    RoundHalfEven,
+    /// Fail with [`ConvertErrorKind::Fractional`] unless the value is an integer.
+    Exact,
+}
+
+/// Why a coordinate value couldn't be converted by [`TryConvertWith`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConvertErrorKind {
+    /// The value has a fractional part, and the policy is [`RoundingPolicy::Exact`].
+    Fractional,
+    /// The (rounded) value doesn't fit in the target type, or isn't finite.
+    Overflow,
+}
+
+/// The error returned by [`TryConvertWith`], locating the first coordinate which couldn't be
+/// converted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConvertError<T: CoordNum> {
+    /// The position of the coordinate among those of the geometry, in the order
+    /// [`MapCoords`] visits them. This is the order of
+    /// [`CoordsIter::coords_iter`](crate::CoordsIter::coords_iter), except that a `Rect` is
+    /// converted by its min and max coordinates only.
+    pub index: usize,
+    /// The coordinate which couldn't be converted.
+    pub coord: Coord<T>,
+    /// Whether the `x` (`true`) or the `y` (`false`) value of the coordinate failed.
+    pub is_x: bool,
+    /// Why the value couldn't be converted.
+    pub kind: ConvertErrorKind,
+}
+
+impl<T: CoordNum + fmt::Display> fmt::Display for ConvertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (axis, value) = if self.is_x {
+            ("x", self.coord.x)
+        } else {
+            ("y", self.coord.y)
+        };
+        let problem = match self.kind {
+            ConvertErrorKind::Fractional => "has a fractional part",
+            ConvertErrorKind::Overflow => "is out of range of the target type",
+        };
+        write!(
+            f,
+            "{axis} value {value} of coordinate {} {problem}",
+            self.index
+        )
+    }
+}
+
+impl<T: CoordNum + fmt::Debug + fmt::Display> std::error::Error for ConvertError<T> {}
+
+/// Convert (fallibly) the floating point coordinate values of a geometry to another numeric
+/// type, usually an integer type, rounding them by a [`RoundingPolicy`].
+///
+/// Unlike [`TryConvert`], which relies on `TryFrom` and so isn't available from floats to
+/// integers, this makes rounding explicit. A value which doesn't fit in the target type after
+/// rounding, or isn't finite, always fails rather than saturating.
+///
+/// # Examples
+///
+/// ```rust
+/// use geo::{line_string, ConvertErrorKind, LineString, RoundingPolicy, TryConvertWith};
+///
+/// let line_string: LineString<f64> = line_string![
+///     (x: 5.5, y: 10.),
+///     (x: -3.7, y: 1e10),
+/// ];
+///
+/// let rounded: LineString<i64> = line_string
+///     .try_convert_with(RoundingPolicy::RoundHalfEven)
+///     .unwrap();
+/// assert_eq!(rounded, line_string![(x: 6, y: 10), (x: -4, y: 10_000_000_000)]);
+///
+/// let truncated: Result<LineString<i32>, _> = line_string.try_convert_with(RoundingPolicy::Truncate);
+/// let error = truncated.unwrap_err();
+/// assert_eq!((error.index, error.is_x), (1, false));
+/// assert_eq!(error.kind, ConvertErrorKind::Overflow);
+/// ```
+pub trait TryConvertWith<T: CoordNum, U> {
+    type Output;
+
+    fn try_convert_with(&self, policy: RoundingPolicy) -> Result<Self::Output, ConvertError<T>>;
+}
+impl<G, T: CoordFloat, U: CoordNum> TryConvertWith<T, U> for G
+where
+    G: MapCoords<T, U>,
+{
+    type Output = <Self as MapCoords<T, U>>::Output;
+
+    fn try_convert_with(&self, policy: RoundingPolicy) -> Result<Self::Output, ConvertError<T>> {
+        let index = Cell::new(0);
+        self.try_map_coords(|coord: Coord<T>| {
+            let position = index.get();
+            index.set(position + 1);
+            let error = |is_x, kind| ConvertError {
+                index: position,
+                coord,
+                is_x,
+                kind,
+            };
+            Ok(Coord {
+                x: convert_value(coord.x, policy).map_err(|kind| error(true, kind))?,
+                y: convert_value(coord.y, policy).map_err(|kind| error(false, kind))?,
+            })
+        })
+    }
+}
+
+fn convert_value<T: CoordFloat, U: CoordNum>(
+    value: T,
+    policy: RoundingPolicy,
+) -> Result<U, ConvertErrorKind> {
+    if !value.is_finite() {
+        return Err(ConvertErrorKind::Overflow);
+    }
+    let rounded = match policy {
+        RoundingPolicy::Truncate => value.trunc(),
+        RoundingPolicy::RoundHalfEven => {
+            let floor = value.floor();
+            let half = T::one() / (T::one() + T::one());
+            match (value - floor).partial_cmp(&half) {
+                Some(std::cmp::Ordering::Less) => floor,
+                Some(std::cmp::Ordering::Equal) if (floor * half).fract().is_zero() => floor,
+                _ => floor + T::one(),
+            }
+        }
+        RoundingPolicy::Exact if value.fract().is_zero() => value,
+        RoundingPolicy::Exact => return Err(ConvertErrorKind::Fractional),
+    };
+    U::from(rounded).ok_or(ConvertErrorKind::Overflow)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Polygon};
+
+    #[test]
+    fn rounding_policies() {
+        let values = [2.5, 3.5, -2.5, -3.5, 2.7, -2.7, 4.0, 0.49999999999999994];
+        let convert = |policy| -> Vec<i32> {
+            values
+                .iter()
+                .map(|&value| convert_value(value, policy).unwrap_or(i32::MIN))
+                .collect()
+        };
+        assert_eq!(
+            convert(RoundingPolicy::Truncate),
+            [2, 3, -2, -3, 2, -2, 4, 0]
+        );
+        assert_eq!(
+            convert(RoundingPolicy::RoundHalfEven),
+            [2, 4, -2, -4, 3, -3, 4, 0]
+        );
+        assert_eq!(
+            convert(RoundingPolicy::Exact),
+            [
+                i32::MIN,
+                i32::MIN,
+                i32::MIN,
+                i32::MIN,
+                i32::MIN,
+                i32::MIN,
+                4,
+                i32::MIN
+            ]
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        for value in [2147483648.0, -2147483649.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                convert_value::<f64, i32>(value, RoundingPolicy::Truncate),
+                Err(ConvertErrorKind::Overflow)
+            );
+        }
+        // truncated into range
+        assert_eq!(
+            convert_value::<f64, i32>(2147483647.9, RoundingPolicy::Truncate),
+            Ok(i32::MAX)
+        );
+        assert_eq!(
+            convert_value::<f64, i32>(-2147483648.5, RoundingPolicy::RoundHalfEven),
+            Ok(i32::MIN)
+        );
+        assert_eq!(
+            convert_value::<f64, u8>(-0.5, RoundingPolicy::RoundHalfEven),
+            Ok(0)
+        );
+        assert_eq!(
+            convert_value::<f64, u8>(-1.0, RoundingPolicy::RoundHalfEven),
+            Err(ConvertErrorKind::Overflow)
+        );
+    }
+
+    #[test]
+    fn error_position() {
+        let polygon: Polygon<f64> = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.5), (x: 2., y: 2.), (x: 1., y: 1.)]],
+        );
+        let converted: Result<Polygon<i32>, _> = polygon.try_convert_with(RoundingPolicy::Exact);
+        let error = converted.unwrap_err();
+        assert_eq!(error.index, 5);
+        assert_eq!(error.coord, Coord { x: 2., y: 1.5 });
+        assert!(!error.is_x);
+        assert_eq!(error.kind, ConvertErrorKind::Fractional);
+        assert_eq!(
+            error.to_string(),
+            "y value 1.5 of coordinate 5 has a fractional part"
+        );
+
+        let converted: Result<Polygon<i32>, _> =
+            polygon.try_convert_with(RoundingPolicy::RoundHalfEven);
+        assert_eq!(converted.unwrap().interiors()[0].0[1], Coord { x: 2, y: 2 });
+    }
+}
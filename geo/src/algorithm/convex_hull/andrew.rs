@@ -0,0 +1,134 @@
+use super::trivial_hull;
+use crate::kernels::*;
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoNum, LineString};
+
+/// [Andrew's monotone chain] algorithm to compute the strict
+/// convex hull of a collection of points, i.e. without
+/// collinear points.
+///
+/// The points are sorted lexicographically, and the lower
+/// and upper hulls are built by scanning them in order and
+/// backtracking at every clockwise or collinear turn. Only
+/// the exact [`Kernel::orient2d`] predicate and coordinate
+/// comparisons are used, so the hull is robust to any
+/// floating point input, and takes `O(n log n)` time
+/// whatever the distribution of the points.
+///
+/// The output is counter-clockwise, starting from the
+/// lexicographically least point.
+///
+/// # References
+///
+/// Andrew, A. M. (1979). "Another efficient algorithm for
+/// convex hulls in two dimensions". Information Processing
+/// Letters. 9 (5): 216–219.
+/// [doi:10.1016/0020-0190(79)90072-3](https://doi.org/10.1016/0020-0190(79)90072-3)
+///
+/// [Andrew's monotone chain]: //en.wikibooks.org/wiki/Algorithm_Implementation/Geometry/Convex_hull/Monotone_chain
+pub fn monotone_chain_hull<T>(points: &mut [Coord<T>]) -> LineString<T>
+where
+    T: GeoNum,
+{
+    if points.len() < 4 {
+        return trivial_hull(points, false);
+    }
+    points.sort_unstable_by(lex_cmp);
+
+    let mut output: Vec<Coord<T>> = Vec::new();
+    // Lower hull, from the least to the greatest point
+    for pt in points.iter() {
+        push_convex(&mut output, *pt, 1);
+    }
+    // Upper hull, back to the least point, which closes
+    // the output
+    let lower_len = output.len();
+    for pt in points.iter().rev().skip(1) {
+        push_convex(&mut output, *pt, lower_len);
+    }
+
+    // All the points are the same
+    if output.len() < 3 {
+        return trivial_hull(&mut [points[0]], false);
+    }
+    LineString::new(output)
+}
+
+// Push `pt` onto `output`, first removing the points which
+// would make a clockwise or collinear turn, but keeping the
+// first `keep` points.
+fn push_convex<T: GeoNum>(output: &mut Vec<Coord<T>>, pt: Coord<T>, keep: usize) {
+    while output.len() > keep {
+        let len = output.len();
+        match T::Ker::orient2d(output[len - 2], output[len - 1], pt) {
+            Orientation::CounterClockwise => break,
+            Orientation::Clockwise | Orientation::Collinear => {
+                output.pop();
+            }
+        }
+    }
+    if output.last() != Some(&pt) {
+        output.push(pt);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::IsConvex;
+
+    #[test]
+    fn monotone_chain_hull_convexity() {
+        for mut points in [
+            geo_test_fixtures::poly1::<f64>().0,
+            geo_test_fixtures::poly2::<f64>().0,
+            geo_test_fixtures::norway_main::<f64>().0,
+        ] {
+            let hull = monotone_chain_hull(&mut points);
+            assert!(hull.is_strictly_ccw_convex());
+        }
+    }
+
+    #[test]
+    fn monotone_chain_hull_degenerate() {
+        // collinear and repeated points
+        let mut points: Vec<Coord<i64>> = vec![
+            (0, 0).into(),
+            (2, 2).into(),
+            (1, 1).into(),
+            (2, 2).into(),
+            (3, 3).into(),
+        ];
+        assert_eq!(
+            monotone_chain_hull(&mut points).0,
+            vec![(0, 0).into(), (3, 3).into(), (0, 0).into()]
+        );
+        let mut points: Vec<Coord<i64>> = vec![(1, 1).into(); 5];
+        assert_eq!(
+            monotone_chain_hull(&mut points).0,
+            vec![(1, 1).into(), (1, 1).into()]
+        );
+        // a square, with points along its edges
+        let mut points: Vec<Coord<i64>> = vec![
+            (0, 0).into(),
+            (1, 0).into(),
+            (2, 0).into(),
+            (2, 1).into(),
+            (2, 2).into(),
+            (1, 2).into(),
+            (0, 2).into(),
+            (0, 1).into(),
+            (1, 1).into(),
+        ];
+        assert_eq!(
+            monotone_chain_hull(&mut points).0,
+            vec![
+                (0, 0).into(),
+                (2, 0).into(),
+                (2, 2).into(),
+                (0, 2).into(),
+                (0, 0).into()
+            ]
+        );
+    }
+}
@@ -42,8 +42,64 @@ use crate::GeoNum;
 pub trait ConvexHull<'a, T> {
     type Scalar: GeoNum;
     fn convex_hull(&'a self) -> Polygon<Self::Scalar>;
+
+    /// Returns the convex hull of a geometry, computed with the given algorithm. The hull is
+    /// the same whatever the algorithm, except that it may start at a different point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{wkt, ConvexHull, ConvexHullStrategy};
+    ///
+    /// let points = wkt!(MULTIPOINT(0. 0.,4. 0.,2. 1.,4. 4.,0. 4.,1. 3.));
+    /// let hull = points.convex_hull_with_strategy(ConvexHullStrategy::MonotoneChain);
+    /// assert_eq!(hull, wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))));
+    /// ```
+    fn convex_hull_with_strategy(&'a self, strategy: ConvexHullStrategy) -> Polygon<Self::Scalar>
+    where
+        Self::Scalar: Send + Sync;
+}
+
+/// The algorithm used by [`ConvexHull::convex_hull_with_strategy`].
+///
+/// All the algorithms decide which side of a line a point is on with the exact
+/// [`Kernel::orient2d`] predicate, so they find the same strict convex hull, without collinear
+/// points, for any input. They differ in speed:
+///
+/// - [`QuickHull`](Self::QuickHull) is quickest for most inputs, as it discards the points
+///   inside the hull early. Its worst case is `O(n²)`, when most of the points are on the hull,
+///   e.g. around a circle. It picks the point furthest from each edge with inexact arithmetic,
+///   which may pick a nearly furthest point instead, but doesn't change the hull.
+/// - [`MonotoneChain`](Self::MonotoneChain), [Andrew's algorithm](monotone_chain_hull), sorts
+///   the points, so takes `O(n log n)` time whatever the input, and only compares coordinates
+///   besides the orientation predicate.
+/// - [`Graham`](Self::Graham), [Graham's scan](graham_hull), also takes `O(n log n)`, sorting
+///   the points by angle.
+/// - `ParallelQuickHull` is [`QuickHull`](Self::QuickHull) with
+///   its recursion running on the rayon thread pool, which is quicker for point clouds of tens
+///   of thousands of points or more. It requires the `multithreading` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvexHullStrategy {
+    /// `ParallelQuickHull` for large inputs, if the `multithreading` feature is enabled, and
+    /// otherwise [`QuickHull`](Self::QuickHull).
+    #[default]
+    Auto,
+    /// [`quick_hull`], as used by [`ConvexHull::convex_hull`].
+    QuickHull,
+    /// [`monotone_chain_hull`].
+    MonotoneChain,
+    /// [`graham_hull`], excluding collinear points.
+    Graham,
+    /// [`par_quick_hull`].
+    #[cfg(feature = "multithreading")]
+    ParallelQuickHull,
 }
 
+/// Inputs of at least this many points use the parallel QuickHull with
+/// [`ConvexHullStrategy::Auto`].
+#[cfg(feature = "multithreading")]
+const AUTO_PARALLEL_MIN_POINTS: usize = 100_000;
+
 use crate::algorithm::CoordsIter;
 use crate::utils::lex_cmp;
 
@@ -58,11 +114,35 @@ where
         let mut exterior: Vec<_> = self.exterior_coords_iter().collect();
         Polygon::new(quick_hull(&mut exterior), vec![])
     }
+
+    fn convex_hull_with_strategy(&'a self, strategy: ConvexHullStrategy) -> Polygon<T>
+    where
+        T: Send + Sync,
+    {
+        let mut exterior: Vec<_> = self.exterior_coords_iter().collect();
+        let hull = match strategy {
+            #[cfg(feature = "multithreading")]
+            ConvexHullStrategy::Auto if exterior.len() >= AUTO_PARALLEL_MIN_POINTS => {
+                par_quick_hull(&mut exterior)
+            }
+            ConvexHullStrategy::Auto | ConvexHullStrategy::QuickHull => quick_hull(&mut exterior),
+            ConvexHullStrategy::MonotoneChain => monotone_chain_hull(&mut exterior),
+            ConvexHullStrategy::Graham => graham_hull(&mut exterior, false),
+            #[cfg(feature = "multithreading")]
+            ConvexHullStrategy::ParallelQuickHull => par_quick_hull(&mut exterior),
+        };
+        Polygon::new(hull, vec![])
+    }
 }
 
 pub mod qhull;
+#[cfg(feature = "multithreading")]
+pub use qhull::par_quick_hull;
 pub use qhull::quick_hull;
 
+pub mod andrew;
+pub use andrew::monotone_chain_hull;
+
 pub mod graham;
 pub use graham::graham_hull;
 
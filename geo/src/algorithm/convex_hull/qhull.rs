@@ -18,9 +18,40 @@ where
 }
 
 // Adapted from https://web.archive.org/web/20180409175413/http://www.ahristov.com/tutorial/geometry-games/convex-hull.html
-pub fn quick_hull<T>(mut points: &mut [Coord<T>]) -> LineString<T>
+pub fn quick_hull<T>(points: &mut [Coord<T>]) -> LineString<T>
 where
     T: GeoNum,
+{
+    quick_hull_by(points, hull_set)
+}
+
+/// The QuickHull algorithm, as in [`quick_hull`], with the
+/// recursion over large subsets of the points running in
+/// parallel on the rayon thread pool. The output is the same
+/// as that of [`quick_hull`], except that of several points
+/// which are equally far from a hull edge, a different one
+/// may be found first, so the hull may start at a different
+/// point.
+///
+/// This pays off for large point clouds, of at least tens
+/// of thousands of points. Requires the `multithreading`
+/// feature.
+#[cfg(feature = "multithreading")]
+pub fn par_quick_hull<T>(points: &mut [Coord<T>]) -> LineString<T>
+where
+    T: GeoNum + Send + Sync,
+{
+    quick_hull_by(points, par_hull_set)
+}
+
+/// Subsets of at least this many points are split in parallel by [`par_quick_hull`].
+#[cfg(feature = "multithreading")]
+const PARALLEL_MIN_POINTS: usize = 1 << 14;
+
+fn quick_hull_by<T, F>(mut points: &mut [Coord<T>], hull_set: F) -> LineString<T>
+where
+    T: GeoNum,
+    F: Fn(Coord<T>, Coord<T>, &mut [Coord<T>], &mut Vec<Coord<T>>),
 {
     // can't build a hull from fewer than four points
     if points.len() < 4 {
@@ -75,6 +106,50 @@ where
         return;
     }
 
+    let furthest_idx = furthest_index(p_a, p_b, set);
+
+    // move Coord at furthest_point from set into hull
+    let furthest_point = swap_with_first_and_remove(&mut set, furthest_idx);
+    // points over PB
+    {
+        let (points, _) = partition_slice(set, |p| is_ccw(*furthest_point, p_b, *p));
+        hull_set(*furthest_point, p_b, points, hull);
+    }
+    hull.push(*furthest_point);
+    // points over AP
+    let (points, _) = partition_slice(set, |p| is_ccw(p_a, *furthest_point, *p));
+    hull_set(p_a, *furthest_point, points, hull);
+}
+
+/// As [`hull_set`], but splitting large subsets in parallel.
+#[cfg(feature = "multithreading")]
+fn par_hull_set<T>(p_a: Coord<T>, p_b: Coord<T>, mut set: &mut [Coord<T>], hull: &mut Vec<Coord<T>>)
+where
+    T: GeoNum + Send + Sync,
+{
+    if set.len() < PARALLEL_MIN_POINTS {
+        return hull_set(p_a, p_b, set, hull);
+    }
+
+    let furthest_idx = furthest_index(p_a, p_b, set);
+    let furthest_point = *swap_with_first_and_remove(&mut set, furthest_idx);
+    // The points over PB and over AP are disjoint, so can be
+    // split into separate slices
+    let (over_pb, rest) = partition_slice(set, |p| is_ccw(furthest_point, p_b, *p));
+    let (over_ap, _) = partition_slice(rest, |p| is_ccw(p_a, furthest_point, *p));
+    let (mut hull_pb, mut hull_ap) = (vec![], vec![]);
+    rayon::join(
+        || par_hull_set(furthest_point, p_b, over_pb, &mut hull_pb),
+        || par_hull_set(p_a, furthest_point, over_ap, &mut hull_ap),
+    );
+    hull.extend(hull_pb);
+    hull.push(furthest_point);
+    hull.extend(hull_ap);
+}
+
+/// The index of the point of `set` furthest over the
+/// segment a-b.
+fn furthest_index<T: GeoNum>(p_a: Coord<T>, p_b: Coord<T>, set: &[Coord<T>]) -> usize {
     // Construct orthogonal vector to `p_b` - `p_a` We
     // compute inner product of this with `v` - `p_a` to
     // find the farthest point from the line segment a-b.
@@ -83,8 +158,7 @@ where
         y: p_b.x - p_a.x,
     };
 
-    let furthest_idx = set
-        .iter()
+    set.iter()
         .map(|pt| {
             let p_diff = coord! {
                 x: pt.x - p_a.x,
@@ -95,19 +169,7 @@ where
         .enumerate()
         .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
         .unwrap()
-        .0;
-
-    // move Coord at furthest_point from set into hull
-    let furthest_point = swap_with_first_and_remove(&mut set, furthest_idx);
-    // points over PB
-    {
-        let (points, _) = partition_slice(set, |p| is_ccw(*furthest_point, p_b, *p));
-        hull_set(*furthest_point, p_b, points, hull);
-    }
-    hull.push(*furthest_point);
-    // points over AP
-    let (points, _) = partition_slice(set, |p| is_ccw(p_a, *furthest_point, *p));
-    hull_set(p_a, *furthest_point, points, hull);
+        .0
 }
 
 #[cfg(test)]
@@ -301,3 +301,50 @@ fn collection() {
         ]
     );
 }
+
+#[test]
+fn strategies_agree() {
+    use rand::{Rng, SeedableRng};
+
+    // The hull, starting at its lexicographically least point
+    fn normalized(polygon: Polygon<f64>) -> Vec<Coord<f64>> {
+        let mut coords = polygon.exterior().0.clone();
+        coords.pop();
+        let start = crate::utils::least_index(&coords);
+        coords.rotate_left(start);
+        coords
+    }
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    let random: MultiPoint = (0..50_000)
+        .map(|_| Point::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)))
+        .collect();
+    // every point on the hull
+    let circle: MultiPoint = (0..2_000)
+        .map(|i| {
+            let angle = i as f64 * std::f64::consts::TAU / 2_000.;
+            Point::new(angle.cos(), angle.sin())
+        })
+        .collect();
+    // many collinear points on the hull
+    let grid: MultiPoint = (0..10_000)
+        .map(|i| Point::new((i % 100) as f64, (i / 100) as f64))
+        .collect();
+    let norway: MultiPoint = geo_test_fixtures::norway_main::<f64>().into_points().into();
+
+    for points in [random, circle, grid, norway] {
+        let expected = normalized(points.convex_hull());
+        let mut strategies = vec![
+            ConvexHullStrategy::Auto,
+            ConvexHullStrategy::QuickHull,
+            ConvexHullStrategy::MonotoneChain,
+            ConvexHullStrategy::Graham,
+        ];
+        #[cfg(feature = "multithreading")]
+        strategies.push(ConvexHullStrategy::ParallelQuickHull);
+        for strategy in strategies {
+            let hull = points.convex_hull_with_strategy(strategy);
+            assert_eq!(normalized(hull), expected, "{strategy:?}");
+        }
+    }
+}
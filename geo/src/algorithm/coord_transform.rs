@@ -0,0 +1,176 @@
+use std::fmt;
+
+use crate::{AffineTransform, Coord, CoordNum, MapCoords, MapCoordsInPlace};
+
+/// A transformation of single coordinates, which can be boxed into a [`DynCoordTransform`].
+///
+/// This is implemented for [`AffineTransform`], for closures mapping a [`Coord`] to a
+/// [`Coord`], and for [`DynCoordTransform`] itself, so that pipelines can be nested.
+pub trait CoordTransform<T: CoordNum> {
+    /// Transform a single coordinate.
+    fn transform_coord(&self, coord: Coord<T>) -> Coord<T>;
+}
+
+impl<T: CoordNum, F: Fn(Coord<T>) -> Coord<T>> CoordTransform<T> for F {
+    fn transform_coord(&self, coord: Coord<T>) -> Coord<T> {
+        self(coord)
+    }
+}
+
+impl<T: CoordNum> CoordTransform<T> for AffineTransform<T> {
+    fn transform_coord(&self, coord: Coord<T>) -> Coord<T> {
+        self.apply(coord)
+    }
+}
+
+/// A pipeline of coordinate transforms, chosen at runtime, which are applied in turn.
+///
+/// Each stage is a boxed [`CoordTransform`], so a pipeline can mix [`AffineTransform`]s and
+/// closures, e.g. a conversion built from a configuration file, without its type spelling out
+/// every stage. [`AffineTransform`]s known at compile time are better composed with
+/// [`AffineTransform::compose`], which multiplies them into a single matrix.
+///
+/// Fallible transforms, such as a PROJ conversion, can be added as a closure
+/// which decides what to do on failure, e.g. returning a `NaN` coordinate to be filtered out
+/// later.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, AffineTransform, Coord, DynCoordTransform};
+///
+/// let mut pipeline = DynCoordTransform::new(AffineTransform::translate(10., 0.));
+/// let swap_axes = true;
+/// if swap_axes {
+///     pipeline = pipeline.then(|Coord { x, y }| Coord { x: y, y: x });
+/// }
+///
+/// let line = wkt!(LINESTRING(0. 0.,1. 2.));
+/// assert_eq!(pipeline.apply(&line), wkt!(LINESTRING(0. 10.,2. 11.)));
+/// ```
+pub struct DynCoordTransform<T: CoordNum> {
+    stages: Vec<Box<dyn CoordTransform<T>>>,
+}
+
+impl<T: CoordNum> DynCoordTransform<T> {
+    /// A pipeline of the single stage `transform`.
+    pub fn new(transform: impl CoordTransform<T> + 'static) -> Self {
+        Self {
+            stages: vec![Box::new(transform)],
+        }
+    }
+
+    /// A pipeline of no stages, which leaves coordinates unchanged.
+    pub fn identity() -> Self {
+        Self { stages: vec![] }
+    }
+
+    /// Append `transform`, to be applied after the current stages.
+    #[must_use]
+    pub fn then(mut self, transform: impl CoordTransform<T> + 'static) -> Self {
+        self.stages.push(Box::new(transform));
+        self
+    }
+
+    /// The number of stages of the pipeline.
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Whether the pipeline has no stages, so leaves coordinates unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Apply the pipeline to every coordinate of `geometry`, returning a new geometry.
+    pub fn apply<G: MapCoords<T, T>>(&self, geometry: &G) -> G::Output {
+        geometry.map_coords(|coord| self.transform_coord(coord))
+    }
+
+    /// Apply the pipeline to every coordinate of `geometry`, in place.
+    pub fn apply_mut<G: MapCoordsInPlace<T>>(&self, geometry: &mut G) {
+        geometry.map_coords_in_place(|coord| self.transform_coord(coord))
+    }
+}
+
+impl<T: CoordNum> CoordTransform<T> for DynCoordTransform<T> {
+    fn transform_coord(&self, coord: Coord<T>) -> Coord<T> {
+        self.stages
+            .iter()
+            .fold(coord, |coord, stage| stage.transform_coord(coord))
+    }
+}
+
+impl<T: CoordNum> Default for DynCoordTransform<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: CoordNum + 'static> From<AffineTransform<T>> for DynCoordTransform<T> {
+    fn from(transform: AffineTransform<T>) -> Self {
+        Self::new(transform)
+    }
+}
+
+impl<T: CoordNum> fmt::Debug for DynCoordTransform<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynCoordTransform")
+            .field("stages", &self.stages.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, AffineOps, Geometry};
+
+    #[test]
+    fn stages_in_order() {
+        let scale = AffineTransform::scale(2., 2., point!(x: 0., y: 0.));
+        let translate = AffineTransform::translate(1., 0.);
+        let pipeline = DynCoordTransform::new(scale).then(translate);
+        let polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)));
+        assert_eq!(
+            pipeline.apply(&polygon),
+            polygon.affine_transform(&scale.compose(&translate))
+        );
+        assert_eq!(
+            pipeline.apply(&polygon),
+            wkt!(POLYGON((1. 0.,3. 0.,3. 2.,1. 0.)))
+        );
+
+        // the other way round
+        let pipeline = DynCoordTransform::from(translate).then(scale);
+        let mut geometry = Geometry::from(polygon);
+        pipeline.apply_mut(&mut geometry);
+        assert_eq!(
+            geometry,
+            Geometry::from(wkt!(POLYGON((2. 0.,4. 0.,4. 2.,2. 0.))))
+        );
+    }
+
+    #[test]
+    fn runtime_pipeline() {
+        // stages chosen at runtime, including a nested pipeline
+        let steps = ["negate", "nested", "negate"];
+        let mut pipeline = DynCoordTransform::identity();
+        assert!(pipeline.is_empty());
+        for step in steps {
+            pipeline = match step {
+                "negate" => pipeline.then(|coord: Coord<f64>| -coord),
+                _ => pipeline.then(
+                    DynCoordTransform::new(AffineTransform::translate(1., 1.))
+                        .then(|Coord { x, y }| Coord { x: x * 10., y }),
+                ),
+            };
+        }
+        assert_eq!(pipeline.len(), 3);
+        assert_eq!(pipeline.apply(&wkt!(POINT(1. 2.))), wkt!(POINT(0. 1.)));
+        assert_eq!(
+            DynCoordTransform::identity().apply(&wkt!(POINT(1. 2.))),
+            wkt!(POINT(1. 2.))
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::{BoundingRect, HasDimensions, Intersects};
 use crate::{GeoNum, GeometryCow};
 
 /// The position of a `Coord` relative to a `Geometry`
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum CoordPos {
     OnBoundary,
@@ -41,18 +42,42 @@ pub trait CoordinatePosition {
 
         self.calculate_coordinate_position(coord, &mut is_inside, &mut boundary_count);
 
-        // “The boundary of an arbitrary collection of geometries whose interiors are disjoint
-        // consists of geometries drawn from the boundaries of the element geometries by
-        // application of the ‘mod 2’ union rule”
-        //
-        // ― OpenGIS Simple Feature Access § 6.1.15.1
-        if boundary_count % 2 == 1 {
-            CoordPos::OnBoundary
-        } else if is_inside {
-            CoordPos::Inside
-        } else {
-            CoordPos::Outside
-        }
+        resolve_coord_pos(is_inside, boundary_count)
+    }
+
+    /// The positions of many coordinates, in the same order as `coords`.
+    ///
+    /// This gives the same results as calling [`coordinate_position`] for each coordinate, but
+    /// polygons, and geometries containing them, are only traversed once to index their rings.
+    /// This is much faster for large numbers of coordinates, e.g. when masking a raster.
+    ///
+    /// [`coordinate_position`]: Self::coordinate_position
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo::{coord, polygon};
+    /// use geo::coordinate_position::{CoordinatePosition, CoordPos};
+    ///
+    /// let square_poly = polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 2.0, y: 2.0), (x: 0.0, y: 2.0), (x: 0.0, y: 0.0)];
+    ///
+    /// let coords = [coord! { x: 1.0, y: 1.0 }, coord! { x: 0.0, y: 1.0 }, coord! { x: 5.0, y: 5.0 }];
+    /// assert_eq!(
+    ///     square_poly.coordinate_positions(&coords),
+    ///     vec![CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside]
+    /// );
+    /// ```
+    fn coordinate_positions(&self, coords: &[Coord<Self::Scalar>]) -> Vec<CoordPos> {
+        let mut is_inside = vec![false; coords.len()];
+        let mut boundary_count = vec![0; coords.len()];
+
+        self.calculate_coordinate_positions(coords, &mut is_inside, &mut boundary_count);
+
+        is_inside
+            .into_iter()
+            .zip(boundary_count)
+            .map(|(is_inside, boundary_count)| resolve_coord_pos(is_inside, boundary_count))
+            .collect()
     }
 
     // impls of this trait must:
@@ -64,6 +89,36 @@ pub trait CoordinatePosition {
         is_inside: &mut bool,
         boundary_count: &mut usize,
     );
+
+    // The batched version of `calculate_coordinate_position`, where `is_inside` and
+    // `boundary_count` correspond to `coords`. Geometries which can be prepared for many
+    // queries override this.
+    fn calculate_coordinate_positions(
+        &self,
+        coords: &[Coord<Self::Scalar>],
+        is_inside: &mut [bool],
+        boundary_count: &mut [usize],
+    ) {
+        for ((coord, is_inside), boundary_count) in coords.iter().zip(is_inside).zip(boundary_count)
+        {
+            self.calculate_coordinate_position(coord, is_inside, boundary_count);
+        }
+    }
+}
+
+fn resolve_coord_pos(is_inside: bool, boundary_count: usize) -> CoordPos {
+    // “The boundary of an arbitrary collection of geometries whose interiors are disjoint
+    // consists of geometries drawn from the boundaries of the element geometries by
+    // application of the ‘mod 2’ union rule”
+    //
+    // ― OpenGIS Simple Feature Access § 6.1.15.1
+    if boundary_count % 2 == 1 {
+        CoordPos::OnBoundary
+    } else if is_inside {
+        CoordPos::Inside
+    } else {
+        CoordPos::Outside
+    }
 }
 
 impl<T> CoordinatePosition for Coord<T>
@@ -186,7 +241,7 @@ where
         is_inside: &mut bool,
         boundary_count: &mut usize,
     ) {
-        *is_inside = self
+        let inside = self
             .to_lines()
             .map(|l| {
                 let orientation = T::Ker::orient2d(l.start, l.end, *coord);
@@ -200,6 +255,9 @@ where
             })
             .windows(2)
             .all(|win| win[0] == win[1] && win[0] != Orientation::Collinear);
+        if inside {
+            *is_inside = true;
+        }
     }
 }
 
@@ -282,28 +340,136 @@ where
             return;
         }
 
-        match coord_pos_relative_to_ring(*coord, self.exterior()) {
-            CoordPos::Outside => {}
-            CoordPos::OnBoundary => {
-                *boundary_count += 1;
-            }
-            CoordPos::Inside => {
-                for hole in self.interiors() {
-                    match coord_pos_relative_to_ring(*coord, hole) {
-                        CoordPos::Outside => {}
-                        CoordPos::OnBoundary => {
-                            *boundary_count += 1;
-                            return;
-                        }
-                        CoordPos::Inside => {
-                            return;
-                        }
+        calculate_polygon_position(
+            coord_pos_relative_to_ring(*coord, self.exterior()),
+            self.interiors()
+                .iter()
+                .map(|hole| coord_pos_relative_to_ring(*coord, hole)),
+            is_inside,
+            boundary_count,
+        );
+    }
+
+    fn calculate_coordinate_positions(
+        &self,
+        coords: &[Coord<T>],
+        is_inside: &mut [bool],
+        boundary_count: &mut [usize],
+    ) {
+        if self.is_empty() {
+            return;
+        }
+
+        let rings: Vec<_> = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .map(RingIndex::new)
+            .collect();
+        for ((coord, is_inside), boundary_count) in coords.iter().zip(is_inside).zip(boundary_count)
+        {
+            calculate_polygon_position(
+                rings[0].coord_pos(*coord),
+                rings[1..].iter().map(|hole| hole.coord_pos(*coord)),
+                is_inside,
+                boundary_count,
+            );
+        }
+    }
+}
+
+// The position of a coord relative to a polygon, from its position relative to the exterior and
+// its (lazily computed) positions relative to the interiors. Interiors which the coord is known to
+// be outside of can be omitted.
+pub(crate) fn calculate_polygon_position(
+    exterior_position: CoordPos,
+    interior_positions: impl Iterator<Item = CoordPos>,
+    is_inside: &mut bool,
+    boundary_count: &mut usize,
+) {
+    match exterior_position {
+        CoordPos::Outside => {}
+        CoordPos::OnBoundary => {
+            *boundary_count += 1;
+        }
+        CoordPos::Inside => {
+            for interior_position in interior_positions {
+                match interior_position {
+                    CoordPos::Outside => {}
+                    CoordPos::OnBoundary => {
+                        *boundary_count += 1;
+                        return;
+                    }
+                    CoordPos::Inside => {
+                        return;
                     }
                 }
-                // the coord is *outside* the interior holes, so it's *inside* the polygon
-                *is_inside = true;
+            }
+            // the coord is *outside* the interior holes, so it's *inside* the polygon
+            *is_inside = true;
+        }
+    }
+}
+
+/// A ring whose segments are bucketed into horizontal bands, so that the segments which could
+/// cross a horizontal ray from a coord are found without visiting the whole ring.
+struct RingIndex<'a, T: GeoNum> {
+    ring: &'a LineString<T>,
+    min_y: T,
+    max_y: T,
+    bands_per_unit: f64,
+    bands: Vec<Vec<Line<T>>>,
+}
+
+impl<'a, T: GeoNum> RingIndex<'a, T> {
+    fn new(ring: &'a LineString<T>) -> Self {
+        let mut index = RingIndex {
+            ring,
+            min_y: T::zero(),
+            max_y: T::zero(),
+            bands_per_unit: 0.0,
+            bands: Vec::new(),
+        };
+        let Some(rect) = ring.bounding_rect() else {
+            return index;
+        };
+        index.min_y = rect.min().y;
+        index.max_y = rect.max().y;
+
+        let num_lines = ring.0.len().saturating_sub(1);
+        let num_bands = (num_lines as f64).sqrt().ceil().max(1.0) as usize;
+        let height = (rect.max().y - rect.min().y).to_f64().unwrap_or(0.0);
+        if height > 0.0 {
+            index.bands_per_unit = num_bands as f64 / height;
+        }
+        index.bands = vec![Vec::new(); num_bands];
+        for line in ring.lines() {
+            let (min_y, max_y) = if line.start.y <= line.end.y {
+                (line.start.y, line.end.y)
+            } else {
+                (line.end.y, line.start.y)
+            };
+            for band in index.band(min_y)..=index.band(max_y) {
+                index.bands[band].push(line);
             }
         }
+        index
+    }
+
+    // Non-decreasing in `y`, so a line is in the band of every `y` within its extent
+    fn band(&self, y: T) -> usize {
+        let offset = (y - self.min_y).to_f64().unwrap_or(0.0);
+        ((offset * self.bands_per_unit) as usize).min(self.bands.len() - 1)
+    }
+
+    /// The same as `coord_pos_relative_to_ring(coord, self.ring)`.
+    fn coord_pos(&self, coord: Coord<T>) -> CoordPos {
+        if self.ring.0.len() < 2 {
+            return coord_pos_relative_to_ring(coord, self.ring);
+        }
+        // Lines only affect coords within their vertical extent
+        if coord.y < self.min_y || coord.y > self.max_y {
+            return CoordPos::Outside;
+        }
+        coord_pos_relative_to_lines(coord, self.bands[self.band(coord.y)].iter().copied())
     }
 }
 
@@ -322,6 +488,17 @@ where
             line_string.calculate_coordinate_position(coord, is_inside, boundary_count);
         }
     }
+
+    fn calculate_coordinate_positions(
+        &self,
+        coords: &[Coord<T>],
+        is_inside: &mut [bool],
+        boundary_count: &mut [usize],
+    ) {
+        for line_string in &self.0 {
+            line_string.calculate_coordinate_positions(coords, is_inside, boundary_count);
+        }
+    }
 }
 
 impl<T> CoordinatePosition for MultiPolygon<T>
@@ -339,6 +516,17 @@ where
             polygon.calculate_coordinate_position(coord, is_inside, boundary_count);
         }
     }
+
+    fn calculate_coordinate_positions(
+        &self,
+        coords: &[Coord<T>],
+        is_inside: &mut [bool],
+        boundary_count: &mut [usize],
+    ) {
+        for polygon in &self.0 {
+            polygon.calculate_coordinate_positions(coords, is_inside, boundary_count);
+        }
+    }
 }
 
 impl<T> CoordinatePosition for GeometryCollection<T>
@@ -356,6 +544,17 @@ where
             geometry.calculate_coordinate_position(coord, is_inside, boundary_count);
         }
     }
+
+    fn calculate_coordinate_positions(
+        &self,
+        coords: &[Coord<T>],
+        is_inside: &mut [bool],
+        boundary_count: &mut [usize],
+    ) {
+        for geometry in self {
+            geometry.calculate_coordinate_positions(coords, is_inside, boundary_count);
+        }
+    }
 }
 
 impl<T> CoordinatePosition for Geometry<T>
@@ -369,6 +568,11 @@ where
             coord: &Coord<T>,
             is_inside: &mut bool,
             boundary_count: &mut usize) -> ();
+        fn calculate_coordinate_positions(
+            &self,
+            coords: &[Coord<T>],
+            is_inside: &mut [bool],
+            boundary_count: &mut [usize]) -> ();
     }
 }
 
@@ -380,6 +584,11 @@ impl<T: GeoNum> CoordinatePosition for GeometryCow<'_, T> {
             coord: &Coord<T>,
             is_inside: &mut bool,
             boundary_count: &mut usize) -> ();
+        fn calculate_coordinate_positions(
+            &self,
+            coords: &[Coord<T>],
+            is_inside: &mut [bool],
+            boundary_count: &mut [usize]) -> ();
     }
 }
 
@@ -405,10 +614,19 @@ where
         };
     }
 
+    coord_pos_relative_to_lines(coord, linestring.lines())
+}
+
+/// Calculate the position of a `Coord` relative to the lines of a closed `LineString`, where
+/// lines which don't span the coord vertically can be omitted.
+fn coord_pos_relative_to_lines<T>(coord: Coord<T>, lines: impl Iterator<Item = Line<T>>) -> CoordPos
+where
+    T: GeoNum,
+{
     // Use winding number algorithm with on boundary short-cicuit
     // See: https://en.wikipedia.org/wiki/Point_in_polygon#Winding_number_algorithm
     let mut winding_number = 0;
-    for line in linestring.lines() {
+    for line in lines {
         // Edge Crossing Rules:
         //   1. an upward edge includes its starting endpoint, and excludes its final endpoint;
         //   2. a downward edge excludes its starting endpoint, and includes its final endpoint;
@@ -744,4 +962,52 @@ mod test {
             CoordPos::Outside
         );
     }
+
+    #[test]
+    fn test_coordinate_positions() {
+        let exterior = LineString::from(vec![
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 10.0),
+            (5.0, 5.0),
+            (0.0, 10.0),
+            (0.0, 0.0),
+        ]);
+        let hole = LineString::from(vec![
+            (2.0, 1.0),
+            (4.0, 1.0),
+            (4.0, 3.0),
+            (2.0, 3.0),
+            (2.0, 1.0),
+        ]);
+        let poly = Polygon::new(exterior, vec![hole]);
+        let collection = GeometryCollection::new_from(vec![
+            poly.clone().into(),
+            Triangle::new((0.0, 0.0).into(), (5.0, 10.0).into(), (10.0, 0.0).into()).into(),
+            Rect::new((8.0, 8.0), (12.0, 12.0)).into(),
+        ]);
+
+        let mut coords = vec![];
+        for x in -2..=24 {
+            for y in -2..=24 {
+                coords.push(coord! { x: x as f64 / 2.0, y: y as f64 / 2.0 });
+            }
+        }
+
+        let expected: Vec<_> = coords.iter().map(|c| poly.coordinate_position(c)).collect();
+        assert_eq!(poly.coordinate_positions(&coords), expected);
+
+        let expected: Vec<_> = coords
+            .iter()
+            .map(|c| collection.coordinate_position(c))
+            .collect();
+        assert_eq!(collection.coordinate_positions(&coords), expected);
+
+        assert!(poly.coordinate_positions(&[]).is_empty());
+        assert_eq!(
+            Polygon::<f64>::new(LineString::new(vec![]), vec![])
+                .coordinate_positions(&[coord! { x: 0.0, y: 0.0 }]),
+            vec![CoordPos::Outside]
+        );
+    }
 }
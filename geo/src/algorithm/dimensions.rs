@@ -21,6 +21,7 @@ use crate::{CoordNum, GeoNum, GeometryCow};
 /// assert!(point.dimensions() < line_string.dimensions());
 /// assert!(rect.dimensions() > line_string.dimensions());
 /// ```
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd)]
 pub enum Dimensions {
     /// Some geometries, like a `MultiPoint` or `GeometryCollection` may have no elements - thus no
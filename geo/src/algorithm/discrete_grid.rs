@@ -0,0 +1,351 @@
+//! Cover geometries with the cells of a discrete global grid.
+//!
+//! A discrete global grid, such as [geohash](crate::geohash) or [H3], partitions the earth into
+//! cells at a number of resolutions and identifies each cell with an integer. The
+//! [`CellCover`](crate::discrete_grid::CellCover) trait finds the cells of such a grid which a lon/lat geometry touches, so that
+//! data can be aggregated by cell.
+//!
+//! Two grids are provided:
+//!
+//! - [`GeohashGrid`](crate::discrete_grid::GeohashGrid), where the resolution is the geohash precision
+//! - `H3Grid`, using the [`h3o`] crate (requires the `use-h3` feature)
+//!
+//! Other grids can be plugged in by implementing [`CellCover`](crate::discrete_grid::CellCover),
+//! typically with the help of [`cover_cells`](crate::discrete_grid::cover_cells).
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::discrete_grid::{CellCover, GeohashGrid};
+//! use geo::{line_string, Geohash};
+//!
+//! let line = line_string![(x: 0.01, y: 51.5), (x: 0.03, y: 51.5)];
+//! let cells = GeohashGrid.cells_covering(&line, 5).unwrap();
+//! assert_eq!(cells.len(), 1);
+//! assert_eq!(cells[0], GeohashGrid::cell_id("u10hb").unwrap());
+//! assert_eq!(line.0[0].geohash(5).unwrap(), "u10hb");
+//! ```
+//!
+//! [H3]: https://h3geo.org
+//! [`h3o`]: https://docs.rs/h3o
+
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt;
+
+use crate::geohash::{GeohashError, Grid};
+use crate::{Coord, CoordsIter, Intersects, Polygon};
+
+/// A discrete global grid that can list the cells covering a geometry.
+pub trait CellCover {
+    /// Returns the ids of the cells at `resolution` which intersect `geometry`, in ascending
+    /// order.
+    ///
+    /// `geometry` must have lon/lat degree coordinates. Geometries crossing the antimeridian or
+    /// enclosing a pole aren't supported.
+    ///
+    /// # Errors
+    ///
+    /// If the grid doesn't have cells at `resolution`, or a coordinate of `geometry` isn't a
+    /// lon/lat degree coordinate.
+    fn cells_covering<G>(&self, geometry: &G, resolution: u8) -> Result<Vec<u64>, CellCoverError>
+    where
+        G: CoordsIter<Scalar = f64> + Intersects<Polygon<f64>>;
+}
+
+/// An error listing the cells covering a geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellCoverError {
+    /// The grid doesn't have cells at this resolution.
+    InvalidResolution(u8),
+    /// A coordinate is outside of the lon/lat range, or isn't finite.
+    InvalidCoordinate,
+}
+
+impl fmt::Display for CellCoverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CellCoverError::InvalidResolution(resolution) => {
+                write!(f, "the grid has no cells at resolution {resolution}")
+            }
+            CellCoverError::InvalidCoordinate => {
+                write!(f, "coordinate is outside of the lon/lat range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CellCoverError {}
+
+/// Finds the cells intersecting a geometry by searching outwards from the cells containing its
+/// coordinates.
+///
+/// Since the cells intersecting a connected geometry are connected, and every component of a
+/// geometry has a coordinate, this visits each intersecting cell, along with their immediate
+/// neighbors.
+///
+/// - `seed` returns the cell containing a coordinate, or an error if it has none
+/// - `neighbors` returns the cells adjacent to a cell
+/// - `boundary` returns the outline of a cell, in lon/lat degree coordinates
+pub fn cover_cells<G, C, N>(
+    geometry: &G,
+    seed: impl Fn(Coord<f64>) -> Result<C, CellCoverError>,
+    neighbors: impl Fn(C) -> N,
+    boundary: impl Fn(C) -> Polygon<f64>,
+) -> Result<Vec<C>, CellCoverError>
+where
+    G: CoordsIter<Scalar = f64> + Intersects<Polygon<f64>>,
+    C: Copy + Ord,
+    N: IntoIterator<Item = C>,
+{
+    let mut visited: BTreeSet<C> = geometry.coords_iter().map(seed).collect::<Result<_, _>>()?;
+    let mut queue: VecDeque<C> = visited.iter().copied().collect();
+    let mut cells = Vec::new();
+    while let Some(cell) = queue.pop_front() {
+        if !geometry.intersects(&boundary(cell)) {
+            continue;
+        }
+        cells.push(cell);
+        for neighbor in neighbors(cell) {
+            if visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    cells.sort_unstable();
+    Ok(cells)
+}
+
+/// The [geohash](crate::geohash) grid, where the resolution is the geohash precision, from 1
+/// to [`MAX_PRECISION`](crate::geohash::MAX_PRECISION).
+///
+/// A cell's id is its geohash read as a base 32 integer. Ids are only unique within a
+/// resolution.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GeohashGrid;
+
+impl GeohashGrid {
+    /// The id of the cell of a geohash.
+    pub fn cell_id(hash: &str) -> Result<u64, GeohashError> {
+        let (grid, lon_index, lat_index) = Grid::decode(hash)?;
+        Ok(grid.id(lon_index, lat_index))
+    }
+}
+
+impl CellCover for GeohashGrid {
+    fn cells_covering<G>(&self, geometry: &G, resolution: u8) -> Result<Vec<u64>, CellCoverError>
+    where
+        G: CoordsIter<Scalar = f64> + Intersects<Polygon<f64>>,
+    {
+        let grid = Grid::new(resolution as usize)
+            .map_err(|_| CellCoverError::InvalidResolution(resolution))?;
+        let mut ids: Vec<u64> = cover_cells(
+            geometry,
+            |coord| {
+                grid.cell_of(coord)
+                    .map_err(|_| CellCoverError::InvalidCoordinate)
+            },
+            |(lon_index, lat_index)| grid.neighbors(lon_index, lat_index),
+            |(lon_index, lat_index)| grid.cell::<f64>(lon_index, lat_index).to_polygon(),
+        )?
+        .into_iter()
+        .map(|(lon_index, lat_index)| grid.id(lon_index, lat_index))
+        .collect();
+        ids.sort_unstable();
+        Ok(ids)
+    }
+}
+
+#[cfg(feature = "use-h3")]
+mod h3 {
+    use super::{cover_cells, CellCover, CellCoverError};
+    use crate::{Coord, CoordsIter, Intersects, LineString, Polygon};
+    use h3o::{CellIndex, LatLng, Resolution};
+
+    /// The [H3](https://h3geo.org) grid, where the resolution is the H3 resolution, from 0 to
+    /// 15, and a cell's id is its H3 index. Requires the `use-h3` feature.
+    ///
+    /// Cell boundaries are treated as straight lines in lon/lat, rather than great circle
+    /// arcs, when testing them against the geometry.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct H3Grid;
+
+    impl CellCover for H3Grid {
+        fn cells_covering<G>(
+            &self,
+            geometry: &G,
+            resolution: u8,
+        ) -> Result<Vec<u64>, CellCoverError>
+        where
+            G: CoordsIter<Scalar = f64> + Intersects<Polygon<f64>>,
+        {
+            let h3_resolution = Resolution::try_from(resolution)
+                .map_err(|_| CellCoverError::InvalidResolution(resolution))?;
+            let cells = cover_cells(
+                geometry,
+                |coord| {
+                    LatLng::new(coord.y, coord.x)
+                        .map(|lat_lng| lat_lng.to_cell(h3_resolution))
+                        .map_err(|_| CellCoverError::InvalidCoordinate)
+                },
+                |cell| cell.grid_disk::<Vec<_>>(1),
+                boundary,
+            )?;
+            Ok(cells.into_iter().map(u64::from).collect())
+        }
+    }
+
+    // The outline of a cell, with longitudes unwrapped so that cells crossing the antimeridian
+    // don't span the globe.
+    fn boundary(cell: CellIndex) -> Polygon<f64> {
+        let mut previous: Option<f64> = None;
+        let exterior: LineString<f64> = cell
+            .boundary()
+            .iter()
+            .map(|vertex| {
+                let mut lon = vertex.lng();
+                if let Some(previous) = previous {
+                    if lon - previous > 180.0 {
+                        lon -= 360.0;
+                    } else if previous - lon > 180.0 {
+                        lon += 360.0;
+                    }
+                }
+                previous = Some(lon);
+                Coord {
+                    x: lon,
+                    y: vertex.lat(),
+                }
+            })
+            .collect();
+        Polygon::new(exterior, vec![])
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{polygon, Contains, Point};
+
+        #[test]
+        fn covers_polygon() {
+            let polygon = polygon![
+                (x: 0.0, y: 51.4),
+                (x: 0.2, y: 51.4),
+                (x: 0.2, y: 51.6),
+                (x: 0.0, y: 51.6),
+            ];
+            let cells = H3Grid.cells_covering(&polygon, 7).unwrap();
+            assert!(cells.len() > 10);
+            assert!(cells.windows(2).all(|pair| pair[0] < pair[1]));
+
+            let center = LatLng::new(51.5, 0.1).unwrap().to_cell(Resolution::Seven);
+            assert!(cells.contains(&u64::from(center)));
+
+            for id in cells {
+                let cell = CellIndex::try_from(id).unwrap();
+                assert_eq!(cell.resolution(), Resolution::Seven);
+                assert!(polygon.intersects(&boundary(cell)));
+            }
+        }
+
+        #[test]
+        fn covers_point() {
+            let point = Point::new(-122.4, 37.8);
+            let cells = H3Grid.cells_covering(&point, 9).unwrap();
+            assert_eq!(cells.len(), 1);
+            let cell = CellIndex::try_from(cells[0]).unwrap();
+            assert!(boundary(cell).contains(&point));
+        }
+
+        #[test]
+        fn invalid_input() {
+            let point = Point::new(-122.4, 37.8);
+            assert_eq!(
+                H3Grid.cells_covering(&point, 16),
+                Err(CellCoverError::InvalidResolution(16))
+            );
+            let point = Point::new(f64::NAN, 37.8);
+            assert_eq!(
+                H3Grid.cells_covering(&point, 9),
+                Err(CellCoverError::InvalidCoordinate)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "use-h3")]
+pub use h3::H3Grid;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point, polygon, wkt, Geohash, GeohashCells, Geometry, MultiPoint};
+
+    #[test]
+    fn geohash_ids() {
+        assert_eq!(GeohashGrid::cell_id("0").unwrap(), 0);
+        assert_eq!(GeohashGrid::cell_id("z").unwrap(), 31);
+        assert_eq!(GeohashGrid::cell_id("10").unwrap(), 32);
+        assert!(GeohashGrid::cell_id("a").is_err());
+    }
+
+    #[test]
+    fn geohash_cover_polygon() {
+        let polygon = polygon![
+            (x: -5.0, y: 40.0),
+            (x: 5.0, y: 40.0),
+            (x: 0.0, y: 50.0),
+        ];
+        let cells = GeohashGrid.cells_covering(&polygon, 3).unwrap();
+
+        // Every intersecting cell of the bounding rectangle is found
+        let mut expected: Vec<u64> = polygon
+            .geohash_cells(3)
+            .unwrap()
+            .into_iter()
+            .filter(|hash| {
+                let rect = crate::geohash::decode_geohash::<f64>(hash).unwrap();
+                polygon.intersects(&rect.to_polygon())
+            })
+            .map(|hash| GeohashGrid::cell_id(&hash).unwrap())
+            .collect();
+        expected.sort_unstable();
+        assert_eq!(cells, expected);
+        assert!(cells.len() > 1);
+    }
+
+    #[test]
+    fn geohash_cover_disjoint() {
+        let points = MultiPoint::new(vec![point!(x: 0.1, y: 0.1), point!(x: 100.1, y: -40.1)]);
+        let cells = GeohashGrid.cells_covering(&points, 4).unwrap();
+        let mut expected: Vec<u64> = points
+            .iter()
+            .map(|point| GeohashGrid::cell_id(&point.geohash(4).unwrap()).unwrap())
+            .collect();
+        expected.sort_unstable();
+        assert_eq!(cells, expected);
+    }
+
+    #[test]
+    fn geohash_cover_empty() {
+        let empty: Geometry = wkt!(POLYGON EMPTY).into();
+        assert!(GeohashGrid.cells_covering(&empty, 4).unwrap().is_empty());
+    }
+
+    #[test]
+    fn geohash_invalid_resolution() {
+        assert_eq!(
+            GeohashGrid.cells_covering(&point!(x: 0.0, y: 0.0), 13),
+            Err(CellCoverError::InvalidResolution(13))
+        );
+    }
+
+    #[test]
+    fn geohash_invalid_coordinate() {
+        // e.g. projected coordinates
+        let line = wkt!(LINESTRING(500000. 4649776.,500100. 4649776.));
+        assert_eq!(
+            GeohashGrid.cells_covering(&line, 4),
+            Err(CellCoverError::InvalidCoordinate)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{Coord, CoordFloat};
+
+/// A precision policy: how far apart two values may be and still be considered equal.
+///
+/// Tolerances come in several kinds, which suit different data:
+///
+/// - An **absolute** tolerance is a fixed distance, in the units of the coordinates, e.g. a
+///   millimetre for survey data in a projected coordinate system.
+/// - A **relative** tolerance scales with the magnitude of the values, so that it allows for
+///   the rounding error of coordinates far from the origin.
+/// - A tolerance in **ULPs** (units in the last place) allows a number of representable
+///   floating point values between the two values, i.e. the rounding error of a few operations.
+///
+/// An `Epsilon` combines any of these: two values are equal if they are equal within any of its
+/// tolerances. Define a single policy for an application, and pass it to each API which accepts
+/// one, such as [`IntersectsWithTolerance`](crate::IntersectsWithTolerance) and
+/// [`RemoveRepeatedPointsWithin`](crate::RemoveRepeatedPointsWithin). These APIs also accept a
+/// plain number, as an absolute tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, Epsilon};
+///
+/// let epsilon = Epsilon::absolute(1e-9).with_relative(1e-12);
+///
+/// // near the origin, the absolute tolerance applies
+/// assert!(epsilon.values_eq(1e-10, 0.));
+/// assert!(!epsilon.values_eq(1e-8, 0.));
+/// // far from it, the relative tolerance is larger
+/// assert!(epsilon.values_eq(1e6, 1e6 + 1e-7));
+///
+/// assert!(Epsilon::ulps(4).coords_eq(
+///     coord! { x: 0.1 + 0.2, y: 1. },
+///     coord! { x: 0.3, y: 1. },
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Epsilon<F> {
+    absolute: F,
+    relative: F,
+    ulps: u32,
+}
+
+impl<F: CoordFloat> Epsilon<F> {
+    /// Only equal values are equal.
+    pub fn exact() -> Self {
+        Self {
+            absolute: F::zero(),
+            relative: F::zero(),
+            ulps: 0,
+        }
+    }
+
+    /// Values at most `tolerance` apart are equal.
+    pub fn absolute(tolerance: F) -> Self {
+        Self::exact().with_absolute(tolerance)
+    }
+
+    /// Values which differ by at most `tolerance` times the larger of their magnitudes are equal.
+    pub fn relative(tolerance: F) -> Self {
+        Self::exact().with_relative(tolerance)
+    }
+
+    /// Values with at most `ulps` representable values between them are equal.
+    pub fn ulps(ulps: u32) -> Self {
+        Self::exact().with_ulps(ulps)
+    }
+
+    /// Also consider values at most `tolerance` apart equal.
+    pub fn with_absolute(self, tolerance: F) -> Self {
+        Self {
+            absolute: tolerance,
+            ..self
+        }
+    }
+
+    /// Also consider values which differ by at most `tolerance` times the larger of their
+    /// magnitudes equal.
+    pub fn with_relative(self, tolerance: F) -> Self {
+        Self {
+            relative: tolerance,
+            ..self
+        }
+    }
+
+    /// Also consider values with at most `ulps` representable values between them equal.
+    pub fn with_ulps(self, ulps: u32) -> Self {
+        Self { ulps, ..self }
+    }
+
+    /// The largest difference between two values of at most the given magnitude which are
+    /// considered equal.
+    ///
+    /// APIs which compare distances rather than values, such as
+    /// [`IntersectsWithTolerance`](crate::IntersectsWithTolerance), use the tolerance at the
+    /// magnitude of the coordinates of their input.
+    pub fn tolerance_at(&self, magnitude: F) -> F {
+        let magnitude = magnitude.abs();
+        let mut tolerance = self.absolute.max(self.relative * magnitude);
+        if self.ulps > 0 && magnitude.is_finite() {
+            let ulps = F::from(self.ulps).expect("ulps fit in a float");
+            tolerance = tolerance.max(ulps * ulp(magnitude));
+        }
+        tolerance
+    }
+
+    /// Whether `a` and `b` are equal under this policy.
+    pub fn values_eq(&self, a: F, b: F) -> bool {
+        a == b || (a - b).abs() <= self.tolerance_at(a.abs().max(b.abs()))
+    }
+
+    /// Whether `a` and `b` are equal under this policy, i.e. both their `x` and `y` values are.
+    ///
+    /// The tolerance of both values is the tolerance at the magnitude of the larger of the
+    /// coordinates, so that e.g. a relative tolerance applies to a coordinate far from the origin
+    /// even if it is on an axis.
+    pub fn coords_eq(&self, a: Coord<F>, b: Coord<F>) -> bool {
+        if a == b {
+            return true;
+        }
+        let magnitude = a.x.abs().max(a.y.abs()).max(b.x.abs()).max(b.y.abs());
+        let tolerance = self.tolerance_at(magnitude);
+        (a.x - b.x).abs() <= tolerance && (a.y - b.y).abs() <= tolerance
+    }
+}
+
+impl<F: CoordFloat> Default for Epsilon<F> {
+    fn default() -> Self {
+        Self::exact()
+    }
+}
+
+/// An absolute tolerance.
+impl<F: CoordFloat> From<F> for Epsilon<F> {
+    fn from(tolerance: F) -> Self {
+        Self::absolute(tolerance)
+    }
+}
+
+/// The spacing of the floating point values of the given (finite, non-negative) magnitude.
+fn ulp<F: CoordFloat>(magnitude: F) -> F {
+    let (_, exponent, _) = magnitude.integer_decode();
+    let two = F::one() + F::one();
+    two.powi(exponent as i32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tolerances() {
+        assert!(Epsilon::exact().values_eq(1., 1.));
+        assert!(!Epsilon::exact().values_eq(1., 1. + f64::EPSILON));
+        assert!(Epsilon::<f64>::default().values_eq(f64::INFINITY, f64::INFINITY));
+
+        assert!(Epsilon::absolute(0.5).values_eq(1., 1.5));
+        assert!(!Epsilon::absolute(0.5).values_eq(1., 1.6));
+
+        assert!(Epsilon::relative(1e-3).values_eq(1000., 1001.));
+        assert!(!Epsilon::relative(1e-3).values_eq(1., 1.01));
+
+        assert!(Epsilon::ulps(1).values_eq(1., 1. + f64::EPSILON));
+        assert!(!Epsilon::ulps(1).values_eq(1., 1. + 2. * f64::EPSILON));
+        assert!(Epsilon::ulps(2).values_eq(1e10, 1e10 + 2. * 1e10 * f64::EPSILON));
+        assert!(Epsilon::ulps(1).values_eq(1f32, 1. + f32::EPSILON));
+        assert!(!Epsilon::<f64>::ulps(1000).values_eq(f64::NAN, f64::NAN));
+
+        // the largest of the tolerances applies
+        let epsilon = Epsilon::absolute(1e-6).with_relative(1e-9).with_ulps(2);
+        assert_eq!(epsilon.tolerance_at(0.), 1e-6);
+        assert_eq!(epsilon.tolerance_at(1e6), 1e-3);
+        assert_eq!(Epsilon::from(0.25), Epsilon::absolute(0.25));
+
+        let epsilon = Epsilon::relative(1e-9);
+        assert!(epsilon.coords_eq(Coord { x: 1e6, y: 0. }, Coord { x: 1e6, y: 1e-4 }));
+        assert!(!epsilon.coords_eq(Coord { x: 1., y: 0. }, Coord { x: 1., y: 1e-4 }));
+    }
+}
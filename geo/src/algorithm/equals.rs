@@ -0,0 +1,65 @@
+use crate::{GeoFloat, Relate};
+
+/// Tests if two geometries are topologically equal, i.e. they are the same set of points,
+/// whatever their vertices and the order, direction or starting point of their parts.
+///
+/// In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is `[T*F**FFF*]`. Any two
+/// empty geometries are equal.
+///
+/// For exact equality of semantically identical geometries which only differ in the order of
+/// their parts, compare them with `==` after [`Normalize`](crate::Normalize)-ing them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Equals};
+///
+/// let line_string = wkt!(LINESTRING(0. 0.,2. 2.));
+/// // reversed, with an extra vertex
+/// let other = wkt!(LINESTRING(2. 2.,1. 1.,0. 0.));
+/// assert!(line_string.is_equal_topo(&other));
+/// assert_ne!(line_string, other);
+///
+/// let longer = wkt!(LINESTRING(0. 0.,3. 3.));
+/// assert!(!line_string.is_equal_topo(&longer));
+/// ```
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait Equals<F: GeoFloat, Rhs = Self> {
+    fn is_equal_topo(&self, other: &Rhs) -> bool;
+}
+
+impl<F, G1, G2> Equals<F, G2> for G1
+where
+    F: GeoFloat,
+    G1: Relate<F>,
+    G2: Relate<F>,
+{
+    fn is_equal_topo(&self, other: &G2) -> bool {
+        self.relate(other).is_equal_topo()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geometry::*;
+    use crate::wkt;
+
+    #[test]
+    fn equals() {
+        let square = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+        let other = wkt!(POLYGON((4. 4.,4. 2.,4. 0.,0. 0.,0. 4.,4. 4.)));
+        assert!(square.is_equal_topo(&other));
+        assert!(other.is_equal_topo(&square));
+        assert!(Geometry::from(square.clone()).is_equal_topo(&MultiPolygon::new(vec![other])));
+
+        let smaller = wkt!(POLYGON((0. 0.,3. 0.,3. 3.,0. 3.,0. 0.)));
+        assert!(!square.is_equal_topo(&smaller));
+        assert!(!square.is_equal_topo(square.exterior()));
+
+        let points = wkt!(MULTIPOINT(1. 1.,2. 2.,1. 1.));
+        assert!(points.is_equal_topo(&wkt!(MULTIPOINT(2. 2.,1. 1.))));
+        assert!(MultiPoint::<f64>::new(vec![]).is_equal_topo(&wkt!(LINESTRING EMPTY)));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::CoordsIter;
-use crate::{Coord, CoordNum};
+use crate::{Coord, CoordNum, Rect};
 
 /// Find the extreme coordinates and indices of a geometry.
 ///
@@ -28,12 +28,14 @@ pub trait Extremes<'a, T: CoordNum> {
     fn extremes(&'a self) -> Option<Outcome<T>>;
 }
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Eq)]
 pub struct Extreme<T: CoordNum> {
     pub index: usize,
     pub coord: Coord<T>,
 }
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Eq)]
 pub struct Outcome<T: CoordNum> {
     pub x_min: Extreme<T>,
@@ -49,40 +51,119 @@ where
 {
     fn extremes(&'a self) -> Option<Outcome<T>> {
         let mut iter = self.exterior_coords_iter().enumerate();
+        let mut outcome = iter.next().map(Outcome::first)?;
+        for (index, coord) in iter {
+            outcome.add(index, coord);
+        }
+        Some(outcome)
+    }
+}
 
-        let mut outcome = iter.next().map(|(index, coord)| Outcome {
+impl<T: CoordNum> Outcome<T> {
+    fn first((index, coord): (usize, Coord<T>)) -> Self {
+        Outcome {
             x_min: Extreme { index, coord },
             y_min: Extreme { index, coord },
             x_max: Extreme { index, coord },
             y_max: Extreme { index, coord },
-        })?;
+        }
+    }
 
-        for (index, coord) in iter {
-            if coord.x < outcome.x_min.coord.x {
-                outcome.x_min = Extreme { coord, index };
-            }
+    fn add(&mut self, index: usize, coord: Coord<T>) {
+        if coord.x < self.x_min.coord.x {
+            self.x_min = Extreme { coord, index };
+        }
 
-            if coord.y < outcome.y_min.coord.y {
-                outcome.y_min = Extreme { coord, index };
-            }
+        if coord.y < self.y_min.coord.y {
+            self.y_min = Extreme { coord, index };
+        }
 
-            if coord.x > outcome.x_max.coord.x {
-                outcome.x_max = Extreme { coord, index };
-            }
+        if coord.x > self.x_max.coord.x {
+            self.x_max = Extreme { coord, index };
+        }
 
-            if coord.y > outcome.y_max.coord.y {
-                outcome.y_max = Extreme { coord, index };
-            }
+        if coord.y > self.y_max.coord.y {
+            self.y_max = Extreme { coord, index };
         }
+    }
+}
 
-        Some(outcome)
+/// Summarize the extent of a geometry in a single pass over its coordinates, rather than
+/// computing its [`BoundingRect`](crate::BoundingRect), [`Extremes`] and
+/// [`coords_count`](CoordsIter::coords_count) separately.
+///
+/// Unlike [`Extremes`] and `BoundingRect`, which only consider the exterior coordinates of
+/// polygons, this covers all of [`coords_iter`](CoordsIter::coords_iter), and the indices of its
+/// extremes are positions in `coords_iter`. The results only differ for invalid polygons with
+/// interiors extending outside their exterior.
+///
+/// # Examples
+///
+/// ```
+/// use geo::extremes::ExtentSummary;
+/// use geo::{wkt, BoundingRect};
+///
+/// let line_string = wkt!(LINESTRING(1. 0.,2. 1.,1. 2.,0. 1.,1. 0.));
+/// let summary = line_string.extent_summary().unwrap();
+///
+/// assert_eq!(Some(summary.bounding_rect), line_string.bounding_rect());
+/// assert_eq!(summary.extremes.y_max.index, 2);
+/// assert_eq!(summary.coords_count, 5);
+/// assert!(summary.is_closed);
+/// ```
+pub trait ExtentSummary<'a, T: CoordNum> {
+    /// The extent of the geometry, or `None` if it has no coordinates.
+    fn extent_summary(&'a self) -> Option<Extent<T>>;
+}
+
+/// The result of [`ExtentSummary::extent_summary`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Extent<T: CoordNum> {
+    pub bounding_rect: Rect<T>,
+    pub extremes: Outcome<T>,
+    pub coords_count: usize,
+    /// Whether the first and last coordinates are equal, as in a closed `LineString`.
+    pub is_closed: bool,
+}
+
+impl<'a, T, G> ExtentSummary<'a, T> for G
+where
+    G: CoordsIter<Scalar = T>,
+    T: CoordNum,
+{
+    fn extent_summary(&'a self) -> Option<Extent<T>> {
+        let mut iter = self.coords_iter().enumerate();
+        let first = iter.next()?;
+        let mut extremes = Outcome::first(first);
+        let mut last = first;
+        for (index, coord) in iter {
+            extremes.add(index, coord);
+            last = (index, coord);
+        }
+
+        Some(Extent {
+            bounding_rect: Rect::new(
+                Coord {
+                    x: extremes.x_min.coord.x,
+                    y: extremes.y_min.coord.y,
+                },
+                Coord {
+                    x: extremes.x_max.coord.x,
+                    y: extremes.y_max.coord.y,
+                },
+            ),
+            extremes,
+            coords_count: last.0 + 1,
+            is_closed: first.1 == last.1,
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{coord, polygon, MultiPoint};
+    use crate::{coord, polygon, wkt, MultiPoint};
 
     #[test]
     fn polygon() {
@@ -127,5 +208,23 @@ mod test {
         let actual = multi_point.extremes();
 
         assert!(actual.is_none());
+        assert!(multi_point.extent_summary().is_none());
+    }
+
+    #[test]
+    fn extent_summary_covers_interiors() {
+        // an interior sticking out of the exterior of an invalid polygon
+        let polygon = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.),(1. 1.,3. 1.,1. 3.,1. 1.)));
+
+        let summary = polygon.extent_summary().unwrap();
+
+        assert_eq!(summary.bounding_rect, Rect::new((0., 0.), (3., 3.)));
+        assert_eq!(summary.coords_count, polygon.coords_count());
+        assert_eq!(summary.extremes.x_max.index, 6);
+        assert_eq!(summary.extremes.x_min, polygon.extremes().unwrap().x_min);
+
+        let summary = wkt!(MULTIPOINT(0. 0.,1. 1.)).extent_summary().unwrap();
+        assert!(!summary.is_closed);
+        assert_eq!(summary.coords_count, 2);
     }
 }
@@ -1,8 +1,12 @@
 use crate::geometry::*;
-use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+use crate::Ellipsoid;
+use geographiclib_rs::{PolygonArea, Winding};
 
 /// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth.
 ///
+/// Measurements are made on the [WGS84](Ellipsoid::WGS84) ellipsoid unless otherwise specified
+/// with one of the `_on` methods.
+///
 /// This uses the geodesic measurement methods given by [Karney (2013)].
 ///
 /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
@@ -57,7 +61,9 @@ pub trait GeodesicArea<T> {
     /// );
     /// ```
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-    fn geodesic_area_signed(&self) -> T;
+    fn geodesic_area_signed(&self) -> T {
+        self.geodesic_area_signed_on(Ellipsoid::WGS84)
+    }
 
     /// Determine the area of a geometry on an ellipsoidal model of the earth. Supports very large geometries that cover a significant portion of the earth.
     ///
@@ -94,7 +100,9 @@ pub trait GeodesicArea<T> {
     /// assert_eq!(area, 510053312945726.94);
     /// ```
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-    fn geodesic_area_unsigned(&self) -> T;
+    fn geodesic_area_unsigned(&self) -> T {
+        self.geodesic_area_unsigned_on(Ellipsoid::WGS84)
+    }
 
     /// Determine the perimeter of a geometry on an ellipsoidal model of the earth.
     ///
@@ -108,7 +116,9 @@ pub trait GeodesicArea<T> {
     /// - return value: meter
     ///
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-    fn geodesic_perimeter(&self) -> T;
+    fn geodesic_perimeter(&self) -> T {
+        self.geodesic_perimeter_on(Ellipsoid::WGS84)
+    }
 
     /// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth, all in one operation.
     ///
@@ -137,7 +147,9 @@ pub trait GeodesicArea<T> {
     /// 2. The polygon is larger than half the planet. In this case, the returned area of the polygon is not correct. If you expect to be dealing with very large polygons, please use the 'unsigned' methods.
     ///
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-    fn geodesic_perimeter_area_signed(&self) -> (T, T);
+    fn geodesic_perimeter_area_signed(&self) -> (T, T) {
+        self.geodesic_perimeter_area_signed_on(Ellipsoid::WGS84)
+    }
 
     /// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth, all in one operation. Supports very large geometries that cover a significant portion of the earth.
     ///
@@ -158,36 +170,97 @@ pub trait GeodesicArea<T> {
     /// - return value: (meter, meter²)
     ///
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-    fn geodesic_perimeter_area_unsigned(&self) -> (T, T);
-}
+    fn geodesic_perimeter_area_unsigned(&self) -> (T, T) {
+        self.geodesic_perimeter_area_unsigned_on(Ellipsoid::WGS84)
+    }
 
-impl GeodesicArea<f64> for Polygon {
-    fn geodesic_perimeter(&self) -> f64 {
-        let (perimeter, _area) = geodesic_area(self, true, false, false);
-        perimeter
+    /// Determine the area of a geometry on the given `ellipsoid`.
+    ///
+    /// See [`geodesic_area_signed`](Self::geodesic_area_signed) for assumptions about winding and size.
+    ///
+    /// # Units
+    ///
+    /// - return value: meter²
+    ///
+    /// # Examples
+    /// ```rust
+    /// use geo::{polygon, Ellipsoid, GeodesicArea};
+    ///
+    /// let polygon = polygon![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 1.0, y: 0.0),
+    ///     (x: 1.0, y: 1.0),
+    ///     (x: 0.0, y: 1.0),
+    /// ];
+    ///
+    /// let wgs84_area = polygon.geodesic_area_signed();
+    /// let grs80_area = polygon.geodesic_area_signed_on(Ellipsoid::GRS80);
+    /// assert_eq!(wgs84_area, polygon.geodesic_area_signed_on(Ellipsoid::WGS84));
+    /// assert_ne!(wgs84_area, grs80_area);
+    /// assert!((wgs84_area - grs80_area).abs() < 1.0);
+    /// ```
+    fn geodesic_area_signed_on(&self, ellipsoid: Ellipsoid) -> T {
+        self.geodesic_perimeter_area_signed_on(ellipsoid).1
     }
 
-    fn geodesic_area_signed(&self) -> f64 {
-        let (_perimeter, area) = geodesic_area(self, true, false, false);
-        area
+    /// Determine the area of a geometry on the given `ellipsoid`. Supports very large geometries.
+    ///
+    /// See [`geodesic_area_unsigned`](Self::geodesic_area_unsigned) for assumptions about winding.
+    ///
+    /// # Units
+    ///
+    /// - return value: meter²
+    fn geodesic_area_unsigned_on(&self, ellipsoid: Ellipsoid) -> T {
+        self.geodesic_perimeter_area_unsigned_on(ellipsoid).1
     }
 
-    fn geodesic_area_unsigned(&self) -> f64 {
-        let (_perimeter, area) = geodesic_area(self, false, false, false);
-        area
+    /// Determine the perimeter of a geometry on the given `ellipsoid`.
+    ///
+    /// # Units
+    ///
+    /// - return value: meter
+    fn geodesic_perimeter_on(&self, ellipsoid: Ellipsoid) -> T {
+        self.geodesic_perimeter_area_signed_on(ellipsoid).0
     }
 
-    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
-        geodesic_area(self, true, false, false)
+    /// Determine the perimeter and area of a geometry on the given `ellipsoid`, all in one operation.
+    ///
+    /// See [`geodesic_perimeter_area_signed`](Self::geodesic_perimeter_area_signed) for assumptions about winding and size.
+    ///
+    /// # Units
+    ///
+    /// - return value: (meter, meter²)
+    fn geodesic_perimeter_area_signed_on(&self, ellipsoid: Ellipsoid) -> (T, T);
+
+    /// Determine the perimeter and area of a geometry on the given `ellipsoid`, all in one operation.
+    /// Supports very large geometries.
+    ///
+    /// See [`geodesic_perimeter_area_unsigned`](Self::geodesic_perimeter_area_unsigned) for assumptions about winding.
+    ///
+    /// # Units
+    ///
+    /// - return value: (meter, meter²)
+    fn geodesic_perimeter_area_unsigned_on(&self, ellipsoid: Ellipsoid) -> (T, T);
+}
+
+impl GeodesicArea<f64> for Polygon {
+    fn geodesic_perimeter_area_signed_on(&self, ellipsoid: Ellipsoid) -> (f64, f64) {
+        geodesic_area(self, ellipsoid, true, false, false)
     }
 
-    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
-        geodesic_area(self, false, false, false)
+    fn geodesic_perimeter_area_unsigned_on(&self, ellipsoid: Ellipsoid) -> (f64, f64) {
+        geodesic_area(self, ellipsoid, false, false, false)
     }
 }
 
-fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool) -> (f64, f64) {
-    let g = Geodesic::wgs84();
+fn geodesic_area(
+    poly: &Polygon,
+    ellipsoid: Ellipsoid,
+    sign: bool,
+    reverse: bool,
+    exterior_only: bool,
+) -> (f64, f64) {
+    let g = ellipsoid.geodesic();
 
     let (exterior_winding, interior_winding) = if reverse {
         (Winding::Clockwise, Winding::CounterClockwise)
@@ -237,23 +310,11 @@ fn geodesic_area(poly: &Polygon, sign: bool, reverse: bool, exterior_only: bool)
 macro_rules! zero_impl {
     ($type:ident) => {
         impl GeodesicArea<f64> for $type {
-            fn geodesic_perimeter(&self) -> f64 {
-                0.0
-            }
-
-            fn geodesic_area_signed(&self) -> f64 {
-                0.0
-            }
-
-            fn geodesic_area_unsigned(&self) -> f64 {
-                0.0
-            }
-
-            fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+            fn geodesic_perimeter_area_signed_on(&self, _ellipsoid: Ellipsoid) -> (f64, f64) {
                 (0.0, 0.0)
             }
 
-            fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+            fn geodesic_perimeter_area_unsigned_on(&self, _ellipsoid: Ellipsoid) -> (f64, f64) {
                 (0.0, 0.0)
             }
         }
@@ -265,24 +326,14 @@ macro_rules! zero_impl {
 macro_rules! to_polygon_impl {
     ($type:ident) => {
         impl GeodesicArea<f64> for $type {
-            fn geodesic_perimeter(&self) -> f64 {
-                self.to_polygon().geodesic_perimeter()
-            }
-
-            fn geodesic_area_signed(&self) -> f64 {
-                self.to_polygon().geodesic_area_signed()
+            fn geodesic_perimeter_area_signed_on(&self, ellipsoid: Ellipsoid) -> (f64, f64) {
+                self.to_polygon()
+                    .geodesic_perimeter_area_signed_on(ellipsoid)
             }
 
-            fn geodesic_area_unsigned(&self) -> f64 {
-                self.to_polygon().geodesic_area_unsigned()
-            }
-
-            fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
-                self.to_polygon().geodesic_perimeter_area_signed()
-            }
-
-            fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
-                self.to_polygon().geodesic_perimeter_area_unsigned()
+            fn geodesic_perimeter_area_unsigned_on(&self, ellipsoid: Ellipsoid) -> (f64, f64) {
+                self.to_polygon()
+                    .geodesic_perimeter_area_unsigned_on(ellipsoid)
             }
         }
     };
@@ -293,33 +344,18 @@ macro_rules! to_polygon_impl {
 macro_rules! sum_impl {
     ($type:ident) => {
         impl GeodesicArea<f64> for $type {
-            fn geodesic_perimeter(&self) -> f64 {
-                self.iter()
-                    .fold(0.0, |total, next| total + next.geodesic_perimeter())
-            }
-
-            fn geodesic_area_signed(&self) -> f64 {
-                self.iter()
-                    .fold(0.0, |total, next| total + next.geodesic_area_signed())
-            }
-
-            fn geodesic_area_unsigned(&self) -> f64 {
-                self.iter()
-                    .fold(0.0, |total, next| total + next.geodesic_area_unsigned())
-            }
-
-            fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+            fn geodesic_perimeter_area_signed_on(&self, ellipsoid: Ellipsoid) -> (f64, f64) {
                 self.iter()
                     .fold((0.0, 0.0), |(total_perimeter, total_area), next| {
-                        let (perimeter, area) = next.geodesic_perimeter_area_signed();
+                        let (perimeter, area) = next.geodesic_perimeter_area_signed_on(ellipsoid);
                         (total_perimeter + perimeter, total_area + area)
                     })
             }
 
-            fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+            fn geodesic_perimeter_area_unsigned_on(&self, ellipsoid: Ellipsoid) -> (f64, f64) {
                 self.iter()
                     .fold((0.0, 0.0), |(total_perimeter, total_area), next| {
-                        let (perimeter, area) = next.geodesic_perimeter_area_unsigned();
+                        let (perimeter, area) = next.geodesic_perimeter_area_unsigned_on(ellipsoid);
                         (total_perimeter + perimeter, total_area + area)
                     })
             }
@@ -339,11 +375,8 @@ sum_impl!(MultiPolygon);
 
 impl GeodesicArea<f64> for Geometry<f64> {
     crate::geometry_delegate_impl! {
-        fn geodesic_perimeter(&self) -> f64;
-        fn geodesic_area_signed(&self) -> f64;
-        fn geodesic_area_unsigned(&self) -> f64;
-        fn geodesic_perimeter_area_signed(&self) -> (f64, f64);
-        fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64);
+        fn geodesic_perimeter_area_signed_on(&self, ellipsoid: Ellipsoid) -> (f64, f64);
+        fn geodesic_perimeter_area_unsigned_on(&self, ellipsoid: Ellipsoid) -> (f64, f64);
     }
 }
 
@@ -380,11 +413,45 @@ mod test {
 
         // Confirm that the exterior ring geodesic_length is the same as the perimeter
         assert_relative_eq!(
-            polygon.exterior().length::<Geodesic>(),
+            polygon.exterior().length(&Geodesic),
             polygon.geodesic_perimeter()
         );
     }
 
+    #[test]
+    fn test_custom_ellipsoid() {
+        let polygon = polygon![
+            (x: 125., y: -15.),
+            (x: 113., y: -22.),
+            (x: 117., y: -37.),
+            (x: 130., y: -33.),
+            (x: 148., y: -39.),
+            (x: 154., y: -27.),
+            (x: 144., y: -15.),
+            (x: 125., y: -15.),
+        ];
+        assert_eq!(
+            polygon.geodesic_perimeter_area_signed(),
+            polygon.geodesic_perimeter_area_signed_on(Ellipsoid::WGS84)
+        );
+
+        // on a sphere, one octant covers an eighth of the surface
+        let radius = crate::MEAN_EARTH_RADIUS;
+        let sphere = Ellipsoid::new(radius, 0.0);
+        let octant = polygon![(x: 0., y: 0.), (x: 90., y: 0.), (x: 0., y: 90.), (x: 0., y: 0.)];
+        assert_relative_eq!(
+            4.0 * std::f64::consts::PI * radius * radius / 8.0,
+            octant.geodesic_area_unsigned_on(sphere),
+            max_relative = 1.0e-9
+        );
+        assert_relative_eq!(
+            octant
+                .exterior()
+                .length(&crate::GeodesicMeasure::new(sphere)),
+            octant.geodesic_perimeter_on(sphere)
+        );
+    }
+
     #[test]
     fn test_positive() {
         let polygon = polygon![
@@ -410,7 +477,7 @@ mod test {
 
         // Confirm that the exterior ring geodesic_length is the same as the perimeter
         assert_relative_eq!(
-            polygon.exterior().length::<Geodesic>(),
+            polygon.exterior().length(&Geodesic),
             polygon.geodesic_perimeter()
         );
     }
@@ -440,7 +507,7 @@ mod test {
 
         // Confirm that the exterior ring geodesic_length is the same as the perimeter
         assert_relative_eq!(
-            polygon.exterior().length::<Geodesic>(),
+            polygon.exterior().length(&Geodesic),
             polygon.geodesic_perimeter()
         );
     }
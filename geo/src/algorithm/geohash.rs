@@ -0,0 +1,437 @@
+//! Encode and decode [geohashes](https://en.wikipedia.org/wiki/Geohash).
+//!
+//! A geohash identifies a cell of a grid over lon/lat coordinates with a short string. Each
+//! additional character subdivides a cell into 32 smaller ones, so hashes sharing a prefix are
+//! close together, which makes them convenient keys for spatial indexing.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::geohash::{decode_geohash, geohash_neighbor, Direction, Geohash, GeohashCells};
+//! use geo::{point, wkt, Contains};
+//!
+//! let point = point!(x: -5.6, y: 42.6);
+//! let hash = point.geohash(5).unwrap();
+//! assert_eq!(hash, "ezs42");
+//!
+//! let cell = decode_geohash::<f64>(&hash).unwrap();
+//! assert!(cell.contains(&point));
+//!
+//! let east = geohash_neighbor(&hash, Direction::East).unwrap().unwrap();
+//! assert_eq!(geohash_neighbor(&east, Direction::West).unwrap().unwrap(), hash);
+//!
+//! let polygon = wkt!(POLYGON((-5.6 42.6,-5.5 42.6,-5.5 42.65,-5.6 42.6)));
+//! let cells = polygon.geohash_cells(5).unwrap();
+//! assert!(cells.contains(&hash));
+//! ```
+
+use crate::{BoundingRect, Coord, CoordFloat, Point, Rect};
+use std::fmt;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The longest supported geohash, which is precise to a few centimeters.
+pub const MAX_PRECISION: usize = 12;
+
+/// The error returned by geohash operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeohashError {
+    /// The precision was 0 or greater than [`MAX_PRECISION`].
+    InvalidPrecision(usize),
+    /// The hash contains a character which isn't part of the geohash alphabet.
+    InvalidCharacter(char),
+    /// The coordinate is not a valid lon/lat coordinate.
+    InvalidCoordinate,
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeohashError::InvalidPrecision(precision) => write!(
+                f,
+                "geohash precision must be between 1 and {MAX_PRECISION}, got {precision}"
+            ),
+            GeohashError::InvalidCharacter(c) => write!(f, "invalid geohash character {c:?}"),
+            GeohashError::InvalidCoordinate => {
+                write!(f, "coordinate is outside of the lon/lat range")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeohashError {}
+
+/// A direction to a neighboring geohash cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All the directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    // The change in (longitude, latitude) cell index
+    fn offset(self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, 1),
+            Direction::NorthEast => (1, 1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, -1),
+            Direction::South => (0, -1),
+            Direction::SouthWest => (-1, -1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, 1),
+        }
+    }
+}
+
+/// Encode the location of a lon/lat geometry as a geohash.
+pub trait Geohash<T: CoordFloat> {
+    /// The geohash, `precision` characters long, of the cell containing `self`.
+    ///
+    /// # Errors
+    ///
+    /// If `precision` is 0 or greater than [`MAX_PRECISION`], or the coordinate is outside the
+    /// lon/lat range.
+    fn geohash(&self, precision: usize) -> Result<String, GeohashError>;
+}
+
+impl<T: CoordFloat> Geohash<T> for Coord<T> {
+    fn geohash(&self, precision: usize) -> Result<String, GeohashError> {
+        let grid = Grid::new(precision)?;
+        let (lon_index, lat_index) = grid.cell_of(*self)?;
+        Ok(grid.encode(lon_index, lat_index))
+    }
+}
+
+impl<T: CoordFloat> Geohash<T> for Point<T> {
+    fn geohash(&self, precision: usize) -> Result<String, GeohashError> {
+        self.0.geohash(precision)
+    }
+}
+
+/// Decode a geohash to the lon/lat cell it identifies.
+pub fn decode_geohash<T: CoordFloat>(hash: &str) -> Result<Rect<T>, GeohashError> {
+    let (grid, lon_index, lat_index) = Grid::decode(hash)?;
+    Ok(grid.cell(lon_index, lat_index))
+}
+
+/// The geohash of the cell adjacent to the cell of `hash` in `direction`, with the same
+/// precision.
+///
+/// Cells wrap around the antimeridian. Returns `Ok(None)` for the cells beyond the poles.
+pub fn geohash_neighbor(hash: &str, direction: Direction) -> Result<Option<String>, GeohashError> {
+    let (grid, lon_index, lat_index) = Grid::decode(hash)?;
+    let (lon_offset, lat_offset) = direction.offset();
+    let lat_index = lat_index as i64 + lat_offset;
+    if lat_index < 0 || lat_index >= 1 << grid.lat_bits {
+        return Ok(None);
+    }
+    let lon_index = (lon_index as i64 + lon_offset).rem_euclid(1 << grid.lon_bits);
+    Ok(Some(grid.encode(lon_index as u64, lat_index as u64)))
+}
+
+/// The geohashes of the (up to 8) cells adjacent to the cell of `hash`, clockwise from north.
+///
+/// Cells wrap around the antimeridian, and there are no cells beyond the poles.
+pub fn geohash_neighbors(hash: &str) -> Result<Vec<String>, GeohashError> {
+    let mut neighbors = Vec::with_capacity(Direction::ALL.len());
+    for direction in Direction::ALL {
+        neighbors.extend(geohash_neighbor(hash, direction)?);
+    }
+    Ok(neighbors)
+}
+
+/// The geohash cells covering the [bounding rectangle](BoundingRect) of a lon/lat geometry.
+///
+/// The number of cells grows quickly with the precision: each additional character multiplies
+/// it by about 32.
+pub trait GeohashCells<T: CoordFloat> {
+    /// The geohashes, `precision` characters long, of the cells intersecting the bounding
+    /// rectangle of `self`, ordered by latitude, then longitude. Empty geometries have no
+    /// cells.
+    ///
+    /// # Errors
+    ///
+    /// If `precision` is 0 or greater than [`MAX_PRECISION`], or the bounding rectangle is
+    /// outside the lon/lat range.
+    fn geohash_cells(&self, precision: usize) -> Result<Vec<String>, GeohashError>;
+}
+
+impl<T, G> GeohashCells<T> for G
+where
+    T: CoordFloat,
+    G: BoundingRect<T>,
+    G::Output: Into<Option<Rect<T>>>,
+{
+    fn geohash_cells(&self, precision: usize) -> Result<Vec<String>, GeohashError> {
+        let grid = Grid::new(precision)?;
+        let Some(rect) = self.bounding_rect().into() else {
+            return Ok(vec![]);
+        };
+        let (min_lon_index, min_lat_index) = grid.cell_of(rect.min())?;
+        let (max_lon_index, max_lat_index) = grid.cell_of(rect.max())?;
+        let mut cells = Vec::new();
+        for lat_index in min_lat_index..=max_lat_index {
+            for lon_index in min_lon_index..=max_lon_index {
+                cells.push(grid.encode(lon_index, lat_index));
+            }
+        }
+        Ok(cells)
+    }
+}
+
+// The grid of cells of geohashes of a given precision. Cells are numbered from the south west,
+// and a geohash interleaves the bits of the longitude and latitude cell indices, starting with
+// the longitude.
+pub(crate) struct Grid {
+    precision: usize,
+    lon_bits: u32,
+    lat_bits: u32,
+}
+
+impl Grid {
+    pub(crate) fn new(precision: usize) -> Result<Self, GeohashError> {
+        if !(1..=MAX_PRECISION).contains(&precision) {
+            return Err(GeohashError::InvalidPrecision(precision));
+        }
+        let bits = 5 * precision as u32;
+        Ok(Self {
+            precision,
+            lon_bits: bits.div_ceil(2),
+            lat_bits: bits / 2,
+        })
+    }
+
+    pub(crate) fn cell_of<T: CoordFloat>(
+        &self,
+        coord: Coord<T>,
+    ) -> Result<(u64, u64), GeohashError> {
+        let (lon, lat) = (coord.x.to_f64(), coord.y.to_f64());
+        let (Some(lon), Some(lat)) = (lon, lat) else {
+            return Err(GeohashError::InvalidCoordinate);
+        };
+        if !(-180.0..=180.0).contains(&lon) || !(-90.0..=90.0).contains(&lat) {
+            return Err(GeohashError::InvalidCoordinate);
+        }
+        Ok((
+            Self::index(lon, -180.0, 360.0, self.lon_bits),
+            Self::index(lat, -90.0, 180.0, self.lat_bits),
+        ))
+    }
+
+    fn index(value: f64, min: f64, range: f64, bits: u32) -> u64 {
+        let cells = (1u64 << bits) as f64;
+        // The maximum value belongs to the last cell
+        (((value - min) / range * cells) as u64).min((1 << bits) - 1)
+    }
+
+    pub(crate) fn cell<T: CoordFloat>(&self, lon_index: u64, lat_index: u64) -> Rect<T> {
+        let width = 360.0 / (1u64 << self.lon_bits) as f64;
+        let height = 180.0 / (1u64 << self.lat_bits) as f64;
+        let min_lon = -180.0 + lon_index as f64 * width;
+        let min_lat = -90.0 + lat_index as f64 * height;
+        Rect::new(
+            Coord {
+                x: T::from(min_lon).unwrap(),
+                y: T::from(min_lat).unwrap(),
+            },
+            Coord {
+                x: T::from(min_lon + width).unwrap(),
+                y: T::from(min_lat + height).unwrap(),
+            },
+        )
+    }
+
+    /// The cells adjacent to a cell, wrapping around the antimeridian.
+    pub(crate) fn neighbors(&self, lon_index: u64, lat_index: u64) -> Vec<(u64, u64)> {
+        let lon_cells = 1i64 << self.lon_bits;
+        let lat_cells = 1i64 << self.lat_bits;
+        Direction::ALL
+            .iter()
+            .filter_map(|direction| {
+                let (dx, dy) = direction.offset();
+                let lat_index = lat_index as i64 + dy;
+                if !(0..lat_cells).contains(&lat_index) {
+                    return None;
+                }
+                let lon_index = (lon_index as i64 + dx).rem_euclid(lon_cells);
+                Some((lon_index as u64, lat_index as u64))
+            })
+            .collect()
+    }
+
+    /// The geohash of a cell as an integer, i.e. its interleaved bits.
+    pub(crate) fn id(&self, lon_index: u64, lat_index: u64) -> u64 {
+        let (mut lon_bit, mut lat_bit) = (self.lon_bits, self.lat_bits);
+        (0..5 * self.precision).fold(0, |id, bit| {
+            let value = if bit % 2 == 0 {
+                lon_bit -= 1;
+                (lon_index >> lon_bit) & 1
+            } else {
+                lat_bit -= 1;
+                (lat_index >> lat_bit) & 1
+            };
+            (id << 1) | value
+        })
+    }
+
+    fn encode(&self, lon_index: u64, lat_index: u64) -> String {
+        let mut hash = String::with_capacity(self.precision);
+        let (mut lon_bit, mut lat_bit) = (self.lon_bits, self.lat_bits);
+        let mut char_index = 0;
+        for bit in 0..5 * self.precision {
+            let value = if bit % 2 == 0 {
+                lon_bit -= 1;
+                (lon_index >> lon_bit) & 1
+            } else {
+                lat_bit -= 1;
+                (lat_index >> lat_bit) & 1
+            };
+            char_index = (char_index << 1) | value as usize;
+            if bit % 5 == 4 {
+                hash.push(BASE32[char_index] as char);
+                char_index = 0;
+            }
+        }
+        hash
+    }
+
+    pub(crate) fn decode(hash: &str) -> Result<(Self, u64, u64), GeohashError> {
+        let grid = Self::new(hash.chars().count())?;
+        let (mut lon_index, mut lat_index) = (0u64, 0u64);
+        let mut bit = 0;
+        for c in hash.chars() {
+            let char_index = BASE32
+                .iter()
+                .position(|&b| b as char == c)
+                .ok_or(GeohashError::InvalidCharacter(c))?;
+            for shift in (0..5).rev() {
+                let value = ((char_index >> shift) & 1) as u64;
+                if bit % 2 == 0 {
+                    lon_index = (lon_index << 1) | value;
+                } else {
+                    lat_index = (lat_index << 1) | value;
+                }
+                bit += 1;
+            }
+        }
+        Ok((grid, lon_index, lat_index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, point, wkt, Contains, MultiPoint};
+
+    #[test]
+    fn encode_decode() {
+        let point = point!(x: 10.40744, y: 57.64911);
+        assert_eq!(point.geohash(11).unwrap(), "u4pruydqqvj");
+        for precision in 1..=MAX_PRECISION {
+            let hash = point.geohash(precision).unwrap();
+            assert_eq!(hash.len(), precision);
+            let cell: Rect<f64> = decode_geohash(&hash).unwrap();
+            assert!(cell.contains(&point));
+            assert_eq!(cell.center().geohash(precision).unwrap(), hash);
+        }
+
+        // The corners of the world
+        assert_eq!(coord! { x: -180., y: -90. }.geohash(3).unwrap(), "000");
+        assert_eq!(coord! { x: 180., y: 90. }.geohash(3).unwrap(), "zzz");
+        assert_eq!(
+            decode_geohash::<f64>("s").unwrap(),
+            Rect::new((0., 0.), (45., 45.))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let point = point!(x: 0., y: 0.);
+        assert_eq!(point.geohash(0), Err(GeohashError::InvalidPrecision(0)));
+        assert_eq!(point.geohash(13), Err(GeohashError::InvalidPrecision(13)));
+        assert_eq!(
+            point!(x: 181., y: 0.).geohash(5),
+            Err(GeohashError::InvalidCoordinate)
+        );
+        assert_eq!(
+            point!(x: f64::NAN, y: 0.).geohash(5),
+            Err(GeohashError::InvalidCoordinate)
+        );
+        assert_eq!(
+            decode_geohash::<f64>("ezs4a"),
+            Err(GeohashError::InvalidCharacter('a'))
+        );
+        assert_eq!(
+            decode_geohash::<f64>(""),
+            Err(GeohashError::InvalidPrecision(0))
+        );
+    }
+
+    #[test]
+    fn neighbors() {
+        let hash = "ezs42";
+        let cell: Rect<f64> = decode_geohash(hash).unwrap();
+        for direction in Direction::ALL {
+            let neighbor = geohash_neighbor(hash, direction).unwrap().unwrap();
+            let neighbor_cell: Rect<f64> = decode_geohash(&neighbor).unwrap();
+            let (dx, dy) = direction.offset();
+            assert_eq!(
+                neighbor_cell.center(),
+                cell.center()
+                    + coord! { x: dx as f64 * cell.width(), y: dy as f64 * cell.height() }
+            );
+        }
+        assert_eq!(geohash_neighbors(hash).unwrap().len(), 8);
+
+        // Wrapping around the antimeridian, and stopping at the poles
+        assert_eq!(
+            geohash_neighbor("zzz", Direction::East).unwrap(),
+            Some(coord! { x: -179., y: 89. }.geohash(3).unwrap())
+        );
+        assert_eq!(geohash_neighbor("zzz", Direction::North).unwrap(), None);
+        assert_eq!(geohash_neighbors("zzz").unwrap().len(), 5);
+    }
+
+    #[test]
+    fn cells() {
+        let polygon = wkt!(POLYGON((-5.6 42.6,-5.5 42.6,-5.5 42.65,-5.6 42.6)));
+        let cells = polygon.geohash_cells(5).unwrap();
+        let rect = polygon.bounding_rect().unwrap();
+        for hash in &cells {
+            let cell: Rect<f64> = decode_geohash(hash).unwrap();
+            assert!(cell.min().x <= rect.max().x && cell.max().x >= rect.min().x);
+            assert!(cell.min().y <= rect.max().y && cell.max().y >= rect.min().y);
+        }
+        let cell_area: f64 = cells
+            .iter()
+            .map(|hash| decode_geohash::<f64>(hash).unwrap())
+            .map(|cell| cell.width() * cell.height())
+            .sum();
+        assert!(cell_area >= rect.width() * rect.height());
+
+        assert_eq!(point!(x: 1., y: 1.).geohash_cells(4).unwrap().len(), 1);
+        assert!(MultiPoint::<f64>::new(vec![])
+            .geohash_cells(4)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,213 @@
+//! Convert geometries to and from [GeoJSON] text, using the [`geojson` crate] (requires the
+//! `use-geojson` feature).
+//!
+//! - [`ToGeoJson`](crate::geojson_conv::ToGeoJson) converts a geometry to a GeoJSON geometry
+//!   object, or its text.
+//! - [`geometry_from_geojson_str`](crate::geojson_conv::geometry_from_geojson_str) parses any
+//!   GeoJSON object into a [`Geometry`](crate::Geometry), a `FeatureCollection` becoming a
+//!   `GeometryCollection` of the geometries of its features.
+//! - [`features_from_geojson_str`](crate::geojson_conv::features_from_geojson_str) and
+//!   [`geometries_with_properties`](crate::geojson_conv::geometries_with_properties) iterate over
+//!   the features of a GeoJSON object as pairs of their geometries and properties.
+//!
+//! The types of the `geojson` crate needed to use them are re-exported, so that they match the
+//! version `geo` was built with.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::geojson_conv::{features_from_geojson_str, geometry_from_geojson_str, ToGeoJson};
+//! use geo::{wkt, Geometry};
+//!
+//! let text = r#"{
+//!     "type": "FeatureCollection",
+//!     "features": [{
+//!         "type": "Feature",
+//!         "geometry": { "type": "Point", "coordinates": [1.0, 2.0] },
+//!         "properties": { "name": "a" }
+//!     }]
+//! }"#;
+//!
+//! for feature in features_from_geojson_str(text).unwrap() {
+//!     let (geometry, properties) = feature.unwrap();
+//!     assert_eq!(geometry, Geometry::Point(wkt!(POINT(1. 2.))));
+//!     assert_eq!(properties["name"], "a");
+//!
+//!     let text = geometry.to_geojson_string();
+//!     assert_eq!(geometry_from_geojson_str(&text).unwrap(), geometry);
+//! }
+//! ```
+//!
+//! [GeoJSON]: https://datatracker.ietf.org/doc/html/rfc7946
+//! [`geojson` crate]: https://docs.rs/geojson
+
+use std::str::FromStr;
+
+pub use ::geojson::{Error, Feature, FeatureCollection, GeoJson, JsonObject};
+
+use crate::Geometry;
+
+/// Convert a geometry to a GeoJSON geometry object.
+///
+/// This is implemented for every geometry type with float coordinates. A `Line` becomes a
+/// `LineString`, and a `Rect` or `Triangle` a `Polygon`.
+pub trait ToGeoJson {
+    /// The GeoJSON geometry object of the geometry.
+    fn to_geojson(&self) -> ::geojson::Geometry;
+
+    /// The GeoJSON text of the geometry.
+    fn to_geojson_string(&self) -> String {
+        self.to_geojson().to_string()
+    }
+}
+
+impl<G> ToGeoJson for G
+where
+    for<'a> ::geojson::Value: From<&'a G>,
+{
+    fn to_geojson(&self) -> ::geojson::Geometry {
+        ::geojson::Geometry::new(self.into())
+    }
+}
+
+/// Parse GeoJSON text into a [`Geometry`].
+///
+/// A `Feature` becomes its geometry, and a `FeatureCollection` a `GeometryCollection` of the
+/// geometries of its features, skipping those without one.
+///
+/// The [`Error`] is boxed, as it's large.
+pub fn geometry_from_geojson_str(geojson: &str) -> Result<Geometry<f64>, Box<Error>> {
+    Ok(Geometry::try_from(GeoJson::from_str(geojson)?)?)
+}
+
+/// Parse GeoJSON text, and iterate over its features as pairs of their geometries and
+/// properties.
+///
+/// See [`geometries_with_properties`].
+pub fn features_from_geojson_str(geojson: &str) -> Result<GeometriesWithProperties, Box<Error>> {
+    Ok(geometries_with_properties(GeoJson::from_str(geojson)?))
+}
+
+/// Iterate over the features of `geojson` as pairs of their geometries and properties.
+///
+/// A `FeatureCollection` yields each of its features in turn, a `Feature` itself, and a bare
+/// geometry itself with no properties. A feature without a geometry yields an
+/// [`Error::FeatureHasNoGeometry`], and one without properties an empty [`JsonObject`].
+pub fn geometries_with_properties(geojson: GeoJson) -> GeometriesWithProperties {
+    let features = match geojson {
+        GeoJson::FeatureCollection(collection) => collection.features,
+        GeoJson::Feature(feature) => vec![feature],
+        GeoJson::Geometry(geometry) => vec![Feature::from(geometry)],
+    };
+    GeometriesWithProperties {
+        features: features.into_iter(),
+    }
+}
+
+/// An iterator over the features of a GeoJSON object, as pairs of their geometries and
+/// properties.
+///
+/// Created by [`geometries_with_properties`] and [`features_from_geojson_str`].
+#[derive(Debug, Clone)]
+pub struct GeometriesWithProperties {
+    features: std::vec::IntoIter<Feature>,
+}
+
+impl Iterator for GeometriesWithProperties {
+    type Item = Result<(Geometry<f64>, JsonObject), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut feature = self.features.next()?;
+        let Some(geometry) = feature.geometry.take() else {
+            return Some(Err(Error::FeatureHasNoGeometry(feature)));
+        };
+        let properties = feature.properties.unwrap_or_default();
+        Some(Geometry::try_from(geometry).map(|geometry| (geometry, properties)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.features.size_hint()
+    }
+}
+
+impl ExactSizeIterator for GeometriesWithProperties {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, GeometryCollection, Rect};
+
+    #[test]
+    fn round_trip() {
+        let geometries: Vec<Geometry> = vec![
+            wkt!(POINT(1. 2.)).into(),
+            wkt!(LINESTRING(0. 0.,1. 1.)).into(),
+            wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.))).into(),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)))).into(),
+            Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(POINT(1. 2.)))),
+        ];
+        for geometry in geometries {
+            let text = geometry.to_geojson_string();
+            assert_eq!(geometry_from_geojson_str(&text).unwrap(), geometry);
+        }
+
+        let rect = Rect::new((0., 0.), (1., 2.));
+        let text = rect.to_geojson_string();
+        assert_eq!(
+            geometry_from_geojson_str(&text).unwrap(),
+            Geometry::Polygon(rect.to_polygon())
+        );
+    }
+
+    #[test]
+    fn features() {
+        let text = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [1.0, 2.0] },
+                    "properties": { "id": 1 }
+                },
+                { "type": "Feature", "geometry": null, "properties": { "id": 2 } },
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0]] },
+                    "properties": null
+                }
+            ]
+        }"#;
+        let features: Vec<_> = features_from_geojson_str(text).unwrap().collect();
+        assert_eq!(features.len(), 3);
+
+        let (geometry, properties) = features[0].as_ref().unwrap();
+        assert_eq!(*geometry, Geometry::Point(wkt!(POINT(1. 2.))));
+        assert_eq!(properties["id"], 1);
+        assert!(matches!(features[1], Err(Error::FeatureHasNoGeometry(_))));
+        let (geometry, properties) = features[2].as_ref().unwrap();
+        assert_eq!(
+            *geometry,
+            Geometry::LineString(wkt!(LINESTRING(0. 0.,1. 1.)))
+        );
+        assert!(properties.is_empty());
+
+        // the geometries of the features with one
+        assert_eq!(
+            geometry_from_geojson_str(text).unwrap(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                wkt!(POINT(1. 2.)).into(),
+                wkt!(LINESTRING(0. 0.,1. 1.)).into(),
+            ]))
+        );
+
+        // a bare geometry
+        let mut features =
+            features_from_geojson_str(r#"{"type":"Point","coordinates":[3,4]}"#).unwrap();
+        let (geometry, properties) = features.next().unwrap().unwrap();
+        assert_eq!(geometry, Geometry::Point(wkt!(POINT(3. 4.))));
+        assert!(properties.is_empty());
+        assert!(features.next().is_none());
+
+        assert!(features_from_geojson_str("{").is_err());
+    }
+}
@@ -0,0 +1,331 @@
+use crate::bounding_rect::bounding_rect_merge;
+use crate::geometry::*;
+use crate::CoordFloat;
+
+/// Compute the statistics of a geometry in a single pass over its coordinates, rather than
+/// computing its [`Area`](crate::Area), [`BoundingRect`](crate::BoundingRect), segment lengths and
+/// coordinate counts separately.
+///
+/// Typical of the checks on the quality of data: tiny segments hint at noise or duplicate
+/// vertices, and huge ones at bad coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, GeometryStatistics, Rect};
+///
+/// let polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 3.,0. 0.),(2. 0.5,3. 0.5,3. 1.5,2. 0.5)));
+/// let stats = polygon.geometry_stats();
+///
+/// assert_eq!(stats.vertex_count, 8);
+/// assert_eq!(stats.ring_count, 2);
+/// assert_eq!(stats.part_count, 1);
+/// assert_eq!(stats.segment_count, 6);
+/// assert_eq!(stats.min_segment_length, Some(1.));
+/// assert_eq!(stats.max_segment_length, Some(5.));
+/// assert_eq!(stats.area, 6. - 0.5);
+/// assert_eq!(stats.perimeter, 12. + 2. + 2f64.sqrt());
+/// assert_eq!(stats.bounding_rect, Some(Rect::new((0., 0.), (4., 3.))));
+/// ```
+pub trait GeometryStatistics<T: CoordFloat> {
+    fn geometry_stats(&self) -> GeometryStats<T>;
+}
+
+/// The result of [`GeometryStatistics::geometry_stats`].
+///
+/// Segments are those of lines, line strings and the rings of polygons, rects and triangles.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryStats<T: CoordFloat = f64> {
+    /// The number of coordinates, as in [`CoordsIter::coords_count`](crate::CoordsIter::coords_count).
+    pub vertex_count: usize,
+    /// The number of non-empty rings of the polygons, rects and triangles.
+    pub ring_count: usize,
+    /// The number of single geometries (points, lines, line strings, polygons, rects and
+    /// triangles), counting the members of multi geometries and collections.
+    pub part_count: usize,
+    /// The number of segments.
+    pub segment_count: usize,
+    /// The length of the shortest segment, or `None` if there are no segments.
+    pub min_segment_length: Option<T>,
+    /// The length of the longest segment, or `None` if there are no segments.
+    pub max_segment_length: Option<T>,
+    /// The mean length of the segments, or `None` if there are no segments.
+    pub mean_segment_length: Option<T>,
+    /// The total length of the segments.
+    pub length: T,
+    /// The unsigned Euclidean area.
+    pub area: T,
+    /// The total length of the rings.
+    pub perimeter: T,
+    /// The bounding rectangle of all the coordinates, including those of polygon interiors, or
+    /// `None` if there are none.
+    pub bounding_rect: Option<Rect<T>>,
+}
+
+impl<T: CoordFloat> GeometryStats<T> {
+    fn new() -> Self {
+        Self {
+            vertex_count: 0,
+            ring_count: 0,
+            part_count: 0,
+            segment_count: 0,
+            min_segment_length: None,
+            max_segment_length: None,
+            mean_segment_length: None,
+            length: T::zero(),
+            area: T::zero(),
+            perimeter: T::zero(),
+            bounding_rect: None,
+        }
+    }
+
+    fn finish(mut self) -> Self {
+        self.mean_segment_length =
+            (self.segment_count > 0).then(|| self.length / T::from(self.segment_count).unwrap());
+        self
+    }
+
+    fn add_coord(&mut self, coord: Coord<T>) {
+        self.vertex_count += 1;
+        let rect = Rect::new(coord, coord);
+        self.bounding_rect = Some(match self.bounding_rect {
+            Some(bounding_rect) => bounding_rect_merge(bounding_rect, rect),
+            None => rect,
+        });
+    }
+
+    /// Add the segments between `coords`, returning their length.
+    fn add_path(&mut self, coords: impl IntoIterator<Item = Coord<T>>) -> T {
+        let mut coords = coords.into_iter();
+        let Some(mut previous) = coords.next() else {
+            return T::zero();
+        };
+        self.add_coord(previous);
+        let mut length = T::zero();
+        for coord in coords {
+            self.add_coord(coord);
+            let segment_length = (coord - previous).x.hypot((coord - previous).y);
+            self.segment_count += 1;
+            self.min_segment_length = Some(match self.min_segment_length {
+                Some(min) => min.min(segment_length),
+                None => segment_length,
+            });
+            self.max_segment_length = Some(match self.max_segment_length {
+                Some(max) => max.max(segment_length),
+                None => segment_length,
+            });
+            length = length + segment_length;
+            previous = coord;
+        }
+        self.length = self.length + length;
+        length
+    }
+
+    /// Add a closed ring, returning its unsigned area.
+    fn add_ring(&mut self, ring: &[Coord<T>]) -> T {
+        let Some(&shift) = ring.first() else {
+            return T::zero();
+        };
+        self.ring_count += 1;
+        self.perimeter = self.perimeter + self.add_path(ring.iter().copied());
+        let twice_area = ring.windows(2).fold(T::zero(), |total, segment| {
+            let (a, b) = (segment[0] - shift, segment[1] - shift);
+            total + (a.x * b.y - a.y * b.x)
+        });
+        (twice_area / (T::one() + T::one())).abs()
+    }
+
+    /// Add a ring of a rect or triangle, whose closing coordinate isn't one of its vertices,
+    /// returning its unsigned area.
+    fn add_implicitly_closed_ring(&mut self, ring: &[Coord<T>]) -> T {
+        let area = self.add_ring(ring);
+        self.vertex_count -= 1;
+        area
+    }
+
+    fn add_point(&mut self, point: &Point<T>) {
+        self.part_count += 1;
+        self.add_coord(point.0);
+    }
+
+    fn add_line(&mut self, line: &Line<T>) {
+        self.part_count += 1;
+        self.add_path([line.start, line.end]);
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<T>) {
+        self.part_count += 1;
+        self.add_path(line_string.0.iter().copied());
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        self.part_count += 1;
+        let area = polygon
+            .interiors()
+            .iter()
+            .fold(self.add_ring(&polygon.exterior().0), |area, interior| {
+                area - self.add_ring(&interior.0)
+            });
+        self.area = self.area + area.abs();
+    }
+
+    fn add_multi_point(&mut self, multi_point: &MultiPoint<T>) {
+        multi_point.iter().for_each(|point| self.add_point(point));
+    }
+
+    fn add_multi_line_string(&mut self, multi_line_string: &MultiLineString<T>) {
+        multi_line_string
+            .iter()
+            .for_each(|line_string| self.add_line_string(line_string));
+    }
+
+    fn add_multi_polygon(&mut self, multi_polygon: &MultiPolygon<T>) {
+        multi_polygon
+            .iter()
+            .for_each(|polygon| self.add_polygon(polygon));
+    }
+
+    fn add_geometry_collection(&mut self, collection: &GeometryCollection<T>) {
+        collection
+            .iter()
+            .for_each(|geometry| self.add_geometry(geometry));
+    }
+
+    fn add_rect(&mut self, rect: &Rect<T>) {
+        self.part_count += 1;
+        let (min, max) = (rect.min(), rect.max());
+        let area = self.add_implicitly_closed_ring(&[
+            min,
+            Coord { x: min.x, y: max.y },
+            max,
+            Coord { x: max.x, y: min.y },
+            min,
+        ]);
+        self.area = self.area + area;
+    }
+
+    fn add_triangle(&mut self, triangle: &Triangle<T>) {
+        self.part_count += 1;
+        let area =
+            self.add_implicitly_closed_ring(&[triangle.0, triangle.1, triangle.2, triangle.0]);
+        self.area = self.area + area;
+    }
+
+    fn add_geometry(&mut self, geometry: &Geometry<T>) {
+        match geometry {
+            Geometry::Point(g) => self.add_point(g),
+            Geometry::Line(g) => self.add_line(g),
+            Geometry::LineString(g) => self.add_line_string(g),
+            Geometry::Polygon(g) => self.add_polygon(g),
+            Geometry::MultiPoint(g) => self.add_multi_point(g),
+            Geometry::MultiLineString(g) => self.add_multi_line_string(g),
+            Geometry::MultiPolygon(g) => self.add_multi_polygon(g),
+            Geometry::GeometryCollection(g) => self.add_geometry_collection(g),
+            Geometry::Rect(g) => self.add_rect(g),
+            Geometry::Triangle(g) => self.add_triangle(g),
+        }
+    }
+}
+
+macro_rules! impl_geometry_statistics {
+    ($($type:ident => $add:ident),* $(,)?) => {
+        $(
+            impl<T: CoordFloat> GeometryStatistics<T> for $type<T> {
+                fn geometry_stats(&self) -> GeometryStats<T> {
+                    let mut stats = GeometryStats::new();
+                    stats.$add(self);
+                    stats.finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_geometry_statistics!(
+    Point => add_point,
+    Line => add_line,
+    LineString => add_line_string,
+    Polygon => add_polygon,
+    MultiPoint => add_multi_point,
+    MultiLineString => add_multi_line_string,
+    MultiPolygon => add_multi_polygon,
+    GeometryCollection => add_geometry_collection,
+    Rect => add_rect,
+    Triangle => add_triangle,
+    Geometry => add_geometry,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Area, BoundingRect, CoordsIter, Euclidean, Length};
+
+    fn assert_consistent(geometry: Geometry) {
+        let stats = geometry.geometry_stats();
+        assert_eq!(stats.vertex_count, geometry.coords_count());
+        assert_relative_eq!(stats.area, geometry.unsigned_area());
+        if !matches!(geometry, Geometry::Polygon(_) | Geometry::MultiPolygon(_)) {
+            // for polygons, the bounding rect also covers the interiors
+            assert_eq!(stats.bounding_rect, geometry.bounding_rect());
+        }
+    }
+
+    #[test]
+    fn matches_separate_algorithms() {
+        let geometries: Vec<Geometry> = vec![
+            wkt!(POINT(1. 2.)).into(),
+            wkt!(LINESTRING(0. 0.,3. 4.,3. 5.)).into(),
+            wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 0.),(5. 1.,9. 1.,9. 5.,5. 1.))).into(),
+            wkt!(MULTIPOINT(0. 0.,1. 1.)).into(),
+            wkt!(MULTILINESTRING((0. 0.,1. 1.),(2. 2.,3. 3.))).into(),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((5. 5.,5. 7.,7. 7.,5. 5.)))).into(),
+            Rect::new((0., 0.), (2., 3.)).into(),
+            Line::new((0., 0.), (1., 10.)).into(),
+            Triangle::new((0., 0.).into(), (4., 0.).into(), (0., 3.).into()).into(),
+        ];
+        for geometry in &geometries {
+            assert_consistent(geometry.clone());
+        }
+        let collection = Geometry::GeometryCollection(GeometryCollection(geometries));
+        assert_consistent(collection.clone());
+
+        let stats = collection.geometry_stats();
+        assert_eq!(stats.part_count, 1 + 1 + 1 + 2 + 2 + 2 + 1 + 1 + 1);
+        assert_eq!(stats.ring_count, 2 + 2 + 1 + 1);
+        assert_eq!(stats.segment_count, 2 + 6 + 2 + 6 + 4 + 1 + 3);
+        assert_eq!(stats.min_segment_length, Some(1.));
+        assert_eq!(stats.max_segment_length, Some(200f64.sqrt()));
+        assert_relative_eq!(
+            stats.mean_segment_length.unwrap(),
+            stats.length / stats.segment_count as f64
+        );
+        let line_string = wkt!(LINESTRING(0. 0.,3. 4.,3. 5.));
+        assert_eq!(
+            line_string.geometry_stats().length,
+            line_string.length(&Euclidean)
+        );
+        assert_eq!(line_string.geometry_stats().perimeter, 0.);
+        assert_eq!(
+            Rect::new((0., 0.), (2., 3.)).geometry_stats().perimeter,
+            10.
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let polygon: Polygon = wkt!(POLYGON EMPTY);
+        let stats = polygon.geometry_stats();
+        assert_eq!(
+            stats,
+            GeometryStats {
+                part_count: 1,
+                ..GeometryStats::new()
+            }
+        );
+        let stats: GeometryStats = wkt!(GEOMETRYCOLLECTION EMPTY).geometry_stats();
+        assert_eq!(stats, GeometryStats::new());
+        assert_eq!(stats.mean_segment_length, None);
+        assert_eq!(stats.bounding_rect, None);
+    }
+}
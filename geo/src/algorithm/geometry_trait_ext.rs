@@ -0,0 +1,267 @@
+//! Measures computed directly from the coordinates of any
+//! [`GeometryTrait`](geo_traits::GeometryTrait) implementation.
+//!
+//! [`GeometryTraitExt`](crate::geometry_trait_ext::GeometryTraitExt) gives the bounding
+//! rectangle and area of geometries backed by other storage, such as
+//! [WKB](crate::wkb_conv::Wkb) or Arrow arrays, without converting them to `geo-types` first.
+//!
+//! It isn't re-exported at the crate root: its methods share their names with those of
+//! [`BoundingRect`](crate::BoundingRect) and [`Area`](crate::Area), which `geo-types` geometries
+//! also implement.
+
+use crate::{Coord, CoordFloat, CoordNum, Rect};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+/// Extension methods computing measures of any [`GeometryTrait`] implementation from its
+/// coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::geometry_trait_ext::GeometryTraitExt;
+/// use geo::wkb_conv::{ToWkb, Wkb};
+/// use geo::{wkt, Rect};
+///
+/// let bytes = wkt!(POLYGON((0. 0.,4. 0.,4. 3.,0. 3.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 2.,1. 1.)))
+///     .to_wkb(Default::default());
+/// let wkb = Wkb::try_new(&bytes).unwrap();
+///
+/// assert_eq!(wkb.bounding_rect(), Some(Rect::new((0., 0.), (4., 3.))));
+/// assert_eq!(wkb.unsigned_area(), 11.);
+/// ```
+pub trait GeometryTraitExt: GeometryTrait {
+    /// The smallest axis-aligned rectangle covering the geometry, or `None` if it has no
+    /// coordinates.
+    ///
+    /// This gives the same result as [`BoundingRect`](crate::BoundingRect) on the geometry
+    /// converted to `geo-types`.
+    fn bounding_rect(&self) -> Option<Rect<Self::T>>
+    where
+        Self::T: CoordNum,
+    {
+        let mut bounds = Bounds { min_max: None };
+        bounds.add_geometry(self);
+        bounds.rect()
+    }
+
+    /// The unsigned planar area of the geometry.
+    ///
+    /// This gives the same result as [`Area::unsigned_area`](crate::Area::unsigned_area) on the
+    /// geometry converted to `geo-types`.
+    fn unsigned_area(&self) -> Self::T
+    where
+        Self::T: CoordFloat,
+    {
+        geometry_unsigned_area(self)
+    }
+}
+
+impl<G: GeometryTrait> GeometryTraitExt for G {}
+
+fn coord<T: CoordNum>(c: &impl CoordTrait<T = T>) -> Coord<T> {
+    Coord { x: c.x(), y: c.y() }
+}
+
+struct Bounds<T: CoordNum> {
+    min_max: Option<(Coord<T>, Coord<T>)>,
+}
+
+impl<T: CoordNum> Bounds<T> {
+    fn add_coord(&mut self, c: Coord<T>) {
+        self.min_max = Some(match self.min_max {
+            Some((min, max)) => (
+                Coord {
+                    x: if c.x < min.x { c.x } else { min.x },
+                    y: if c.y < min.y { c.y } else { min.y },
+                },
+                Coord {
+                    x: if c.x > max.x { c.x } else { max.x },
+                    y: if c.y > max.y { c.y } else { max.y },
+                },
+            ),
+            None => (c, c),
+        });
+    }
+
+    fn add_line_string(&mut self, line_string: &impl LineStringTrait<T = T>) {
+        for c in line_string.coords() {
+            self.add_coord(coord(&c));
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &impl PolygonTrait<T = T>) {
+        // Like `BoundingRect`, only the exterior counts, which covers the interiors of a valid
+        // polygon.
+        if let Some(exterior) = polygon.exterior() {
+            self.add_line_string(&exterior);
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &(impl GeometryTrait<T = T> + ?Sized)) {
+        match geometry.as_type() {
+            GeometryType::Point(point) => {
+                if let Some(c) = point.coord() {
+                    self.add_coord(coord(&c));
+                }
+            }
+            GeometryType::LineString(line_string) => self.add_line_string(line_string),
+            GeometryType::Polygon(polygon) => self.add_polygon(polygon),
+            GeometryType::MultiPoint(multi_point) => {
+                for point in multi_point.points() {
+                    if let Some(c) = point.coord() {
+                        self.add_coord(coord(&c));
+                    }
+                }
+            }
+            GeometryType::MultiLineString(multi_line_string) => {
+                for line_string in multi_line_string.line_strings() {
+                    self.add_line_string(&line_string);
+                }
+            }
+            GeometryType::MultiPolygon(multi_polygon) => {
+                for polygon in multi_polygon.polygons() {
+                    self.add_polygon(&polygon);
+                }
+            }
+            GeometryType::GeometryCollection(collection) => {
+                for geometry in collection.geometries() {
+                    self.add_geometry(&geometry);
+                }
+            }
+            GeometryType::Rect(rect) => {
+                self.add_coord(coord(&rect.min()));
+                self.add_coord(coord(&rect.max()));
+            }
+            GeometryType::Triangle(triangle) => {
+                for c in triangle.coords() {
+                    self.add_coord(coord(&c));
+                }
+            }
+            GeometryType::Line(line) => {
+                for c in line.coords() {
+                    self.add_coord(coord(&c));
+                }
+            }
+        }
+    }
+
+    fn rect(&self) -> Option<Rect<T>> {
+        self.min_max.map(|(min, max)| Rect::new(min, max))
+    }
+}
+
+/// The area of a ring, as `twice_signed_ring_area` computes it for a `LineString`.
+fn ring_signed_area<T: CoordFloat>(ring: &impl LineStringTrait<T = T>) -> T {
+    let n = ring.num_coords();
+    if n < 3 {
+        return T::zero();
+    }
+    let mut coords = ring.coords().map(|c| coord(&c));
+    let first = coords.next().unwrap();
+    if ring.coord(n - 1).map(|c| coord(&c)) != Some(first) {
+        return T::zero();
+    }
+
+    // Shift the coordinates to the first one, to reduce numerical errors.
+    let mut previous = Coord::zero();
+    let mut twice_area = T::zero();
+    for c in coords {
+        let c = c - first;
+        twice_area = twice_area + previous.x * c.y - previous.y * c.x;
+        previous = c;
+    }
+    twice_area / (T::one() + T::one())
+}
+
+fn polygon_unsigned_area<T: CoordFloat>(polygon: &impl PolygonTrait<T = T>) -> T {
+    let exterior = polygon
+        .exterior()
+        .map_or(T::zero(), |exterior| ring_signed_area(&exterior).abs());
+    polygon
+        .interiors()
+        .fold(exterior, |area, interior| {
+            area - ring_signed_area(&interior).abs()
+        })
+        .abs()
+}
+
+fn geometry_unsigned_area<T: CoordFloat>(geometry: &(impl GeometryTrait<T = T> + ?Sized)) -> T {
+    match geometry.as_type() {
+        GeometryType::Polygon(polygon) => polygon_unsigned_area(polygon),
+        GeometryType::MultiPolygon(multi_polygon) => {
+            multi_polygon.polygons().fold(T::zero(), |area, polygon| {
+                area + polygon_unsigned_area(&polygon)
+            })
+        }
+        GeometryType::GeometryCollection(collection) => {
+            collection.geometries().fold(T::zero(), |area, geometry| {
+                area + geometry_unsigned_area(&geometry)
+            })
+        }
+        GeometryType::Rect(rect) => {
+            let (min, max) = (coord(&rect.min()), coord(&rect.max()));
+            ((max.x - min.x) * (max.y - min.y)).abs()
+        }
+        GeometryType::Triangle(triangle) => {
+            let [a, b, c] = triangle.coords().map(|c| coord(&c));
+            let (b, c) = (b - a, c - a);
+            ((b.x * c.y - b.y * c.x) / (T::one() + T::one())).abs()
+        }
+        GeometryType::Point(_)
+        | GeometryType::LineString(_)
+        | GeometryType::MultiPoint(_)
+        | GeometryType::MultiLineString(_)
+        | GeometryType::Line(_) => T::zero(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GeometryTraitExt;
+    use crate::wkb_conv::{ToWkb, Wkb};
+    use crate::{wkt, Area, BoundingRect, Geometry, Rect, Triangle};
+
+    #[test]
+    fn matches_geo_types() {
+        let geometries: Vec<Geometry> = vec![
+            wkt!(POINT(1. 2.)).into(),
+            wkt!(LINESTRING(0. 0.,3. -1.,2. 5.)).into(),
+            wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 0.),(3. 1.,3. 2.,2. 1.,3. 1.))).into(),
+            // an invalid polygon, with an interior outside its exterior
+            wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.),(2. 2.,3. 2.,3. 3.,2. 2.))).into(),
+            wkt!(MULTIPOINT(1. 1.,-2. 3.)).into(),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((5. 5.,5. 7.,7. 7.,5. 5.)))).into(),
+            Geometry::GeometryCollection(
+                wkt!(GEOMETRYCOLLECTION(POINT(9. 9.),POLYGON((0. 0.,2. 0.,0. 2.,0. 0.)))),
+            ),
+            Rect::new((3., 1.), (0., 4.)).into(),
+            Triangle::from([(0., 0.), (0., 3.), (2., 0.)]).into(),
+        ];
+        for geometry in geometries {
+            let bytes = geometry.to_wkb(Default::default());
+            let wkb = Wkb::try_new(&bytes).unwrap();
+
+            assert_eq!(
+                GeometryTraitExt::bounding_rect(&geometry),
+                BoundingRect::bounding_rect(&geometry)
+            );
+            assert_eq!(wkb.bounding_rect(), BoundingRect::bounding_rect(&geometry));
+            assert_eq!(
+                GeometryTraitExt::unsigned_area(&geometry),
+                Area::unsigned_area(&geometry)
+            );
+            assert_eq!(wkb.unsigned_area(), Area::unsigned_area(&geometry));
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let geometry: Geometry = Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION EMPTY));
+        assert_eq!(GeometryTraitExt::bounding_rect(&geometry), None);
+        assert_eq!(GeometryTraitExt::unsigned_area(&geometry), 0.);
+    }
+}
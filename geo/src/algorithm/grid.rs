@@ -0,0 +1,454 @@
+//! Generate regular grids of polygons or points over an extent.
+//!
+//! Grids of [squares](crate::grid::GridShape::Square),
+//! [hexagons](crate::grid::GridShape::Hexagon) or equilateral
+//! [triangles](crate::grid::GridShape::Triangle) tile the plane, and are useful for spatial
+//! binning and choropleth maps. The grid functions here cover the bounding rectangle of a
+//! geometry — or a [`Rect`](crate::Rect) itself — with such cells, starting from its minimum
+//! corner:
+//!
+//! - [`grid_polygons`](crate::grid::grid_polygons) and
+//!   [`grid_points`](crate::grid::grid_points) return the cells overlapping the bounding
+//!   rectangle, or their centers
+//! - [`clipped_grid_polygons`](crate::grid::clipped_grid_polygons) and
+//!   [`clipped_grid_points`](crate::grid::clipped_grid_points) additionally clip the cells to
+//!   the geometry, or keep only the centers inside it
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::grid::{clipped_grid_polygons, grid_points, grid_polygons, GridShape};
+//! use geo::{polygon, Area, Rect};
+//!
+//! let extent = Rect::new((0.0, 0.0), (4.0, 2.0));
+//! assert_eq!(grid_polygons(&extent, GridShape::Square, 1.0).len(), 8);
+//! assert_eq!(grid_points(&extent, GridShape::Square, 2.0).len(), 2);
+//!
+//! let triangle = polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 0.0, y: 4.0)];
+//! let cells = clipped_grid_polygons(&triangle, GridShape::Hexagon, 0.5);
+//! let area: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+//! approx::assert_relative_eq!(area, triangle.unsigned_area(), epsilon = 1e-9);
+//! ```
+
+use crate::bool_ops::BoolOpsNum;
+use crate::{
+    BooleanOps, BoundingRect, Contains, Coord, GeoFloat, LineString, MultiPolygon, Point, Polygon,
+    Rect,
+};
+
+/// The shape of the cells of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridShape {
+    /// Axis-aligned squares, where the cell size is the side length.
+    Square,
+    /// Flat-topped regular hexagons, where the cell size is the side length. Columns of
+    /// hexagons are offset by half a hexagon.
+    Hexagon,
+    /// Equilateral triangles, alternately pointing up and down, where the cell size is the side
+    /// length.
+    Triangle,
+}
+
+/// Returns the grid cells overlapping the bounding rectangle of `geometry`, from south to north
+/// and west to east.
+///
+/// Cells which only touch the bounding rectangle are omitted.
+///
+/// # Panics
+///
+/// If `size` isn't positive.
+pub fn grid_polygons<T, G>(geometry: &G, shape: GridShape, size: T) -> Vec<Polygon<T>>
+where
+    T: GeoFloat,
+    G: BoundingRect<T>,
+{
+    match geometry.bounding_rect().into() {
+        Some(extent) => Lattice::new(extent, shape, size).polygons().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Returns the centers of the grid cells overlapping the bounding rectangle of `geometry`, in
+/// the same order as [`grid_polygons`].
+///
+/// # Panics
+///
+/// If `size` isn't positive.
+pub fn grid_points<T, G>(geometry: &G, shape: GridShape, size: T) -> Vec<Point<T>>
+where
+    T: GeoFloat,
+    G: BoundingRect<T>,
+{
+    match geometry.bounding_rect().into() {
+        Some(extent) => {
+            let lattice = Lattice::new(extent, shape, size);
+            lattice
+                .cells()
+                .map(|(i, j)| lattice.center(i, j).into())
+                .collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+/// Returns the parts of `geometry` within each grid cell, in the same order as
+/// [`grid_polygons`]. Cells which don't overlap `geometry` are omitted.
+///
+/// # Panics
+///
+/// If `size` isn't positive.
+pub fn clipped_grid_polygons<T, G>(geometry: &G, shape: GridShape, size: T) -> Vec<MultiPolygon<T>>
+where
+    T: GeoFloat + BoolOpsNum,
+    G: BooleanOps<Scalar = T> + BoundingRect<T>,
+{
+    grid_polygons(geometry, shape, size)
+        .into_iter()
+        .map(|cell| geometry.intersection(&cell))
+        .filter(|clipped| !clipped.0.is_empty())
+        .collect()
+}
+
+/// Returns the centers of the grid cells which lie inside `geometry`, in the same order as
+/// [`grid_points`].
+///
+/// # Panics
+///
+/// If `size` isn't positive.
+pub fn clipped_grid_points<T, G>(geometry: &G, shape: GridShape, size: T) -> Vec<Point<T>>
+where
+    T: GeoFloat,
+    G: BoundingRect<T> + Contains<Point<T>>,
+{
+    grid_points(geometry, shape, size)
+        .into_iter()
+        .filter(|point| geometry.contains(point))
+        .collect()
+}
+
+// Cells are indexed by column `i` and row `j`, with cell (0, 0) at the minimum corner of the
+// extent.
+struct Lattice<T: GeoFloat> {
+    extent: Rect<T>,
+    shape: GridShape,
+    size: T,
+    // The horizontal and vertical distance between the cells of adjacent columns and rows
+    step: Coord<T>,
+}
+
+impl<T: GeoFloat> Lattice<T> {
+    fn new(extent: Rect<T>, shape: GridShape, size: T) -> Self {
+        assert!(size > T::zero());
+        let two = T::one() + T::one();
+        let three = two + T::one();
+        let step = match shape {
+            GridShape::Square => Coord { x: size, y: size },
+            GridShape::Hexagon => Coord {
+                x: size * three / two,
+                y: size * three.sqrt(),
+            },
+            GridShape::Triangle => Coord {
+                x: size / two,
+                y: size * three.sqrt() / two,
+            },
+        };
+        Self {
+            extent,
+            shape,
+            size,
+            step,
+        }
+    }
+
+    // The indices of the cells overlapping the extent. The ranges are padded by a cell to
+    // allow for the offset hexagon columns and the overhang of triangles.
+    fn cells(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        let columns = (self.extent.width() / self.step.x).ceil().to_i64().unwrap();
+        let rows = (self.extent.height() / self.step.y)
+            .ceil()
+            .to_i64()
+            .unwrap();
+        (-1..=rows).flat_map(move |j| {
+            (-2..=columns)
+                .map(move |i| (i, j))
+                .filter(|&(i, j)| self.overlaps_extent(&self.vertices(i, j)))
+        })
+    }
+
+    fn polygons(&self) -> impl Iterator<Item = Polygon<T>> + '_ {
+        self.cells()
+            .map(|(i, j)| Polygon::new(LineString::from(self.vertices(i, j)), vec![]))
+    }
+
+    fn origin(&self, i: i64, j: i64) -> Coord<T> {
+        self.extent.min()
+            + Coord {
+                x: T::from(i).unwrap() * self.step.x,
+                y: T::from(j).unwrap() * self.step.y,
+            }
+    }
+
+    fn center(&self, i: i64, j: i64) -> Coord<T> {
+        let vertices = self.vertices(i, j);
+        let sum = vertices
+            .iter()
+            .fold(Coord::zero(), |sum, &vertex| sum + vertex);
+        sum / T::from(vertices.len()).unwrap()
+    }
+
+    // The vertices of a cell, counter-clockwise
+    fn vertices(&self, i: i64, j: i64) -> Vec<Coord<T>> {
+        let two = T::one() + T::one();
+        let size = self.size;
+        let origin = self.origin(i, j);
+        match self.shape {
+            GridShape::Square => vec![
+                origin,
+                origin
+                    + Coord {
+                        x: size,
+                        y: T::zero(),
+                    },
+                origin + Coord { x: size, y: size },
+                origin
+                    + Coord {
+                        x: T::zero(),
+                        y: size,
+                    },
+            ],
+            GridShape::Hexagon => {
+                // Odd columns are shifted up by half a hexagon
+                let shift = if i.rem_euclid(2) == 1 {
+                    self.step.y / two
+                } else {
+                    T::zero()
+                };
+                let center = origin
+                    + Coord {
+                        x: T::zero(),
+                        y: shift,
+                    };
+                let half_height = self.step.y / two;
+                vec![
+                    center
+                        + Coord {
+                            x: size,
+                            y: T::zero(),
+                        },
+                    center
+                        + Coord {
+                            x: size / two,
+                            y: half_height,
+                        },
+                    center
+                        + Coord {
+                            x: -size / two,
+                            y: half_height,
+                        },
+                    center
+                        + Coord {
+                            x: -size,
+                            y: T::zero(),
+                        },
+                    center
+                        + Coord {
+                            x: -size / two,
+                            y: -half_height,
+                        },
+                    center
+                        + Coord {
+                            x: size / two,
+                            y: -half_height,
+                        },
+                ]
+            }
+            GridShape::Triangle => {
+                let height = self.step.y;
+                if (i + j).rem_euclid(2) == 0 {
+                    vec![
+                        origin,
+                        origin
+                            + Coord {
+                                x: size,
+                                y: T::zero(),
+                            },
+                        origin
+                            + Coord {
+                                x: size / two,
+                                y: height,
+                            },
+                    ]
+                } else {
+                    vec![
+                        origin
+                            + Coord {
+                                x: T::zero(),
+                                y: height,
+                            },
+                        origin
+                            + Coord {
+                                x: size / two,
+                                y: T::zero(),
+                            },
+                        origin + Coord { x: size, y: height },
+                    ]
+                }
+            }
+        }
+    }
+
+    // Whether a convex cell overlaps the extent, by the separating axis theorem: they overlap
+    // unless their projections onto one of the axes of the extent, or one of the edge normals
+    // of the cell, are disjoint. Projections which only touch count as disjoint.
+    //
+    // Where the extent is degenerate along one of its axes, it's taken to belong to the cells
+    // whose projection contains it, excluding their maximum, so that a horizontal or vertical
+    // line on a cell boundary isn't covered twice.
+    fn overlaps_extent(&self, vertices: &[Coord<T>]) -> bool {
+        let (min, max) = (self.extent.min(), self.extent.max());
+        let corners = [
+            min,
+            Coord { x: max.x, y: min.y },
+            max,
+            Coord { x: min.x, y: max.y },
+        ];
+        let overlaps = |axis: Coord<T>, half_open: bool| {
+            let (cell_min, cell_max) = project(vertices, axis);
+            let (extent_min, extent_max) = project(&corners, axis);
+            if extent_min < extent_max {
+                cell_max.min(extent_max) > cell_min.max(extent_min)
+            } else if half_open {
+                cell_min <= extent_min && extent_min < cell_max
+            } else {
+                cell_min <= extent_min && extent_min <= cell_max
+            }
+        };
+        let x_axis = Coord {
+            x: T::one(),
+            y: T::zero(),
+        };
+        let y_axis = Coord {
+            x: T::zero(),
+            y: T::one(),
+        };
+        overlaps(x_axis, true)
+            && overlaps(y_axis, true)
+            && vertices
+                .iter()
+                .zip(vertices.iter().cycle().skip(1))
+                .all(|(&a, &b)| {
+                    let normal = Coord {
+                        x: a.y - b.y,
+                        y: b.x - a.x,
+                    };
+                    overlaps(normal, false)
+                })
+    }
+}
+
+fn project<T: GeoFloat>(coords: &[Coord<T>], axis: Coord<T>) -> (T, T) {
+    coords
+        .iter()
+        .map(|coord| coord.x * axis.x + coord.y * axis.y)
+        .fold((T::infinity(), T::neg_infinity()), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon, wkt, Area, Intersects, Relate};
+
+    const SHAPES: [GridShape; 3] = [GridShape::Square, GridShape::Hexagon, GridShape::Triangle];
+
+    #[test]
+    fn square_grid() {
+        let extent = Rect::new((1.0, 1.0), (4.0, 3.0));
+        let cells = grid_polygons(&extent, GridShape::Square, 1.0);
+        assert_eq!(cells.len(), 6);
+        assert_eq!(
+            cells[0],
+            polygon![(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0), (x: 1.0, y: 2.0)]
+        );
+        assert_eq!(
+            grid_points(&extent, GridShape::Square, 1.0),
+            vec![
+                point!(x: 1.5, y: 1.5),
+                point!(x: 2.5, y: 1.5),
+                point!(x: 3.5, y: 1.5),
+                point!(x: 1.5, y: 2.5),
+                point!(x: 2.5, y: 2.5),
+                point!(x: 3.5, y: 2.5),
+            ]
+        );
+
+        // A partial column and row
+        assert_eq!(grid_polygons(&extent, GridShape::Square, 2.5).len(), 2);
+    }
+
+    #[test]
+    fn cells_tile_extent() {
+        let extent = Rect::new((-3.0, 2.0), (7.0, 8.5));
+        for shape in SHAPES {
+            let cells = grid_polygons(&extent, shape, 0.7);
+            let cell_area = cells[0].unsigned_area();
+
+            // The cells are congruent, counter-clockwise, and cover the extent
+            let mut covered = 0.0;
+            for cell in &cells {
+                approx::assert_relative_eq!(cell.signed_area(), cell_area, epsilon = 1e-9);
+                assert!(!cell.relate(&extent).is_touches());
+                covered += extent.to_polygon().intersection(cell).unsigned_area();
+            }
+            approx::assert_relative_eq!(covered, extent.unsigned_area(), max_relative = 1e-6);
+
+            // and don't overlap each other
+            let union: f64 = crate::unary_union(&cells).unsigned_area();
+            approx::assert_relative_eq!(union, cell_area * cells.len() as f64, max_relative = 1e-6);
+
+            let centers = grid_points(&extent, shape, 0.7);
+            assert_eq!(centers.len(), cells.len());
+            for (center, cell) in centers.iter().zip(&cells) {
+                assert!(cell.contains(center));
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_extent() {
+        let line = line_string![(x: 0.0, y: 0.5), (x: 3.5, y: 0.5)];
+        let cells = grid_polygons(&line, GridShape::Square, 1.0);
+        assert_eq!(cells.len(), 4);
+        for shape in SHAPES {
+            for cell in grid_polygons(&line, shape, 1.0) {
+                assert!(cell.intersects(&line));
+            }
+        }
+
+        let empty: MultiPolygon = wkt!(MULTIPOLYGON EMPTY);
+        assert!(grid_polygons(&empty, GridShape::Hexagon, 1.0).is_empty());
+        assert!(clipped_grid_polygons(&empty, GridShape::Hexagon, 1.0).is_empty());
+    }
+
+    #[test]
+    fn clipped() {
+        let triangle = polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 0.0, y: 4.0)];
+        for shape in SHAPES {
+            let cells = clipped_grid_polygons(&triangle, shape, 0.5);
+            let area: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+            approx::assert_relative_eq!(area, triangle.unsigned_area(), epsilon = 1e-9);
+
+            let points = clipped_grid_points(&triangle, shape, 0.5);
+            assert!(!points.is_empty());
+            assert!(points.len() < grid_points(&triangle, shape, 0.5).len());
+            assert!(points.iter().all(|point| triangle.contains(point)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_size() {
+        grid_polygons(&Rect::new((0.0, 0.0), (1.0, 1.0)), GridShape::Square, 0.0);
+    }
+}
@@ -18,6 +18,35 @@ where
     fn hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> T
     where
         Rhs: CoordsIter<Scalar = T>;
+
+    /// The directed (one-way) Hausdorff distance from `self` to `rhs`, i.e. the maximum
+    /// distance of a coordinate of `self` to the nearest coordinate of `rhs`, and the
+    /// coordinate of `self` which is that far, or `None` if either is empty.
+    ///
+    /// Unlike [`hausdorff_distance`](Self::hausdorff_distance), this isn't symmetric. For
+    /// instance, the directed distance from an original geometry to its simplification
+    /// measures how much detail was lost, and the witness coordinate shows where.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, wkt, HausdorffDistance};
+    ///
+    /// let original = wkt!(LINESTRING(0. 0.,1. 0.5,2. 0.,3. 3.,4. 0.));
+    /// let simplified = wkt!(LINESTRING(0. 0.,2. 0.,4. 0.));
+    ///
+    /// assert_eq!(
+    ///     original.directed_hausdorff_distance(&simplified),
+    ///     Some((10_f64.sqrt(), coord! { x: 3., y: 3. }))
+    /// );
+    /// assert_eq!(
+    ///     simplified.directed_hausdorff_distance(&original),
+    ///     Some((0., coord! { x: 0., y: 0. }))
+    /// );
+    /// ```
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> Option<(T, Coord<T>)>
+    where
+        Rhs: CoordsIter<Scalar = T>;
 }
 
 impl<T, G> HausdorffDistance<T> for G
@@ -30,28 +59,53 @@ where
         Rhs: CoordsIter<Scalar = T>,
     {
         // calculate from A -> B
-        let hd1 = self
-            .coords_iter()
-            .map(|c| {
-                rhs.coords_iter()
-                    .map(|c2| Euclidean::distance(c, c2))
-                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
-            })
-            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val));
+        let (hd1, _) = directed_hausdorff(self, rhs);
 
         // Calculate from B -> A
-        let hd2 = rhs
-            .coords_iter()
-            .map(|c| {
-                self.coords_iter()
-                    .map(|c2| Euclidean::distance(c, c2))
-                    .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
-            })
-            .fold(<T as Bounded>::min_value(), |accum, val| accum.max(val));
+        let (hd2, _) = directed_hausdorff(rhs, self);
 
         // The max of the two
         hd1.max(hd2)
     }
+
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> Option<(T, Coord<T>)>
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        if rhs.coords_count() == 0 {
+            return None;
+        }
+        let (distance, witness) = directed_hausdorff(self, rhs);
+        witness.map(|witness| (distance, witness))
+    }
+}
+
+// The maximum distance of a coordinate of `a` to the nearest coordinate of `b`, and that
+// coordinate of `a`, if any
+fn directed_hausdorff<T, A, B>(a: &A, b: &B) -> (T, Option<Coord<T>>)
+where
+    T: GeoFloat,
+    A: CoordsIter<Scalar = T>,
+    B: CoordsIter<Scalar = T>,
+{
+    a.coords_iter()
+        .map(|c| {
+            let nearest = b
+                .coords_iter()
+                .map(|c2| Euclidean.distance(c, c2))
+                .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val));
+            (nearest, c)
+        })
+        .fold(
+            (<T as Bounded>::min_value(), None),
+            |(accum, witness), (val, c)| {
+                if witness.is_none() || val > accum {
+                    (val, Some(c))
+                } else {
+                    (accum, witness)
+                }
+            },
+        )
 }
 
 // ┌───────────────────────────┐
@@ -68,12 +122,19 @@ where
     {
         Point::from(*self).hausdorff_distance(rhs)
     }
+
+    fn directed_hausdorff_distance<Rhs>(&self, rhs: &Rhs) -> Option<(T, Coord<T>)>
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        Point::from(*self).directed_hausdorff_distance(rhs)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::HausdorffDistance;
-    use crate::{line_string, polygon, MultiPoint, MultiPolygon};
+    use crate::{coord, line_string, polygon, MultiPoint, MultiPolygon};
 
     #[test]
     fn hd_mpnt_mpnt() {
@@ -132,4 +193,25 @@ mod test {
             epsilon = 1.0e-6
         )
     }
+
+    #[test]
+    fn directed_hd_mpnt_mpnt() {
+        let p1: MultiPoint<_> = vec![(0., 0.), (1., 2.)].into();
+        let p2: MultiPoint<_> = vec![(2., 3.), (1., 2.), (1., 1.)].into();
+        assert_eq!(
+            p1.directed_hausdorff_distance(&p2),
+            Some((2_f64.sqrt(), coord! { x: 0., y: 0. }))
+        );
+        let (distance, witness) = p2.directed_hausdorff_distance(&p1).unwrap();
+        assert_relative_eq!(distance, 2_f64.sqrt());
+        assert_eq!(witness, coord! { x: 2., y: 3. });
+        assert_eq!(
+            p1.directed_hausdorff_distance(&p1),
+            Some((0., coord! { x: 0., y: 0. }))
+        );
+
+        let empty = MultiPoint::<f64>::new(vec![]);
+        assert_eq!(p1.directed_hausdorff_distance(&empty), None);
+        assert_eq!(empty.directed_hausdorff_distance(&p1), None);
+    }
 }
@@ -1,6 +1,6 @@
-use crate::line_measures::{Bearing, Destination, Distance, Haversine};
+use crate::line_measures::{Bearing, Destination, Distance, Haversine, HaversineMeasure};
 use crate::{Closest, Contains};
-use crate::{CoordsIter, GeoFloat, Point, MEAN_EARTH_RADIUS};
+use crate::{CoordsIter, GeoFloat, Point};
 use geo_types::{
     Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
     MultiPolygon, Polygon, Rect, Triangle,
@@ -38,7 +38,17 @@ pub trait HaversineClosestPoint<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T>;
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        self.haversine_closest_point_on(from, Haversine)
+    }
+
+    /// Like [`haversine_closest_point`](Self::haversine_closest_point), but on a sphere of the
+    /// given [`HaversineMeasure`]'s radius rather than the earth's.
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T>;
 }
 
 // Implement for references as well as types
@@ -47,8 +57,12 @@ where
     G: HaversineClosestPoint<T>,
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
-        (*self).haversine_closest_point(from)
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
+        (*self).haversine_closest_point_on(from, haversine)
     }
 }
 
@@ -56,7 +70,7 @@ impl<T> HaversineClosestPoint<T> for Point<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, pt: &Point<T>) -> Closest<T> {
+    fn haversine_closest_point_on(&self, pt: &Point<T>, _: HaversineMeasure) -> Closest<T> {
         if self == pt {
             Closest::Intersection(*self)
         } else {
@@ -69,8 +83,8 @@ impl<T> HaversineClosestPoint<T> for Coord<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, pt: &Point<T>) -> Closest<T> {
-        Point::from(*self).haversine_closest_point(pt)
+    fn haversine_closest_point_on(&self, pt: &Point<T>, haversine: HaversineMeasure) -> Closest<T> {
+        Point::from(*self).haversine_closest_point_on(pt, haversine)
     }
 }
 
@@ -78,7 +92,11 @@ impl<T> HaversineClosestPoint<T> for Line<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
         let p1 = self.start_point();
         let p2 = self.end_point();
 
@@ -92,7 +110,7 @@ where
         }
 
         // This can probably be done cheaper
-        let d3 = Haversine::distance(p2, p1);
+        let d3 = haversine.distance(p2, p1);
         if d3 <= T::epsilon() {
             // I think here it should be return Closest::SinglePoint(p1)
             // If the line segment is degenerated to a point, that point is still the closest
@@ -102,38 +120,38 @@ where
         }
 
         let pi = T::from(std::f64::consts::PI).unwrap();
-        let crs_ad = Haversine::bearing(p1, *from).to_radians();
-        let crs_ab = Haversine::bearing(p1, p2).to_radians();
+        let crs_ad = haversine.bearing(p1, *from).to_radians();
+        let crs_ab = haversine.bearing(p1, p2).to_radians();
         let crs_ba = if crs_ab > T::zero() {
             crs_ab - pi
         } else {
             crs_ab + pi
         };
-        let crs_bd = Haversine::bearing(p2, *from).to_radians();
+        let crs_bd = haversine.bearing(p2, *from).to_radians();
         let d_crs1 = crs_ad - crs_ab;
         let d_crs2 = crs_bd - crs_ba;
 
-        let d1 = Haversine::distance(p1, *from);
+        let d1 = haversine.distance(p1, *from);
 
         // d1, d2, d3 are in principle not needed, only the sign matters
         let projection1 = d_crs1.cos();
         let projection2 = d_crs2.cos();
 
         if projection1.is_sign_positive() && projection2.is_sign_positive() {
-            let earth_radius = T::from(MEAN_EARTH_RADIUS).unwrap();
-            let xtd = (((d1 / earth_radius).sin() * d_crs1.sin()).asin()).abs();
-            let atd = earth_radius * (((d1 / earth_radius).cos() / xtd.cos()).acos()).abs();
+            let radius = T::from(haversine.radius()).unwrap();
+            let xtd = (((d1 / radius).sin() * d_crs1.sin()).asin()).abs();
+            let atd = radius * (((d1 / radius).cos() / xtd.cos()).acos()).abs();
 
             if xtd < T::epsilon() {
                 return Closest::Intersection(*from);
             } else {
-                return Closest::SinglePoint(Haversine::destination(p1, crs_ab.to_degrees(), atd));
+                return Closest::SinglePoint(haversine.destination(p1, crs_ab.to_degrees(), atd));
             }
         }
 
         // Projected falls outside the GC Arc
         // Return shortest distance pt, project either on point sp1 or sp2
-        let d2 = Haversine::distance(p2, *from);
+        let d2 = haversine.distance(p2, *from);
         if d1 < d2 {
             return Closest::SinglePoint(p1);
         }
@@ -146,7 +164,11 @@ where
     T: GeoFloat + FromPrimitive,
 {
     // This is a naive implementation
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
         if self.coords_count() == 0 {
             return Closest::Indeterminate; // Empty LineString
         }
@@ -155,7 +177,7 @@ where
         let mut rv = Closest::Indeterminate;
 
         for line in self.lines() {
-            match line.haversine_closest_point(from) {
+            match line.haversine_closest_point_on(from, haversine) {
                 intersect @ Closest::Intersection(_) => {
                     // let's investigate the situation here:
                     // - we have discovered that the point actually intersects the linestring.
@@ -166,7 +188,7 @@ where
                     return intersect;
                 }
                 Closest::SinglePoint(pt) => {
-                    let dist = Haversine::distance(pt, *from);
+                    let dist = haversine.distance(pt, *from);
                     if dist < min_distance {
                         min_distance = dist;
                         rv = Closest::SinglePoint(pt);
@@ -182,7 +204,11 @@ where
     }
 }
 
-fn closest_closed_simple_poly<T, I>(lines: I, from: &Point<T>) -> (Closest<T>, T)
+fn closest_closed_simple_poly<T, I>(
+    lines: I,
+    from: &Point<T>,
+    haversine: HaversineMeasure,
+) -> (Closest<T>, T)
 where
     T: GeoFloat + FromPrimitive,
     I: IntoIterator<Item = Line<T>>,
@@ -190,7 +216,7 @@ where
     let mut min_distance = num_traits::Float::max_value();
     let mut rv = Closest::Indeterminate;
     for line in lines {
-        match line.haversine_closest_point(from) {
+        match line.haversine_closest_point_on(from, haversine) {
             intersect @ Closest::Intersection(_) => {
                 // same as for the linestring, even if we detected multiple intersections,
                 // they would by definition be the same point, so we can just return it.
@@ -198,7 +224,7 @@ where
                 return (intersect, T::zero());
             }
             Closest::SinglePoint(pt) => {
-                let dist = Haversine::distance(pt, *from);
+                let dist = haversine.distance(pt, *from);
                 if dist < min_distance {
                     min_distance = dist;
                     rv = Closest::SinglePoint(pt);
@@ -219,12 +245,16 @@ impl<T> HaversineClosestPoint<T> for Triangle<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
         if self.contains(from) {
             return Closest::Intersection(*from);
         }
 
-        closest_closed_simple_poly(self.to_lines(), from).0
+        closest_closed_simple_poly(self.to_lines(), from, haversine).0
     }
 }
 
@@ -232,12 +262,16 @@ impl<T> HaversineClosestPoint<T> for Rect<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
         if self.contains(from) {
             return Closest::Intersection(*from);
         }
 
-        closest_closed_simple_poly(self.to_lines(), from).0
+        closest_closed_simple_poly(self.to_lines(), from, haversine).0
     }
 }
 
@@ -246,7 +280,11 @@ where
     T: GeoFloat + FromPrimitive,
 {
     #[warn(unused_assignments)]
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
         if self.contains(from) {
             return Closest::Intersection(*from);
         }
@@ -256,7 +294,8 @@ where
             return Closest::Indeterminate;
         }
 
-        let (mut rv, mut min_distance) = closest_closed_simple_poly(self.exterior().lines(), from);
+        let (mut rv, mut min_distance) =
+            closest_closed_simple_poly(self.exterior().lines(), from, haversine);
 
         match rv {
             // Would not happen as it should be caught at the beginning of the function
@@ -269,7 +308,7 @@ where
 
         // Could be inside a inner ring
         for ls in self.interiors() {
-            match closest_closed_simple_poly(ls.lines(), from) {
+            match closest_closed_simple_poly(ls.lines(), from, haversine) {
                 // Would not happen as it should be caught at the beginning of the function
                 (Closest::Intersection(pt), _) => return Closest::Intersection(pt),
                 (Closest::SinglePoint(pt), dist) => {
@@ -287,7 +326,11 @@ where
     }
 }
 
-fn multi_geometry_nearest<G, I, T>(iter: I, from: &Point<T>) -> Closest<T>
+fn multi_geometry_nearest<G, I, T>(
+    iter: I,
+    from: &Point<T>,
+    haversine: HaversineMeasure,
+) -> Closest<T>
 where
     T: GeoFloat + FromPrimitive,
     G: HaversineClosestPoint<T>,
@@ -297,11 +340,11 @@ where
     let mut rv = Closest::Indeterminate;
 
     for c in iter {
-        match c.haversine_closest_point(from) {
+        match c.haversine_closest_point_on(from, haversine) {
             // This mean on top of the line.
             Closest::Intersection(pt) => return Closest::Intersection(pt),
             Closest::SinglePoint(pt) => {
-                let dist = Haversine::distance(pt, *from);
+                let dist = haversine.distance(pt, *from);
                 if dist < min_distance {
                     min_distance = dist;
                     rv = Closest::SinglePoint(pt);
@@ -317,8 +360,12 @@ impl<T> HaversineClosestPoint<T> for MultiPoint<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
-        multi_geometry_nearest(self, from)
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
+        multi_geometry_nearest(self, from, haversine)
     }
 }
 
@@ -326,8 +373,12 @@ impl<T> HaversineClosestPoint<T> for MultiLineString<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
-        multi_geometry_nearest(self, from)
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
+        multi_geometry_nearest(self, from, haversine)
     }
 }
 
@@ -335,8 +386,12 @@ impl<T> HaversineClosestPoint<T> for MultiPolygon<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
-        multi_geometry_nearest(self, from)
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
+        multi_geometry_nearest(self, from, haversine)
     }
 }
 
@@ -345,7 +400,7 @@ where
     T: GeoFloat + FromPrimitive,
 {
     crate::geometry_delegate_impl! {
-        fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T>;
+        fn haversine_closest_point_on(&self, from: &Point<T>, haversine: HaversineMeasure) -> Closest<T>;
     }
 }
 
@@ -353,8 +408,12 @@ impl<T> HaversineClosestPoint<T> for GeometryCollection<T>
 where
     T: GeoFloat + FromPrimitive,
 {
-    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
-        multi_geometry_nearest(self, from)
+    fn haversine_closest_point_on(
+        &self,
+        from: &Point<T>,
+        haversine: HaversineMeasure,
+    ) -> Closest<T> {
+        multi_geometry_nearest(self, from, haversine)
     }
 }
 
@@ -409,6 +468,26 @@ mod test {
         }
     }
 
+    #[test]
+    fn point_to_line_custom_radius() {
+        let p_1 = Point::new(-84.74905, 32.61454);
+        let p_2 = Point::new(-85.93942, 32.11055);
+        let line = Line::new(p_2, p_1);
+
+        // The closest point doesn't depend on the size of the sphere
+        let p_from = Point::new(-84.75625, 31.81056);
+        let moon = HaversineMeasure::new(1_737_400.0);
+        if let Closest::SinglePoint(pt) = line.haversine_closest_point_on(&p_from, moon) {
+            assert_relative_eq!(
+                pt,
+                Point::new(-85.13337428852164, 32.45365659858937),
+                epsilon = 1e-9
+            );
+        } else {
+            panic!("Did not get Closest::SinglePoint!");
+        }
+    }
+
     #[test]
     fn point_to_line_intersection() {
         let p_1 = Point::new(-84.74905, 32.61454);
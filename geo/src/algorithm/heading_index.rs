@@ -0,0 +1,201 @@
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+
+use crate::{Closest, ClosestPoint, GeoFloat, Line, MultiLineString, Point};
+
+/// An index of the segments of a `MultiLineString`, for finding the nearest segment whose
+/// direction is close to a heading.
+///
+/// This is the query for snapping a vehicle's position onto a road network: the nearest
+/// segment isn't always right, e.g. on a divided highway the opposing carriageway may be
+/// closer, but its direction differs from the vehicle's heading by about 180°.
+///
+/// Headings and the directions of segments are bearings in degrees, clockwise from north,
+/// i.e. the positive `y` direction, as returned by [`Bearing`](crate::Bearing). The direction of
+/// a segment is from its start to its end, so roads which may be travelled both ways should be
+/// included in each direction. Distances and directions are Euclidean, so geographic
+/// coordinates should be projected first.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, HeadingIndex, MultiLineString};
+///
+/// // the two carriageways of a road running east to west
+/// let road: MultiLineString = wkt!(MULTILINESTRING(
+///     (0. 0.,100. 0.),
+///     (100. 10.,0. 10.)
+/// ));
+/// let index = HeadingIndex::new(&road);
+///
+/// // a GPS fix nearer the westbound carriageway, heading east
+/// let fix = point!(x: 50., y: 6.);
+/// let snapped = index.closest_point_with_heading(fix, 85., 30.).unwrap();
+/// assert_eq!(snapped.line_string_idx, 0);
+/// assert_eq!(snapped.point, point!(x: 50., y: 0.));
+///
+/// // nothing heads north
+/// assert!(index.closest_point_with_heading(fix, 0., 30.).is_none());
+/// ```
+pub struct HeadingIndex<T: GeoFloat> {
+    tree: RTree<GeomWithData<Line<T>, Segment<T>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Segment<T: GeoFloat> {
+    line_string_idx: usize,
+    segment_idx: usize,
+    bearing: T,
+}
+
+/// The closest point on a segment of a [`HeadingIndex`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeadingMatch<T: GeoFloat> {
+    /// The index of the `LineString` in the `MultiLineString`.
+    pub line_string_idx: usize,
+    /// The index of the segment in the `LineString`, i.e. of its start coordinate.
+    pub segment_idx: usize,
+    /// The closest point on the segment.
+    pub point: Point<T>,
+    /// The distance to the closest point.
+    pub distance: T,
+    /// The bearing of the segment, in degrees.
+    pub bearing: T,
+}
+
+impl<T: GeoFloat> HeadingIndex<T> {
+    /// Indexes the segments of `multi_line_string`. Segments of zero length have no direction,
+    /// so are left out.
+    pub fn new(multi_line_string: &MultiLineString<T>) -> Self {
+        let segments = multi_line_string
+            .iter()
+            .enumerate()
+            .flat_map(|(line_string_idx, line_string)| {
+                line_string
+                    .lines()
+                    .enumerate()
+                    .filter(|(_, line)| line.start != line.end)
+                    .map(move |(segment_idx, line)| {
+                        let segment = Segment {
+                            line_string_idx,
+                            segment_idx,
+                            bearing: bearing(line),
+                        };
+                        GeomWithData::new(line, segment)
+                    })
+            })
+            .collect();
+        Self {
+            tree: RTree::bulk_load(segments),
+        }
+    }
+
+    /// The closest point to `point` on a segment whose bearing differs from `heading` by at
+    /// most `tolerance` degrees, or `None` if there is no such segment.
+    ///
+    /// Segments are visited nearest first, so a query is quick when a matching segment is
+    /// near, but visits every segment if there is none.
+    pub fn closest_point_with_heading(
+        &self,
+        point: Point<T>,
+        heading: T,
+        tolerance: T,
+    ) -> Option<HeadingMatch<T>> {
+        self.tree
+            .nearest_neighbor_iter_with_distance_2(&point)
+            .find(|(segment, _)| angle_between(segment.data.bearing, heading) <= tolerance)
+            .map(|(segment, distance_2)| {
+                let closest = match segment.geom().closest_point(&point) {
+                    Closest::Intersection(closest) | Closest::SinglePoint(closest) => closest,
+                    Closest::Indeterminate => unreachable!("segments have a length"),
+                };
+                HeadingMatch {
+                    line_string_idx: segment.data.line_string_idx,
+                    segment_idx: segment.data.segment_idx,
+                    point: closest,
+                    distance: distance_2.sqrt(),
+                    bearing: segment.data.bearing,
+                }
+            })
+    }
+}
+
+/// The bearing of `line` in degrees, from `0` to `360`, clockwise from the positive `y` axis.
+fn bearing<T: GeoFloat>(line: Line<T>) -> T {
+    let delta = line.delta();
+    let degrees = delta.x.atan2(delta.y).to_degrees();
+    if degrees < T::zero() {
+        degrees + T::from(360).unwrap()
+    } else {
+        degrees
+    }
+}
+
+/// The smaller angle, in degrees, between two bearings.
+fn angle_between<T: GeoFloat>(a: T, b: T) -> T {
+    let full_turn = T::from(360).unwrap();
+    let difference = (a - b).abs() % full_turn;
+    difference.min(full_turn - difference)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt};
+
+    #[test]
+    fn bearings() {
+        let origin = point!(x: 0., y: 0.);
+        let line_to = |x: f64, y: f64| Line::new(origin, point!(x: x, y: y));
+        approx::assert_relative_eq!(bearing(line_to(0., 1.)), 0.);
+        approx::assert_relative_eq!(bearing(line_to(1., 1.)), 45.);
+        approx::assert_relative_eq!(bearing(line_to(0., -1.)), 180.);
+        approx::assert_relative_eq!(bearing(line_to(-1., 0.)), 270.);
+
+        assert_eq!(angle_between(10., 350.), 20.);
+        assert_eq!(angle_between(-10., 10.), 20.);
+        assert_eq!(angle_between(90., 270.), 180.);
+        assert_eq!(angle_between(45., 45. + 720.), 0.);
+    }
+
+    #[test]
+    fn snapping() {
+        // a bend, and a parallel carriageway in the opposite direction, with a repeated
+        // coordinate
+        let roads: MultiLineString = wkt!(MULTILINESTRING(
+            (0. 0.,10. 0.,10. 10.),
+            (12. 10.,12. 2.,12. 2.,0. 2.)
+        ));
+        let index = HeadingIndex::new(&roads);
+
+        let near_bend = point!(x: 9., y: 1.5);
+        let east = index
+            .closest_point_with_heading(near_bend, 80., 30.)
+            .unwrap();
+        assert_eq!((east.line_string_idx, east.segment_idx), (0, 0));
+        assert_eq!(east.point, point!(x: 9., y: 0.));
+        assert_eq!(east.distance, 1.5);
+        assert_eq!(east.bearing, 90.);
+
+        let north = index
+            .closest_point_with_heading(near_bend, 350., 30.)
+            .unwrap();
+        assert_eq!((north.line_string_idx, north.segment_idx), (0, 1));
+        assert_eq!(north.point, point!(x: 10., y: 1.5));
+
+        // without the filter, the westbound carriageway is nearest
+        let west = index
+            .closest_point_with_heading(near_bend, 0., 180.)
+            .unwrap();
+        assert_eq!((west.line_string_idx, west.segment_idx), (1, 2));
+        assert_eq!(west.distance, 0.5);
+
+        assert!(index
+            .closest_point_with_heading(near_bend, 135., 30.)
+            .is_none());
+        let empty = HeadingIndex::new(&MultiLineString::<f64>::new(vec![]));
+        assert!(empty
+            .closest_point_with_heading(near_bend, 0., 180.)
+            .is_none());
+    }
+}
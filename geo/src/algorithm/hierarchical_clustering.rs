@@ -0,0 +1,441 @@
+use crate::line_measures::{Distance, Euclidean};
+use crate::{GeoFloat, MultiPoint, Point};
+
+/// Cluster points by [hierarchical agglomerative clustering], which repeatedly merges the two
+/// closest clusters, starting from a cluster of each point.
+///
+/// Unlike k-means, this needs no number of clusters up front: the [`Dendrogram`] of all the
+/// merges can be cut at a distance or a number of clusters afterwards. How the distance
+/// between two clusters is derived from the distances between their points is chosen by the
+/// [`Linkage`].
+///
+/// This takes `O(n²)` time and memory for `n` points, using the nearest-neighbour chain
+/// algorithm, so it suits up to tens of thousands of points.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, ClusterCut, HierarchicalClustering, Linkage};
+///
+/// let points = vec![
+///     point!(x: 0.0, y: 0.0),
+///     point!(x: 10.0, y: 0.0),
+///     point!(x: 0.0, y: 1.0),
+///     point!(x: 10.0, y: 2.0),
+///     point!(x: 5.0, y: 20.0),
+/// ];
+///
+/// let labels = points.hierarchical_cluster(Linkage::Single, ClusterCut::Count(3));
+/// assert_eq!(labels, vec![0, 1, 0, 1, 2]);
+///
+/// let labels = points.hierarchical_cluster(Linkage::Single, ClusterCut::Distance(1.5));
+/// assert_eq!(labels, vec![0, 1, 0, 2, 3]);
+///
+/// let dendrogram = points.dendrogram(Linkage::Complete);
+/// assert_eq!(dendrogram.merges().len(), 4);
+/// assert_eq!(dendrogram.labels(ClusterCut::Count(3)), vec![0, 1, 0, 1, 2]);
+/// ```
+///
+/// [hierarchical agglomerative clustering]: https://en.wikipedia.org/wiki/Hierarchical_clustering
+pub trait HierarchicalClustering<T>
+where
+    T: GeoFloat,
+{
+    /// Merge the points into a single cluster, recording each merge and the distance at which
+    /// it happened.
+    fn dendrogram(&self, linkage: Linkage) -> Dendrogram<T>;
+
+    /// The cluster of each point, in input point order, after merging clusters until `cut`.
+    ///
+    /// Clusters are numbered from 0, in the order of their first points.
+    fn hierarchical_cluster(&self, linkage: Linkage, cut: ClusterCut<T>) -> Vec<usize> {
+        self.dendrogram(linkage).labels(cut)
+    }
+}
+
+/// How [`HierarchicalClustering`] measures the distance between two clusters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Linkage {
+    /// The distance between their closest points, which can chain clusters along thin
+    /// trails of points.
+    Single,
+    /// The distance between their farthest points, which favours compact clusters.
+    Complete,
+    /// The mean distance between their points (UPGMA).
+    Average,
+}
+
+/// Where to stop merging clusters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClusterCut<T> {
+    /// Merge clusters no farther apart than this distance.
+    Distance(T),
+    /// Merge clusters until there are at most this many, or one if this is 0.
+    Count(usize),
+}
+
+/// A merge of two clusters in a [`Dendrogram`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Merge<T> {
+    /// The clusters merged: `i < n` is the cluster of the `i`th of the `n` points, and
+    /// `n + i` is the cluster formed by the `i`th merge.
+    pub clusters: [usize; 2],
+    /// The distance between the clusters, by the [`Linkage`] used.
+    pub distance: T,
+    /// The number of points in the merged cluster.
+    pub size: usize,
+}
+
+/// The merges of [`HierarchicalClustering::dendrogram`], in order of increasing distance, which
+/// can be cut into clusters.
+///
+/// This is the same as the linkage matrix of SciPy's `scipy.cluster.hierarchy.linkage`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dendrogram<T> {
+    merges: Vec<Merge<T>>,
+    len: usize,
+}
+
+impl<T: GeoFloat> Dendrogram<T> {
+    /// The `n - 1` merges of the `n` points, in order of increasing distance.
+    pub fn merges(&self) -> &[Merge<T>] {
+        &self.merges
+    }
+
+    /// The number of points clustered.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The cluster of each point, in input point order, after the merges up to `cut`.
+    ///
+    /// Clusters are numbered from 0, in the order of their first points.
+    pub fn labels(&self, cut: ClusterCut<T>) -> Vec<usize> {
+        let merges = match cut {
+            ClusterCut::Distance(distance) => self
+                .merges
+                .iter()
+                .take_while(|merge| merge.distance <= distance)
+                .count(),
+            ClusterCut::Count(count) => self.len.saturating_sub(count.max(1)),
+        };
+
+        // the merges refer to clusters by id, so track a point of each cluster
+        let mut parents: Vec<usize> = (0..self.len).collect();
+        let mut representatives: Vec<usize> = (0..self.len).collect();
+        for merge in &self.merges[..merges] {
+            let [a, b] = merge.clusters.map(|cluster| representatives[cluster]);
+            let (a, b) = (find(&mut parents, a), find(&mut parents, b));
+            parents[a.max(b)] = a.min(b);
+            representatives.push(a.min(b));
+        }
+
+        let mut labels = vec![usize::MAX; self.len];
+        let mut next_label = 0;
+        for point in 0..self.len {
+            let root = find(&mut parents, point);
+            if labels[root] == usize::MAX {
+                labels[root] = next_label;
+                next_label += 1;
+            }
+            labels[point] = labels[root];
+        }
+        labels
+    }
+}
+
+/// The root of `i` in the disjoint set forest `parents`, halving the path to it.
+fn find(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// The distances between each pair of clusters, of which `i < j` is at `i * n + j`.
+struct DistanceMatrix<T> {
+    distances: Vec<T>,
+    len: usize,
+}
+
+impl<T: GeoFloat> DistanceMatrix<T> {
+    fn new(points: &[Point<T>]) -> Self {
+        let len = points.len();
+        let mut distances = vec![T::zero(); len * len];
+        for i in 0..len {
+            for j in i + 1..len {
+                distances[i * len + j] = Euclidean.distance(points[i], points[j]);
+            }
+        }
+        Self { distances, len }
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        self.distances[i.min(j) * self.len + i.max(j)]
+    }
+
+    fn set(&mut self, i: usize, j: usize, distance: T) {
+        self.distances[i.min(j) * self.len + i.max(j)] = distance;
+    }
+}
+
+/// Build the dendrogram of `points` with the nearest-neighbour chain algorithm, which is exact
+/// for the reducible linkages of [`Linkage`].
+///
+/// Based on: Müllner, D. (2011). *Modern hierarchical, agglomerative clustering algorithms.*
+/// arXiv: [1109.2378](https://arxiv.org/abs/1109.2378)
+fn nn_chain<T: GeoFloat>(points: &[Point<T>], linkage: Linkage) -> Dendrogram<T> {
+    let len = points.len();
+    let mut distances = DistanceMatrix::new(points);
+    // the clusters are kept at the index of one of their points
+    let mut sizes = vec![1; len];
+    let mut active: Vec<usize> = (0..len).collect();
+    let mut chain: Vec<usize> = Vec::new();
+    let mut merges: Vec<(usize, usize, T)> = Vec::with_capacity(len.saturating_sub(1));
+
+    while active.len() > 1 {
+        if chain.is_empty() {
+            chain.push(active[0]);
+        }
+        // grow the chain of nearest neighbours until two clusters are each other's nearest
+        let (a, b, distance) = loop {
+            let a = chain[chain.len() - 1];
+            let previous = (chain.len() > 1).then(|| chain[chain.len() - 2]);
+            // prefer the previous cluster on ties, so that the chain ends
+            let mut nearest = previous.map(|p| (p, distances.get(a, p)));
+            for &c in &active {
+                if c == a {
+                    continue;
+                }
+                let distance = distances.get(a, c);
+                if !nearest.is_some_and(|(_, nearest)| nearest <= distance) {
+                    nearest = Some((c, distance));
+                }
+            }
+            let (b, distance) = nearest.expect("another active cluster");
+            if Some(b) == previous {
+                chain.truncate(chain.len() - 2);
+                break (a, b, distance);
+            }
+            chain.push(b);
+        };
+
+        // merge `a` into `b`, updating the distances to `b` by the Lance-Williams formula
+        let (size_a, size_b) = (sizes[a], sizes[b]);
+        active.retain(|&c| c != a);
+        for &c in &active {
+            if c == b {
+                continue;
+            }
+            let (to_a, to_b) = (distances.get(a, c), distances.get(b, c));
+            let distance = match linkage {
+                Linkage::Single => to_a.min(to_b),
+                Linkage::Complete => to_a.max(to_b),
+                Linkage::Average => {
+                    let (size_a, size_b) = (T::from(size_a).unwrap(), T::from(size_b).unwrap());
+                    (to_a * size_a + to_b * size_b) / (size_a + size_b)
+                }
+            };
+            distances.set(b, c, distance);
+        }
+        sizes[b] = size_a + size_b;
+        merges.push((a, b, distance));
+    }
+
+    // the chain finds merges out of order, but a cluster is never merged at a smaller distance
+    // than those it was formed at, so sorting them keeps each after those it depends on
+    merges.sort_by(|(_, _, a), (_, _, b)| a.total_cmp(b));
+
+    // number the clusters as the merges form them
+    let mut parents: Vec<usize> = (0..len).collect();
+    let mut ids: Vec<usize> = (0..len).collect();
+    let mut sizes = vec![1; len];
+    let merges = merges
+        .into_iter()
+        .enumerate()
+        .map(|(i, (a, b, distance))| {
+            let (a, b) = (find(&mut parents, a), find(&mut parents, b));
+            let mut clusters = [ids[a], ids[b]];
+            clusters.sort_unstable();
+            parents[a] = b;
+            ids[b] = len + i;
+            sizes[b] += sizes[a];
+            Merge {
+                clusters,
+                distance,
+                size: sizes[b],
+            }
+        })
+        .collect();
+    Dendrogram { merges, len }
+}
+
+impl<T> HierarchicalClustering<T> for MultiPoint<T>
+where
+    T: GeoFloat,
+{
+    fn dendrogram(&self, linkage: Linkage) -> Dendrogram<T> {
+        nn_chain(&self.0, linkage)
+    }
+}
+
+impl<T> HierarchicalClustering<T> for [Point<T>]
+where
+    T: GeoFloat,
+{
+    fn dendrogram(&self, linkage: Linkage) -> Dendrogram<T> {
+        nn_chain(self, linkage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn merges() {
+        let points = [
+            point!(x: 0.0, y: 0.0),
+            point!(x: 4.0, y: 0.0),
+            point!(x: 0.0, y: 1.0),
+            point!(x: 4.0, y: 3.0),
+        ];
+
+        let dendrogram = points.dendrogram(Linkage::Single);
+        assert_eq!(
+            dendrogram.merges(),
+            &[
+                Merge {
+                    clusters: [0, 2],
+                    distance: 1.0,
+                    size: 2
+                },
+                Merge {
+                    clusters: [1, 3],
+                    distance: 3.0,
+                    size: 2
+                },
+                Merge {
+                    clusters: [4, 5],
+                    distance: 4.0,
+                    size: 4
+                },
+            ]
+        );
+
+        let dendrogram = points.dendrogram(Linkage::Complete);
+        assert_eq!(dendrogram.merges()[2].distance, 5.0);
+
+        // the mean of the distances between the points of the two clusters
+        let dendrogram = points.dendrogram(Linkage::Average);
+        assert_relative_eq!(
+            dendrogram.merges()[2].distance,
+            (4.0 + 5.0 + 17f64.sqrt() + 20f64.sqrt()) / 4.0
+        );
+    }
+
+    #[test]
+    fn matches_naive() {
+        // pseudorandom points, without ties between their distances
+        let points: Vec<Point> = (0..40)
+            .map(|i| {
+                let i = i as f64;
+                point!(x: (i * 12.9898).sin() * 100.0, y: (i * 78.233).sin() * 100.0)
+            })
+            .collect();
+
+        for linkage in [Linkage::Single, Linkage::Complete, Linkage::Average] {
+            // merge the closest pair of clusters by comparing all of their points
+            let mut clusters: Vec<Vec<Point>> = points.iter().map(|p| vec![*p]).collect();
+            let mut naive = vec![];
+            while clusters.len() > 1 {
+                let mut closest = (0, 0, f64::INFINITY);
+                for i in 0..clusters.len() {
+                    for j in i + 1..clusters.len() {
+                        let distances = clusters[i]
+                            .iter()
+                            .flat_map(|a| clusters[j].iter().map(|b| Euclidean.distance(*a, *b)));
+                        let distance = match linkage {
+                            Linkage::Single => distances.fold(f64::INFINITY, f64::min),
+                            Linkage::Complete => distances.fold(0.0, f64::max),
+                            Linkage::Average => {
+                                distances.sum::<f64>()
+                                    / (clusters[i].len() * clusters[j].len()) as f64
+                            }
+                        };
+                        if distance < closest.2 {
+                            closest = (i, j, distance);
+                        }
+                    }
+                }
+                let merged = clusters.remove(closest.1);
+                clusters[closest.0].extend(merged);
+                naive.push(closest.2);
+            }
+
+            let dendrogram = points.dendrogram(linkage);
+            for (merge, distance) in dendrogram.merges().iter().zip(naive) {
+                assert_relative_eq!(merge.distance, distance, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn chaining() {
+        // evenly spaced points, and a compact pair far away
+        let mut points: Vec<_> = (0..10).map(|i| point!(x: i as f64, y: 0.0)).collect();
+        points.extend([point!(x: 100.0, y: 0.0), point!(x: 100.0, y: 0.5)]);
+        let multi_point = MultiPoint::new(points);
+
+        let two_groups = [vec![0; 10], vec![1; 2]].concat();
+
+        // single linkage chains the evenly spaced points together
+        let labels = multi_point.hierarchical_cluster(Linkage::Single, ClusterCut::Distance(1.0));
+        assert_eq!(labels, two_groups);
+
+        // complete linkage can't
+        let labels = multi_point.hierarchical_cluster(Linkage::Complete, ClusterCut::Distance(1.0));
+        assert_eq!(*labels.iter().max().unwrap(), 5);
+        assert_eq!(labels[10..], [5, 5]);
+
+        for linkage in [Linkage::Single, Linkage::Complete, Linkage::Average] {
+            let dendrogram = multi_point.dendrogram(linkage);
+            assert_eq!(dendrogram.merges().len(), 11);
+            assert!(dendrogram
+                .merges()
+                .windows(2)
+                .all(|w| w[0].distance <= w[1].distance));
+            assert_eq!(dendrogram.merges()[10].size, 12);
+            assert_eq!(dendrogram.labels(ClusterCut::Count(0)), vec![0; 12]);
+            assert_eq!(
+                dendrogram.labels(ClusterCut::Count(12)),
+                (0..12).collect::<Vec<_>>()
+            );
+            assert_eq!(dendrogram.labels(ClusterCut::Count(2)), two_groups);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: [Point; 0] = [];
+        assert!(empty.dendrogram(Linkage::Single).is_empty());
+        assert!(empty
+            .hierarchical_cluster(Linkage::Single, ClusterCut::Count(1))
+            .is_empty());
+
+        let duplicates = [point!(x: 1.0, y: 1.0); 3];
+        let dendrogram = duplicates.dendrogram(Linkage::Average);
+        assert_eq!(dendrogram.len(), 3);
+        assert!(dendrogram
+            .merges()
+            .iter()
+            .all(|merge| merge.distance == 0.0));
+        assert_eq!(dendrogram.labels(ClusterCut::Distance(0.0)), vec![0; 3]);
+    }
+}
@@ -0,0 +1,137 @@
+use rstar::primitives::CachedEnvelope;
+use rstar::{PointDistance, RTree, AABB};
+
+use crate::{Closest, ClosestPoint, GeoFloat, Line, LinesIter, Point};
+
+/// An index of the segments ("facets") of a geometry, for repeated distance queries against it.
+///
+/// Building the index takes `O(n log n)` time for a geometry of `n` segments, after which the
+/// distance from a point to the nearest segment, and the nearest point on it, take about
+/// `O(log n)`, rather than the `O(n)` of [`Distance`](crate::Distance) and [`ClosestPoint`].
+/// This is a big win when querying many points against a large geometry, e.g. the distance of
+/// many locations to a coastline. As in [JTS], only the segments are indexed, so the distance
+/// from a point inside a polygon is the distance to its boundary, not zero.
+///
+/// Distances are Euclidean, so geographic coordinates should be projected first.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, IndexedFacetDistance};
+///
+/// let coastline = wkt!(LINESTRING(0. 0.,10. 0.,10. 10.,20. 10.));
+/// let index = IndexedFacetDistance::new(&coastline);
+///
+/// assert_eq!(index.distance(point!(x: 5., y: 3.)), Some(3.));
+/// assert_eq!(index.nearest_point(point!(x: 12., y: 3.)), Some(point!(x: 10., y: 3.)));
+/// assert!(index.is_within_distance(point!(x: 15., y: 12.), 2.));
+/// ```
+///
+/// [JTS]: https://locationtech.github.io/jts/javadoc/org/locationtech/jts/operation/distance/IndexedFacetDistance.html
+pub struct IndexedFacetDistance<T: GeoFloat> {
+    tree: RTree<CachedEnvelope<Line<T>>>,
+}
+
+impl<T: GeoFloat> IndexedFacetDistance<T> {
+    /// Indexes the segments of `geometry`, i.e. of a line string, or of the rings of a polygon.
+    pub fn new<'a, G>(geometry: &'a G) -> Self
+    where
+        G: LinesIter<'a, Scalar = T>,
+    {
+        Self {
+            tree: RTree::bulk_load(geometry.lines_iter().map(CachedEnvelope::new).collect()),
+        }
+    }
+
+    /// The distance from `point` to the nearest segment, or `None` if there are no segments.
+    pub fn distance(&self, point: Point<T>) -> Option<T> {
+        self.nearest_segment(point)
+            .map(|(_, distance_2)| distance_2.sqrt())
+    }
+
+    /// The nearest point to `point` on any segment, or `None` if there are no segments.
+    pub fn nearest_point(&self, point: Point<T>) -> Option<Point<T>> {
+        self.nearest_segment(point)
+            .map(|(segment, _)| match segment.closest_point(&point) {
+                Closest::Intersection(closest) | Closest::SinglePoint(closest) => closest,
+                // a degenerate segment is a single point
+                Closest::Indeterminate => segment.start_point(),
+            })
+    }
+
+    // `RTree::nearest_neighbor` prunes with a bound which only holds for points, so can miss
+    // the nearest segment
+    fn nearest_segment(&self, point: Point<T>) -> Option<(&Line<T>, T)> {
+        self.tree
+            .nearest_neighbor_iter_with_distance_2(&point)
+            .next()
+            .map(|(segment, distance_2)| (&**segment, distance_2))
+    }
+
+    /// Whether any segment is within `max_distance` of `point`.
+    ///
+    /// This only visits the segments near `point`, so is quicker than comparing
+    /// [`distance`](Self::distance) to `max_distance` when `max_distance` is small.
+    pub fn is_within_distance(&self, point: Point<T>, max_distance: T) -> bool {
+        let margin = Point::new(max_distance, max_distance);
+        let envelope = AABB::from_corners(point - margin, point + margin);
+        self.tree
+            .locate_in_envelope_intersecting(&envelope)
+            .any(|segment| segment.distance_2(&point) <= max_distance * max_distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, BoundingRect, Distance, Euclidean, LineString, Polygon};
+
+    #[test]
+    fn matches_distance() {
+        let norway = geo_test_fixtures::norway_main::<f64>();
+        let polygon = Polygon::new(norway.clone(), vec![]);
+        let index = IndexedFacetDistance::new(&polygon);
+        let rect = norway.bounding_rect().unwrap();
+        for i in 0..20 {
+            for j in 0..20 {
+                let point = point!(
+                    x: rect.min().x + rect.width() * (i as f64 * 0.061 - 0.1),
+                    y: rect.min().y + rect.height() * (j as f64 * 0.059 - 0.1)
+                );
+                let expected = Euclidean.distance(&point, &norway);
+                let distance = index.distance(point).unwrap();
+                approx::assert_relative_eq!(distance, expected);
+                let nearest = index.nearest_point(point).unwrap();
+                approx::assert_relative_eq!(
+                    Euclidean.distance(point, nearest),
+                    expected,
+                    max_relative = 1e-9
+                );
+                assert!(index.is_within_distance(point, expected * 1.001));
+                assert!(!index.is_within_distance(point, expected * 0.999));
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_rings() {
+        let polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)
+        ));
+        let index = IndexedFacetDistance::new(&polygon);
+        // inside the polygon, nearer the hole than the exterior
+        assert_eq!(index.distance(point!(x: 3., y: 5.)), Some(1.));
+        assert_eq!(
+            index.nearest_point(point!(x: 3., y: 5.)),
+            Some(point!(x: 4., y: 5.))
+        );
+        assert_eq!(index.distance(point!(x: 5., y: 5.)), Some(1.));
+
+        let empty =
+            IndexedFacetDistance::new(&Polygon::<f64>::new(LineString::new(vec![]), vec![]));
+        assert_eq!(empty.distance(point!(x: 0., y: 0.)), None);
+        assert_eq!(empty.nearest_point(point!(x: 0., y: 0.)), None);
+        assert!(!empty.is_within_distance(point!(x: 0., y: 0.), 1.));
+    }
+}
@@ -3,6 +3,7 @@ use crate::geometry::*;
 use crate::geometry_delegate_impl;
 use crate::BoundingRect;
 use crate::CoordNum;
+use crate::GeometryCow;
 
 impl<T, G> Intersects<G> for Geometry<T>
 where
@@ -22,6 +23,26 @@ where
         fn intersects(&self, rhs: &G) -> bool;
     }
 }
+
+impl<T, G> Intersects<G> for GeometryCow<'_, T>
+where
+    T: CoordNum,
+    Point<T>: Intersects<G>,
+    MultiPoint<T>: Intersects<G>,
+    Line<T>: Intersects<G>,
+    LineString<T>: Intersects<G>,
+    MultiLineString<T>: Intersects<G>,
+    Triangle<T>: Intersects<G>,
+    Rect<T>: Intersects<G>,
+    Polygon<T>: Intersects<G>,
+    MultiPolygon<T>: Intersects<G>,
+    G: BoundingRect<T>,
+{
+    crate::geometry_cow_delegate_impl! {
+        fn intersects(&self, rhs: &G) -> bool;
+    }
+}
+
 symmetric_intersects_impl!(Coord<T>, Geometry<T>);
 symmetric_intersects_impl!(Line<T>, Geometry<T>);
 symmetric_intersects_impl!(Rect<T>, Geometry<T>);
@@ -108,8 +108,22 @@ where
         && value_in_between(value.y, bound_1.y, bound_2.y)
 }
 
-// A cheap bbox check to see if we can skip the more expensive intersection computation
-fn has_disjoint_bboxes<T, A, B>(a: &A, b: &B) -> bool
+/// Checks if the bounding rectangles of two geometries are disjoint.
+///
+/// This is a cheap test which can be used to skip a more expensive pairwise computation, since
+/// geometries with disjoint bounding rectangles can't intersect. Empty geometries have no
+/// bounding rectangle, so `false` is returned if either geometry is empty.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{has_disjoint_bboxes, line_string, point};
+///
+/// let line = line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)];
+/// assert!(has_disjoint_bboxes(&line, &point!(x: 2.0, y: 2.0)));
+/// assert!(!has_disjoint_bboxes(&line, &point!(x: 1.0, y: 0.0)));
+/// ```
+pub fn has_disjoint_bboxes<T, A, B>(a: &A, b: &B) -> bool
 where
     T: CoordNum,
     A: BoundingRect<T>,
@@ -126,6 +140,35 @@ where
     disjoint_bbox
 }
 
+/// Wraps a pairwise predicate so that it returns `false` without being called when the
+/// bounding rectangles of the geometries are disjoint.
+///
+/// This suits predicates which can only hold for geometries that intersect, such as custom
+/// touching or overlap tests. See [`has_disjoint_bboxes`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{bbox_filtered, line_string, Intersects, LineString, Polygon, Rect};
+///
+/// let crosses_boundary = bbox_filtered(|line: &LineString, polygon: &Polygon| {
+///     line.intersects(polygon.exterior())
+/// });
+///
+/// let polygon = Rect::new((0.0, 0.0), (1.0, 1.0)).to_polygon();
+/// assert!(crosses_boundary(&line_string![(x: 0.5, y: 0.5), (x: 2.0, y: 0.5)], &polygon));
+/// assert!(!crosses_boundary(&line_string![(x: 2.0, y: 2.0), (x: 3.0, y: 3.0)], &polygon));
+/// ```
+pub fn bbox_filtered<T, A, B, P>(predicate: P) -> impl Fn(&A, &B) -> bool
+where
+    T: CoordNum,
+    A: BoundingRect<T>,
+    B: BoundingRect<T>,
+    P: Fn(&A, &B) -> bool,
+{
+    move |a, b| !has_disjoint_bboxes(a, b) && predicate(a, b)
+}
+
 #[cfg(test)]
 mod test {
     use crate::Intersects;
@@ -135,6 +178,26 @@ mod test {
     };
 
     /// Tests: intersection LineString and LineString
+    #[test]
+    fn bbox_filtered_skips_disjoint() {
+        use crate::{bbox_filtered, point, wkt, Point};
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        let predicate = bbox_filtered(|_: &Point, _: &Point| {
+            calls.set(calls.get() + 1);
+            true
+        });
+        assert!(!predicate(&point!(x: 0.0, y: 0.0), &point!(x: 1.0, y: 1.0)));
+        assert_eq!(calls.get(), 0);
+        assert!(predicate(&point!(x: 1.0, y: 1.0), &point!(x: 1.0, y: 1.0)));
+        assert_eq!(calls.get(), 1);
+
+        // Empty geometries have no bbox, so the predicate decides
+        let empty = bbox_filtered(|_: &crate::MultiPoint, _: &Point| true);
+        assert!(empty(&wkt!(MULTIPOINT EMPTY), &point!(x: 1.0, y: 1.0)));
+    }
+
     #[test]
     fn empty_linestring1_test() {
         let linestring = line_string![(x: 3., y: 2.), (x: 7., y: 6.)];
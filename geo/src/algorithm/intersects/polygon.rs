@@ -1,5 +1,6 @@
 use super::{has_disjoint_bboxes, Intersects};
 use crate::coordinate_position::CoordPos;
+use crate::monotone_chain::{MonotoneChains, MIN_CHAINED_COORDS};
 use crate::{BoundingRect, CoordinatePosition};
 use crate::{
     Coord, CoordNum, GeoNum, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon, Rect,
@@ -29,7 +30,32 @@ where
     }
 }
 symmetric_intersects_impl!(Line<T>, Polygon<T>);
-symmetric_intersects_impl!(Polygon<T>, LineString<T>);
+
+impl<T> Intersects<LineString<T>> for Polygon<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, line_string: &LineString<T>) -> bool {
+        if line_string.0.is_empty() {
+            return false;
+        }
+        if line_string.0.len() < MIN_CHAINED_COORDS && self.exterior().0.len() < MIN_CHAINED_COORDS
+        {
+            return line_string.intersects(self);
+        }
+        if has_disjoint_bboxes(self, line_string) {
+            return false;
+        }
+
+        // Unless it crosses or touches a ring, the line string lies entirely inside or entirely
+        // outside of the polygon.
+        let chains = MonotoneChains::new(line_string);
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .any(|ring| MonotoneChains::new(ring).intersects(&chains))
+            || self.intersects(&line_string.0[0])
+    }
+}
 symmetric_intersects_impl!(Polygon<T>, MultiLineString<T>);
 
 impl<T> Intersects<Rect<T>> for Polygon<T>
@@ -100,4 +126,18 @@ mod tests {
         let b = Geometry::from(polygon![]);
         assert!(!a.intersects(&b));
     }
+
+    #[test]
+    fn long_polygon_does_not_intersect_empty_line_string() {
+        let exterior: LineString = (0..40)
+            .map(|i| {
+                let angle = f64::from(i) / 40. * std::f64::consts::TAU;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        let polygon = Polygon::new(exterior, vec![]);
+        let empty = LineString::<f64>::new(vec![]);
+        assert!(!polygon.intersects(&empty));
+        assert!(!empty.intersects(&polygon));
+    }
 }
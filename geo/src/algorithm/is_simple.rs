@@ -0,0 +1,254 @@
+use crate::sweep::{Cross, Intersections, LineOrPoint};
+use crate::{
+    Coord, GeoFloat, Line, LineIntersection, LineString, MultiLineString, MultiPoint, Point,
+};
+
+/// Whether a geometry is simple, as defined by the [OGC Simple Features
+/// specification](https://www.ogc.org/standard/sfa/), i.e. it has no anomalous points such as
+/// self-intersections.
+///
+/// - A `Point` or `Line` is always simple.
+/// - A `MultiPoint` is simple if no two of its points are equal.
+/// - A `LineString` is simple if it doesn't pass through any point twice, except that a closed
+///   `LineString` ends where it starts.
+/// - A `MultiLineString` is simple if each of its `LineString`s is simple, and they only
+///   intersect at points on the boundary of both, i.e. at the ends of unclosed `LineString`s.
+///
+/// Repeated consecutive coordinates are ignored, and empty geometries are simple. This is a
+/// lighter check than [`Validation`](crate::Validation), e.g. to check the input of an
+/// algorithm which requires simple geometries.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, IsSimple};
+///
+/// assert!(wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 1.)).is_simple());
+/// // a bow tie
+/// assert!(!wkt!(LINESTRING(0. 0.,1. 1.,1. 0.,0. 1.,0. 0.)).is_simple());
+///
+/// // touching at the ends
+/// assert!(wkt!(MULTILINESTRING((0. 0.,1. 0.),(1. 0.,1. 1.))).is_simple());
+/// // touching at an interior vertex
+/// assert!(!wkt!(MULTILINESTRING((0. 0.,1. 0.,2. 0.),(1. 0.,1. 1.))).is_simple());
+///
+/// assert!(!wkt!(MULTIPOINT(0. 0.,1. 1.,0. 0.)).is_simple());
+/// ```
+pub trait IsSimple {
+    fn is_simple(&self) -> bool;
+}
+
+/// Whether a `LineString` is a ring, i.e. it is closed and [simple](IsSimple), with at least
+/// three distinct coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, IsRing};
+///
+/// assert!(wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.)).is_ring());
+/// // not closed
+/// assert!(!wkt!(LINESTRING(0. 0.,1. 0.,1. 1.)).is_ring());
+/// // not simple
+/// assert!(!wkt!(LINESTRING(0. 0.,1. 1.,1. 0.,0. 1.,0. 0.)).is_ring());
+/// ```
+pub trait IsRing {
+    fn is_ring(&self) -> bool;
+}
+
+impl<T: GeoFloat> IsSimple for Point<T> {
+    fn is_simple(&self) -> bool {
+        true
+    }
+}
+
+impl<T: GeoFloat> IsSimple for Line<T> {
+    fn is_simple(&self) -> bool {
+        true
+    }
+}
+
+impl<T: GeoFloat> IsSimple for MultiPoint<T> {
+    fn is_simple(&self) -> bool {
+        let mut coords: Vec<Coord<T>> = self.iter().map(|point| point.0).collect();
+        coords.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        coords.windows(2).all(|pair| pair[0] != pair[1])
+    }
+}
+
+impl<T: GeoFloat> IsSimple for LineString<T> {
+    fn is_simple(&self) -> bool {
+        lines_are_simple(std::slice::from_ref(self))
+    }
+}
+
+impl<T: GeoFloat> IsSimple for MultiLineString<T> {
+    fn is_simple(&self) -> bool {
+        lines_are_simple(&self.0)
+    }
+}
+
+impl<T: GeoFloat> IsRing for LineString<T> {
+    fn is_ring(&self) -> bool {
+        let mut coords = self.0.clone();
+        coords.dedup();
+        coords.len() >= 4 && self.is_closed() && self.is_simple()
+    }
+}
+
+/// A segment of one of the `LineString`s, between its coordinates `idx` and `idx + 1`, after
+/// removing repeated coordinates.
+#[derive(Debug, Clone, Copy)]
+struct Segment<T: GeoFloat> {
+    line: Line<T>,
+    line_string_idx: usize,
+    idx: usize,
+}
+
+impl<T: GeoFloat> Cross for Segment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<T> {
+        self.line.into()
+    }
+}
+
+/// The coordinates of a `LineString` without repeated consecutive coordinates, and whether it's
+/// closed.
+struct Path<T: GeoFloat> {
+    coords: Vec<Coord<T>>,
+    is_closed: bool,
+}
+
+impl<T: GeoFloat> Path<T> {
+    fn segments(&self) -> usize {
+        self.coords.len().saturating_sub(1)
+    }
+
+    /// Whether `coord` is on the boundary of the path, i.e. is an end of an unclosed path.
+    fn is_boundary(&self, coord: Coord<T>) -> bool {
+        !self.is_closed
+            && (self.coords.first() == Some(&coord) || self.coords.last() == Some(&coord))
+    }
+}
+
+fn lines_are_simple<T: GeoFloat>(line_strings: &[LineString<T>]) -> bool {
+    let paths: Vec<Path<T>> = line_strings
+        .iter()
+        .map(|line_string| {
+            let mut coords = line_string.0.clone();
+            coords.dedup();
+            Path {
+                is_closed: coords.len() > 1 && coords.first() == coords.last(),
+                coords,
+            }
+        })
+        .collect();
+
+    let segments = paths
+        .iter()
+        .enumerate()
+        .flat_map(|(line_string_idx, path)| {
+            path.coords
+                .windows(2)
+                .enumerate()
+                .map(move |(idx, pair)| Segment {
+                    line: Line::new(pair[0], pair[1]),
+                    line_string_idx,
+                    idx,
+                })
+        });
+
+    Intersections::from_iter(segments).all(|(a, b, intersection)| {
+        let LineIntersection::SinglePoint { intersection, .. } = intersection else {
+            // overlapping segments
+            return false;
+        };
+        let (a, b) = if (a.line_string_idx, a.idx) < (b.line_string_idx, b.idx) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        if a.line_string_idx != b.line_string_idx {
+            return paths[a.line_string_idx].is_boundary(intersection)
+                && paths[b.line_string_idx].is_boundary(intersection);
+        }
+
+        // Consecutive segments meet at their shared coordinate, as do the first and last segments
+        // of a closed path
+        let path = &paths[a.line_string_idx];
+        (b.idx == a.idx + 1 && intersection == a.line.end)
+            || (path.is_closed
+                && a.idx == 0
+                && b.idx == path.segments() - 1
+                && intersection == a.line.start)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn line_strings() {
+        let simple = [
+            wkt!(LINESTRING EMPTY),
+            wkt!(LINESTRING(0. 0.)),
+            wkt!(LINESTRING(0. 0.,1. 0.)),
+            // repeated and collinear coordinates
+            wkt!(LINESTRING(0. 0.,1. 0.,1. 0.,2. 0.,2. 1.)),
+            wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)),
+            wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 1.,0. 0.,0. 0.)),
+        ];
+        for line_string in simple {
+            assert!(line_string.is_simple(), "{line_string:?}");
+        }
+
+        let not_simple = [
+            // a spike
+            wkt!(LINESTRING(0. 0.,2. 0.,1. 0.)),
+            wkt!(LINESTRING(0. 0.,1. 0.,0. 0.)),
+            // touching itself at a vertex, or in the middle of a segment
+            wkt!(LINESTRING(0. 0.,2. 0.,2. 2.,1. 2.,1. 0.)),
+            wkt!(LINESTRING(0. 0.,2. 0.,2. 2.,0. 2.,2. 0.)),
+            // closed, but touching the start
+            wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.,-1. 1.,-1. 0.,0. 0.)),
+            // ends on itself without being closed
+            wkt!(LINESTRING(0. 0.,2. 0.,2. 2.,1. 0.)),
+        ];
+        for line_string in not_simple {
+            assert!(!line_string.is_simple(), "{line_string:?}");
+        }
+    }
+
+    #[test]
+    fn multi_line_strings() {
+        assert!(MultiLineString::<f64>::new(vec![]).is_simple());
+        assert!(wkt!(MULTILINESTRING((0. 0.,1. 0.),(1. 0.,2. 0.),(1. 0.,1. 1.))).is_simple());
+        // a closed ring has no boundary
+        assert!(!wkt!(MULTILINESTRING((0. 0.,1. 0.,1. 1.,0. 0.),(1. 0.,2. 0.))).is_simple());
+        // crossing
+        assert!(!wkt!(MULTILINESTRING((0. 0.,2. 2.),(0. 2.,2. 0.))).is_simple());
+        // overlapping
+        assert!(!wkt!(MULTILINESTRING((0. 0.,2. 0.),(1. 0.,3. 0.))).is_simple());
+        // one of the line strings isn't simple
+        assert!(!wkt!(MULTILINESTRING((0. 0.,1. 1.,1. 0.,0. 1.),(5. 5.,6. 6.))).is_simple());
+    }
+
+    #[test]
+    fn rings() {
+        assert!(wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.)).is_ring());
+        assert!(!LineString::<f64>::new(vec![]).is_ring());
+        assert!(!wkt!(LINESTRING(0. 0.,1. 0.,0. 0.)).is_ring());
+        assert!(!wkt!(LINESTRING(0. 0.,1. 0.,1. 1.)).is_ring());
+    }
+
+    #[test]
+    fn points() {
+        assert!(wkt!(MULTIPOINT(0. 0.,1. 1.,1. 0.)).is_simple());
+        assert!(!wkt!(MULTIPOINT(0. 0.,1. 1.,0. 0.)).is_simple());
+        assert!(MultiPoint::<f64>::new(vec![]).is_simple());
+        assert!(wkt!(POINT(0. 0.)).is_simple());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::bounding_rect::bounding_rect_merge;
+use crate::{CoordNum, ExtentSummary, GeoFloat, Geometry, Rect, Validation};
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{GeometryTrait, GeometryType};
+
+/// Summary statistics of a layer (a collection of geometries), computed in a single pass.
+///
+/// Typical of the metrics logged when ingesting data: how many geometries of each type there
+/// are, how many vertices they have, the extent of the layer, and, optionally, how many of the
+/// geometries are [valid](Validation).
+///
+/// With the `use-serde` feature, a summary can be serialized for logging or metrics export.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Geometry, LayerSummary, Rect};
+///
+/// let layer: Vec<Geometry> = vec![
+///     wkt!(POINT(0. 0.)).into(),
+///     wkt!(POINT(1. 5.)).into(),
+///     wkt!(LINESTRING(0. 0.,4. 1.,4. 2.)).into(),
+///     // self-intersecting
+///     wkt!(POLYGON((0. 0.,2. 2.,2. 0.,0. 2.,0. 0.))).into(),
+/// ];
+///
+/// let summary: LayerSummary = layer.iter().collect();
+/// assert_eq!(summary.count, 4);
+/// assert_eq!(summary.counts_by_type.point, 2);
+/// assert_eq!(summary.counts_by_type.line_string, 1);
+/// assert_eq!(summary.counts_by_type.polygon, 1);
+/// assert_eq!(summary.vertex_count, 10);
+/// assert_eq!(summary.mean_vertex_count(), Some(2.5));
+/// assert_eq!(summary.bounding_rect, Some(Rect::new((0., 0.), (4., 5.))));
+/// assert_eq!(summary.validity, None);
+///
+/// let summary = LayerSummary::from_iter_with_validity(&layer);
+/// let validity = summary.validity.unwrap();
+/// assert_eq!((validity.valid, validity.invalid), (3, 1));
+/// ```
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerSummary<T: CoordNum = f64> {
+    /// The number of geometries.
+    pub count: usize,
+    /// The number of geometries of each type.
+    pub counts_by_type: GeometryTypeCounts,
+    /// The total number of vertices of all the geometries.
+    pub vertex_count: usize,
+    /// The bounding rectangle of all the geometries, or `None` if they are all empty.
+    pub bounding_rect: Option<Rect<T>>,
+    /// How many of the geometries are valid, if validity was checked.
+    pub validity: Option<ValidityTally>,
+}
+
+/// The number of geometries of each type in a [`LayerSummary`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GeometryTypeCounts {
+    pub point: usize,
+    pub line: usize,
+    pub line_string: usize,
+    pub polygon: usize,
+    pub multi_point: usize,
+    pub multi_line_string: usize,
+    pub multi_polygon: usize,
+    pub geometry_collection: usize,
+    pub rect: usize,
+    pub triangle: usize,
+}
+
+/// How many of the geometries in a [`LayerSummary`] are valid.
+///
+/// Geometries which can't be represented by `geo` (e.g. empty points) count as invalid.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ValidityTally {
+    pub valid: usize,
+    pub invalid: usize,
+}
+
+impl<T: CoordNum> LayerSummary<T> {
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            counts_by_type: GeometryTypeCounts::default(),
+            vertex_count: 0,
+            bounding_rect: None,
+            validity: None,
+        }
+    }
+
+    /// The mean number of vertices per geometry, or `None` if there are no geometries.
+    pub fn mean_vertex_count(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.vertex_count as f64 / self.count as f64)
+    }
+
+    /// Add `geometry` to the summary, without checking its validity.
+    pub fn add(&mut self, geometry: &impl GeometryTrait<T = T>) {
+        self.add_geometry(geometry);
+    }
+
+    fn add_geometry(&mut self, geometry: &impl GeometryTrait<T = T>) -> Option<Geometry<T>> {
+        self.count += 1;
+        let counts = &mut self.counts_by_type;
+        match geometry.as_type() {
+            GeometryType::Point(_) => counts.point += 1,
+            GeometryType::Line(_) => counts.line += 1,
+            GeometryType::LineString(_) => counts.line_string += 1,
+            GeometryType::Polygon(_) => counts.polygon += 1,
+            GeometryType::MultiPoint(_) => counts.multi_point += 1,
+            GeometryType::MultiLineString(_) => counts.multi_line_string += 1,
+            GeometryType::MultiPolygon(_) => counts.multi_polygon += 1,
+            GeometryType::GeometryCollection(_) => counts.geometry_collection += 1,
+            GeometryType::Rect(_) => counts.rect += 1,
+            GeometryType::Triangle(_) => counts.triangle += 1,
+        }
+
+        let geometry = geometry.try_to_geometry()?;
+        if let Some(extent) = geometry.extent_summary() {
+            self.vertex_count += extent.coords_count;
+            self.bounding_rect = Some(match self.bounding_rect {
+                Some(bounding_rect) => bounding_rect_merge(bounding_rect, extent.bounding_rect),
+                None => extent.bounding_rect,
+            });
+        }
+        Some(geometry)
+    }
+}
+
+impl<T: GeoFloat> LayerSummary<T> {
+    /// Add `geometry` to the summary, including whether it is valid.
+    pub fn add_with_validity(&mut self, geometry: &impl GeometryTrait<T = T>) {
+        let is_valid = self
+            .add_geometry(geometry)
+            .is_some_and(|geometry| geometry.is_valid());
+        let validity = self.validity.get_or_insert_with(ValidityTally::default);
+        if is_valid {
+            validity.valid += 1;
+        } else {
+            validity.invalid += 1;
+        }
+    }
+
+    /// Summarize `geometries`, including how many of them are valid.
+    pub fn from_iter_with_validity<G: GeometryTrait<T = T>>(
+        geometries: impl IntoIterator<Item = G>,
+    ) -> Self {
+        let mut summary = Self::new();
+        for geometry in geometries {
+            summary.add_with_validity(&geometry);
+        }
+        summary.validity.get_or_insert_with(ValidityTally::default);
+        summary
+    }
+}
+
+impl<T: CoordNum> Default for LayerSummary<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: CoordNum, G: GeometryTrait<T = T>> FromIterator<G> for LayerSummary<T> {
+    /// Summarize `geometries`, without checking their validity.
+    fn from_iter<I: IntoIterator<Item = G>>(geometries: I) -> Self {
+        let mut summary = Self::new();
+        summary.extend(geometries);
+        summary
+    }
+}
+
+impl<T: CoordNum, G: GeometryTrait<T = T>> Extend<G> for LayerSummary<T> {
+    fn extend<I: IntoIterator<Item = G>>(&mut self, geometries: I) {
+        for geometry in geometries {
+            self.add(&geometry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn empty_layer() {
+        let summary: LayerSummary = Vec::<Geometry>::new().iter().collect();
+        assert_eq!(summary, LayerSummary::new());
+        assert_eq!(summary.mean_vertex_count(), None);
+
+        let summary = LayerSummary::from_iter_with_validity(Vec::<Geometry>::new());
+        assert_eq!(summary.validity, Some(ValidityTally::default()));
+    }
+
+    #[test]
+    fn counts_every_type() {
+        let layer: Vec<Geometry> = vec![
+            wkt!(MULTIPOINT(0. 0.,1. 1.)).into(),
+            wkt!(MULTILINESTRING((0. 0.,1. 1.),(2. 2.,3. 3.))).into(),
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)))).into(),
+            Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(POINT(-1. 3.)))),
+            Rect::new((0., 0.), (1., 1.)).into(),
+            crate::Line::new((0., 0.), (1., 10.)).into(),
+            crate::Triangle::new((0., 0.).into(), (1., 0.).into(), (0., 1.).into()).into(),
+        ];
+        let mut summary = LayerSummary::new();
+        for geometry in &layer {
+            summary.add_with_validity(geometry);
+        }
+        assert_eq!(
+            summary.counts_by_type,
+            GeometryTypeCounts {
+                multi_point: 1,
+                multi_line_string: 1,
+                multi_polygon: 1,
+                geometry_collection: 1,
+                rect: 1,
+                line: 1,
+                triangle: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(summary.vertex_count, 2 + 4 + 4 + 1 + 4 + 2 + 3);
+        assert_eq!(summary.bounding_rect, Some(Rect::new((-1., 0.), (3., 10.))));
+        assert_eq!(
+            summary.validity,
+            Some(ValidityTally {
+                valid: 7,
+                invalid: 0
+            })
+        );
+    }
+}
@@ -4,6 +4,7 @@ use geo_types::coord;
 use crate::BoundingRect;
 use crate::Intersects;
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum LineIntersection<F: GeoFloat> {
     /// Lines intersect in a single point
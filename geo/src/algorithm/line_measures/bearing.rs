@@ -9,5 +9,5 @@ pub trait Bearing<F: CoordFloat> {
     /// # Units
     /// - `origin`, `destination`: Point where the units of x/y depend on the [trait implementation](#implementors).
     /// - returns: degrees, where: North: 0°, East: 90°, South: 180°, West: 270°
-    fn bearing(origin: Point<F>, destination: Point<F>) -> F;
+    fn bearing(&self, origin: Point<F>, destination: Point<F>) -> F;
 }
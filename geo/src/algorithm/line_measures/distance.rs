@@ -8,5 +8,37 @@ pub trait Distance<F, Origin, Destination> {
     ///
     /// - `origin`, `destination`: geometry where the units of x/y depend on the trait implementation.
     /// - returns: depends on the trait implementation.
-    fn distance(origin: Origin, destination: Destination) -> F;
+    fn distance(&self, origin: Origin, destination: Destination) -> F;
+
+    /// Whether the minimum distance between two geometries is at most `max_distance`.
+    ///
+    /// This is `self.distance(origin, destination) <= max_distance`, but implementations may
+    /// answer without computing the exact distance, e.g. the [`Euclidean`] implementations
+    /// between line strings and polygons skip geometries whose bounding rectangles are too far
+    /// apart, and stop at the first pair of segments which are close enough. This makes it much
+    /// quicker for filtering many pairs of geometries by proximity.
+    ///
+    /// # Units
+    ///
+    /// - `origin`, `destination`, `max_distance`: as for [`distance`](Self::distance).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{wkt, Distance, Euclidean};
+    ///
+    /// let river = wkt!(LINESTRING(0. 0.,10. 0.,20. 5.));
+    /// let field = wkt!(POLYGON((12. 5.,16. 5.,16. 8.,12. 8.,12. 5.)));
+    ///
+    /// assert!(Euclidean.is_within_distance(&river, &field, 3.));
+    /// assert!(!Euclidean.is_within_distance(&river, &field, 1.));
+    /// ```
+    ///
+    /// [`Euclidean`]: crate::Euclidean
+    fn is_within_distance(&self, origin: Origin, destination: Destination, max_distance: F) -> bool
+    where
+        F: PartialOrd,
+    {
+        self.distance(origin, destination) <= max_distance
+    }
 }
@@ -6,6 +6,7 @@ pub trait InterpolatePoint<F: CoordFloat> {
     ///
     /// See [specific implementations](#implementors) for details.
     fn point_at_distance_between(
+        &self,
         start: Point<F>,
         end: Point<F>,
         distance_from_start: F,
@@ -14,7 +15,12 @@ pub trait InterpolatePoint<F: CoordFloat> {
     /// Returns a new Point along a line between two existing points.
     ///
     /// See [specific implementations](#implementors) for details.
-    fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F>;
+    fn point_at_ratio_between(
+        &self,
+        start: Point<F>,
+        end: Point<F>,
+        ratio_from_start: F,
+    ) -> Point<F>;
 
     /// Interpolates `Point`s along a line between `start` and `end`.
     ///
@@ -26,6 +32,7 @@ pub trait InterpolatePoint<F: CoordFloat> {
     ///
     /// `include_ends`: Should the start and end points be included in the output?
     fn points_along_line(
+        &self,
         start: Point<F>,
         end: Point<F>,
         max_distance: F,
@@ -43,16 +50,16 @@ mod tests {
         let end = Point::new(1.0, 1.0);
 
         let ratio = 0.0;
-        assert_eq!(Haversine::point_at_ratio_between(start, end, ratio), start);
-        assert_eq!(Euclidean::point_at_ratio_between(start, end, ratio), start);
-        assert_eq!(Geodesic::point_at_ratio_between(start, end, ratio), start);
-        assert_eq!(Rhumb::point_at_ratio_between(start, end, ratio), start);
+        assert_eq!(Haversine.point_at_ratio_between(start, end, ratio), start);
+        assert_eq!(Euclidean.point_at_ratio_between(start, end, ratio), start);
+        assert_eq!(Geodesic.point_at_ratio_between(start, end, ratio), start);
+        assert_eq!(Rhumb.point_at_ratio_between(start, end, ratio), start);
 
         let ratio = 1.0;
-        assert_eq!(Haversine::point_at_ratio_between(start, end, ratio), end);
-        assert_eq!(Euclidean::point_at_ratio_between(start, end, ratio), end);
-        assert_eq!(Geodesic::point_at_ratio_between(start, end, ratio), end);
-        assert_eq!(Rhumb::point_at_ratio_between(start, end, ratio), end);
+        assert_eq!(Haversine.point_at_ratio_between(start, end, ratio), end);
+        assert_eq!(Euclidean.point_at_ratio_between(start, end, ratio), end);
+        assert_eq!(Geodesic.point_at_ratio_between(start, end, ratio), end);
+        assert_eq!(Rhumb.point_at_ratio_between(start, end, ratio), end);
     }
 
     mod degenerate {
@@ -63,40 +70,22 @@ mod tests {
             let start = Point::new(1.0, 1.0);
 
             let ratio = 0.0;
-            assert_eq!(
-                Haversine::point_at_ratio_between(start, start, ratio),
-                start
-            );
-            assert_eq!(
-                Euclidean::point_at_ratio_between(start, start, ratio),
-                start
-            );
-            assert_eq!(Geodesic::point_at_ratio_between(start, start, ratio), start);
-            assert_eq!(Rhumb::point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Haversine.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Euclidean.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Geodesic.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Rhumb.point_at_ratio_between(start, start, ratio), start);
 
             let ratio = 0.5;
-            assert_eq!(
-                Haversine::point_at_ratio_between(start, start, ratio),
-                start
-            );
-            assert_eq!(
-                Euclidean::point_at_ratio_between(start, start, ratio),
-                start
-            );
-            assert_eq!(Geodesic::point_at_ratio_between(start, start, ratio), start);
-            assert_eq!(Rhumb::point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Haversine.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Euclidean.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Geodesic.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Rhumb.point_at_ratio_between(start, start, ratio), start);
 
             let ratio = 1.0;
-            assert_eq!(
-                Haversine::point_at_ratio_between(start, start, ratio),
-                start
-            );
-            assert_eq!(
-                Euclidean::point_at_ratio_between(start, start, ratio),
-                start
-            );
-            assert_eq!(Geodesic::point_at_ratio_between(start, start, ratio), start);
-            assert_eq!(Rhumb::point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Haversine.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Euclidean.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Geodesic.point_at_ratio_between(start, start, ratio), start);
+            assert_eq!(Rhumb.point_at_ratio_between(start, start, ratio), start);
         }
 
         #[test]
@@ -107,19 +96,19 @@ mod tests {
 
             let distance = 0.0;
             assert_eq!(
-                Haversine::point_at_distance_between(start, start, distance),
+                Haversine.point_at_distance_between(start, start, distance),
                 start
             );
 
-            let euclidean_result = Euclidean::point_at_distance_between(start, start, distance);
+            let euclidean_result = Euclidean.point_at_distance_between(start, start, distance);
             assert!(euclidean_result.x().is_nan());
             assert!(euclidean_result.y().is_nan());
             assert_eq!(
-                Geodesic::point_at_distance_between(start, start, distance),
+                Geodesic.point_at_distance_between(start, start, distance),
                 start
             );
             assert_eq!(
-                Rhumb::point_at_distance_between(start, start, distance),
+                Rhumb.point_at_distance_between(start, start, distance),
                 start
             );
 
@@ -127,20 +116,20 @@ mod tests {
             let due_north = Point::new(1.0, 1.9);
             let due_south = Point::new(1.0, 0.1);
             assert_relative_eq!(
-                Haversine::point_at_distance_between(start, start, distance),
+                Haversine.point_at_distance_between(start, start, distance),
                 due_north,
                 epsilon = 1.0e-1
             );
-            let euclidean_result = Euclidean::point_at_distance_between(start, start, distance);
+            let euclidean_result = Euclidean.point_at_distance_between(start, start, distance);
             assert!(euclidean_result.x().is_nan());
             assert!(euclidean_result.y().is_nan());
             assert_relative_eq!(
-                Geodesic::point_at_distance_between(start, start, distance),
+                Geodesic.point_at_distance_between(start, start, distance),
                 due_south,
                 epsilon = 1.0e-1
             );
             assert_relative_eq!(
-                Rhumb::point_at_distance_between(start, start, distance),
+                Rhumb.point_at_distance_between(start, start, distance),
                 due_north,
                 epsilon = 1.0e-1
             );
@@ -153,37 +142,45 @@ mod tests {
             let max_distance = 1.0;
 
             let include_ends = true;
-            let points: Vec<_> =
-                Haversine::points_along_line(start, start, max_distance, include_ends).collect();
+            let points: Vec<_> = Haversine
+                .points_along_line(start, start, max_distance, include_ends)
+                .collect();
             assert_eq!(points, vec![start, start]);
 
-            let points: Vec<_> =
-                Euclidean::points_along_line(start, start, max_distance, include_ends).collect();
+            let points: Vec<_> = Euclidean
+                .points_along_line(start, start, max_distance, include_ends)
+                .collect();
             assert_eq!(points, vec![start, start]);
 
-            let points: Vec<_> =
-                Geodesic::points_along_line(start, start, max_distance, include_ends).collect();
+            let points: Vec<_> = Geodesic
+                .points_along_line(start, start, max_distance, include_ends)
+                .collect();
             assert_eq!(points, vec![start, start]);
 
-            let points: Vec<_> =
-                Rhumb::points_along_line(start, start, max_distance, include_ends).collect();
+            let points: Vec<_> = Rhumb
+                .points_along_line(start, start, max_distance, include_ends)
+                .collect();
             assert_eq!(points, vec![start, start]);
 
             let include_ends = false;
-            let points: Vec<_> =
-                Haversine::points_along_line(start, start, max_distance, include_ends).collect();
+            let points: Vec<_> = Haversine
+                .points_along_line(start, start, max_distance, include_ends)
+                .collect();
             assert_eq!(points, vec![]);
 
-            let points: Vec<_> =
-                Euclidean::points_along_line(start, start, max_distance, include_ends).collect();
+            let points: Vec<_> = Euclidean
+                .points_along_line(start, start, max_distance, include_ends)
+                .collect();
             assert_eq!(points, vec![]);
 
-            let points: Vec<_> =
-                Geodesic::points_along_line(start, start, max_distance, include_ends).collect();
+            let points: Vec<_> = Geodesic
+                .points_along_line(start, start, max_distance, include_ends)
+                .collect();
             assert_eq!(points, vec![]);
 
-            let points: Vec<_> =
-                Rhumb::points_along_line(start, start, max_distance, include_ends).collect();
+            let points: Vec<_> = Rhumb
+                .points_along_line(start, start, max_distance, include_ends)
+                .collect();
             assert_eq!(points, vec![]);
         }
     }
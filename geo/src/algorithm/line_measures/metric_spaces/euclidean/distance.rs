@@ -2,10 +2,10 @@ use super::{Distance, Euclidean};
 use crate::algorithm::Intersects;
 use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
 use crate::geometry::*;
-use crate::{CoordFloat, GeoFloat, GeoNum};
-use num_traits::{Bounded, Float};
+use crate::monotone_chain::{MonotoneChains, MIN_CHAINED_COORDS};
+use crate::{BoundingRect, CoordFloat, GeoFloat, GeoNum};
 use rstar::primitives::CachedEnvelope;
-use rstar::RTree;
+use rstar::{RTree, RTreeObject, AABB};
 
 // Distance is a symmetric operation, so we can implement it once for both
 macro_rules! symmetric_distance_impl {
@@ -14,8 +14,12 @@ macro_rules! symmetric_distance_impl {
         where
             F: $t,
         {
-            fn distance(a: $a, b: $b) -> F {
-                Self::distance(b, a)
+            fn distance(&self, a: $a, b: $b) -> F {
+                self.distance(b, a)
+            }
+
+            fn is_within_distance(&self, a: $a, b: $b, max_distance: F) -> bool {
+                self.is_within_distance(b, a, max_distance)
             }
         }
     };
@@ -26,13 +30,13 @@ macro_rules! symmetric_distance_impl {
 // └───────────────────────────┘
 
 impl<F: CoordFloat> Distance<F, Coord<F>, Coord<F>> for Euclidean {
-    fn distance(origin: Coord<F>, destination: Coord<F>) -> F {
+    fn distance(&self, origin: Coord<F>, destination: Coord<F>) -> F {
         let delta = origin - destination;
         delta.x.hypot(delta.y)
     }
 }
 impl<F: CoordFloat> Distance<F, Coord<F>, &Line<F>> for Euclidean {
-    fn distance(coord: Coord<F>, line: &Line<F>) -> F {
+    fn distance(&self, coord: Coord<F>, line: &Line<F>) -> F {
         ::geo_types::private_utils::point_line_euclidean_distance(Point(coord), *line)
     }
 }
@@ -59,7 +63,7 @@ impl<F: CoordFloat> Distance<F, Point<F>, Point<F>> for Euclidean {
     /// let new_york_city = Point::new(-8238310.24, 4942194.78);
     /// // web mercator
     /// let london = Point::new(-14226.63, 6678077.70);
-    /// let distance: f64 = Euclidean::distance(new_york_city, london);
+    /// let distance: f64 = Euclidean.distance(new_york_city, london);
     ///
     /// assert_eq!(
     ///     8_405_286., // meters in web mercator
@@ -70,33 +74,43 @@ impl<F: CoordFloat> Distance<F, Point<F>, Point<F>> for Euclidean {
     /// [`Haversine`]: crate::line_measures::metric_spaces::Haversine
     /// [`Geodesic`]: crate::line_measures::metric_spaces::Geodesic
     /// [metric spaces]: crate::line_measures::metric_spaces
-    fn distance(origin: Point<F>, destination: Point<F>) -> F {
-        Self::distance(origin.0, destination.0)
+    fn distance(&self, origin: Point<F>, destination: Point<F>) -> F {
+        self.distance(origin.0, destination.0)
     }
 }
 
 impl<F: CoordFloat> Distance<F, &Point<F>, &Point<F>> for Euclidean {
-    fn distance(origin: &Point<F>, destination: &Point<F>) -> F {
-        Self::distance(*origin, *destination)
+    fn distance(&self, origin: &Point<F>, destination: &Point<F>) -> F {
+        self.distance(*origin, *destination)
     }
 }
 
 impl<F: CoordFloat> Distance<F, &Point<F>, &Line<F>> for Euclidean {
-    fn distance(origin: &Point<F>, destination: &Line<F>) -> F {
+    fn distance(&self, origin: &Point<F>, destination: &Line<F>) -> F {
         geo_types::private_utils::point_line_euclidean_distance(*origin, *destination)
     }
 }
 
 impl<F: CoordFloat> Distance<F, &Point<F>, &LineString<F>> for Euclidean {
-    fn distance(origin: &Point<F>, destination: &LineString<F>) -> F {
-        geo_types::private_utils::point_line_string_euclidean_distance(*origin, destination)
+    fn distance(&self, origin: &Point<F>, destination: &LineString<F>) -> F {
+        match destination.0.as_slice() {
+            [] => F::infinity(),
+            // a line string of a single coordinate has no segments
+            [coord] => self.distance(origin.0, *coord),
+            _ => {
+                geo_types::private_utils::point_line_string_euclidean_distance(*origin, destination)
+            }
+        }
     }
 }
 
 impl<F: GeoFloat> Distance<F, &Point<F>, &Polygon<F>> for Euclidean {
-    fn distance(point: &Point<F>, polygon: &Polygon<F>) -> F {
-        // No need to continue if the polygon intersects the point, or is zero-length
-        if polygon.exterior().0.is_empty() || polygon.intersects(point) {
+    fn distance(&self, point: &Point<F>, polygon: &Polygon<F>) -> F {
+        if polygon.exterior().0.is_empty() {
+            return F::infinity();
+        }
+        // No need to continue if the polygon intersects the point
+        if polygon.intersects(point) {
             return F::zero();
         }
         // fold the minimum interior ring distance if any, followed by the exterior
@@ -104,8 +118,8 @@ impl<F: GeoFloat> Distance<F, &Point<F>, &Polygon<F>> for Euclidean {
         polygon
             .interiors()
             .iter()
-            .map(|ring| Self::distance(point, ring))
-            .fold(Bounded::max_value(), |accum: F, val| accum.min(val))
+            .map(|ring| self.distance(point, ring))
+            .fold(F::infinity(), |accum: F, val| accum.min(val))
             .min(
                 polygon
                     .exterior()
@@ -115,7 +129,7 @@ impl<F: GeoFloat> Distance<F, &Point<F>, &Polygon<F>> for Euclidean {
                             point.0, line.start, line.end,
                         )
                     })
-                    .fold(Bounded::max_value(), |accum, val| accum.min(val)),
+                    .fold(F::infinity(), |accum, val| accum.min(val)),
             )
     }
 }
@@ -128,30 +142,42 @@ symmetric_distance_impl!(CoordFloat, &Line<F>, Coord<F>);
 symmetric_distance_impl!(CoordFloat, &Line<F>, &Point<F>);
 
 impl<F: GeoFloat> Distance<F, &Line<F>, &Line<F>> for Euclidean {
-    fn distance(line_a: &Line<F>, line_b: &Line<F>) -> F {
+    fn distance(&self, line_a: &Line<F>, line_b: &Line<F>) -> F {
         if line_a.intersects(line_b) {
             return F::zero();
         }
         // minimum of all Point-Line distances
-        Self::distance(&line_a.start_point(), line_b)
-            .min(Self::distance(&line_a.end_point(), line_b))
-            .min(Self::distance(&line_b.start_point(), line_a))
-            .min(Self::distance(&line_b.end_point(), line_a))
+        self.distance(&line_a.start_point(), line_b)
+            .min(self.distance(&line_a.end_point(), line_b))
+            .min(self.distance(&line_b.start_point(), line_a))
+            .min(self.distance(&line_b.end_point(), line_a))
     }
 }
 
 impl<F: GeoFloat> Distance<F, &Line<F>, &LineString<F>> for Euclidean {
-    fn distance(line: &Line<F>, line_string: &LineString<F>) -> F {
-        line_string
-            .lines()
-            .fold(Bounded::max_value(), |acc, segment| {
-                acc.min(Self::distance(line, &segment))
+    fn distance(&self, line: &Line<F>, line_string: &LineString<F>) -> F {
+        segments(line_string)
+            .into_iter()
+            .fold(F::infinity(), |acc, segment| {
+                acc.min(self.distance(line, &segment))
             })
     }
+
+    fn is_within_distance(
+        &self,
+        line: &Line<F>,
+        line_string: &LineString<F>,
+        max_distance: F,
+    ) -> bool {
+        self.is_within_distance(&LineString::from(*line), line_string, max_distance)
+    }
 }
 
 impl<F: GeoFloat> Distance<F, &Line<F>, &Polygon<F>> for Euclidean {
-    fn distance(line: &Line<F>, polygon: &Polygon<F>) -> F {
+    fn distance(&self, line: &Line<F>, polygon: &Polygon<F>) -> F {
+        if polygon.exterior().0.is_empty() {
+            return F::infinity();
+        }
         if line.intersects(polygon) {
             return F::zero();
         }
@@ -159,10 +185,14 @@ impl<F: GeoFloat> Distance<F, &Line<F>, &Polygon<F>> for Euclidean {
         // REVIEW: This impl changed slightly.
         std::iter::once(polygon.exterior())
             .chain(polygon.interiors().iter())
-            .fold(Bounded::max_value(), |acc, line_string| {
-                acc.min(Self::distance(line, line_string))
+            .fold(F::infinity(), |acc, line_string| {
+                acc.min(self.distance(line, line_string))
             })
     }
+
+    fn is_within_distance(&self, line: &Line<F>, polygon: &Polygon<F>, max_distance: F) -> bool {
+        self.is_within_distance(&LineString::from(*line), polygon, max_distance)
+    }
 }
 
 // ┌────────────────────────────────┐
@@ -173,25 +203,37 @@ symmetric_distance_impl!(CoordFloat, &LineString<F>, &Point<F>);
 symmetric_distance_impl!(GeoFloat, &LineString<F>, &Line<F>);
 
 impl<F: GeoFloat> Distance<F, &LineString<F>, &LineString<F>> for Euclidean {
-    fn distance(line_string_a: &LineString<F>, line_string_b: &LineString<F>) -> F {
-        if line_string_a.intersects(line_string_b) {
+    fn distance(&self, line_string_a: &LineString<F>, line_string_b: &LineString<F>) -> F {
+        if line_string_a.0.is_empty() || line_string_b.0.is_empty() {
+            F::infinity()
+        } else if line_strings_intersect(line_string_a, line_string_b) {
             F::zero()
         } else {
             nearest_neighbour_distance(line_string_a, line_string_b)
         }
     }
+
+    fn is_within_distance(
+        &self,
+        line_string_a: &LineString<F>,
+        line_string_b: &LineString<F>,
+        max_distance: F,
+    ) -> bool {
+        rings_within_distance(&[line_string_a], &[line_string_b], max_distance)
+    }
 }
 
 impl<F: GeoFloat> Distance<F, &LineString<F>, &Polygon<F>> for Euclidean {
-    fn distance(line_string: &LineString<F>, polygon: &Polygon<F>) -> F {
-        if line_string.intersects(polygon) {
+    fn distance(&self, line_string: &LineString<F>, polygon: &Polygon<F>) -> F {
+        if line_string.0.is_empty() || polygon.exterior().0.is_empty() {
+            F::infinity()
+        } else if polygon.intersects(line_string) {
             F::zero()
         } else if !polygon.interiors().is_empty()
-            // FIXME: Explodes on empty line_string
             && ring_contains_coord(polygon.exterior(), line_string.0[0])
         {
             // check each ring distance, returning the minimum
-            let mut mindist: F = Float::max_value();
+            let mut mindist: F = F::infinity();
             for ring in polygon.interiors() {
                 mindist = mindist.min(nearest_neighbour_distance(line_string, ring))
             }
@@ -200,6 +242,20 @@ impl<F: GeoFloat> Distance<F, &LineString<F>, &Polygon<F>> for Euclidean {
             nearest_neighbour_distance(line_string, polygon.exterior())
         }
     }
+
+    fn is_within_distance(
+        &self,
+        line_string: &LineString<F>,
+        polygon: &Polygon<F>,
+        max_distance: F,
+    ) -> bool {
+        if line_string.0.is_empty() || polygon.exterior().0.is_empty() {
+            return false;
+        }
+        rings_within_distance(&[line_string], &rings(polygon), max_distance)
+            // the line string may be inside the polygon, far from its rings
+            || polygon.intersects(line_string)
+    }
 }
 
 // ┌─────────────────────────────┐
@@ -210,26 +266,27 @@ symmetric_distance_impl!(GeoFloat, &Polygon<F>, &Point<F>);
 symmetric_distance_impl!(GeoFloat, &Polygon<F>, &Line<F>);
 symmetric_distance_impl!(GeoFloat, &Polygon<F>, &LineString<F>);
 impl<F: GeoFloat> Distance<F, &Polygon<F>, &Polygon<F>> for Euclidean {
-    fn distance(polygon_a: &Polygon<F>, polygon_b: &Polygon<F>) -> F {
+    fn distance(&self, polygon_a: &Polygon<F>, polygon_b: &Polygon<F>) -> F {
+        if polygon_a.exterior().0.is_empty() || polygon_b.exterior().0.is_empty() {
+            return F::infinity();
+        }
         if polygon_a.intersects(polygon_b) {
             return F::zero();
         }
-        // FIXME: explodes when polygon_b.exterior() is empty
         // Containment check
         if !polygon_a.interiors().is_empty()
             && ring_contains_coord(polygon_a.exterior(), polygon_b.exterior().0[0])
         {
             // check each ring distance, returning the minimum
-            let mut mindist: F = Float::max_value();
+            let mut mindist: F = F::infinity();
             for ring in polygon_a.interiors() {
                 mindist = mindist.min(nearest_neighbour_distance(polygon_b.exterior(), ring))
             }
             return mindist;
         } else if !polygon_b.interiors().is_empty()
-            // FIXME: explodes when polygon_a.exterior() is empty
             && ring_contains_coord(polygon_b.exterior(), polygon_a.exterior().0[0])
         {
-            let mut mindist: F = Float::max_value();
+            let mut mindist: F = F::infinity();
             for ring in polygon_b.interiors() {
                 mindist = mindist.min(nearest_neighbour_distance(polygon_a.exterior(), ring))
             }
@@ -237,6 +294,20 @@ impl<F: GeoFloat> Distance<F, &Polygon<F>, &Polygon<F>> for Euclidean {
         }
         nearest_neighbour_distance(polygon_a.exterior(), polygon_b.exterior())
     }
+
+    fn is_within_distance(
+        &self,
+        polygon_a: &Polygon<F>,
+        polygon_b: &Polygon<F>,
+        max_distance: F,
+    ) -> bool {
+        if polygon_a.exterior().0.is_empty() || polygon_b.exterior().0.is_empty() {
+            return false;
+        }
+        rings_within_distance(&rings(polygon_a), &rings(polygon_b), max_distance)
+            // either polygon may be inside the other, far from its rings
+            || polygon_a.intersects(polygon_b)
+    }
 }
 
 // ┌────────────────────────────────────────┐
@@ -248,15 +319,23 @@ macro_rules! impl_euclidean_distance_for_polygonlike_geometry {
   ($polygonlike:ty,  [$($geometry_b:ty),*]) => {
       impl<F: GeoFloat> Distance<F, $polygonlike, $polygonlike> for Euclidean
       {
-          fn distance(origin: $polygonlike, destination: $polygonlike) -> F {
-              Self::distance(&origin.to_polygon(), destination)
+          fn distance(&self, origin: $polygonlike, destination: $polygonlike) -> F {
+              self.distance(&origin.to_polygon(), destination)
+          }
+
+          fn is_within_distance(&self, origin: $polygonlike, destination: $polygonlike, max_distance: F) -> bool {
+              self.is_within_distance(&origin.to_polygon(), destination, max_distance)
           }
       }
       $(
           impl<F: GeoFloat> Distance<F, $polygonlike, $geometry_b> for Euclidean
           {
-              fn distance(polygonlike: $polygonlike, geometry_b: $geometry_b) -> F {
-                    Self::distance(&polygonlike.to_polygon(), geometry_b)
+              fn distance(&self, polygonlike: $polygonlike, geometry_b: $geometry_b) -> F {
+                    self.distance(&polygonlike.to_polygon(), geometry_b)
+              }
+
+              fn is_within_distance(&self, polygonlike: $polygonlike, geometry_b: $geometry_b, max_distance: F) -> bool {
+                    self.is_within_distance(&polygonlike.to_polygon(), geometry_b, max_distance)
               }
           }
           symmetric_distance_impl!(GeoFloat, $geometry_b, $polygonlike);
@@ -275,23 +354,35 @@ impl_euclidean_distance_for_polygonlike_geometry!(&Rect<F>,      [&Point<F>, &Li
 macro_rules! impl_euclidean_distance_for_iter_geometry {
     ($iter_geometry:ty,  [$($to_geometry:ty),*]) => {
         impl<F: GeoFloat> Distance<F, $iter_geometry, $iter_geometry> for Euclidean {
-            fn distance(origin: $iter_geometry, destination: $iter_geometry) -> F {
+            fn distance(&self, origin: $iter_geometry, destination: $iter_geometry) -> F {
                 origin
                     .iter()
-                    .fold(Bounded::max_value(), |accum: F, member| {
-                        accum.min(Self::distance(member, destination))
+                    .fold(F::infinity(), |accum: F, member| {
+                        accum.min(self.distance(member, destination))
                     })
              }
+
+            fn is_within_distance(&self, origin: $iter_geometry, destination: $iter_geometry, max_distance: F) -> bool {
+                origin
+                    .iter()
+                    .any(|member| self.is_within_distance(member, destination, max_distance))
+            }
         }
         $(
             impl<F: GeoFloat> Distance<F, $iter_geometry, $to_geometry> for Euclidean {
-                fn distance(iter_geometry: $iter_geometry, to_geometry: $to_geometry) -> F {
+                fn distance(&self, iter_geometry: $iter_geometry, to_geometry: $to_geometry) -> F {
                     iter_geometry
                         .iter()
-                        .fold(Bounded::max_value(), |accum: F, member| {
-                            accum.min(Self::distance(member, to_geometry))
+                        .fold(F::infinity(), |accum: F, member| {
+                            accum.min(self.distance(member, to_geometry))
                         })
                 }
+
+                fn is_within_distance(&self, iter_geometry: $iter_geometry, to_geometry: $to_geometry, max_distance: F) -> bool {
+                    iter_geometry
+                        .iter()
+                        .any(|member| self.is_within_distance(member, to_geometry, max_distance))
+                }
             }
             symmetric_distance_impl!(GeoFloat, $to_geometry, $iter_geometry);
         )*
@@ -312,18 +403,33 @@ macro_rules! impl_euclidean_distance_for_geometry_and_variant {
   ([$($target:ty),*]) => {
       $(
           impl<F: GeoFloat> Distance<F, $target, &Geometry<F>> for Euclidean {
-              fn distance(origin: $target, destination: &Geometry<F>) -> F {
+              fn distance(&self, origin: $target, destination: &Geometry<F>) -> F {
                   match destination {
-                      Geometry::Point(point) => Self::distance(origin, point),
-                      Geometry::Line(line) => Self::distance(origin, line),
-                      Geometry::LineString(line_string) => Self::distance(origin, line_string),
-                      Geometry::Polygon(polygon) => Self::distance(origin, polygon),
-                      Geometry::MultiPoint(multi_point) => Self::distance(origin, multi_point),
-                      Geometry::MultiLineString(multi_line_string) => Self::distance(origin, multi_line_string),
-                      Geometry::MultiPolygon(multi_polygon) => Self::distance(origin, multi_polygon),
-                      Geometry::GeometryCollection(geometry_collection) => Self::distance(origin, geometry_collection),
-                      Geometry::Rect(rect) => Self::distance(origin, rect),
-                      Geometry::Triangle(triangle) => Self::distance(origin, triangle),
+                      Geometry::Point(point) => self.distance(origin, point),
+                      Geometry::Line(line) => self.distance(origin, line),
+                      Geometry::LineString(line_string) => self.distance(origin, line_string),
+                      Geometry::Polygon(polygon) => self.distance(origin, polygon),
+                      Geometry::MultiPoint(multi_point) => self.distance(origin, multi_point),
+                      Geometry::MultiLineString(multi_line_string) => self.distance(origin, multi_line_string),
+                      Geometry::MultiPolygon(multi_polygon) => self.distance(origin, multi_polygon),
+                      Geometry::GeometryCollection(geometry_collection) => self.distance(origin, geometry_collection),
+                      Geometry::Rect(rect) => self.distance(origin, rect),
+                      Geometry::Triangle(triangle) => self.distance(origin, triangle),
+                  }
+              }
+
+              fn is_within_distance(&self, origin: $target, destination: &Geometry<F>, max_distance: F) -> bool {
+                  match destination {
+                      Geometry::Point(point) => self.is_within_distance(origin, point, max_distance),
+                      Geometry::Line(line) => self.is_within_distance(origin, line, max_distance),
+                      Geometry::LineString(line_string) => self.is_within_distance(origin, line_string, max_distance),
+                      Geometry::Polygon(polygon) => self.is_within_distance(origin, polygon, max_distance),
+                      Geometry::MultiPoint(multi_point) => self.is_within_distance(origin, multi_point, max_distance),
+                      Geometry::MultiLineString(multi_line_string) => self.is_within_distance(origin, multi_line_string, max_distance),
+                      Geometry::MultiPolygon(multi_polygon) => self.is_within_distance(origin, multi_polygon, max_distance),
+                      Geometry::GeometryCollection(geometry_collection) => self.is_within_distance(origin, geometry_collection, max_distance),
+                      Geometry::Rect(rect) => self.is_within_distance(origin, rect, max_distance),
+                      Geometry::Triangle(triangle) => self.is_within_distance(origin, triangle, max_distance),
                   }
               }
           }
@@ -334,23 +440,62 @@ macro_rules! impl_euclidean_distance_for_geometry_and_variant {
 
 impl_euclidean_distance_for_geometry_and_variant!([&Point<F>, &MultiPoint<F>, &Line<F>, &LineString<F>, &MultiLineString<F>, &Polygon<F>, &MultiPolygon<F>, &Triangle<F>, &Rect<F>, &GeometryCollection<F>]);
 
+/// Euclidean distance between any two geometries, recursing into (possibly nested)
+/// `GeometryCollection`s.
+///
+/// Empty geometries, and empty members of collections, are infinitely far from everything, so
+/// the distance to an empty geometry is `F::infinity()`.
 impl<F: GeoFloat> Distance<F, &Geometry<F>, &Geometry<F>> for Euclidean {
-    fn distance(origin: &Geometry<F>, destination: &Geometry<F>) -> F {
+    fn distance(&self, origin: &Geometry<F>, destination: &Geometry<F>) -> F {
         match origin {
-            Geometry::Point(point) => Self::distance(point, destination),
-            Geometry::Line(line) => Self::distance(line, destination),
-            Geometry::LineString(line_string) => Self::distance(line_string, destination),
-            Geometry::Polygon(polygon) => Self::distance(polygon, destination),
-            Geometry::MultiPoint(multi_point) => Self::distance(multi_point, destination),
+            Geometry::Point(point) => self.distance(point, destination),
+            Geometry::Line(line) => self.distance(line, destination),
+            Geometry::LineString(line_string) => self.distance(line_string, destination),
+            Geometry::Polygon(polygon) => self.distance(polygon, destination),
+            Geometry::MultiPoint(multi_point) => self.distance(multi_point, destination),
             Geometry::MultiLineString(multi_line_string) => {
-                Self::distance(multi_line_string, destination)
+                self.distance(multi_line_string, destination)
             }
-            Geometry::MultiPolygon(multi_polygon) => Self::distance(multi_polygon, destination),
+            Geometry::MultiPolygon(multi_polygon) => self.distance(multi_polygon, destination),
             Geometry::GeometryCollection(geometry_collection) => {
-                Self::distance(geometry_collection, destination)
+                self.distance(geometry_collection, destination)
+            }
+            Geometry::Rect(rect) => self.distance(rect, destination),
+            Geometry::Triangle(triangle) => self.distance(triangle, destination),
+        }
+    }
+
+    fn is_within_distance(
+        &self,
+        origin: &Geometry<F>,
+        destination: &Geometry<F>,
+        max_distance: F,
+    ) -> bool {
+        match origin {
+            Geometry::Point(point) => self.is_within_distance(point, destination, max_distance),
+            Geometry::Line(line) => self.is_within_distance(line, destination, max_distance),
+            Geometry::LineString(line_string) => {
+                self.is_within_distance(line_string, destination, max_distance)
+            }
+            Geometry::Polygon(polygon) => {
+                self.is_within_distance(polygon, destination, max_distance)
+            }
+            Geometry::MultiPoint(multi_point) => {
+                self.is_within_distance(multi_point, destination, max_distance)
+            }
+            Geometry::MultiLineString(multi_line_string) => {
+                self.is_within_distance(multi_line_string, destination, max_distance)
+            }
+            Geometry::MultiPolygon(multi_polygon) => {
+                self.is_within_distance(multi_polygon, destination, max_distance)
+            }
+            Geometry::GeometryCollection(geometry_collection) => {
+                self.is_within_distance(geometry_collection, destination, max_distance)
+            }
+            Geometry::Rect(rect) => self.is_within_distance(rect, destination, max_distance),
+            Geometry::Triangle(triangle) => {
+                self.is_within_distance(triangle, destination, max_distance)
             }
-            Geometry::Rect(rect) => Self::distance(rect, destination),
-            Geometry::Triangle(triangle) => Self::distance(triangle, destination),
         }
     }
 }
@@ -362,21 +507,95 @@ impl<F: GeoFloat> Distance<F, &Geometry<F>, &Geometry<F>> for Euclidean {
 /// Uses an R* tree and nearest-neighbour lookups to calculate minimum distances
 // This is somewhat slow and memory-inefficient, but certainly better than quadratic time
 fn nearest_neighbour_distance<F: GeoFloat>(geom1: &LineString<F>, geom2: &LineString<F>) -> F {
-    let tree_a = RTree::bulk_load(geom1.lines().map(CachedEnvelope::new).collect());
-    let tree_b = RTree::bulk_load(geom2.lines().map(CachedEnvelope::new).collect());
+    let tree_a = RTree::bulk_load(
+        segments(geom1)
+            .into_iter()
+            .map(CachedEnvelope::new)
+            .collect(),
+    );
+    let tree_b = RTree::bulk_load(
+        segments(geom2)
+            .into_iter()
+            .map(CachedEnvelope::new)
+            .collect(),
+    );
     // Return minimum distance between all geom a points and geom b lines, and all geom b points and geom a lines
     geom2
         .points()
-        .fold(Bounded::max_value(), |acc: F, point| {
+        .fold(F::infinity(), |acc: F, point| {
             let nearest = tree_a.nearest_neighbor(&point).unwrap();
-            acc.min(Euclidean::distance(nearest as &Line<F>, &point))
+            acc.min(Euclidean.distance(nearest as &Line<F>, &point))
         })
-        .min(geom1.points().fold(Bounded::max_value(), |acc, point| {
+        .min(geom1.points().fold(F::infinity(), |acc, point| {
             let nearest = tree_b.nearest_neighbor(&point).unwrap();
-            acc.min(Euclidean::distance(nearest as &Line<F>, &point))
+            acc.min(Euclidean.distance(nearest as &Line<F>, &point))
         }))
 }
 
+/// Whether two line strings intersect, using monotone chains for long line strings.
+fn line_strings_intersect<F: GeoFloat>(a: &LineString<F>, b: &LineString<F>) -> bool {
+    if a.0.len() < MIN_CHAINED_COORDS && b.0.len() < MIN_CHAINED_COORDS {
+        return a.intersects(b);
+    }
+    MonotoneChains::new(a).intersects(&MonotoneChains::new(b))
+        // a line string of a single point has no segments
+        || (a.0.len() == 1 && b.intersects(&a.0[0]))
+        || (b.0.len() == 1 && a.intersects(&b.0[0]))
+}
+
+fn rings<F: GeoFloat>(polygon: &Polygon<F>) -> Vec<&LineString<F>> {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .collect()
+}
+
+/// Whether any segment of `a` is within `max_distance` of any segment of `b`, stopping at the
+/// first such pair. A line string of a single coordinate is a single degenerate segment.
+fn rings_within_distance<F: GeoFloat>(
+    a: &[&LineString<F>],
+    b: &[&LineString<F>],
+    max_distance: F,
+) -> bool {
+    let envelopes_within_distance = |a: Option<Rect<F>>, b: Option<Rect<F>>| match (a, b) {
+        (Some(a), Some(b)) => {
+            let dx = (a.min().x - b.max().x).max(b.min().x - a.max().x);
+            let dy = (a.min().y - b.max().y).max(b.min().y - a.max().y);
+            dx.max(F::zero()).hypot(dy.max(F::zero())) <= max_distance
+        }
+        _ => false,
+    };
+    let bounding_rect = |rings: &[&LineString<F>]| {
+        rings
+            .iter()
+            .filter_map(|ring| ring.bounding_rect())
+            .reduce(crate::bounding_rect::bounding_rect_merge)
+    };
+    if !envelopes_within_distance(bounding_rect(a), bounding_rect(b)) {
+        return false;
+    }
+
+    let tree = RTree::bulk_load(
+        b.iter()
+            .flat_map(|ring| segments(ring))
+            .map(CachedEnvelope::new)
+            .collect(),
+    );
+    let margin = Point::new(max_distance, max_distance);
+    a.iter().flat_map(|ring| segments(ring)).any(|segment| {
+        let envelope = segment.envelope();
+        let envelope = AABB::from_corners(envelope.lower() - margin, envelope.upper() + margin);
+        tree.locate_in_envelope_intersecting(&envelope)
+            .any(|other| Euclidean.distance(&segment, other as &Line<F>) <= max_distance)
+    })
+}
+
+fn segments<F: GeoFloat>(line_string: &LineString<F>) -> Vec<Line<F>> {
+    match line_string.0.as_slice() {
+        [coord] => vec![Line::new(*coord, *coord)],
+        _ => line_string.lines().collect(),
+    }
+}
+
 fn ring_contains_coord<T: GeoNum>(ring: &LineString<T>, c: Coord<T>) -> bool {
     match coord_pos_relative_to_ring(c, ring) {
         CoordPos::Inside => true,
@@ -433,7 +652,7 @@ mod test {
         let poly = Polygon::new(ls, vec![]);
         // A Random point outside the octagon
         let p = Point::new(2.5, 0.5);
-        let dist = Euclidean::distance(&p, &poly);
+        let dist = Euclidean.distance(&p, &poly);
         assert_relative_eq!(dist, 2.1213203435596424);
     }
     #[test]
@@ -455,7 +674,7 @@ mod test {
         let poly = Polygon::new(ls, vec![]);
         // A Random point inside the octagon
         let p = Point::new(5.5, 2.1);
-        let dist = Euclidean::distance(&p, &poly);
+        let dist = Euclidean.distance(&p, &poly);
         assert_relative_eq!(dist, 0.0);
     }
     #[test]
@@ -477,7 +696,7 @@ mod test {
         let poly = Polygon::new(ls, vec![]);
         // A point on the octagon
         let p = Point::new(5.0, 1.0);
-        let dist = Euclidean::distance(&p, &poly);
+        let dist = Euclidean.distance(&p, &poly);
         assert_relative_eq!(dist, 0.0);
     }
     #[test]
@@ -493,7 +712,7 @@ mod test {
 
         let poly = Polygon::new(exterior, vec![]);
         let bugged_point = Point::new(0.0001, 0.);
-        assert_relative_eq!(Euclidean::distance(&poly, &bugged_point), 0.);
+        assert_relative_eq!(Euclidean.distance(&poly, &bugged_point), 0.);
     }
     #[test]
     // Point to Polygon, empty Polygon
@@ -504,8 +723,8 @@ mod test {
         let poly = Polygon::new(ls, vec![]);
         // A point on the octagon
         let p = Point::new(2.5, 0.5);
-        let dist = Euclidean::distance(&p, &poly);
-        assert_relative_eq!(dist, 0.0);
+        let dist = Euclidean.distance(&p, &poly);
+        assert_eq!(dist, f64::INFINITY);
     }
     #[test]
     // Point to Polygon with an interior ring
@@ -529,7 +748,7 @@ mod test {
         let poly = Polygon::new(ls_ext, vec![ls_int]);
         // A point inside the cutout triangle
         let p = Point::new(3.5, 2.5);
-        let dist = Euclidean::distance(&p, &poly);
+        let dist = Euclidean.distance(&p, &poly);
 
         // 0.41036467732879783 <-- Shapely
         assert_relative_eq!(dist, 0.41036467732879767);
@@ -569,8 +788,8 @@ mod test {
         let pnt1 = Point::new(0.0, 15.0);
         let pnt2 = Point::new(10.0, 20.0);
         let ln = Line::new(pnt1.0, pnt2.0);
-        let dist_mp_ln = Euclidean::distance(&ln, &mp);
-        let dist_pol1_ln = Euclidean::distance(&ln, &pol1);
+        let dist_mp_ln = Euclidean.distance(&ln, &mp);
+        let dist_pol1_ln = Euclidean.distance(&ln, &pol1);
         assert_relative_eq!(dist_mp_ln, dist_pol1_ln);
     }
 
@@ -582,7 +801,7 @@ mod test {
         let p2 = Polygon::new(ls2, vec![]);
         let mp = MultiPolygon::new(vec![p1, p2]);
         let p = Point::new(50.0, 50.0);
-        assert_relative_eq!(Euclidean::distance(&p, &mp), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&p, &mp), 60.959002616512684);
     }
     #[test]
     // Point to LineString
@@ -601,7 +820,7 @@ mod test {
         let ls = LineString::from(points);
         // A Random point "inside" the LineString
         let p = Point::new(5.5, 2.1);
-        let dist = Euclidean::distance(&p, &ls);
+        let dist = Euclidean.distance(&p, &ls);
         assert_relative_eq!(dist, 1.1313708498984762);
     }
     #[test]
@@ -621,7 +840,7 @@ mod test {
         let ls = LineString::from(points);
         // A point which lies on the LineString
         let p = Point::new(5.0, 4.0);
-        let dist = Euclidean::distance(&p, &ls);
+        let dist = Euclidean.distance(&p, &ls);
         assert_relative_eq!(dist, 0.0);
     }
     #[test]
@@ -630,7 +849,7 @@ mod test {
         let points = vec![(3.5, 3.5), (4.4, 2.0), (2.6, 2.0), (3.5, 3.5)];
         let ls = LineString::from(points);
         let p = Point::new(3.5, 2.5);
-        let dist = Euclidean::distance(&p, &ls);
+        let dist = Euclidean.distance(&p, &ls);
         assert_relative_eq!(dist, 0.5);
     }
     #[test]
@@ -639,8 +858,8 @@ mod test {
         let points = vec![];
         let ls = LineString::new(points);
         let p = Point::new(5.0, 4.0);
-        let dist = Euclidean::distance(&p, &ls);
-        assert_relative_eq!(dist, 0.0);
+        let dist = Euclidean.distance(&p, &ls);
+        assert_eq!(dist, f64::INFINITY);
     }
     #[test]
     fn distance_multilinestring_test() {
@@ -648,19 +867,18 @@ mod test {
         let v2 = LineString::from(vec![(1.0, 10.0), (2.0, 0.0), (3.0, 1.0)]);
         let mls = MultiLineString::new(vec![v1, v2]);
         let p = Point::new(50.0, 50.0);
-        assert_relative_eq!(Euclidean::distance(&p, &mls), 63.25345840347388);
+        assert_relative_eq!(Euclidean.distance(&p, &mls), 63.25345840347388);
     }
     #[test]
     fn distance1_test() {
         assert_relative_eq!(
-            Euclidean::distance(&Point::new(0., 0.), &Point::new(1., 0.)),
+            Euclidean.distance(&Point::new(0., 0.), &Point::new(1., 0.)),
             1.
         );
     }
     #[test]
     fn distance2_test() {
-        let dist =
-            Euclidean::distance(&Point::new(-72.1235, 42.3521), &Point::new(72.1260, 70.612));
+        let dist = Euclidean.distance(&Point::new(-72.1235, 42.3521), &Point::new(72.1260, 70.612));
         assert_relative_eq!(dist, 146.99163308930207);
     }
     #[test]
@@ -678,7 +896,7 @@ mod test {
         ];
         let mp = MultiPoint::new(v);
         let p = Point::new(50.0, 50.0);
-        assert_relative_eq!(Euclidean::distance(&p, &mp), 64.03124237432849)
+        assert_relative_eq!(Euclidean.distance(&p, &mp), 64.03124237432849)
     }
     #[test]
     fn distance_line_test() {
@@ -686,21 +904,21 @@ mod test {
         let p0 = Point::new(2., 3.);
         let p1 = Point::new(3., 0.);
         let p2 = Point::new(6., 0.);
-        assert_relative_eq!(Euclidean::distance(&line0, &p0), 3.);
-        assert_relative_eq!(Euclidean::distance(&p0, &line0), 3.);
+        assert_relative_eq!(Euclidean.distance(&line0, &p0), 3.);
+        assert_relative_eq!(Euclidean.distance(&p0, &line0), 3.);
 
-        assert_relative_eq!(Euclidean::distance(&line0, &p1), 0.);
-        assert_relative_eq!(Euclidean::distance(&p1, &line0), 0.);
+        assert_relative_eq!(Euclidean.distance(&line0, &p1), 0.);
+        assert_relative_eq!(Euclidean.distance(&p1, &line0), 0.);
 
-        assert_relative_eq!(Euclidean::distance(&line0, &p2), 1.);
-        assert_relative_eq!(Euclidean::distance(&p2, &line0), 1.);
+        assert_relative_eq!(Euclidean.distance(&line0, &p2), 1.);
+        assert_relative_eq!(Euclidean.distance(&p2, &line0), 1.);
     }
     #[test]
     fn distance_line_line_test() {
         let line0 = Line::from([(0., 0.), (5., 0.)]);
         let line1 = Line::from([(2., 1.), (7., 2.)]);
-        assert_relative_eq!(Euclidean::distance(&line0, &line1), 1.);
-        assert_relative_eq!(Euclidean::distance(&line1, &line0), 1.);
+        assert_relative_eq!(Euclidean.distance(&line0, &line1), 1.);
+        assert_relative_eq!(Euclidean.distance(&line1, &line0), 1.);
     }
     #[test]
     // See https://github.com/georust/geo/issues/476
@@ -733,7 +951,7 @@ mod test {
                 y: -0.15433610862574643,
             },
         ];
-        assert_eq!(Euclidean::distance(&line, &poly), 0.18752558079168907);
+        assert_eq!(Euclidean.distance(&line, &poly), 0.18752558079168907);
     }
     #[test]
     // test edge-vertex minimum distance
@@ -844,7 +1062,7 @@ mod test {
             (4.921875, 66.33750501996518),
         ];
         let poly2 = Polygon::new(vec2.into(), vec![]);
-        let distance = Euclidean::distance(&poly1, &poly2);
+        let distance = Euclidean.distance(&poly1, &poly2);
         // GEOS says 2.2864896295566055
         assert_relative_eq!(distance, 2.2864896295566055);
     }
@@ -858,14 +1076,14 @@ mod test {
         // inside is "inside" outside's ring, but they are disjoint
         let outside = Polygon::new(shell, vec![ring]);
         let inside = Polygon::new(poly_in_ring, vec![]);
-        assert_relative_eq!(Euclidean::distance(&outside, &inside), 5.992772737231033);
+        assert_relative_eq!(Euclidean.distance(&outside, &inside), 5.992772737231033);
     }
     #[test]
     // two ring LineStrings; one encloses the other but they neither touch nor intersect
     fn test_linestring_distance() {
         let ring = geo_test_fixtures::ring::<f64>();
         let poly_in_ring = geo_test_fixtures::poly_in_ring::<f64>();
-        assert_relative_eq!(Euclidean::distance(&ring, &poly_in_ring), 5.992772737231033);
+        assert_relative_eq!(Euclidean.distance(&ring, &poly_in_ring), 5.992772737231033);
     }
     #[test]
     // Line-Polygon test: closest point on Polygon is NOT nearest to a Line end-point
@@ -873,7 +1091,7 @@ mod test {
         let line = Line::from([(0.0, 0.0), (0.0, 3.0)]);
         let v = vec![(5.0, 1.0), (5.0, 2.0), (0.25, 1.5), (5.0, 1.0)];
         let poly = Polygon::new(v.into(), vec![]);
-        assert_relative_eq!(Euclidean::distance(&line, &poly), 0.25);
+        assert_relative_eq!(Euclidean.distance(&line, &poly), 0.25);
     }
     #[test]
     // Line-Polygon test: Line intersects Polygon
@@ -881,7 +1099,7 @@ mod test {
         let line = Line::from([(0.5, 0.0), (0.0, 3.0)]);
         let v = vec![(5.0, 1.0), (5.0, 2.0), (0.25, 1.5), (5.0, 1.0)];
         let poly = Polygon::new(v.into(), vec![]);
-        assert_relative_eq!(Euclidean::distance(&line, &poly), 0.0);
+        assert_relative_eq!(Euclidean.distance(&line, &poly), 0.0);
     }
     #[test]
     // Line-Polygon test: Line contained by interior ring
@@ -890,14 +1108,14 @@ mod test {
         let v = vec![(5.0, 1.0), (5.0, 2.0), (0.25, 1.0), (5.0, 1.0)];
         let v2 = vec![(4.5, 1.2), (4.5, 1.8), (3.5, 1.2), (4.5, 1.2)];
         let poly = Polygon::new(v.into(), vec![v2.into()]);
-        assert_relative_eq!(Euclidean::distance(&line, &poly), 0.04999999999999982);
+        assert_relative_eq!(Euclidean.distance(&line, &poly), 0.04999999999999982);
     }
     #[test]
     // LineString-Line test
     fn test_linestring_line_distance() {
         let line = Line::from([(0.0, 0.0), (0.0, 2.0)]);
         let ls: LineString<_> = vec![(3.0, 0.0), (1.0, 1.0), (3.0, 2.0)].into();
-        assert_relative_eq!(Euclidean::distance(&ls, &line), 1.0);
+        assert_relative_eq!(Euclidean.distance(&ls, &line), 1.0);
     }
 
     #[test]
@@ -905,7 +1123,7 @@ mod test {
     fn test_triangle_point_on_vertex_distance() {
         let triangle = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
         let point = Point::new(0.0, 0.0);
-        assert_relative_eq!(Euclidean::distance(&triangle, &point), 0.0);
+        assert_relative_eq!(Euclidean.distance(&triangle, &point), 0.0);
     }
 
     #[test]
@@ -913,7 +1131,7 @@ mod test {
     fn test_triangle_point_on_edge_distance() {
         let triangle = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
         let point = Point::new(1.5, 0.0);
-        assert_relative_eq!(Euclidean::distance(&triangle, &point), 0.0);
+        assert_relative_eq!(Euclidean.distance(&triangle, &point), 0.0);
     }
 
     #[test]
@@ -921,7 +1139,7 @@ mod test {
     fn test_triangle_point_distance() {
         let triangle = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
         let point = Point::new(2.0, 3.0);
-        assert_relative_eq!(Euclidean::distance(&triangle, &point), 1.0);
+        assert_relative_eq!(Euclidean.distance(&triangle, &point), 1.0);
     }
 
     #[test]
@@ -929,7 +1147,7 @@ mod test {
     fn test_triangle_point_inside_distance() {
         let triangle = Triangle::from([(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)]);
         let point = Point::new(1.0, 0.5);
-        assert_relative_eq!(Euclidean::distance(&triangle, &point), 0.0);
+        assert_relative_eq!(Euclidean.distance(&triangle, &point), 0.0);
     }
 
     #[test]
@@ -954,7 +1172,7 @@ mod test {
         let second_polygon = Polygon::new(ls2, vec![]);
 
         assert_relative_eq!(
-            Euclidean::distance(&first_polygon, &second_polygon),
+            Euclidean.distance(&first_polygon, &second_polygon),
             224.35357967013238
         );
     }
@@ -987,10 +1205,10 @@ mod test {
             (x: 50_f64, y: 200_f64),
         )
         .orient(Direction::Reversed);
-        assert_eq!(Euclidean::distance(&p1, &p2), 50.0f64);
-        assert_eq!(Euclidean::distance(&p3, &p4), 50.0f64);
-        assert_eq!(Euclidean::distance(&p1, &p4), 50.0f64);
-        assert_eq!(Euclidean::distance(&p2, &p3), 50.0f64);
+        assert_eq!(Euclidean.distance(&p1, &p2), 50.0f64);
+        assert_eq!(Euclidean.distance(&p3, &p4), 50.0f64);
+        assert_eq!(Euclidean.distance(&p1, &p4), 50.0f64);
+        assert_eq!(Euclidean.distance(&p2, &p3), 50.0f64);
     }
     #[test]
     fn all_types_geometry_collection_test() {
@@ -1040,22 +1258,22 @@ mod test {
         ]);
 
         let test_p = Point::new(50., 50.);
-        assert_relative_eq!(Euclidean::distance(&test_p, &gc), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&test_p, &gc), 60.959002616512684);
 
         let test_multipoint = MultiPoint::new(vec![test_p]);
         assert_relative_eq!(
-            Euclidean::distance(&test_multipoint, &gc),
+            Euclidean.distance(&test_multipoint, &gc),
             60.959002616512684
         );
 
         let test_line = Line::from([(50., 50.), (60., 60.)]);
-        assert_relative_eq!(Euclidean::distance(&test_line, &gc), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&test_line, &gc), 60.959002616512684);
 
         let test_ls = LineString::from(vec![(50., 50.), (60., 60.), (70., 70.)]);
-        assert_relative_eq!(Euclidean::distance(&test_ls, &gc), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&test_ls, &gc), 60.959002616512684);
 
         let test_mls = MultiLineString::new(vec![test_ls]);
-        assert_relative_eq!(Euclidean::distance(&test_mls, &gc), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&test_mls, &gc), 60.959002616512684);
 
         let test_poly = Polygon::new(
             LineString::from(vec![
@@ -1067,21 +1285,156 @@ mod test {
             ]),
             vec![],
         );
-        assert_relative_eq!(Euclidean::distance(&test_poly, &gc), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&test_poly, &gc), 60.959002616512684);
 
         let test_multipoly = MultiPolygon::new(vec![test_poly]);
-        assert_relative_eq!(
-            Euclidean::distance(&test_multipoly, &gc),
-            60.959002616512684
-        );
+        assert_relative_eq!(Euclidean.distance(&test_multipoly, &gc), 60.959002616512684);
 
         let test_tri = Triangle::from([(50., 50.), (60., 50.), (55., 55.)]);
-        assert_relative_eq!(Euclidean::distance(&test_tri, &gc), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&test_tri, &gc), 60.959002616512684);
 
         let test_rect = Rect::new(coord! { x: 50., y: 50. }, coord! { x: 60., y: 60. });
-        assert_relative_eq!(Euclidean::distance(&test_rect, &gc), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&test_rect, &gc), 60.959002616512684);
 
         let test_gc = GeometryCollection(vec![Geometry::Rect(test_rect)]);
-        assert_relative_eq!(Euclidean::distance(&test_gc, &gc), 60.959002616512684);
+        assert_relative_eq!(Euclidean.distance(&test_gc, &gc), 60.959002616512684);
+    }
+
+    fn all_geometries() -> Vec<Geometry> {
+        vec![
+            Point::new(0.0, 0.0).into(),
+            Line::new((1.0, 1.0), (2.0, 2.0)).into(),
+            LineString::from(vec![(1.0, 1.0), (2.0, 2.0)]).into(),
+            LineString::from(vec![(-4.0, 2.0)]).into(),
+            polygon![(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0)].into(),
+            MultiPoint::from(vec![(3.0, 0.0), (4.0, 0.0)]).into(),
+            MultiLineString::new(vec![LineString::from(vec![(0.0, 3.0), (0.0, 4.0)])]).into(),
+            MultiPolygon::new(vec![
+                polygon![(x: 5.0, y: 5.0), (x: 6.0, y: 5.0), (x: 6.0, y: 6.0)],
+            ])
+            .into(),
+            Rect::new((1.0, -2.0), (2.0, -1.0)).into(),
+            Triangle::new((-2.0, 0.0).into(), (-1.0, 0.0).into(), (-1.0, 1.0).into()).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                Point::new(-3.0, -3.0).into(),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![Line::new(
+                    (0.0, -5.0),
+                    (1.0, -5.0),
+                )
+                .into()])),
+            ])),
+        ]
+    }
+
+    fn empty_geometries() -> Vec<Geometry> {
+        vec![
+            LineString::<f64>::new(vec![]).into(),
+            Polygon::<f64>::new(LineString::new(vec![]), vec![]).into(),
+            MultiPoint::<f64>::new(vec![]).into(),
+            MultiLineString::<f64>::new(vec![]).into(),
+            MultiPolygon::<f64>::new(vec![]).into(),
+            Geometry::GeometryCollection(GeometryCollection::<f64>::new_from(vec![])),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![])),
+            ])),
+        ]
+    }
+
+    #[test]
+    fn geometry_matrix_is_symmetric() {
+        for a in all_geometries() {
+            for b in all_geometries() {
+                let distance = Euclidean.distance(&a, &b);
+                assert!(distance.is_finite(), "{a:?} {b:?}");
+                assert_relative_eq!(distance, Euclidean.distance(&b, &a));
+            }
+        }
+    }
+
+    #[test]
+    fn nested_geometry_collections() {
+        let nested = GeometryCollection::new_from(vec![Geometry::GeometryCollection(
+            GeometryCollection::new_from(vec![
+                Point::new(3.0, 4.0).into(),
+                LineString::<f64>::new(vec![]).into(),
+            ]),
+        )]);
+        let origin = Point::new(0.0, 0.0);
+        assert_relative_eq!(Euclidean.distance(&origin, &nested), 5.0);
+        assert_relative_eq!(Euclidean.distance(&nested, &nested), 0.0);
+
+        let other = GeometryCollection::new_from(vec![Geometry::GeometryCollection(
+            GeometryCollection::new_from(vec![Rect::new((6.0, 4.0), (7.0, 5.0)).into()]),
+        )]);
+        assert_relative_eq!(Euclidean.distance(&nested, &other), 3.0);
+    }
+
+    #[test]
+    fn empty_geometries_are_infinitely_far() {
+        for empty in empty_geometries() {
+            for other in all_geometries().into_iter().chain(empty_geometries()) {
+                assert_eq!(
+                    Euclidean.distance(&empty, &other),
+                    f64::INFINITY,
+                    "{empty:?} {other:?}"
+                );
+                assert_eq!(
+                    Euclidean.distance(&other, &empty),
+                    f64::INFINITY,
+                    "{other:?} {empty:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_within_distance_matches_distance() {
+        let geometries: Vec<Geometry> = all_geometries()
+            .into_iter()
+            .chain(empty_geometries())
+            .chain([
+                // a polygon inside the hole of another, and one far inside it
+                polygon!(
+                    exterior: [(x: -10.0, y: -10.0), (x: 10.0, y: -10.0), (x: 10.0, y: 10.0), (x: -10.0, y: 10.0)],
+                    interiors: [[(x: 4.0, y: 4.0), (x: 8.0, y: 4.0), (x: 8.0, y: 8.0), (x: 4.0, y: 8.0)]],
+                )
+                .into(),
+                polygon![(x: 5.0, y: 5.0), (x: 7.0, y: 5.0), (x: 7.0, y: 7.0)].into(),
+                LineString::from(vec![(-1.0, -8.0), (1.0, -8.0)]).into(),
+            ])
+            .collect();
+        for a in &geometries {
+            for b in &geometries {
+                let distance = Euclidean.distance(a, b);
+                for max_distance in [0.0, 0.5, 1.0, 1.5, 2.0, 5.0, 20.0] {
+                    assert_eq!(
+                        Euclidean.is_within_distance(a, b, max_distance),
+                        distance <= max_distance,
+                        "{a:?} {b:?} {max_distance}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_within_distance_fixtures() {
+        let norway = geo_test_fixtures::norway_main::<f64>();
+        let polygon = Polygon::new(norway.clone(), vec![]);
+        // beside the original, so that the nearest segments are far along both
+        let width = norway.bounding_rect().unwrap().width();
+        let shifted: LineString = norway
+            .0
+            .iter()
+            .map(|coord| coord! { x: coord.x + width * 1.1, y: coord.y + 0.25 })
+            .collect();
+        let distance = Euclidean.distance(&norway, &shifted);
+        assert!(distance > 0.0);
+        assert!(Euclidean.is_within_distance(&norway, &shifted, distance));
+        assert!(!Euclidean.is_within_distance(&norway, &shifted, distance * 0.99));
+
+        let distance = Euclidean.distance(&polygon, &shifted);
+        assert!(Euclidean.is_within_distance(&polygon, &shifted, distance));
+        assert!(!Euclidean.is_within_distance(&shifted, &polygon, distance * 0.99));
     }
 }
@@ -15,11 +15,15 @@ use num_traits::FromPrimitive;
 /// using the [`Transform::transform`](crate::Transform::transform) / [`Transform::transform_crs_to_crs`](crate::Transform::transform_crs_to_crs) methods or their
 /// immutable variants. Use of these requires the proj feature
 ///
+/// The [`Distance`] between any two geometries is supported, including (nested)
+/// `GeometryCollection`s. The distance to an empty geometry is infinite.
+///
 /// [Euclidean plane]: https://en.wikipedia.org/wiki/Euclidean_plane
 /// [`Transform`]: crate::Transform
 /// [`Haversine`]: super::Haversine
 /// [`Geodesic`]: super::Geodesic
 /// [metric spaces]: super
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Euclidean;
 
 /// Interpolate Point(s) along a line on the [Euclidean plane].
@@ -39,6 +43,7 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Euclidean {
     /// [`Geodesic`]: crate::line_measures::Geodesic
     /// [metric spaces]: crate::line_measures::metric_spaces
     fn point_at_distance_between(
+        &self,
         start: Point<F>,
         end: Point<F>,
         distance_from_start: F,
@@ -61,7 +66,12 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Euclidean {
     /// [`Haversine`]: crate::line_measures::Haversine
     /// [`Geodesic`]: crate::line_measures::Geodesic
     /// [metric spaces]: crate::line_measures::metric_spaces
-    fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F> {
+    fn point_at_ratio_between(
+        &self,
+        start: Point<F>,
+        end: Point<F>,
+        ratio_from_start: F,
+    ) -> Point<F> {
         let diff = end - start;
         start + diff * ratio_from_start
     }
@@ -85,6 +95,7 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Euclidean {
     /// [`Geodesic`]: crate::line_measures::Geodesic
     /// [metric spaces]: crate::line_measures::metric_spaces
     fn points_along_line(
+        &self,
         start: Point<F>,
         end: Point<F>,
         max_distance: F,
@@ -94,7 +105,7 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Euclidean {
         if include_ends {
             container.push(start);
         }
-        densify_between::<F, Self>(start, end, &mut container, max_distance);
+        densify_between(self, start, end, &mut container, max_distance);
         if include_ends {
             container.push(end);
         }
@@ -106,8 +117,6 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Euclidean {
 mod tests {
     use super::*;
 
-    type MetricSpace = Euclidean;
-
     mod distance {
         use super::*;
 
@@ -117,7 +126,7 @@ mod tests {
             let new_york_city = Point::new(-8238310.24, 4942194.78);
             // web mercator
             let london = Point::new(-14226.63, 6678077.70);
-            let distance: f64 = MetricSpace::distance(new_york_city, london);
+            let distance: f64 = Euclidean.distance(new_york_city, london);
 
             assert_relative_eq!(
                 8_405_286., // meters in web mercator
@@ -130,14 +139,14 @@ mod tests {
             let new_york_city = Point::new(-8_238_310.24, 4_942_194.78);
             // web mercator
             let london = Point::new(-14_226.63, 6_678_077.70);
-            let start = MetricSpace::point_at_distance_between(new_york_city, london, 0.0);
+            let start = Euclidean.point_at_distance_between(new_york_city, london, 0.0);
             assert_relative_eq!(new_york_city, start);
 
             let midway =
-                MetricSpace::point_at_distance_between(new_york_city, london, 8_405_286.0 / 2.0);
+                Euclidean.point_at_distance_between(new_york_city, london, 8_405_286.0 / 2.0);
             assert_relative_eq!(Point::new(-4_126_268., 5_810_136.), midway, epsilon = 1.0);
 
-            let end = MetricSpace::point_at_distance_between(new_york_city, london, 8_405_286.0);
+            let end = Euclidean.point_at_distance_between(new_york_city, london, 8_405_286.0);
             assert_relative_eq!(london, end, epsilon = 1.0);
         }
     }
@@ -2,15 +2,134 @@ use super::super::{Bearing, Destination, Distance, InterpolatePoint};
 use crate::Point;
 use geographiclib_rs::{DirectGeodesic, InverseGeodesic};
 
-/// An ellipsoidal model of the earth, using methods given by [Karney (2013)].
+/// The size and shape of a reference ellipsoid, described by its semi-major axis and flattening.
+///
+/// Used to parameterize geodesic measures like [`GeodesicMeasure`] and
+/// [`GeodesicArea`](crate::GeodesicArea) for datums other than [WGS84](Ellipsoid::WGS84).
+///
+/// # Examples
+///
+/// ```
+/// use geo::Ellipsoid;
+///
+/// // Mars, per the IAU 2000 report
+/// let mars = Ellipsoid::new(3_396_190.0, 1.0 / 169.894_47);
+/// assert_eq!(mars.semi_major_axis(), 3_396_190.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    semi_major_axis: f64,
+    flattening: f64,
+}
+
+impl Ellipsoid {
+    /// The [WGS84](https://en.wikipedia.org/wiki/World_Geodetic_System) ellipsoid, used by GPS.
+    pub const WGS84: Self = Self::new(6_378_137.0, 1.0 / 298.257_223_563);
+
+    /// The [GRS80](https://en.wikipedia.org/wiki/Geodetic_Reference_System_1980) ellipsoid,
+    /// used by NAD83 and ETRS89 among others.
+    pub const GRS80: Self = Self::new(6_378_137.0, 1.0 / 298.257_222_101);
+
+    /// Create a new ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - `semi_major_axis`: the equatorial radius, in meters
+    /// - `flattening`: `(a - b) / a`, where `a` is the semi-major and `b` is the semi-minor axis.
+    ///   Use `0.0` for a sphere.
+    pub const fn new(semi_major_axis: f64, flattening: f64) -> Self {
+        Self {
+            semi_major_axis,
+            flattening,
+        }
+    }
+
+    /// The equatorial radius, in meters.
+    pub fn semi_major_axis(&self) -> f64 {
+        self.semi_major_axis
+    }
+
+    /// The polar radius, in meters.
+    pub fn semi_minor_axis(&self) -> f64 {
+        self.semi_major_axis * (1.0 - self.flattening)
+    }
+
+    /// The flattening, `(a - b) / a`.
+    pub fn flattening(&self) -> f64 {
+        self.flattening
+    }
+
+    pub(crate) fn geodesic(&self) -> geographiclib_rs::Geodesic {
+        geographiclib_rs::Geodesic::new(self.semi_major_axis, self.flattening)
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Self::WGS84
+    }
+}
+
+/// An ellipsoidal model of a planet, using methods given by [Karney (2013)].
 ///
 /// Distances are computed using [geodesic lines] and are measured in meters.
 ///
+/// Use the [`Geodesic`] constant for measurements on the [WGS84](Ellipsoid::WGS84) ellipsoid,
+/// or construct a `GeodesicMeasure` for any other [`Ellipsoid`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Distance, Ellipsoid, Geodesic, GeodesicMeasure, Point};
+///
+/// let new_york_city = Point::new(-74.006, 40.7128);
+/// let london = Point::new(-0.1278, 51.5074);
+///
+/// let grs80 = GeodesicMeasure::new(Ellipsoid::GRS80);
+/// let distance = grs80.distance(new_york_city, london);
+/// assert_eq!(5_585_234., distance.round());
+///
+/// // Mars, per the IAU 2000 report
+/// let mars = GeodesicMeasure::new(Ellipsoid::new(3_396_190.0, 1.0 / 169.894_47));
+/// assert!(mars.distance(new_york_city, london) < Geodesic.distance(new_york_city, london));
+/// ```
+///
 /// [geodesic lines]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
 /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-pub struct Geodesic;
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GeodesicMeasure {
+    ellipsoid: Ellipsoid,
+}
+
+impl GeodesicMeasure {
+    /// Create a geodesic measure on the given `ellipsoid`.
+    pub const fn new(ellipsoid: Ellipsoid) -> Self {
+        Self { ellipsoid }
+    }
+
+    /// The ellipsoid on which measurements are made.
+    pub fn ellipsoid(&self) -> Ellipsoid {
+        self.ellipsoid
+    }
 
-impl Bearing<f64> for Geodesic {
+    fn geodesic(&self) -> geographiclib_rs::Geodesic {
+        self.ellipsoid.geodesic()
+    }
+}
+
+/// An ellipsoidal model of the earth, using methods given by [Karney (2013)] on the
+/// [WGS84](Ellipsoid::WGS84) ellipsoid.
+///
+/// Distances are computed using [geodesic lines] and are measured in meters.
+///
+/// See [`GeodesicMeasure`] to measure on a different [`Ellipsoid`].
+///
+/// [geodesic lines]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+#[allow(non_upper_case_globals)]
+pub const Geodesic: GeodesicMeasure = GeodesicMeasure::new(Ellipsoid::WGS84);
+
+impl Bearing<f64> for GeodesicMeasure {
     /// Returns the bearing from `origin` to `destination` in degrees along a [geodesic line].
     ///
     /// # Units
@@ -25,7 +144,7 @@ impl Bearing<f64> for Geodesic {
     ///
     /// let origin = Point::new(9.0, 10.0);
     /// let destination = Point::new(9.5, 10.1);
-    /// let bearing = Geodesic::bearing(origin, destination);
+    /// let bearing = Geodesic.bearing(origin, destination);
     /// // A little north of east
     /// assert_relative_eq!(bearing, 78.54, epsilon = 1.0e-2);
     /// ```
@@ -36,18 +155,15 @@ impl Bearing<f64> for Geodesic {
     ///
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-    fn bearing(origin: Point<f64>, destination: Point<f64>) -> f64 {
-        let (azi1, _, _) = geographiclib_rs::Geodesic::wgs84().inverse(
-            origin.y(),
-            origin.x(),
-            destination.y(),
-            destination.x(),
-        );
+    fn bearing(&self, origin: Point<f64>, destination: Point<f64>) -> f64 {
+        let (azi1, _, _) =
+            self.geodesic()
+                .inverse(origin.y(), origin.x(), destination.y(), destination.x());
         (azi1 + 360.0) % 360.0
     }
 }
 
-impl Destination<f64> for Geodesic {
+impl Destination<f64> for GeodesicMeasure {
     /// Returns a new point having travelled the `distance` along a [geodesic line]
     /// from the `origin` point with the given `bearing`.
     ///
@@ -71,7 +187,7 @@ impl Destination<f64> for Geodesic {
     /// let northeast_bearing = 45.0;
     /// let distance = 100_000.0;
     ///
-    /// let northeast_of_jfk = Geodesic::destination(jfk, northeast_bearing, distance);
+    /// let northeast_of_jfk = Geodesic.destination(jfk, northeast_bearing, distance);
     /// assert_relative_eq!(Point::new(-72.94, 41.27), northeast_of_jfk, epsilon = 1.0e-2);
     /// ```
     ///
@@ -81,14 +197,15 @@ impl Destination<f64> for Geodesic {
     ///
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-    fn destination(origin: Point<f64>, bearing: f64, distance: f64) -> Point<f64> {
-        let (lat, lon) =
-            geographiclib_rs::Geodesic::wgs84().direct(origin.y(), origin.x(), bearing, distance);
+    fn destination(&self, origin: Point<f64>, bearing: f64, distance: f64) -> Point<f64> {
+        let (lat, lon) = self
+            .geodesic()
+            .direct(origin.y(), origin.x(), bearing, distance);
         Point::new(lon, lat)
     }
 }
 
-impl Distance<f64, Point<f64>, Point<f64>> for Geodesic {
+impl Distance<f64, Point<f64>, Point<f64>> for GeodesicMeasure {
     /// Determine the length of the [geodesic line] between two geometries on an ellipsoidal model of the earth.
     ///
     /// # Units
@@ -106,7 +223,7 @@ impl Distance<f64, Point<f64>, Point<f64>> for Geodesic {
     /// // London
     /// let london = Point::new(-0.1278, 51.5074);
     ///
-    /// let distance = Geodesic::distance(new_york_city, london);
+    /// let distance = Geodesic.distance(new_york_city, london);
     ///
     /// assert_eq!(
     ///     5_585_234., // meters
@@ -120,20 +237,16 @@ impl Distance<f64, Point<f64>, Point<f64>> for Geodesic {
     ///
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
-    fn distance(origin: Point<f64>, destination: Point<f64>) -> f64 {
-        geographiclib_rs::Geodesic::wgs84().inverse(
-            origin.y(),
-            origin.x(),
-            destination.y(),
-            destination.x(),
-        )
+    fn distance(&self, origin: Point<f64>, destination: Point<f64>) -> f64 {
+        self.geodesic()
+            .inverse(origin.y(), origin.x(), destination.y(), destination.x())
     }
 }
 
 /// Interpolate Point(s) along a [geodesic line].
 ///
 /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
-impl InterpolatePoint<f64> for Geodesic {
+impl InterpolatePoint<f64> for GeodesicMeasure {
     /// Returns a new Point along a [geodesic line] between two existing points on an ellipsoidal model of the earth.
     ///
     /// # Units
@@ -150,10 +263,10 @@ impl InterpolatePoint<f64> for Geodesic {
     /// let p1 = Point::new(10.0, 20.0);
     /// let p2 = Point::new(125.0, 25.0);
     ///
-    /// let closer_to_p1 = Geodesic::point_at_distance_between(p1, p2, 100_000.0);
+    /// let closer_to_p1 = Geodesic.point_at_distance_between(p1, p2, 100_000.0);
     /// assert_relative_eq!(closer_to_p1, Point::new(10.81, 20.49), epsilon = 1.0e-2);
     ///
-    /// let closer_to_p2 = Geodesic::point_at_distance_between(p1, p2, 10_000_000.0);
+    /// let closer_to_p2 = Geodesic.point_at_distance_between(p1, p2, 10_000_000.0);
     /// assert_relative_eq!(closer_to_p2, Point::new(112.20, 30.67), epsilon = 1.0e-2);
     /// ```
     ///
@@ -164,6 +277,7 @@ impl InterpolatePoint<f64> for Geodesic {
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
     fn point_at_distance_between(
+        &self,
         start: Point<f64>,
         end: Point<f64>,
         meters_from_start: f64,
@@ -171,8 +285,8 @@ impl InterpolatePoint<f64> for Geodesic {
         if meters_from_start == 0.0 {
             return start;
         }
-        let bearing = Self::bearing(start, end);
-        Self::destination(start, bearing, meters_from_start)
+        let bearing = self.bearing(start, end);
+        self.destination(start, bearing, meters_from_start)
     }
 
     /// Returns a new Point along a [geodesic line] between two existing points on an ellipsoidal model of the earth.
@@ -187,13 +301,13 @@ impl InterpolatePoint<f64> for Geodesic {
     /// let p1 = Point::new(10.0, 20.0);
     /// let p2 = Point::new(125.0, 25.0);
     ///
-    /// let closer_to_p1 = Geodesic::point_at_ratio_between(p1, p2, 0.1);
+    /// let closer_to_p1 = Geodesic.point_at_ratio_between(p1, p2, 0.1);
     /// assert_relative_eq!(closer_to_p1, Point::new(19.52, 25.31), epsilon = 1.0e-2);
     ///
-    /// let closer_to_p2 = Geodesic::point_at_ratio_between(p1, p2, 0.9);
+    /// let closer_to_p2 = Geodesic.point_at_ratio_between(p1, p2, 0.9);
     /// assert_relative_eq!(closer_to_p2, Point::new(114.73, 29.69), epsilon = 1.0e-2);
     ///
-    /// let midpoint = Geodesic::point_at_ratio_between(p1, p2, 0.5);
+    /// let midpoint = Geodesic.point_at_ratio_between(p1, p2, 0.5);
     /// assert_relative_eq!(midpoint, Point::new(65.88, 37.72), epsilon = 1.0e-2);
     /// ```
     ///
@@ -204,6 +318,7 @@ impl InterpolatePoint<f64> for Geodesic {
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
     fn point_at_ratio_between(
+        &self,
         start: Point<f64>,
         end: Point<f64>,
         ratio_from_start: f64,
@@ -215,10 +330,10 @@ impl InterpolatePoint<f64> for Geodesic {
             return end;
         }
 
-        let g = geographiclib_rs::Geodesic::wgs84();
+        let g = self.geodesic();
         let (total_distance, azi1, _azi2, _a12) = g.inverse(start.y(), start.x(), end.y(), end.x());
         let distance = total_distance * ratio_from_start;
-        Self::destination(start, azi1, distance)
+        self.destination(start, azi1, distance)
     }
 
     /// Interpolates `Point`s along a [geodesic line] between `start` and `end`.
@@ -236,12 +351,13 @@ impl InterpolatePoint<f64> for Geodesic {
     /// [geodesic line]: https://en.wikipedia.org/wiki/Geodesics_on_an_ellipsoid
     /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
     fn points_along_line(
+        &self,
         start: Point<f64>,
         end: Point<f64>,
         max_distance: f64,
         include_ends: bool,
     ) -> impl Iterator<Item = Point<f64>> {
-        let g = geographiclib_rs::Geodesic::wgs84();
+        let g = self.geodesic();
         let (total_distance, azi1, _azi2, _a12) = g.inverse(start.y(), start.x(), end.y(), end.x());
 
         if total_distance <= max_distance {
@@ -277,8 +393,6 @@ impl InterpolatePoint<f64> for Geodesic {
 mod tests {
     use super::*;
 
-    type MetricSpace = Geodesic;
-
     mod bearing {
         use super::*;
 
@@ -286,28 +400,28 @@ mod tests {
         fn north() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(0.0, 1.0);
-            assert_relative_eq!(0.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(0.0, Geodesic.bearing(origin, destination));
         }
 
         #[test]
         fn east() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(1.0, 0.0);
-            assert_relative_eq!(90.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(90.0, Geodesic.bearing(origin, destination));
         }
 
         #[test]
         fn south() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(0.0, -1.0);
-            assert_relative_eq!(180.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(180.0, Geodesic.bearing(origin, destination));
         }
 
         #[test]
         fn west() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(-1.0, 0.0);
-            assert_relative_eq!(270.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(270.0, Geodesic.bearing(origin, destination));
         }
     }
 
@@ -320,7 +434,7 @@ mod tests {
             let bearing = 0.0;
             assert_relative_eq!(
                 Point::new(0.0, 0.9043687229127633),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Geodesic.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -330,7 +444,7 @@ mod tests {
             let bearing = 90.0;
             assert_relative_eq!(
                 Point::new(0.8983152841195217, 0.0),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Geodesic.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -340,7 +454,7 @@ mod tests {
             let bearing = 180.0;
             assert_relative_eq!(
                 Point::new(0.0, -0.9043687229127633),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Geodesic.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -350,7 +464,7 @@ mod tests {
             let bearing = 270.0;
             assert_relative_eq!(
                 Point::new(-0.8983152841195217, 0.0),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Geodesic.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -362,13 +476,37 @@ mod tests {
                 let new_york_city = Point::new(-74.006f64, 40.7128f64);
                 let london = Point::new(-0.1278f64, 51.5074f64);
 
-                let distance = MetricSpace::distance(new_york_city, london);
+                let distance = Geodesic.distance(new_york_city, london);
 
                 assert_relative_eq!(
                     5_585_234.0, // meters
                     distance.round()
                 );
             }
+
+            #[test]
+            fn custom_ellipsoid() {
+                let new_york_city = Point::new(-74.006f64, 40.7128f64);
+                let london = Point::new(-0.1278f64, 51.5074f64);
+
+                let wgs84 = GeodesicMeasure::new(Ellipsoid::WGS84);
+                assert_eq!(
+                    Geodesic.distance(new_york_city, london),
+                    wgs84.distance(new_york_city, london)
+                );
+
+                // GRS80 differs from WGS84 by a fraction of a millimeter in its semi-minor axis
+                let grs80 = GeodesicMeasure::new(Ellipsoid::GRS80);
+                assert_relative_eq!(5_585_234.0, grs80.distance(new_york_city, london).round());
+
+                // A sphere with the earth's mean radius agrees closely with Haversine
+                let sphere = GeodesicMeasure::new(Ellipsoid::new(crate::MEAN_EARTH_RADIUS, 0.0));
+                assert_relative_eq!(
+                    crate::Haversine.distance(new_york_city, london),
+                    sphere.distance(new_york_city, london),
+                    epsilon = 1.0e-6
+                );
+            }
         }
 
         mod interpolate_point {
@@ -378,7 +516,7 @@ mod tests {
             fn point_at_ratio_between_midpoint() {
                 let start = Point::new(10.0, 20.0);
                 let end = Point::new(125.0, 25.0);
-                let midpoint = MetricSpace::point_at_ratio_between(start, end, 0.5);
+                let midpoint = Geodesic.point_at_ratio_between(start, end, 0.5);
                 assert_relative_eq!(midpoint, Point::new(65.87936072133309, 37.72225378005785));
             }
 
@@ -387,8 +525,9 @@ mod tests {
                 let start = Point::new(10.0, 20.0);
                 let end = Point::new(125.0, 25.0);
                 let max_dist = 1000000.0; // meters
-                let route =
-                    MetricSpace::points_along_line(start, end, max_dist, true).collect::<Vec<_>>();
+                let route = Geodesic
+                    .points_along_line(start, end, max_dist, true)
+                    .collect::<Vec<_>>();
                 assert_eq!(route.len(), 13);
                 assert_eq!(route[0], start);
                 assert_eq!(route.last().unwrap(), &end);
@@ -400,8 +539,9 @@ mod tests {
                 let start = Point::new(10.0, 20.0);
                 let end = Point::new(125.0, 25.0);
                 let max_dist = 1000000.0; // meters
-                let route =
-                    MetricSpace::points_along_line(start, end, max_dist, false).collect::<Vec<_>>();
+                let route = Geodesic
+                    .points_along_line(start, end, max_dist, false)
+                    .collect::<Vec<_>>();
                 assert_eq!(route.len(), 11);
                 assert_relative_eq!(route[0], Point::new(17.878754355562464, 24.466667836189565));
             }
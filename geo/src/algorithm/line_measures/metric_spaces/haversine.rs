@@ -4,20 +4,194 @@ use super::super::{Bearing, Destination, Distance, InterpolatePoint};
 use crate::utils::normalize_longitude;
 use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
 
+/// A spherical model of a planet using the [haversine formula].
+///
+/// Distances are considered [great circle] lengths and are measured in meters.
+///
+/// Use the [`Haversine`] constant for measurements on the earth, or construct a
+/// `HaversineMeasure` with a custom radius, e.g. for other planets or to match the mean radius
+/// used by another library.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Distance, Haversine, HaversineMeasure, Point};
+///
+/// let new_york_city = Point::new(-74.006f64, 40.7128f64);
+/// let london = Point::new(-0.1278f64, 51.5074f64);
+///
+/// // The radius used by some other libraries, in meters
+/// let haversine = HaversineMeasure::new(6_378_137.0);
+/// assert_eq!(5_576_462., haversine.distance(new_york_city, london).round());
+/// assert_eq!(5_570_230., Haversine.distance(new_york_city, london).round());
+/// ```
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula//
+/// [great circle]: https://en.wikipedia.org/wiki/Great_circle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HaversineMeasure {
+    radius: f64,
+}
+
+impl HaversineMeasure {
+    /// Create a haversine measure on a sphere of the given `radius`, in meters.
+    pub const fn new(radius: f64) -> Self {
+        Self { radius }
+    }
+
+    /// The radius of the sphere, in meters.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    fn radius_as<F: CoordFloat>(&self) -> F {
+        F::from(self.radius).expect("radius to be representable by the coordinate type")
+    }
+
+    /// The [distance](Distance::distance) from each of `origins` to the destination of the
+    /// same index, in meters.
+    ///
+    /// This gives the same results as calling `distance` on each pair, and with the
+    /// `multithreading` feature, computes large batches in parallel.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `origins` and `destinations` have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Distance, Haversine, Point};
+    ///
+    /// let origins = [Point::new(-74.006, 40.7128), Point::new(2.3522, 48.8566)];
+    /// let destinations = [Point::new(-0.1278, 51.5074), Point::new(13.405, 52.52)];
+    ///
+    /// let distances = Haversine.distance_batch(&origins, &destinations);
+    /// assert_eq!(distances[0], Haversine.distance(origins[0], destinations[0]));
+    /// assert_eq!(distances[1], Haversine.distance(origins[1], destinations[1]));
+    /// ```
+    pub fn distance_batch<F>(&self, origins: &[Point<F>], destinations: &[Point<F>]) -> Vec<F>
+    where
+        F: CoordFloat + Send + Sync,
+    {
+        assert_eq!(
+            origins.len(),
+            destinations.len(),
+            "origins and destinations have different lengths"
+        );
+        let radius = self.radius_as::<F>();
+        let distance = |(origin, destination): (&Point<F>, &Point<F>)| {
+            PreparedPoint::new(*origin).distance(&PreparedPoint::new(*destination), radius)
+        };
+
+        #[cfg(feature = "multithreading")]
+        if origins.len() >= PARALLEL_MIN_DISTANCES {
+            use rayon::prelude::*;
+            return origins.par_iter().zip(destinations).map(distance).collect();
+        }
+        origins.iter().zip(destinations).map(distance).collect()
+    }
+
+    /// The [distance](Distance::distance) from each of `origins` to each of `destinations`, in
+    /// meters, as a row for each origin.
+    ///
+    /// This gives the same results as calling `distance` on each pair, but computes the cosine of
+    /// each point's latitude only once rather than once per pair, and with the `multithreading`
+    /// feature, computes large matrices in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Distance, Haversine, Point};
+    ///
+    /// let origins = [Point::new(-74.006, 40.7128), Point::new(2.3522, 48.8566)];
+    /// let destinations = [Point::new(-0.1278, 51.5074), Point::new(13.405, 52.52)];
+    ///
+    /// let matrix = Haversine.distance_matrix(&origins, &destinations);
+    /// assert_eq!(matrix.len(), 2);
+    /// assert_eq!(matrix[0][1], Haversine.distance(origins[0], destinations[1]));
+    /// assert_eq!(matrix[1][0], Haversine.distance(origins[1], destinations[0]));
+    /// ```
+    pub fn distance_matrix<F>(&self, origins: &[Point<F>], destinations: &[Point<F>]) -> Vec<Vec<F>>
+    where
+        F: CoordFloat + Send + Sync,
+    {
+        let radius = self.radius_as::<F>();
+        let destinations: Vec<_> = destinations
+            .iter()
+            .copied()
+            .map(PreparedPoint::new)
+            .collect();
+        let row = |origin: &Point<F>| {
+            let origin = PreparedPoint::new(*origin);
+            destinations
+                .iter()
+                .map(|destination| origin.distance(destination, radius))
+                .collect()
+        };
+
+        #[cfg(feature = "multithreading")]
+        if origins.len() * destinations.len() >= PARALLEL_MIN_DISTANCES {
+            use rayon::prelude::*;
+            return origins.par_iter().map(row).collect();
+        }
+        origins.iter().map(row).collect()
+    }
+}
+
+/// Batches of at least this many distances are computed in parallel.
+#[cfg(feature = "multithreading")]
+const PARALLEL_MIN_DISTANCES: usize = 10_000;
+
+/// A point with the cosine of its latitude, for the haversine formula.
+struct PreparedPoint<F: CoordFloat> {
+    point: Point<F>,
+    cos_lat: F,
+}
+
+impl<F: CoordFloat> PreparedPoint<F> {
+    fn new(point: Point<F>) -> Self {
+        Self {
+            point,
+            cos_lat: point.y().to_radians().cos(),
+        }
+    }
+
+    /// The haversine distance to `other` on a sphere of `radius`.
+    fn distance(&self, other: &Self, radius: F) -> F {
+        let two = F::one() + F::one();
+        let delta_theta = (other.point.y() - self.point.y()).to_radians();
+        let delta_lambda = (other.point.x() - self.point.x()).to_radians();
+        let a = (delta_theta / two).sin().powi(2)
+            + self.cos_lat * other.cos_lat * (delta_lambda / two).sin().powi(2);
+        let c = two * a.sqrt().asin();
+        radius * c
+    }
+}
+
+impl Default for HaversineMeasure {
+    fn default() -> Self {
+        Haversine
+    }
+}
+
 /// A spherical model of the earth using the [haversine formula].
 ///
 /// Distances are considered [great circle] lengths and are measured in meters.
 ///
+/// See [`HaversineMeasure`] to measure on a sphere of a different radius.
+///
 /// # References
 ///
-/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// *Note*: this uses a mean earth radius of 6371.088 km, based on the [recommendation of
 /// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
 ///
 /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula//
 /// [great circle]: https://en.wikipedia.org/wiki/Great_circle
-pub struct Haversine;
+#[allow(non_upper_case_globals)]
+pub const Haversine: HaversineMeasure = HaversineMeasure::new(MEAN_EARTH_RADIUS);
 
-impl<F: CoordFloat + FromPrimitive> Bearing<F> for Haversine {
+impl<F: CoordFloat + FromPrimitive> Bearing<F> for HaversineMeasure {
     /// Returns the bearing from `origin` to `destination` in degrees along a [great circle].
     ///
     /// # Units
@@ -34,7 +208,7 @@ impl<F: CoordFloat + FromPrimitive> Bearing<F> for Haversine {
     ///
     /// let origin = Point::new(9.0, 10.0);
     /// let destination = Point::new(9.5, 10.1);
-    /// let bearing = Haversine::bearing(origin, destination);
+    /// let bearing = Haversine.bearing(origin, destination);
     /// // A little north of east
     /// assert_relative_eq!(bearing, 78.47, epsilon = 1.0e-2);
     /// ```
@@ -45,7 +219,7 @@ impl<F: CoordFloat + FromPrimitive> Bearing<F> for Haversine {
     /// (<https://dtcenter.org/met/users/docs/write_ups/gc_simple.pdf>)
     ///
     /// [great circle]: https://en.wikipedia.org/wiki/Great_circle
-    fn bearing(origin: Point<F>, destination: Point<F>) -> F {
+    fn bearing(&self, origin: Point<F>, destination: Point<F>) -> F {
         let three_sixty =
             F::from(360.0).expect("Numeric type to be constructable from primitive 360");
         let (lng_a, lat_a) = (origin.x().to_radians(), origin.y().to_radians());
@@ -59,7 +233,7 @@ impl<F: CoordFloat + FromPrimitive> Bearing<F> for Haversine {
     }
 }
 
-impl<F: CoordFloat + FromPrimitive> Destination<F> for Haversine {
+impl<F: CoordFloat + FromPrimitive> Destination<F> for HaversineMeasure {
     /// Returns a new point having travelled the `distance` along a [great circle]
     /// from the `origin` point with the given `bearing`.
     ///
@@ -78,22 +252,22 @@ impl<F: CoordFloat + FromPrimitive> Destination<F> for Haversine {
     /// use geo::Point;
     ///
     /// let origin = Point::new(9.177789688110352, 48.776781529534965);
-    /// let destination = Haversine::destination(origin, 45., 10000.);
+    /// let destination = Haversine.destination(origin, 45., 10000.);
     /// assert_relative_eq!(Point::new(9.274409949623532, 48.84033274015048), destination);
     /// ```
     ///
     /// # References
     ///
-    /// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+    /// *Note*: [`Haversine`] uses a mean earth radius of 6371.088 km, based on the [recommendation of
     /// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
     ///
     /// [great circle]: https://en.wikipedia.org/wiki/Great_circle
-    fn destination(origin: Point<F>, bearing: F, meters: F) -> Point<F> {
+    fn destination(&self, origin: Point<F>, bearing: F, meters: F) -> Point<F> {
         let center_lng = origin.x().to_radians();
         let center_lat = origin.y().to_radians();
         let bearing_rad = bearing.to_radians();
 
-        let rad = meters / F::from(MEAN_EARTH_RADIUS).unwrap();
+        let rad = meters / self.radius_as::<F>();
 
         let lat =
             { center_lat.sin() * rad.cos() + center_lat.cos() * rad.sin() * bearing_rad.cos() }
@@ -106,7 +280,7 @@ impl<F: CoordFloat + FromPrimitive> Destination<F> for Haversine {
     }
 }
 
-impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for Haversine {
+impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for HaversineMeasure {
     /// Determine the distance between two points using the [haversine formula].
     ///
     /// # Units
@@ -124,7 +298,7 @@ impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for Haversin
     /// let new_york_city = Point::new(-74.006f64, 40.7128f64);
     /// let london = Point::new(-0.1278f64, 51.5074f64);
     ///
-    /// let distance = Haversine::distance(new_york_city, london);
+    /// let distance = Haversine.distance(new_york_city, london);
     ///
     /// assert_relative_eq!(
     ///     5_570_230., // meters
@@ -134,27 +308,19 @@ impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for Haversin
     ///
     /// # References
     ///
-    /// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+    /// *Note*: [`Haversine`] uses a mean earth radius of 6371.088 km, based on the [recommendation of
     /// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
     ///
     /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
-    fn distance(origin: Point<F>, destination: Point<F>) -> F {
-        let two = F::one() + F::one();
-        let theta1 = origin.y().to_radians();
-        let theta2 = destination.y().to_radians();
-        let delta_theta = (destination.y() - origin.y()).to_radians();
-        let delta_lambda = (destination.x() - origin.x()).to_radians();
-        let a = (delta_theta / two).sin().powi(2)
-            + theta1.cos() * theta2.cos() * (delta_lambda / two).sin().powi(2);
-        let c = two * a.sqrt().asin();
-        F::from(MEAN_EARTH_RADIUS).unwrap() * c
+    fn distance(&self, origin: Point<F>, destination: Point<F>) -> F {
+        PreparedPoint::new(origin).distance(&PreparedPoint::new(destination), self.radius_as())
     }
 }
 
 /// Interpolate Point(s) along a [great circle].
 ///
 /// [great circle]: https://en.wikipedia.org/wiki/Great_circle
-impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Haversine {
+impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for HaversineMeasure {
     /// Returns a new Point along a [great circle] between two existing points.
     ///
     /// # Examples
@@ -167,17 +333,22 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Haversine {
     /// let p1 = Point::new(10.0, 20.0);
     /// let p2 = Point::new(125.0, 25.0);
     ///
-    /// let closer_to_p1 = Haversine::point_at_distance_between(p1, p2, 100_000.0);
+    /// let closer_to_p1 = Haversine.point_at_distance_between(p1, p2, 100_000.0);
     /// assert_relative_eq!(closer_to_p1, Point::new(10.81, 20.49), epsilon = 1.0e-2);
     ///
-    /// let closer_to_p2 = Haversine::point_at_distance_between(p1, p2, 10_000_000.0);
+    /// let closer_to_p2 = Haversine.point_at_distance_between(p1, p2, 10_000_000.0);
     /// assert_relative_eq!(closer_to_p2, Point::new(112.33, 30.57), epsilon = 1.0e-2);
     /// ```
     ///
     /// [great circle]: https://en.wikipedia.org/wiki/Great_circle
-    fn point_at_distance_between(start: Point<F>, end: Point<F>, meters_from_start: F) -> Point<F> {
-        let bearing = Self::bearing(start, end);
-        Self::destination(start, bearing, meters_from_start)
+    fn point_at_distance_between(
+        &self,
+        start: Point<F>,
+        end: Point<F>,
+        meters_from_start: F,
+    ) -> Point<F> {
+        let bearing = self.bearing(start, end);
+        self.destination(start, bearing, meters_from_start)
     }
 
     /// Returns a new Point along a [great circle] between two existing points.
@@ -192,18 +363,23 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Haversine {
     /// let p1 = Point::new(10.0, 20.0);
     /// let p2 = Point::new(125.0, 25.0);
     ///
-    /// let closer_to_p1 = Haversine::point_at_ratio_between(p1, p2, 0.1);
+    /// let closer_to_p1 = Haversine.point_at_ratio_between(p1, p2, 0.1);
     /// assert_relative_eq!(closer_to_p1, Point::new(19.52, 25.27), epsilon = 1.0e-2);
     ///
-    /// let closer_to_p2 = Haversine::point_at_ratio_between(p1, p2, 0.9);
+    /// let closer_to_p2 = Haversine.point_at_ratio_between(p1, p2, 0.9);
     /// assert_relative_eq!(closer_to_p2, Point::new(114.72, 29.65), epsilon = 1.0e-2);
     ///
-    /// let midpoint = Haversine::point_at_ratio_between(p1, p2, 0.5);
+    /// let midpoint = Haversine.point_at_ratio_between(p1, p2, 0.5);
     /// assert_relative_eq!(midpoint, Point::new(65.87, 37.62), epsilon = 1.0e-2);
     /// ```
     ///
     /// [great circle]: https://en.wikipedia.org/wiki/Great_circle
-    fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F> {
+    fn point_at_ratio_between(
+        &self,
+        start: Point<F>,
+        end: Point<F>,
+        ratio_from_start: F,
+    ) -> Point<F> {
         if start == end || ratio_from_start == F::zero() {
             return start;
         }
@@ -225,6 +401,7 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Haversine {
     /// [great circle]: https://en.wikipedia.org/wiki/Great_circle
     /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
     fn points_along_line(
+        &self,
         start: Point<F>,
         end: Point<F>,
         max_distance: F,
@@ -233,7 +410,7 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Haversine {
         let calculation = HaversineIntermediateFillCalculation::new(start, end);
         let HaversineIntermediateFillCalculation { d, .. } = calculation;
 
-        let total_distance = d * F::from(MEAN_EARTH_RADIUS).unwrap();
+        let total_distance = d * self.radius_as::<F>();
 
         if total_distance <= max_distance {
             return if include_ends {
@@ -345,8 +522,6 @@ impl<T: CoordFloat + FromPrimitive> HaversineIntermediateFillCalculation<T> {
 mod tests {
     use super::*;
 
-    type MetricSpace = Haversine;
-
     mod bearing {
         use super::*;
 
@@ -354,28 +529,28 @@ mod tests {
         fn north() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(0.0, 1.0);
-            assert_relative_eq!(0.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(0.0, Haversine.bearing(origin, destination));
         }
 
         #[test]
         fn east() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(1.0, 0.0);
-            assert_relative_eq!(90.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(90.0, Haversine.bearing(origin, destination));
         }
 
         #[test]
         fn south() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(0.0, -1.0);
-            assert_relative_eq!(180.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(180.0, Haversine.bearing(origin, destination));
         }
 
         #[test]
         fn west() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(-1.0, 0.0);
-            assert_relative_eq!(270.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(270.0, Haversine.bearing(origin, destination));
         }
     }
 
@@ -388,7 +563,7 @@ mod tests {
             let bearing = 0.0;
             assert_relative_eq!(
                 Point::new(0.0, 0.899320363724538),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Haversine.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -398,7 +573,7 @@ mod tests {
             let bearing = 90.0;
             assert_relative_eq!(
                 Point::new(0.8993203637245415, 5.506522912913066e-17),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Haversine.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -408,7 +583,7 @@ mod tests {
             let bearing = 180.0;
             assert_relative_eq!(
                 Point::new(0.0, -0.899320363724538),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Haversine.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -418,7 +593,18 @@ mod tests {
             let bearing = 270.0;
             assert_relative_eq!(
                 Point::new(-0.8993203637245415, -1.6519568738739197e-16),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Haversine.destination(origin, bearing, 100_000.0)
+            );
+        }
+
+        #[test]
+        fn custom_radius() {
+            let unit_sphere = HaversineMeasure::new(1.0);
+            let origin = Point::new(0.0, 0.0);
+            assert_relative_eq!(
+                Point::new(90.0, 0.0),
+                unit_sphere.destination(origin, 90.0, std::f64::consts::FRAC_PI_2),
+                epsilon = 1.0e-12
             );
         }
     }
@@ -431,13 +617,56 @@ mod tests {
             let new_york_city = Point::new(-74.006f64, 40.7128f64);
             let london = Point::new(-0.1278f64, 51.5074f64);
 
-            let distance = MetricSpace::distance(new_york_city, london);
+            let distance = Haversine.distance(new_york_city, london);
 
             assert_relative_eq!(
                 5_570_230., // meters
                 distance.round()
             );
         }
+
+        #[test]
+        fn custom_radius() {
+            let unit_sphere = HaversineMeasure::new(1.0);
+            let distance = unit_sphere.distance(Point::new(0.0, 0.0), Point::new(90.0, 0.0));
+            assert_relative_eq!(std::f64::consts::FRAC_PI_2, distance);
+            assert_eq!(Haversine, HaversineMeasure::default());
+        }
+
+        #[test]
+        fn batch_and_matrix() {
+            // enough points for a parallel matrix with the `multithreading` feature
+            let points: Vec<Point> = (0..120)
+                .map(|i| Point::new(i as f64 * 3. - 180., (i as f64 * 7.) % 180. - 90.))
+                .collect();
+            let reversed: Vec<Point> = points.iter().rev().copied().collect();
+
+            let batch = Haversine.distance_batch(&points, &reversed);
+            assert_eq!(batch.len(), points.len());
+            for (i, distance) in batch.into_iter().enumerate() {
+                assert_eq!(distance, Haversine.distance(points[i], reversed[i]));
+            }
+
+            let matrix = Haversine.distance_matrix(&points, &points[..90]);
+            assert_eq!(matrix.len(), points.len());
+            for (i, row) in matrix.into_iter().enumerate() {
+                assert_eq!(row.len(), 90);
+                for (j, distance) in row.into_iter().enumerate() {
+                    assert_eq!(distance, Haversine.distance(points[i], points[j]));
+                }
+            }
+
+            assert!(Haversine
+                .distance_matrix(&points, &[])
+                .iter()
+                .all(Vec::is_empty));
+        }
+
+        #[test]
+        #[should_panic(expected = "different lengths")]
+        fn batch_lengths() {
+            Haversine.distance_batch(&[Point::new(0., 0.)], &[]);
+        }
     }
     mod interpolate_point {
         use super::*;
@@ -446,7 +675,7 @@ mod tests {
         fn point_at_ratio_between_midpoint() {
             let start = Point::new(10.0, 20.0);
             let end = Point::new(125.0, 25.0);
-            let midpoint = MetricSpace::point_at_ratio_between(start, end, 0.5);
+            let midpoint = Haversine.point_at_ratio_between(start, end, 0.5);
             assert_relative_eq!(midpoint, Point::new(65.87394172511485, 37.61809316888599));
         }
         #[test]
@@ -454,8 +683,9 @@ mod tests {
             let start = Point::new(10.0, 20.0);
             let end = Point::new(125.0, 25.0);
             let max_dist = 1000000.0; // meters
-            let route =
-                MetricSpace::points_along_line(start, end, max_dist, true).collect::<Vec<_>>();
+            let route = Haversine
+                .points_along_line(start, end, max_dist, true)
+                .collect::<Vec<_>>();
             assert_eq!(route.len(), 13);
             assert_eq!(route[0], start);
             assert_eq!(route.last().unwrap(), &end);
@@ -466,10 +696,26 @@ mod tests {
             let start = Point::new(10.0, 20.0);
             let end = Point::new(125.0, 25.0);
             let max_dist = 1000000.0; // meters
-            let route =
-                MetricSpace::points_along_line(start, end, max_dist, false).collect::<Vec<_>>();
+            let route = Haversine
+                .points_along_line(start, end, max_dist, false)
+                .collect::<Vec<_>>();
             assert_eq!(route.len(), 11);
             assert_relative_eq!(route[0], Point::new(17.882467331860965, 24.435542998803793));
         }
+        #[test]
+        fn points_along_line_custom_radius() {
+            let start = Point::new(10.0, 20.0);
+            let end = Point::new(125.0, 25.0);
+            // on a sphere half the size of the earth, distances are halved too
+            let half_earth = HaversineMeasure::new(Haversine.radius() / 2.0);
+            let max_dist = 500000.0; // meters
+            let route = half_earth
+                .points_along_line(start, end, max_dist, true)
+                .collect::<Vec<_>>();
+            assert_eq!(route.len(), 13);
+            assert_relative_eq!(route[1], Point::new(17.882467331860965, 24.435542998803793));
+            assert_eq!(route[0], start);
+            assert_eq!(route.last().unwrap(), &end);
+        }
     }
 }
@@ -2,10 +2,10 @@ mod euclidean;
 pub use euclidean::Euclidean;
 
 mod geodesic;
-pub use geodesic::Geodesic;
+pub use geodesic::{Ellipsoid, Geodesic, GeodesicMeasure};
 
 mod haversine;
-pub use haversine::Haversine;
+pub use haversine::{Haversine, HaversineMeasure};
 
 mod rhumb;
 pub use rhumb::Rhumb;
@@ -20,6 +20,7 @@ use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
 /// Veness; both are available under an MIT license.
 ///
 /// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Rhumb;
 
 impl<F: CoordFloat + FromPrimitive> Bearing<F> for Rhumb {
@@ -39,7 +40,7 @@ impl<F: CoordFloat + FromPrimitive> Bearing<F> for Rhumb {
     ///
     /// let origin = Point::new(9.177789688110352, 48.776781529534965);
     /// let destination = Point::new(9.274348757829898, 48.84037308229984);
-    /// let bearing = Rhumb::bearing(origin, destination);
+    /// let bearing = Rhumb.bearing(origin, destination);
     /// assert_relative_eq!(bearing, 45., epsilon = 1.0e-6);
     /// ```
     ///
@@ -49,7 +50,7 @@ impl<F: CoordFloat + FromPrimitive> Bearing<F> for Rhumb {
     ///
     /// Bullock, R.: Great Circle Distances and Bearings Between Two Locations, 2007.
     /// (<https://dtcenter.org/met/users/docs/write_ups/gc_simple.pdf>)
-    fn bearing(origin: Point<F>, destination: Point<F>) -> F {
+    fn bearing(&self, origin: Point<F>, destination: Point<F>) -> F {
         let three_sixty = F::from(360.0f64).unwrap();
 
         let calculations = RhumbCalculations::new(&origin, &destination);
@@ -76,12 +77,12 @@ impl<F: CoordFloat + FromPrimitive> Destination<F> for Rhumb {
     /// use geo::Point;
     ///
     /// let p_1 = Point::new(9.177789688110352, 48.776781529534965);
-    /// let p_2 = Rhumb::destination(p_1, 45., 10000.);
+    /// let p_2 = Rhumb.destination(p_1, 45., 10000.);
     /// assert_relative_eq!(p_2, Point::new(9.274348757829898, 48.84037308229984))
     /// ```
     ///
     /// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
-    fn destination(origin: Point<F>, bearing: F, distance: F) -> Point<F> {
+    fn destination(&self, origin: Point<F>, bearing: F, distance: F) -> Point<F> {
         let delta = distance / F::from(MEAN_EARTH_RADIUS).unwrap(); // angular distance in radians
         let lambda1 = origin.x().to_radians();
         let phi1 = origin.y().to_radians();
@@ -111,7 +112,7 @@ impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for Rhumb {
     /// // London
     /// let p2 = point!(x: -0.1278, y: 51.5074);
     ///
-    /// let distance = Rhumb::distance(p1, p2);
+    /// let distance = Rhumb.distance(p1, p2);
     ///
     /// assert_eq!(
     ///     5_794_129., // meters
@@ -120,7 +121,7 @@ impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for Rhumb {
     /// ```
     ///
     /// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
-    fn distance(origin: Point<F>, destination: Point<F>) -> F {
+    fn distance(&self, origin: Point<F>, destination: Point<F>) -> F {
         let calculations = RhumbCalculations::new(&origin, &destination);
         calculations.delta() * F::from(MEAN_EARTH_RADIUS).unwrap()
     }
@@ -142,17 +143,22 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Rhumb {
     /// let p1 = Point::new(10.0, 20.0);
     /// let p2 = Point::new(125.0, 25.0);
     ///
-    /// let closer_to_p1 = Rhumb::point_at_distance_between(p1, p2, 100_000.0);
+    /// let closer_to_p1 = Rhumb.point_at_distance_between(p1, p2, 100_000.0);
     /// assert_relative_eq!(closer_to_p1, Point::new(10.96, 20.04), epsilon = 1.0e-2);
     ///
-    /// let closer_to_p2 = Rhumb::point_at_distance_between(p1, p2, 10_000_000.0);
+    /// let closer_to_p2 = Rhumb.point_at_distance_between(p1, p2, 10_000_000.0);
     /// assert_relative_eq!(closer_to_p2, Point::new(107.00, 24.23), epsilon = 1.0e-2);
     /// ```
     ///
     /// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
-    fn point_at_distance_between(start: Point<F>, end: Point<F>, meters_from_start: F) -> Point<F> {
-        let bearing = Self::bearing(start, end);
-        Self::destination(start, bearing, meters_from_start)
+    fn point_at_distance_between(
+        &self,
+        start: Point<F>,
+        end: Point<F>,
+        meters_from_start: F,
+    ) -> Point<F> {
+        let bearing = self.bearing(start, end);
+        self.destination(start, bearing, meters_from_start)
     }
 
     /// Returns a new Point along a [rhumb line] between two existing points.
@@ -167,18 +173,23 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Rhumb {
     /// let p1 = Point::new(10.0, 20.0);
     /// let p2 = Point::new(125.0, 25.0);
     ///
-    /// let closer_to_p1 = Rhumb::point_at_ratio_between(p1, p2, 0.1);
+    /// let closer_to_p1 = Rhumb.point_at_ratio_between(p1, p2, 0.1);
     /// assert_relative_eq!(closer_to_p1, Point::new(21.32, 20.50), epsilon = 1.0e-2);
     ///
-    /// let closer_to_p2 = Rhumb::point_at_ratio_between(p1, p2, 0.9);
+    /// let closer_to_p2 = Rhumb.point_at_ratio_between(p1, p2, 0.9);
     /// assert_relative_eq!(closer_to_p2, Point::new(113.31, 24.50), epsilon = 1.0e-2);
     ///
-    /// let midpoint = Rhumb::point_at_ratio_between(p1, p2, 0.5);
+    /// let midpoint = Rhumb.point_at_ratio_between(p1, p2, 0.5);
     /// assert_relative_eq!(midpoint, Point::new(66.98, 22.50), epsilon = 1.0e-2);
     /// ```
     ///
     /// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
-    fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F> {
+    fn point_at_ratio_between(
+        &self,
+        start: Point<F>,
+        end: Point<F>,
+        ratio_from_start: F,
+    ) -> Point<F> {
         let calculations = RhumbCalculations::new(&start, &end);
         calculations.intermediate(ratio_from_start)
     }
@@ -193,6 +204,7 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Rhumb {
     ///
     /// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
     fn points_along_line(
+        &self,
         start: Point<F>,
         end: Point<F>,
         max_distance: F,
@@ -210,8 +222,6 @@ impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Rhumb {
 mod tests {
     use super::*;
 
-    type MetricSpace = Rhumb;
-
     mod bearing {
         use super::*;
 
@@ -219,28 +229,28 @@ mod tests {
         fn north() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(0.0, 1.0);
-            assert_relative_eq!(0.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(0.0, Rhumb.bearing(origin, destination));
         }
 
         #[test]
         fn east() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(1.0, 0.0);
-            assert_relative_eq!(90.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(90.0, Rhumb.bearing(origin, destination));
         }
 
         #[test]
         fn south() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(0.0, -1.0);
-            assert_relative_eq!(180.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(180.0, Rhumb.bearing(origin, destination));
         }
 
         #[test]
         fn west() {
             let origin = Point::new(0.0, 0.0);
             let destination = Point::new(-1.0, 0.0);
-            assert_relative_eq!(270.0, MetricSpace::bearing(origin, destination));
+            assert_relative_eq!(270.0, Rhumb.bearing(origin, destination));
         }
     }
 
@@ -253,7 +263,7 @@ mod tests {
             let bearing = 0.0;
             assert_relative_eq!(
                 Point::new(0.0, 0.899320363724538),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Rhumb.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -263,7 +273,7 @@ mod tests {
             let bearing = 90.0;
             assert_relative_eq!(
                 Point::new(0.8993203637245415, 5.506522912913066e-17),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Rhumb.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -273,7 +283,7 @@ mod tests {
             let bearing = 180.0;
             assert_relative_eq!(
                 Point::new(0.0, -0.899320363724538),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Rhumb.destination(origin, bearing, 100_000.0)
             );
         }
 
@@ -283,7 +293,7 @@ mod tests {
             let bearing = 270.0;
             assert_relative_eq!(
                 Point::new(-0.8993203637245415, -1.6520247072649334e-16),
-                MetricSpace::destination(origin, bearing, 100_000.0)
+                Rhumb.destination(origin, bearing, 100_000.0)
             );
         }
     }
@@ -296,7 +306,7 @@ mod tests {
             let new_york_city = Point::new(-74.006, 40.7128);
             let london = Point::new(-0.1278, 51.5074);
 
-            let distance: f64 = MetricSpace::distance(new_york_city, london);
+            let distance: f64 = Rhumb.distance(new_york_city, london);
 
             assert_relative_eq!(
                 5_794_129., // meters
@@ -312,7 +322,7 @@ mod tests {
         fn point_at_ratio_between_midpoint() {
             let start = Point::new(10.0, 20.0);
             let end = Point::new(125.0, 25.0);
-            let midpoint = MetricSpace::point_at_ratio_between(start, end, 0.5);
+            let midpoint = Rhumb.point_at_ratio_between(start, end, 0.5);
             assert_relative_eq!(
                 midpoint,
                 Point::new(66.98011173721943, 22.500000000000007),
@@ -324,8 +334,9 @@ mod tests {
             let start = Point::new(10.0, 20.0);
             let end = Point::new(125.0, 25.0);
             let max_dist = 1000000.0; // meters
-            let route =
-                MetricSpace::points_along_line(start, end, max_dist, true).collect::<Vec<_>>();
+            let route = Rhumb
+                .points_along_line(start, end, max_dist, true)
+                .collect::<Vec<_>>();
             assert_eq!(route.len(), 13);
             assert_eq!(route[0], start);
             assert_eq!(route.last().unwrap(), &end);
@@ -340,8 +351,9 @@ mod tests {
             let start = Point::new(10.0, 20.0);
             let end = Point::new(125.0, 25.0);
             let max_dist = 1000000.0; // meters
-            let route =
-                MetricSpace::points_along_line(start, end, max_dist, false).collect::<Vec<_>>();
+            let route = Rhumb
+                .points_along_line(start, end, max_dist, false)
+                .collect::<Vec<_>>();
             assert_eq!(route.len(), 11);
             assert_relative_eq!(
                 route[0],
@@ -19,4 +19,6 @@ mod densify;
 pub use densify::Densify;
 
 pub mod metric_spaces;
-pub use metric_spaces::{Euclidean, Geodesic, Haversine, Rhumb};
+pub use metric_spaces::{
+    Ellipsoid, Euclidean, Geodesic, GeodesicMeasure, Haversine, HaversineMeasure, Rhumb,
+};
@@ -1,3 +1,4 @@
+use crate::validation::RingRole;
 use crate::{
     Coord, CoordNum, Line, LineString, MultiLineString, MultiPolygon, Polygon, Rect, Triangle,
 };
@@ -43,6 +44,63 @@ pub trait LinesIter<'a> {
     /// assert_eq!(None, iter.next());
     /// ```
     fn lines_iter(&'a self) -> Self::Iter;
+
+    /// Iterate over the same lines as [`lines_iter`](Self::lines_iter), each along with its
+    /// [`SegmentPosition`] within the geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::validation::RingRole;
+    /// use geo::{coord, wkt, Line, LinesIter, SegmentPosition};
+    ///
+    /// let multi_polygon = wkt!(MULTIPOLYGON(
+    ///     ((0. 0.,1. 0.,1. 1.,0. 0.)),
+    ///     ((5. 5.,9. 5.,9. 9.,5. 5.),(6. 6.,8. 6.,8. 7.,6. 6.))
+    /// ));
+    ///
+    /// let (line, position) = multi_polygon.segments_with_position().nth(4).unwrap();
+    /// assert_eq!(line, Line::new(coord! { x: 9., y: 5. }, coord! { x: 9., y: 9. }));
+    /// assert_eq!(
+    ///     position,
+    ///     SegmentPosition {
+    ///         geometry_index: 1,
+    ///         ring: Some(RingRole::Exterior),
+    ///         segment_index: 1,
+    ///     }
+    /// );
+    ///
+    /// let interior_segments = multi_polygon
+    ///     .segments_with_position()
+    ///     .filter(|(_, position)| position.ring == Some(RingRole::Interior(0)))
+    ///     .count();
+    /// assert_eq!(interior_segments, 3);
+    /// ```
+    fn segments_with_position(
+        &'a self,
+    ) -> impl Iterator<Item = (Line<Self::Scalar>, SegmentPosition)> {
+        self.lines_iter().enumerate().map(|(segment_index, line)| {
+            (
+                line,
+                SegmentPosition {
+                    segment_index,
+                    ..Default::default()
+                },
+            )
+        })
+    }
+}
+
+/// Where a line lies within a geometry, as yielded by [`LinesIter::segments_with_position`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentPosition {
+    /// The index of the member containing the line within a `Multi*` geometry, or `0` for
+    /// other geometries.
+    pub geometry_index: usize,
+    /// The ring containing the line, if it is part of a [`Polygon`].
+    pub ring: Option<RingRole>,
+    /// The index of the line within its [`LineString`], ring, [`Rect`] or [`Triangle`].
+    pub segment_index: usize,
 }
 
 impl<'a, T: CoordNum + 'a> LinesIter<'a> for Line<T> {
@@ -98,6 +156,12 @@ impl<'a, T: CoordNum + 'a> LinesIter<'a> for MultiLineString<T> {
     fn lines_iter(&'a self) -> Self::Iter {
         MapLinesIter(self.0.iter()).flatten()
     }
+
+    fn segments_with_position(
+        &'a self,
+    ) -> impl Iterator<Item = (Line<Self::Scalar>, SegmentPosition)> {
+        members_segments_with_position(&self.0)
+    }
 }
 
 type PolygonIter<'a, T> = iter::Chain<
@@ -114,6 +178,32 @@ impl<'a, T: CoordNum + 'a> LinesIter<'a> for Polygon<T> {
             .lines_iter()
             .chain(MapLinesIter(self.interiors().iter()).flatten())
     }
+
+    fn segments_with_position(
+        &'a self,
+    ) -> impl Iterator<Item = (Line<Self::Scalar>, SegmentPosition)> {
+        let interiors = self
+            .interiors()
+            .iter()
+            .enumerate()
+            .map(|(i, interior)| (interior, RingRole::Interior(i)));
+        iter::once((self.exterior(), RingRole::Exterior))
+            .chain(interiors)
+            .flat_map(|(ring, role)| {
+                ring.lines_iter()
+                    .enumerate()
+                    .map(move |(segment_index, line)| {
+                        (
+                            line,
+                            SegmentPosition {
+                                geometry_index: 0,
+                                ring: Some(role),
+                                segment_index,
+                            },
+                        )
+                    })
+            })
+    }
 }
 
 type MultiPolygonIter<'a, T> =
@@ -126,6 +216,35 @@ impl<'a, T: CoordNum + 'a> LinesIter<'a> for MultiPolygon<T> {
     fn lines_iter(&'a self) -> Self::Iter {
         MapLinesIter(self.0.iter()).flatten()
     }
+
+    fn segments_with_position(
+        &'a self,
+    ) -> impl Iterator<Item = (Line<Self::Scalar>, SegmentPosition)> {
+        members_segments_with_position(&self.0)
+    }
+}
+
+/// The segments of each member of a `Multi*` geometry, with `geometry_index` set to the index of
+/// the member.
+fn members_segments_with_position<'a, G: LinesIter<'a>>(
+    members: &'a [G],
+) -> impl Iterator<Item = (Line<G::Scalar>, SegmentPosition)> + 'a {
+    members
+        .iter()
+        .enumerate()
+        .flat_map(|(geometry_index, member)| {
+            member
+                .segments_with_position()
+                .map(move |(line, position)| {
+                    (
+                        line,
+                        SegmentPosition {
+                            geometry_index,
+                            ..position
+                        },
+                    )
+                })
+        })
 }
 
 impl<'a, T: CoordNum + 'a> LinesIter<'a> for Rect<T> {
@@ -163,7 +282,8 @@ impl<'a, Iter1: Iterator<Item = &'a Iter2>, Iter2: LinesIter<'a>> Iterator
 #[cfg(test)]
 mod test {
 
-    use super::LinesIter;
+    use super::{LinesIter, SegmentPosition};
+    use crate::validation::RingRole;
     use crate::{
         coord, line_string, polygon, Line, LineString, MultiLineString, MultiPolygon, Rect,
         Triangle,
@@ -297,4 +417,44 @@ mod test {
         let want = triangle.to_polygon().lines_iter().collect::<Vec<_>>();
         assert_eq!(want, triangle.lines_iter().collect::<Vec<_>>());
     }
+
+    #[test]
+    fn test_segments_with_position() {
+        let multi_line_string = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)],
+            line_string![(x: 5., y: 5.), (x: 6., y: 6.)],
+        ]);
+        let positions: Vec<_> = multi_line_string
+            .segments_with_position()
+            .map(|(_, position)| (position.geometry_index, position.segment_index))
+            .collect();
+        assert_eq!(positions, vec![(0, 0), (0, 1), (1, 0)]);
+
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+            interiors: [[(x: 6., y: 2.), (x: 8., y: 2.), (x: 8., y: 4.)]],
+        );
+        let (lines, positions): (Vec<_>, Vec<_>) = polygon.segments_with_position().unzip();
+        assert_eq!(lines, polygon.lines_iter().collect::<Vec<_>>());
+        assert_eq!(
+            positions[4],
+            SegmentPosition {
+                geometry_index: 0,
+                ring: Some(RingRole::Interior(0)),
+                segment_index: 1,
+            }
+        );
+
+        let triangle = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 0. },
+            coord! { x: 0., y: 1. },
+        );
+        let positions: Vec<_> = triangle
+            .segments_with_position()
+            .map(|(_, position)| position)
+            .collect();
+        assert_eq!(positions[2].segment_index, 2);
+        assert_eq!(positions[2].ring, None);
+    }
 }
@@ -1,6 +1,6 @@
 use crate::algorithm::{Densify, Length, LineInterpolatePoint, LinesIter};
-use crate::geometry::{Coord, LineString, MultiLineString};
-use crate::line_measures::{Euclidean, Haversine};
+use crate::geometry::{Coord, LineString, MultiLineString, Point};
+use crate::line_measures::{Distance, Euclidean, Haversine, HaversineMeasure, InterpolatePoint};
 
 /// Segments a LineString into `segment_count` equal length LineStrings as a MultiLineString
 /// using Euclidean distance calculations.  See `LineStringSegmentizeHaversine`
@@ -32,90 +32,114 @@ pub trait LineStringSegmentize {
 ///
 /// # Examples
 /// ```
-/// use geo::{LineString, MultiLineString, LineStringSegmentizeHaversine};
+/// use geo::{HaversineMeasure, LineString, MultiLineString, LineStringSegmentizeHaversine};
 /// // Create a simple line string
 /// let lns: LineString<f64> = vec![[0.0, 0.0], [1.0, 2.0], [3.0, 6.0]].into();
 /// // Segment it into 6 LineStrings inside of a MultiLineString
 /// let segmentized = lns.line_segmentize_haversine(6).unwrap();
 /// // Compare the number of elements
 /// assert_eq!(6, segmentized.0.len());
+///
+/// // Segmentize on a sphere other than the earth, e.g. the moon
+/// let moon = HaversineMeasure::new(1_737_400.0);
+/// let segmentized = lns.line_segmentize_haversine_on(6, moon).unwrap();
+/// assert_eq!(6, segmentized.0.len());
 ///```
 pub trait LineStringSegmentizeHaversine {
-    fn line_segmentize_haversine(&self, segment_count: usize) -> Option<MultiLineString>;
+    fn line_segmentize_haversine(&self, segment_count: usize) -> Option<MultiLineString> {
+        self.line_segmentize_haversine_on(segment_count, Haversine)
+    }
+
+    /// Like [`line_segmentize_haversine`](Self::line_segmentize_haversine), but measuring
+    /// distances on a sphere of the given [`HaversineMeasure`]'s radius.
+    fn line_segmentize_haversine_on(
+        &self,
+        segment_count: usize,
+        haversine: HaversineMeasure,
+    ) -> Option<MultiLineString>;
 }
 
-macro_rules! implement_segmentize {
-    ($trait_name:ident, $method_name:ident, $metric_space:ty) => {
-        impl $trait_name for LineString {
-            fn $method_name(&self, n: usize) -> Option<MultiLineString> {
-                if (n == usize::MIN) || (n == usize::MAX) {
-                    return None;
-                } else if n == 1 {
-                    let mlns = MultiLineString::from(self.clone());
-                    return Some(mlns);
-                }
-
-                let mut res_coords: Vec<Vec<Coord>> = Vec::with_capacity(n);
-                let total_length = self.length::<$metric_space>();
-                let mut cum_length = 0_f64;
-                let segment_prop = (1_f64) / (n as f64);
-                let segment_length = total_length * segment_prop;
-                let densified = self.densify::<$metric_space>(segment_length - f64::EPSILON);
-
-                if densified.lines().count() == n {
-                    let linestrings = densified
-                        .lines()
-                        .map(LineString::from)
-                        .collect::<Vec<LineString>>();
-                    return Some(MultiLineString::new(linestrings));
-                }
-
-                let n_lines = densified.lines().count();
-                let lns = densified.lines_iter();
-                let mut ln_vec: Vec<Coord> = Vec::new();
-
-                for (i, segment) in lns.enumerate() {
-                    if i == 0 {
-                        ln_vec.push(segment.start)
-                    }
-
-                    let length = segment.length::<$metric_space>();
-                    cum_length += length;
-
-                    if (cum_length >= segment_length) && (i != (n_lines - 1)) {
-                        let remainder = cum_length - segment_length;
-                        let endpoint =
-                            segment.line_interpolate_point((length - remainder) / length)?;
-
-                        ln_vec.push(endpoint.into());
-                        let to_push = ln_vec.drain(..);
-                        res_coords.push(to_push.collect::<Vec<Coord>>());
-
-                        if i != n_lines {
-                            ln_vec.push(endpoint.into());
-                        }
-                        cum_length = remainder;
-                    }
-                    ln_vec.push(segment.end);
-                }
-
-                res_coords.push(ln_vec);
-                let res_lines = res_coords
-                    .into_iter()
-                    .map(LineString::new)
-                    .collect::<Vec<LineString>>();
-                Some(MultiLineString::new(res_lines))
+impl LineStringSegmentize for LineString {
+    fn line_segmentize(&self, n: usize) -> Option<MultiLineString> {
+        segmentize(self, n, &Euclidean)
+    }
+}
+
+impl LineStringSegmentizeHaversine for LineString {
+    fn line_segmentize_haversine_on(
+        &self,
+        n: usize,
+        haversine: HaversineMeasure,
+    ) -> Option<MultiLineString> {
+        segmentize(self, n, &haversine)
+    }
+}
+
+fn segmentize<MetricSpace>(
+    line_string: &LineString,
+    n: usize,
+    metric_space: &MetricSpace,
+) -> Option<MultiLineString>
+where
+    MetricSpace: Distance<f64, Point, Point> + InterpolatePoint<f64>,
+{
+    if (n == usize::MIN) || (n == usize::MAX) {
+        return None;
+    } else if n == 1 {
+        let mlns = MultiLineString::from(line_string.clone());
+        return Some(mlns);
+    }
+
+    let mut res_coords: Vec<Vec<Coord>> = Vec::with_capacity(n);
+    let total_length = line_string.length(metric_space);
+    let mut cum_length = 0_f64;
+    let segment_prop = (1_f64) / (n as f64);
+    let segment_length = total_length * segment_prop;
+    let densified = line_string.densify(metric_space, segment_length - f64::EPSILON);
+
+    if densified.lines().count() == n {
+        let linestrings = densified
+            .lines()
+            .map(LineString::from)
+            .collect::<Vec<LineString>>();
+        return Some(MultiLineString::new(linestrings));
+    }
+
+    let n_lines = densified.lines().count();
+    let lns = densified.lines_iter();
+    let mut ln_vec: Vec<Coord> = Vec::new();
+
+    for (i, segment) in lns.enumerate() {
+        if i == 0 {
+            ln_vec.push(segment.start)
+        }
+
+        let length = segment.length(metric_space);
+        cum_length += length;
+
+        if (cum_length >= segment_length) && (i != (n_lines - 1)) {
+            let remainder = cum_length - segment_length;
+            let endpoint = segment.line_interpolate_point((length - remainder) / length)?;
+
+            ln_vec.push(endpoint.into());
+            let to_push = ln_vec.drain(..);
+            res_coords.push(to_push.collect::<Vec<Coord>>());
+
+            if i != n_lines {
+                ln_vec.push(endpoint.into());
             }
+            cum_length = remainder;
         }
-    };
-}
+        ln_vec.push(segment.end);
+    }
 
-implement_segmentize!(LineStringSegmentize, line_segmentize, Euclidean);
-implement_segmentize!(
-    LineStringSegmentizeHaversine,
-    line_segmentize_haversine,
-    Haversine
-);
+    res_coords.push(ln_vec);
+    let res_lines = res_coords
+        .into_iter()
+        .map(LineString::new)
+        .collect::<Vec<LineString>>();
+    Some(MultiLineString::new(res_lines))
+}
 
 #[cfg(test)]
 mod test {
@@ -148,10 +172,7 @@ mod test {
         let segments = linestring.line_segmentize(4).unwrap();
         assert_eq!(segments.0.len(), 4);
 
-        assert_eq!(
-            segments.length::<Euclidean>(),
-            linestring.length::<Euclidean>()
-        );
+        assert_eq!(segments.length(&Euclidean), linestring.length(&Euclidean));
     }
 
     #[test]
@@ -170,8 +191,8 @@ mod test {
         let segments = linestring.line_segmentize(5).unwrap();
         assert_eq!(segments.0.len(), 5);
         assert_relative_eq!(
-            linestring.length::<Euclidean>(),
-            segments.length::<Euclidean>(),
+            linestring.length(&Euclidean),
+            segments.length(&Euclidean),
             epsilon = f64::EPSILON
         );
     }
@@ -183,8 +204,8 @@ mod test {
         let segments = linestring.line_segmentize(5).unwrap();
         assert_eq!(segments.0.len(), 5);
         assert_relative_eq!(
-            linestring.length::<Euclidean>(),
-            segments.length::<Euclidean>(),
+            linestring.length(&Euclidean),
+            segments.length(&Euclidean),
             epsilon = f64::EPSILON
         );
     }
@@ -221,8 +242,8 @@ mod test {
         assert_eq!(segments.0.len(), 5);
 
         assert_relative_eq!(
-            linestring.length::<Euclidean>(),
-            segments.length::<Euclidean>(),
+            linestring.length(&Euclidean),
+            segments.length(&Euclidean),
             epsilon = f64::EPSILON
         );
     }
@@ -253,7 +274,7 @@ mod test {
         // assert that the lines are equal length
         let lens = segments
             .into_iter()
-            .map(|x| x.length::<Euclidean>())
+            .map(|x| x.length(&Euclidean))
             .collect::<Vec<f64>>();
 
         let first = lens[0];
@@ -270,8 +291,8 @@ mod test {
         let segments = linestring.line_segmentize(2).unwrap();
 
         assert_relative_eq!(
-            linestring.length::<Euclidean>(),
-            segments.length::<Euclidean>(),
+            linestring.length(&Euclidean),
+            segments.length(&Euclidean),
             epsilon = f64::EPSILON
         )
     }
@@ -329,7 +350,7 @@ mod test {
         let lens = segments
             .0
             .iter()
-            .map(|li| li.length::<Haversine>())
+            .map(|li| li.length(&Haversine))
             .collect::<Vec<_>>();
 
         let epsilon = 1e-6; // 6th decimal place which is micrometers
@@ -345,7 +366,7 @@ mod test {
         ]
         .into();
 
-        assert_relative_eq!(linestring.length::<Haversine>(), 83.3523000093029);
+        assert_relative_eq!(linestring.length(&Haversine), 83.3523000093029);
 
         let n = 8;
 
@@ -353,9 +374,36 @@ mod test {
 
         // different at 12th decimal which is a picometer
         assert_relative_eq!(
-            linestring.length::<Haversine>(),
-            segments.length::<Haversine>(),
+            linestring.length(&Haversine),
+            segments.length(&Haversine),
+            epsilon = 1e-11
+        );
+    }
+
+    #[test]
+    fn haversine_custom_radius() {
+        let linestring: LineString = vec![
+            [-3.19416, 55.95524],
+            [-3.19352, 55.95535],
+            [-3.19288, 55.95546],
+        ]
+        .into();
+
+        let moon = HaversineMeasure::new(1_737_400.0);
+        let n = 8;
+
+        let segments = linestring.line_segmentize_haversine_on(n, moon).unwrap();
+        assert_eq!(n, segments.0.len());
+        assert_relative_eq!(
+            linestring.length(&moon),
+            segments.length(&moon),
             epsilon = 1e-11
         );
+        let lens = segments
+            .0
+            .iter()
+            .map(|li| li.length(&moon))
+            .collect::<Vec<_>>();
+        assert!(lens.iter().all(|&x| (x - lens[0]).abs() < 1e-6));
     }
 }
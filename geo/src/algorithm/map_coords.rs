@@ -25,6 +25,7 @@
 //! ```
 
 pub(crate) use crate::geometry::*;
+use crate::validation::RingRole;
 pub(crate) use crate::CoordNum;
 
 /// Map a function over all the coordinates in an object, returning a new one
@@ -147,7 +148,9 @@ pub trait MapCoordsInPlace<T> {
     /// Map a fallible function over all the coordinates in a geometry, in place, returning a `Result`.
     ///
     /// Upon encountering an `Err` from the function, `try_map_coords_in_place` immediately returns
-    /// and the geometry is potentially left in a partially mapped state.
+    /// and the geometry is potentially left in a partially mapped state. Use
+    /// [`try_map_coords_in_place_transactional`](Self::try_map_coords_in_place_transactional)
+    /// to leave the geometry unchanged instead.
     ///
     /// # Examples
     ///
@@ -176,6 +179,46 @@ pub trait MapCoordsInPlace<T> {
     ) -> Result<(), E>
     where
         T: CoordNum;
+
+    /// Map a fallible function over all the coordinates in a geometry, in place, returning a `Result`.
+    ///
+    /// Unlike [`try_map_coords_in_place`](Self::try_map_coords_in_place), either every
+    /// coordinate is mapped, or, upon encountering an `Err` from the function, the geometry is
+    /// left unchanged. The coordinates are mapped on a copy of the geometry, which replaces
+    /// `self` only once all of them have been mapped successfully.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, Coord, MapCoordsInPlace};
+    ///
+    /// let mut line_string = line_string![(x: 1u32, y: 1u32), (x: 2, y: 2), (x: 3, y: 0)];
+    /// let original = line_string.clone();
+    ///
+    /// let result = line_string.try_map_coords_in_place_transactional(|Coord { x, y }| {
+    ///     Ok(Coord {
+    ///         x: x * 10,
+    ///         y: y.checked_sub(1).ok_or("Underflow")?,
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(result, Err("Underflow"));
+    /// // Though the first two coordinates were mapped successfully, they are unchanged
+    /// assert_eq!(line_string, original);
+    /// ```
+    fn try_map_coords_in_place_transactional<E>(
+        &mut self,
+        func: impl Fn(Coord<T>) -> Result<Coord<T>, E>,
+    ) -> Result<(), E>
+    where
+        T: CoordNum,
+        Self: Clone,
+    {
+        let mut mapped = self.clone();
+        mapped.try_map_coords_in_place(func)?;
+        *self = mapped;
+        Ok(())
+    }
 }
 
 //-----------------------//
@@ -495,6 +538,61 @@ impl<T: CoordNum> MapCoordsInPlace<T> for MultiPolygon<T> {
     }
 }
 
+/// Where a coordinate lies within a geometry, as passed to the closure of
+/// [`MapCoordsIndexed::map_coords_indexed`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CoordContext {
+    /// The index of the member containing the coordinate within the innermost `Multi*`
+    /// geometry or [`GeometryCollection`] containing it, or `0` if there is none.
+    pub geometry_index: usize,
+    /// The ring containing the coordinate, if it is part of a [`Polygon`].
+    pub ring: Option<RingRole>,
+    /// The index of the coordinate within its [`LineString`], ring, [`Line`], [`Triangle`] or
+    /// [`Rect`] (where `0` is the minimum and `1` the maximum coordinate).
+    pub coord_index: usize,
+}
+
+/// Map a function over all the coordinates in an object, passing along where in the object
+/// each coordinate lies, returning a new one
+pub trait MapCoordsIndexed<T, NT> {
+    type Output;
+
+    /// Apply a function to all the coordinates in a geometric object, returning a new object.
+    ///
+    /// Unlike [`MapCoords::map_coords`], `func` is also passed the [`CoordContext`] of each
+    /// coordinate, so that it can treat coordinates differently depending on their position,
+    /// without walking the geometry itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::validation::RingRole;
+    /// use geo::{wkt, Coord, MapCoordsIndexed};
+    ///
+    /// let polygon = wkt!(POLYGON(
+    ///     (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+    ///     (2.1 2.1,2.9 2.1,2.9 2.9,2.1 2.9,2.1 2.1)
+    /// ));
+    ///
+    /// // Snap only the interior rings to the integer grid
+    /// let snapped = polygon.map_coords_indexed(|coord: Coord<f64>, context| match context.ring {
+    ///     Some(RingRole::Interior(_)) => Coord { x: coord.x.round(), y: coord.y.round() },
+    ///     _ => coord,
+    /// });
+    /// assert_eq!(
+    ///     snapped,
+    ///     wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(2. 2.,3. 2.,3. 3.,2. 3.,2. 2.)))
+    /// );
+    /// ```
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output
+    where
+        T: CoordNum,
+        NT: CoordNum;
+}
+
 //--------------------------//
 // Geometry implementations //
 //--------------------------//
@@ -690,9 +788,261 @@ impl<T: CoordNum> MapCoordsInPlace<T> for Triangle<T> {
     }
 }
 
+//----------------------------------//
+// MapCoordsIndexed implementations //
+//----------------------------------//
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for Point<T> {
+    type Output = Point<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        Point(func(self.0, CoordContext::default()))
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for Line<T> {
+    type Output = Line<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        Line::new(
+            func(self.start, CoordContext::default()),
+            func(
+                self.end,
+                CoordContext {
+                    coord_index: 1,
+                    ..Default::default()
+                },
+            ),
+        )
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for LineString<T> {
+    type Output = LineString<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        map_ring_indexed(self, func, None)
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for Polygon<T> {
+    type Output = Polygon<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        Polygon::new(
+            map_ring_indexed(self.exterior(), func, Some(RingRole::Exterior)),
+            self.interiors()
+                .iter()
+                .enumerate()
+                .map(|(i, interior)| map_ring_indexed(interior, func, Some(RingRole::Interior(i))))
+                .collect(),
+        )
+    }
+}
+
+fn map_ring_indexed<T: CoordNum, NT: CoordNum>(
+    line_string: &LineString<T>,
+    func: impl Fn(Coord<T>, CoordContext) -> Coord<NT>,
+    ring: Option<RingRole>,
+) -> LineString<NT> {
+    line_string
+        .0
+        .iter()
+        .enumerate()
+        .map(|(coord_index, coord)| {
+            func(
+                *coord,
+                CoordContext {
+                    geometry_index: 0,
+                    ring,
+                    coord_index,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Map each member of a `Multi*` geometry or [`GeometryCollection`], setting
+/// [`CoordContext::geometry_index`] to the index of the member.
+fn map_members_indexed<'a, T, NT, G>(
+    members: &'a [G],
+    func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+) -> Vec<G::Output>
+where
+    T: CoordNum,
+    NT: CoordNum,
+    G: MapCoordsIndexed<T, NT> + 'a,
+{
+    members
+        .iter()
+        .enumerate()
+        .map(|(geometry_index, member)| {
+            member.map_coords_indexed(move |coord, context| {
+                func(
+                    coord,
+                    CoordContext {
+                        geometry_index,
+                        ..context
+                    },
+                )
+            })
+        })
+        .collect()
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for MultiPoint<T> {
+    type Output = MultiPoint<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        MultiPoint::new(map_members_indexed(&self.0, func))
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for MultiLineString<T> {
+    type Output = MultiLineString<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        MultiLineString::new(map_members_indexed(&self.0, func))
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for MultiPolygon<T> {
+    type Output = MultiPolygon<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        MultiPolygon::new(map_members_indexed(&self.0, func))
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for GeometryCollection<T> {
+    type Output = GeometryCollection<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        // Nested collections would otherwise instantiate this method for ever more deeply
+        // nested closures, so erase their type
+        type DynFunc<'a, T, NT> = &'a dyn Fn(Coord<T>, CoordContext) -> Coord<NT>;
+
+        let func: DynFunc<T, NT> = &func;
+        let geometries = self
+            .iter()
+            .enumerate()
+            .map(|(geometry_index, geometry)| match geometry {
+                // Keep the index within the innermost collection
+                Geometry::MultiPoint(_)
+                | Geometry::MultiLineString(_)
+                | Geometry::MultiPolygon(_)
+                | Geometry::GeometryCollection(_) => geometry.map_coords_indexed(func),
+                _ => {
+                    let member_func = move |coord, context| {
+                        func(
+                            coord,
+                            CoordContext {
+                                geometry_index,
+                                ..context
+                            },
+                        )
+                    };
+                    geometry.map_coords_indexed(&member_func as DynFunc<T, NT>)
+                }
+            })
+            .collect();
+        GeometryCollection::new_from(geometries)
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for Rect<T> {
+    type Output = Rect<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        Rect::new(
+            func(self.min(), CoordContext::default()),
+            func(
+                self.max(),
+                CoordContext {
+                    coord_index: 1,
+                    ..Default::default()
+                },
+            ),
+        )
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for Triangle<T> {
+    type Output = Triangle<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        let Triangle(a, b, c) = *self;
+        let context = |coord_index| CoordContext {
+            coord_index,
+            ..Default::default()
+        };
+        Triangle::new(
+            func(a, context(0)),
+            func(b, context(1)),
+            func(c, context(2)),
+        )
+    }
+}
+
+impl<T: CoordNum, NT: CoordNum> MapCoordsIndexed<T, NT> for Geometry<T> {
+    type Output = Geometry<NT>;
+
+    fn map_coords_indexed(
+        &self,
+        func: impl Fn(Coord<T>, CoordContext) -> Coord<NT> + Copy,
+    ) -> Self::Output {
+        match *self {
+            Geometry::Point(ref x) => Geometry::Point(x.map_coords_indexed(func)),
+            Geometry::Line(ref x) => Geometry::Line(x.map_coords_indexed(func)),
+            Geometry::LineString(ref x) => Geometry::LineString(x.map_coords_indexed(func)),
+            Geometry::Polygon(ref x) => Geometry::Polygon(x.map_coords_indexed(func)),
+            Geometry::MultiPoint(ref x) => Geometry::MultiPoint(x.map_coords_indexed(func)),
+            Geometry::MultiLineString(ref x) => {
+                Geometry::MultiLineString(x.map_coords_indexed(func))
+            }
+            Geometry::MultiPolygon(ref x) => Geometry::MultiPolygon(x.map_coords_indexed(func)),
+            Geometry::GeometryCollection(ref x) => {
+                Geometry::GeometryCollection(x.map_coords_indexed(func))
+            }
+            Geometry::Rect(ref x) => Geometry::Rect(x.map_coords_indexed(func)),
+            Geometry::Triangle(ref x) => Geometry::Triangle(x.map_coords_indexed(func)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{MapCoords, MapCoordsInPlace};
+    use super::{CoordContext, MapCoords, MapCoordsInPlace, MapCoordsIndexed};
     use crate::{
         coord, polygon, Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString,
         MultiPoint, MultiPolygon, Point, Polygon, Rect,
@@ -1006,4 +1356,87 @@ mod test {
         // constructor panics if min coords > max coords
         rect.map_coords(|Coord { x, y }| (-x, -y).into());
     }
+
+    #[test]
+    fn map_coords_indexed_context() {
+        use crate::validation::RingRole;
+        use crate::wkt;
+
+        let collection = wkt!(GEOMETRYCOLLECTION(
+            POINT(0. 0.),
+            MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((5. 5.,6. 5.,6. 6.,5. 5.),(5.1 5.1,5.2 5.1,5.2 5.2,5.1 5.1))),
+            LINESTRING(0. 0.,1. 1.)
+        ));
+        let contexts = std::cell::RefCell::new(vec![]);
+        let mapped = collection.map_coords_indexed(|coord, context| {
+            contexts.borrow_mut().push(context);
+            coord
+        });
+        assert_eq!(mapped, collection);
+
+        let contexts = contexts.into_inner();
+        assert_eq!(contexts.len(), 1 + 4 + 4 + 4 + 2);
+        assert_eq!(contexts[0], CoordContext::default());
+        assert_eq!(
+            contexts[3],
+            CoordContext {
+                geometry_index: 0,
+                ring: Some(RingRole::Exterior),
+                coord_index: 2,
+            }
+        );
+        assert_eq!(
+            contexts[10],
+            CoordContext {
+                geometry_index: 1,
+                ring: Some(RingRole::Interior(0)),
+                coord_index: 1,
+            }
+        );
+        assert_eq!(
+            contexts[14],
+            CoordContext {
+                geometry_index: 2,
+                ring: None,
+                coord_index: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn try_map_coords_in_place_transactional() {
+        let mut polygon = polygon![(x: 0, y: 0), (x: 10, y: 0), (x: -10, y: 10)];
+        let original = polygon.clone();
+
+        let double_non_negative = |Coord { x, y }: Coord<i32>| {
+            if x < 0 {
+                Err(x)
+            } else {
+                Ok(Coord { x: x * 2, y: y * 2 })
+            }
+        };
+        assert_eq!(
+            polygon.try_map_coords_in_place_transactional(double_non_negative),
+            Err(-10)
+        );
+        assert_eq!(polygon, original);
+
+        // By contrast, `try_map_coords_in_place` leaves the polygon partially mapped
+        assert_eq!(
+            polygon.try_map_coords_in_place(double_non_negative),
+            Err(-10)
+        );
+        assert_ne!(polygon, original);
+
+        let mut polygon = original.clone();
+        polygon.exterior_mut(|exterior| exterior.0[2].x = 10);
+        assert_eq!(
+            polygon.try_map_coords_in_place_transactional(double_non_negative),
+            Ok(())
+        );
+        assert_eq!(
+            polygon,
+            polygon![(x: 0, y: 0), (x: 20, y: 0), (x: 20, y: 20)]
+        );
+    }
 }
@@ -0,0 +1,214 @@
+use crate::convex_hull::quick_hull;
+use crate::{Coord, CoordsIter, Distance, Euclidean, GeoFloat, Point};
+
+/// Determine the maximum Euclidean distance between two geometries, and the pair of points
+/// which are farthest apart.
+///
+/// The farthest points of two geometries are vertices of their convex hulls. They're found by
+/// rotating calipers around both hulls at once, which takes linear time in the size of the
+/// hulls, after the hulls themselves are computed in `O(n log n)` time.
+///
+/// The maximum distance *within* a geometry, also known as its diameter, is the maximum
+/// distance between the geometry and itself.
+///
+/// Returns `None` if either geometry is empty.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, MaxDistance};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 4.)];
+/// let point = point!(x: 0., y: 0.);
+///
+/// assert_eq!(line_string.max_distance(&point), Some(5.));
+/// assert_eq!(
+///     line_string.farthest_points(&point),
+///     Some((point!(x: 3., y: 4.), point!(x: 0., y: 0.)))
+/// );
+///
+/// // The diameter of the line string
+/// assert_eq!(line_string.max_distance(&line_string), Some(5.));
+/// ```
+pub trait MaxDistance<T>
+where
+    T: GeoFloat,
+{
+    /// The maximum distance between any point of `self` and any point of `rhs`.
+    fn max_distance<Rhs>(&self, rhs: &Rhs) -> Option<T>
+    where
+        Rhs: CoordsIter<Scalar = T>;
+
+    /// The point of `self` and the point of `rhs` which are farthest apart.
+    fn farthest_points<Rhs>(&self, rhs: &Rhs) -> Option<(Point<T>, Point<T>)>
+    where
+        Rhs: CoordsIter<Scalar = T>;
+}
+
+impl<T, G> MaxDistance<T> for G
+where
+    T: GeoFloat,
+    G: CoordsIter<Scalar = T>,
+{
+    fn max_distance<Rhs>(&self, rhs: &Rhs) -> Option<T>
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        let (a, b) = self.farthest_points(rhs)?;
+        Some(Euclidean.distance(a, b))
+    }
+
+    fn farthest_points<Rhs>(&self, rhs: &Rhs) -> Option<(Point<T>, Point<T>)>
+    where
+        Rhs: CoordsIter<Scalar = T>,
+    {
+        let hull_a = hull_vertices(self)?;
+        let hull_b = hull_vertices(rhs)?;
+        let (a, b) = farthest_vertices(&hull_a, &hull_b);
+        Some((a.into(), b.into()))
+    }
+}
+
+/// The vertices of the convex hull of a geometry, counter-clockwise from the lowest (and then
+/// leftmost) vertex, without repeating the first vertex.
+fn hull_vertices<T: GeoFloat>(geometry: &impl CoordsIter<Scalar = T>) -> Option<Vec<Coord<T>>> {
+    let mut coords: Vec<_> = geometry.exterior_coords_iter().collect();
+    let mut hull = quick_hull(&mut coords).0;
+    if hull.len() > 1 && hull.first() == hull.last() {
+        hull.pop();
+    }
+    let lowest = hull
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))?
+        .0;
+    hull.rotate_left(lowest);
+    Some(hull)
+}
+
+/// The farthest pair of vertices of two convex polygons.
+///
+/// The farthest points `a` and `b` are extreme in opposite directions: `a` is the farthest
+/// point of its hull along `a - b`, and `b` along `b - a`. Rotating a direction once around
+/// the circle, the extreme vertex of `hull_a` changes as the direction passes the normal of each
+/// of its edges, and likewise for `hull_b` in the opposite direction. Merging the edges of
+/// `hull_a` and the reversed edges of `hull_b` by their angle, as when computing the
+/// Minkowski sum of `hull_a` and `-hull_b`, visits every such pair of extreme vertices.
+fn farthest_vertices<T: GeoFloat>(
+    hull_a: &[Coord<T>],
+    hull_b: &[Coord<T>],
+) -> (Coord<T>, Coord<T>) {
+    // Negating `hull_b` keeps it counter-clockwise, and moves its lowest vertex to the top
+    let negated: Vec<_> = hull_b.iter().map(|&coord| -coord).collect();
+    let start_b = negated
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+        .expect("hulls aren't empty")
+        .0;
+
+    let (n, m) = (hull_a.len(), hull_b.len());
+    let vertex_b = |j: usize| (start_b + j) % m;
+    let mut farthest = (hull_a[0], hull_b[vertex_b(0)]);
+    let mut max_distance_2 = T::neg_infinity();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let (a, b) = (hull_a[i % n], hull_b[vertex_b(j)]);
+        let delta = a - b;
+        let distance_2 = delta.x * delta.x + delta.y * delta.y;
+        if distance_2 > max_distance_2 {
+            max_distance_2 = distance_2;
+            farthest = (a, b);
+        }
+
+        // Advance along whichever edge comes first by angle, or both if they're parallel
+        let edge_a = hull_a[(i + 1) % n] - hull_a[i % n];
+        let edge_b = negated[vertex_b(j + 1)] - negated[vertex_b(j)];
+        let cross = edge_a.x * edge_b.y - edge_a.y * edge_b.x;
+        if j == m || (i < n && cross > T::zero()) {
+            i += 1;
+        } else if i == n || cross < T::zero() {
+            j += 1;
+        } else {
+            i += 1;
+            j += 1;
+        }
+    }
+    farthest
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, wkt, MultiPoint};
+
+    fn brute_force(a: &impl CoordsIter<Scalar = f64>, b: &impl CoordsIter<Scalar = f64>) -> f64 {
+        a.coords_iter()
+            .flat_map(|a| b.coords_iter().map(move |b| Euclidean.distance(a, b)))
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let norway = geo_test_fixtures::norway_main::<f64>();
+        let louisiana = geo_test_fixtures::louisiana::<f64>();
+        let east_baton_rouge = geo_test_fixtures::east_baton_rouge::<f64>();
+        let geometries = [
+            MultiPoint::from(norway.0.clone()),
+            MultiPoint::from(louisiana.0.clone()),
+            east_baton_rouge.exterior().coords().copied().collect(),
+            wkt!(MULTIPOINT(1. 1.)),
+            wkt!(MULTIPOINT(0. 0.,1. 1.,2. 2.,3. 3.)),
+            wkt!(MULTIPOINT(-5. 3.,-5. 3.,-5. 3.,-5. 3.)),
+        ];
+        for a in &geometries {
+            for b in &geometries {
+                let expected = brute_force(a, b);
+                let (point_a, point_b) = a.farthest_points(b).unwrap();
+                assert_relative_eq!(a.max_distance(b).unwrap(), expected, max_relative = 1e-12);
+                assert_eq!(
+                    Euclidean.distance(point_a, point_b),
+                    a.max_distance(b).unwrap()
+                );
+                assert!(a.0.contains(&point_a));
+                assert!(b.0.contains(&point_b));
+            }
+        }
+    }
+
+    #[test]
+    fn squares() {
+        let a = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let b = polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.), (x: 5., y: 6.)];
+        assert_eq!(
+            a.farthest_points(&b),
+            Some((point!(x: 0., y: 0.), point!(x: 6., y: 6.)))
+        );
+        assert_eq!(
+            b.farthest_points(&a),
+            Some((point!(x: 6., y: 6.), point!(x: 0., y: 0.)))
+        );
+        assert_eq!(a.max_distance(&a), Some(2f64.sqrt()));
+    }
+
+    #[test]
+    fn collinear() {
+        let line_string =
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.), (x: 2., y: 2.)];
+        assert_eq!(line_string.max_distance(&line_string), Some(18f64.sqrt()));
+        assert_eq!(
+            line_string.max_distance(&point!(x: 1., y: 1.)),
+            Some(8f64.sqrt())
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let empty: MultiPoint = wkt!(MULTIPOINT EMPTY);
+        let point = point!(x: 1., y: 1.);
+        assert_eq!(empty.max_distance(&point), None);
+        assert_eq!(point.max_distance(&empty), None);
+        assert_eq!(point.farthest_points(&empty), None);
+        assert_eq!(point.max_distance(&point), Some(0.));
+    }
+}
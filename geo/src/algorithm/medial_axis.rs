@@ -0,0 +1,360 @@
+use spade::{DelaunayTriangulation, Point2, Triangulation};
+
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::triangulate_spade::SpadeTriangulationFloat;
+use crate::{Coord, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// Returns an approximation of the [medial axis](https://en.wikipedia.org/wiki/Medial_axis)
+/// of a polygon, its skeleton of points with more than one closest point on the boundary.
+///
+/// The boundary is densified so that no two consecutive points are more than `max_spacing`
+/// apart, and the medial axis is approximated by the edges of the Voronoi diagram of these
+/// points which lie inside the polygon. Voronoi edges between points which are adjacent on the
+/// boundary run towards the boundary rather than along the axis, and are omitted. Smaller
+/// spacings give a more accurate axis, at the cost of more points to triangulate.
+///
+/// Every convex vertex of the boundary, and any noise in it, adds a branch to the medial axis.
+/// Branches from a leaf of the skeleton to a junction which are shorter than `prune_length` are
+/// pruned, shortest first and repeatedly, but the last branch at a junction is always kept, so
+/// that pruning never removes the whole skeleton. A `prune_length` of zero keeps every branch.
+///
+/// Each `LineString` of the result runs between two leaves or junctions of the skeleton, or
+/// around a cycle of it, which surrounds a hole. This is the usual starting point for the
+/// centerline of a river, or the width of a corridor.
+///
+/// # Panics
+///
+/// Panics if `max_spacing` isn't positive.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Euclidean, Length, MedialAxis, Polygon};
+///
+/// // A long, 2 unit wide corridor
+/// let corridor: Polygon = wkt!(POLYGON((0. 0.,20. 0.,20. 2.,0. 2.,0. 0.)));
+///
+/// let skeleton = corridor.medial_axis(0.1, 2.);
+/// assert_eq!(skeleton.0.len(), 1);
+///
+/// // The centerline, which stops short of the ends of the corridor by about half its width
+/// let centerline = &skeleton.0[0];
+/// assert!(centerline.coords().all(|coord| (coord.y - 1.).abs() < 1e-6));
+/// assert!((centerline.length(&Euclidean) - 18.).abs() < 0.2);
+/// ```
+pub trait MedialAxis<T: SpadeTriangulationFloat> {
+    fn medial_axis(&self, max_spacing: T, prune_length: T) -> MultiLineString<T>;
+}
+
+impl<T: SpadeTriangulationFloat> MedialAxis<T> for Polygon<T> {
+    fn medial_axis(&self, max_spacing: T, prune_length: T) -> MultiLineString<T> {
+        MultiLineString::new(polygon_medial_axis(self, max_spacing, prune_length))
+    }
+}
+
+impl<T: SpadeTriangulationFloat> MedialAxis<T> for MultiPolygon<T> {
+    fn medial_axis(&self, max_spacing: T, prune_length: T) -> MultiLineString<T> {
+        self.iter()
+            .flat_map(|polygon| polygon_medial_axis(polygon, max_spacing, prune_length))
+            .collect()
+    }
+}
+
+fn polygon_medial_axis<T: SpadeTriangulationFloat>(
+    polygon: &Polygon<T>,
+    max_spacing: T,
+    prune_length: T,
+) -> Vec<LineString<T>> {
+    assert!(max_spacing > T::zero());
+
+    // The ring of each vertex of the triangulation, and its position and the number of points
+    // along the densified ring
+    let mut triangulation = DelaunayTriangulation::<Point2<T>>::new();
+    let mut boundary_positions = Vec::new();
+    for (ring_idx, ring) in std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .enumerate()
+    {
+        let points = densify_ring(ring, max_spacing);
+        for (position, coord) in points.iter().enumerate() {
+            let Ok(vertex) = triangulation.insert(Point2::new(coord.x, coord.y)) else {
+                continue;
+            };
+            let idx = vertex.index();
+            if idx >= boundary_positions.len() {
+                boundary_positions.resize(idx + 1, (0, 0, 0));
+            }
+            boundary_positions[idx] = (ring_idx, position, points.len());
+        }
+    }
+    let adjacent_on_boundary = |a: usize, b: usize| {
+        let (a_ring, a_position, len) = boundary_positions[a];
+        let (b_ring, b_position, _) = boundary_positions[b];
+        let distance = a_position.abs_diff(b_position);
+        a_ring == b_ring && (distance == 1 || distance == len - 1)
+    };
+
+    // The Voronoi vertices are the circumcenters of the triangles
+    let faces: Vec<_> = triangulation.inner_faces().collect();
+    let circumcenters: Vec<Coord<T>> = faces
+        .iter()
+        .map(|face| {
+            let center = face.circumcenter();
+            Coord {
+                x: center.x,
+                y: center.y,
+            }
+        })
+        .collect();
+    let mut node_of_face = vec![None; triangulation.num_all_faces()];
+    for (position, (face, _)) in faces
+        .iter()
+        .zip(polygon.coordinate_positions(&circumcenters))
+        .enumerate()
+        .filter(|(_, (_, position))| *position == CoordPos::Inside)
+    {
+        node_of_face[face.fix().index()] = Some(position);
+    }
+
+    let mut skeleton = Skeleton::new(circumcenters);
+    for edge in triangulation.undirected_edges() {
+        let [from, to] = edge.vertices();
+        if adjacent_on_boundary(from.fix().index(), to.fix().index()) {
+            continue;
+        }
+        let edge = edge.as_directed();
+        let left = node_of_face[edge.face().fix().index()];
+        let right = node_of_face[edge.rev().face().fix().index()];
+        if let (Some(a), Some(b)) = (left, right) {
+            skeleton.add_edge(a, b);
+        }
+    }
+    skeleton.prune(prune_length);
+    skeleton.into_line_strings()
+}
+
+/// The points along a ring, without repeating the first point, such that no two consecutive
+/// points are more than `max_spacing` apart.
+fn densify_ring<T: SpadeTriangulationFloat>(ring: &LineString<T>, max_spacing: T) -> Vec<Coord<T>> {
+    let mut points = Vec::new();
+    for line in ring.lines() {
+        let length = line.delta().x.hypot(line.delta().y);
+        let segments = (length / max_spacing)
+            .ceil()
+            .to_usize()
+            .expect("unreasonable number of segments")
+            .max(1);
+        let segments_t =
+            <T as num_traits::NumCast>::from(segments).expect("unreasonable number of segments");
+        points.extend((0..segments).map(|idx| {
+            let ratio = <T as num_traits::NumCast>::from(idx)
+                .expect("unreasonable number of segments")
+                / segments_t;
+            line.start + line.delta() * ratio
+        }));
+    }
+    points
+}
+
+/// An undirected graph of the Voronoi edges inside the polygon.
+struct Skeleton<T: SpadeTriangulationFloat> {
+    coords: Vec<Coord<T>>,
+    // The neighbours of each node, and the index of the edge to them
+    neighbours: Vec<Vec<(usize, usize)>>,
+    edges: Vec<(usize, usize)>,
+    removed: Vec<bool>,
+}
+
+impl<T: SpadeTriangulationFloat> Skeleton<T> {
+    fn new(coords: Vec<Coord<T>>) -> Self {
+        Self {
+            neighbours: vec![Vec::new(); coords.len()],
+            coords,
+            edges: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    fn add_edge(&mut self, a: usize, b: usize) {
+        let edge_idx = self.edges.len();
+        self.edges.push((a, b));
+        self.removed.push(false);
+        self.neighbours[a].push((b, edge_idx));
+        self.neighbours[b].push((a, edge_idx));
+    }
+
+    fn live_neighbours(&self, node: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours[node]
+            .iter()
+            .copied()
+            .filter(|&(_, edge_idx)| !self.removed[edge_idx])
+    }
+
+    fn degree(&self, node: usize) -> usize {
+        self.live_neighbours(node).count()
+    }
+
+    fn edge_length(&self, edge_idx: usize) -> T {
+        let (a, b) = self.edges[edge_idx];
+        let delta = self.coords[b] - self.coords[a];
+        delta.x.hypot(delta.y)
+    }
+
+    /// Walk from `start` along the edge `edge_idx`, and on through nodes of degree 2 until
+    /// reaching another node or returning to `start`, returning the edges walked and the node the
+    /// walk stopped at.
+    fn walk(&self, start: usize, mut edge_idx: usize) -> (Vec<usize>, usize) {
+        let mut edges = vec![edge_idx];
+        let mut node = start;
+        loop {
+            let (a, b) = self.edges[edge_idx];
+            node = if a == node { b } else { a };
+            if node == start || self.degree(node) != 2 {
+                return (edges, node);
+            }
+            let Some((_, next_edge_idx)) = self
+                .live_neighbours(node)
+                .find(|&(_, next_edge_idx)| next_edge_idx != edge_idx)
+            else {
+                return (edges, node);
+            };
+            edge_idx = next_edge_idx;
+            edges.push(edge_idx);
+        }
+    }
+
+    /// Repeatedly remove the branches from a leaf to a junction which are shorter than
+    /// `prune_length`, shortest first, while the junction has another branch.
+    fn prune(&mut self, prune_length: T) {
+        loop {
+            let mut branches = Vec::new();
+            for leaf in 0..self.coords.len() {
+                let mut neighbours = self.live_neighbours(leaf);
+                let (Some((_, edge_idx)), None) = (neighbours.next(), neighbours.next()) else {
+                    continue;
+                };
+                let (edges, junction) = self.walk(leaf, edge_idx);
+                let length = edges.iter().fold(T::zero(), |length, &edge_idx| {
+                    length + self.edge_length(edge_idx)
+                });
+                if length < prune_length {
+                    branches.push((length, edges, junction));
+                }
+            }
+            branches.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut pruned = false;
+            for (_, edges, junction) in branches {
+                if self.degree(junction) < 2 || edges.iter().any(|&edge| self.removed[edge]) {
+                    continue;
+                }
+                for edge_idx in edges {
+                    self.removed[edge_idx] = true;
+                }
+                pruned = true;
+            }
+            if !pruned {
+                return;
+            }
+        }
+    }
+
+    /// The paths between leaves and junctions, followed by any remaining cycles.
+    fn into_line_strings(self) -> Vec<LineString<T>> {
+        let mut visited = self.removed.clone();
+        let mut line_strings = Vec::new();
+        let mut add_path = |start: usize, edge_idx: usize, visited: &mut Vec<bool>| {
+            let (edges, _) = self.walk(start, edge_idx);
+            let mut coords = vec![self.coords[start]];
+            let mut node = start;
+            for edge_idx in edges {
+                if visited[edge_idx] {
+                    break;
+                }
+                visited[edge_idx] = true;
+                let (a, b) = self.edges[edge_idx];
+                node = if a == node { b } else { a };
+                coords.push(self.coords[node]);
+            }
+            if coords.len() > 1 {
+                line_strings.push(LineString::new(coords));
+            }
+        };
+
+        for node in 0..self.coords.len() {
+            if self.degree(node) == 2 {
+                continue;
+            }
+            for (_, edge_idx) in self.live_neighbours(node) {
+                if !visited[edge_idx] {
+                    add_path(node, edge_idx, &mut visited);
+                }
+            }
+        }
+        for edge_idx in 0..self.edges.len() {
+            if !visited[edge_idx] {
+                add_path(self.edges[edge_idx].0, edge_idx, &mut visited);
+            }
+        }
+        line_strings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{wkt, Contains, Euclidean, Length};
+
+    #[test]
+    fn corridor_centerline() {
+        let corridor: Polygon = wkt!(POLYGON((0. 0.,20. 0.,20. 2.,0. 2.,0. 0.)));
+
+        // without pruning, the axis forks towards each corner
+        let skeleton = corridor.medial_axis(0.25, 0.);
+        assert_eq!(skeleton.0.len(), 5);
+        for line_string in &skeleton {
+            assert!(line_string.coords().all(|coord| corridor.contains(coord)));
+        }
+
+        let skeleton = corridor.medial_axis(0.25, 2.);
+        assert_eq!(skeleton.0.len(), 1);
+        let centerline = &skeleton.0[0];
+        assert!(centerline.coords().all(|coord| (coord.y - 1.).abs() < 1e-9));
+        assert!((centerline.length(&Euclidean) - 18.).abs() < 0.5);
+    }
+
+    #[test]
+    fn branches() {
+        // a T junction, whose branches towards the corners are pruned
+        let tee = wkt!(POLYGON((0. 0.,10. 0.,10. 2.,6. 2.,6. 10.,4. 10.,4. 2.,0. 2.,0. 0.)));
+        let skeleton = tee.medial_axis(0.1, 1.5);
+        assert_eq!(skeleton.0.len(), 3);
+        let length: f64 = skeleton.iter().map(|line| line.length(&Euclidean)).sum();
+        assert!((length - 16.).abs() < 0.5, "{length}");
+
+        // the axis around a hole is a cycle, which is rounded near the corners of the hole
+        let ring: Polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (2. 2.,2. 8.,8. 8.,8. 2.,2. 2.)
+        ));
+        let skeleton = ring.medial_axis(0.1, 3.);
+        assert_eq!(skeleton.0.len(), 1);
+        assert!(skeleton.0[0].is_closed());
+        let length = skeleton.0[0].length(&Euclidean);
+        assert!(length > 30. && length < 32., "{length}");
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,10. 0.,10. 2.,0. 2.,0. 0.)),
+            ((0. 5.,10. 5.,10. 7.,0. 7.,0. 5.))
+        ));
+        let skeleton = multi_polygon.medial_axis(0.2, 2.);
+        assert_eq!(skeleton.0.len(), 2);
+        assert!(MultiPolygon::<f64>::new(vec![])
+            .medial_axis(0.2, 2.)
+            .0
+            .is_empty());
+    }
+}
@@ -2,7 +2,7 @@ use num_traits::Float;
 
 use crate::{
     algorithm::{centroid::Centroid, rotate::Rotate, BoundingRect, CoordsIter},
-    Area, ConvexHull, CoordFloat, GeoFloat, GeoNum, LinesIter, Polygon,
+    Area, ConvexHull, CoordFloat, CoordNum, GeoFloat, GeoNum, LinesIter, Point, Polygon, Rect,
 };
 /// Return the minimum bounding rectangle(MBR) of geometry
 /// reference: <https://en.wikipedia.org/wiki/Minimum_bounding_box>
@@ -31,6 +31,43 @@ use crate::{
 pub trait MinimumRotatedRect<T> {
     type Scalar: GeoNum;
     fn minimum_rotated_rect(&self) -> Option<Polygon<Self::Scalar>>;
+
+    /// Return the minimum rotated rect along with its center, side lengths, and orientation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, polygon, MinimumRotatedRect, Rotate};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // A 4 x 2 rectangle, rotated by 30°
+    /// let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)]
+    ///     .rotate_around_point(30., point!(x: 2., y: 1.));
+    /// let rect = poly.oriented_minimum_rect().unwrap();
+    ///
+    /// assert_relative_eq!(rect.center, point!(x: 2., y: 1.), epsilon = 1e-9);
+    /// assert_relative_eq!(rect.length, 4., epsilon = 1e-9);
+    /// assert_relative_eq!(rect.width, 2., epsilon = 1e-9);
+    /// assert_relative_eq!(rect.angle, 30., epsilon = 1e-9);
+    /// assert_eq!(Some(rect.polygon), poly.minimum_rotated_rect());
+    /// ```
+    fn oriented_minimum_rect(&self) -> Option<OrientedRect<Self::Scalar>>;
+}
+
+/// A [minimum rotated rect](MinimumRotatedRect), along with its dimensions and orientation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrientedRect<T: CoordNum> {
+    /// The rectangle, as returned by [`MinimumRotatedRect::minimum_rotated_rect`].
+    pub polygon: Polygon<T>,
+    /// The center of the rectangle.
+    pub center: Point<T>,
+    /// The length of the longer sides of the rectangle.
+    pub length: T,
+    /// The length of the shorter sides of the rectangle.
+    pub width: T,
+    /// The angle of the longer sides of the rectangle, in degrees counter-clockwise from the
+    /// x-axis, in the range (-90, 90].
+    pub angle: T,
 }
 
 impl<T, G> MinimumRotatedRect<T> for G
@@ -41,25 +78,64 @@ where
     type Scalar = T;
 
     fn minimum_rotated_rect(&self) -> Option<Polygon<Self::Scalar>> {
-        let convex_poly = ConvexHull::convex_hull(self);
-        let mut min_area: T = Float::max_value();
-        let mut min_angle: T = T::zero();
-        let mut rect_poly: Option<Polygon<T>> = None;
-        let rotate_point = convex_poly.centroid();
-        for line in convex_poly.exterior().lines_iter() {
-            let (ci, cii) = line.points();
-            let angle = (cii.y() - ci.y()).atan2(cii.x() - ci.x()).to_degrees();
-            let rotated_poly = Rotate::rotate_around_point(&convex_poly, -angle, rotate_point?);
-            let tmp_poly = rotated_poly.bounding_rect()?.to_polygon();
-            let area = tmp_poly.unsigned_area();
-            if area < min_area {
-                min_area = area;
-                min_angle = angle;
-                rect_poly = Some(tmp_poly);
-            }
+        let (rect, angle, rotate_point) = minimum_rotated_bounding_rect(self)?;
+        Some(rect.to_polygon().rotate_around_point(angle, rotate_point))
+    }
+
+    fn oriented_minimum_rect(&self) -> Option<OrientedRect<Self::Scalar>> {
+        let (rect, angle, rotate_point) = minimum_rotated_bounding_rect(self)?;
+        let polygon = rect.to_polygon().rotate_around_point(angle, rotate_point);
+        let center = Point::from(rect.center()).rotate_around_point(angle, rotate_point);
+
+        // The sides of `rect` along its x-axis are at `angle`
+        let (length, width, mut angle) = if rect.width() >= rect.height() {
+            (rect.width(), rect.height(), angle)
+        } else {
+            (rect.height(), rect.width(), angle + T::from(90).unwrap())
+        };
+        let half_turn = T::from(180).unwrap();
+        while angle > half_turn / T::from(2).unwrap() {
+            angle = angle - half_turn;
+        }
+        while angle <= -half_turn / T::from(2).unwrap() {
+            angle = angle + half_turn;
+        }
+
+        Some(OrientedRect {
+            polygon,
+            center,
+            length,
+            width,
+            angle,
+        })
+    }
+}
+
+// The axis-aligned bounding rect of the geometry's convex hull after rotating it by `-angle`
+// degrees around `rotate_point`, for the `angle` giving the smallest such rect.
+fn minimum_rotated_bounding_rect<T, G>(geometry: &G) -> Option<(Rect<T>, T, Point<T>)>
+where
+    T: CoordFloat + GeoFloat + GeoNum,
+    G: CoordsIter<Scalar = T>,
+{
+    let convex_poly = ConvexHull::convex_hull(geometry);
+    let mut min_area: T = Float::max_value();
+    let mut min_angle: T = T::zero();
+    let mut min_rect: Option<Rect<T>> = None;
+    let rotate_point = convex_poly.centroid();
+    for line in convex_poly.exterior().lines_iter() {
+        let (ci, cii) = line.points();
+        let angle = (cii.y() - ci.y()).atan2(cii.x() - ci.x()).to_degrees();
+        let rotated_poly = Rotate::rotate_around_point(&convex_poly, -angle, rotate_point?);
+        let tmp_rect = rotated_poly.bounding_rect()?;
+        let area = tmp_rect.to_polygon().unsigned_area();
+        if area < min_area {
+            min_area = area;
+            min_angle = angle;
+            min_rect = Some(tmp_rect);
         }
-        Some(rect_poly?.rotate_around_point(min_angle, rotate_point?))
     }
+    Some((min_rect?, min_angle, rotate_point?))
 }
 
 #[cfg(test)]
@@ -98,4 +174,43 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn oriented_minimum_rect() {
+        use crate::{point, Distance, Euclidean};
+
+        let poly: Polygon<f64> = polygon![(x: 3.3, y: 30.4), (x: 1.7, y: 24.6), (x: 13.4, y: 25.1), (x: 14.4, y: 31.0),(x:3.3,y:30.4)];
+        let rect = poly.oriented_minimum_rect().unwrap();
+        assert_eq!(Some(rect.polygon.clone()), poly.minimum_rotated_rect());
+
+        let corners: Vec<_> = rect.polygon.exterior().points().collect();
+        let sides = [
+            Euclidean.distance(corners[0], corners[1]),
+            Euclidean.distance(corners[1], corners[2]),
+        ];
+        approx::assert_relative_eq!(rect.length, sides[0].max(sides[1]), epsilon = 1e-9);
+        approx::assert_relative_eq!(rect.width, sides[0].min(sides[1]), epsilon = 1e-9);
+        approx::assert_relative_eq!(
+            rect.center,
+            point!(x: (corners[0].x() + corners[2].x()) / 2., y: (corners[0].y() + corners[2].y()) / 2.),
+            epsilon = 1e-9
+        );
+        // The long side runs from corner 1 to corner 2
+        let long_side = corners[2] - corners[1];
+        approx::assert_relative_eq!(
+            rect.angle,
+            long_side.y().atan2(long_side.x()).to_degrees(),
+            epsilon = 1e-9
+        );
+
+        // Axis-aligned, with the long axis vertical
+        let poly: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 3.), (x: 0., y: 3.)];
+        let rect = poly.oriented_minimum_rect().unwrap();
+        assert_eq!((rect.length, rect.width, rect.angle), (3., 1., 90.));
+
+        assert!(LineString::<f64>::new(vec![])
+            .oriented_minimum_rect()
+            .is_none());
+    }
 }
@@ -2,13 +2,46 @@
 pub mod kernels;
 pub use kernels::{Kernel, Orientation};
 
+/// Calculate the alpha shape of a geometry's coordinates from their Delaunay triangulation.
+#[cfg(feature = "spade")]
+pub mod alpha_shape;
+#[cfg(feature = "spade")]
+pub use alpha_shape::AlphaShape;
+
+/// Assemble polygons from rings without shell and hole information.
+pub mod assemble_polygons;
+pub use assemble_polygons::assemble_polygons;
+
 /// Calculate the area of the surface of a `Geometry`.
 pub mod area;
 pub use area::Area;
 
+/// Accumulate the area, bounding rectangle, centroid or convex hull of a stream of geometries or points.
+pub mod accumulators;
+pub use accumulators::{
+    AreaAccumulator, BboxAccumulator, CentroidAccumulator, ConvexHullAccumulator,
+};
+
 /// Boolean Operations such as the union, xor, or difference of two geometries.
 pub mod bool_ops;
-pub use bool_ops::{unary_union, BooleanOps, OpType};
+pub use bool_ops::{
+    dissolve_by_key, unary_union, unary_union_cancellable, unary_union_with_progress,
+    union_overlay, BooleanOps, OpType, OverlayFace,
+};
+#[cfg(feature = "multithreading")]
+pub use bool_ops::{dissolve_by_key_parallel, unary_union_parallel};
+
+/// Cache the area, length, bounding rectangle and centroid of a geometry.
+pub mod cached_metrics;
+pub use cached_metrics::CachedMetrics;
+
+/// Cooperative cancellation of long-running algorithms.
+pub mod cancellation;
+pub use cancellation::{CancellationToken, Cancelled};
+
+/// Encode geometries as deterministic, normalized bytes, e.g. for cache keys.
+pub mod canonical_bytes;
+pub use canonical_bytes::{CanonicalBytes, CANONICAL_BYTES_VERSION};
 
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
@@ -16,11 +49,11 @@ pub use bounding_rect::BoundingRect;
 
 /// Calculate the minimum rotated rectangle of a `Geometry`.
 pub mod minimum_rotated_rect;
-pub use minimum_rotated_rect::MinimumRotatedRect;
+pub use minimum_rotated_rect::{MinimumRotatedRect, OrientedRect};
 
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
-pub use centroid::Centroid;
+pub use centroid::{Centroid, WeightedCentroid};
 
 /// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
 pub mod chaikin_smoothing;
@@ -30,21 +63,39 @@ pub use chaikin_smoothing::ChaikinSmoothing;
 pub mod chamberlain_duquette_area;
 pub use chamberlain_duquette_area::ChamberlainDuquetteArea;
 
+/// Clip a geometry to a circle around a point.
+pub mod clip_to_circle;
+pub use clip_to_circle::ClipToCircle;
+
 /// Calculate the closest `Point` between a `Geometry` and an input `Point`.
 pub mod closest_point;
 pub use closest_point::ClosestPoint;
 
+/// Collapse degenerate geometries into points and lines.
+pub mod coerce_degenerate;
+pub use coerce_degenerate::CoerceDegenerate;
+
 /// Calculate the concave hull of a `Geometry`.
 pub mod concave_hull;
-pub use concave_hull::ConcaveHull;
+pub use concave_hull::{ConcaveHull, ConcaveHullParams};
 
 /// Determine whether `Geometry` `A` completely encloses `Geometry` `B`.
 pub mod contains;
 pub use contains::Contains;
 
+/// Find which cells of a raster grid have their center inside a polygon.
+pub mod containment_mask;
+pub use containment_mask::{ContainmentMask, GridMask};
+
 /// Convert the type of a geometry’s coordinate value.
 pub mod convert;
-pub use convert::{Convert, TryConvert};
+pub use convert::{
+    Convert, ConvertError, ConvertErrorKind, RoundingPolicy, TryConvert, TryConvertWith,
+};
+
+/// Compute algorithms on `f32` geometries internally in `f64`.
+pub mod promote;
+pub use promote::{PromotedArea, PromotedBooleanOps};
 
 /// Convert coordinate angle units between radians and degrees.
 pub mod convert_angle_unit;
@@ -52,7 +103,7 @@ pub use convert_angle_unit::{ToDegrees, ToRadians};
 
 /// Calculate the convex hull of a `Geometry`.
 pub mod convex_hull;
-pub use convex_hull::ConvexHull;
+pub use convex_hull::{ConvexHull, ConvexHullStrategy};
 
 /// Cross track distance
 pub mod cross_track_distance;
@@ -75,6 +126,18 @@ pub use densify_haversine::DensifyHaversine;
 pub mod dimensions;
 pub use dimensions::HasDimensions;
 
+/// Cover geometries with the cells of discrete global grids, such as geohash or H3.
+pub mod discrete_grid;
+pub use discrete_grid::{CellCover, CellCoverError};
+
+/// A precision policy of absolute, relative and ULP tolerances.
+pub mod epsilon;
+pub use epsilon::Epsilon;
+
+/// Determine whether two geometries are topologically equal.
+pub mod equals;
+pub use equals::Equals;
+
 /// Calculate the minimum Euclidean distance between two `Geometries`.
 pub mod euclidean_distance;
 #[allow(deprecated)]
@@ -87,7 +150,7 @@ pub use euclidean_length::EuclideanLength;
 
 /// Calculate the extreme coordinates and indices of a geometry.
 pub mod extremes;
-pub use extremes::Extremes;
+pub use extremes::{ExtentSummary, Extremes};
 
 /// Calculate the Frechet distance between two `LineStrings`.
 pub mod frechet_distance;
@@ -121,6 +184,22 @@ pub mod geodesic_length;
 #[allow(deprecated)]
 pub use geodesic_length::GeodesicLength;
 
+/// Convert geometries to and from GeoJSON text (requires the `use-geojson` feature).
+#[cfg(feature = "use-geojson")]
+pub mod geojson_conv;
+#[cfg(feature = "use-geojson")]
+pub use geojson_conv::ToGeoJson;
+
+/// Encode and decode geohashes.
+pub mod geohash;
+pub use geohash::{Geohash, GeohashCells};
+
+/// Calculate the bounding rectangle and area of any `geo-traits` geometry from its coordinates.
+pub mod geometry_trait_ext;
+
+/// Generate square, hexagonal and triangular grids over an extent.
+pub mod grid;
+
 /// Calculate the Hausdorff distance between two geometries.
 pub mod hausdorff_distance;
 pub use hausdorff_distance::HausdorffDistance;
@@ -154,22 +233,42 @@ pub use haversine_length::HaversineLength;
 pub mod haversine_closest_point;
 pub use haversine_closest_point::HaversineClosestPoint;
 
+/// Find the nearest segment of a `MultiLineString` whose direction is close to a heading.
+pub mod heading_index;
+pub use heading_index::{HeadingIndex, HeadingMatch};
+
+/// Index the segments of a geometry for repeated distance queries.
+pub mod indexed_facet_distance;
+pub use indexed_facet_distance::IndexedFacetDistance;
+
 /// Calculate a representative `Point` inside a `Geometry`
 pub mod interior_point;
 pub use interior_point::InteriorPoint;
 
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
 pub mod intersects;
-pub use intersects::Intersects;
+pub use intersects::{bbox_filtered, has_disjoint_bboxes, Intersects};
 
 /// Determines whether a `LineString` is convex.
 pub mod is_convex;
 pub use is_convex::IsConvex;
 
+/// Determine whether a geometry is simple, or a `LineString` is a ring.
+pub mod is_simple;
+pub use is_simple::{IsRing, IsSimple};
+
 /// Calculate concave hull using k-nearest algorithm
 pub mod k_nearest_concave_hull;
 pub use k_nearest_concave_hull::KNearestConcaveHull;
 
+/// Summary statistics of a single geometry: counts, segment lengths, area and extent.
+pub mod geometry_stats;
+pub use geometry_stats::{GeometryStatistics, GeometryStats};
+
+/// Summary statistics of a collection of geometries.
+pub mod layer_summary;
+pub use layer_summary::{GeometryTypeCounts, LayerSummary, ValidityTally};
+
 /// Interpolate a point along a `Line` or `LineString`.
 pub mod line_interpolate_point;
 pub use line_interpolate_point::LineInterpolatePoint;
@@ -184,10 +283,12 @@ pub use line_locate_point::LineLocatePoint;
 
 /// Iterate over the lines in a geometry.
 pub mod lines_iter;
-pub use lines_iter::LinesIter;
+pub use lines_iter::{LinesIter, SegmentPosition};
 
 pub mod line_measures;
-pub use line_measures::metric_spaces::{Euclidean, Geodesic, Haversine, Rhumb};
+pub use line_measures::metric_spaces::{
+    Ellipsoid, Euclidean, Geodesic, GeodesicMeasure, Haversine, HaversineMeasure, Rhumb,
+};
 pub use line_measures::{Bearing, Densify, Destination, Distance, InterpolatePoint, Length};
 
 /// Split a LineString into n segments
@@ -196,11 +297,44 @@ pub use linestring_segment::{LineStringSegmentize, LineStringSegmentizeHaversine
 
 /// Apply a function to all `Coord`s of a `Geometry`.
 pub mod map_coords;
-pub use map_coords::{MapCoords, MapCoordsInPlace};
+pub use map_coords::{CoordContext, MapCoords, MapCoordsInPlace, MapCoordsIndexed};
+
+/// Calculate the maximum distance between two geometries, and their farthest points.
+pub mod max_distance;
+pub use max_distance::MaxDistance;
+
+/// Approximate the medial axis of a polygon from the Voronoi diagram of its boundary.
+#[cfg(feature = "spade")]
+pub mod medial_axis;
+#[cfg(feature = "spade")]
+pub use medial_axis::MedialAxis;
+
+/// Find the pair of nearest points between two geometries.
+pub mod nearest_points;
+pub use nearest_points::NearestPoints;
+
+/// Map geometries into a well-conditioned local frame for computation.
+pub mod normalize;
+pub use normalize::{
+    normalize_for_computation, ComputationFrame, NormalizedBooleanOps, NormalizedRelate,
+};
+
+/// Put geometries into a normal form, so that semantically identical geometries compare equal.
+pub mod normalize_geometry;
+pub use normalize_geometry::Normalize;
+
+/// Parallel versions of algorithms on collections of geometries (requires the `multithreading` feature).
+#[cfg(feature = "multithreading")]
+pub mod parallel;
+#[cfg(feature = "multithreading")]
+pub use parallel::{
+    ParallelArea, ParallelBoundingRect, ParallelDensify, ParallelLength, ParallelSimplify,
+    ParallelValidation,
+};
 
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
-pub use orient::Orient;
+pub use orient::{Orient, OrientInPlace};
 
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
@@ -212,7 +346,11 @@ pub use relate::Relate;
 
 /// Remove (consecutive) repeated points
 pub mod remove_repeated_points;
-pub use remove_repeated_points::RemoveRepeatedPoints;
+pub use remove_repeated_points::{RemoveRepeatedPoints, RemoveRepeatedPointsWithin};
+
+/// Remove spikes and gores from the rings of polygons.
+pub mod remove_spikes;
+pub use remove_spikes::RemoveSpikes;
 
 /// Rotate a `Geometry` by an angle given in degrees.
 pub mod rotate;
@@ -220,7 +358,7 @@ pub use rotate::Rotate;
 
 /// Scale a `Geometry` up or down by a factor
 pub mod scale;
-pub use scale::Scale;
+pub use scale::{Scale, ScaleOrigin};
 
 /// Skew a `Geometry` by shearing it at angles along the x and y dimensions
 pub mod skew;
@@ -228,15 +366,34 @@ pub use skew::Skew;
 
 /// Composable affine operations such as rotate, scale, skew, and translate
 pub mod affine_ops;
-pub use affine_ops::{AffineOps, AffineTransform};
+pub use affine_ops::{AffineOps, AffineTransform, TransformBuilder};
+
+/// Compose coordinate transforms chosen at runtime into a single pipeline
+pub mod coord_transform;
+pub use coord_transform::{CoordTransform, DynCoordTransform};
 
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
-pub use simplify::{Simplify, SimplifyIdx};
+pub use simplify::{
+    simplify_chunked, simplify_line_string_idx, Simplify, SimplifyChunked, SimplifyIdx,
+    SimplifyInto, SimplifyToNPoints,
+};
 
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
 pub mod simplify_vw;
-pub use simplify_vw::{SimplifyVw, SimplifyVwIdx, SimplifyVwPreserve};
+pub use simplify_vw::{SimplifyVw, SimplifyVwIdx, SimplifyVwPreserve, SimplifyVwToNPoints};
+
+/// Split a lon/lat geometry into nearly planar cells with their spherical areas.
+pub mod spherical_cells;
+pub use spherical_cells::{SphericalCell, SphericalCells};
+
+/// Assign points to the polygons containing them.
+pub mod spatial_join;
+pub use spatial_join::{areal_interpolate, areal_interpolate_weighted, points_in_polygons};
+
+/// Determine whether a lon/lat `Polygon` contains a `Point` on the sphere.
+pub mod spherical_contains;
+pub use spherical_contains::SphericalContains;
 
 /// Stitch together triangles with adjacent sides. Alternative to unioning triangles via BooleanOps.
 #[allow(dead_code)]
@@ -267,6 +424,12 @@ pub mod triangulate_spade;
 #[cfg(feature = "spade")]
 pub use triangulate_spade::TriangulateSpade;
 
+/// Tolerance-based `Contains`, `Intersects` and `Within` predicates for noisy data.
+pub mod tolerant_predicates;
+pub use tolerant_predicates::{
+    ContainsWithTolerance, IntersectsWithTolerance, WithinWithTolerance,
+};
+
 /// Vector Operations for 2D coordinates
 mod vector_ops;
 pub use vector_ops::Vector2DOps;
@@ -279,6 +442,12 @@ pub use vincenty_distance::VincentyDistance;
 pub mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Measure the width of an elongated polygon along its centerline.
+#[cfg(feature = "spade")]
+pub mod width_profile;
+#[cfg(feature = "spade")]
+pub use width_profile::WidthProfile;
+
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
 pub use winding_order::Winding;
@@ -287,17 +456,31 @@ pub use winding_order::Winding;
 pub mod within;
 pub use within::Within;
 
+/// Read and write geometries as Well-Known Binary (WKB) and PostGIS Extended WKB.
+pub mod wkb_conv;
+pub use wkb_conv::ToWkb;
+
 /// Planar sweep algorithm and related utils
 pub mod sweep;
 
-/// Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor)
+/// Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor), kNN distances or an isolation forest.
 pub mod outlier_detection;
 
-pub use outlier_detection::OutlierDetection;
+pub use outlier_detection::{OutlierDetection, OutlierMethod, OutlierMetric};
+
+/// Cluster points by hierarchical agglomerative clustering.
+pub mod hierarchical_clustering;
+pub use hierarchical_clustering::{ClusterCut, Dendrogram, HierarchicalClustering, Linkage};
+
+/// Decompose a `LineString` into monotone chains, which speed up spatial predicates.
+pub mod monotone_chain;
+pub use monotone_chain::{IndexedIntersection, MonotoneChain, MonotoneChainIndex, MonotoneChains};
 
 /// Monotonic polygon subdivision
 pub mod monotone;
-pub use monotone::{monotone_subdivision, MonoPoly, MonotonicPolygons};
+pub use monotone::{
+    monotone_subdivision, monotone_subdivision_cancellable, MonoPoly, MonotonicPolygons,
+};
 
 /// Rhumb-line-related algorithms and utils
 pub mod rhumb;
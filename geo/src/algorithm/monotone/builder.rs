@@ -9,6 +9,7 @@
 
 use super::{MonoPoly, SimpleSweep};
 use crate::{
+    cancellation::{CancellationToken, Cancelled},
     sweep::{EventType, LineOrPoint, SweepPoint},
     *,
 };
@@ -28,6 +29,16 @@ pub fn monotone_subdivision<T: GeoNum, I: IntoIterator<Item = Polygon<T>>>(
     Builder::from_polygons_iter(iter).build()
 }
 
+/// Like [`monotone_subdivision`], but returns [`Cancelled`] if `token` is set.
+///
+/// `token` is checked before processing each point of the sweep.
+pub fn monotone_subdivision_cancellable<T: GeoNum, I: IntoIterator<Item = Polygon<T>>>(
+    iter: I,
+    token: &impl CancellationToken,
+) -> Result<Vec<MonoPoly<T>>, Cancelled> {
+    Builder::from_polygons_iter(iter).build_cancellable(token)
+}
+
 pub(super) struct Builder<T: GeoNum> {
     sweep: SimpleSweep<T, Info>,
     chains: Vec<Option<Chain<T>>>,
@@ -64,6 +75,17 @@ impl<T: GeoNum> Builder<T> {
         self.outputs
     }
 
+    pub fn build_cancellable(
+        mut self,
+        token: &impl CancellationToken,
+    ) -> Result<Vec<MonoPoly<T>>, Cancelled> {
+        while {
+            token.check_cancelled()?;
+            self.process_next_pt()
+        } {}
+        Ok(self.outputs)
+    }
+
     fn process_next_pt(&mut self) -> bool {
         // Step 1. Get all the incoming and outgoing segments at the next point,
         // and sort each of them by sweep ordering.
@@ -10,7 +10,7 @@ mod sweep;
 pub(crate) use sweep::SimpleSweep;
 
 mod builder;
-pub use builder::monotone_subdivision;
+pub use builder::{monotone_subdivision, monotone_subdivision_cancellable};
 
 /// A multi-polygon represented as a collection of (disjoint) monotone polygons.
 ///
@@ -6,8 +6,11 @@ use num_traits::Signed;
 use wkt::{ToWkt, TryFromWkt};
 
 use crate::{
-    area::twice_signed_ring_area, coordinate_position::CoordPos, dimensions::Dimensions,
-    monotone::monotone_subdivision, GeoFloat, GeoNum, Relate,
+    area::twice_signed_ring_area,
+    coordinate_position::CoordPos,
+    dimensions::Dimensions,
+    monotone::{monotone_subdivision, monotone_subdivision_cancellable},
+    Cancelled, GeoFloat, GeoNum, Relate,
 };
 
 pub(super) fn init_log() {
@@ -112,3 +115,25 @@ fn test_tangent() {
     (60 140, 110 170, 110 100, 80 100, 60 140))";
     check_monotone_subdivision::<f64>(input);
 }
+
+#[test]
+fn test_cancellable() {
+    let input = Polygon::<f64>::try_from_wkt_str(
+        "POLYGON ((100 100, 200 150, 100 200, 200 250, 100 300, 400 300,
+       300 200, 400 100, 100 100))",
+    )
+    .unwrap();
+    let subdivisions = monotone_subdivision_cancellable([input.clone()], &|| false).unwrap();
+    assert_eq!(
+        subdivisions.len(),
+        monotone_subdivision([input.clone()]).len()
+    );
+
+    let checks = std::cell::Cell::new(0);
+    let result = monotone_subdivision_cancellable([input], &|| {
+        checks.set(checks.get() + 1);
+        checks.get() > 2
+    });
+    assert_eq!(result.unwrap_err(), Cancelled);
+    assert_eq!(checks.get(), 3);
+}
@@ -0,0 +1,563 @@
+use std::cmp::Ordering;
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, AABB};
+
+use crate::geometry::*;
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{GeoFloat, GeoNum, Intersects};
+
+/// `LineString`s with at least this many coordinates are decomposed into monotone chains by
+/// predicates which support them.
+pub(crate) const MIN_CHAINED_COORDS: usize = 32;
+
+/// A run of consecutive segments of a `LineString` along which both `x` and `y` are monotone,
+/// i.e. each is either non-decreasing or non-increasing.
+///
+/// Because of this, the bounding rectangle of any sub-run of a chain is the rectangle spanned by
+/// its first and last coordinates, so that the segments of a chain near a region can be found
+/// by bisection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonotoneChain<'a, T: GeoNum> {
+    coords: &'a [Coord<T>],
+    start_idx: usize,
+}
+
+impl<'a, T: GeoNum> MonotoneChain<'a, T> {
+    /// The chain through `coords`, which must be monotone in `x` and `y`, whose first coordinate
+    /// is at `start_idx` in its `LineString`.
+    pub(crate) fn from_parts(coords: &'a [Coord<T>], start_idx: usize) -> Self {
+        debug_assert!(coords.len() >= 2);
+        Self { coords, start_idx }
+    }
+
+    /// The coordinates of the chain, including the first and last. A chain has at least two
+    /// coordinates.
+    pub fn coords(&self) -> &'a [Coord<T>] {
+        self.coords
+    }
+
+    /// The index of the first coordinate of the chain in its `LineString`, which is also the
+    /// index of its first segment.
+    pub fn start_idx(&self) -> usize {
+        self.start_idx
+    }
+
+    /// The segments of the chain.
+    pub fn lines(&self) -> impl ExactSizeIterator<Item = Line<T>> + 'a {
+        self.coords.windows(2).map(|w| Line::new(w[0], w[1]))
+    }
+
+    /// The bounding rectangle of the chain.
+    pub fn envelope(&self) -> Rect<T> {
+        self.sub_envelope(0, self.coords.len() - 1)
+    }
+
+    // The bounding rectangle of the coords from `start` to `end` (inclusive)
+    fn sub_envelope(&self, start: usize, end: usize) -> Rect<T> {
+        Rect::new(self.coords[start], self.coords[end])
+    }
+
+    /// Whether any segment of this chain intersects any segment of `other`.
+    pub fn intersects(&self, other: &MonotoneChain<T>) -> bool {
+        self.overlap_intersects(0, self.coords.len() - 1, other, 0, other.coords.len() - 1)
+    }
+
+    // Bisect the longer of the two sub-chains until their envelopes are disjoint, or both are a
+    // single segment.
+    fn overlap_intersects(
+        &self,
+        start: usize,
+        end: usize,
+        other: &MonotoneChain<T>,
+        other_start: usize,
+        other_end: usize,
+    ) -> bool {
+        if envelopes_disjoint(
+            self.sub_envelope(start, end),
+            other.sub_envelope(other_start, other_end),
+        ) {
+            return false;
+        }
+
+        let len = end - start;
+        let other_len = other_end - other_start;
+        if len == 1 && other_len == 1 {
+            let line = Line::new(self.coords[start], self.coords[end]);
+            let other_line = Line::new(other.coords[other_start], other.coords[other_end]);
+            return line.intersects(&other_line);
+        }
+
+        if len >= other_len {
+            let mid = start + len / 2;
+            self.overlap_intersects(start, mid, other, other_start, other_end)
+                || self.overlap_intersects(mid, end, other, other_start, other_end)
+        } else {
+            let other_mid = other_start + other_len / 2;
+            self.overlap_intersects(start, end, other, other_start, other_mid)
+                || self.overlap_intersects(start, end, other, other_mid, other_end)
+        }
+    }
+}
+
+impl<T: GeoFloat> MonotoneChain<'_, T> {
+    /// The intersections between the segments of this chain and those of `other`, as tuples of
+    /// the indices of the two segments in their `LineString`s, and their intersection.
+    pub fn intersections(
+        &self,
+        other: &MonotoneChain<T>,
+    ) -> Vec<(usize, usize, LineIntersection<T>)> {
+        let mut intersections = Vec::new();
+        self.visit_candidates(other, &mut |segment_idx, other_segment_idx| {
+            let line = Line::new(
+                self.coords[segment_idx - self.start_idx],
+                self.coords[segment_idx - self.start_idx + 1],
+            );
+            let other_line = Line::new(
+                other.coords[other_segment_idx - other.start_idx],
+                other.coords[other_segment_idx - other.start_idx + 1],
+            );
+            if let Some(intersection) = line_intersection(line, other_line) {
+                intersections.push((segment_idx, other_segment_idx, intersection));
+            }
+        });
+        intersections
+    }
+}
+
+impl<T: GeoNum> MonotoneChain<'_, T> {
+    /// Visit the indices of every pair of a segment of this chain and a segment of `other`
+    /// whose bounding rectangles intersect, and so which might intersect.
+    pub(crate) fn visit_candidates(
+        &self,
+        other: &MonotoneChain<T>,
+        visit: &mut impl FnMut(usize, usize),
+    ) {
+        self.overlap_candidates(
+            0,
+            self.coords.len() - 1,
+            other,
+            0,
+            other.coords.len() - 1,
+            visit,
+        );
+    }
+
+    // As `overlap_intersects`, but visiting every pair of segments which might intersect
+    fn overlap_candidates(
+        &self,
+        start: usize,
+        end: usize,
+        other: &MonotoneChain<T>,
+        other_start: usize,
+        other_end: usize,
+        visit: &mut impl FnMut(usize, usize),
+    ) {
+        if envelopes_disjoint(
+            self.sub_envelope(start, end),
+            other.sub_envelope(other_start, other_end),
+        ) {
+            return;
+        }
+
+        let len = end - start;
+        let other_len = other_end - other_start;
+        if len == 1 && other_len == 1 {
+            visit(self.start_idx + start, other.start_idx + other_start);
+            return;
+        }
+
+        if len >= other_len {
+            let mid = start + len / 2;
+            self.overlap_candidates(start, mid, other, other_start, other_end, visit);
+            self.overlap_candidates(mid, end, other, other_start, other_end, visit);
+        } else {
+            let other_mid = other_start + other_len / 2;
+            self.overlap_candidates(start, end, other, other_start, other_mid, visit);
+            self.overlap_candidates(start, end, other, other_mid, other_end, visit);
+        }
+    }
+}
+
+/// The decomposition of a `LineString` into [`MonotoneChain`]s.
+///
+/// Monotone chains speed up spatial predicates on long `LineString`s, as used by
+/// [JTS](https://locationtech.github.io/jts/): whole chains can be skipped when their bounding
+/// rectangles don't overlap, and overlapping chains can be bisected to find the segments which
+/// might intersect without testing every pair of segments.
+///
+/// [`Intersects`] between a `Polygon` and a long `LineString` (and so between `Polygon`s), the
+/// [`Euclidean`](crate::Euclidean) distance between long `LineString`s, and
+/// [`Relate`](crate::Relate), use monotone chains.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, MonotoneChains, Rect};
+///
+/// let zigzag = wkt!(LINESTRING(0. 0.,1. 1.,2. 2.,3. 0.,4. 1.));
+/// let chains = MonotoneChains::new(&zigzag);
+///
+/// let envelopes: Vec<_> = chains.iter().map(|chain| chain.envelope()).collect();
+/// assert_eq!(
+///     envelopes,
+///     vec![
+///         Rect::new((0., 0.), (2., 2.)),
+///         Rect::new((2., 2.), (3., 0.)),
+///         Rect::new((3., 0.), (4., 1.)),
+///     ]
+/// );
+///
+/// let crossing = wkt!(LINESTRING(0. 2.,4. 0.));
+/// assert!(chains.intersects(&MonotoneChains::new(&crossing)));
+/// let disjoint = wkt!(LINESTRING(0. 3.,4. 3.));
+/// assert!(!chains.intersects(&MonotoneChains::new(&disjoint)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonotoneChains<'a, T: GeoNum> {
+    chains: Vec<MonotoneChain<'a, T>>,
+}
+
+impl<'a, T: GeoNum> MonotoneChains<'a, T> {
+    pub fn new(line_string: &'a LineString<T>) -> Self {
+        Self::from_coords(&line_string.0)
+    }
+
+    /// The chains of the path through `coords`.
+    pub(crate) fn from_coords(coords: &'a [Coord<T>]) -> Self {
+        let mut chains = Vec::new();
+        if coords.len() < 2 {
+            return Self { chains };
+        }
+
+        let mut start = 0;
+        // The direction of the current chain along each axis, which is `Equal` until known
+        let mut direction = (Ordering::Equal, Ordering::Equal);
+        for (idx, w) in coords.windows(2).enumerate() {
+            let segment_direction = (direction_of(w[0].x, w[1].x), direction_of(w[0].y, w[1].y));
+            match (
+                merge_direction(direction.0, segment_direction.0),
+                merge_direction(direction.1, segment_direction.1),
+            ) {
+                (Some(x), Some(y)) => direction = (x, y),
+                _ => {
+                    chains.push(MonotoneChain {
+                        coords: &coords[start..=idx],
+                        start_idx: start,
+                    });
+                    start = idx;
+                    direction = segment_direction;
+                }
+            }
+        }
+        chains.push(MonotoneChain {
+            coords: &coords[start..],
+            start_idx: start,
+        });
+        Self { chains }
+    }
+
+    /// The chains, in the order of the `LineString`.
+    pub fn chains(&self) -> &[MonotoneChain<'a, T>] {
+        &self.chains
+    }
+
+    /// An iterator over the chains, in the order of the `LineString`.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &MonotoneChain<'a, T>> {
+        self.chains.iter()
+    }
+
+    /// Whether any segment of these chains intersects any segment of `other`.
+    pub fn intersects(&self, other: &MonotoneChains<T>) -> bool {
+        let other_envelopes: Vec<_> = other.iter().map(MonotoneChain::envelope).collect();
+        self.iter().any(|chain| {
+            let envelope = chain.envelope();
+            other
+                .iter()
+                .zip(&other_envelopes)
+                .any(|(other_chain, other_envelope)| {
+                    !envelopes_disjoint(envelope, *other_envelope) && chain.intersects(other_chain)
+                })
+        })
+    }
+}
+
+impl<T: GeoFloat> MonotoneChains<'_, T> {
+    /// The intersections between the segments of these chains and those of `other`, as tuples
+    /// of the indices of the two segments in their `LineString`s, and their intersection.
+    pub fn intersections(
+        &self,
+        other: &MonotoneChains<T>,
+    ) -> Vec<(usize, usize, LineIntersection<T>)> {
+        let other_envelopes: Vec<_> = other.iter().map(MonotoneChain::envelope).collect();
+        let mut intersections = Vec::new();
+        for chain in self.iter() {
+            let envelope = chain.envelope();
+            for (other_chain, other_envelope) in other.iter().zip(&other_envelopes) {
+                if !envelopes_disjoint(envelope, *other_envelope) {
+                    intersections.extend(chain.intersections(other_chain));
+                }
+            }
+        }
+        intersections
+    }
+}
+
+impl<'a, T: GeoNum> From<&'a LineString<T>> for MonotoneChains<'a, T> {
+    fn from(line_string: &'a LineString<T>) -> Self {
+        Self::new(line_string)
+    }
+}
+
+/// An R-tree of the bounding rectangles of the [`MonotoneChain`]s of a set of `LineString`s.
+///
+/// This finds the chains near a region, and the intersections between two sets of
+/// `LineString`s, without comparing every pair of chains, so is the basis for custom overlays
+/// and predicates between many `LineString`s, e.g. the rings of polygon layers.
+///
+/// # Examples
+///
+/// ```
+/// use geo::MonotoneChainIndex;
+/// use geo::{wkt, Rect};
+///
+/// let roads = [
+///     wkt!(LINESTRING(0. 0.,10. 0.,10. 10.)),
+///     wkt!(LINESTRING(20. 0.,20. 10.)),
+/// ];
+/// let rivers = [wkt!(LINESTRING(-5. 5.,25. 5.))];
+/// let road_index = MonotoneChainIndex::new(&roads);
+/// let river_index = MonotoneChainIndex::new(&rivers);
+///
+/// // the river crosses the second segment of the first road, and the second road
+/// let mut bridges: Vec<_> = road_index
+///     .intersections(&river_index)
+///     .into_iter()
+///     .map(|intersection| intersection.segment)
+///     .collect();
+/// bridges.sort();
+/// assert_eq!(bridges, vec![(0, 1), (1, 0)]);
+///
+/// let near_origin = road_index.locate_in_rect(Rect::new((-1., -1.), (1., 1.)));
+/// assert_eq!(near_origin.map(|(road, _)| road).collect::<Vec<_>>(), vec![0]);
+/// ```
+pub struct MonotoneChainIndex<'a, T: GeoFloat> {
+    chains: Vec<(usize, MonotoneChain<'a, T>)>,
+    tree: RTree<GeomWithData<Rectangle<Point<T>>, usize>>,
+}
+
+impl<'a, T: GeoFloat> MonotoneChainIndex<'a, T> {
+    /// Indexes the chains of `line_strings`, which are identified by their position.
+    pub fn new(line_strings: impl IntoIterator<Item = &'a LineString<T>>) -> Self {
+        let chains: Vec<_> = line_strings
+            .into_iter()
+            .enumerate()
+            .flat_map(|(idx, line_string)| {
+                MonotoneChains::new(line_string)
+                    .chains
+                    .into_iter()
+                    .map(move |chain| (idx, chain))
+            })
+            .collect();
+        let tree = RTree::bulk_load(
+            chains
+                .iter()
+                .enumerate()
+                .map(|(idx, (_, chain))| GeomWithData::new(rectangle(chain.envelope()), idx))
+                .collect(),
+        );
+        Self { chains, tree }
+    }
+
+    /// The chains whose bounding rectangles intersect `rect`, with the index of their
+    /// `LineString`.
+    pub fn locate_in_rect(
+        &self,
+        rect: Rect<T>,
+    ) -> impl Iterator<Item = (usize, &MonotoneChain<'a, T>)> {
+        let envelope = AABB::from_corners(rect.min().into(), rect.max().into());
+        self.tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|entry| {
+                let (idx, chain) = &self.chains[entry.data];
+                (*idx, chain)
+            })
+    }
+
+    /// The intersections between the segments of these `LineString`s and those of `other`.
+    pub fn intersections(&self, other: &MonotoneChainIndex<T>) -> Vec<IndexedIntersection<T>> {
+        let mut intersections = Vec::new();
+        for (entry, other_entry) in self
+            .tree
+            .intersection_candidates_with_other_tree(&other.tree)
+        {
+            let (idx, chain) = &self.chains[entry.data];
+            let (other_idx, other_chain) = &other.chains[other_entry.data];
+            intersections.extend(chain.intersections(other_chain).into_iter().map(
+                |(segment_idx, other_segment_idx, intersection)| IndexedIntersection {
+                    segment: (*idx, segment_idx),
+                    other_segment: (*other_idx, other_segment_idx),
+                    intersection,
+                },
+            ));
+        }
+        intersections
+    }
+}
+
+/// An intersection between segments of two [`MonotoneChainIndex`]s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IndexedIntersection<T: GeoFloat> {
+    /// The `(line_string_idx, segment_idx)` of the segment of the first index.
+    pub segment: (usize, usize),
+    /// The `(line_string_idx, segment_idx)` of the segment of the other index.
+    pub other_segment: (usize, usize),
+    /// The intersection of the two segments.
+    pub intersection: LineIntersection<T>,
+}
+
+fn rectangle<T: GeoFloat>(rect: Rect<T>) -> Rectangle<Point<T>> {
+    Rectangle::from_corners(rect.min().into(), rect.max().into())
+}
+
+fn direction_of<T: GeoNum>(from: T, to: T) -> Ordering {
+    from.partial_cmp(&to).unwrap_or(Ordering::Equal)
+}
+
+// The direction of a chain extended by a segment, or `None` if the segment turns back
+fn merge_direction(chain: Ordering, segment: Ordering) -> Option<Ordering> {
+    match (chain, segment) {
+        (Ordering::Equal, _) => Some(segment),
+        (_, Ordering::Equal) => Some(chain),
+        _ if chain == segment => Some(chain),
+        _ => None,
+    }
+}
+
+fn envelopes_disjoint<T: GeoNum>(a: Rect<T>, b: Rect<T>) -> bool {
+    a.max().x < b.min().x || b.max().x < a.min().x || a.max().y < b.min().y || b.max().y < a.min().y
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Translate};
+
+    #[test]
+    fn decomposition() {
+        // an empty or single point line string has no segments
+        assert!(MonotoneChains::new(&LineString::<f64>::new(vec![]))
+            .chains()
+            .is_empty());
+        assert!(MonotoneChains::new(&wkt!(LINESTRING(1. 1.)))
+            .chains()
+            .is_empty());
+
+        // horizontal, vertical and repeated segments extend any chain
+        let line_string = wkt!(LINESTRING(0. 0.,1. 0.,1. 0.,1. 1.,2. 3.,1. 4.,0. 4.,0. 5.));
+        let chains = MonotoneChains::new(&line_string);
+        let coords: Vec<_> = chains.iter().map(|chain| chain.coords().len()).collect();
+        assert_eq!(coords, vec![5, 4]);
+        assert_eq!(chains.chains()[1].envelope(), Rect::new((0., 3.), (2., 5.)));
+
+        // consecutive chains share a coordinate, and cover every segment
+        let norway = geo_test_fixtures::norway_main::<f64>();
+        let chains = MonotoneChains::new(&norway);
+        let lines: Vec<_> = chains.iter().flat_map(|chain| chain.lines()).collect();
+        assert_eq!(lines, norway.lines().collect::<Vec<_>>());
+        for chain in chains.iter() {
+            let envelope = chain.envelope();
+            assert!(chain
+                .coords()
+                .iter()
+                .all(|coord| envelope.intersects(coord)));
+        }
+    }
+
+    #[test]
+    fn intersects_matches_lines() {
+        let norway = geo_test_fixtures::norway_main::<f64>();
+        let chains = MonotoneChains::new(&norway);
+        for (x_offset, y_offset) in [(0.05, 0.05), (2., -1.), (0., 20.), (-1e-3, 0.)] {
+            let shifted = norway.translate(x_offset, y_offset);
+            let shifted: LineString = shifted.0[..200].to_vec().into();
+            let expected = norway
+                .lines()
+                .any(|line| shifted.lines().any(|other| line.intersects(&other)));
+            assert_eq!(
+                chains.intersects(&MonotoneChains::new(&shifted)),
+                expected,
+                "{x_offset} {y_offset}"
+            );
+        }
+    }
+
+    #[test]
+    fn intersections_match_lines() {
+        let norway = geo_test_fixtures::norway_main::<f64>();
+        let shifted: LineString = norway.translate(0.05, 0.05).0[..400].to_vec().into();
+        let mut expected: Vec<_> = norway
+            .lines()
+            .enumerate()
+            .flat_map(|(idx, line)| {
+                shifted
+                    .lines()
+                    .enumerate()
+                    .filter_map(move |(other_idx, other)| {
+                        line_intersection(line, other)
+                            .map(|intersection| (idx, other_idx, intersection))
+                    })
+            })
+            .collect();
+        assert!(!expected.is_empty());
+        let by_segments =
+            |a: &(usize, usize, _), b: &(usize, usize, _)| (a.0, a.1).cmp(&(b.0, b.1));
+        expected.sort_by(by_segments);
+
+        let mut intersections =
+            MonotoneChains::new(&norway).intersections(&MonotoneChains::new(&shifted));
+        intersections.sort_by(by_segments);
+        assert_eq!(intersections, expected);
+
+        let mut indexed =
+            MonotoneChainIndex::new([&norway]).intersections(&MonotoneChainIndex::new([&shifted]));
+        indexed.sort_by_key(|indexed| (indexed.segment, indexed.other_segment));
+        let indexed: Vec<_> = indexed
+            .into_iter()
+            .map(|indexed| {
+                (
+                    indexed.segment.1,
+                    indexed.other_segment.1,
+                    indexed.intersection,
+                )
+            })
+            .collect();
+        assert_eq!(indexed, expected);
+    }
+
+    #[test]
+    fn index() {
+        let line_strings = [
+            wkt!(LINESTRING(0. 0.,1. 1.,2. 0.,3. 1.)),
+            wkt!(LINESTRING(5. 5.)),
+            wkt!(LINESTRING(0. 3.,3. 3.,3. 2.)),
+        ];
+        let index = MonotoneChainIndex::new(&line_strings);
+        let mut located: Vec<_> = index
+            .locate_in_rect(Rect::new((1.5, 0.5), (2.5, 1.5)))
+            .map(|(idx, chain)| (idx, chain.start_idx()))
+            .collect();
+        located.sort();
+        assert_eq!(located, vec![(0, 1), (0, 2)]);
+
+        // a vertical line crossing each line string, and touching the last at its end
+        let other = [wkt!(LINESTRING(2.5 -1.,2.5 2.,3. 2.))];
+        let mut intersections: Vec<_> = index
+            .intersections(&MonotoneChainIndex::new(&other))
+            .into_iter()
+            .map(|intersection| (intersection.segment, intersection.other_segment))
+            .collect();
+        intersections.sort();
+        assert_eq!(intersections, vec![((0, 2), (0, 0)), ((2, 1), (0, 1))]);
+    }
+}
@@ -0,0 +1,516 @@
+use std::ops::Range;
+
+use num_traits::FromPrimitive;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::line_measures::{Distance, Euclidean, Haversine, HaversineMeasure};
+use crate::{Closest, GeoFloat, HaversineClosestPoint};
+use geo_types::{
+    Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Find the pair of nearest points between two geometries: a point on `self`, and a point on
+/// `other`, at the minimum distance between them.
+///
+/// This is the counterpart of [`ClosestPoint`](crate::ClosestPoint) for any pair of geometries,
+/// like shapely's `nearest_points`. If the geometries intersect, both points are the same point
+/// of their intersection, e.g. where their boundaries cross, or a vertex of one inside an area
+/// of the other. Returns `None` if either geometry is empty.
+///
+/// Every pair of segments is compared, which takes `O(n m)` time for geometries of `n` and `m`
+/// segments.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, wkt, NearestPoints};
+///
+/// let road = wkt!(LINESTRING(0. 0.,10. 0.));
+/// let field = wkt!(POLYGON((3. 2.,8. 2.,8. 5.,3. 5.,3. 2.)));
+///
+/// assert_eq!(
+///     road.nearest_points(&field),
+///     Some((point!(x: 3., y: 0.), point!(x: 3., y: 2.)))
+/// );
+/// ```
+///
+/// In lon/lat, with the nearest points on great circle arcs between the vertices:
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{point, wkt, NearestPoints};
+///
+/// let meridian = wkt!(LINESTRING(0. -10.,0. 10.));
+/// let (on_meridian, from) = meridian.haversine_nearest_points(&point!(x: 1., y: 5.)).unwrap();
+/// assert_relative_eq!(on_meridian, point!(x: 0., y: 5.0008), epsilon = 1e-4);
+/// assert_eq!(from, point!(x: 1., y: 5.));
+/// ```
+pub trait NearestPoints<T: GeoFloat, Rhs = Self> {
+    /// The nearest points between `self` and `other` on the Euclidean plane.
+    fn nearest_points(&self, other: &Rhs) -> Option<(Point<T>, Point<T>)>;
+
+    /// The nearest points between `self` and `other` on the earth, with the
+    /// [`Haversine`] measure, whose coordinates are longitudes and latitudes in degrees.
+    ///
+    /// As in [`HaversineClosestPoint`], whether a point is inside a polygon is decided on the
+    /// plane of longitudes and latitudes.
+    fn haversine_nearest_points(&self, other: &Rhs) -> Option<(Point<T>, Point<T>)>
+    where
+        T: FromPrimitive,
+    {
+        self.haversine_nearest_points_on(other, Haversine)
+    }
+
+    /// Like [`haversine_nearest_points`](Self::haversine_nearest_points), but on a sphere of the
+    /// given [`HaversineMeasure`]'s radius rather than the earth's.
+    fn haversine_nearest_points_on(
+        &self,
+        other: &Rhs,
+        haversine: HaversineMeasure,
+    ) -> Option<(Point<T>, Point<T>)>
+    where
+        T: FromPrimitive;
+}
+
+impl<T, G, Rhs> NearestPoints<T, Rhs> for G
+where
+    T: GeoFloat,
+    G: private::Parts<T>,
+    Rhs: private::Parts<T>,
+{
+    fn nearest_points(&self, other: &Rhs) -> Option<(Point<T>, Point<T>)> {
+        nearest_points(self, other, |a, b| {
+            let (p, q) = euclidean_nearest_points(a, b);
+            (p, q, Euclidean.distance(p, q))
+        })
+    }
+
+    fn haversine_nearest_points_on(
+        &self,
+        other: &Rhs,
+        haversine: HaversineMeasure,
+    ) -> Option<(Point<T>, Point<T>)>
+    where
+        T: FromPrimitive,
+    {
+        nearest_points(self, other, |a, b| {
+            let (p, q) = haversine_nearest_points(a, b, haversine);
+            (p, q, haversine.distance(p, q))
+        })
+    }
+}
+
+// seal the decomposition of geometries into segments, so it doesn't leak on the public interface
+mod private {
+    use super::*;
+
+    /// The segments of a geometry, with single points as zero length segments.
+    pub struct Segments<T: GeoFloat> {
+        pub(super) segments: Vec<Line<T>>,
+        /// The ranges of `segments` which are the rings of each polygon
+        pub(super) areas: Vec<Range<usize>>,
+        /// A coordinate of each connected part
+        pub(super) representatives: Vec<Coord<T>>,
+    }
+
+    pub trait Parts<T: GeoFloat> {
+        fn add_parts(&self, segments: &mut Segments<T>);
+    }
+}
+
+use private::{Parts, Segments};
+
+fn nearest_points<T, A, B, F>(a: &A, b: &B, nearest: F) -> Option<(Point<T>, Point<T>)>
+where
+    T: GeoFloat,
+    A: Parts<T>,
+    B: Parts<T>,
+    F: Fn(Line<T>, Line<T>) -> (Point<T>, Point<T>, T),
+{
+    let a_segments = segments(a);
+    let b_segments = segments(b);
+
+    // If a part of one geometry has no segment intersecting the other geometry, it's either
+    // wholly inside one of its areas or outside all of them, so one coordinate of each part is
+    // enough to find the parts inside the other
+    if let Some(coord) = inside_any(&a_segments, &b_segments) {
+        return Some((coord.into(), coord.into()));
+    }
+    if let Some(coord) = inside_any(&b_segments, &a_segments) {
+        return Some((coord.into(), coord.into()));
+    }
+
+    let mut best: Option<(Point<T>, Point<T>, T)> = None;
+    for a in &a_segments.segments {
+        for b in &b_segments.segments {
+            let candidate = nearest(*a, *b);
+            if candidate.2 == T::zero() {
+                return Some((candidate.0, candidate.1));
+            }
+            if best.map_or(true, |best| candidate.2 < best.2) {
+                best = Some(candidate);
+            }
+        }
+    }
+    best.map(|(p, q, _)| (p, q))
+}
+
+fn segments<T: GeoFloat>(geometry: &impl Parts<T>) -> Segments<T> {
+    let mut segments = Segments {
+        segments: vec![],
+        areas: vec![],
+        representatives: vec![],
+    };
+    geometry.add_parts(&mut segments);
+    segments
+}
+
+// The first coordinate of `parts` which is inside an area of `areas`
+fn inside_any<T: GeoFloat>(parts: &Segments<T>, areas: &Segments<T>) -> Option<Coord<T>> {
+    parts.representatives.iter().copied().find(|coord| {
+        areas
+            .areas
+            .iter()
+            .any(|area| crosses_odd(*coord, &areas.segments[area.clone()]))
+    })
+}
+
+// Whether a ray from `coord` towards positive x crosses `rings` an odd number of times
+fn crosses_odd<T: GeoFloat>(coord: Coord<T>, rings: &[Line<T>]) -> bool {
+    let mut inside = false;
+    for segment in rings {
+        if (segment.start.y > coord.y) != (segment.end.y > coord.y) {
+            let orientation = T::Ker::orient2d(segment.start, segment.end, coord);
+            let left = orientation == Orientation::CounterClockwise;
+            if left == (segment.end.y > segment.start.y) {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+fn euclidean_nearest_points<T: GeoFloat>(a: Line<T>, b: Line<T>) -> (Point<T>, Point<T>) {
+    match line_intersection(a, b) {
+        Some(LineIntersection::SinglePoint { intersection, .. }) => {
+            return (intersection.into(), intersection.into())
+        }
+        Some(LineIntersection::Collinear { intersection }) => {
+            return (intersection.start_point(), intersection.start_point())
+        }
+        None => {}
+    }
+    // Segments which don't intersect are nearest at an endpoint of one of them
+    let candidates = [
+        (a.start, closest_on_segment(b, a.start)),
+        (a.end, closest_on_segment(b, a.end)),
+        (closest_on_segment(a, b.start), b.start),
+        (closest_on_segment(a, b.end), b.end),
+    ];
+    let (p, q) = candidates
+        .into_iter()
+        .min_by(|(p, q), (r, s)| {
+            let first = Euclidean.distance(Point::from(*p), Point::from(*q));
+            let second = Euclidean.distance(Point::from(*r), Point::from(*s));
+            first.total_cmp(&second)
+        })
+        .expect("there are candidates");
+    (p.into(), q.into())
+}
+
+fn closest_on_segment<T: GeoFloat>(segment: Line<T>, coord: Coord<T>) -> Coord<T> {
+    let delta = segment.delta();
+    let length_2 = delta.x * delta.x + delta.y * delta.y;
+    if length_2 == T::zero() {
+        return segment.start;
+    }
+    let to_coord = coord - segment.start;
+    let t = (to_coord.x * delta.x + to_coord.y * delta.y) / length_2;
+    let t = t.max(T::zero()).min(T::one());
+    segment.start + delta * t
+}
+
+fn haversine_nearest_points<T>(
+    a: Line<T>,
+    b: Line<T>,
+    haversine: HaversineMeasure,
+) -> (Point<T>, Point<T>)
+where
+    T: GeoFloat + FromPrimitive,
+{
+    if let Some(crossing) = arcs_crossing(a, b) {
+        return (crossing, crossing);
+    }
+    // Arcs which don't cross are nearest at an endpoint of one of them
+    let on = |arc: Line<T>, from: Point<T>| match arc.haversine_closest_point_on(&from, haversine) {
+        Closest::Intersection(point) | Closest::SinglePoint(point) => point,
+        // never the case for a `Line`
+        Closest::Indeterminate => arc.start_point(),
+    };
+    let candidates = [
+        (a.start_point(), on(b, a.start_point())),
+        (a.end_point(), on(b, a.end_point())),
+        (on(a, b.start_point()), b.start_point()),
+        (on(a, b.end_point()), b.end_point()),
+    ];
+    candidates
+        .into_iter()
+        .min_by(|(p, q), (r, s)| {
+            haversine
+                .distance(*p, *q)
+                .total_cmp(&haversine.distance(*r, *s))
+        })
+        .expect("there are candidates")
+}
+
+// The point where two great circle arcs cross, if they do
+fn arcs_crossing<T: GeoFloat>(a: Line<T>, b: Line<T>) -> Option<Point<T>> {
+    let (a_start, a_end) = (unit_vector(a.start), unit_vector(a.end));
+    let (b_start, b_end) = (unit_vector(b.start), unit_vector(b.end));
+    let a_normal = cross(a_start, a_end);
+    let b_normal = cross(b_start, b_end);
+    let straddles = |normal: [T; 3], start: [T; 3], end: [T; 3]| {
+        let start = dot(normal, start);
+        let end = dot(normal, end);
+        start != T::zero() && end != T::zero() && start.is_sign_positive() != end.is_sign_positive()
+    };
+    if !straddles(b_normal, a_start, a_end) || !straddles(a_normal, b_start, b_end) {
+        return None;
+    }
+    // The great circles cross at two antipodal points, of which the arcs may share one
+    let mut crossing = cross(a_normal, b_normal);
+    let a_middle = [0, 1, 2].map(|i| a_start[i] + a_end[i]);
+    if dot(crossing, a_middle) < T::zero() {
+        crossing = crossing.map(|x| -x);
+    }
+    let b_middle = [0, 1, 2].map(|i| b_start[i] + b_end[i]);
+    if dot(crossing, b_middle) <= T::zero() {
+        return None;
+    }
+    let [x, y, z] = crossing;
+    Some(Point::new(
+        y.atan2(x).to_degrees(),
+        z.atan2(x.hypot(y)).to_degrees(),
+    ))
+}
+
+fn unit_vector<T: GeoFloat>(coord: Coord<T>) -> [T; 3] {
+    let (lon, lat) = (coord.x.to_radians(), coord.y.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+fn cross<T: GeoFloat>(u: [T; 3], v: [T; 3]) -> [T; 3] {
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+fn dot<T: GeoFloat>(u: [T; 3], v: [T; 3]) -> T {
+    u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
+}
+
+// Add the segments of `line_string`, returning their range
+fn add_line_string<T: GeoFloat>(
+    line_string: &LineString<T>,
+    parts: &mut Segments<T>,
+) -> Range<usize> {
+    let start = parts.segments.len();
+    match line_string.0.as_slice() {
+        [] => {}
+        [coord] => parts.segments.push(Line::new(*coord, *coord)),
+        _ => parts.segments.extend(line_string.lines()),
+    }
+    start..parts.segments.len()
+}
+
+impl<T: GeoFloat> Parts<T> for Point<T> {
+    fn add_parts(&self, parts: &mut Segments<T>) {
+        parts.segments.push(Line::new(self.0, self.0));
+        parts.representatives.push(self.0);
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for Line<T> {
+    fn add_parts(&self, parts: &mut Segments<T>) {
+        parts.segments.push(*self);
+        parts.representatives.push(self.start);
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for LineString<T> {
+    fn add_parts(&self, parts: &mut Segments<T>) {
+        add_line_string(self, parts);
+        parts.representatives.extend(self.0.first());
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for Polygon<T> {
+    fn add_parts(&self, parts: &mut Segments<T>) {
+        let exterior = add_line_string(self.exterior(), parts);
+        let rings = self.interiors().iter().fold(exterior, |rings, interior| {
+            rings.start..add_line_string(interior, parts).end
+        });
+        if !rings.is_empty() {
+            parts.areas.push(rings);
+        }
+        parts.representatives.extend(self.exterior().0.first());
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for Rect<T> {
+    fn add_parts(&self, parts: &mut Segments<T>) {
+        self.to_polygon().add_parts(parts)
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for Triangle<T> {
+    fn add_parts(&self, parts: &mut Segments<T>) {
+        self.to_polygon().add_parts(parts)
+    }
+}
+
+macro_rules! impl_parts_iter {
+    ($type:ident) => {
+        impl<T: GeoFloat> Parts<T> for $type<T> {
+            fn add_parts(&self, parts: &mut Segments<T>) {
+                for member in self {
+                    member.add_parts(parts);
+                }
+            }
+        }
+    };
+}
+
+impl_parts_iter!(MultiPoint);
+impl_parts_iter!(MultiLineString);
+impl_parts_iter!(MultiPolygon);
+impl_parts_iter!(GeometryCollection);
+
+impl<T: GeoFloat> Parts<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn add_parts(&self, parts: &mut Segments<T>) -> ();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, wkt, Distance, Euclidean, Translate};
+
+    #[test]
+    fn disjoint() {
+        let square = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+        let line = wkt!(LINESTRING(6. 1.,8. 5.));
+        assert_eq!(
+            square.nearest_points(&line),
+            Some((point!(x: 4., y: 1.), point!(x: 6., y: 1.)))
+        );
+        assert_eq!(
+            line.nearest_points(&square),
+            Some((point!(x: 6., y: 1.), point!(x: 4., y: 1.)))
+        );
+
+        let points = wkt!(MULTIPOINT(10. 10.,5. 2.));
+        assert_eq!(
+            square.nearest_points(&points),
+            Some((point!(x: 4., y: 2.), point!(x: 5., y: 2.)))
+        );
+        assert_eq!(
+            point!(x: 1., y: 1.).nearest_points(&point!(x: 4., y: 5.)),
+            Some((point!(x: 1., y: 1.), point!(x: 4., y: 5.)))
+        );
+
+        // the nearest points are at the minimum distance
+        let norway = geo_test_fixtures::norway_main::<f64>();
+        let other = norway.translate(1., 0.5);
+        let (p, q) = norway.nearest_points(&wkt!(POINT(10. 70.))).unwrap();
+        approx::assert_relative_eq!(
+            Euclidean.distance(p, q),
+            Euclidean.distance(&norway, &point!(x: 10., y: 70.))
+        );
+        assert_eq!(q, point!(x: 10., y: 70.));
+        let (p, q) = norway.nearest_points(&other).unwrap();
+        assert_eq!(p, q);
+    }
+
+    #[test]
+    fn intersecting() {
+        let square = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+        // crossing boundaries
+        let line = wkt!(LINESTRING(2. -2.,2. 6.));
+        assert_eq!(
+            square.nearest_points(&line),
+            Some((point!(x: 2., y: 0.), point!(x: 2., y: 0.)))
+        );
+        // inside, in either order
+        let inner = wkt!(LINESTRING(1. 1.,3. 3.));
+        assert_eq!(
+            square.nearest_points(&inner),
+            Some((point!(x: 1., y: 1.), point!(x: 1., y: 1.)))
+        );
+        assert_eq!(
+            inner.nearest_points(&square),
+            Some((point!(x: 1., y: 1.), point!(x: 1., y: 1.)))
+        );
+        // in a hole isn't inside
+        let holed = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (2. 2.,8. 2.,8. 8.,2. 8.,2. 2.)
+        ));
+        assert_eq!(
+            holed.nearest_points(&point!(x: 5., y: 3.)),
+            Some((point!(x: 5., y: 2.), point!(x: 5., y: 3.)))
+        );
+        // a geometry collection
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            wkt!(POINT(20. 20.)).into(),
+            wkt!(POINT(9. 9.)).into(),
+        ]));
+        assert_eq!(
+            collection.nearest_points(&holed),
+            Some((point!(x: 9., y: 9.), point!(x: 9., y: 9.)))
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.nearest_points(&point!(x: 0., y: 0.)), None);
+        assert_eq!(point!(x: 0., y: 0.).nearest_points(&empty), None);
+        assert_eq!(empty.haversine_nearest_points(&empty), None);
+    }
+
+    #[test]
+    fn haversine() {
+        // arcs which cross
+        let equator = wkt!(LINESTRING(-10. 0.,10. 0.));
+        let meridian = wkt!(LINESTRING(5. -10.,5. 10.));
+        let (p, q) = equator.haversine_nearest_points(&meridian).unwrap();
+        assert_eq!(p, q);
+        approx::assert_relative_eq!(p, point!(x: 5., y: 0.), epsilon = 1e-9);
+
+        // arcs which don't: the great circle arc bulges towards the pole
+        let arc = wkt!(LINESTRING(-40. 60.,40. 60.));
+        let point = point!(x: 0., y: 70.);
+        let (p, q) = arc.haversine_nearest_points(&point).unwrap();
+        assert_eq!(q, point);
+        approx::assert_relative_eq!(p.x(), 0., epsilon = 1e-9);
+        assert!(p.y() > 66.);
+        let Closest::SinglePoint(closest) = arc.haversine_closest_point(&point) else {
+            panic!("expected a single closest point");
+        };
+        approx::assert_relative_eq!(p, closest);
+
+        // the nearest of several
+        let lines = wkt!(MULTILINESTRING((0. 0.,0. 10.),(3. 0.,3. 10.)));
+        let (p, q) = lines
+            .haversine_nearest_points(&point!(x: 2., y: 5.))
+            .unwrap();
+        approx::assert_relative_eq!(p, point!(x: 3., y: 5.), epsilon = 1e-3);
+        assert_eq!(q, point!(x: 2., y: 5.));
+    }
+}
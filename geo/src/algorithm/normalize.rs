@@ -0,0 +1,283 @@
+use crate::bounding_rect::bounding_rect_merge;
+use crate::relate::IntersectionMatrix;
+use crate::{
+    BooleanOps, BoundingRect, Coord, GeoFloat, MapCoords, MultiPolygon, OpType, Rect, Relate,
+};
+
+/// A local coordinate frame in which a set of geometries is well-conditioned for computation.
+///
+/// Coordinates far from the origin, such as `8000.000001`, leave few bits of precision for the
+/// differences between them, which is what overlay and predicates depend on. A frame translates
+/// geometries so that their common bounding rectangle is centred on the origin, and scales them
+/// by a power of two so that its largest side is at most 1. Scaling by a power of two is exact,
+/// so only the translation rounds.
+///
+/// Use [`normalize_for_computation`] to map a set of geometries into their frame, or
+/// [`NormalizedBooleanOps`] and [`NormalizedRelate`] to run an operation in the frame of its
+/// inputs.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{normalize_for_computation, wkt};
+///
+/// let polygons = [
+///     wkt!(POLYGON((8000. 8000.,8004. 8000.,8004. 8002.,8000. 8000.))),
+///     wkt!(POLYGON((8002. 8000.,8006. 8000.,8006. 8001.,8002. 8000.))),
+/// ];
+/// let (frame, local) = normalize_for_computation(&polygons).unwrap();
+///
+/// assert_eq!(
+///     local[0],
+///     wkt!(POLYGON((-0.375 -0.125,0.125 -0.125,0.125 0.125,-0.375 -0.125)))
+/// );
+/// assert_eq!(frame.to_world(&local[1]), polygons[1]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComputationFrame<T: GeoFloat> {
+    origin: Coord<T>,
+    scale: T,
+}
+
+impl<T: GeoFloat> ComputationFrame<T> {
+    /// The frame centred on `bounds`, scaled so that its largest side is at most 1.
+    pub fn new(bounds: Rect<T>) -> Self {
+        let extent = bounds.width().max(bounds.height());
+        let scale = if extent > T::zero() && extent.is_finite() {
+            let exponent = extent.log2().ceil().to_i32().unwrap_or(0);
+            T::from(2).unwrap().powi(-exponent)
+        } else {
+            T::one()
+        };
+        Self {
+            origin: bounds.center(),
+            scale,
+        }
+    }
+
+    /// The frame for the common bounding rectangle of `geometries`, or `None` if they are all
+    /// empty.
+    pub fn for_geometries<'a, G>(geometries: impl IntoIterator<Item = &'a G>) -> Option<Self>
+    where
+        G: BoundingRect<T> + 'a,
+        G::Output: Into<Option<Rect<T>>>,
+    {
+        geometries
+            .into_iter()
+            .filter_map(|geometry| geometry.bounding_rect().into())
+            .reduce(bounding_rect_merge)
+            .map(Self::new)
+    }
+
+    /// The translation from world coordinates to the frame.
+    pub fn origin(&self) -> Coord<T> {
+        self.origin
+    }
+
+    /// The scale factor from world coordinates to the frame, which is always a power of two.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    /// Map `geometry` from world coordinates into the frame.
+    pub fn to_local<G: MapCoords<T, T>>(&self, geometry: &G) -> G::Output {
+        geometry.map_coords(|coord| (coord - self.origin) * self.scale)
+    }
+
+    /// Map `geometry` from the frame back to world coordinates.
+    pub fn to_world<G: MapCoords<T, T>>(&self, geometry: &G) -> G::Output {
+        geometry.map_coords(|coord| coord / self.scale + self.origin)
+    }
+}
+
+type Normalized<T, G> = (ComputationFrame<T>, Vec<<G as MapCoords<T, T>>::Output>);
+
+/// Map `geometries` into their common [`ComputationFrame`], returning the frame and the mapped
+/// geometries, or `None` if they are all empty.
+pub fn normalize_for_computation<T, G>(geometries: &[G]) -> Option<Normalized<T, G>>
+where
+    T: GeoFloat,
+    G: BoundingRect<T> + MapCoords<T, T>,
+    <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+{
+    let frame = ComputationFrame::for_geometries(geometries)?;
+    let local = geometries
+        .iter()
+        .map(|geometry| frame.to_local(geometry))
+        .collect();
+    Some((frame, local))
+}
+
+/// Boolean operations computed in the [`ComputationFrame`] of both inputs. See [`BooleanOps`].
+///
+/// The output is mapped back to world coordinates, which rounds its coordinates once more.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Area, NormalizedBooleanOps};
+///
+/// let a = wkt!(POLYGON((8000. 0.,8000.000001 0.,8000.000001 1.,8000. 1.,8000. 0.)));
+/// let b = wkt!(POLYGON((8000.0000005 0.,8000.000002 0.,8000.000002 1.,8000.0000005 1.,8000.0000005 0.)));
+///
+/// let intersection = a.intersection_normalized(&b);
+/// approx::assert_relative_eq!(intersection.unsigned_area(), 0.0000005, max_relative = 1e-3);
+/// ```
+pub trait NormalizedBooleanOps<T: GeoFloat> {
+    fn boolean_op_normalized<G>(&self, other: &G, op: OpType) -> MultiPolygon<T>
+    where
+        G: BoundingRect<T> + MapCoords<T, T>,
+        <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+        <G as MapCoords<T, T>>::Output: BooleanOps<Scalar = T>;
+
+    /// Returns the overlapping regions shared by both `self` and `other`.
+    fn intersection_normalized<G>(&self, other: &G) -> MultiPolygon<T>
+    where
+        G: BoundingRect<T> + MapCoords<T, T>,
+        <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+        <G as MapCoords<T, T>>::Output: BooleanOps<Scalar = T>,
+    {
+        self.boolean_op_normalized(other, OpType::Intersection)
+    }
+
+    /// Combines the regions of both `self` and `other` into a single geometry, removing
+    /// overlaps and merging boundaries.
+    fn union_normalized<G>(&self, other: &G) -> MultiPolygon<T>
+    where
+        G: BoundingRect<T> + MapCoords<T, T>,
+        <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+        <G as MapCoords<T, T>>::Output: BooleanOps<Scalar = T>,
+    {
+        self.boolean_op_normalized(other, OpType::Union)
+    }
+
+    /// The regions that are in either `self` or `other`, but not in both.
+    fn xor_normalized<G>(&self, other: &G) -> MultiPolygon<T>
+    where
+        G: BoundingRect<T> + MapCoords<T, T>,
+        <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+        <G as MapCoords<T, T>>::Output: BooleanOps<Scalar = T>,
+    {
+        self.boolean_op_normalized(other, OpType::Xor)
+    }
+
+    /// The regions of `self` which are not in `other`.
+    fn difference_normalized<G>(&self, other: &G) -> MultiPolygon<T>
+    where
+        G: BoundingRect<T> + MapCoords<T, T>,
+        <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+        <G as MapCoords<T, T>>::Output: BooleanOps<Scalar = T>,
+    {
+        self.boolean_op_normalized(other, OpType::Difference)
+    }
+}
+
+impl<T, G0> NormalizedBooleanOps<T> for G0
+where
+    T: GeoFloat,
+    G0: BoundingRect<T> + MapCoords<T, T>,
+    <G0 as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+    <G0 as MapCoords<T, T>>::Output: BooleanOps<Scalar = T>,
+{
+    fn boolean_op_normalized<G>(&self, other: &G, op: OpType) -> MultiPolygon<T>
+    where
+        G: BoundingRect<T> + MapCoords<T, T>,
+        <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+        <G as MapCoords<T, T>>::Output: BooleanOps<Scalar = T>,
+    {
+        let frame = match (self.bounding_rect().into(), other.bounding_rect().into()) {
+            (Some(a), Some(b)) => ComputationFrame::new(bounding_rect_merge(a, b)),
+            (Some(rect), None) | (None, Some(rect)) => ComputationFrame::new(rect),
+            (None, None) => return MultiPolygon::new(vec![]),
+        };
+        let result = frame.to_local(self).boolean_op(&frame.to_local(other), op);
+        frame.to_world(&result)
+    }
+}
+
+/// [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) relation computed in the
+/// [`ComputationFrame`] of both inputs. See [`Relate`].
+///
+/// Translating and scaling preserves topological relationships, so the matrix applies to the
+/// original geometries, up to the rounding of the translation.
+pub trait NormalizedRelate<T: GeoFloat> {
+    fn relate_normalized<G>(&self, other: &G) -> IntersectionMatrix
+    where
+        G: BoundingRect<T> + MapCoords<T, T>,
+        <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+        <G as MapCoords<T, T>>::Output: Relate<T>;
+}
+
+impl<T, G0> NormalizedRelate<T> for G0
+where
+    T: GeoFloat,
+    G0: BoundingRect<T> + MapCoords<T, T>,
+    <G0 as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+    <G0 as MapCoords<T, T>>::Output: Relate<T>,
+{
+    fn relate_normalized<G>(&self, other: &G) -> IntersectionMatrix
+    where
+        G: BoundingRect<T> + MapCoords<T, T>,
+        <G as BoundingRect<T>>::Output: Into<Option<Rect<T>>>,
+        <G as MapCoords<T, T>>::Output: Relate<T>,
+    {
+        let frame = match (self.bounding_rect().into(), other.bounding_rect().into()) {
+            (Some(a), Some(b)) => ComputationFrame::new(bounding_rect_merge(a, b)),
+            (Some(rect), None) | (None, Some(rect)) => ComputationFrame::new(rect),
+            // Nothing to translate
+            (None, None) => ComputationFrame::new(Rect::new(Coord::zero(), Coord::zero())),
+        };
+        frame.to_local(self).relate(&frame.to_local(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{wkt, Area, MultiPoint, Polygon};
+
+    #[test]
+    fn frame_round_trip() {
+        let frame = ComputationFrame::new(Rect::new((8000., -3.), (8005., 2.)));
+        assert_eq!(frame.origin(), Coord { x: 8002.5, y: -0.5 });
+        assert_eq!(frame.scale(), 0.125);
+
+        let polygon = wkt!(POLYGON((8000. -3.,8005. -3.,8005. 2.,8000. -3.)));
+        assert_eq!(frame.to_world(&frame.to_local(&polygon)), polygon);
+
+        // A single point has no extent to scale
+        let frame = ComputationFrame::new(Rect::new((1., 1.), (1., 1.)));
+        assert_eq!(frame.scale(), 1.);
+    }
+
+    #[test]
+    fn empty() {
+        let empty: [MultiPoint<f64>; 2] = [MultiPoint::new(vec![]), MultiPoint::new(vec![])];
+        assert!(normalize_for_computation(&empty).is_none());
+
+        let polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 0.)));
+        let empty = MultiPolygon::<f64>::new(vec![]);
+        assert_eq!(polygon.union_normalized(&empty).unsigned_area(), 0.5);
+        assert!(empty.relate_normalized(&polygon).is_disjoint());
+    }
+
+    #[test]
+    fn matches_world_operations() {
+        let a: Polygon =
+            wkt!(POLYGON((8000. 8000.,8004. 8000.,8004. 8002.,8000. 8002.,8000. 8000.)));
+        let b: Polygon =
+            wkt!(POLYGON((8002. 8001.,8006. 8001.,8006. 8003.,8002. 8003.,8002. 8001.)));
+
+        assert_eq!(
+            a.intersection_normalized(&b).unsigned_area(),
+            a.intersection(&b).unsigned_area()
+        );
+        assert_eq!(
+            a.union_normalized(&b).unsigned_area(),
+            a.union(&b).unsigned_area()
+        );
+        assert_eq!(a.xor_normalized(&b).unsigned_area(), 12.);
+        assert_eq!(a.difference_normalized(&b).unsigned_area(), 6.);
+        assert_eq!(a.relate_normalized(&b), a.relate(&b));
+    }
+}
@@ -0,0 +1,286 @@
+use std::cmp::Ordering;
+
+use crate::geometry::*;
+use crate::winding_order::{Winding, WindingOrder};
+use crate::{CoordsIter, GeoNum};
+
+/// Puts a geometry into a normal form, so that geometries which only differ in the order,
+/// winding or starting vertex of their rings, the direction of their lines, or the order of
+/// their members compare equal with `==`, e.g. to deduplicate them.
+///
+/// - Polygon and triangle exteriors are wound counter-clockwise, and interiors clockwise.
+/// - Rings start at their least coordinate, ordering by `x` and then `y`.
+/// - Lines and `LineString`s run from the lesser to the greater of their ends, comparing their
+///   coordinates from the ends inwards until they differ.
+/// - The interiors of a polygon, and the members of a multi-geometry, are sorted by their
+///   coordinates, compared in turn. The members of a `GeometryCollection` are sorted by type,
+///   in the order of the [`Geometry`] variants, and then by their coordinates.
+///
+/// Coordinates are compared exactly, and aren't removed, so geometries with different vertices
+/// are still different. Use [`Equals`](crate::Equals) for topological equality.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Normalize};
+///
+/// let polygons = wkt!(MULTIPOLYGON(
+///     ((10. 10.,11. 10.,11. 11.,10. 10.)),
+///     ((4. 4.,4. 0.,0. 0.,0. 4.,4. 4.))
+/// ));
+/// assert_eq!(
+///     polygons.normalize(),
+///     wkt!(MULTIPOLYGON(
+///         ((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)),
+///         ((10. 10.,11. 10.,11. 11.,10. 10.))
+///     ))
+/// );
+/// ```
+pub trait Normalize {
+    /// The normal form of the geometry.
+    fn normalize(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut normalized = self.clone();
+        normalized.normalize_mut();
+        normalized
+    }
+
+    /// Put the geometry into its normal form, in place.
+    fn normalize_mut(&mut self);
+}
+
+impl<T: GeoNum> Normalize for Point<T> {
+    fn normalize_mut(&mut self) {}
+}
+
+impl<T: GeoNum> Normalize for Line<T> {
+    fn normalize_mut(&mut self) {
+        if compare_coords(self.start, self.end) == Ordering::Greater {
+            *self = Line::new(self.end, self.start);
+        }
+    }
+}
+
+impl<T: GeoNum> Normalize for LineString<T> {
+    fn normalize_mut(&mut self) {
+        let coords = &self.0;
+        let first_difference = coords
+            .iter()
+            .zip(coords.iter().rev())
+            .take(coords.len() / 2)
+            .map(|(start, end)| compare_coords(*start, *end))
+            .find(|ordering| *ordering != Ordering::Equal);
+        if first_difference == Some(Ordering::Greater) {
+            self.0.reverse();
+        }
+    }
+}
+
+impl<T: GeoNum> Normalize for Polygon<T> {
+    fn normalize_mut(&mut self) {
+        self.exterior_mut(|exterior| {
+            *exterior = normalized_ring(exterior, WindingOrder::CounterClockwise)
+        });
+        self.interiors_mut(|interiors| {
+            for interior in interiors.iter_mut() {
+                *interior = normalized_ring(interior, WindingOrder::Clockwise);
+            }
+            interiors.sort_by(compare_coords_iter);
+        });
+    }
+}
+
+impl<T: GeoNum> Normalize for Rect<T> {
+    // the corners of a `Rect` are always its minimum and maximum
+    fn normalize_mut(&mut self) {}
+}
+
+impl<T: GeoNum> Normalize for Triangle<T> {
+    fn normalize_mut(&mut self) {
+        let ring = LineString::new(self.to_array().to_vec());
+        let ring = normalized_ring(&ring, WindingOrder::CounterClockwise);
+        *self = Triangle::new(ring.0[0], ring.0[1], ring.0[2]);
+    }
+}
+
+macro_rules! impl_normalize_multi {
+    ($type:ident) => {
+        impl<T: GeoNum> Normalize for $type<T> {
+            fn normalize_mut(&mut self) {
+                for member in self.0.iter_mut() {
+                    member.normalize_mut();
+                }
+                self.0.sort_by(compare_coords_iter);
+            }
+        }
+    };
+}
+
+impl_normalize_multi!(MultiPoint);
+impl_normalize_multi!(MultiLineString);
+impl_normalize_multi!(MultiPolygon);
+
+impl<T: GeoNum> Normalize for GeometryCollection<T> {
+    fn normalize_mut(&mut self) {
+        for member in self.0.iter_mut() {
+            member.normalize_mut();
+        }
+        self.0.sort_by(|a, b| {
+            type_rank(a)
+                .cmp(&type_rank(b))
+                .then_with(|| compare_coords_iter(a, b))
+        });
+    }
+}
+
+impl<T: GeoNum> Normalize for Geometry<T> {
+    fn normalize_mut(&mut self) {
+        match self {
+            Geometry::Point(g) => g.normalize_mut(),
+            Geometry::Line(g) => g.normalize_mut(),
+            Geometry::LineString(g) => g.normalize_mut(),
+            Geometry::Polygon(g) => g.normalize_mut(),
+            Geometry::MultiPoint(g) => g.normalize_mut(),
+            Geometry::MultiLineString(g) => g.normalize_mut(),
+            Geometry::MultiPolygon(g) => g.normalize_mut(),
+            Geometry::GeometryCollection(g) => g.normalize_mut(),
+            Geometry::Rect(g) => g.normalize_mut(),
+            Geometry::Triangle(g) => g.normalize_mut(),
+        }
+    }
+}
+
+fn type_rank<T: GeoNum>(geometry: &Geometry<T>) -> u8 {
+    match geometry {
+        Geometry::Point(_) => 0,
+        Geometry::Line(_) => 1,
+        Geometry::LineString(_) => 2,
+        Geometry::Polygon(_) => 3,
+        Geometry::MultiPoint(_) => 4,
+        Geometry::MultiLineString(_) => 5,
+        Geometry::MultiPolygon(_) => 6,
+        Geometry::GeometryCollection(_) => 7,
+        Geometry::Rect(_) => 8,
+        Geometry::Triangle(_) => 9,
+    }
+}
+
+// Compare the coordinates of `a` and `b` in turn, and then their numbers of coordinates
+fn compare_coords_iter<T, G>(a: &G, b: &G) -> Ordering
+where
+    T: GeoNum,
+    G: CoordsIter<Scalar = T>,
+{
+    let mut a = a.coords_iter();
+    let mut b = b.coords_iter();
+    loop {
+        match (a.next(), b.next()) {
+            (Some(a), Some(b)) => match compare_coords(a, b) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            },
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        }
+    }
+}
+
+/// `ring` closed, wound in `winding_order`, and starting at its least coordinate.
+pub(crate) fn normalized_ring<T: GeoNum>(
+    ring: &LineString<T>,
+    winding_order: WindingOrder,
+) -> LineString<T> {
+    let mut coords = ring.0.clone();
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    if let Some(&first) = coords.first() {
+        let mut closed = LineString::new(coords.clone());
+        closed.0.push(first);
+        if closed
+            .winding_order()
+            .is_some_and(|order| order != winding_order)
+        {
+            coords.reverse();
+        }
+    }
+    if let Some(start) = coords
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| compare_coords(**a, **b))
+        .map(|(idx, _)| idx)
+    {
+        coords.rotate_left(start);
+        coords.push(coords[0]);
+    }
+    LineString::new(coords)
+}
+
+/// Order coordinates by `x` and then `y`, treating `-0.0` as `0.0` and all NaNs alike.
+fn compare_coords<T: GeoNum>(a: Coord<T>, b: Coord<T>) -> Ordering {
+    canonical_f64(a.x)
+        .total_cmp(&canonical_f64(b.x))
+        .then(canonical_f64(a.y).total_cmp(&canonical_f64(b.y)))
+}
+
+pub(crate) fn canonical_f64<T: GeoNum>(value: T) -> f64 {
+    match value.to_f64() {
+        Some(value) if value.is_nan() => f64::NAN,
+        // adding zero turns -0.0 into 0.0
+        Some(value) => value + 0.0,
+        None => f64::NAN,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wkt;
+
+    #[test]
+    fn normalize() {
+        let polygon = wkt!(POLYGON(
+            (10. 10.,10. 0.,0. 0.,0. 10.,10. 10.),
+            (6. 6.,6. 8.,8. 8.,8. 6.,6. 6.),
+            (2. 2.,4. 2.,4. 4.,2. 4.,2. 2.)
+        ));
+        assert_eq!(
+            polygon.normalize(),
+            wkt!(POLYGON(
+                (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+                (2. 2.,2. 4.,4. 4.,4. 2.,2. 2.),
+                (6. 6.,6. 8.,8. 8.,8. 6.,6. 6.)
+            ))
+        );
+
+        let mut line_string = wkt!(LINESTRING(3. 3.,1. 1.,0. 0.,3. 3.,3. 2.));
+        line_string.normalize_mut();
+        assert_eq!(line_string, wkt!(LINESTRING(3. 2.,3. 3.,0. 0.,1. 1.,3. 3.)));
+        // a palindrome is already normal
+        let palindrome = wkt!(LINESTRING(0. 0.,1. 1.,0. 0.));
+        assert_eq!(palindrome.normalize(), palindrome);
+
+        assert_eq!(
+            Line::<f64>::new((1., 1.), (0., 5.)).normalize(),
+            Line::new((0., 5.), (1., 1.))
+        );
+        assert_eq!(
+            Triangle::from([(4., 4.), (0., 4.), (0., 0.)]).normalize(),
+            Triangle::from([(0., 0.), (4., 4.), (0., 4.)])
+        );
+
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            wkt!(LINESTRING(1. 1.,0. 0.)).into(),
+            wkt!(POINT(5. 5.)).into(),
+        ]));
+        let reordered = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            wkt!(POINT(5. 5.)).into(),
+            wkt!(LINESTRING(0. 0.,1. 1.)).into(),
+        ]));
+        assert_eq!(collection.normalize(), reordered.normalize());
+        assert_eq!(collection.normalize().normalize(), collection.normalize());
+    }
+}
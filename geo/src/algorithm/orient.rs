@@ -1,4 +1,4 @@
-use crate::{GeoNum, MultiPolygon, Polygon};
+use crate::{GeoNum, Geometry, GeometryCollection, MultiPolygon, Polygon, Triangle};
 
 use crate::winding_order::{Winding, WindingOrder};
 
@@ -8,6 +8,10 @@ pub trait Orient {
     /// By default, the exterior ring of a Polygon is oriented counter-clockwise, and any interior
     /// rings are oriented clockwise.
     ///
+    /// Multi-geometries, geometry collections and [`Geometry`] orient each of their polygons.
+    /// A [`Triangle`] is oriented like a polygon's exterior, by reordering its vertices.
+    /// Other geometries are returned unchanged.
+    ///
     /// # Examples
     ///
     /// ```
@@ -65,6 +69,30 @@ pub trait Orient {
     fn orient(&self, orientation: Direction) -> Self;
 }
 
+/// Orients a geometry's polygon rings in place, according to convention.
+///
+/// This is the mutating counterpart of [`Orient`], which avoids cloning the geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::orient::{Direction, OrientInPlace};
+/// use geo::{wkt, Geometry, GeometryCollection, Winding};
+///
+/// let mut collection: GeometryCollection = wkt!(GEOMETRYCOLLECTION(
+///     POINT(0. 0.),
+///     POLYGON((0. 0.,0. 1.,1. 1.,1. 0.,0. 0.))
+/// ));
+/// collection.orient_in_place(Direction::RFC7946);
+///
+/// let Geometry::Polygon(polygon) = &collection[1] else { unreachable!() };
+/// assert!(polygon.exterior().is_ccw());
+/// ```
+pub trait OrientInPlace {
+    /// Orients a geometry's polygon rings in place, see [`Orient::orient`].
+    fn orient_in_place(&mut self, orientation: Direction);
+}
+
 impl<T> Orient for Polygon<T>
 where
     T: GeoNum,
@@ -74,6 +102,40 @@ where
     }
 }
 
+impl<T> OrientInPlace for Polygon<T>
+where
+    T: GeoNum,
+{
+    fn orient_in_place(&mut self, direction: Direction) {
+        self.exterior_mut(|exterior| exterior.make_winding_order(direction.exterior()));
+        self.interiors_mut(|interiors| {
+            for interior in interiors {
+                interior.make_winding_order(direction.interior())
+            }
+        });
+    }
+}
+
+impl<T> Orient for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn orient(&self, direction: Direction) -> Triangle<T> {
+        let mut triangle = *self;
+        triangle.orient_in_place(direction);
+        triangle
+    }
+}
+
+impl<T> OrientInPlace for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn orient_in_place(&mut self, direction: Direction) {
+        self.make_winding_order(direction.exterior());
+    }
+}
+
 impl<T> Orient for MultiPolygon<T>
 where
     T: GeoNum,
@@ -83,6 +145,67 @@ where
     }
 }
 
+impl<T> OrientInPlace for MultiPolygon<T>
+where
+    T: GeoNum,
+{
+    fn orient_in_place(&mut self, direction: Direction) {
+        for polygon in self.iter_mut() {
+            polygon.orient_in_place(direction);
+        }
+    }
+}
+
+impl<T> Orient for GeometryCollection<T>
+where
+    T: GeoNum,
+{
+    fn orient(&self, direction: Direction) -> GeometryCollection<T> {
+        GeometryCollection::new_from(self.iter().map(|g| g.orient(direction)).collect())
+    }
+}
+
+impl<T> OrientInPlace for GeometryCollection<T>
+where
+    T: GeoNum,
+{
+    fn orient_in_place(&mut self, direction: Direction) {
+        for geometry in self.iter_mut() {
+            geometry.orient_in_place(direction);
+        }
+    }
+}
+
+impl<T> Orient for Geometry<T>
+where
+    T: GeoNum,
+{
+    fn orient(&self, direction: Direction) -> Geometry<T> {
+        match self {
+            Geometry::Polygon(g) => Geometry::Polygon(g.orient(direction)),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.orient(direction)),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.orient(direction)),
+            Geometry::Triangle(g) => Geometry::Triangle(g.orient(direction)),
+            _ => self.clone(),
+        }
+    }
+}
+
+impl<T> OrientInPlace for Geometry<T>
+where
+    T: GeoNum,
+{
+    fn orient_in_place(&mut self, direction: Direction) {
+        match self {
+            Geometry::Polygon(g) => g.orient_in_place(direction),
+            Geometry::MultiPolygon(g) => g.orient_in_place(direction),
+            Geometry::GeometryCollection(g) => g.orient_in_place(direction),
+            Geometry::Triangle(g) => g.orient_in_place(direction),
+            _ => {}
+        }
+    }
+}
+
 /// By default, a properly-oriented Polygon has its outer ring oriented counter-clockwise,
 /// and its inner ring(s) oriented clockwise. Selecting `Reversed` will result in a Polygon
 /// with a clockwise-oriented exterior ring, and counter-clockwise interior ring(s)
@@ -94,6 +217,26 @@ pub enum Direction {
     Reversed,
 }
 
+impl Direction {
+    /// The orientation required by [RFC 7946] (GeoJSON), which is the same as
+    /// [`Direction::Default`]: exterior ring is oriented counter-clockwise, interior rings are
+    /// oriented clockwise.
+    ///
+    /// [RFC 7946]: https://datatracker.ietf.org/doc/html/rfc7946#section-3.1.6
+    pub const RFC7946: Direction = Direction::Default;
+
+    fn exterior(self) -> WindingOrder {
+        match self {
+            Direction::Default => WindingOrder::CounterClockwise,
+            Direction::Reversed => WindingOrder::Clockwise,
+        }
+    }
+
+    fn interior(self) -> WindingOrder {
+        self.exterior().inverse()
+    }
+}
+
 // orient a Polygon according to convention
 // by default, the exterior ring will be oriented ccw
 // and the interior ring(s) will be oriented clockwise
@@ -104,18 +247,10 @@ where
     let interiors = poly
         .interiors()
         .iter()
-        .map(|l| {
-            l.clone_to_winding_order(match direction {
-                Direction::Default => WindingOrder::Clockwise,
-                Direction::Reversed => WindingOrder::CounterClockwise,
-            })
-        })
+        .map(|l| l.clone_to_winding_order(direction.interior()))
         .collect();
 
-    let ext_ring = poly.exterior().clone_to_winding_order(match direction {
-        Direction::Default => WindingOrder::CounterClockwise,
-        Direction::Reversed => WindingOrder::Clockwise,
-    });
+    let ext_ring = poly.exterior().clone_to_winding_order(direction.exterior());
 
     Polygon::new(ext_ring, interiors)
 }
@@ -123,7 +258,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{LineString, Polygon};
+    use crate::{wkt, LineString, Polygon, Winding};
     #[test]
     fn test_polygon_orientation() {
         // a diamond shape, oriented clockwise outside
@@ -145,4 +280,67 @@ mod test {
         assert_eq!(oriented.exterior().0, oriented_ext_ls.0);
         assert_eq!(oriented.interiors()[0].0, oriented_int_ls.0);
     }
+
+    #[test]
+    fn test_orient_in_place_matches_orient() {
+        let polygon: Polygon = wkt!(POLYGON(
+            (1. 0.,0. 1.,1. 2.,2. 1.,1. 0.),
+            (1. 0.5,1.5 1.,1. 1.5,0.5 1.,1. 0.5)
+        ));
+        for direction in [Direction::Default, Direction::Reversed] {
+            let mut in_place = polygon.clone();
+            in_place.orient_in_place(direction);
+            assert_eq!(in_place, polygon.orient(direction));
+        }
+        assert_eq!(
+            polygon.orient(Direction::RFC7946),
+            polygon.orient(Direction::Default)
+        );
+    }
+
+    #[test]
+    fn test_geometry_collection_orientation() {
+        let square: Polygon = wkt!(POLYGON((0. 0.,0. 1.,1. 1.,1. 0.,0. 0.)));
+        let collection = GeometryCollection::new_from(vec![
+            wkt!(POINT(0. 0.)).into(),
+            wkt!(LINESTRING(0. 0.,0. 1.,1. 1.)).into(),
+            square.clone().into(),
+            MultiPolygon::new(vec![square.clone()]).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![square.into()])),
+            // clockwise
+            Triangle::from([(0., 0.), (0., 1.), (1., 0.)]).into(),
+        ]);
+
+        let oriented = collection.orient(Direction::Default);
+        assert_eq!(oriented[0], collection[0]);
+        assert_eq!(oriented[1], collection[1]);
+        let Geometry::Polygon(polygon) = &oriented[2] else {
+            panic!("expected a polygon")
+        };
+        assert!(polygon.exterior().is_ccw());
+        let Geometry::MultiPolygon(multi_polygon) = &oriented[3] else {
+            panic!("expected a multipolygon")
+        };
+        assert!(multi_polygon.0[0].exterior().is_ccw());
+        let Geometry::GeometryCollection(nested) = &oriented[4] else {
+            panic!("expected a geometry collection")
+        };
+        let Geometry::Polygon(polygon) = &nested[0] else {
+            panic!("expected a polygon")
+        };
+        assert!(polygon.exterior().is_ccw());
+        let Geometry::Triangle(triangle) = &oriented[5] else {
+            panic!("expected a triangle")
+        };
+        assert!(triangle.is_ccw());
+        assert_eq!(
+            collection.orient(Direction::Reversed)[5],
+            collection[5],
+            "already clockwise"
+        );
+
+        let mut in_place = collection.clone();
+        in_place.orient_in_place(Direction::Default);
+        assert_eq!(in_place, oriented);
+    }
 }
@@ -112,6 +112,40 @@ where
     /// A [`PreparedDetector`] can efficiently recompute outliers with different `k_neigbhours` values.
     fn prepared_detector(&self) -> PreparedDetector<T>;
 
+    /// Create a prepared outlier detector measuring distances with the given [`OutlierMetric`],
+    /// e.g. to detect outliers among lon/lat points.
+    ///
+    /// [`prepared_detector`](Self::prepared_detector) and the other methods of this trait use
+    /// [`OutlierMetric::Euclidean`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::{point, Distance, Haversine, OutlierDetection, OutlierMethod, OutlierMetric};
+    ///
+    /// // points around the antimeridian, where the third is far from the others
+    /// let v = vec![
+    ///     point!(x: 179.9, y: 10.0),
+    ///     point!(x: -179.9, y: 10.0),
+    ///     point!(x: 170.0, y: 10.0),
+    ///     point!(x: 179.9, y: 10.1),
+    /// ];
+    ///
+    /// let detector = v.prepared_detector_with_metric(OutlierMetric::Haversine);
+    /// let scores = detector.scores(OutlierMethod::KnnDistance { k_neighbours: 1 });
+    /// // the distance to the nearest neighbour, in meters
+    /// assert_relative_eq!(scores[0], Haversine.distance(v[0], v[3]), epsilon = 1e-6);
+    /// assert_relative_eq!(scores[2], Haversine.distance(v[2], v[3]), epsilon = 1e-6);
+    /// assert!(scores[2] > 10. * scores[1]);
+    /// ```
+    fn prepared_detector_with_metric(&self, metric: OutlierMetric) -> PreparedDetector<'_, T>
+    where
+        T: Sum,
+    {
+        self.prepared_detector().with_metric(metric)
+    }
+
     /// Perform successive runs with `k_neighbours` values between `bounds`,
     /// generating an ensemble of LOF scores, which may be aggregated using e.g. min, max, or mean
     ///
@@ -158,6 +192,119 @@ where
 
     /// Convenience method to efficiently calculate the maximum values of an LOF ensemble
     fn ensemble_max(&self, bounds: RangeInclusive<usize>) -> Vec<T>;
+
+    /// Score every point with the given [`OutlierMethod`], in input point order.
+    ///
+    /// Higher scores indicate likelier outliers, but their scale depends on the method: see
+    /// [`OutlierMethod`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, OutlierDetection, OutlierMethod};
+    ///
+    /// let v = vec![
+    ///     point!(x: 0.0, y: 0.0),
+    ///     point!(x: 0.0, y: 1.0),
+    ///     point!(x: 3.0, y: 0.0),
+    ///     point!(x: 1.0, y: 1.0),
+    /// ];
+    ///
+    /// // the distance of each point to its nearest neighbour
+    /// let scores = v.outlier_scores(OutlierMethod::KnnDistance { k_neighbours: 1 });
+    /// assert_eq!(scores, vec![1.0, 1.0, 2.23606797749979, 1.0]);
+    ///
+    /// // the third point is easiest to isolate
+    /// let scores = v.outlier_scores(OutlierMethod::IsolationForest {
+    ///     trees: 100,
+    ///     sample_size: 256,
+    ///     seed: 0,
+    /// });
+    /// assert!(scores[2] > scores[0] && scores[2] > scores[1] && scores[2] > scores[3]);
+    /// ```
+    fn outlier_scores(&self, method: OutlierMethod) -> Vec<T>
+    where
+        T: Sum,
+    {
+        self.prepared_detector().scores(method)
+    }
+
+    /// The indices of the points whose [score](Self::outlier_scores) with the given
+    /// [`OutlierMethod`] is greater than `threshold`, in input point order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, OutlierDetection, OutlierMethod};
+    ///
+    /// let v = vec![
+    ///     point!(x: 0.0, y: 0.0),
+    ///     point!(x: 0.0, y: 1.0),
+    ///     point!(x: 3.0, y: 0.0),
+    ///     point!(x: 1.0, y: 1.0),
+    /// ];
+    ///
+    /// let method = OutlierMethod::LocalOutlierFactor { k_neighbours: 2 };
+    /// assert_eq!(v.outlier_indices(method, 1.5), vec![2]);
+    /// ```
+    fn outlier_indices(&self, method: OutlierMethod, threshold: T) -> Vec<usize>
+    where
+        T: Sum,
+    {
+        self.outlier_scores(method)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, score)| (score > threshold).then_some(index))
+            .collect()
+    }
+}
+
+/// The algorithms available to [`OutlierDetection::outlier_scores`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutlierMethod {
+    /// The [Local Outlier Factor](OutlierDetection::outliers) of each point among its
+    /// `k_neighbours` nearest neighbours, which compares its density to theirs.
+    ///
+    /// Scores around 1 or below are inliers.
+    LocalOutlierFactor { k_neighbours: usize },
+    /// The distance from each point to its `k_neighbours`th nearest neighbour, in the units of
+    /// the coordinates.
+    ///
+    /// Unlike LOF, this doesn't adapt to areas of differing density, but it is cheaper and its
+    /// scores are easier to threshold. If `k_neighbours` >= points in the set, or
+    /// `k_neighbours` < 1, all points score 0.
+    KnnDistance { k_neighbours: usize },
+    /// An [isolation forest](https://en.wikipedia.org/wiki/Isolation_forest) of `trees` random
+    /// trees, each built from `sample_size` points drawn with the given `seed`, which scores
+    /// points by how few random axis-aligned splits isolate them.
+    ///
+    /// Scores are between 0 and 1: close to 1 for outliers, and 0.5 or below for inliers. The
+    /// paper introducing it suggests 100 trees of 256 points. The same `seed` gives the same
+    /// scores.
+    ///
+    /// Based on: Liu, F. T., Ting, K. M., and Zhou, Z.-H. (2008). *Isolation Forest.* In IEEE
+    /// Int. Conf. on Data Mining, pages 413-422. doi:
+    /// [10.1109/ICDM.2008.17](https://doi.org/10.1109/ICDM.2008.17)
+    IsolationForest {
+        trees: usize,
+        sample_size: usize,
+        seed: u64,
+    },
+}
+
+/// How a [`PreparedDetector`] measures the distances between points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OutlierMetric {
+    /// Planar distances, in the units of the coordinates.
+    #[default]
+    Euclidean,
+    /// [Haversine](crate::Haversine) distances in meters, for points whose x/y are lon/lat
+    /// degrees.
+    ///
+    /// Unlike Euclidean distances between lon/lat coordinates, these aren't distorted at high
+    /// latitudes or across the antimeridian. Isolation forests split the points' positions on
+    /// the sphere rather than their coordinates.
+    Haversine,
 }
 
 /// This struct allows multiple detection operations to be run on a point set using varying `k_neighbours` sizes
@@ -169,39 +316,284 @@ pub struct PreparedDetector<'a, T>
 where
     T: GeoFloat,
 {
-    tree: RTree<GeomWithData<Point<T>, usize>>,
+    index: NeighbourIndex<T>,
     points: &'a [Point<T>],
 }
 
+/// The spatial index of a [`PreparedDetector`], for its [`OutlierMetric`].
+#[derive(Clone, Debug)]
+enum NeighbourIndex<T: GeoFloat> {
+    Planar(RTree<GeomWithData<Point<T>, usize>>),
+    /// The points on the unit sphere, where the straight line distances between them order
+    /// them as their great circle distances do.
+    Spherical(RTree<GeomWithData<[T; 3], usize>>),
+}
+
 impl<'a, T> PreparedDetector<'a, T>
 where
     T: GeoFloat + Sum,
 {
     /// Create a new "prepared" detector which allows repeated LOF algorithm calls with varying neighbour sizes
-    fn new(points: &'a [Point<T>]) -> Self {
-        let geoms: Vec<GeomWithData<_, usize>> = points
-            .iter()
-            .enumerate()
-            .map(|(idx, point)| GeomWithData::new(*point, idx))
-            .collect();
-        let tree = RTree::bulk_load(geoms);
-        Self { tree, points }
+    fn new(points: &'a [Point<T>], metric: OutlierMetric) -> Self {
+        let index = match metric {
+            OutlierMetric::Euclidean => NeighbourIndex::Planar(RTree::bulk_load(
+                points
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, point)| GeomWithData::new(*point, idx))
+                    .collect(),
+            )),
+            OutlierMetric::Haversine => NeighbourIndex::Spherical(RTree::bulk_load(
+                points
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, point)| GeomWithData::new(unit_vector(*point), idx))
+                    .collect(),
+            )),
+        };
+        Self { index, points }
+    }
+
+    /// Rebuild the spatial index of the detector for `metric`.
+    fn with_metric(self, metric: OutlierMetric) -> Self {
+        Self::new(self.points, metric)
     }
 
     /// See [`OutlierDetection::outliers`] for usage
     pub fn outliers(&self, kneighbours: usize) -> Vec<T> {
-        lof(self.points, &self.tree, kneighbours)
+        lof(self, kneighbours)
     }
+
+    /// See [`OutlierDetection::outlier_scores`] for usage
+    pub fn scores(&self, method: OutlierMethod) -> Vec<T> {
+        match method {
+            OutlierMethod::LocalOutlierFactor { k_neighbours } => self.outliers(k_neighbours),
+            OutlierMethod::KnnDistance { k_neighbours } => knn_distances(self, k_neighbours),
+            OutlierMethod::IsolationForest {
+                trees,
+                sample_size,
+                seed,
+            } => match &self.index {
+                NeighbourIndex::Planar(_) => {
+                    let points: Vec<_> = self
+                        .points
+                        .iter()
+                        .map(|p| [p.x(), p.y(), T::zero()])
+                        .collect();
+                    isolation_forest(&points, 2, trees, sample_size, seed)
+                }
+                NeighbourIndex::Spherical(_) => {
+                    let points: Vec<_> = self.points.iter().map(|p| unit_vector(*p)).collect();
+                    isolation_forest(&points, 3, trees, sample_size, seed)
+                }
+            },
+        }
+    }
+
+    /// The indices of the `k` nearest neighbours of `point`, including itself, with their
+    /// squared distances in the metric of the detector, nearest first.
+    fn neighbours(&self, point: Point<T>, k: usize) -> Vec<(usize, T)> {
+        match &self.index {
+            NeighbourIndex::Planar(tree) => tree
+                .nearest_neighbor_iter_with_distance_2(&point)
+                .take(k)
+                .map(|(neighbour, distance_2)| (neighbour.data, distance_2))
+                .collect(),
+            NeighbourIndex::Spherical(tree) => {
+                let radius = T::from(crate::Haversine.radius()).unwrap();
+                let two = T::one() + T::one();
+                tree.nearest_neighbor_iter_with_distance_2(&unit_vector(point))
+                    .take(k)
+                    .map(|(neighbour, chord_2)| {
+                        // the chord subtends an angle of 2 * asin(chord / 2)
+                        let distance = radius * two * (chord_2.sqrt() / two).min(T::one()).asin();
+                        (neighbour.data, distance * distance)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The position of a lon/lat `point` on the unit sphere.
+fn unit_vector<T: GeoFloat>(point: Point<T>) -> [T; 3] {
+    let (lon, lat) = (point.x().to_radians(), point.y().to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
 }
 
-fn lof<T>(
-    points: &[Point<T>],
-    tree: &RTree<GeomWithData<Point<T>, usize>>,
-    kneighbours: usize,
+fn knn_distances<T>(detector: &PreparedDetector<T>, kneighbours: usize) -> Vec<T>
+where
+    T: GeoFloat + Sum,
+{
+    let points = detector.points;
+    if points.len() <= kneighbours || kneighbours < 1 {
+        return points.iter().map(|_| T::zero()).collect();
+    }
+    points
+        .iter()
+        .map(|point| {
+            // the nearest neighbour of each point is itself, at distance 0
+            let (_, distance_2) = detector.neighbours(*point, kneighbours + 1)[kneighbours];
+            distance_2.sqrt()
+        })
+        .collect()
+}
+
+/// Score `points`, which vary along their first `axes` ordinates, with an isolation forest.
+fn isolation_forest<T>(
+    points: &[[T; 3]],
+    axes: usize,
+    trees: usize,
+    sample_size: usize,
+    seed: u64,
 ) -> Vec<T>
+where
+    T: GeoFloat,
+{
+    let sample_size = sample_size.min(points.len());
+    if sample_size < 2 || trees < 1 {
+        // nothing can be isolated
+        return points.iter().map(|_| T::from(0.5).unwrap()).collect();
+    }
+    // trees stop growing at the average depth of an unsuccessful search
+    let max_depth = (sample_size as f64).log2().ceil() as usize;
+    let mut rng = SplitMix64(seed);
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    let mut routed = indices.clone();
+    let mut path_lengths = vec![0.0; points.len()];
+    for _ in 0..trees {
+        // a partial Fisher-Yates shuffle draws the sample without replacement
+        for i in 0..sample_size {
+            let j = i + (rng.next_f64() * (points.len() - i) as f64) as usize;
+            indices.swap(i, j);
+        }
+        // every point follows the splits of the tree built from the sample
+        routed.iter_mut().enumerate().for_each(|(i, r)| *r = i);
+        isolate(
+            points,
+            axes,
+            &mut indices[..sample_size],
+            &mut routed,
+            0,
+            max_depth,
+            &mut rng,
+            &mut path_lengths,
+        );
+    }
+    let normalization = average_path_length(sample_size) * trees as f64;
+    path_lengths
+        .into_iter()
+        .map(|length| T::from(2f64.powf(-length / normalization)).unwrap())
+        .collect()
+}
+
+/// Split `sample` at random until each point is isolated or `max_depth` is reached, adding
+/// the depth at which each of the `routed` points ends to its path length.
+#[allow(clippy::too_many_arguments)]
+fn isolate<T: GeoFloat>(
+    points: &[[T; 3]],
+    axes: usize,
+    sample: &mut [usize],
+    routed: &mut [usize],
+    depth: usize,
+    max_depth: usize,
+    rng: &mut SplitMix64,
+    path_lengths: &mut [f64],
+) {
+    if routed.is_empty() {
+        return;
+    }
+    let leaf = |routed: &[usize], path_lengths: &mut [f64]| {
+        // points left in a leaf would take about this many more splits to isolate
+        let length = depth as f64 + average_path_length(sample.len());
+        routed.iter().for_each(|&i| path_lengths[i] += length);
+    };
+    if sample.len() < 2 || depth >= max_depth {
+        return leaf(routed, path_lengths);
+    }
+
+    let coord = |i: usize, axis: usize| points[i][axis];
+    // pick an axis at random, or the next one along which the sample isn't constant
+    let first_axis = (rng.next_f64() * axes as f64) as usize;
+    let split = (0..axes).find_map(|offset| {
+        let axis = (first_axis + offset) % axes;
+        let (min, max) = sample.iter().fold(
+            (coord(sample[0], axis), coord(sample[0], axis)),
+            |(min, max), &i| (min.min(coord(i, axis)), max.max(coord(i, axis))),
+        );
+        let split = min + (max - min) * T::from(rng.next_f64()).unwrap();
+        // a split equal to the minimum would leave the left side empty
+        (min < max && split > min).then_some((axis, split))
+    });
+    let Some((axis, split)) = split else {
+        // all of the sample is at the same point
+        return leaf(routed, path_lengths);
+    };
+
+    let sample_left = partition(sample, |i| coord(i, axis) < split);
+    let routed_left = partition(routed, |i| coord(i, axis) < split);
+    let (sample_low, sample_high) = sample.split_at_mut(sample_left);
+    let (routed_low, routed_high) = routed.split_at_mut(routed_left);
+    for (sample, routed) in [(sample_low, routed_low), (sample_high, routed_high)] {
+        isolate(
+            points,
+            axes,
+            sample,
+            routed,
+            depth + 1,
+            max_depth,
+            rng,
+            path_lengths,
+        );
+    }
+}
+
+/// Move the indices satisfying `predicate` to the start of `indices`, returning their count.
+fn partition(indices: &mut [usize], predicate: impl Fn(usize) -> bool) -> usize {
+    let mut count = 0;
+    for i in 0..indices.len() {
+        if predicate(indices[i]) {
+            indices.swap(count, i);
+            count += 1;
+        }
+    }
+    count
+}
+
+/// The average path length of an unsuccessful search in a binary search tree of `n` points.
+fn average_path_length(n: usize) -> f64 {
+    match n {
+        0 | 1 => 0.0,
+        2 => 1.0,
+        n => {
+            let n = n as f64;
+            const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9;
+            2.0 * ((n - 1.0).ln() + EULER_MASCHERONI) - 2.0 * (n - 1.0) / n
+        }
+    }
+}
+
+/// A small, seedable pseudorandom number generator, so that isolation forests are reproducible
+/// without a dependency on `rand`.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// A uniformly distributed number in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn lof<T>(detector: &PreparedDetector<T>, kneighbours: usize) -> Vec<T>
 where
     T: GeoFloat + Sum,
 {
+    let points = detector.points;
     debug_assert!(kneighbours > 0);
     if points.len() <= kneighbours || kneighbours < 1 {
         // no point in trying to run the algorithm in this case
@@ -209,11 +601,7 @@ where
     }
     let knn_dists = points
         .iter()
-        .map(|point| {
-            tree.nearest_neighbor_iter_with_distance_2(point)
-                .take(kneighbours)
-                .collect()
-        })
+        .map(|point| detector.neighbours(*point, kneighbours))
         .collect::<Vec<Vec<_>>>();
     // calculate LRD (local reachability density) of each point
     // LRD is the estimated distance at which a point can be found by its neighbours:
@@ -251,7 +639,7 @@ where
             // sum neighbour set LRD scores
             let lrd_scores: T = neighbours
                 .iter()
-                .map(|(neighbour, _)| local_reachability_densities[neighbour.data])
+                .map(|(neighbour, _)| local_reachability_densities[*neighbour])
                 .sum();
             // sum neighbour set reachDistance
             let sum_rd: T = neighbours
@@ -273,7 +661,11 @@ where
     }
 
     fn prepared_detector(&self) -> PreparedDetector<T> {
-        PreparedDetector::new(&self.0)
+        PreparedDetector::new(&self.0, OutlierMetric::Euclidean)
+    }
+
+    fn prepared_detector_with_metric(&self, metric: OutlierMetric) -> PreparedDetector<'_, T> {
+        PreparedDetector::new(&self.0, metric)
     }
 
     fn generate_ensemble(&self, bounds: RangeInclusive<usize>) -> Vec<Vec<T>> {
@@ -307,7 +699,11 @@ where
     }
 
     fn prepared_detector(&self) -> PreparedDetector<T> {
-        PreparedDetector::new(self)
+        PreparedDetector::new(self, OutlierMetric::Euclidean)
+    }
+
+    fn prepared_detector_with_metric(&self, metric: OutlierMetric) -> PreparedDetector<'_, T> {
+        PreparedDetector::new(self, metric)
     }
 
     fn generate_ensemble(&self, bounds: RangeInclusive<usize>) -> Vec<Vec<T>> {
@@ -442,4 +838,141 @@ mod tests {
         // different neighbour sizes give different scores
         assert_ne!(s1[2], s2[2]);
     }
+
+    fn four_outliers() -> Vec<Point> {
+        // indices 6, 7, 8, 9 are outliers, as in test_lof4
+        vec![
+            point!(x: 0.16, y: 0.14),
+            point!(x: 0.15, y: 0.33),
+            point!(x: 0.37, y: 0.25),
+            point!(x: 0.3 , y: 0.4),
+            point!(x: 0.3 , y: 0.1),
+            point!(x: 0.3 , y: 0.2),
+            point!(x: 1.3 , y: 2.3),
+            point!(x: 1.7 , y: 0.2),
+            point!(x: 0.7 , y: -0.9),
+            point!(x: 0.21, y: 2.45),
+            point!(x: 0.8 , y: 0.7),
+            point!(x: 0.9 , y: 0.7),
+            point!(x: 0.8 , y: 0.6),
+            point!(x: 0.73, y: 0.65),
+            point!(x: 0.9 , y: 0.6),
+            point!(x: 1.0, y: 0.6),
+            point!(x: 1.0, y: 0.7),
+            point!(x: 0.25, y: 0.29),
+            point!(x: 0.2 , y: 0.2),
+        ]
+    }
+
+    #[test]
+    fn test_knn_distance() {
+        let v = four_outliers();
+        let method = OutlierMethod::KnnDistance { k_neighbours: 2 };
+        assert_eq!(v.outlier_indices(method, 0.5), vec![6, 7, 8, 9]);
+        assert_eq!(
+            v[..2].outlier_scores(OutlierMethod::KnnDistance { k_neighbours: 2 }),
+            vec![0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_isolation_forest() {
+        let v = four_outliers();
+        let method = OutlierMethod::IsolationForest {
+            trees: 100,
+            sample_size: 256,
+            seed: 42,
+        };
+        let scores = v.outlier_scores(method);
+        assert!(scores.iter().all(|score| (0.0..=1.0).contains(score)));
+        // reproducible with the same seed
+        assert_eq!(scores, v.outlier_scores(method));
+        assert_eq!(scores, MultiPoint::from(v.clone()).outlier_scores(method));
+
+        let mut indices: Vec<usize> = (0..v.len()).collect();
+        indices.sort_by(|&a, &b| scores[b].partial_cmp(&scores[a]).unwrap());
+        indices.truncate(4);
+        indices.sort();
+        assert_eq!(indices, vec![6, 7, 8, 9]);
+
+        // identical points can't be isolated
+        let same = [Point::new(1.0, 1.0); 3];
+        for score in same.outlier_scores(method) {
+            assert_relative_eq!(score, 0.5, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_haversine_metric() {
+        // a cluster straddling the antimeridian, and an outlier at index 6
+        let v = [
+            point!(x: 179.8, y: 0.0),
+            point!(x: 179.9, y: 0.1),
+            point!(x: 179.9, y: -0.1),
+            point!(x: -179.8, y: 0.0),
+            point!(x: -179.9, y: 0.1),
+            point!(x: -179.9, y: -0.1),
+            point!(x: 179.0, y: 1.0),
+        ];
+        let most_outlying = |scores: Vec<f64>| {
+            (0..scores.len())
+                .max_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap())
+                .unwrap()
+        };
+        let detector = v.prepared_detector_with_metric(OutlierMetric::Haversine);
+
+        // in the plane, the two halves of the cluster are far apart
+        let method = OutlierMethod::KnnDistance { k_neighbours: 3 };
+        assert_ne!(most_outlying(v.outlier_scores(method)), 6);
+        assert_eq!(most_outlying(detector.scores(method)), 6);
+
+        let method = OutlierMethod::LocalOutlierFactor { k_neighbours: 3 };
+        assert_eq!(most_outlying(detector.scores(method)), 6);
+
+        let method = OutlierMethod::IsolationForest {
+            trees: 100,
+            sample_size: 256,
+            seed: 1,
+        };
+        assert_eq!(most_outlying(detector.scores(method)), 6);
+    }
+
+    #[test]
+    fn test_default_prepared_detector_with_metric() {
+        // an implementor providing only the required methods
+        struct Wrapper(Vec<Point<f64>>);
+        impl OutlierDetection<f64> for Wrapper {
+            fn outliers(&self, k_neighbours: usize) -> Vec<f64> {
+                self.0.outliers(k_neighbours)
+            }
+            fn prepared_detector(&self) -> PreparedDetector<'_, f64> {
+                self.0.prepared_detector()
+            }
+            fn generate_ensemble(&self, bounds: RangeInclusive<usize>) -> Vec<Vec<f64>> {
+                self.0.generate_ensemble(bounds)
+            }
+            fn ensemble_min(&self, bounds: RangeInclusive<usize>) -> Vec<f64> {
+                self.0.ensemble_min(bounds)
+            }
+            fn ensemble_max(&self, bounds: RangeInclusive<usize>) -> Vec<f64> {
+                self.0.ensemble_max(bounds)
+            }
+        }
+
+        let wrapper = Wrapper(vec![
+            point!(x: 179.9, y: 0.0),
+            point!(x: -179.9, y: 0.0),
+            point!(x: 179.0, y: 0.0),
+        ]);
+        let method = OutlierMethod::KnnDistance { k_neighbours: 1 };
+        assert_eq!(
+            wrapper
+                .prepared_detector_with_metric(OutlierMetric::Haversine)
+                .scores(method),
+            wrapper
+                .0
+                .prepared_detector_with_metric(OutlierMetric::Haversine)
+                .scores(method)
+        );
+    }
 }
@@ -0,0 +1,428 @@
+//! Parallel versions of algorithms on collections of geometries, using [rayon].
+//!
+//! Each trait here mirrors an existing algorithm trait, with methods prefixed by `par_`, and
+//! processes the members of a `Multi*` geometry or
+//! [`GeometryCollection`](crate::GeometryCollection) in parallel on the rayon thread pool. This
+//! pays off for collections of many, or very large, members; for small collections the
+//! sequential version is usually faster.
+//!
+//! Results are the same as those of the sequential versions, except that sums of floating
+//! point numbers (e.g. [`par_signed_area`](ParallelArea::par_signed_area)) may differ in the
+//! last few bits, as they are summed in a different order.
+//!
+//! Requires the `multithreading` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::{wkt, Area, MultiPolygon, ParallelArea, ParallelSimplify, Simplify};
+//!
+//! let square = wkt!(POLYGON((0. 0.,1. 0.,1. 0.5,1. 1.,0. 1.,0. 0.)));
+//! let multi_polygon = MultiPolygon::new(vec![square; 1000]);
+//!
+//! assert_eq!(multi_polygon.par_unsigned_area(), multi_polygon.unsigned_area());
+//! assert_eq!(multi_polygon.par_simplify(&0.1), multi_polygon.simplify(&0.1));
+//! ```
+//!
+//! [rayon]: https://docs.rs/rayon
+
+use crate::bounding_rect::bounding_rect_merge;
+use crate::line_measures::{Densify, Distance, InterpolatePoint, Length};
+use crate::validation::{self, InvalidPolygon, RingChecks};
+use crate::{
+    Area, BoundingRect, CoordFloat, CoordNum, GeoFloat, GeometryCollection, HasDimensions,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Simplify, Validation,
+};
+use num_traits::FromPrimitive;
+use rayon::prelude::*;
+
+/// Calculate the area of a collection of geometries in parallel. See [`Area`].
+pub trait ParallelArea<T: CoordNum> {
+    fn par_signed_area(&self) -> T;
+
+    fn par_unsigned_area(&self) -> T;
+}
+
+impl<T: CoordFloat + Send + Sync> ParallelArea<T> for MultiPolygon<T> {
+    fn par_signed_area(&self) -> T {
+        self.0
+            .par_iter()
+            .map(|polygon| polygon.signed_area())
+            .reduce(T::zero, |a, b| a + b)
+    }
+
+    /// As with [`Area::unsigned_area`], this is the absolute value of the sum of the signed
+    /// areas of the polygons.
+    fn par_unsigned_area(&self) -> T {
+        self.0
+            .par_iter()
+            .map(|polygon| polygon.signed_area().abs())
+            .reduce(T::zero, |a, b| a + b)
+    }
+}
+
+impl<T: CoordFloat + Send + Sync> ParallelArea<T> for GeometryCollection<T> {
+    fn par_signed_area(&self) -> T {
+        self.0
+            .par_iter()
+            .map(|geometry| geometry.signed_area())
+            .reduce(T::zero, |a, b| a + b)
+    }
+
+    fn par_unsigned_area(&self) -> T {
+        self.0
+            .par_iter()
+            .map(|geometry| geometry.unsigned_area())
+            .reduce(T::zero, |a, b| a + b)
+    }
+}
+
+/// Calculate the length of a collection of geometries in parallel. See [`Length`].
+pub trait ParallelLength<F: CoordFloat> {
+    fn par_length(&self, metric_space: &(impl Distance<F, Point<F>, Point<F>> + Sync)) -> F;
+}
+
+impl<F: CoordFloat + Send + Sync> ParallelLength<F> for MultiLineString<F> {
+    fn par_length(&self, metric_space: &(impl Distance<F, Point<F>, Point<F>> + Sync)) -> F {
+        self.0
+            .par_iter()
+            .map(|line_string| line_string.length(metric_space))
+            .reduce(F::zero, |a, b| a + b)
+    }
+}
+
+/// Calculate the bounding rectangle of a collection of geometries in parallel. See
+/// [`BoundingRect`].
+pub trait ParallelBoundingRect<T: CoordNum> {
+    fn par_bounding_rect(&self) -> Option<Rect<T>>;
+}
+
+fn par_bounding_rect<'a, T, G>(members: &'a [G]) -> Option<Rect<T>>
+where
+    T: CoordNum + Send,
+    G: BoundingRect<T> + Sync + 'a,
+    G::Output: Into<Option<Rect<T>>>,
+{
+    members
+        .par_iter()
+        .filter_map(|member| member.bounding_rect().into())
+        .reduce_with(bounding_rect_merge)
+}
+
+impl<T: CoordNum + Send + Sync> ParallelBoundingRect<T> for MultiPoint<T> {
+    fn par_bounding_rect(&self) -> Option<Rect<T>> {
+        par_bounding_rect(&self.0)
+    }
+}
+
+impl<T: CoordNum + Send + Sync> ParallelBoundingRect<T> for MultiLineString<T> {
+    fn par_bounding_rect(&self) -> Option<Rect<T>> {
+        par_bounding_rect(&self.0)
+    }
+}
+
+impl<T: CoordNum + Send + Sync> ParallelBoundingRect<T> for MultiPolygon<T> {
+    fn par_bounding_rect(&self) -> Option<Rect<T>> {
+        par_bounding_rect(&self.0)
+    }
+}
+
+impl<T: CoordNum + Send + Sync> ParallelBoundingRect<T> for GeometryCollection<T> {
+    fn par_bounding_rect(&self) -> Option<Rect<T>> {
+        par_bounding_rect(&self.0)
+    }
+}
+
+/// Densify a collection of geometries in parallel. See [`Densify`].
+pub trait ParallelDensify<F: CoordFloat> {
+    type Output;
+
+    fn par_densify<MetricSpace>(
+        &self,
+        metric_space: &MetricSpace,
+        max_segment_length: F,
+    ) -> Self::Output
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F> + Sync;
+}
+
+impl<F: CoordFloat + FromPrimitive + Send + Sync> ParallelDensify<F> for MultiLineString<F> {
+    type Output = Self;
+
+    fn par_densify<MetricSpace>(
+        &self,
+        metric_space: &MetricSpace,
+        max_segment_length: F,
+    ) -> Self::Output
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F> + Sync,
+    {
+        MultiLineString::new(
+            self.0
+                .par_iter()
+                .map(|line_string| line_string.densify(metric_space, max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<F: CoordFloat + FromPrimitive + Send + Sync> ParallelDensify<F> for MultiPolygon<F> {
+    type Output = Self;
+
+    fn par_densify<MetricSpace>(
+        &self,
+        metric_space: &MetricSpace,
+        max_segment_length: F,
+    ) -> Self::Output
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>> + InterpolatePoint<F> + Sync,
+    {
+        MultiPolygon::new(
+            self.0
+                .par_iter()
+                .map(|polygon| polygon.densify(metric_space, max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+/// Simplify a collection of geometries in parallel. See [`Simplify`].
+pub trait ParallelSimplify<T> {
+    fn par_simplify(&self, epsilon: &T) -> Self;
+}
+
+impl<T: GeoFloat + Send + Sync> ParallelSimplify<T> for MultiLineString<T> {
+    fn par_simplify(&self, epsilon: &T) -> Self {
+        MultiLineString::new(
+            self.0
+                .par_iter()
+                .map(|line_string| line_string.simplify(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat + Send + Sync> ParallelSimplify<T> for MultiPolygon<T> {
+    fn par_simplify(&self, epsilon: &T) -> Self {
+        MultiPolygon::new(
+            self.0
+                .par_iter()
+                .map(|polygon| polygon.simplify(epsilon))
+                .collect(),
+        )
+    }
+}
+
+/// Validate a polygon in parallel. See [`Validation`].
+///
+/// Each ring of the polygon is checked, and then each interior ring against the exterior ring
+/// and the other interior rings, on the rayon thread pool. This pays off for polygons with many
+/// interior rings, such as cadastral parcels. The errors are the same, and in the same order, as
+/// those of [`Validation::validation_errors`].
+///
+/// Rings are checked in batches, so that [`par_is_valid`](Self::par_is_valid) and
+/// [`par_check_validation`](Self::par_check_validation) stop after the first batch with an
+/// error.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, LineString, ParallelValidation, Polygon, Rect, Validation};
+///
+/// let holes: Vec<LineString> = (0..20)
+///     .flat_map(|x| (0..20).map(move |y| (x, y)))
+///     .map(|(x, y)| {
+///         let min = coord! { x: 3.0 * x as f64 + 1.0, y: 3.0 * y as f64 + 1.0 };
+///         Rect::new(min, min + coord! { x: 1.0, y: 1.0 }).to_polygon().into_inner().0
+///     })
+///     .collect();
+/// let exterior = Rect::new((0., 0.), (60., 60.)).to_polygon().into_inner().0;
+/// let polygon = Polygon::new(exterior, holes);
+///
+/// assert!(polygon.par_is_valid());
+/// assert_eq!(polygon.par_validation_errors(), polygon.validation_errors());
+/// ```
+pub trait ParallelValidation: Validation {
+    fn par_is_valid(&self) -> bool {
+        self.par_check_validation().is_ok()
+    }
+
+    fn par_check_validation(&self) -> Result<(), Self::Error>;
+
+    fn par_validation_errors(&self) -> Vec<Self::Error>;
+}
+
+impl<F: GeoFloat + Send + Sync> ParallelValidation for Polygon<F> {
+    fn par_check_validation(&self) -> Result<(), Self::Error> {
+        par_visit_polygon_validation(self, Err)
+    }
+
+    fn par_validation_errors(&self) -> Vec<Self::Error> {
+        let mut validation_errors = Vec::new();
+        par_visit_polygon_validation(self, |problem| {
+            validation_errors.push(problem);
+            Ok::<(), Self::Error>(())
+        })
+        .expect("no errors are returned");
+        validation_errors
+    }
+}
+
+/// The number of rings checked in parallel before reporting their errors.
+const VALIDATION_BATCH_SIZE: usize = 256;
+
+fn par_visit_polygon_validation<F: GeoFloat + Send + Sync, T>(
+    polygon: &Polygon<F>,
+    mut handle_validation_error: impl FnMut(InvalidPolygon) -> Result<(), T>,
+) -> Result<(), T> {
+    if polygon.is_empty() {
+        return Ok(());
+    }
+
+    let rings: Vec<_> = validation::rings(polygon).collect();
+    for batch in rings.chunks(VALIDATION_BATCH_SIZE) {
+        let errors: Vec<_> = batch
+            .par_iter()
+            .map(|&(ring_role, ring)| validation::ring_errors(ring_role, ring))
+            .collect();
+        for error in errors.into_iter().flatten() {
+            handle_validation_error(error)?;
+        }
+    }
+
+    // `PreparedGeometry` can't be shared between threads, so each job prepares its own
+    // exterior
+    let ring_checks = RingChecks::new(polygon);
+    let indices: Vec<usize> = (0..ring_checks.len()).collect();
+    for batch in indices.chunks(VALIDATION_BATCH_SIZE) {
+        let errors: Vec<_> = batch
+            .par_iter()
+            .map_init(
+                || ring_checks.prepared_exterior(),
+                |exterior, &index| ring_checks.interior_errors(exterior, index),
+            )
+            .collect();
+        for error in errors.into_iter().flatten() {
+            handle_validation_error(error)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{wkt, Euclidean, Geometry, Haversine};
+
+    #[test]
+    fn matches_sequential() {
+        let multi_polygon: MultiPolygon = geo_test_fixtures::nl_plots_wgs84();
+        assert_relative_eq!(
+            multi_polygon.par_signed_area(),
+            multi_polygon.signed_area(),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            multi_polygon.par_unsigned_area(),
+            multi_polygon.unsigned_area(),
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            multi_polygon.par_bounding_rect(),
+            multi_polygon.bounding_rect()
+        );
+        assert_eq!(
+            multi_polygon.par_densify(&Haversine, 10.0),
+            multi_polygon.densify(&Haversine, 10.0)
+        );
+        assert_eq!(
+            multi_polygon.par_simplify(&1e-5),
+            multi_polygon.simplify(&1e-5)
+        );
+
+        let multi_line_string: MultiLineString = multi_polygon
+            .iter()
+            .map(|polygon| polygon.exterior().clone())
+            .collect();
+        assert_relative_eq!(
+            multi_line_string.par_length(&Euclidean),
+            multi_line_string.length(&Euclidean),
+            max_relative = 1e-12
+        );
+        assert_eq!(
+            multi_line_string.par_bounding_rect(),
+            multi_line_string.bounding_rect()
+        );
+        assert_eq!(
+            multi_line_string.par_densify(&Euclidean, 1e-4),
+            multi_line_string.densify(&Euclidean, 1e-4)
+        );
+        assert_eq!(
+            multi_line_string.par_simplify(&1e-5),
+            multi_line_string.simplify(&1e-5)
+        );
+    }
+
+    #[test]
+    fn geometry_collection() {
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::Polygon(wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)))),
+            Geometry::MultiPoint(MultiPoint::new(vec![])),
+            Geometry::Point(wkt!(POINT(5. - 1.))),
+        ]);
+        assert_eq!(collection.par_unsigned_area(), 4.);
+        assert_eq!(collection.par_signed_area(), collection.signed_area());
+        assert_eq!(
+            collection.par_bounding_rect(),
+            Some(Rect::new((0., -1.), (5., 2.)))
+        );
+        assert_eq!(
+            GeometryCollection::<f64>::default().par_bounding_rect(),
+            None
+        );
+        assert_eq!(MultiPoint::<f64>::new(vec![]).par_bounding_rect(), None);
+    }
+
+    #[test]
+    fn polygon_validation() {
+        use crate::validation::RingRole;
+        use crate::{coord, LineString};
+
+        let square = |x: f64, y: f64, size: f64| -> LineString {
+            let min = coord! { x: x, y: y };
+            Rect::new(min, min + coord! { x: size, y: size })
+                .to_polygon()
+                .into_inner()
+                .0
+        };
+        let mut holes: Vec<LineString> = (0..30)
+            .flat_map(|x| (0..30).map(move |y| (x, y)))
+            .map(|(x, y)| square(3.0 * x as f64 + 1.0, 3.0 * y as f64 + 1.0, 1.0))
+            .collect();
+        let polygon = Polygon::new(square(0., 0., 90.), holes.clone());
+        assert!(polygon.is_valid());
+        assert!(polygon.par_is_valid());
+
+        // A hole overlapping another, and one outside the exterior
+        holes.push(square(1.5, 1.5, 1.0));
+        holes.push(square(100., 100., 1.0));
+        let polygon = Polygon::new(square(0., 0., 90.), holes);
+        let errors = polygon.validation_errors();
+        assert_eq!(
+            errors,
+            vec![
+                InvalidPolygon::IntersectingRingsOnAnArea(
+                    RingRole::Interior(0),
+                    RingRole::Interior(900)
+                ),
+                InvalidPolygon::InteriorRingNotContainedInExteriorRing(RingRole::Interior(901)),
+            ]
+        );
+        assert_eq!(polygon.par_validation_errors(), errors);
+        assert_eq!(polygon.par_check_validation(), Err(errors[0].clone()));
+        assert!(!polygon.par_is_valid());
+
+        let empty: Polygon = wkt!(POLYGON EMPTY);
+        assert!(empty.par_is_valid());
+    }
+}
@@ -0,0 +1,176 @@
+//! Opt-in promotion of `f32` geometries to `f64` for precision-sensitive algorithms.
+//!
+//! Algorithms on `f32` geometries normally compute in `f32`, which can lose a lot of precision:
+//! the shoelace formula used by [`Area`] subtracts products of coordinates, and the overlay used
+//! by [`BooleanOps`] snaps intersections to the coordinate type. The traits here convert `f32`
+//! geometries to `f64`, run the algorithm, and convert the result back to `f32`, so only the
+//! final result is rounded.
+//!
+//! Promotion costs a copy of the inputs; see the `promote` benchmark for the overhead.
+//!
+//! [`Winding`](crate::Winding) needs no promotion: its orientation test always uses robust
+//! predicates evaluated in `f64`, which are exact for `f32` coordinates.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::{polygon, Area, Convert, Polygon, PromotedArea};
+//!
+//! let polygon: Polygon<f32> = polygon![
+//!     (x: 0., y: 0.),
+//!     (x: 1000.1, y: 0.1),
+//!     (x: 1000.1, y: 1000.1),
+//!     (x: 0.3, y: 999.9),
+//! ];
+//! let polygon_f64: Polygon<f64> = polygon.convert();
+//!
+//! // Rounding intermediate products to `f32` loses precision
+//! assert_eq!(polygon.unsigned_area(), 999900.);
+//! assert_eq!(polygon.unsigned_area_promoted(), 999899.94);
+//! assert_eq!(
+//!     polygon.unsigned_area_promoted(),
+//!     polygon_f64.unsigned_area() as f32
+//! );
+//! ```
+
+use crate::{Area, BooleanOps, Convert, Coord, MapCoords, MultiPolygon, OpType};
+
+/// Calculate the area of an `f32` geometry, computing internally in `f64`. See [`Area`].
+pub trait PromotedArea {
+    fn signed_area_promoted(&self) -> f32;
+
+    fn unsigned_area_promoted(&self) -> f32;
+}
+
+impl<G> PromotedArea for G
+where
+    G: Convert<f32, f64>,
+    G::Output: Area<f64>,
+{
+    fn signed_area_promoted(&self) -> f32 {
+        self.convert().signed_area() as f32
+    }
+
+    fn unsigned_area_promoted(&self) -> f32 {
+        self.convert().unsigned_area() as f32
+    }
+}
+
+/// Boolean operations on `f32` geometries, computing internally in `f64`. See [`BooleanOps`].
+///
+/// The output coordinates are rounded to `f32`, which may merge nearly coincident vertices of
+/// the `f64` output.
+pub trait PromotedBooleanOps {
+    fn boolean_op_promoted<G>(&self, other: &G, op: OpType) -> MultiPolygon<f32>
+    where
+        G: Convert<f32, f64>,
+        G::Output: BooleanOps<Scalar = f64>;
+
+    /// Returns the overlapping regions shared by both `self` and `other`.
+    fn intersection_promoted<G>(&self, other: &G) -> MultiPolygon<f32>
+    where
+        G: Convert<f32, f64>,
+        G::Output: BooleanOps<Scalar = f64>,
+    {
+        self.boolean_op_promoted(other, OpType::Intersection)
+    }
+
+    /// Combines the regions of both `self` and `other` into a single geometry, removing
+    /// overlaps and merging boundaries.
+    fn union_promoted<G>(&self, other: &G) -> MultiPolygon<f32>
+    where
+        G: Convert<f32, f64>,
+        G::Output: BooleanOps<Scalar = f64>,
+    {
+        self.boolean_op_promoted(other, OpType::Union)
+    }
+
+    /// The regions that are in either `self` or `other`, but not in both.
+    fn xor_promoted<G>(&self, other: &G) -> MultiPolygon<f32>
+    where
+        G: Convert<f32, f64>,
+        G::Output: BooleanOps<Scalar = f64>,
+    {
+        self.boolean_op_promoted(other, OpType::Xor)
+    }
+
+    /// The regions of `self` which are not in `other`.
+    fn difference_promoted<G>(&self, other: &G) -> MultiPolygon<f32>
+    where
+        G: Convert<f32, f64>,
+        G::Output: BooleanOps<Scalar = f64>,
+    {
+        self.boolean_op_promoted(other, OpType::Difference)
+    }
+}
+
+impl<G0> PromotedBooleanOps for G0
+where
+    G0: Convert<f32, f64>,
+    G0::Output: BooleanOps<Scalar = f64>,
+{
+    fn boolean_op_promoted<G>(&self, other: &G, op: OpType) -> MultiPolygon<f32>
+    where
+        G: Convert<f32, f64>,
+        G::Output: BooleanOps<Scalar = f64>,
+    {
+        self.convert()
+            .boolean_op(&other.convert(), op)
+            .map_coords(|Coord { x, y }| Coord {
+                x: x as f32,
+                y: y as f32,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, Polygon};
+
+    #[test]
+    fn area_matches_f64() {
+        let polygon = Polygon::new(geo_test_fixtures::norway_main::<f32>(), vec![]);
+        let polygon_f64: Polygon<f64> = polygon.convert();
+        assert_eq!(
+            polygon.signed_area_promoted(),
+            polygon_f64.signed_area() as f32
+        );
+        assert_eq!(
+            polygon.unsigned_area_promoted(),
+            polygon_f64.unsigned_area() as f32
+        );
+    }
+
+    #[test]
+    fn boolean_ops_match_f64() {
+        let a: Polygon<f32> = polygon![
+            (x: 8000., y: 0.),
+            (x: 8001., y: 0.),
+            (x: 8001., y: 1.),
+            (x: 8000., y: 1.),
+        ];
+        let b: MultiPolygon<f32> = polygon![
+            (x: 8000.5, y: 0.5),
+            (x: 8002., y: 0.5),
+            (x: 8002., y: 2.),
+            (x: 8000.5, y: 2.),
+        ]
+        .into();
+
+        let a_f64: Polygon<f64> = a.convert();
+        let b_f64: MultiPolygon<f64> = b.convert();
+        let expected: MultiPolygon<f32> =
+            a_f64
+                .intersection(&b_f64)
+                .map_coords(|Coord { x, y }| Coord {
+                    x: x as f32,
+                    y: y as f32,
+                });
+        assert_eq!(a.intersection_promoted(&b), expected);
+        assert_eq!(a.intersection_promoted(&b).unsigned_area(), 0.25);
+        assert_eq!(a.union_promoted(&b).unsigned_area(), 3.);
+        assert_eq!(a.xor_promoted(&b).unsigned_area(), 2.75);
+        assert_eq!(a.difference_promoted(&b).unsigned_area(), 0.75);
+    }
+}
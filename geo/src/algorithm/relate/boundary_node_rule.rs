@@ -0,0 +1,64 @@
+use crate::coordinate_position::CoordPos;
+
+/// The rule deciding whether the endpoints of the lines of a geometry are in its boundary, given
+/// how many line endpoints meet at a point.
+///
+/// The OGC Simple Features specification uses the [`Mod2`](Self::Mod2) rule, which is the
+/// default for [`Relate`](crate::Relate). Other systems, e.g. some Oracle and ESRI
+/// predicates, use other rules, which [`Relate::relate_with_boundary_node_rule`] and
+/// [`PreparedGeometry::with_boundary_node_rule`] can match.
+///
+/// The rule only affects the endpoints of lines. The boundary of a polygon is always its rings.
+///
+/// # Examples
+///
+/// ```
+/// use geo::relate::BoundaryNodeRule;
+/// use geo::{wkt, Relate};
+///
+/// // two lines meeting at (1 1)
+/// let lines = wkt!(MULTILINESTRING((0. 0.,1. 1.),(1. 1.,2. 0.)));
+/// let point = wkt!(POINT(1. 1.));
+///
+/// // by the mod-2 rule, two endpoints meeting are in the interior
+/// assert!(lines.relate(&point).is_contains());
+/// let matrix = lines.relate_with_boundary_node_rule(&point, BoundaryNodeRule::EndPoint);
+/// assert!(matrix.is_touches());
+/// ```
+///
+/// [`Relate::relate_with_boundary_node_rule`]: crate::Relate::relate_with_boundary_node_rule
+/// [`PreparedGeometry::with_boundary_node_rule`]: crate::PreparedGeometry::with_boundary_node_rule
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundaryNodeRule {
+    /// Points where an odd number of line endpoints meet are in the boundary, so a closed line
+    /// has no boundary. The rule of the OGC Simple Features specification.
+    #[default]
+    Mod2,
+    /// Every line endpoint is in the boundary, however many meet there.
+    EndPoint,
+    /// Points where more than one line endpoint meets are in the boundary.
+    MultiValent,
+    /// Points where exactly one line endpoint is, i.e. the free ends of a network of lines, are
+    /// in the boundary.
+    MonoValent,
+}
+
+impl BoundaryNodeRule {
+    /// Whether a point where `boundary_count` line endpoints meet is in the boundary.
+    pub fn is_in_boundary(&self, boundary_count: usize) -> bool {
+        match self {
+            BoundaryNodeRule::Mod2 => boundary_count % 2 == 1,
+            BoundaryNodeRule::EndPoint => boundary_count > 0,
+            BoundaryNodeRule::MultiValent => boundary_count > 1,
+            BoundaryNodeRule::MonoValent => boundary_count == 1,
+        }
+    }
+
+    pub(crate) fn position(&self, boundary_count: usize) -> CoordPos {
+        if self.is_in_boundary(boundary_count) {
+            CoordPos::OnBoundary
+        } else {
+            CoordPos::Inside
+        }
+    }
+}
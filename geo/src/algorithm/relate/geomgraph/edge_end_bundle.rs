@@ -1,4 +1,5 @@
-use super::{CoordPos, Direction, Edge, EdgeEnd, GeometryGraph, IntersectionMatrix, Label};
+use super::{CoordPos, Direction, Edge, EdgeEnd, IntersectionMatrix, Label};
+use crate::relate::BoundaryNodeRule;
 use crate::{Coord, GeoFloat};
 
 /// A collection of [`EdgeEnds`](EdgeEnd) which obey the following invariant:
@@ -37,7 +38,10 @@ where
         self.edge_ends.push(edge_end);
     }
 
-    pub(crate) fn into_labeled(mut self) -> LabeledEdgeEndBundle<F> {
+    pub(crate) fn into_labeled(
+        mut self,
+        boundary_node_rules: [BoundaryNodeRule; 2],
+    ) -> LabeledEdgeEndBundle<F> {
         let is_area = self
             .edge_ends_iter()
             .any(|edge_end| edge_end.label().is_area());
@@ -48,8 +52,8 @@ where
             Label::empty_line_or_point()
         };
 
-        for i in 0..2 {
-            self.compute_label_on(&mut label, i);
+        for (i, boundary_node_rule) in boundary_node_rules.into_iter().enumerate() {
+            self.compute_label_on(&mut label, i, boundary_node_rule);
             if is_area {
                 self.compute_label_side(&mut label, i, Direction::Left);
                 self.compute_label_side(&mut label, i, Direction::Right);
@@ -77,12 +81,17 @@ where
     /// top of a Polygon edge.) In this case the Boundary is given precedence.
     ///
     /// These observations result in the following rules for computing the ON location:
-    /// - if there are an odd number of Bdy edges, the attribute is Bdy
-    /// - if there are an even number >= 2 of Bdy edges, the attribute is Int
+    /// - if the number of Bdy edges is in the boundary by the boundary node rule (e.g. an odd
+    ///   number by the mod-2 rule), the attribute is Bdy, and otherwise Int
     /// - if there are any Int edges, the attribute is Int
     /// - otherwise, the attribute is None
     ///
-    fn compute_label_on(&mut self, label: &mut Label, geom_index: usize) {
+    fn compute_label_on(
+        &mut self,
+        label: &mut Label,
+        geom_index: usize,
+        boundary_node_rule: BoundaryNodeRule,
+    ) {
         let mut boundary_count = 0;
         let mut found_interior = false;
 
@@ -104,7 +113,7 @@ where
         }
 
         if boundary_count > 0 {
-            position = Some(GeometryGraph::<'_, F>::determine_boundary(boundary_count));
+            position = Some(boundary_node_rule.position(boundary_count));
         }
 
         if let Some(location) = position {
@@ -198,10 +198,11 @@ where
         graph_b: &GeometryGraph<F>,
     ) -> LabeledEdgeEndBundleStar<F> {
         debug!("edge_end_bundle_star: {:?}", self);
+        let boundary_node_rules = [graph_a.boundary_node_rule(), graph_b.boundary_node_rule()];
         let labeled_edges = self
             .edge_map
             .into_values()
-            .map(|edge_end_bundle| edge_end_bundle.into_labeled())
+            .map(|edge_end_bundle| edge_end_bundle.into_labeled(boundary_node_rules))
             .collect();
         LabeledEdgeEndBundleStar::new(labeled_edges, graph_a, graph_b)
     }
@@ -1,11 +1,12 @@
 use super::{
     index::{
-        EdgeSetIntersector, RStarEdgeSetIntersector, Segment, SegmentIntersector,
+        EdgeChain, EdgeSetIntersector, RStarEdgeSetIntersector, SegmentIntersector,
         SimpleEdgeSetIntersector,
     },
     CoordNode, CoordPos, Direction, Edge, Label, LineIntersector, PlanarGraph, TopologyPosition,
 };
 
+use crate::relate::BoundaryNodeRule;
 use crate::HasDimensions;
 use crate::{Coord, GeoFloat, GeometryCow, Line, LineString, Point, Polygon};
 
@@ -35,8 +36,9 @@ where
 {
     arg_index: usize,
     parent_geometry: GeometryCow<'a, F>,
-    tree: Option<Rc<RTree<Segment<F>>>>,
+    tree: Option<Rc<RTree<EdgeChain<F>>>>,
     use_boundary_determination_rule: bool,
+    boundary_node_rule: BoundaryNodeRule,
     has_computed_self_nodes: bool,
     planar_graph: PlanarGraph<F>,
 }
@@ -49,32 +51,24 @@ impl<F> GeometryGraph<'_, F>
 where
     F: GeoFloat,
 {
-    pub(crate) fn set_tree(&mut self, tree: Rc<RTree<Segment<F>>>) {
+    pub(crate) fn set_tree(&mut self, tree: Rc<RTree<EdgeChain<F>>>) {
         self.tree = Some(tree);
     }
 
-    pub(crate) fn get_or_build_tree(&self) -> Rc<RTree<Segment<F>>> {
+    pub(crate) fn get_or_build_tree(&self) -> Rc<RTree<EdgeChain<F>>> {
         self.tree
             .clone()
             .unwrap_or_else(|| Rc::new(self.build_tree()))
     }
 
-    pub(crate) fn build_tree(&self) -> RTree<Segment<F>> {
-        let segments: Vec<Segment<F>> = self
+    pub(crate) fn build_tree(&self) -> RTree<EdgeChain<F>> {
+        let chains: Vec<EdgeChain<F>> = self
             .edges()
             .iter()
             .enumerate()
-            .flat_map(|(edge_idx, edge)| {
-                let edge = RefCell::borrow(edge);
-                let start_of_final_segment: usize = edge.coords().len() - 1;
-                (0..start_of_final_segment).map(move |segment_idx| {
-                    let p1 = edge.coords()[segment_idx];
-                    let p2 = edge.coords()[segment_idx + 1];
-                    Segment::new(edge_idx, segment_idx, p1, p2)
-                })
-            })
+            .flat_map(|(edge_idx, edge)| EdgeChain::chains_of(edge_idx, &RefCell::borrow(edge)))
             .collect();
-        RTree::bulk_load(segments)
+        RTree::bulk_load(chains)
     }
 
     pub(crate) fn assert_eq_graph(&self, other: &Self) {
@@ -83,6 +77,7 @@ where
             self.use_boundary_determination_rule,
             other.use_boundary_determination_rule
         );
+        assert_eq!(self.boundary_node_rule, other.boundary_node_rule);
         assert_eq!(self.parent_geometry, other.parent_geometry);
         self.planar_graph.assert_eq_graph(&other.planar_graph);
     }
@@ -100,11 +95,16 @@ where
             parent_geometry: self.parent_geometry.clone(),
             tree: self.tree.clone(),
             use_boundary_determination_rule: self.use_boundary_determination_rule,
+            boundary_node_rule: self.boundary_node_rule,
             has_computed_self_nodes: true,
             planar_graph,
         }
     }
 
+    pub(crate) fn boundary_node_rule(&self) -> BoundaryNodeRule {
+        self.boundary_node_rule
+    }
+
     pub(crate) fn edges(&self) -> &[Rc<RefCell<Edge<F>>>] {
         self.planar_graph.edges()
     }
@@ -131,10 +131,19 @@ where
     F: GeoFloat + RTreeNum,
 {
     pub(crate) fn new(arg_index: usize, parent_geometry: GeometryCow<'a, F>) -> Self {
+        Self::new_with_boundary_node_rule(arg_index, parent_geometry, BoundaryNodeRule::Mod2)
+    }
+
+    pub(crate) fn new_with_boundary_node_rule(
+        arg_index: usize,
+        parent_geometry: GeometryCow<'a, F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> Self {
         let mut graph = GeometryGraph {
             arg_index,
             parent_geometry,
             use_boundary_determination_rule: true,
+            boundary_node_rule,
             tree: None,
             has_computed_self_nodes: false,
             planar_graph: PlanarGraph::new(),
@@ -147,18 +156,33 @@ where
         &self.parent_geometry
     }
 
-    /// Determine whether a component (node or edge) that appears multiple times in elements
-    /// of a Multi-Geometry is in the boundary or the interior of the Geometry
-    pub fn determine_boundary(boundary_count: usize) -> CoordPos {
-        // For now, we only support the SFS "Mod-2 Rule"
-        // We could make this configurable if we wanted to support alternative boundary rules.
-        if boundary_count % 2 == 1 {
-            CoordPos::OnBoundary
+    pub(crate) fn into_geometry(self) -> GeometryCow<'a, F> {
+        self.parent_geometry
+    }
+
+    /// This graph, or if it uses another rule, a new graph of its geometry using
+    /// `boundary_node_rule`.
+    pub(crate) fn with_boundary_node_rule(self, boundary_node_rule: BoundaryNodeRule) -> Self {
+        if self.boundary_node_rule == boundary_node_rule {
+            self
         } else {
-            CoordPos::Inside
+            Self::new_with_boundary_node_rule(
+                self.arg_index,
+                self.parent_geometry,
+                boundary_node_rule,
+            )
         }
     }
 
+    /// Determine whether a component (node or edge) that appears multiple times in elements
+    /// of a Multi-Geometry is in the boundary or the interior of the Geometry, by the SFS
+    /// "Mod-2 Rule".
+    ///
+    /// Graphs built for other [`BoundaryNodeRule`]s use [`BoundaryNodeRule::is_in_boundary`].
+    pub fn determine_boundary(boundary_count: usize) -> CoordPos {
+        BoundaryNodeRule::Mod2.position(boundary_count)
+    }
+
     fn boundary_nodes(&self) -> impl Iterator<Item = &CoordNode<F>> {
         self.planar_graph.boundary_nodes(self.arg_index)
     }
@@ -380,24 +404,19 @@ where
     /// Add the boundary points of 1-dim (line) geometries.
     fn insert_boundary_point(&mut self, coord: Coord<F>) {
         let arg_index = self.arg_index;
+        let boundary_node_rule = self.boundary_node_rule;
         let node: &mut CoordNode<F> = self.add_node_with_coordinate(coord);
 
-        let label: &mut Label = node.label_mut();
-
-        // determine the current location for the point (if any)
-        let boundary_count = {
-            #[allow(clippy::bool_to_int_with_if)]
-            let prev_boundary_count =
-                if Some(CoordPos::OnBoundary) == label.position(arg_index, Direction::On) {
-                    1
-                } else {
-                    0
-                };
-            prev_boundary_count + 1
+        // count the boundary points at the node, where one inserted without counting (e.g. the
+        // start of a polygon ring) counts once
+        let boundary_count = match node.boundary_count() {
+            0 if Some(CoordPos::OnBoundary) == node.label().position(arg_index, Direction::On) => 2,
+            boundary_count => boundary_count + 1,
         };
+        node.set_boundary_count(boundary_count);
 
-        let new_position = Self::determine_boundary(boundary_count);
-        label.set_on_position(arg_index, new_position);
+        let new_position = boundary_node_rule.position(boundary_count);
+        node.label_mut().set_on_position(arg_index, new_position);
     }
 
     fn add_self_intersection_nodes(&mut self) {
@@ -0,0 +1,75 @@
+use super::super::Edge;
+use crate::monotone_chain::{MonotoneChain, MonotoneChains};
+use crate::Coord;
+use crate::GeoFloat;
+
+use std::cell::RefCell;
+
+/// A [monotone chain](crate::MonotoneChain) of the segments of an edge, indexed by its bounding
+/// rectangle.
+#[derive(Debug, Clone)]
+pub(crate) struct EdgeChain<F: GeoFloat + rstar::RTreeNum> {
+    pub edge_idx: usize,
+    /// The index of the first coordinate of the chain, and so of its first segment
+    pub start_idx: usize,
+    /// The index of the last coordinate of the chain
+    pub end_idx: usize,
+    pub envelope: rstar::AABB<Coord<F>>,
+}
+
+impl<F> EdgeChain<F>
+where
+    F: GeoFloat + rstar::RTreeNum,
+{
+    /// The monotone chains of the edge at `edge_idx`.
+    pub fn chains_of(edge_idx: usize, edge: &Edge<F>) -> Vec<Self> {
+        MonotoneChains::from_coords(edge.coords())
+            .chains()
+            .iter()
+            .map(move |chain| {
+                let coords = chain.coords();
+                Self {
+                    edge_idx,
+                    start_idx: chain.start_idx(),
+                    end_idx: chain.start_idx() + coords.len() - 1,
+                    envelope: rstar::AABB::from_corners(coords[0], coords[coords.len() - 1]),
+                }
+            })
+            .collect()
+    }
+
+    /// The indices of the pairs of segments of this chain of `edge` and `other` chain of
+    /// `other_edge` whose bounding rectangles intersect, and so which might intersect.
+    pub fn candidate_segments(
+        &self,
+        edge: &RefCell<Edge<F>>,
+        other: &EdgeChain<F>,
+        other_edge: &RefCell<Edge<F>>,
+    ) -> Vec<(usize, usize)> {
+        let (edge, other_edge) = (edge.borrow(), other_edge.borrow());
+        let chain = MonotoneChain::from_parts(
+            &edge.coords()[self.start_idx..=self.end_idx],
+            self.start_idx,
+        );
+        let other_chain = MonotoneChain::from_parts(
+            &other_edge.coords()[other.start_idx..=other.end_idx],
+            other.start_idx,
+        );
+        let mut candidates = vec![];
+        chain.visit_candidates(&other_chain, &mut |segment_idx, other_segment_idx| {
+            candidates.push((segment_idx, other_segment_idx))
+        });
+        candidates
+    }
+}
+
+impl<F> rstar::RTreeObject for EdgeChain<F>
+where
+    F: GeoFloat + rstar::RTreeNum,
+{
+    type Envelope = rstar::AABB<Coord<F>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.envelope
+    }
+}
@@ -1,13 +1,13 @@
+mod edge_chain;
 mod edge_set_intersector;
 mod prepared_geometry;
 mod rstar_edge_set_intersector;
-mod segment;
 mod segment_intersector;
 mod simple_edge_set_intersector;
 
+pub(crate) use edge_chain::EdgeChain;
 pub(crate) use edge_set_intersector::EdgeSetIntersector;
 pub use prepared_geometry::PreparedGeometry;
 pub(crate) use rstar_edge_set_intersector::RStarEdgeSetIntersector;
-pub(crate) use segment::Segment;
 pub(crate) use segment_intersector::SegmentIntersector;
 pub(crate) use simple_edge_set_intersector::SimpleEdgeSetIntersector;
@@ -1,13 +1,18 @@
-use super::Segment;
+use crate::coordinate_position::{
+    calculate_polygon_position, coord_pos_relative_to_ring, CoordPos,
+};
+use crate::dimensions::{Dimensions, HasDimensions};
 use crate::geometry::*;
 use crate::relate::geomgraph::{GeometryGraph, RobustLineIntersector};
+use crate::relate::{BoundaryNodeRule, IntersectionMatrix};
 use crate::GeometryCow;
-use crate::{GeoFloat, Relate};
+use crate::{BoundingRect, Contains, CoordinatePosition, GeoFloat, Intersects, Relate};
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use rstar::{RTree, RTreeNum};
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeNum, RTreeObject};
 
 /// A `PreparedGeometry` can be more efficient than a plain Geometry when performing
 /// multiple topological comparisons against the `PreparedGeometry`.
@@ -26,14 +31,73 @@ use rstar::{RTree, RTreeNum};
 /// assert!(prepared_polygon.relate(&contained_line).is_contains());
 ///
 /// ```
+///
+/// Polygons with many interior rings also have their interiors indexed, so that
+/// [`coordinate_position`](CoordinatePosition::coordinate_position) and
+/// [`contains`](Contains::contains) queries for a point only test the interiors near it.
+///
+/// ```
+/// use geo::{coord, Contains, CoordinatePosition, PreparedGeometry, wkt};
+/// use geo::coordinate_position::CoordPos;
+///
+/// let polygon = wkt! { POLYGON((0.0 0.0,10.0 0.0,10.0 10.0,0.0 10.0,0.0 0.0),(4.0 4.0,6.0 4.0,6.0 6.0,4.0 6.0,4.0 4.0)) };
+/// let prepared_polygon = PreparedGeometry::from(&polygon);
+///
+/// assert!(prepared_polygon.contains(&coord! { x: 2.0, y: 2.0 }));
+/// assert!(!prepared_polygon.contains(&coord! { x: 5.0, y: 5.0 }));
+/// assert_eq!(prepared_polygon.coordinate_position(&coord! { x: 4.0, y: 5.0 }), CoordPos::OnBoundary);
+/// ```
 pub struct PreparedGeometry<'a, F: GeoFloat + RTreeNum = f64> {
     geometry_graph: GeometryGraph<'a, F>,
+    bounding_rect: Option<Rect<F>>,
+    interiors: Option<InteriorsIndex<F>>,
+}
+
+/// Polygons with at least this many interiors have their interiors indexed.
+const MIN_INDEXED_INTERIORS: usize = 16;
+
+/// The bounding rectangles of the interiors of a polygonal geometry, tagged with the indices of
+/// their polygon and interior.
+type InteriorsIndex<F> = RTree<GeomWithData<Rectangle<Point<F>>, (usize, usize)>>;
+
+fn build_interiors_index<F: GeoFloat>(geometry: &GeometryCow<F>) -> Option<InteriorsIndex<F>> {
+    let polygons: &[Polygon<F>] = match geometry {
+        GeometryCow::Polygon(polygon) => std::slice::from_ref(polygon.as_ref()),
+        GeometryCow::MultiPolygon(multi_polygon) => &multi_polygon.0,
+        _ => return None,
+    };
+    if polygons
+        .iter()
+        .all(|polygon| polygon.interiors().len() < MIN_INDEXED_INTERIORS)
+    {
+        return None;
+    }
+
+    let interiors = polygons
+        .iter()
+        .enumerate()
+        .flat_map(|(polygon_idx, polygon)| {
+            polygon
+                .interiors()
+                .iter()
+                .enumerate()
+                .filter(|(_, interior)| !interior.0.is_empty())
+                .map(move |(interior_idx, interior)| {
+                    GeomWithData::new(
+                        Rectangle::from_aabb(interior.envelope()),
+                        (polygon_idx, interior_idx),
+                    )
+                })
+        })
+        .collect();
+    Some(RTree::bulk_load(interiors))
 }
 
 mod conversions {
     use crate::geometry_cow::GeometryCow;
     use crate::relate::geomgraph::{GeometryGraph, RobustLineIntersector};
-    use crate::{GeoFloat, PreparedGeometry};
+    use crate::relate::BoundaryNodeRule;
+    use crate::{BoundingRect, GeoFloat, PreparedGeometry};
     use geo_types::{
         Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
         Point, Polygon, Rect, Triangle,
@@ -155,25 +219,236 @@ mod conversions {
 
     impl<'a, F: GeoFloat> From<GeometryCow<'a, F>> for PreparedGeometry<'a, F> {
         fn from(geometry: GeometryCow<'a, F>) -> Self {
-            let mut geometry_graph = GeometryGraph::new(0, geometry);
+            PreparedGeometry::new(geometry, BoundaryNodeRule::Mod2)
+        }
+    }
+
+    impl<'a, F: GeoFloat> PreparedGeometry<'a, F> {
+        pub(super) fn new(
+            geometry: GeometryCow<'a, F>,
+            boundary_node_rule: BoundaryNodeRule,
+        ) -> Self {
+            let bounding_rect = geometry.bounding_rect();
+            let interiors = super::build_interiors_index(&geometry);
+            let mut geometry_graph =
+                GeometryGraph::new_with_boundary_node_rule(0, geometry, boundary_node_rule);
             geometry_graph.set_tree(Rc::new(geometry_graph.build_tree()));
 
             // TODO: don't pass in line intersector here - in theory we'll want pluggable line intersectors
             // and the type (Robust) shouldn't be hard coded here.
             geometry_graph.compute_self_nodes(Box::new(RobustLineIntersector::new()));
 
-            Self { geometry_graph }
+            Self {
+                geometry_graph,
+                bounding_rect,
+                interiors,
+            }
         }
     }
 }
 
-impl<F> PreparedGeometry<'_, F>
+impl<'a, F> PreparedGeometry<'a, F>
 where
     F: GeoFloat + RTreeNum,
 {
     pub(crate) fn geometry(&self) -> &GeometryCow<F> {
         self.geometry_graph.geometry()
     }
+
+    /// The prepared geometry, with the boundaries of its lines decided by `boundary_node_rule`
+    /// rather than the OGC mod-2 rule, in [`relate`](Relate::relate) and the predicates based on
+    /// it. The other geometry is related with the same rule.
+    ///
+    /// ```
+    /// use geo::relate::BoundaryNodeRule;
+    /// use geo::{wkt, PreparedGeometry, Relate};
+    ///
+    /// let ring = wkt!(LINESTRING(0. 0.,1. 0.,1. 1.,0. 0.));
+    /// let prepared = PreparedGeometry::from(&ring).with_boundary_node_rule(BoundaryNodeRule::EndPoint);
+    /// assert!(prepared.relate(&wkt!(POINT(0. 0.))).is_touches());
+    /// ```
+    pub fn with_boundary_node_rule(self, boundary_node_rule: BoundaryNodeRule) -> Self {
+        if self.geometry_graph.boundary_node_rule() == boundary_node_rule {
+            return self;
+        }
+        Self::new(self.geometry_graph.into_geometry(), boundary_node_rule)
+    }
+
+    /// Computes the [`IntersectionMatrix`] of this geometry and a `Rect`, as
+    /// [`relate`](Relate::relate) would, but with fast paths for the common cases when testing
+    /// many tiles against one geometry.
+    ///
+    /// The result is decided without building a graph for the `Rect` when:
+    ///
+    /// - the `Rect` is disjoint from the bounding rectangle of the geometry
+    /// - the `Rect` strictly contains the bounding rectangle of the geometry
+    /// - the geometry is polygonal and none of its edges come near the `Rect`, in which case the
+    ///   `Rect` lies either entirely inside or entirely outside of it
+    ///
+    /// Otherwise this falls back to a full `relate`.
+    ///
+    /// ```
+    /// use geo::{wkt, PreparedGeometry, Rect};
+    ///
+    /// let polygon = wkt! { POLYGON((0.0 0.0,10.0 0.0,10.0 10.0,0.0 10.0,0.0 0.0)) };
+    /// let prepared = PreparedGeometry::from(&polygon);
+    ///
+    /// let inside = Rect::new((2.0, 2.0), (3.0, 3.0));
+    /// assert!(prepared.relate_rect(&inside).is_contains());
+    ///
+    /// let crossing = Rect::new((8.0, 8.0), (12.0, 12.0));
+    /// assert!(prepared.relate_rect(&crossing).is_overlaps());
+    ///
+    /// let outside = Rect::new((20.0, 20.0), (30.0, 30.0));
+    /// assert!(prepared.relate_rect(&outside).is_disjoint());
+    /// ```
+    pub fn relate_rect(&self, rect: &Rect<F>) -> IntersectionMatrix {
+        let Some(bounding_rect) = self.bounding_rect else {
+            return self.relate(rect);
+        };
+
+        if !bounding_rect.intersects(rect) {
+            return self.disjoint_from_rect(rect);
+        }
+
+        let (min, max) = (rect.min(), rect.max());
+        let has_area = min.x < max.x && min.y < max.y;
+        if !has_area {
+            return self.relate(rect);
+        }
+
+        if min.x < bounding_rect.min().x
+            && min.y < bounding_rect.min().y
+            && bounding_rect.max().x < max.x
+            && bounding_rect.max().y < max.y
+        {
+            // The geometry is within the interior of the rect
+            let mut matrix = IntersectionMatrix::empty_disjoint();
+            let geometry = self.geometry();
+            matrix.set(CoordPos::Inside, CoordPos::Inside, geometry.dimensions());
+            let boundary_dimensions = geometry.boundary_dimensions();
+            if boundary_dimensions != Dimensions::Empty {
+                matrix.set(CoordPos::OnBoundary, CoordPos::Inside, boundary_dimensions);
+            }
+            matrix.set(
+                CoordPos::Outside,
+                CoordPos::Inside,
+                Dimensions::TwoDimensional,
+            );
+            matrix.set(
+                CoordPos::Outside,
+                CoordPos::OnBoundary,
+                Dimensions::OneDimensional,
+            );
+            return matrix;
+        }
+
+        let envelope = rstar::AABB::from_corners(min, max);
+        let near_edges = self
+            .geometry_graph
+            .get_or_build_tree()
+            .locate_in_envelope_intersecting(&envelope)
+            .next()
+            .is_some();
+        if near_edges {
+            return self.relate(rect);
+        }
+
+        let position = match self.geometry() {
+            GeometryCow::Polygon(_)
+            | GeometryCow::MultiPolygon(_)
+            | GeometryCow::Rect(_)
+            | GeometryCow::Triangle(_) => self.coordinate_position(&rect.center()),
+            _ => return self.relate(rect),
+        };
+        if position == CoordPos::Inside {
+            // The rect is within the interior of the geometry
+            let mut matrix = IntersectionMatrix::empty_disjoint();
+            matrix.set(
+                CoordPos::Inside,
+                CoordPos::Inside,
+                Dimensions::TwoDimensional,
+            );
+            matrix.set(
+                CoordPos::Inside,
+                CoordPos::OnBoundary,
+                Dimensions::OneDimensional,
+            );
+            matrix.set(
+                CoordPos::Inside,
+                CoordPos::Outside,
+                Dimensions::TwoDimensional,
+            );
+            matrix.set(
+                CoordPos::OnBoundary,
+                CoordPos::Outside,
+                Dimensions::OneDimensional,
+            );
+            matrix
+        } else {
+            self.disjoint_from_rect(rect)
+        }
+    }
+
+    fn disjoint_from_rect(&self, rect: &Rect<F>) -> IntersectionMatrix {
+        let mut matrix = IntersectionMatrix::empty_disjoint();
+        matrix.compute_disjoint(self.geometry(), &GeometryCow::from(rect));
+        matrix
+    }
+}
+
+impl<F: GeoFloat> CoordinatePosition for PreparedGeometry<'_, F> {
+    type Scalar = F;
+
+    fn calculate_coordinate_position(
+        &self,
+        coord: &Coord<F>,
+        is_inside: &mut bool,
+        boundary_count: &mut usize,
+    ) {
+        let Some(interiors) = &self.interiors else {
+            self.geometry()
+                .calculate_coordinate_position(coord, is_inside, boundary_count);
+            return;
+        };
+        let polygons: &[Polygon<F>] = match self.geometry() {
+            GeometryCow::Polygon(polygon) => std::slice::from_ref(polygon.as_ref()),
+            GeometryCow::MultiPolygon(multi_polygon) => &multi_polygon.0,
+            _ => unreachable!("only polygonal geometries have indexed interiors"),
+        };
+
+        let point = Point::from(*coord);
+        for (polygon_idx, polygon) in polygons.iter().enumerate() {
+            if polygon.is_empty() {
+                continue;
+            }
+            // The coord is outside of every interior whose bounding rect doesn't contain it
+            let interior_positions = interiors
+                .locate_all_at_point(&point)
+                .filter(|interior| interior.data.0 == polygon_idx)
+                .map(|interior| {
+                    coord_pos_relative_to_ring(*coord, &polygon.interiors()[interior.data.1])
+                });
+            calculate_polygon_position(
+                coord_pos_relative_to_ring(*coord, polygon.exterior()),
+                interior_positions,
+                is_inside,
+                boundary_count,
+            );
+        }
+    }
+}
+
+impl<F: GeoFloat> Contains<Coord<F>> for PreparedGeometry<'_, F> {
+    fn contains(&self, coord: &Coord<F>) -> bool {
+        self.coordinate_position(coord) == CoordPos::Inside
+    }
+}
+
+impl<F: GeoFloat> Contains<Point<F>> for PreparedGeometry<'_, F> {
+    fn contains(&self, point: &Point<F>) -> bool {
+        self.contains(&point.0)
+    }
 }
 
 impl<F: GeoFloat> Relate<F> for PreparedGeometry<'_, F> {
@@ -182,6 +457,10 @@ impl<F: GeoFloat> Relate<F> for PreparedGeometry<'_, F> {
     fn geometry_graph(&self, arg_index: usize) -> GeometryGraph<F> {
         self.geometry_graph.clone_for_arg_index(arg_index)
     }
+
+    fn relate(&self, other: &impl Relate<F>) -> IntersectionMatrix {
+        self.relate_with_boundary_node_rule(other, self.geometry_graph.boundary_node_rule())
+    }
 }
 
 #[cfg(test)]
@@ -209,6 +488,88 @@ mod tests {
         assert!(p2.relate(&prepared_1).is_within());
     }
 
+    #[test]
+    fn relate_rect_matches_relate() {
+        use crate::{wkt, Geometry};
+
+        let geometries: Vec<Geometry> = vec![
+            wkt! { POLYGON((0.0 0.0,10.0 0.0,10.0 10.0,0.0 10.0,0.0 0.0),(4.0 4.0,6.0 4.0,6.0 6.0,4.0 6.0,4.0 4.0)) }.into(),
+            wkt! { MULTIPOLYGON(((0.0 0.0,3.0 0.0,3.0 3.0,0.0 0.0)),((5.0 5.0,9.0 5.0,9.0 9.0,5.0 9.0,5.0 5.0))) }.into(),
+            wkt! { LINESTRING(0.0 0.0,5.0 5.0,10.0 0.0) }.into(),
+            wkt! { MULTIPOINT(1.0 1.0,5.0 5.0) }.into(),
+            wkt! { POINT(5.0 5.0) }.into(),
+            Rect::new((1.0, 1.0), (9.0, 9.0)).into(),
+            Triangle::new((0.0, 0.0).into(), (10.0, 0.0).into(), (5.0, 10.0).into()).into(),
+        ];
+        let steps = [
+            -3.0, -1.0, 0.0, 0.5, 1.0, 4.0, 4.5, 5.0, 6.0, 9.0, 10.0, 12.0,
+        ];
+        for geometry in &geometries {
+            let prepared = PreparedGeometry::from(geometry);
+            for min_x in steps {
+                for min_y in steps {
+                    for (width, height) in
+                        [(0.5, 0.5), (1.0, 3.0), (8.0, 8.0), (20.0, 20.0), (0.0, 2.0)]
+                    {
+                        let rect = Rect::new((min_x, min_y), (min_x + width, min_y + height));
+                        assert_eq!(
+                            prepared.relate_rect(&rect),
+                            geometry.relate(&rect),
+                            "{geometry:?} {rect:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn coordinate_position_with_indexed_interiors() {
+        use crate::{coord, Contains, MultiPolygon};
+
+        // a 10x10 grid of square holes
+        let exterior = LineString::from(vec![(0., 0.), (21., 0.), (21., 21.), (0., 21.)]);
+        let interiors = (0..10)
+            .flat_map(|i| {
+                (0..10).map(move |j| {
+                    let (x, y) = (2. * i as f64 + 1., 2. * j as f64 + 1.);
+                    LineString::from(vec![(x, y), (x, y + 1.), (x + 1., y + 1.), (x + 1., y)])
+                })
+            })
+            .collect();
+        let polygon = Polygon::new(exterior, interiors);
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon.clone(),
+            polygon![(x: 30., y: 0.), (x: 40., y: 0.), (x: 40., y: 10.)],
+        ]);
+
+        let prepared_polygon = PreparedGeometry::from(&polygon);
+        let prepared_multi_polygon = PreparedGeometry::from(&multi_polygon);
+        assert!(prepared_polygon.interiors.is_some());
+        assert!(prepared_multi_polygon.interiors.is_some());
+
+        for x in -2..=84 {
+            for y in -2..=44 {
+                let coord = coord! { x: x as f64 / 2., y: y as f64 / 2. };
+                assert_eq!(
+                    prepared_polygon.coordinate_position(&coord),
+                    polygon.coordinate_position(&coord),
+                    "{coord:?}"
+                );
+                assert_eq!(
+                    prepared_multi_polygon.coordinate_position(&coord),
+                    multi_polygon.coordinate_position(&coord),
+                    "{coord:?}"
+                );
+                assert_eq!(
+                    prepared_multi_polygon.contains(&coord),
+                    multi_polygon.contains(&coord),
+                    "{coord:?}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn swap_arg_index() {
         let poly = polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 1.0, y: 1.0)];
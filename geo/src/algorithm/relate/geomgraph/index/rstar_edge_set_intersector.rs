@@ -1,12 +1,11 @@
-use super::super::{Edge, GeometryGraph};
-use super::{EdgeSetIntersector, Segment, SegmentIntersector};
+use super::super::GeometryGraph;
+use super::{EdgeSetIntersector, SegmentIntersector};
 use crate::GeoFloat;
 
-use std::cell::RefCell;
-use std::rc::Rc;
-
-use rstar::{RTree, RTreeNum};
+use rstar::RTreeNum;
 
+/// Finds the intersecting edges of graphs using an R-tree of the monotone chains of their edges,
+/// bisecting overlapping chains to find the segments which might intersect.
 pub(crate) struct RStarEdgeSetIntersector;
 
 impl<F> EdgeSetIntersector<F> for RStarEdgeSetIntersector
@@ -22,16 +21,20 @@ where
         let edges = graph.edges();
 
         let tree = graph.get_or_build_tree();
-        for (segment_0, segment_1) in tree.intersection_candidates_with_other_tree(&tree) {
-            if check_for_self_intersecting_edges || segment_0.edge_idx != segment_1.edge_idx {
-                let edge_0 = &edges[segment_0.edge_idx];
-                let edge_1 = &edges[segment_1.edge_idx];
-                segment_intersector.add_intersections(
-                    edge_0,
-                    segment_0.segment_idx,
-                    edge_1,
-                    segment_1.segment_idx,
-                );
+        for (chain_0, chain_1) in tree.intersection_candidates_with_other_tree(&tree) {
+            if check_for_self_intersecting_edges || chain_0.edge_idx != chain_1.edge_idx {
+                let edge_0 = &edges[chain_0.edge_idx];
+                let edge_1 = &edges[chain_1.edge_idx];
+                for (segment_idx_0, segment_idx_1) in
+                    chain_0.candidate_segments(edge_0, chain_1, edge_1)
+                {
+                    segment_intersector.add_intersections(
+                        edge_0,
+                        segment_idx_0,
+                        edge_1,
+                        segment_idx_1,
+                    );
+                }
             }
         }
     }
@@ -48,15 +51,14 @@ where
         let tree_0 = graph_0.get_or_build_tree();
         let tree_1 = graph_1.get_or_build_tree();
 
-        for (segment_0, segment_1) in tree_0.intersection_candidates_with_other_tree(&tree_1) {
-            let edge_0 = &edges_0[segment_0.edge_idx];
-            let edge_1 = &edges_1[segment_1.edge_idx];
-            segment_intersector.add_intersections(
-                edge_0,
-                segment_0.segment_idx,
-                edge_1,
-                segment_1.segment_idx,
-            );
+        for (chain_0, chain_1) in tree_0.intersection_candidates_with_other_tree(&tree_1) {
+            let edge_0 = &edges_0[chain_0.edge_idx];
+            let edge_1 = &edges_1[chain_1.edge_idx];
+            for (segment_idx_0, segment_idx_1) in
+                chain_0.candidate_segments(edge_0, chain_1, edge_1)
+            {
+                segment_intersector.add_intersections(edge_0, segment_idx_0, edge_1, segment_idx_1);
+            }
         }
     }
 }
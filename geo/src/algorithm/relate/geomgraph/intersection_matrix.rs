@@ -85,26 +85,42 @@ impl std::fmt::Display for InvalidInputError {
 
 impl std::fmt::Debug for IntersectionMatrix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fn char_for_dim(dim: &Dimensions) -> &'static str {
-            match dim {
-                Dimensions::Empty => "F",
-                Dimensions::ZeroDimensional => "0",
-                Dimensions::OneDimensional => "1",
-                Dimensions::TwoDimensional => "2",
-            }
-        }
-        let text = self
-            .0
-            .iter()
-            .flat_map(|r| r.iter().map(char_for_dim))
-            .collect::<Vec<&str>>()
-            .join("");
+        write!(f, "IntersectionMatrix({})", self.de9im_string())
+    }
+}
+
+/// With the `use-serde` feature, an `IntersectionMatrix` is serialized as its DE-9IM string,
+/// e.g. `"212FF1FF2"`.
+#[cfg(feature = "use-serde")]
+impl serde::Serialize for IntersectionMatrix {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.de9im_string())
+    }
+}
 
-        write!(f, "IntersectionMatrix({})", &text)
+#[cfg(feature = "use-serde")]
+impl<'de> serde::Deserialize<'de> for IntersectionMatrix {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        IntersectionMatrix::from_str(&text).map_err(serde::de::Error::custom)
     }
 }
 
 impl IntersectionMatrix {
+    /// The 9 characters of the matrix, row by row, e.g. `"212FF1FF2"`.
+    fn de9im_string(&self) -> String {
+        self.0
+            .iter()
+            .flat_map(|r| r.iter())
+            .map(|dim| match dim {
+                Dimensions::Empty => 'F',
+                Dimensions::ZeroDimensional => '0',
+                Dimensions::OneDimensional => '1',
+                Dimensions::TwoDimensional => '2',
+            })
+            .collect()
+    }
+
     pub const fn empty() -> Self {
         IntersectionMatrix(LocationArray([LocationArray([Dimensions::Empty; 3]); 3]))
     }
@@ -8,6 +8,8 @@ where
 {
     coordinate: Coord<F>,
     label: Label,
+    /// The number of line endpoints at the node, for the boundary node rule
+    boundary_count: usize,
 }
 
 impl<F: GeoFloat> CoordNode<F> {
@@ -23,6 +25,14 @@ impl<F: GeoFloat> CoordNode<F> {
         &mut self.label
     }
 
+    pub(crate) fn boundary_count(&self) -> usize {
+        self.boundary_count
+    }
+
+    pub(crate) fn set_boundary_count(&mut self, boundary_count: usize) {
+        self.boundary_count = boundary_count;
+    }
+
     pub(crate) fn is_isolated(&self) -> bool {
         self.label.geometry_count() == 1
     }
@@ -36,6 +46,7 @@ where
         CoordNode {
             coordinate,
             label: Label::empty_line_or_point(),
+            boundary_count: 0,
         }
     }
 
@@ -1,3 +1,4 @@
+pub use boundary_node_rule::BoundaryNodeRule;
 pub(crate) use edge_end_builder::EdgeEndBuilder;
 pub use geomgraph::intersection_matrix::IntersectionMatrix;
 use relate_operation::RelateOperation;
@@ -7,6 +8,7 @@ pub use crate::relate::geomgraph::index::PreparedGeometry;
 pub use crate::relate::geomgraph::GeometryGraph;
 use crate::{GeoFloat, GeometryCow};
 
+mod boundary_node_rule;
 mod edge_end_builder;
 mod geomgraph;
 mod relate_operation;
@@ -53,15 +55,43 @@ mod relate_operation;
 /// assert!(!intersection_matrix.is_within());
 /// ```
 ///
+/// The boundaries of lines are decided by the OGC "mod-2" [`BoundaryNodeRule`]. Use
+/// [`relate_with_boundary_node_rule`](Self::relate_with_boundary_node_rule) for another rule.
+///
 /// Note: `Relate` must not be called on geometries containing `NaN` coordinates.
 pub trait Relate<F: GeoFloat> {
     /// Construct a [`GeometryGraph`]
     fn geometry_graph(&self, arg_index: usize) -> GeometryGraph<F>;
 
+    /// Construct a [`GeometryGraph`] whose line boundaries are decided by `boundary_node_rule`
+    fn geometry_graph_with_boundary_node_rule(
+        &self,
+        arg_index: usize,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> GeometryGraph<'_, F> {
+        self.geometry_graph(arg_index)
+            .with_boundary_node_rule(boundary_node_rule)
+    }
+
     fn relate(&self, other: &impl Relate<F>) -> IntersectionMatrix {
         RelateOperation::new(self.geometry_graph(0), other.geometry_graph(1))
             .compute_intersection_matrix()
     }
+
+    /// Like [`relate`](Self::relate), but deciding which line endpoints of both geometries are
+    /// in their boundaries with `boundary_node_rule`, e.g. to match systems which don't use the
+    /// OGC mod-2 rule.
+    fn relate_with_boundary_node_rule(
+        &self,
+        other: &impl Relate<F>,
+        boundary_node_rule: BoundaryNodeRule,
+    ) -> IntersectionMatrix {
+        RelateOperation::new(
+            self.geometry_graph_with_boundary_node_rule(0, boundary_node_rule),
+            other.geometry_graph_with_boundary_node_rule(1, boundary_node_rule),
+        )
+        .compute_intersection_matrix()
+    }
 }
 
 macro_rules! relate_impl {
@@ -71,6 +101,18 @@ macro_rules! relate_impl {
                 fn geometry_graph(&self, arg_index: usize) -> GeometryGraph<F> {
                     GeometryGraph::new(arg_index, GeometryCow::from(self))
                 }
+
+                fn geometry_graph_with_boundary_node_rule(
+                    &self,
+                    arg_index: usize,
+                    boundary_node_rule: BoundaryNodeRule,
+                ) -> GeometryGraph<'_, F> {
+                    GeometryGraph::new_with_boundary_node_rule(
+                        arg_index,
+                        GeometryCow::from(self),
+                        boundary_node_rule,
+                    )
+                }
             }
         )*
     };
@@ -88,12 +130,122 @@ relate_impl![
     Triangle<F>,
     GeometryCollection<F>,
     Geometry<F>,
+    GeometryCow<'_, F>,
 ];
 
+/// Topologically relate two geometries of any types implementing the [`geo_traits`], e.g. a
+/// [`Wkb`](crate::wkb_conv::Wkb), based on [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM)
+/// semantics.
+///
+/// This is [`Relate::relate`] for geometries which aren't `geo-types` geometries, so the
+/// predicates of the [`IntersectionMatrix`], such as [`is_contains`] and [`is_within`], can be
+/// evaluated without converting them first. Their coordinates are copied into the graph which
+/// relates them, as those of `geo-types` geometries are. An empty point, which `geo-types` can't
+/// represent, is related as an empty `MultiPoint`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::relate::relate_geometries;
+/// use geo::wkb_conv::{Endianness, ToWkb, Wkb};
+/// use geo::wkt;
+///
+/// let polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+/// let bytes = wkt!(POINT(1. 1.)).to_wkb(Endianness::LittleEndian);
+/// let point = Wkb::try_new(&bytes).unwrap();
+///
+/// assert!(relate_geometries(&polygon, &point).is_contains());
+/// assert!(relate_geometries(&point, &polygon).is_within());
+/// ```
+///
+/// [`is_contains`]: IntersectionMatrix::is_contains
+/// [`is_within`]: IntersectionMatrix::is_within
+pub fn relate_geometries<F: GeoFloat>(
+    a: &impl geo_traits::GeometryTrait<T = F>,
+    b: &impl geo_traits::GeometryTrait<T = F>,
+) -> IntersectionMatrix {
+    RelateOperation::new(
+        GeometryGraph::new(0, GeometryCow::from_geometry_trait(a)),
+        GeometryGraph::new(1, GeometryCow::from_geometry_trait(b)),
+    )
+    .compute_intersection_matrix()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::wkt;
+
     #[test]
     fn run_jts_relate_tests() {
         jts_test_runner::assert_jts_tests_succeed("*Relate*.xml");
     }
+
+    #[test]
+    fn boundary_node_rules() {
+        // three lines meeting at (1 1), and a closed line
+        let lines = wkt!(MULTILINESTRING((0. 0.,1. 1.),(1. 1.,2. 0.),(1. 1.,1. 2.)));
+        let ring = wkt!(LINESTRING(5. 5.,6. 5.,6. 6.,5. 5.));
+        let center = wkt!(POINT(1. 1.));
+        let end = wkt!(POINT(0. 0.));
+        let ring_start = wkt!(POINT(5. 5.));
+
+        let touches = |a: &MultiLineString, b: &Point, rule| {
+            a.relate_with_boundary_node_rule(b, rule).is_touches()
+        };
+        use BoundaryNodeRule::*;
+        // by the mod-2 rule, 3 endpoints are in the boundary
+        assert!(touches(&lines, &center, Mod2));
+        assert!(touches(&lines, &end, Mod2));
+        assert!(touches(&lines, &center, EndPoint));
+        assert!(touches(&lines, &end, EndPoint));
+        assert!(touches(&lines, &center, MultiValent));
+        assert!(!touches(&lines, &end, MultiValent));
+        assert!(!touches(&lines, &center, MonoValent));
+        assert!(touches(&lines, &end, MonoValent));
+
+        assert_eq!(
+            lines.relate(&center),
+            lines.relate_with_boundary_node_rule(&center, Mod2)
+        );
+
+        // a closed line has no boundary by the mod-2 rule, but its ends by the end point rule
+        assert!(ring.relate(&ring_start).is_contains());
+        let matrix = ring.relate_with_boundary_node_rule(&ring_start, EndPoint);
+        assert!(matrix.is_touches());
+        assert!(!ring
+            .relate_with_boundary_node_rule(&ring_start, MonoValent)
+            .is_touches());
+
+        // the rule applies to both geometries
+        let matrix = center.relate_with_boundary_node_rule(&lines, MonoValent);
+        assert!(matrix.is_within());
+    }
+
+    #[test]
+    fn relate_geometry_traits() {
+        use crate::wkb_conv::{Endianness, ToWkb, Wkb};
+
+        let polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)));
+        let geo_line_string = wkt!(LINESTRING(1. 1.,2. 3.,5. 5.));
+        let bytes = geo_line_string.to_wkb(Endianness::BigEndian);
+        let line_string = Wkb::try_new(&bytes).unwrap();
+        assert_eq!(
+            relate_geometries(&polygon, &line_string),
+            polygon.relate(&geo_line_string)
+        );
+        assert_eq!(
+            relate_geometries(&line_string, &polygon),
+            geo_line_string.relate(&polygon)
+        );
+
+        // a collection with an empty point is related as if it had none
+        let mut bytes = vec![1];
+        bytes.extend(7u32.to_le_bytes());
+        bytes.extend(2u32.to_le_bytes());
+        bytes.extend(wkt!(POINT(1. 1.)).to_wkb(Endianness::LittleEndian));
+        bytes.extend(Point::new(f64::NAN, f64::NAN).to_wkb(Endianness::LittleEndian));
+        let collection = Wkb::try_new(&bytes).unwrap();
+        assert!(relate_geometries(&polygon, &collection).is_contains());
+    }
 }
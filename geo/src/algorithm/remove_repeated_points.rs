@@ -1,6 +1,6 @@
 use crate::{
-    CoordNum, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-    Polygon, Rect, Triangle,
+    Coord, CoordFloat, CoordNum, Epsilon, Geometry, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 use geo_types::GeometryCollection;
 
@@ -195,6 +195,149 @@ impl<T: CoordNum> RemoveRepeatedPoints<T> for Geometry<T> {
     }
 }
 
+/// Remove points which are equal, within an [`Epsilon`], to a kept point from a `MultiPoint`,
+/// and consecutive coordinates which are equal within it from `LineString`, `Polygon`,
+/// `MultiLineString` and `MultiPolygon`, and from each member of a `GeometryCollection`.
+///
+/// Like [`RemoveRepeatedPoints`], but for noisy data, where repeated points are rarely exactly
+/// equal. The first of a run of coordinates which are equal within `epsilon` is kept, except
+/// that a closed `LineString`, such as the ring of a polygon, is kept closed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Epsilon, RemoveRepeatedPointsWithin};
+///
+/// let line_string = wkt!(LINESTRING(0. 0.,1. 0.,1. 1e-9,1. 1.));
+/// assert_eq!(
+///     line_string.remove_repeated_points_within(1e-6),
+///     wkt!(LINESTRING(0. 0.,1. 0.,1. 1.))
+/// );
+///
+/// // far from the origin, a relative tolerance allows for rounding
+/// let far = wkt!(LINESTRING(1e9 0.,1e9 1e-5,1e9 1.));
+/// assert_eq!(
+///     far.remove_repeated_points_within(Epsilon::relative(1e-12)).0.len(),
+///     2
+/// );
+/// ```
+pub trait RemoveRepeatedPointsWithin<T: CoordFloat> {
+    /// Create a new geometry with points removed which are repeated within `epsilon`.
+    fn remove_repeated_points_within(&self, epsilon: impl Into<Epsilon<T>>) -> Self;
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for MultiPoint<T> {
+    fn remove_repeated_points_within(&self, epsilon: impl Into<Epsilon<T>>) -> Self {
+        let epsilon = epsilon.into();
+        let mut points: Vec<Point<T>> = vec![];
+        for p in self.0.iter() {
+            if !points.iter().any(|kept| epsilon.coords_eq(kept.0, p.0)) {
+                points.push(*p);
+            }
+        }
+        MultiPoint(points)
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for LineString<T> {
+    fn remove_repeated_points_within(&self, epsilon: impl Into<Epsilon<T>>) -> Self {
+        LineString(dedup_coords_within(&self.0, epsilon.into()))
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for Polygon<T> {
+    fn remove_repeated_points_within(&self, epsilon: impl Into<Epsilon<T>>) -> Self {
+        let epsilon = epsilon.into();
+        Polygon::new(
+            self.exterior().remove_repeated_points_within(epsilon),
+            self.interiors()
+                .iter()
+                .map(|ls| ls.remove_repeated_points_within(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for MultiLineString<T> {
+    fn remove_repeated_points_within(&self, epsilon: impl Into<Epsilon<T>>) -> Self {
+        let epsilon = epsilon.into();
+        MultiLineString::new(
+            self.0
+                .iter()
+                .map(|ls| ls.remove_repeated_points_within(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for MultiPolygon<T> {
+    fn remove_repeated_points_within(&self, epsilon: impl Into<Epsilon<T>>) -> Self {
+        let epsilon = epsilon.into();
+        MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|p| p.remove_repeated_points_within(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for GeometryCollection<T> {
+    fn remove_repeated_points_within(&self, epsilon: impl Into<Epsilon<T>>) -> Self {
+        let epsilon = epsilon.into();
+        GeometryCollection::new_from(
+            self.0
+                .iter()
+                .map(|g| g.remove_repeated_points_within(epsilon))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RemoveRepeatedPointsWithin<T> for Geometry<T> {
+    fn remove_repeated_points_within(&self, epsilon: impl Into<Epsilon<T>>) -> Self {
+        let epsilon = epsilon.into();
+        match self {
+            Geometry::LineString(ls) => {
+                Geometry::LineString(ls.remove_repeated_points_within(epsilon))
+            }
+            Geometry::Polygon(p) => Geometry::Polygon(p.remove_repeated_points_within(epsilon)),
+            Geometry::MultiPoint(mp) => {
+                Geometry::MultiPoint(mp.remove_repeated_points_within(epsilon))
+            }
+            Geometry::MultiLineString(mls) => {
+                Geometry::MultiLineString(mls.remove_repeated_points_within(epsilon))
+            }
+            Geometry::MultiPolygon(mp) => {
+                Geometry::MultiPolygon(mp.remove_repeated_points_within(epsilon))
+            }
+            Geometry::GeometryCollection(gc) => {
+                Geometry::GeometryCollection(gc.remove_repeated_points_within(epsilon))
+            }
+            Geometry::Point(_) | Geometry::Line(_) | Geometry::Rect(_) | Geometry::Triangle(_) => {
+                self.clone()
+            }
+        }
+    }
+}
+
+fn dedup_coords_within<T: CoordFloat>(coords: &[Coord<T>], epsilon: Epsilon<T>) -> Vec<Coord<T>> {
+    let mut kept: Vec<Coord<T>> = Vec::with_capacity(coords.len());
+    for coord in coords {
+        match kept.last() {
+            Some(last) if epsilon.coords_eq(*last, *coord) => {}
+            _ => kept.push(*coord),
+        }
+    }
+    // If the closing coordinate was removed, the last kept coordinate is within `epsilon` of it
+    if let (Some(first), Some(last)) = (coords.first(), coords.last()) {
+        if coords.len() > 1 && first == last && kept.len() > 1 {
+            *kept.last_mut().expect("kept isn't empty") = *last;
+        }
+    }
+    kept
+}
+
 #[cfg(test)]
 mod test {
     use crate::RemoveRepeatedPoints;
@@ -471,4 +614,50 @@ mod test {
 
         assert_eq!(gc, expected);
     }
+
+    #[test]
+    fn remove_repeated_points_within() {
+        use crate::{wkt, Epsilon, RemoveRepeatedPointsWithin};
+
+        let multi_point = wkt!(MULTIPOINT(0. 0.,1. 1.,1e-9 0.,1. 1.));
+        assert_eq!(
+            multi_point.remove_repeated_points_within(1e-6),
+            wkt!(MULTIPOINT(0. 0.,1. 1.))
+        );
+        assert_eq!(
+            multi_point.remove_repeated_points_within(Epsilon::exact()),
+            wkt!(MULTIPOINT(0. 0.,1. 1.,1e-9 0.))
+        );
+
+        // the ring stays closed, even though its last coordinates are within epsilon
+        let polygon = wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,1e-9 0.,0. 0.)));
+        assert_eq!(
+            polygon.remove_repeated_points_within(1e-6),
+            wkt!(POLYGON((0. 0.,1. 0.,1. 1.,0. 1.,0. 0.)))
+        );
+
+        let multi_line_string = wkt!(MULTILINESTRING((0. 0.,0. 0.,1. 0.),(1. 1.,1. 1.000001)));
+        assert_eq!(
+            multi_line_string.remove_repeated_points_within(Epsilon::ulps(4)),
+            wkt!(MULTILINESTRING((0. 0.,1. 0.),(1. 1.,1. 1.000001)))
+        );
+        assert_eq!(
+            multi_line_string.remove_repeated_points_within(1e-3),
+            wkt!(MULTILINESTRING((0. 0.,1. 0.),(1. 1.)))
+        );
+
+        let collection = GeometryCollection::new_from(vec![
+            crate::Geometry::Point(wkt!(POINT(0. 0.))),
+            crate::Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(LINESTRING(
+                0. 0.,1e-9 0.,1. 0.
+            )))),
+            crate::Geometry::MultiPoint(wkt!(MULTIPOINT(0. 0.,1e-9 0.))),
+        ]);
+        let expected = GeometryCollection::new_from(vec![
+            crate::Geometry::Point(wkt!(POINT(0. 0.))),
+            crate::Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(LINESTRING(0. 0.,1. 0.)))),
+            crate::Geometry::MultiPoint(wkt!(MULTIPOINT(0. 0.))),
+        ]);
+        assert_eq!(collection.remove_repeated_points_within(1e-6), expected);
+    }
 }
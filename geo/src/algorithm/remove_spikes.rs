@@ -0,0 +1,216 @@
+use crate::{Coord, CoordFloat, LineString, MultiPolygon, Polygon};
+
+/// Remove spikes and gores from the rings of polygons: needle-like excursions, outwards and
+/// inwards respectively, where a ring goes out to a vertex and comes back nearly the same way.
+///
+/// A vertex is removed if the angle between its two segments is at most `max_angle` degrees,
+/// and the triangle it forms with its neighbours has an area of at most `max_area`. Pass
+/// `T::infinity()` as `max_area` to remove sharp vertices however large their excursion is.
+///
+/// Removing a vertex can make its neighbours spikes in turn, so a needle of several vertices is
+/// removed completely, and so is a zero-width excursion back to the same coordinate. Rings
+/// which collapse to fewer than three distinct vertices are removed; if that is the exterior,
+/// the result is an empty polygon, which a `MultiPolygon` drops.
+///
+/// Vertices repeated consecutively have no angle and are kept, so remove them first with
+/// [`RemoveRepeatedPoints`](crate::RemoveRepeatedPoints) or
+/// [`RemoveRepeatedPointsWithin`](crate::RemoveRepeatedPointsWithin).
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, RemoveSpikes};
+///
+/// // a spike at (8 5), and a gore at (5 1) of the hole
+/// let polygon = wkt!(POLYGON(
+///     (0. 0.,4. 0.,4. 4.,8. 5.,4. 4.001,0. 4.,0. 0.),
+///     (1. 1.,2. 1.,5. 1.,2. 1.0001,2. 2.,1. 2.,1. 1.)
+/// ));
+///
+/// assert_eq!(
+///     polygon.remove_spikes(1., 0.01),
+///     wkt!(POLYGON(
+///         (0. 0.,4. 0.,4. 4.,4. 4.001,0. 4.,0. 0.),
+///         (1. 1.,2. 1.,2. 1.0001,2. 2.,1. 2.,1. 1.)
+///     ))
+/// );
+///
+/// // with a smaller area tolerance, the spike is kept
+/// assert_eq!(polygon.remove_spikes(1., 0.001).exterior().0.len(), 7);
+/// ```
+pub trait RemoveSpikes<T: CoordFloat> {
+    /// Create a new geometry with the vertices of spikes and gores removed.
+    fn remove_spikes(&self, max_angle: T, max_area: T) -> Self;
+}
+
+impl<T: CoordFloat> RemoveSpikes<T> for Polygon<T> {
+    fn remove_spikes(&self, max_angle: T, max_area: T) -> Self {
+        let tolerance = Tolerance::new(max_angle, max_area);
+        let Some(exterior) = remove_ring_spikes(self.exterior(), tolerance) else {
+            return Polygon::new(LineString::new(vec![]), vec![]);
+        };
+        Polygon::new(
+            exterior,
+            self.interiors()
+                .iter()
+                .filter_map(|interior| remove_ring_spikes(interior, tolerance))
+                .collect(),
+        )
+    }
+}
+
+impl<T: CoordFloat> RemoveSpikes<T> for MultiPolygon<T> {
+    fn remove_spikes(&self, max_angle: T, max_area: T) -> Self {
+        MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|polygon| polygon.remove_spikes(max_angle, max_area))
+                .filter(|polygon| !polygon.exterior().0.is_empty())
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tolerance<T> {
+    max_angle: T,
+    twice_max_area: T,
+}
+
+impl<T: CoordFloat> Tolerance<T> {
+    fn new(max_angle: T, max_area: T) -> Self {
+        Self {
+            max_angle: max_angle.to_radians(),
+            twice_max_area: max_area + max_area,
+        }
+    }
+
+    /// Whether the ring going from `previous` to `vertex` to `next` forms a spike at `vertex`.
+    fn is_spike(&self, previous: Coord<T>, vertex: Coord<T>, next: Coord<T>) -> bool {
+        if vertex == previous || vertex == next {
+            return false;
+        }
+        let (a, b) = (previous - vertex, next - vertex);
+        let twice_area = (a.x * b.y - a.y * b.x).abs();
+        let angle = twice_area.atan2(a.x * b.x + a.y * b.y);
+        angle <= self.max_angle && twice_area <= self.twice_max_area
+    }
+}
+
+/// The ring without its spikes, or `None` if it collapses.
+fn remove_ring_spikes<T: CoordFloat>(
+    ring: &LineString<T>,
+    tolerance: Tolerance<T>,
+) -> Option<LineString<T>> {
+    let coords = match ring.0.split_last() {
+        Some((last, rest)) if rest.first() == Some(last) => rest,
+        _ => &ring.0[..],
+    };
+
+    // Keep the vertices which aren't spikes with respect to the kept vertices before them,
+    // backtracking when removing one makes the previous vertex a spike.
+    let mut kept: Vec<Coord<T>> = Vec::with_capacity(coords.len());
+    for &coord in coords {
+        kept.push(coord);
+        pop_spikes(&mut kept, tolerance);
+    }
+
+    // Then check the vertices around the start of the ring, until neither end changes.
+    loop {
+        let len = kept.len();
+        if len < 3 {
+            return None;
+        }
+        // the last vertex is either a repeat of the first one or a spike
+        if kept[len - 1] == kept[0] || tolerance.is_spike(kept[len - 2], kept[len - 1], kept[0]) {
+            kept.pop();
+        } else if tolerance.is_spike(kept[len - 1], kept[0], kept[1]) {
+            kept.remove(0);
+        } else {
+            break;
+        }
+    }
+
+    kept.push(kept[0]);
+    Some(LineString::new(kept))
+}
+
+/// Remove the second to last vertex of `kept` while it is a spike, and the last one if that
+/// leaves it repeated.
+fn pop_spikes<T: CoordFloat>(kept: &mut Vec<Coord<T>>, tolerance: Tolerance<T>) {
+    while let [.., previous, vertex, next] = kept[..] {
+        if !tolerance.is_spike(previous, vertex, next) {
+            break;
+        }
+        kept.remove(kept.len() - 2);
+        if previous == next {
+            kept.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, Area};
+
+    #[test]
+    fn multi_vertex_needle() {
+        // the needle has two vertices, the first of which only becomes a spike once the second
+        // is removed
+        let polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,9. 4.5,10. 4.5,4. 4.,0. 4.,0. 0.)));
+        assert_eq!(
+            polygon.remove_spikes(10., 10.),
+            wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)))
+        );
+    }
+
+    #[test]
+    fn zero_width_excursion() {
+        let polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,6. 6.,4. 4.,0. 4.,0. 0.)));
+        let cleaned = polygon.remove_spikes(0., 0.);
+        assert_eq!(cleaned, wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))));
+        assert_eq!(cleaned.unsigned_area(), polygon.unsigned_area());
+    }
+
+    #[test]
+    fn spike_at_ring_start() {
+        let polygon = wkt!(POLYGON((6. 6.,4. 4.,0. 4.,0. 0.,4. 0.,4. 4.,6. 6.)));
+        assert_eq!(
+            polygon.remove_spikes(0., 0.),
+            wkt!(POLYGON((4. 4.,0. 4.,0. 0.,4. 0.,4. 4.)))
+        );
+    }
+
+    #[test]
+    fn keeps_regular_vertices() {
+        let polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,2. 4.,0. 4.,0. 0.)));
+        assert_eq!(polygon.remove_spikes(30., f64::INFINITY), polygon);
+        // a sharp but regular corner
+        let triangle = wkt!(POLYGON((0. 0.,10. 0.,0. 1.,0. 0.)));
+        assert_eq!(triangle.remove_spikes(1., f64::INFINITY), triangle);
+    }
+
+    #[test]
+    fn collapsing_rings() {
+        let polygon = wkt!(POLYGON(
+            (0. 0.,4. 0.,4. 4.,0. 4.,0. 0.),
+            (1. 1.,2. 1.,1. 1.)
+        ));
+        assert_eq!(
+            polygon.remove_spikes(1., 1.),
+            wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)))
+        );
+
+        let multi_polygon = wkt!(MULTIPOLYGON(
+            ((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.)),
+            ((10. 10.,12. 10.,10. 10.))
+        ));
+        assert_eq!(
+            multi_polygon.remove_spikes(1., 1.),
+            wkt!(MULTIPOLYGON(((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))))
+        );
+        let empty: Polygon = wkt!(POLYGON EMPTY);
+        assert_eq!(empty.remove_spikes(1., 1.), empty);
+    }
+}
@@ -1,4 +1,19 @@
-use crate::{AffineOps, AffineTransform, BoundingRect, Coord, CoordFloat, CoordNum, Rect};
+use crate::{
+    AffineOps, AffineTransform, BoundingRect, Coord, CoordFloat, CoordNum, GeoFloat, Point, Rect,
+};
+
+/// The point of origin used by [`Scale::scale_with_origin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleOrigin<T: CoordNum> {
+    /// Scale the whole geometry around its centroid.
+    GlobalCentroid,
+    /// Scale each member of a `Multi*` geometry or `GeometryCollection` around its own
+    /// centroid, so that the members stay in place. A single geometry is scaled around its
+    /// centroid.
+    PerMemberCentroid,
+    /// Scale the whole geometry around a point.
+    Point(Point<T>),
+}
 
 /// An affine transformation which scales a geometry up or down by a factor.
 ///
@@ -82,6 +97,69 @@ pub trait Scale<T: CoordNum> {
 
     /// Mutable version of [`scale_around_point`](Self::scale_around_point).
     fn scale_around_point_mut(&mut self, x_factor: T, y_factor: T, origin: impl Into<Coord<T>>);
+
+    /// Scale a geometry around a point of `origin` chosen by [`ScaleOrigin`], which can be the
+    /// centroid of the geometry, the centroids of each of its members, or any point.
+    ///
+    /// # Negative factors
+    ///
+    /// A negative factor reflects the geometry across the vertical (for `x_factor`) or
+    /// horizontal (for `y_factor`) line through the origin. Reflecting in exactly one axis
+    /// reverses the winding order of every ring, so that counter-clockwise exteriors become
+    /// clockwise. Use [`Orient`](crate::Orient) to restore the winding order if needed.
+    /// Negating both factors is a rotation by 180°, and keeps the winding order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, wkt, Scale, ScaleOrigin};
+    ///
+    /// let squares = wkt!(MULTIPOLYGON(
+    ///     ((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)),
+    ///     ((10. 0.,12. 0.,12. 2.,10. 2.,10. 0.))
+    /// ));
+    ///
+    /// // Each square is shrunk in place
+    /// let scaled = squares.scale_with_origin(0.5, 0.5, ScaleOrigin::PerMemberCentroid);
+    /// assert_eq!(scaled, wkt!(MULTIPOLYGON(
+    ///     ((0.5 0.5,1.5 0.5,1.5 1.5,0.5 1.5,0.5 0.5)),
+    ///     ((10.5 0.5,11.5 0.5,11.5 1.5,10.5 1.5,10.5 0.5))
+    /// )));
+    ///
+    /// // The squares are drawn towards their common centroid
+    /// let scaled = squares.scale_with_origin(0.5, 0.5, ScaleOrigin::GlobalCentroid);
+    /// assert_eq!(scaled.0[0], polygon![(x: 3., y: 0.5), (x: 4., y: 0.5), (x: 4., y: 1.5), (x: 3., y: 1.5)]);
+    /// ```
+    ///
+    /// Reflecting a polygon, and restoring its winding order:
+    ///
+    /// ```
+    /// use geo::orient::{Direction, Orient};
+    /// use geo::{point, polygon, Scale, ScaleOrigin, Winding};
+    ///
+    /// let triangle = polygon![(x: 1., y: 0.), (x: 3., y: 0.), (x: 1., y: 2.)];
+    /// assert!(triangle.exterior().is_ccw());
+    ///
+    /// // Mirror across the y axis
+    /// let origin = ScaleOrigin::Point(point!(x: 0., y: 0.));
+    /// let mirrored = triangle.scale_with_origin(-1., 1., origin);
+    /// assert_eq!(mirrored, polygon![(x: -1., y: 0.), (x: -3., y: 0.), (x: -1., y: 2.)]);
+    /// assert!(mirrored.exterior().is_cw());
+    ///
+    /// let mirrored = mirrored.orient(Direction::Default);
+    /// assert!(mirrored.exterior().is_ccw());
+    /// ```
+    #[must_use]
+    fn scale_with_origin(&self, x_factor: T, y_factor: T, origin: ScaleOrigin<T>) -> Self
+    where
+        T: GeoFloat,
+        Self: private::ScaleMembers<T>;
+
+    /// Mutable version of [`scale_with_origin`](Self::scale_with_origin).
+    fn scale_with_origin_mut(&mut self, x_factor: T, y_factor: T, origin: ScaleOrigin<T>)
+    where
+        T: GeoFloat,
+        Self: private::ScaleMembers<T>;
 }
 
 impl<T, IR, G> Scale<T> for G
@@ -127,4 +205,121 @@ where
         let affineop = AffineTransform::scale(x_factor, y_factor, origin);
         self.affine_transform_mut(&affineop)
     }
+
+    fn scale_with_origin(&self, x_factor: T, y_factor: T, origin: ScaleOrigin<T>) -> Self
+    where
+        T: GeoFloat,
+        Self: private::ScaleMembers<T>,
+    {
+        let mut scaled = self.clone();
+        scaled.scale_with_origin_mut(x_factor, y_factor, origin);
+        scaled
+    }
+
+    fn scale_with_origin_mut(&mut self, x_factor: T, y_factor: T, origin: ScaleOrigin<T>)
+    where
+        T: GeoFloat,
+        Self: private::ScaleMembers<T>,
+    {
+        match origin {
+            ScaleOrigin::GlobalCentroid => {
+                // Empty geometries have no centroid, but in that case
+                // transforming is a no-op anyway.
+                if let Some(centroid) = self.centroid_coord() {
+                    self.scale_around_point_mut(x_factor, y_factor, centroid);
+                }
+            }
+            ScaleOrigin::PerMemberCentroid => self.scale_members_mut(x_factor, y_factor),
+            ScaleOrigin::Point(point) => self.scale_around_point_mut(x_factor, y_factor, point),
+        }
+    }
+}
+
+// seal the trait needed by `Scale::scale_with_origin`, so that its methods don't leak onto the
+// public interface.
+mod private {
+    use super::Scale;
+    use crate::geometry::*;
+    use crate::{Centroid, GeoFloat};
+
+    pub trait ScaleMembers<T: GeoFloat> {
+        /// the centroid of the whole geometry, if it isn't empty
+        fn centroid_coord(&self) -> Option<Coord<T>>;
+        /// scale each member of the geometry around its own centroid
+        fn scale_members_mut(&mut self, x_factor: T, y_factor: T);
+    }
+
+    fn centroid_coord<T, G, O>(geometry: &G) -> Option<Coord<T>>
+    where
+        T: GeoFloat,
+        G: Centroid<Output = O>,
+        O: Into<Option<Point<T>>>,
+    {
+        geometry.centroid().into().map(|centroid| centroid.0)
+    }
+
+    macro_rules! impl_single {
+        ($($type: ident),+) => {
+            $(
+            impl<T: GeoFloat> ScaleMembers<T> for $type<T> {
+                fn centroid_coord(&self) -> Option<Coord<T>> {
+                    centroid_coord(self)
+                }
+
+                fn scale_members_mut(&mut self, x_factor: T, y_factor: T) {
+                    if let Some(centroid) = self.centroid_coord() {
+                        self.scale_around_point_mut(x_factor, y_factor, centroid);
+                    }
+                }
+            }
+            )+
+        };
+    }
+
+    macro_rules! impl_multi {
+        ($($type: ident),+) => {
+            $(
+            impl<T: GeoFloat> ScaleMembers<T> for $type<T> {
+                fn centroid_coord(&self) -> Option<Coord<T>> {
+                    centroid_coord(self)
+                }
+
+                fn scale_members_mut(&mut self, x_factor: T, y_factor: T) {
+                    for member in self.iter_mut() {
+                        member.scale_members_mut(x_factor, y_factor);
+                    }
+                }
+            }
+            )+
+        };
+    }
+
+    impl_single!(Point, Line, LineString, Polygon, Rect, Triangle);
+    impl_multi!(
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        GeometryCollection
+    );
+
+    impl<T: GeoFloat> ScaleMembers<T> for Geometry<T> {
+        fn centroid_coord(&self) -> Option<Coord<T>> {
+            centroid_coord(self)
+        }
+
+        fn scale_members_mut(&mut self, x_factor: T, y_factor: T) {
+            match self {
+                Geometry::Point(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::Line(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::LineString(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::Polygon(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::MultiPoint(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::MultiLineString(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::MultiPolygon(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::GeometryCollection(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::Rect(g) => g.scale_members_mut(x_factor, y_factor),
+                Geometry::Triangle(g) => g.scale_members_mut(x_factor, y_factor),
+            }
+        }
+    }
 }
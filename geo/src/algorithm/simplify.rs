@@ -1,153 +1,163 @@
-use crate::algorithm::{CoordsIter, Distance, Euclidean};
-use crate::geometry::{Coord, Line, LineString, MultiLineString, MultiPolygon, Polygon};
+use crate::algorithm::{Distance, Euclidean};
+use crate::geometry::{
+    Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPolygon, Polygon,
+};
 use crate::GeoFloat;
+use geo_traits::{CoordTrait, LineStringTrait};
+use std::cmp::Ordering;
 
 const LINE_STRING_INITIAL_MIN: usize = 2;
 const POLYGON_INITIAL_MIN: usize = 4;
 
-// Because the RDP algorithm is recursive, we can't assign an index to a point inside the loop
-// instead, we wrap a simple struct around index and point in a wrapper function,
-// passing that around instead, extracting either points or indices on the way back out
-#[derive(Copy, Clone)]
-struct RdpIndex<T>
-where
-    T: GeoFloat,
-{
-    index: usize,
-    coord: Coord<T>,
-}
-
 // Wrapper for the RDP algorithm, returning simplified points
-fn rdp<T, I: Iterator<Item = Coord<T>>, const INITIAL_MIN: usize>(
-    coords: I,
-    epsilon: &T,
-) -> Vec<Coord<T>>
+fn rdp<T, const INITIAL_MIN: usize>(coords: &[Coord<T>], epsilon: &T) -> Vec<Coord<T>>
 where
     T: GeoFloat,
 {
-    // Epsilon must be greater than zero for any meaningful simplification to happen
-    if *epsilon <= T::zero() {
-        return coords.collect::<Vec<Coord<T>>>();
-    }
-    let rdp_indices = &coords
-        .enumerate()
-        .map(|(idx, coord)| RdpIndex { index: idx, coord })
-        .collect::<Vec<RdpIndex<T>>>();
-    let mut simplified_len = rdp_indices.len();
-    let simplified_coords: Vec<_> =
-        compute_rdp::<T, INITIAL_MIN>(rdp_indices, &mut simplified_len, epsilon)
-            .into_iter()
-            .map(|rdpindex| rdpindex.coord)
-            .collect();
-    debug_assert_eq!(simplified_coords.len(), simplified_len);
-    simplified_coords
+    let mut simplified = Vec::new();
+    compute_rdp::<T, INITIAL_MIN>(
+        coords.len(),
+        |i| coords[i],
+        epsilon,
+        &mut Vec::new(),
+        |index| simplified.push(coords[index]),
+    );
+    simplified
 }
 
 // Wrapper for the RDP algorithm, returning simplified point indices
 fn calculate_rdp_indices<T, const INITIAL_MIN: usize>(
-    rdp_indices: &[RdpIndex<T>],
+    coords: &[Coord<T>],
     epsilon: &T,
 ) -> Vec<usize>
 where
     T: GeoFloat,
 {
-    if *epsilon <= T::zero() {
-        return rdp_indices
-            .iter()
-            .map(|rdp_index| rdp_index.index)
-            .collect();
-    }
-
-    let mut simplified_len = rdp_indices.len();
-    let simplified_coords =
-        compute_rdp::<T, INITIAL_MIN>(rdp_indices, &mut simplified_len, epsilon)
-            .into_iter()
-            .map(|rdpindex| rdpindex.index)
-            .collect::<Vec<usize>>();
-    debug_assert_eq!(simplified_len, simplified_coords.len());
-    simplified_coords
+    let mut simplified = Vec::new();
+    compute_rdp::<T, INITIAL_MIN>(
+        coords.len(),
+        |i| coords[i],
+        epsilon,
+        &mut Vec::new(),
+        |index| simplified.push(index),
+    );
+    simplified
 }
 
 // Ramer–Douglas-Peucker line simplification algorithm
-// This function returns both the retained points, and their indices in the original geometry,
-// for more flexible use by FFI implementers
+// This function reads the `len` coordinates of the input through `coord`, so it works with any
+// storage, and calls `retain` with the index of each retained point, in order, for more
+// flexible use by FFI implementers. Rather than recursing into the subsegments either side of
+// the farthest point, it keeps the subsegments still to be simplified on `stack`, so that very
+// long inputs can't overflow the call stack. Subsegments are simplified in the same order as
+// a recursive implementation would.
 fn compute_rdp<T, const INITIAL_MIN: usize>(
-    rdp_indices: &[RdpIndex<T>],
-    simplified_len: &mut usize,
+    len: usize,
+    coord: impl Fn(usize) -> Coord<T>,
     epsilon: &T,
-) -> Vec<RdpIndex<T>>
-where
+    stack: &mut Vec<(usize, usize)>,
+    mut retain: impl FnMut(usize),
+) where
     T: GeoFloat,
 {
-    if rdp_indices.is_empty() {
-        return vec![];
-    }
-
-    let first = rdp_indices[0];
-    let last = rdp_indices[rdp_indices.len() - 1];
-    if rdp_indices.len() == 2 {
-        return vec![first, last];
+    // Epsilon must be greater than zero for any meaningful simplification to happen
+    if *epsilon <= T::zero() || len <= 2 {
+        (0..len).for_each(retain);
+        return;
     }
 
-    let first_last_line = Line::new(first.coord, last.coord);
-
-    // Find the farthest `RdpIndex` from `first_last_line`
-    let (farthest_index, farthest_distance) = rdp_indices
-        .iter()
-        .enumerate()
-        .take(rdp_indices.len() - 1) // Don't include the last index
-        .skip(1) // Don't include the first index
-        .map(|(index, rdp_index)| {
-            (
-                index,
-                Euclidean::distance(rdp_index.coord, &first_last_line),
-            )
-        })
-        .fold(
-            (0usize, T::zero()),
-            |(farthest_index, farthest_distance), (index, distance)| {
-                if distance >= farthest_distance {
-                    (index, distance)
-                } else {
-                    (farthest_index, farthest_distance)
-                }
-            },
-        );
-    debug_assert_ne!(farthest_index, 0);
-
-    if farthest_distance > *epsilon {
-        // The farthest index was larger than epsilon, so we will recursively simplify subsegments
-        // split by the farthest index.
-        let mut intermediate =
-            compute_rdp::<T, INITIAL_MIN>(&rdp_indices[..=farthest_index], simplified_len, epsilon);
-
-        intermediate.pop(); // Don't include the farthest index twice
-
-        intermediate.extend_from_slice(&compute_rdp::<T, INITIAL_MIN>(
-            &rdp_indices[farthest_index..],
-            simplified_len,
-            epsilon,
-        ));
-        return intermediate;
+    let mut simplified_len = len;
+    stack.clear();
+    stack.push((0, len - 1));
+    while let Some((first, last)) = stack.pop() {
+        if last - first > 1 {
+            let first_last_line = Line::new(coord(first), coord(last));
+
+            // Find the farthest coordinate from `first_last_line`
+            let (farthest_index, farthest_distance) = (first + 1..last)
+                .map(|index| (index, Euclidean.distance(coord(index), &first_last_line)))
+                .fold(
+                    (first, T::zero()),
+                    |(farthest_index, farthest_distance), (index, distance)| {
+                        if distance >= farthest_distance {
+                            (index, distance)
+                        } else {
+                            (farthest_index, farthest_distance)
+                        }
+                    },
+                );
+            debug_assert_ne!(farthest_index, first);
+
+            if farthest_distance > *epsilon {
+                // The farthest index was larger than epsilon, so we will simplify the
+                // subsegments split by the farthest index, the first one first.
+                stack.push((farthest_index, last));
+                stack.push((first, farthest_index));
+                continue;
+            }
+
+            // The farthest index was less than or equal to epsilon, so we will retain only the
+            // first and last indices, resulting in the indices inbetween getting culled.
+
+            // Update `simplified_len` to reflect the new number of indices by subtracting the
+            // number of indices we're culling.
+            let number_culled = last - first - 1;
+            let new_length = simplified_len - number_culled;
+
+            // If `simplified_len` is now lower than the minimum number of indices needed, then
+            // don't perform the culling and retain the whole subsegment.
+            if new_length < INITIAL_MIN {
+                (first..last).for_each(&mut retain);
+                continue;
+            }
+            simplified_len = new_length;
+        }
+
+        // The last index is retained as the first index of the next subsegment
+        retain(first);
     }
+    retain(len - 1);
+}
 
-    // The farthest index was less than or equal to epsilon, so we will retain only the first
-    // and last indices, resulting in the indices inbetween getting culled.
-
-    // Update `simplified_len` to reflect the new number of indices by subtracting the number
-    // of indices we're culling.
-    let number_culled = rdp_indices.len() - 2;
-    let new_length = *simplified_len - number_culled;
-
-    // If `simplified_len` is now lower than the minimum number of indices needed, then don't
-    // perform the culling and return the original input.
-    if new_length < INITIAL_MIN {
-        return rdp_indices.to_owned();
+// The largest epsilon for which the RDP algorithm retains each coordinate of a line string:
+// the distance at which the coordinate splits its subsegment, capped by the distances at which
+// the enclosing subsegments were split. A coordinate is retained for `epsilon` if and only if
+// its importance is greater than `epsilon`. The endpoints are always retained.
+fn rdp_importance<T>(coords: &[Coord<T>]) -> Vec<T>
+where
+    T: GeoFloat,
+{
+    let mut importance = vec![T::zero(); coords.len()];
+    let Some(last) = coords.len().checked_sub(1) else {
+        return importance;
+    };
+    importance[0] = T::infinity();
+    importance[last] = T::infinity();
+
+    let mut stack = vec![(0, last, T::infinity())];
+    while let Some((first, last, cap)) = stack.pop() {
+        if last - first < 2 {
+            continue;
+        }
+        let first_last_line = Line::new(coords[first], coords[last]);
+        let (farthest_index, farthest_distance) = (first + 1..last)
+            .map(|index| (index, Euclidean.distance(coords[index], &first_last_line)))
+            .fold(
+                (first, T::zero()),
+                |(farthest_index, farthest_distance), (index, distance)| {
+                    if distance >= farthest_distance {
+                        (index, distance)
+                    } else {
+                        (farthest_index, farthest_distance)
+                    }
+                },
+            );
+        let farthest_importance = farthest_distance.min(cap);
+        importance[farthest_index] = farthest_importance;
+        stack.push((first, farthest_index, farthest_importance));
+        stack.push((farthest_index, last, farthest_importance));
     }
-    *simplified_len = new_length;
-
-    // Cull indices between `first` and `last`.
-    vec![first, last]
+    importance
 }
 
 /// Simplifies a geometry.
@@ -159,6 +169,10 @@ where
 ///
 /// Multi* objects are simplified by simplifying all their constituent geometries individually.
 ///
+/// A `Geometry` is simplified according to its variant, and a `GeometryCollection` by
+/// simplifying each of its members, so a whole layer can be simplified at once. Variants which
+/// can't be simplified, such as points, lines, rects and triangles, are returned unchanged.
+///
 /// A larger `epsilon` means being more aggressive about removing points with less concern for
 /// maintaining the existing shape.
 ///
@@ -249,10 +263,7 @@ where
     T: GeoFloat,
 {
     fn simplify(&self, epsilon: &T) -> Self {
-        LineString::from(rdp::<_, _, LINE_STRING_INITIAL_MIN>(
-            self.coords_iter(),
-            epsilon,
-        ))
+        LineString::from(rdp::<_, LINE_STRING_INITIAL_MIN>(&self.0, epsilon))
     }
 }
 
@@ -261,17 +272,274 @@ where
     T: GeoFloat,
 {
     fn simplify_idx(&self, epsilon: &T) -> Vec<usize> {
-        calculate_rdp_indices::<_, LINE_STRING_INITIAL_MIN>(
-            &self
-                .0
-                .iter()
-                .enumerate()
-                .map(|(idx, coord)| RdpIndex {
-                    index: idx,
-                    coord: *coord,
-                })
-                .collect::<Vec<RdpIndex<T>>>(),
+        calculate_rdp_indices::<_, LINE_STRING_INITIAL_MIN>(&self.0, epsilon)
+    }
+}
+
+/// Simplifies any [`LineStringTrait`] implementation, returning the retained _indices_ of its
+/// coordinates.
+///
+/// This is the same as [`SimplifyIdx`] for a [`LineString`], but reads the coordinates
+/// directly from the input, e.g. a WKB buffer or a columnar array, without converting it to a
+/// `geo` geometry. The indices can then be applied as a mask in the input's own storage.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, simplify_line_string_idx};
+///
+/// let line_string = line_string![
+///     (x: 0.0, y: 0.0),
+///     (x: 5.0, y: 4.0),
+///     (x: 11.0, y: 5.5),
+///     (x: 17.3, y: 3.2),
+///     (x: 27.8, y: 0.1),
+/// ];
+/// // An attribute stored alongside each coordinate
+/// let elevations = [10, 12, 15, 11, 9];
+///
+/// let retained = simplify_line_string_idx(&line_string, &1.0);
+/// assert_eq!(retained, [0, 1, 2, 4]);
+///
+/// let simplified_elevations: Vec<_> = retained.iter().map(|&i| elevations[i]).collect();
+/// assert_eq!(simplified_elevations, [10, 12, 15, 9]);
+/// ```
+pub fn simplify_line_string_idx<T, L>(line_string: &L, epsilon: &T) -> Vec<usize>
+where
+    T: GeoFloat,
+    L: LineStringTrait<T = T>,
+{
+    let mut simplified = Vec::new();
+    compute_rdp::<T, LINE_STRING_INITIAL_MIN>(
+        line_string.num_coords(),
+        |i| {
+            let coord = line_string
+                .coord(i)
+                .expect("index is less than the number of coordinates");
+            Coord {
+                x: coord.x(),
+                y: coord.y(),
+            }
+        },
+        epsilon,
+        &mut Vec::new(),
+        |index| simplified.push(index),
+    );
+    simplified
+}
+
+/// Simplifies a geometry into a reusable buffer.
+///
+/// This is the same as [`Simplify`], except that the retained coordinates are written to
+/// `output`, which is cleared first, rather than to a new geometry. Reusing one buffer while
+/// simplifying many geometries avoids allocating for each of them.
+pub trait SimplifyInto<T> {
+    /// Writes the coordinates retained by the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) algorithm to `output`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{coord, line_string, SimplifyInto};
+    ///
+    /// let line_strings = [
+    ///     line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 0.1), (x: 10.0, y: 0.0)],
+    ///     line_string![(x: 0.0, y: 0.0), (x: 5.0, y: 4.0), (x: 10.0, y: 0.0)],
+    /// ];
+    ///
+    /// let mut buffer = Vec::new();
+    /// line_strings[0].simplify_into(&1.0, &mut buffer);
+    /// assert_eq!(buffer, [coord! { x: 0.0, y: 0.0 }, coord! { x: 10.0, y: 0.0 }]);
+    ///
+    /// line_strings[1].simplify_into(&1.0, &mut buffer);
+    /// assert_eq!(buffer, line_strings[1].0);
+    /// ```
+    fn simplify_into(&self, epsilon: &T, output: &mut Vec<Coord<T>>)
+    where
+        T: GeoFloat;
+}
+
+impl<T> SimplifyInto<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_into(&self, epsilon: &T, output: &mut Vec<Coord<T>>) {
+        output.clear();
+        compute_rdp::<T, LINE_STRING_INITIAL_MIN>(
+            self.0.len(),
+            |i| self.0[i],
             epsilon,
+            &mut Vec::new(),
+            |index| output.push(self.0[index]),
+        );
+    }
+}
+
+/// Simplifies a stream of coordinates in chunks, using bounded memory.
+///
+/// Up to `chunk_size` coordinates are buffered at a time and simplified with the
+/// [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+/// algorithm. Consecutive chunks share an endpoint, which is always retained, so every input
+/// coordinate is still within `epsilon` of the output. The output may keep a few more
+/// coordinates than simplifying the whole line string at once would. The simplification is
+/// unaware of topology, as with [`Simplify`].
+///
+/// A `chunk_size` less than 2 is treated as 2.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, simplify_chunked, Coord};
+///
+/// // A zig-zag too long to hold in memory
+/// let coords = (0..1_000_000).map(|i| coord! { x: i as f64, y: (i % 2) as f64 * 0.1 });
+///
+/// let simplified: Vec<Coord> = simplify_chunked(coords, &1.0, 10_000).collect();
+/// assert_eq!(simplified.first(), Some(&coord! { x: 0.0, y: 0.0 }));
+/// assert_eq!(simplified.last(), Some(&coord! { x: 999_999.0, y: 0.1 }));
+/// // The endpoints of each chunk
+/// assert_eq!(simplified.len(), 102);
+/// ```
+pub fn simplify_chunked<T, I>(
+    coords: I,
+    epsilon: &T,
+    chunk_size: usize,
+) -> SimplifyChunked<I::IntoIter, T>
+where
+    T: GeoFloat,
+    I: IntoIterator<Item = Coord<T>>,
+{
+    SimplifyChunked {
+        coords: coords.into_iter(),
+        epsilon: *epsilon,
+        chunk_size: chunk_size.max(2),
+        chunk: Vec::new(),
+        stack: Vec::new(),
+        retained: Vec::new(),
+        next_retained: 0,
+        finished: false,
+    }
+}
+
+/// An iterator over the coordinates retained by [`simplify_chunked`].
+#[derive(Debug, Clone)]
+pub struct SimplifyChunked<I, T: GeoFloat> {
+    coords: I,
+    epsilon: T,
+    chunk_size: usize,
+    chunk: Vec<Coord<T>>,
+    stack: Vec<(usize, usize)>,
+    retained: Vec<Coord<T>>,
+    next_retained: usize,
+    finished: bool,
+}
+
+impl<I, T> Iterator for SimplifyChunked<I, T>
+where
+    I: Iterator<Item = Coord<T>>,
+    T: GeoFloat,
+{
+    type Item = Coord<T>;
+
+    fn next(&mut self) -> Option<Coord<T>> {
+        while self.next_retained == self.retained.len() {
+            if self.finished {
+                return None;
+            }
+
+            // The chunk starts with the last coordinate of the previous chunk, if any
+            let carried = self.chunk.len();
+            self.chunk
+                .extend(self.coords.by_ref().take(self.chunk_size - carried));
+            self.finished = self.chunk.len() < self.chunk_size;
+
+            self.retained.clear();
+            self.next_retained = 0;
+            let (chunk, retained) = (&self.chunk, &mut self.retained);
+            compute_rdp::<T, LINE_STRING_INITIAL_MIN>(
+                chunk.len(),
+                |i| chunk[i],
+                &self.epsilon,
+                &mut self.stack,
+                |index| retained.push(chunk[index]),
+            );
+            if !self.finished {
+                // The last coordinate is emitted as the first of the next chunk
+                self.retained.pop();
+                self.chunk.drain(..self.chunk.len() - 1);
+            }
+        }
+        let coord = self.retained[self.next_retained];
+        self.next_retained += 1;
+        Some(coord)
+    }
+}
+
+/// Simplifies a geometry to a target number of coordinates, rather than to a tolerance.
+///
+/// Each coordinate is ranked by the largest `epsilon` for which the [`Simplify`]
+/// (Ramer–Douglas–Peucker) algorithm would retain it, and the highest-ranked coordinates are
+/// kept. This is the same as simplifying with the smallest `epsilon` which leaves at most `n`
+/// coordinates.
+pub trait SimplifyToNPoints<T> {
+    /// Returns the simplified representation of a geometry with at most `n` coordinates, using
+    /// the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) algorithm
+    ///
+    /// Fewer than `n` coordinates may be kept when several coordinates are equally important.
+    /// The endpoints are always kept, so `n` less than 2 is treated as 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, Simplify, SimplifyToNPoints};
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 5.0, y: 4.0),
+    ///     (x: 11.0, y: 5.5),
+    ///     (x: 17.3, y: 3.2),
+    ///     (x: 27.8, y: 0.1),
+    /// ];
+    ///
+    /// let simplified = line_string.simplify_to_n_points(3);
+    ///
+    /// let expected = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 11.0, y: 5.5),
+    ///     (x: 27.8, y: 0.1),
+    /// ];
+    ///
+    /// assert_eq!(expected, simplified);
+    /// assert_eq!(line_string.simplify(&2.0), simplified);
+    /// ```
+    fn simplify_to_n_points(&self, n: usize) -> Self
+    where
+        T: GeoFloat;
+}
+
+impl<T> SimplifyToNPoints<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_to_n_points(&self, n: usize) -> Self {
+        let n = n.max(LINE_STRING_INITIAL_MIN);
+        if self.0.len() <= n {
+            return self.clone();
+        }
+        let importance = rdp_importance(&self.0);
+
+        // The smallest epsilon retaining at most `n` coordinates is the `n + 1`th largest
+        // importance
+        let mut ranked = importance.clone();
+        ranked.select_nth_unstable_by(n, |a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        let epsilon = ranked[n];
+
+        LineString::new(
+            self.0
+                .iter()
+                .zip(importance)
+                .filter(|(_, importance)| *importance > epsilon)
+                .map(|(coord, _)| *coord)
+                .collect(),
         )
     }
 }
@@ -291,15 +559,10 @@ where
 {
     fn simplify(&self, epsilon: &T) -> Self {
         Polygon::new(
-            LineString::from(rdp::<_, _, POLYGON_INITIAL_MIN>(
-                self.exterior().coords_iter(),
-                epsilon,
-            )),
+            LineString::from(rdp::<_, POLYGON_INITIAL_MIN>(&self.exterior().0, epsilon)),
             self.interiors()
                 .iter()
-                .map(|l| {
-                    LineString::from(rdp::<_, _, POLYGON_INITIAL_MIN>(l.coords_iter(), epsilon))
-                })
+                .map(|l| LineString::from(rdp::<_, POLYGON_INITIAL_MIN>(&l.0, epsilon)))
                 .collect(),
         )
     }
@@ -314,6 +577,35 @@ where
     }
 }
 
+impl<T> Simplify<T> for GeometryCollection<T>
+where
+    T: GeoFloat,
+{
+    fn simplify(&self, epsilon: &T) -> Self {
+        GeometryCollection::new_from(self.iter().map(|g| g.simplify(epsilon)).collect())
+    }
+}
+
+impl<T> Simplify<T> for Geometry<T>
+where
+    T: GeoFloat,
+{
+    fn simplify(&self, epsilon: &T) -> Self {
+        match self {
+            Geometry::LineString(ls) => Geometry::LineString(ls.simplify(epsilon)),
+            Geometry::Polygon(p) => Geometry::Polygon(p.simplify(epsilon)),
+            Geometry::MultiLineString(mls) => Geometry::MultiLineString(mls.simplify(epsilon)),
+            Geometry::MultiPolygon(mp) => Geometry::MultiPolygon(mp.simplify(epsilon)),
+            Geometry::GeometryCollection(gc) => Geometry::GeometryCollection(gc.simplify(epsilon)),
+            Geometry::Point(_)
+            | Geometry::Line(_)
+            | Geometry::MultiPoint(_)
+            | Geometry::Rect(_)
+            | Geometry::Triangle(_) => self.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -326,7 +618,7 @@ mod test {
             coord! { x: 9.0, y: 100.0 },
             coord! { x: 12.0, y: 100.0 },
         ];
-        let actual = rdp::<_, _, 2>(input.into_iter(), &1.0);
+        let actual = rdp::<_, 2>(&input, &1.0);
         let expected = [coord! { x: 8.0, y: 100.0 }, coord! { x: 12.0, y: 100.0 }];
         assert_eq!(actual, expected);
     }
@@ -346,21 +638,21 @@ mod test {
             coord! { x: 11.0, y: 5.5 },
             coord! { x: 27.8, y: 0.1 },
         ];
-        let simplified = rdp::<_, _, 2>(vec.into_iter(), &1.0);
+        let simplified = rdp::<_, 2>(&vec, &1.0);
         assert_eq!(simplified, compare);
     }
     #[test]
     fn rdp_test_empty_linestring() {
         let vec = Vec::new();
         let compare = Vec::new();
-        let simplified = rdp::<_, _, 2>(vec.into_iter(), &1.0);
+        let simplified = rdp::<_, 2>(&vec, &1.0);
         assert_eq!(simplified, compare);
     }
     #[test]
     fn rdp_test_two_point_linestring() {
         let vec = vec![coord! { x: 0.0, y: 0.0 }, coord! { x: 27.8, y: 0.1 }];
         let compare = vec![coord! { x: 0.0, y: 0.0 }, coord! { x: 27.8, y: 0.1 }];
-        let simplified = rdp::<_, _, 2>(vec.into_iter(), &1.0);
+        let simplified = rdp::<_, 2>(&vec, &1.0);
         assert_eq!(simplified, compare);
     }
 
@@ -437,6 +729,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn geometry_collection() {
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::Point(crate::wkt!(POINT(0. 0.))),
+            Geometry::LineString(crate::wkt!(LINESTRING(0. 0.,5. 0.1,10. 0.))),
+            Geometry::GeometryCollection(crate::wkt!(GEOMETRYCOLLECTION(POLYGON(
+                (0. 0.,0. 10.,5. 11.,10. 10.,10. 0.,0. 0.)
+            )))),
+            Geometry::MultiPoint(crate::wkt!(MULTIPOINT(0. 0.,0.1 0.,1. 1.))),
+        ]);
+        let expected = GeometryCollection::new_from(vec![
+            Geometry::Point(crate::wkt!(POINT(0. 0.))),
+            Geometry::LineString(crate::wkt!(LINESTRING(0. 0.,10. 0.))),
+            Geometry::GeometryCollection(crate::wkt!(GEOMETRYCOLLECTION(POLYGON(
+                (0. 0.,0. 10.,10. 10.,10. 0.,0. 0.)
+            )))),
+            Geometry::MultiPoint(crate::wkt!(MULTIPOINT(0. 0.,0.1 0.,1. 1.))),
+        ]);
+        assert_eq!(collection.simplify(&2.), expected);
+        assert_eq!(
+            Geometry::GeometryCollection(collection).simplify(&2.),
+            Geometry::GeometryCollection(expected)
+        );
+    }
+
     #[test]
     fn simplify_negative_epsilon() {
         let ls = line_string![
@@ -515,4 +832,77 @@ mod test {
         ];
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn simplify_into_matches_simplify() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let mut buffer = vec![coord! { x: 1.0, y: 1.0 }];
+        for epsilon in [0.0, 0.001, 0.01, 1.0] {
+            line_string.simplify_into(&epsilon, &mut buffer);
+            assert_eq!(buffer, line_string.simplify(&epsilon).0);
+        }
+    }
+
+    #[test]
+    fn simplify_chunked_single_chunk() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let chunk_size = line_string.0.len();
+        for epsilon in [0.0, 0.001, 0.01, 1.0] {
+            let simplified: Vec<_> =
+                simplify_chunked(line_string.0.iter().copied(), &epsilon, chunk_size).collect();
+            assert_eq!(simplified, line_string.simplify(&epsilon).0);
+        }
+    }
+
+    #[test]
+    fn simplify_chunked_within_epsilon() {
+        use crate::{Distance, Euclidean};
+
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let epsilon = 0.01;
+        for chunk_size in [0, 2, 3, 100, line_string.0.len() - 1] {
+            let simplified = LineString::new(
+                simplify_chunked(line_string.0.iter().copied(), &epsilon, chunk_size).collect(),
+            );
+            assert_eq!(simplified.0.first(), line_string.0.first());
+            assert_eq!(simplified.0.last(), line_string.0.last());
+            assert!(simplified.0.len() >= line_string.simplify(&epsilon).0.len());
+            for coord in &line_string.0 {
+                assert!(Euclidean.distance(&crate::Point::from(*coord), &simplified) <= epsilon);
+            }
+        }
+
+        let empty: Vec<Coord> = simplify_chunked(vec![], &epsilon, 10).collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn simplify_line_string_idx_matches_simplify_idx() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        for epsilon in [0.0, 0.001, 0.01, 1.0] {
+            assert_eq!(
+                simplify_line_string_idx(&line_string, &epsilon),
+                line_string.simplify_idx(&epsilon)
+            );
+        }
+        assert!(simplify_line_string_idx(&LineString::<f64>::new(vec![]), &1.0).is_empty());
+    }
+
+    #[test]
+    fn simplify_to_n_points_matches_simplify() {
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let len = line_string.0.len();
+        for n in [0, 2, 3, 10, 100, len - 1, len, len + 1] {
+            let simplified = line_string.simplify_to_n_points(n);
+            assert!(simplified.0.len() <= n.max(2));
+            assert_eq!(simplified.0.first(), line_string.0.first());
+            assert_eq!(simplified.0.last(), line_string.0.last());
+        }
+        assert_eq!(line_string.simplify_to_n_points(len), line_string);
+        // Any tolerance is equivalent to the number of coordinates it retains
+        for epsilon in [0.001, 0.01, 0.1] {
+            let expected = line_string.simplify(&epsilon);
+            assert_eq!(line_string.simplify_to_n_points(expected.0.len()), expected);
+        }
+    }
 }
@@ -1,10 +1,11 @@
 use crate::prelude::*;
+use crate::utils::lex_cmp;
 use crate::{
-    Coord, CoordFloat, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Point, Polygon,
-    Triangle,
+    Coord, CoordFloat, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPolygon, Point, Polygon, Triangle,
 };
 use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use std::collections::{BTreeMap, BinaryHeap};
 
 use rstar::primitives::CachedEnvelope;
 use rstar::{RTree, RTreeNum};
@@ -73,6 +74,14 @@ where
 // based on Huon Wilson's original implementation:
 // https://github.com/huonw/isrustfastyet/blob/25e7a68ff26673a8556b170d3c9af52e1c818288/mem/line_simplify.rs
 fn visvalingam_indices<T>(orig: &LineString<T>, epsilon: &T) -> Vec<usize>
+where
+    T: CoordFloat,
+{
+    visvalingam_indices_until(orig, epsilon, 0)
+}
+
+// As `visvalingam_indices`, but also stops removing points once only `min_len` remain
+fn visvalingam_indices_until<T>(orig: &LineString<T>, epsilon: &T, min_len: usize) -> Vec<usize>
 where
     T: CoordFloat,
 {
@@ -116,8 +125,9 @@ where
         .collect::<BinaryHeap<VScore<T>>>();
     // While there are still points for which the associated triangle
     // has an area below the epsilon
+    let mut remaining = max;
     while let Some(smallest) = pq.pop() {
-        if smallest.area > *epsilon {
+        if smallest.area > *epsilon || remaining <= min_len {
             // no need to keep trying: the min-heap ensures that we process triangles in order
             // so if we see one that exceeds the tolerance we're done: everything else is too big
             break;
@@ -129,6 +139,7 @@ where
         if left != smallest.left as i32 || right != smallest.right as i32 {
             continue;
         }
+        remaining -= 1;
         // We've got a valid triangle, and its area is smaller than epsilon, so
         // remove it from the simulated "linked list"
         let (ll, _) = adjacent[left as usize];
@@ -273,6 +284,168 @@ where
     rings
 }
 
+// Simplify the rings of several polygons jointly, so that boundaries shared between
+// them are simplified identically and the polygons stay snapped together.
+//
+// Rings are split into arcs at junctions: vertices shared between rings whose neighbouring
+// vertices differ from one occurrence to the next. Between two junctions, all rings which
+// share a boundary pass through exactly the same vertices, so each distinct arc is simplified
+// exactly once (with its end points fixed), and the result is reused by every ring it
+// belongs to. As in `vwp_wrapper`, a single R* tree holds the segments of every arc, so arcs
+// can't be simplified across each other.
+//
+// Returns the simplified rings in the same order as `rings`.
+fn vwp_shared_rings<T>(rings: &[&LineString<T>], epsilon: &T) -> Vec<LineString<T>>
+where
+    T: GeoFloat + RTreeNum,
+{
+    // Rings too small to be simplified are passed through untouched
+    let is_simplifiable = |ring: &LineString<T>| ring.0.len() >= 4 && ring.is_closed();
+
+    // Give every distinct coordinate an id, in lexicographic order.
+    // Closed rings are considered without their repeated closing coordinate.
+    let mut occurrences: Vec<(Coord<T>, usize, usize)> = rings
+        .iter()
+        .enumerate()
+        .filter(|(_, ring)| is_simplifiable(ring))
+        .flat_map(|(ring_idx, ring)| {
+            ring.0[..ring.0.len() - 1]
+                .iter()
+                .enumerate()
+                .map(move |(pos, coord)| (*coord, ring_idx, pos))
+        })
+        .collect();
+    occurrences.sort_by(|a, b| lex_cmp(&a.0, &b.0));
+
+    // Rings which aren't simplifiable are left without ids, so that they're skipped below
+    let mut ids: Vec<Vec<usize>> = rings
+        .iter()
+        .map(|ring| {
+            if is_simplifiable(ring) {
+                vec![0; ring.0.len() - 1]
+            } else {
+                vec![]
+            }
+        })
+        .collect();
+    let mut occurrence_counts: Vec<usize> = vec![];
+    for (i, (coord, ring_idx, pos)) in occurrences.iter().enumerate() {
+        if i == 0 || occurrences[i - 1].0 != *coord {
+            occurrence_counts.push(0);
+        }
+        ids[*ring_idx][*pos] = occurrence_counts.len() - 1;
+        *occurrence_counts.last_mut().unwrap() += 1;
+    }
+
+    // A shared vertex is a junction unless all of its occurrences have the same neighbours
+    let mut neighbours: Vec<Option<(usize, usize)>> = vec![None; occurrence_counts.len()];
+    let mut is_junction: Vec<bool> = vec![false; occurrence_counts.len()];
+    for ring_ids in ids.iter().filter(|ring_ids| !ring_ids.is_empty()) {
+        let n = ring_ids.len();
+        for (pos, &id) in ring_ids.iter().enumerate() {
+            let prev = ring_ids[(pos + n - 1) % n];
+            let next = ring_ids[(pos + 1) % n];
+            let pair = (prev.min(next), prev.max(next));
+            match neighbours[id] {
+                None => neighbours[id] = Some(pair),
+                Some(existing) if existing != pair => is_junction[id] = true,
+                Some(_) => {}
+            }
+        }
+    }
+
+    // Split every ring into arcs, identifying arcs which are shared (possibly reversed) between rings
+    let mut arc_keys: BTreeMap<Vec<usize>, usize> = BTreeMap::new();
+    let mut arcs: Vec<LineString<T>> = vec![];
+    let mut arc_min_points: Vec<usize> = vec![];
+    // for each ring: its arcs, and whether each one is traversed in reverse
+    let mut ring_arcs: Vec<Vec<(usize, bool)>> = vec![vec![]; rings.len()];
+    for (ring_idx, ring_ids) in ids.iter().enumerate() {
+        if ring_ids.is_empty() {
+            continue;
+        }
+        let n = ring_ids.len();
+        let mut cuts: Vec<usize> = (0..n).filter(|&pos| is_junction[ring_ids[pos]]).collect();
+        if cuts.is_empty() {
+            // Keep the ring's own start, unless the whole ring is shared with another ring,
+            // in which case every copy must start at the same vertex.
+            if occurrence_counts[ring_ids[0]] == 1 {
+                cuts.push(0);
+            } else {
+                cuts.push((0..n).min_by_key(|&pos| ring_ids[pos]).unwrap());
+            }
+        }
+        // A ring must retain at least 3 distinct vertices, which constrains how far the arcs
+        // making it up can be simplified
+        let min_points = match cuts.len() {
+            1 => 4,
+            2 => 3,
+            _ => 2,
+        };
+        for (i, &start) in cuts.iter().enumerate() {
+            let end = cuts[(i + 1) % cuts.len()];
+            let len = (end + n - start - 1) % n + 2;
+            let arc_ids: Vec<usize> = (0..len).map(|k| ring_ids[(start + k) % n]).collect();
+            let reversed_ids: Vec<usize> = arc_ids.iter().rev().copied().collect();
+            let (key, reversed) = if reversed_ids < arc_ids {
+                (reversed_ids, true)
+            } else {
+                (arc_ids, false)
+            };
+            let arc_idx = *arc_keys.entry(key).or_insert_with(|| {
+                let mut coords: Vec<Coord<T>> =
+                    (0..len).map(|k| rings[ring_idx][(start + k) % n]).collect();
+                if reversed {
+                    coords.reverse();
+                }
+                arcs.push(LineString::new(coords));
+                arc_min_points.push(2);
+                arcs.len() - 1
+            });
+            arc_min_points[arc_idx] = arc_min_points[arc_idx].max(min_points);
+            ring_arcs[ring_idx].push((arc_idx, reversed));
+        }
+    }
+
+    let mut tree: RTree<CachedEnvelope<_>> = RTree::bulk_load(
+        arcs.iter()
+            .flat_map(|arc| arc.lines())
+            .map(CachedEnvelope::new)
+            .collect::<Vec<_>>(),
+    );
+    let simplified_arcs: Vec<Vec<Coord<T>>> = arcs
+        .iter()
+        .zip(arc_min_points)
+        .map(|(arc, min_points)| match min_points {
+            2 => visvalingam_preserve::<T, 2, 4>(arc, epsilon, &mut tree),
+            3 => visvalingam_preserve::<T, 3, 5>(arc, epsilon, &mut tree),
+            _ => visvalingam_preserve::<T, 4, 5>(arc, epsilon, &mut tree),
+        })
+        .collect();
+
+    rings
+        .iter()
+        .zip(ring_arcs)
+        .map(|(ring, ring_arcs)| {
+            if ring_arcs.is_empty() {
+                return (*ring).clone();
+            }
+            let mut coords: Vec<Coord<T>> = vec![];
+            for (arc_idx, reversed) in ring_arcs {
+                let arc = &simplified_arcs[arc_idx];
+                // consecutive arcs share their junction
+                let skip = usize::from(!coords.is_empty());
+                if reversed {
+                    coords.extend(arc.iter().rev().skip(skip));
+                } else {
+                    coords.extend(arc.iter().skip(skip));
+                }
+            }
+            LineString::new(coords)
+        })
+        .collect()
+}
+
 /// Visvalingam-Whyatt with self-intersection detection to preserve topologies
 /// this is a port of the technique at https://www.jasondavies.com/simplify/
 //
@@ -519,6 +692,47 @@ pub trait SimplifyVwIdx<T, Epsilon = T> {
         T: CoordFloat;
 }
 
+/// Simplifies a geometry to a target number of coordinates, rather than to a tolerance.
+///
+/// Coordinates are removed in the same order as by [`SimplifyVw`] (Visvalingam-Whyatt), which
+/// removes the coordinate forming the smallest triangle with its neighbours first, until only
+/// `n` remain.
+pub trait SimplifyVwToNPoints<T> {
+    /// Returns the simplified representation of a geometry with `n` coordinates, using the
+    /// [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm
+    ///
+    /// The endpoints are always kept, so `n` less than 2 is treated as 2. A geometry with `n`
+    /// or fewer coordinates is returned unaltered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, SimplifyVw, SimplifyVwToNPoints};
+    ///
+    /// let line_string = line_string![
+    ///     (x: 5.0, y: 2.0),
+    ///     (x: 3.0, y: 8.0),
+    ///     (x: 6.0, y: 20.0),
+    ///     (x: 7.0, y: 25.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// let simplified = line_string.simplify_vw_to_n_points(3);
+    ///
+    /// let expected = line_string![
+    ///     (x: 5.0, y: 2.0),
+    ///     (x: 7.0, y: 25.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// assert_eq!(expected, simplified);
+    /// assert_eq!(line_string.simplify_vw(&30.0), simplified);
+    /// ```
+    fn simplify_vw_to_n_points(&self, n: usize) -> Self
+    where
+        T: CoordFloat;
+}
+
 /// Simplifies a geometry, attempting to preserve its topology by removing self-intersections
 ///
 /// A larger `epsilon` means being more aggressive about removing points with less concern for
@@ -631,11 +845,25 @@ impl<T> SimplifyVwPreserve<T> for MultiPolygon<T>
 where
     T: GeoFloat + RTreeNum,
 {
+    /// Simplifies all member polygons jointly: boundaries shared between members (and between
+    /// rings of the same member) are simplified identically, so adjacent members stay snapped
+    /// together rather than drifting apart and creating gaps or overlaps.
     fn simplify_vw_preserve(&self, epsilon: &T) -> MultiPolygon<T> {
+        let rings: Vec<&LineString<T>> = self
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .collect();
+        let mut simplified = vwp_shared_rings(&rings, epsilon).into_iter();
         MultiPolygon::new(
-            self.0
-                .iter()
-                .map(|p| p.simplify_vw_preserve(epsilon))
+            self.iter()
+                .map(|polygon| {
+                    let exterior = simplified.next().unwrap();
+                    let interiors = simplified
+                        .by_ref()
+                        .take(polygon.interiors().len())
+                        .collect();
+                    Polygon::new(exterior, interiors)
+                })
                 .collect(),
         )
     }
@@ -659,6 +887,18 @@ where
     }
 }
 
+impl<T> SimplifyVwToNPoints<T> for LineString<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw_to_n_points(&self, n: usize) -> LineString<T> {
+        visvalingam_indices_until(self, &T::max_value(), n)
+            .into_iter()
+            .map(|index| self.0[index])
+            .collect()
+    }
+}
+
 impl<T> SimplifyVw<T> for MultiLineString<T>
 where
     T: CoordFloat,
@@ -692,11 +932,45 @@ where
     }
 }
 
+impl<T> SimplifyVw<T> for GeometryCollection<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw(&self, epsilon: &T) -> GeometryCollection<T> {
+        GeometryCollection::new_from(self.iter().map(|g| g.simplify_vw(epsilon)).collect())
+    }
+}
+
+impl<T> SimplifyVw<T> for Geometry<T>
+where
+    T: CoordFloat,
+{
+    fn simplify_vw(&self, epsilon: &T) -> Geometry<T> {
+        match self {
+            Geometry::LineString(ls) => Geometry::LineString(ls.simplify_vw(epsilon)),
+            Geometry::Polygon(p) => Geometry::Polygon(p.simplify_vw(epsilon)),
+            Geometry::MultiLineString(mls) => Geometry::MultiLineString(mls.simplify_vw(epsilon)),
+            Geometry::MultiPolygon(mp) => Geometry::MultiPolygon(mp.simplify_vw(epsilon)),
+            Geometry::GeometryCollection(gc) => {
+                Geometry::GeometryCollection(gc.simplify_vw(epsilon))
+            }
+            Geometry::Point(_)
+            | Geometry::Line(_)
+            | Geometry::MultiPoint(_)
+            | Geometry::Rect(_)
+            | Geometry::Triangle(_) => self.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{visvalingam, vwp_wrapper, SimplifyVw, SimplifyVwPreserve};
+    use crate::utils::lex_cmp;
+    use crate::Area;
     use crate::{
-        line_string, polygon, Coord, LineString, MultiLineString, MultiPolygon, Point, Polygon,
+        line_string, polygon, Coord, Geometry, GeometryCollection, LineString, MultiLineString,
+        MultiPolygon, Point, Polygon,
     };
 
     // See https://github.com/georust/geo/issues/1049
@@ -943,4 +1217,168 @@ mod test {
             epsilon = 1e-6
         );
     }
+
+    #[test]
+    fn geometry_collection() {
+        let line_string = LineString::from(vec![(0., 0.), (5., 0.1), (10., 0.)]);
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (0., 10.), (5., 11.), (10., 10.), (10., 0.)]),
+            vec![],
+        );
+        let point = Point::new(1., 1.);
+        let collection = GeometryCollection::new_from(vec![
+            line_string.clone().into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![polygon
+                .clone()
+                .into()])),
+            point.into(),
+        ]);
+
+        let simplified = Geometry::GeometryCollection(collection).simplify_vw(&10.);
+        assert_eq!(
+            simplified,
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                line_string.simplify_vw(&10.).into(),
+                Geometry::GeometryCollection(GeometryCollection::new_from(vec![polygon
+                    .simplify_vw(&10.)
+                    .into()])),
+                point.into(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn multipolygon_preserve_shared_boundary() {
+        // Two members sharing a wiggly boundary, wound in opposite directions along it
+        let left = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10.5, y: 2.),
+            (x: 10., y: 4.),
+            (x: 9.5, y: 6.),
+            (x: 10., y: 8.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let right = polygon![
+            (x: 10., y: 0.),
+            (x: 20., y: 0.),
+            (x: 20., y: 10.),
+            (x: 10., y: 10.),
+            (x: 10., y: 8.),
+            (x: 9.5, y: 6.),
+            (x: 10., y: 4.),
+            (x: 10.5, y: 2.),
+            (x: 10., y: 0.),
+        ];
+        let mpoly = MultiPolygon::new(vec![left, right]);
+
+        let simplified = mpoly.simplify_vw_preserve(&2.);
+
+        let shared = |polygon: &Polygon<f64>| {
+            let mut coords: Vec<_> = polygon
+                .exterior()
+                .coords()
+                .filter(|c| c.x > 9. && c.x < 11.)
+                .map(|c| (c.x, c.y))
+                .collect();
+            coords.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            coords.dedup();
+            coords
+        };
+        let left_boundary = shared(&simplified.0[0]);
+        assert_eq!(left_boundary, shared(&simplified.0[1]));
+        // the boundary has been simplified, but its junctions are retained
+        assert!(left_boundary.len() < 6);
+        assert!(left_boundary.contains(&(10., 0.)));
+        assert!(left_boundary.contains(&(10., 10.)));
+        // no gaps or overlaps have been introduced
+        assert_relative_eq!(mpoly.unsigned_area(), simplified.unsigned_area());
+    }
+
+    #[test]
+    fn multipolygon_preserve_degenerate_member() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.1),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let degenerate = polygon![(x: 100., y: 100.), (x: 101., y: 100.), (x: 100., y: 100.)];
+        let mpoly = MultiPolygon::new(vec![square.clone(), degenerate.clone()]);
+
+        let simplified = mpoly.simplify_vw_preserve(&1.);
+
+        assert_eq!(simplified.0[0], square.simplify_vw_preserve(&1.));
+        assert_eq!(simplified.0[1], degenerate);
+    }
+
+    #[test]
+    fn multipolygon_preserve_enclave() {
+        // A member filling a hole in another member, starting from a different vertex
+        let island = polygon![
+            (x: 2., y: 2.),
+            (x: 5., y: 1.5),
+            (x: 8., y: 2.),
+            (x: 8., y: 8.),
+            (x: 5., y: 8.5),
+            (x: 2., y: 8.),
+            (x: 2., y: 2.),
+        ];
+        let hole = line_string![
+            (x: 8., y: 8.),
+            (x: 8., y: 2.),
+            (x: 5., y: 1.5),
+            (x: 2., y: 2.),
+            (x: 2., y: 8.),
+            (x: 5., y: 8.5),
+            (x: 8., y: 8.),
+        ];
+        let surrounding = Polygon::new(
+            line_string![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            vec![hole],
+        );
+        let mpoly = MultiPolygon::new(vec![surrounding, island]);
+
+        let simplified = mpoly.simplify_vw_preserve(&2.);
+
+        let mut hole: Vec<_> = simplified.0[0].interiors()[0].coords().copied().collect();
+        let mut island: Vec<_> = simplified.0[1].exterior().coords().copied().collect();
+        assert!(island.len() < 7);
+        hole.sort_by(lex_cmp);
+        island.sort_by(lex_cmp);
+        assert_eq!(hole, island);
+    }
+
+    #[test]
+    fn simplify_vw_to_n_points() {
+        use super::SimplifyVwToNPoints;
+
+        let line_string: LineString = geo_test_fixtures::louisiana();
+        let len = line_string.0.len();
+        for n in [0, 2, 3, 10, 100, len - 1] {
+            let simplified = line_string.simplify_vw_to_n_points(n);
+            assert_eq!(simplified.0.len(), n.max(2));
+            assert_eq!(simplified.0.first(), line_string.0.first());
+            assert_eq!(simplified.0.last(), line_string.0.last());
+        }
+        assert_eq!(line_string.simplify_vw_to_n_points(len + 1), line_string);
+        // Any tolerance is equivalent to the number of coordinates it retains
+        for epsilon in [0.0001, 0.001, 0.01] {
+            let expected = line_string.simplify_vw(&epsilon);
+            assert_eq!(
+                line_string.simplify_vw_to_n_points(expected.0.len()),
+                expected
+            );
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{AffineOps, AffineTransform, BoundingRect, Coord, CoordFloat, CoordNum, Rect};
+use crate::{AffineOps, AffineTransform, BoundingRect, Coord, CoordFloat, CoordNum, Line, Rect};
 
 /// An affine transformation which skews a geometry, sheared by angles along x and y dimensions.
 ///
@@ -109,6 +109,43 @@ pub trait Skew<T: CoordNum> {
 
     /// Mutable version of [`skew_around_point`](Self::skew_around_point).
     fn skew_around_point_mut(&mut self, degrees_x: T, degrees_y: T, origin: impl Into<Coord<T>>);
+
+    /// An affine transformation which skews a geometry parallel to an arbitrary `axis`, sheared
+    /// by an angle of `degrees`, e.g. for axonometric views.
+    ///
+    /// Coordinates move parallel to the line through `axis`, by the tangent of the angle times
+    /// their distance from it, in the direction of `axis` for those to its left. See
+    /// [`AffineTransform::skew_along`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Skew;
+    /// use geo::{Line, Polygon, polygon};
+    ///
+    /// let square: Polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 10., y: 0.),
+    ///     (x: 10., y: 10.),
+    ///     (x: 0., y: 10.)
+    /// ];
+    ///
+    /// // shear the square downwards along its left edge, into a parallelogram
+    /// let skewed = square.skew_along(45., Line::new((0., 0.), (0., 10.)));
+    ///
+    /// let expected_output: Polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 10., y: -10.),
+    ///     (x: 10., y: 0.),
+    ///     (x: 0., y: 10.)
+    /// ];
+    /// approx::assert_relative_eq!(skewed, expected_output, epsilon = 1e-9);
+    /// ```
+    #[must_use]
+    fn skew_along(&self, degrees: T, axis: Line<T>) -> Self;
+
+    /// Mutable version of [`skew_along`](Self::skew_along).
+    fn skew_along_mut(&mut self, degrees: T, axis: Line<T>);
 }
 
 impl<T, IR, G> Skew<T> for G
@@ -154,12 +191,20 @@ where
         let transform = AffineTransform::skew(xs, ys, origin);
         self.affine_transform_mut(&transform);
     }
+
+    fn skew_along(&self, degrees: T, axis: Line<T>) -> Self {
+        self.affine_transform(&AffineTransform::skew_along(degrees, axis))
+    }
+
+    fn skew_along_mut(&mut self, degrees: T, axis: Line<T>) {
+        self.affine_transform_mut(&AffineTransform::skew_along(degrees, axis));
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{line_string, BoundingRect, Centroid, LineString};
+    use crate::{line_string, polygon, BoundingRect, Centroid, LineString};
 
     #[test]
     fn skew_linestring() {
@@ -177,4 +222,33 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn skew_along_axis() {
+        use crate::{coord, point, AffineTransform, Rotate};
+        let poly = polygon![(x: 1.0, y: 2.0), (x: 4.0, y: -1.0), (x: 6.0, y: 5.0)];
+
+        // along the x axis, the same as skewing along x
+        let axis = Line::new((0.0, 3.0), (2.0, 3.0));
+        assert_relative_eq!(
+            AffineTransform::skew_along(30.0, axis),
+            AffineTransform::skew(30.0, 0.0, (0.0, 3.0))
+        );
+
+        // along a line at 30°: rotating it onto the x axis, skewing along x, and rotating back
+        let start = point!(x: 2.0, y: 1.0);
+        let axis = Line::new(start.0, coord! { x: 2.0 + 3f64.sqrt(), y: 2.0 });
+        let expected = poly
+            .rotate_around_point(-30.0, start)
+            .skew_around_point(20.0, 0.0, start)
+            .rotate_around_point(30.0, start);
+        assert_relative_eq!(poly.skew_along(20.0, axis), expected, epsilon = 1e-9);
+
+        let mut mutated = poly.clone();
+        mutated.skew_along_mut(20.0, axis);
+        assert_eq!(mutated, poly.skew_along(20.0, axis));
+
+        // a line without length has no direction
+        assert_eq!(poly.skew_along(20.0, Line::new(start.0, start.0)), poly);
+    }
 }
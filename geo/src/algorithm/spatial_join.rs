@@ -0,0 +1,380 @@
+use crate::bool_ops::{BoolOpsNum, BooleanOps};
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::geometry::*;
+use crate::{Area, GeoFloat};
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, AABB};
+
+/// For each point, the index of the first polygon which contains it, or `None` if no polygon
+/// contains it.
+///
+/// This is the usual first step of a zonal aggregation: assigning points (e.g. observations or
+/// addresses) to the zones they fall in. As with [`Contains`](crate::Contains), a point on the
+/// boundary of a polygon is not contained by it. Where polygons overlap, a point is assigned to
+/// the polygon with the lowest index.
+///
+/// The bounding rectangles of the polygons are indexed in an R-tree, so that each point is only
+/// tested against the polygons whose bounding rectangles contain it. The candidate points of
+/// each polygon are then tested together with
+/// [`coordinate_positions`](CoordinatePosition::coordinate_positions), which indexes the rings
+/// of each polygon only once. This is much faster than testing every point against every
+/// polygon.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon};
+/// use geo::spatial_join::points_in_polygons;
+///
+/// let left = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let right = polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)];
+///
+/// let points = [
+///     point!(x: 1., y: 1.),
+///     point!(x: 3., y: 1.),
+///     // on the boundary of both polygons
+///     point!(x: 2., y: 1.),
+///     point!(x: 5., y: 1.),
+/// ];
+///
+/// assert_eq!(
+///     points_in_polygons(&points, &[left, right]),
+///     vec![Some(0), Some(1), None, None]
+/// );
+/// ```
+pub fn points_in_polygons<T: GeoFloat>(
+    points: &[Point<T>],
+    polygons: &[Polygon<T>],
+) -> Vec<Option<usize>> {
+    let tree = envelope_tree(polygons.iter());
+
+    // The indices of the points which may be contained by each polygon
+    let mut candidates = vec![Vec::new(); polygons.len()];
+    for (point_idx, point) in points.iter().enumerate() {
+        for polygon in tree.locate_all_at_point(point) {
+            candidates[polygon.data].push(point_idx);
+        }
+    }
+
+    let mut containing_polygons = vec![None; points.len()];
+    let mut coords = Vec::new();
+    for (polygon_idx, point_indices) in candidates.into_iter().enumerate() {
+        // Points already assigned to a polygon with a lower index needn't be tested again
+        let point_indices: Vec<_> = point_indices
+            .into_iter()
+            .filter(|&point_idx| containing_polygons[point_idx].is_none())
+            .collect();
+        if point_indices.is_empty() {
+            continue;
+        }
+
+        coords.clear();
+        coords.extend(point_indices.iter().map(|&point_idx| points[point_idx].0));
+        let positions = polygons[polygon_idx].coordinate_positions(&coords);
+        for (point_idx, position) in point_indices.into_iter().zip(positions) {
+            if position == CoordPos::Inside {
+                containing_polygons[point_idx] = Some(polygon_idx);
+            }
+        }
+    }
+    containing_polygons
+}
+
+/// Transfers values, such as population counts, from one set of zones to another, in
+/// proportion to the areas of their intersections.
+///
+/// This is areal interpolation, e.g. to estimate the population of school districts from that
+/// of census tracts whose boundaries don't match. Each source value is split between the target
+/// polygons in proportion to the area of the source polygon which each of them covers, and the
+/// value of each target polygon is the sum of its shares. Values should be extensive, i.e.
+/// counts or totals rather than densities or rates. The share of a source value which falls
+/// outside every target polygon is lost, and the target polygons shouldn't overlap, or their
+/// shares are counted more than once.
+///
+/// As in [`points_in_polygons`], the source polygons' bounding rectangles are indexed in an
+/// R-tree, so that only the source polygons which may intersect a target polygon are intersected
+/// with it.
+///
+/// See [`areal_interpolate_weighted`] to take into account where within the source zones the
+/// values are, e.g. that nobody lives in a lake.
+///
+/// # Panics
+///
+/// Panics if `source_polygons` and `source_values` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use geo::spatial_join::areal_interpolate;
+/// use geo::{wkt, Polygon};
+///
+/// let tracts: Vec<Polygon> = vec![
+///     wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.))),
+///     wkt!(POLYGON((2. 0.,4. 0.,4. 2.,2. 2.,2. 0.))),
+/// ];
+/// let population = [100., 300.];
+/// // a district covering half of each tract
+/// let districts: Vec<Polygon> = vec![wkt!(POLYGON((1. 0.,3. 0.,3. 2.,1. 2.,1. 0.)))];
+///
+/// let estimate = areal_interpolate(&tracts, &population, &districts);
+/// assert_eq!(estimate, vec![200.]);
+/// ```
+pub fn areal_interpolate<T: GeoFloat + BoolOpsNum>(
+    source_polygons: &[Polygon<T>],
+    source_values: &[T],
+    target_polygons: &[Polygon<T>],
+) -> Vec<T> {
+    interpolate(
+        source_polygons,
+        source_values,
+        target_polygons,
+        |region: &MultiPolygon<T>| region.unsigned_area(),
+    )
+}
+
+/// Transfers values from one set of zones to another, in proportion to the weighted areas of
+/// their intersections.
+///
+/// This is dasymetric areal interpolation. Like [`areal_interpolate`], but rather than being
+/// spread evenly over each source polygon, values are spread over `ancillary_zones` in proportion
+/// to their weights, e.g. the expected population density of each land use. Areas outside the
+/// ancillary zones have no weight, so binary dasymetric interpolation, e.g. spreading population
+/// over residential land only, uses zones with a weight of one. The ancillary zones shouldn't
+/// overlap.
+///
+/// A source value is lost if its polygon has no weight, i.e. doesn't intersect any ancillary zone
+/// with a positive weight.
+///
+/// # Panics
+///
+/// Panics if `source_polygons` and `source_values` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use geo::spatial_join::areal_interpolate_weighted;
+/// use geo::{wkt, Polygon};
+///
+/// let tracts: Vec<Polygon> = vec![wkt!(POLYGON((0. 0.,4. 0.,4. 2.,0. 2.,0. 0.)))];
+/// let population = [100.];
+/// let districts: Vec<Polygon> = vec![
+///     wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.))),
+///     wkt!(POLYGON((2. 0.,4. 0.,4. 2.,2. 2.,2. 0.))),
+/// ];
+/// // everyone lives in the housing, in the west of the second district
+/// let housing = [(wkt!(POLYGON((2. 0.,3. 0.,3. 2.,2. 2.,2. 0.))), 1.)];
+///
+/// let estimate = areal_interpolate_weighted(&tracts, &population, &districts, &housing);
+/// assert_eq!(estimate, vec![0., 100.]);
+/// ```
+pub fn areal_interpolate_weighted<T: GeoFloat + BoolOpsNum>(
+    source_polygons: &[Polygon<T>],
+    source_values: &[T],
+    target_polygons: &[Polygon<T>],
+    ancillary_zones: &[(Polygon<T>, T)],
+) -> Vec<T> {
+    let ancillary_tree = envelope_tree(ancillary_zones.iter().map(|(polygon, _)| polygon));
+    let weighted_area = |region: &MultiPolygon<T>| {
+        let Some(envelope) = multi_polygon_envelope(region) else {
+            return T::zero();
+        };
+        ancillary_tree
+            .locate_in_envelope_intersecting(&envelope)
+            .fold(T::zero(), |total, zone| {
+                let (polygon, weight) = &ancillary_zones[zone.data];
+                total + *weight * region.intersection(polygon).unsigned_area()
+            })
+    };
+    interpolate(
+        source_polygons,
+        source_values,
+        target_polygons,
+        weighted_area,
+    )
+}
+
+/// Areal interpolation, where `measure` is the weight of a region.
+fn interpolate<T: GeoFloat + BoolOpsNum>(
+    source_polygons: &[Polygon<T>],
+    source_values: &[T],
+    target_polygons: &[Polygon<T>],
+    measure: impl Fn(&MultiPolygon<T>) -> T,
+) -> Vec<T> {
+    assert_eq!(
+        source_polygons.len(),
+        source_values.len(),
+        "a value is needed for each source polygon"
+    );
+    let source_tree = envelope_tree(source_polygons.iter());
+    // The measures of the source polygons, computed when first needed
+    let mut source_measures: Vec<Option<T>> = vec![None; source_polygons.len()];
+
+    target_polygons
+        .iter()
+        .map(|target| {
+            if target.exterior().0.is_empty() {
+                return T::zero();
+            }
+            let envelope: AABB<Point<T>> = rstar::RTreeObject::envelope(target);
+            source_tree.locate_in_envelope_intersecting(&envelope).fold(
+                T::zero(),
+                |total, source| {
+                    let idx = source.data;
+                    let overlap = measure(&source_polygons[idx].intersection(target));
+                    if overlap <= T::zero() {
+                        return total;
+                    }
+                    let source_measure = *source_measures[idx].get_or_insert_with(|| {
+                        measure(&MultiPolygon::new(vec![source_polygons[idx].clone()]))
+                    });
+                    total + source_values[idx] * overlap / source_measure
+                },
+            )
+        })
+        .collect()
+}
+
+/// An R-tree of the bounding rectangles of the non-empty polygons, with their indices.
+fn envelope_tree<'a, T: GeoFloat + 'a>(
+    polygons: impl Iterator<Item = &'a Polygon<T>>,
+) -> RTree<GeomWithData<Rectangle<Point<T>>, usize>> {
+    RTree::bulk_load(
+        polygons
+            .enumerate()
+            .filter(|(_, polygon)| !polygon.exterior().0.is_empty())
+            .map(|(idx, polygon)| {
+                let envelope: AABB<Point<T>> = rstar::RTreeObject::envelope(polygon);
+                GeomWithData::new(Rectangle::from_aabb(envelope), idx)
+            })
+            .collect(),
+    )
+}
+
+fn multi_polygon_envelope<T: GeoFloat>(multi_polygon: &MultiPolygon<T>) -> Option<AABB<Point<T>>> {
+    use crate::BoundingRect;
+    multi_polygon
+        .bounding_rect()
+        .map(|rect| AABB::from_corners(rect.min().into(), rect.max().into()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Contains};
+
+    #[test]
+    fn matches_contains() {
+        let polygons = vec![
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            // overlaps the first polygon
+            polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)],
+            // inside the hole of the last polygon
+            polygon![(x: 22., y: 2.), (x: 24., y: 2.), (x: 24., y: 4.), (x: 22., y: 4.)],
+            polygon!(
+                exterior: [(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 20., y: 10.)],
+                interiors: [[(x: 21., y: 1.), (x: 25., y: 1.), (x: 25., y: 5.), (x: 21., y: 5.)]],
+            ),
+        ];
+
+        let mut points = vec![];
+        for x in -2..=64 {
+            for y in -2..=32 {
+                points.push(point!(x: x as f64 / 2., y: y as f64 / 2.));
+            }
+        }
+
+        let expected: Vec<_> = points
+            .iter()
+            .map(|point| polygons.iter().position(|polygon| polygon.contains(point)))
+            .collect();
+        assert_eq!(points_in_polygons(&points, &polygons), expected);
+
+        assert_eq!(
+            points_in_polygons(&[point!(x: 7., y: 7.)], &polygons),
+            vec![Some(0)]
+        );
+        assert_eq!(
+            points_in_polygons(&[point!(x: 12., y: 7.)], &polygons),
+            vec![Some(1)]
+        );
+        assert_eq!(
+            points_in_polygons(&[point!(x: 23., y: 3.)], &polygons),
+            vec![Some(2)]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(
+            points_in_polygons(&[point!(x: 0., y: 0.)], &[empty]),
+            vec![None]
+        );
+        assert_eq!(points_in_polygons::<f64>(&[], &[]), vec![]);
+        assert_eq!(points_in_polygons(&[point!(x: 0., y: 0.)], &[]), vec![None]);
+    }
+
+    #[test]
+    fn areal_interpolation() {
+        let square = |x: f64, y: f64, size: f64| polygon![(x: x, y: y), (x: x + size, y: y), (x: x + size, y: y + size), (x: x, y: y + size)];
+        let sources = vec![square(0., 0., 2.), square(2., 0., 2.), square(10., 10., 1.)];
+        let values = [100., 300., 7.];
+        // the first two targets straddle the first two sources, covering half and a quarter of
+        // each, and the second is partly outside any source
+        let targets = vec![
+            square(1., 0., 2.),
+            square(1., -1., 2.),
+            square(10., 10., 5.),
+            square(50., 50., 1.),
+        ];
+        let estimate = areal_interpolate(&sources, &values, &targets);
+        approx::assert_relative_eq!(estimate[0], 50. + 150.);
+        approx::assert_relative_eq!(estimate[1], 25. + 75.);
+        approx::assert_relative_eq!(estimate[2], 7.);
+        assert_eq!(estimate[3], 0.);
+
+        // the whole of the sources' values is kept by a partition of them
+        let halves = vec![square(-1., -1., 3.), square(2., -1., 12.)];
+        let total: f64 = areal_interpolate(&sources, &values, &halves).iter().sum();
+        approx::assert_relative_eq!(total, 407.);
+    }
+
+    #[test]
+    fn weighted_areal_interpolation() {
+        let sources =
+            vec![polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)]];
+        let targets = vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 4.), (x: 0., y: 4.)],
+            polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 2., y: 4.)],
+        ];
+        // dense housing in the west, sparse in the east, and none in the middle
+        let ancillary = vec![
+            (
+                polygon![(x: -1., y: 0.), (x: 1., y: 0.), (x: 1., y: 4.), (x: -1., y: 4.)],
+                3.,
+            ),
+            (
+                polygon![(x: 3., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 3., y: 4.)],
+                1.,
+            ),
+        ];
+        let estimate = areal_interpolate_weighted(&sources, &[80.], &targets, &ancillary);
+        approx::assert_relative_eq!(estimate[0], 60.);
+        approx::assert_relative_eq!(estimate[1], 20.);
+
+        // binary weights spread values evenly over the zones
+        let binary: Vec<_> = ancillary
+            .iter()
+            .map(|(polygon, _)| (polygon.clone(), 1.))
+            .collect();
+        let estimate = areal_interpolate_weighted(&sources, &[80.], &targets, &binary);
+        approx::assert_relative_eq!(estimate[0], 40.);
+        approx::assert_relative_eq!(estimate[1], 40.);
+
+        // a source without weight is lost
+        assert_eq!(
+            areal_interpolate_weighted(&sources, &[80.], &targets, &[]),
+            vec![0., 0.]
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use crate::bool_ops::BoolOpsNum;
+use crate::{
+    BooleanOps, BoundingRect, CoordFloat, LineString, MultiPolygon, Rect, EQUATORIAL_EARTH_RADIUS,
+};
+
+/// Subdivision stops at this depth even if a cell is still too distorted, which bounds the
+/// recursion for degenerate inputs.
+const MAX_DEPTH: usize = 32;
+
+/// A cell produced by [`SphericalCells`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SphericalCell<T: CoordFloat> {
+    /// The lon/lat bounds of the cell.
+    pub bounds: Rect<T>,
+    /// The part of the input geometry inside `bounds`.
+    pub geometry: MultiPolygon<T>,
+    /// The spherical area of `geometry`, in meters².
+    pub area: T,
+}
+
+/// Split a lon/lat geometry into cells small enough to be treated as planar, along with the
+/// spherical area of the geometry within each cell.
+///
+/// The bounding rectangle of the geometry is repeatedly split into quadrants until every cell
+/// is within `tolerance` of being a rectangle on the sphere. Within such a cell the
+/// lon/lat grid is nearly uniform in area, so a planar overlay of raster or point data against
+/// the cell's `geometry`, weighted by its `area`, approximates a geodesic zonal statistic
+/// without reprojecting the inputs.
+///
+/// The distortion of a cell is the difference between the lengths of its southern and
+/// northern edges, relative to its longest side. It shrinks in proportion to the cell size,
+/// so smaller tolerances produce more, smaller cells. Cells which don't overlap the geometry
+/// are omitted.
+///
+/// As in a planar overlay, the edges of the geometry are taken to be straight lines in
+/// lon/lat, and each cell's `area` is the exact area on the sphere of the region they
+/// enclose. The cell areas therefore sum to the area of the whole geometry, whatever the
+/// tolerance. For small geometries this closely matches
+/// [`ChamberlainDuquetteArea`](crate::ChamberlainDuquetteArea).
+///
+/// # Units
+///
+/// - `self`: a geometry where x/y are lon/lat degree coordinates
+/// - `tolerance`: the maximum distortion of a cell, as a fraction
+/// - `area`: meters²
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, SphericalCells};
+///
+/// let polygon = polygon![
+///     (x: 0.0, y: 0.0),
+///     (x: 40.0, y: 0.0),
+///     (x: 40.0, y: 60.0),
+///     (x: 0.0, y: 0.0),
+/// ];
+///
+/// let coarse = polygon.spherical_cells(1.0);
+/// assert_eq!(coarse.len(), 1);
+///
+/// let fine = polygon.spherical_cells(0.05);
+/// assert!(fine.len() > 1);
+///
+/// let total: f64 = fine.iter().map(|cell| cell.area).sum();
+/// approx::assert_relative_eq!(total, coarse[0].area, max_relative = 1e-9);
+/// ```
+pub trait SphericalCells<T: CoordFloat> {
+    fn spherical_cells(&self, tolerance: T) -> Vec<SphericalCell<T>>;
+}
+
+impl<T, G> SphericalCells<T> for G
+where
+    T: CoordFloat + BoolOpsNum,
+    G: BooleanOps<Scalar = T> + BoundingRect<T>,
+    G::Output: Into<Option<Rect<T>>>,
+{
+    fn spherical_cells(&self, tolerance: T) -> Vec<SphericalCell<T>> {
+        let mut cells = Vec::new();
+        let Some(bounds) = self.bounding_rect().into() else {
+            return cells;
+        };
+
+        let root = self.intersection(&bounds.to_polygon());
+        let mut stack = vec![(bounds, root, 0)];
+        while let Some((bounds, geometry, depth)) = stack.pop() {
+            if geometry.0.is_empty() {
+                continue;
+            }
+            if depth >= MAX_DEPTH || distortion(bounds) <= tolerance {
+                let area = spherical_area(&geometry);
+                cells.push(SphericalCell {
+                    bounds,
+                    geometry,
+                    area,
+                });
+                continue;
+            }
+            // Each child only needs to clip its parent's part of the geometry. Children are
+            // pushed in reverse so that cells are emitted west to east, south to north.
+            for half in bounds.split_x().into_iter().rev() {
+                for quadrant in half.split_y().into_iter().rev() {
+                    let clipped = geometry.intersection(&quadrant.to_polygon());
+                    stack.push((quadrant, clipped, depth + 1));
+                }
+            }
+        }
+        cells
+    }
+}
+
+/// The difference between the lengths of the southern and northern edges of a lon/lat cell,
+/// relative to its longest side.
+fn distortion<T: CoordFloat>(bounds: Rect<T>) -> T {
+    let south = bounds.min().y.to_radians();
+    let north = bounds.max().y.to_radians();
+    let width = (bounds.max().x - bounds.min().x).to_radians();
+    let south_edge = south.cos() * width;
+    let north_edge = north.cos() * width;
+    let longest = south_edge.max(north_edge).max(north - south);
+    if longest.is_zero() {
+        return T::zero();
+    }
+    (south_edge - north_edge).abs() / longest
+}
+
+/// The area on the sphere of a lon/lat geometry whose edges are straight lines in lon/lat.
+///
+/// Integrating `cos(lat)` over each ring by Green's theorem gives, for an edge along which
+/// latitude varies linearly with longitude, `Δlon · (cos(lat1) - cos(lat2)) / Δlat`.
+fn spherical_area<T: CoordFloat>(geometry: &MultiPolygon<T>) -> T {
+    let radius = T::from(EQUATORIAL_EARTH_RADIUS).unwrap();
+    let area = geometry.iter().fold(T::zero(), |area, polygon| {
+        polygon
+            .interiors()
+            .iter()
+            .fold(area + ring_area(polygon.exterior()), |area, interior| {
+                area - ring_area(interior)
+            })
+    });
+    area * radius * radius
+}
+
+fn ring_area<T: CoordFloat>(ring: &LineString<T>) -> T {
+    ring.lines()
+        .fold(T::zero(), |area, line| {
+            let lat1 = line.start.y.to_radians();
+            let lat2 = line.end.y.to_radians();
+            let dlon = (line.end.x - line.start.x).to_radians();
+            let dlat = lat2 - lat1;
+            let mean_sin = if dlat.abs() < T::epsilon() {
+                ((lat1 + lat2) / (T::one() + T::one())).sin()
+            } else {
+                (lat1.cos() - lat2.cos()) / dlat
+            };
+            area + dlon * mean_sin
+        })
+        .abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{polygon, wkt, Area, ChamberlainDuquetteArea, Intersects, Polygon};
+
+    fn triangle() -> Polygon {
+        polygon![
+            (x: -10.0, y: 20.0),
+            (x: 30.0, y: 20.0),
+            (x: 10.0, y: 75.0),
+            (x: -10.0, y: 20.0),
+        ]
+    }
+
+    #[test]
+    fn cells_meet_tolerance() {
+        for tolerance in [0.1, 0.01] {
+            let cells = triangle().spherical_cells(tolerance);
+            assert!(!cells.is_empty());
+            for cell in &cells {
+                assert!(distortion(cell.bounds) <= tolerance);
+            }
+        }
+        assert!(triangle().spherical_cells(0.01).len() > triangle().spherical_cells(0.1).len());
+    }
+
+    #[test]
+    fn cells_partition_geometry() {
+        let polygon = triangle();
+        let cells = polygon.spherical_cells(0.02);
+
+        let total: f64 = cells.iter().map(|cell| cell.area).sum();
+        let whole = spherical_area(&MultiPolygon::new(vec![polygon.clone()]));
+        approx::assert_relative_eq!(total, whole, max_relative = 1e-9);
+        let planar: f64 = cells.iter().map(|cell| cell.geometry.unsigned_area()).sum();
+        approx::assert_relative_eq!(planar, polygon.unsigned_area(), max_relative = 1e-9);
+
+        for cell in &cells {
+            assert!(cell.area > 0.0);
+            assert!(cell.geometry.intersects(&polygon));
+            let cell_bounds = cell.geometry.bounding_rect().unwrap();
+            assert!(cell_bounds.min().x >= cell.bounds.min().x);
+            assert!(cell_bounds.max().y <= cell.bounds.max().y);
+        }
+    }
+
+    #[test]
+    fn small_polygon_is_single_cell() {
+        let polygon = polygon![
+            (x: 0.0, y: 51.50),
+            (x: 0.01, y: 51.50),
+            (x: 0.01, y: 51.51),
+            (x: 0.0, y: 51.51),
+        ];
+        let cells = polygon.spherical_cells(0.01);
+        assert_eq!(cells.len(), 1);
+        assert_eq!(cells[0].bounds, polygon.bounding_rect().unwrap());
+        approx::assert_relative_eq!(
+            cells[0].area,
+            polygon.chamberlain_duquette_unsigned_area(),
+            max_relative = 1e-6
+        );
+    }
+
+    #[test]
+    fn polar_cells_terminate() {
+        let polygon = polygon![
+            (x: -180.0, y: 80.0),
+            (x: 180.0, y: 80.0),
+            (x: 180.0, y: 90.0),
+            (x: -180.0, y: 90.0),
+        ];
+        let cells = polygon.spherical_cells(0.05);
+        for cell in &cells {
+            assert!(distortion(cell.bounds) <= 0.05);
+        }
+        // The exact area of a band between two latitudes
+        let radius = EQUATORIAL_EARTH_RADIUS;
+        let expected =
+            2.0 * std::f64::consts::PI * radius * radius * (1.0 - 80f64.to_radians().sin());
+        let total: f64 = cells.iter().map(|cell| cell.area).sum();
+        approx::assert_relative_eq!(total, expected, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn holes() {
+        let polygon = polygon![
+            exterior: [
+                (x: 0.0, y: 0.0),
+                (x: 10.0, y: 0.0),
+                (x: 10.0, y: 10.0),
+                (x: 0.0, y: 10.0),
+            ],
+            interiors: [[
+                (x: 2.0, y: 2.0),
+                (x: 8.0, y: 2.0),
+                (x: 8.0, y: 8.0),
+                (x: 2.0, y: 8.0),
+            ]],
+        ];
+        let cells = polygon.spherical_cells(0.01);
+        let total: f64 = cells.iter().map(|cell| cell.area).sum();
+        approx::assert_relative_eq!(
+            total,
+            polygon.chamberlain_duquette_unsigned_area(),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let empty: MultiPolygon = wkt!(MULTIPOLYGON EMPTY);
+        assert!(empty.spherical_cells(0.1).is_empty());
+    }
+}
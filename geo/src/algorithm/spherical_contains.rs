@@ -0,0 +1,265 @@
+use crate::{Coord, CoordFloat, LineString, MultiPolygon, Point, Polygon};
+
+/// Checks if a lon/lat geometry contains a point, treating edges as [great circle] arcs on a
+/// sphere rather than straight lines on a plane.
+///
+/// [`Contains`](crate::Contains) is planar: it gives wrong answers for large polygons, and for
+/// polygons spanning the antimeridian or enclosing a pole. `SphericalContains` instead tests
+/// containment on the sphere.
+///
+/// Since any ring divides the sphere into two regions, each ring is taken to bound the
+/// *smaller* of the two, regardless of its winding order. As with `Contains`, points on the
+/// boundary are not contained.
+///
+/// # Units
+///
+/// - `self`, `rhs`: geometries where x/y are lon/lat degree coordinates
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, Contains, SphericalContains};
+///
+/// // A polygon spanning the antimeridian
+/// let pacific = polygon![
+///     (x: 170.0, y: -10.0),
+///     (x: -170.0, y: -10.0),
+///     (x: -170.0, y: 10.0),
+///     (x: 170.0, y: 10.0),
+/// ];
+/// let fiji = point!(x: 178.0, y: -1.0);
+/// assert!(pacific.spherical_contains(&fiji));
+/// assert!(!pacific.contains(&fiji));
+///
+/// // A polygon enclosing the north pole
+/// let arctic = polygon![
+///     (x: 0.0, y: 80.0),
+///     (x: 90.0, y: 80.0),
+///     (x: 180.0, y: 80.0),
+///     (x: -90.0, y: 80.0),
+/// ];
+/// assert!(arctic.spherical_contains(&point!(x: 0.0, y: 90.0)));
+/// assert!(arctic.spherical_contains(&point!(x: 135.0, y: 85.0)));
+/// assert!(!arctic.spherical_contains(&point!(x: 135.0, y: 75.0)));
+/// ```
+///
+/// [great circle]: https://en.wikipedia.org/wiki/Great_circle
+pub trait SphericalContains<Rhs = Self> {
+    fn spherical_contains(&self, rhs: &Rhs) -> bool;
+}
+
+impl<T: CoordFloat> SphericalContains<Point<T>> for Polygon<T> {
+    fn spherical_contains(&self, point: &Point<T>) -> bool {
+        let point = unit_vector(point.0);
+        ring_position(self.exterior(), point) == RingPosition::Inside
+            && self
+                .interiors()
+                .iter()
+                .all(|interior| ring_position(interior, point) == RingPosition::Outside)
+    }
+}
+
+impl<T: CoordFloat> SphericalContains<Coord<T>> for Polygon<T> {
+    fn spherical_contains(&self, coord: &Coord<T>) -> bool {
+        self.spherical_contains(&Point(*coord))
+    }
+}
+
+impl<T: CoordFloat> SphericalContains<Point<T>> for MultiPolygon<T> {
+    fn spherical_contains(&self, point: &Point<T>) -> bool {
+        self.iter().any(|polygon| polygon.spherical_contains(point))
+    }
+}
+
+impl<T: CoordFloat> SphericalContains<Coord<T>> for MultiPolygon<T> {
+    fn spherical_contains(&self, coord: &Coord<T>) -> bool {
+        self.spherical_contains(&Point(*coord))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum RingPosition {
+    Inside,
+    OnBoundary,
+    Outside,
+}
+
+type Vector3<T> = [T; 3];
+
+/// The position of `point` relative to the smaller of the two regions bounded by `ring`.
+///
+/// Summing the signed areas of the spherical triangles formed by the antipode of `point` and
+/// each edge gives the area of the region to the left of the ring when `point` lies to its
+/// right, or that area minus 4π when `point` lies to its left. So the sign of the sum tells
+/// which side `point` is on, and the sum itself tells which side is the smaller one.
+fn ring_position<T: CoordFloat>(ring: &LineString<T>, point: Vector3<T>) -> RingPosition {
+    if ring.0.len() < 4 {
+        return RingPosition::Outside;
+    }
+    let antipode = [-point[0], -point[1], -point[2]];
+    let mut area = T::zero();
+    for line in ring.lines() {
+        let (start, end) = (unit_vector(line.start), unit_vector(line.end));
+        if is_on_arc(point, start, end) {
+            return RingPosition::OnBoundary;
+        }
+        area = area + signed_triangle_area(antipode, start, end);
+    }
+
+    let two_pi = T::from(std::f64::consts::TAU).unwrap();
+    let is_left = area < T::zero();
+    let left_area = if is_left {
+        area + two_pi + two_pi
+    } else {
+        area
+    };
+    let left_is_smaller = left_area <= two_pi;
+    if is_left == left_is_smaller {
+        RingPosition::Inside
+    } else {
+        RingPosition::Outside
+    }
+}
+
+/// The signed area of the spherical triangle `a`, `b`, `c` on the unit sphere, positive if
+/// counter-clockwise, using the formula of [Van Oosterom and Strackee (1983)].
+///
+/// [Van Oosterom and Strackee (1983)]: https://doi.org/10.1109/TBME.1983.325207
+fn signed_triangle_area<T: CoordFloat>(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> T {
+    let two = T::one() + T::one();
+    let numerator = dot(a, cross(b, c));
+    let denominator = T::one() + dot(a, b) + dot(b, c) + dot(c, a);
+    two * numerator.atan2(denominator)
+}
+
+/// Whether `point` lies on the minor great circle arc from `start` to `end`.
+fn is_on_arc<T: CoordFloat>(point: Vector3<T>, start: Vector3<T>, end: Vector3<T>) -> bool {
+    let tolerance = T::epsilon() * T::from(16.0).unwrap();
+    let normal = cross(start, end);
+    if dot(point, normal).abs() > tolerance {
+        return false;
+    }
+    // Within the arc, `point` lies counter-clockwise of `start` and clockwise of `end`
+    dot(cross(start, point), normal) >= -tolerance && dot(cross(point, end), normal) >= -tolerance
+}
+
+fn unit_vector<T: CoordFloat>(coord: Coord<T>) -> Vector3<T> {
+    let (lon, lat) = (coord.x.to_radians(), coord.y.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+fn dot<T: CoordFloat>(a: Vector3<T>, b: Vector3<T>) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross<T: CoordFloat>(a: Vector3<T>, b: Vector3<T>) -> Vector3<T> {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point, polygon, wkt, Contains};
+
+    #[test]
+    fn agrees_with_planar_contains_for_small_polygons() {
+        let polygon = polygon![
+            (x: 10.0, y: 10.0),
+            (x: 11.0, y: 10.0),
+            (x: 11.0, y: 11.0),
+            (x: 10.0, y: 11.0),
+        ];
+        for point in [
+            point!(x: 10.5, y: 10.5),
+            point!(x: 10.1, y: 10.9),
+            point!(x: 9.9, y: 10.5),
+            point!(x: 10.5, y: 11.1),
+            point!(x: -169.5, y: -10.5),
+        ] {
+            assert_eq!(
+                polygon.contains(&point),
+                polygon.spherical_contains(&point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn winding_order_does_not_matter() {
+        let mut polygon = polygon![
+            (x: 0.0, y: 80.0),
+            (x: 90.0, y: 80.0),
+            (x: 180.0, y: 80.0),
+            (x: -90.0, y: 80.0),
+        ];
+        let north_pole = point!(x: 0.0, y: 90.0);
+        let south_pole = point!(x: 0.0, y: -90.0);
+        assert!(polygon.spherical_contains(&north_pole));
+        assert!(!polygon.spherical_contains(&south_pole));
+
+        polygon.exterior_mut(|exterior| exterior.0.reverse());
+        assert!(polygon.spherical_contains(&north_pole));
+        assert!(!polygon.spherical_contains(&south_pole));
+    }
+
+    #[test]
+    fn edges_are_great_circle_arcs() {
+        // The great circle arc between these points bulges north of the 60th parallel
+        let polygon = polygon![
+            (x: -60.0, y: 0.0),
+            (x: 60.0, y: 0.0),
+            (x: 60.0, y: 60.0),
+            (x: -60.0, y: 60.0),
+        ];
+        let point = point!(x: 0.0, y: 62.0);
+        assert!(!polygon.contains(&point));
+        assert!(polygon.spherical_contains(&point));
+    }
+
+    #[test]
+    fn boundary_is_not_contained() {
+        let polygon = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 10.0, y: 0.0),
+            (x: 10.0, y: 10.0),
+            (x: 0.0, y: 10.0),
+        ];
+        assert!(!polygon.spherical_contains(&point!(x: 0.0, y: 0.0)));
+        assert!(!polygon.spherical_contains(&point!(x: 5.0, y: 0.0)));
+        assert!(!polygon.spherical_contains(&point!(x: 0.0, y: 5.0)));
+    }
+
+    #[test]
+    fn holes() {
+        let polygon = wkt!(POLYGON(
+            (170. -20.,-170. -20.,-170. 20.,170. 20.,170. -20.),
+            (178. -2.,-178. -2.,-178. 2.,178. 2.,178. -2.)
+        ));
+        assert!(polygon.spherical_contains(&point!(x: 175.0, y: 0.0)));
+        assert!(!polygon.spherical_contains(&point!(x: 180.0, y: 0.0)));
+        assert!(!polygon.spherical_contains(&point!(x: 0.0, y: 0.0)));
+
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon,
+            polygon![
+                (x: 179.0, y: -1.0),
+                (x: -179.0, y: -1.0),
+                (x: -179.0, y: 1.0),
+                (x: 179.0, y: 1.0),
+            ],
+        ]);
+        assert!(multi_polygon.spherical_contains(&point!(x: 175.0, y: 0.0)));
+        assert!(multi_polygon.spherical_contains(&Coord { x: 180.0, y: 0.0 }));
+        assert!(!multi_polygon.spherical_contains(&point!(x: 178.5, y: 1.5)));
+    }
+
+    #[test]
+    fn empty_polygon() {
+        let polygon: Polygon = polygon![];
+        assert!(!polygon.spherical_contains(&point!(x: 0.0, y: 0.0)));
+    }
+}
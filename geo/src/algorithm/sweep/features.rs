@@ -0,0 +1,320 @@
+use super::{Cross, Intersections, LineOrPoint};
+use crate::{
+    Coord, GeoFloat, Geometry, Line, LineIntersection, LineString, MultiLineString, Polygon,
+};
+
+/// Iterator over all intersections between the segments of a set of features.
+///
+/// Yields tuples `(feature_a_idx, feature_b_idx, LineIntersection)`, with `feature_a_idx <=
+/// feature_b_idx`, for each pair of intersecting or overlapping segments. Features are the
+/// `LineString`s of a `MultiLineString`, the rings of a `Polygon`, starting with its exterior,
+/// or the `Geometry`s of an iterator. Points are included as degenerate segments.
+///
+/// Consecutive segments of a line string or ring always meet at their shared coordinate, as do
+/// the first and last segments of a closed one, so these intersections are omitted. Any other
+/// intersection within a feature, i.e. a self-intersection, is yielded with both indices equal.
+///
+/// This uses the [Bentley-Ottman] sweep of [`Intersections`], so is much faster than testing
+/// every pair of segments when there are few intersections, e.g. to find the crossings between
+/// two layers of features.
+///
+/// # Examples
+///
+/// ```
+/// use geo::sweep::FeatureIntersections;
+/// use geo::{wkt, Geometry};
+///
+/// let features: Vec<Geometry> = vec![
+///     wkt!(LINESTRING(0. 0.,2. 2.)).into(),
+///     wkt!(LINESTRING(5. 5.,6. 6.)).into(),
+///     wkt!(LINESTRING(0. 2.,2. 0.)).into(),
+///     wkt!(POINT(6. 6.)).into(),
+/// ];
+/// let mut pairs: Vec<(usize, usize)> = FeatureIntersections::from_geometries(&features)
+///     .map(|(a, b, _)| (a, b))
+///     .collect();
+/// pairs.sort();
+/// assert_eq!(pairs, vec![(0, 2), (1, 3)]);
+/// ```
+///
+/// [Bentley-Ottman]: //en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm
+pub struct FeatureIntersections<T: GeoFloat> {
+    inner: Intersections<FeatureSegment<T>>,
+    parts: Vec<Part>,
+}
+
+/// A segment of a feature, between the coordinates `idx` and `idx + 1` of one of its parts,
+/// after removing repeated coordinates.
+#[derive(Debug, Clone, Copy)]
+struct FeatureSegment<T: GeoFloat> {
+    line: Line<T>,
+    feature: usize,
+    part: usize,
+    idx: usize,
+}
+
+impl<T: GeoFloat> Cross for FeatureSegment<T> {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<T> {
+        self.line.into()
+    }
+}
+
+/// A line string, ring or point of a feature.
+struct Part {
+    segments: usize,
+    is_closed: bool,
+}
+
+impl<T: GeoFloat> FeatureIntersections<T> {
+    /// The intersections of a `LineString` with itself, as feature `0`.
+    pub fn from_line_string(line_string: &LineString<T>) -> Self {
+        let mut builder = Builder::default();
+        builder.add_line_string(line_string, 0);
+        builder.build()
+    }
+
+    /// The intersections between, and within, the `LineString`s of a `MultiLineString`.
+    pub fn from_multi_line_string(multi_line_string: &MultiLineString<T>) -> Self {
+        let mut builder = Builder::default();
+        for (feature, line_string) in multi_line_string.iter().enumerate() {
+            builder.add_line_string(line_string, feature);
+        }
+        builder.build()
+    }
+
+    /// The intersections between, and within, the rings of a `Polygon`. The exterior is feature
+    /// `0`, and the interiors follow in order.
+    pub fn from_polygon(polygon: &Polygon<T>) -> Self {
+        let mut builder = Builder::default();
+        for (feature, ring) in std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .enumerate()
+        {
+            builder.add_line_string(ring, feature);
+        }
+        builder.build()
+    }
+
+    /// The intersections between, and within, a sequence of geometries.
+    pub fn from_geometries<'a>(geometries: impl IntoIterator<Item = &'a Geometry<T>>) -> Self
+    where
+        T: 'a,
+    {
+        let mut builder = Builder::default();
+        for (feature, geometry) in geometries.into_iter().enumerate() {
+            builder.add_geometry(geometry, feature);
+        }
+        builder.build()
+    }
+}
+
+impl<T: GeoFloat> Iterator for FeatureIntersections<T> {
+    type Item = (usize, usize, LineIntersection<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (a, b, intersection) in self.inner.by_ref() {
+            let (a, b) = if (a.feature, a.part, a.idx) <= (b.feature, b.part, b.idx) {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            if a.part == b.part {
+                if let LineIntersection::SinglePoint { intersection, .. } = intersection {
+                    let part = &self.parts[a.part];
+                    let consecutive = b.idx == a.idx + 1 && intersection == a.line.end;
+                    let closing = part.is_closed
+                        && a.idx == 0
+                        && b.idx + 1 == part.segments
+                        && intersection == a.line.start;
+                    if consecutive || closing {
+                        continue;
+                    }
+                }
+            }
+            return Some((a.feature, b.feature, intersection));
+        }
+        None
+    }
+}
+
+struct Builder<T: GeoFloat> {
+    segments: Vec<FeatureSegment<T>>,
+    parts: Vec<Part>,
+}
+
+impl<T: GeoFloat> Default for Builder<T> {
+    fn default() -> Self {
+        Self {
+            segments: Vec::new(),
+            parts: Vec::new(),
+        }
+    }
+}
+
+impl<T: GeoFloat> Builder<T> {
+    fn add_coords(&mut self, coords: impl IntoIterator<Item = Coord<T>>, feature: usize) {
+        let mut coords: Vec<Coord<T>> = coords.into_iter().collect();
+        coords.dedup();
+        let part = self.parts.len();
+        let segments = match coords.len() {
+            0 => return,
+            1 => {
+                self.segments.push(FeatureSegment {
+                    line: Line::new(coords[0], coords[0]),
+                    feature,
+                    part,
+                    idx: 0,
+                });
+                1
+            }
+            len => {
+                self.segments
+                    .extend(
+                        coords
+                            .windows(2)
+                            .enumerate()
+                            .map(|(idx, pair)| FeatureSegment {
+                                line: Line::new(pair[0], pair[1]),
+                                feature,
+                                part,
+                                idx,
+                            }),
+                    );
+                len - 1
+            }
+        };
+        self.parts.push(Part {
+            segments,
+            is_closed: coords.len() > 2 && coords.first() == coords.last(),
+        });
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<T>, feature: usize) {
+        self.add_coords(line_string.0.iter().copied(), feature);
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>, feature: usize) {
+        self.add_line_string(polygon.exterior(), feature);
+        for interior in polygon.interiors() {
+            self.add_line_string(interior, feature);
+        }
+    }
+
+    fn add_geometry(&mut self, geometry: &Geometry<T>, feature: usize) {
+        match geometry {
+            Geometry::Point(point) => self.add_coords([point.0], feature),
+            Geometry::Line(line) => self.add_coords([line.start, line.end], feature),
+            Geometry::LineString(line_string) => self.add_line_string(line_string, feature),
+            Geometry::Polygon(polygon) => self.add_polygon(polygon, feature),
+            Geometry::MultiPoint(multi_point) => {
+                for point in multi_point {
+                    self.add_coords([point.0], feature);
+                }
+            }
+            Geometry::MultiLineString(multi_line_string) => {
+                for line_string in multi_line_string {
+                    self.add_line_string(line_string, feature);
+                }
+            }
+            Geometry::MultiPolygon(multi_polygon) => {
+                for polygon in multi_polygon {
+                    self.add_polygon(polygon, feature);
+                }
+            }
+            Geometry::GeometryCollection(collection) => {
+                for geometry in collection {
+                    self.add_geometry(geometry, feature);
+                }
+            }
+            Geometry::Rect(rect) => self.add_polygon(&rect.to_polygon(), feature),
+            Geometry::Triangle(triangle) => self.add_polygon(&triangle.to_polygon(), feature),
+        }
+    }
+
+    fn build(self) -> FeatureIntersections<T> {
+        FeatureIntersections {
+            inner: Intersections::from_iter(self.segments),
+            parts: self.parts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wkt;
+
+    fn pairs<T: GeoFloat>(intersections: FeatureIntersections<T>) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<_> = intersections.map(|(a, b, _)| (a, b)).collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn line_strings() {
+        // a closed ring, with a repeated coordinate, has no intersections
+        let ring = wkt!(LINESTRING(0. 0.,1. 0.,1. 0.,1. 1.,0. 1.,0. 0.));
+        assert!(pairs(FeatureIntersections::from_line_string(&ring)).is_empty());
+        // a bow tie crosses itself once
+        let bow_tie = wkt!(LINESTRING(0. 0.,1. 1.,1. 0.,0. 1.,0. 0.));
+        assert_eq!(
+            pairs(FeatureIntersections::from_line_string(&bow_tie)),
+            vec![(0, 0)]
+        );
+
+        let multi_line_string = wkt!(MULTILINESTRING(
+            (0. 0.,4. 0.),
+            (1. -1.,1. 1.,3. 1.,3. -1.),
+            (10. 10.,11. 11.),
+            (2. 0.,3. 0.)
+        ));
+        let intersections: Vec<_> =
+            FeatureIntersections::from_multi_line_string(&multi_line_string).collect();
+        assert_eq!(intersections.len(), 4);
+        assert!(intersections.contains(&(
+            0,
+            3,
+            LineIntersection::Collinear {
+                intersection: Line::new(Coord { x: 2., y: 0. }, Coord { x: 3., y: 0. })
+            }
+        )));
+        assert_eq!(
+            pairs(FeatureIntersections::from_multi_line_string(
+                &multi_line_string
+            )),
+            vec![(0, 1), (0, 1), (0, 3), (1, 3)]
+        );
+    }
+
+    #[test]
+    fn polygon_rings() {
+        // the second hole touches the exterior with a vertex, so with two of its segments, and
+        // the third crosses the first
+        let polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (1. 1.,3. 1.,3. 3.,1. 1.),
+            (5. 0.,6. 1.,7. 1.,5. 0.),
+            (2. 0.5,4. 0.5,4. 2.,2. 0.5)
+        ));
+        assert_eq!(
+            pairs(FeatureIntersections::from_polygon(&polygon)),
+            vec![(0, 2), (0, 2), (1, 3), (1, 3)]
+        );
+    }
+
+    #[test]
+    fn geometries() {
+        let geometries: Vec<Geometry> = vec![
+            wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 4.,0. 0.))).into(),
+            wkt!(MULTIPOINT(4. 2.,8. 8.)).into(),
+            Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(LINESTRING(-1. 1.,1. 1.)))),
+            wkt!(LINESTRING(20. 20.,21. 21.)).into(),
+        ];
+        assert_eq!(
+            pairs(FeatureIntersections::from_geometries(&geometries)),
+            vec![(0, 1), (0, 2)]
+        );
+    }
+}
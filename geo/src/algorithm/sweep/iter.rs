@@ -95,6 +95,7 @@ where
 {
     sweep: Sweep<C>,
     segments: Vec<Crossing<C>>,
+    last_point: Option<SweepPoint<C::Scalar>>,
 }
 
 impl<C> CrossingsIter<C>
@@ -111,6 +112,19 @@ where
         &self.segments
     }
 
+    /// Add a segment to the sweep, if it starts after the last yielded point. Otherwise, the
+    /// sweep has already passed it, so it is returned.
+    pub fn add(&mut self, cross: C) -> Result<(), C> {
+        if self
+            .last_point
+            .is_some_and(|last_point| cross.line().left() <= last_point)
+        {
+            return Err(cross);
+        }
+        self.sweep.add(cross);
+        Ok(())
+    }
+
     fn new_ex<T: IntoIterator<Item = C>>(iter: T, is_simple: bool) -> Self {
         let iter = iter.into_iter();
         let size = {
@@ -119,7 +133,11 @@ where
         };
         let sweep = Sweep::new(iter, is_simple);
         let segments = Vec::with_capacity(4 * size);
-        Self { sweep, segments }
+        Self {
+            sweep,
+            segments,
+            last_point: None,
+        }
     }
 }
 
@@ -154,6 +172,9 @@ where
             });
         }
 
+        if last_point.is_some() {
+            self.last_point = last_point;
+        }
         if segments.is_empty() {
             None
         } else {
@@ -195,6 +216,12 @@ where
 /// assert_eq!(iter.count(), 6);
 /// ```
 ///
+/// The sweep is lazy: each call to `next` only advances it as far as the next intersection, so
+/// taking the first few intersections of a large input is cheap. Segments may also be
+/// [added](Self::add) as the sweep proceeds, e.g. while streaming features sorted by `x`, as
+/// long as they start after the point it has reached. Once the iterator returns `None`, adding
+/// segments resumes it.
+///
 /// [Bentley-Ottman]: //en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm
 pub struct Intersections<C: Cross + Clone> {
     inner: CrossingsIter<C>,
@@ -223,6 +250,35 @@ impl<C> Intersections<C>
 where
     C: Cross + Clone,
 {
+    /// Adds a segment to the sweep.
+    ///
+    /// The sweep visits points in order of `x`, then `y`, and has processed every point up to
+    /// that of the last yielded intersection. The segment must start after that point, i.e. its
+    /// leftmost (then lowest) end must be greater. Otherwise, it is returned as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::sweep::Intersections;
+    /// use geo::Line;
+    ///
+    /// let mut iter: Intersections<Line> = Intersections::from_iter([
+    ///     Line::from([(0., 0.), (2., 2.)]),
+    ///     Line::from([(0., 2.), (2., 0.)]),
+    /// ]);
+    /// // the lines cross at (1, 1)
+    /// assert!(iter.next().is_some());
+    ///
+    /// // so the sweep has passed x = 0, but not x = 1.5
+    /// assert!(iter.add(Line::from([(0., 1.), (3., 1.)])).is_err());
+    /// assert!(iter.add(Line::from([(1.5, 0.), (1.5, 3.)])).is_ok());
+    /// // which crosses both lines
+    /// assert_eq!(iter.count(), 2);
+    /// ```
+    pub fn add(&mut self, cross: C) -> Result<(), C> {
+        self.inner.add(cross)
+    }
+
     fn intersection(&mut self) -> Option<(C, C, LineIntersection<C::Scalar>)> {
         let (si, sj) = {
             let segments = self.inner.intersections();
@@ -336,6 +392,39 @@ pub(super) mod tests {
         assert_eq!(iter.count(), 5);
     }
 
+    #[test]
+    fn incremental() {
+        // short lines scattered along the x axis, in general position
+        let lines: Vec<Line<f64>> = (0..200)
+            .map(|i| {
+                let fract = |factor: f64| (i as f64 * factor).fract();
+                let (x, y) = (50. * fract(0.618034), 4. * fract(0.414214));
+                Line::from([(x, y), (x + 3. * fract(0.732051), 4. * fract(0.236068))])
+            })
+            .collect();
+        let (first, rest) = lines.split_at(100);
+
+        let mut iter = Intersections::from_iter(first.iter().copied());
+        let (_, _, intersection) = iter.next().unwrap();
+        let LineIntersection::SinglePoint { intersection, .. } = intersection else {
+            panic!("the lines aren't collinear");
+        };
+        let reached = SweepPoint::from(intersection);
+
+        // lines starting after the first intersection can be added
+        let mut accepted = Vec::new();
+        for line in rest {
+            match iter.add(*line) {
+                Ok(()) => accepted.push(*line),
+                Err(_) => assert!(LineOrPoint::from(*line).left() <= reached),
+            }
+        }
+        assert!(!accepted.is_empty() && accepted.len() < rest.len());
+
+        let expected = Intersections::from_iter(first.iter().chain(&accepted)).count();
+        assert_eq!(1 + iter.count(), expected);
+    }
+
     #[test]
     fn overlap_intersect() {
         init_log();
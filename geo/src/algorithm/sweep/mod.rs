@@ -27,3 +27,6 @@ use proc::Sweep;
 
 mod iter;
 pub use iter::Intersections;
+
+mod features;
+pub use features::FeatureIntersections;
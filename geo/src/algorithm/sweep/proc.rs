@@ -27,12 +27,19 @@ impl<C: Cross + Clone> Sweep<C> {
             is_simple,
         };
         for cr in iter {
-            IMSegment::create_segment(cr, None, None, |ev| sweep.events.push(ev));
+            sweep.add(cr);
         }
 
         sweep
     }
 
+    /// Add a segment to the sweep.
+    ///
+    /// The segment must start after the point of the last processed event.
+    pub(crate) fn add(&mut self, cr: C) {
+        IMSegment::create_segment(cr, None, None, |ev| self.events.push(ev));
+    }
+
     /// Process the next event in heap.
     ///
     /// Calls the callback unless the event is spurious.
@@ -0,0 +1,616 @@
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::geometry::*;
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::line_measures::{Distance, Euclidean};
+use crate::{BoundingRect, Epsilon, GeoFloat, GeometryCow, Vector2DOps};
+use rstar::primitives::CachedEnvelope;
+use rstar::{RTree, AABB};
+
+/// Test whether two geometries intersect, tolerating gaps of up to `tolerance` between them.
+///
+/// Real-world data is noisy: a point which ought to lie on a line may be a few nanometers off,
+/// and two polygons which ought to touch may be separated by a sliver. With a `tolerance` of
+/// zero this is equivalent to [`Intersects`](crate::Intersects); otherwise the geometries are
+/// considered intersecting if the (Euclidean) distance between them is at most `tolerance`.
+///
+/// The `tolerance` is an absolute distance, or an [`Epsilon`] policy, whose tolerance at the
+/// magnitude of the coordinates of both geometries is used.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, IntersectsWithTolerance};
+///
+/// let line_string = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0)];
+/// let noisy_point = point!(x: 5.0, y: 1e-9);
+///
+/// assert!(line_string.intersects_with_tolerance(&noisy_point, 1e-6));
+/// assert!(!line_string.intersects_with_tolerance(&point!(x: 5.0, y: 1.0), 1e-6));
+/// ```
+pub trait IntersectsWithTolerance<Rhs, F> {
+    fn intersects_with_tolerance(&self, rhs: &Rhs, tolerance: impl Into<Epsilon<F>>) -> bool;
+}
+
+/// Test whether a geometry contains another, tolerating parts of the other geometry which lie
+/// at most `tolerance` outside of it.
+///
+/// Conceptually, `self` is expanded by `tolerance` before testing whether every point of `rhs`
+/// lies within it, so that e.g. a point on (or within `tolerance` of) the boundary of a polygon
+/// counts as contained. This is implemented with distance checks against the boundary of `self`
+/// rather than by buffering it. As for [`IntersectsWithTolerance`], the `tolerance` is an
+/// absolute distance, or an [`Epsilon`] policy.
+///
+/// Unlike [`Contains`](crate::Contains), no interior intersection is required: a polygon
+/// contains its own boundary within any tolerance. An empty `rhs` is never contained.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon, ContainsWithTolerance, Contains};
+///
+/// let square = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+///
+/// // a point just outside the boundary
+/// let point = point!(x: 10.0 + 1e-9, y: 5.0);
+/// assert!(!square.contains(&point));
+/// assert!(square.contains_with_tolerance(&point, 1e-6));
+///
+/// // a line running along the boundary, wavering slightly outside of it
+/// let line_string = line_string![(x: 0.0, y: 0.0), (x: 5.0, y: -1e-9), (x: 10.0, y: 0.0)];
+/// assert!(square.contains_with_tolerance(&line_string, 1e-6));
+/// assert!(!square.contains_with_tolerance(&line_string, 1e-12));
+/// ```
+pub trait ContainsWithTolerance<Rhs, F> {
+    fn contains_with_tolerance(&self, rhs: &Rhs, tolerance: impl Into<Epsilon<F>>) -> bool;
+}
+
+/// Test whether a geometry is within another, tolerating parts of it which lie at most
+/// `tolerance` outside of the other geometry.
+///
+/// `WithinWithTolerance` is equivalent to [`ContainsWithTolerance`] with the arguments swapped.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, WithinWithTolerance};
+///
+/// let square = polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)];
+///
+/// assert!(point!(x: -1e-9, y: 5.0).is_within_with_tolerance(&square, 1e-6));
+/// ```
+pub trait WithinWithTolerance<Rhs, F> {
+    fn is_within_with_tolerance(&self, rhs: &Rhs, tolerance: impl Into<Epsilon<F>>) -> bool;
+}
+
+impl<G1, G2, F> WithinWithTolerance<G2, F> for G1
+where
+    G2: ContainsWithTolerance<G1, F>,
+{
+    fn is_within_with_tolerance(&self, rhs: &G2, tolerance: impl Into<Epsilon<F>>) -> bool {
+        rhs.contains_with_tolerance(self, tolerance)
+    }
+}
+
+/// The segments and isolated points making up a geometry.
+struct Parts<F: GeoFloat> {
+    segments: Vec<Line<F>>,
+    points: Vec<Coord<F>>,
+    /// The vertices of every polygon ring, exteriors and holes alike.
+    ring_vertices: Vec<Coord<F>>,
+}
+
+impl<F: GeoFloat> Parts<F> {
+    fn new(geometry: &GeometryCow<F>) -> Self {
+        let mut parts = Parts {
+            segments: vec![],
+            points: vec![],
+            ring_vertices: vec![],
+        };
+        parts.add_geometry(geometry);
+        parts
+    }
+
+    fn add_geometry(&mut self, geometry: &GeometryCow<F>) {
+        match geometry {
+            GeometryCow::Point(point) => self.points.push(point.0),
+            GeometryCow::Line(line) => self.add_line_string(&[line.start, line.end]),
+            GeometryCow::LineString(line_string) => self.add_line_string(&line_string.0),
+            GeometryCow::Polygon(polygon) => self.add_polygon(polygon),
+            GeometryCow::MultiPoint(multi_point) => {
+                self.points.extend(multi_point.iter().map(|point| point.0))
+            }
+            GeometryCow::MultiLineString(multi_line_string) => multi_line_string
+                .iter()
+                .for_each(|line_string| self.add_line_string(&line_string.0)),
+            GeometryCow::MultiPolygon(multi_polygon) => multi_polygon
+                .iter()
+                .for_each(|polygon| self.add_polygon(polygon)),
+            GeometryCow::GeometryCollection(collection) => collection
+                .iter()
+                .for_each(|geometry| self.add_geometry(&GeometryCow::from(geometry))),
+            GeometryCow::Rect(rect) => self.add_polygon(&rect.to_polygon()),
+            GeometryCow::Triangle(triangle) => self.add_polygon(&triangle.to_polygon()),
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<F>) {
+        std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .for_each(|ring| {
+                self.ring_vertices.extend(ring.coords());
+                self.add_line_string(&ring.0)
+            });
+    }
+
+    fn add_line_string(&mut self, coords: &[Coord<F>]) {
+        match coords {
+            [] => {}
+            [coord] => self.points.push(*coord),
+            _ => {
+                for window in coords.windows(2) {
+                    if window[0] == window[1] {
+                        self.points.push(window[0]);
+                    } else {
+                        self.segments.push(Line::new(window[0], window[1]));
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.segments.is_empty() && self.points.is_empty()
+    }
+
+    fn segment_tree(&self) -> RTree<CachedEnvelope<Line<F>>> {
+        RTree::bulk_load(
+            self.segments
+                .iter()
+                .copied()
+                .map(CachedEnvelope::new)
+                .collect(),
+        )
+    }
+
+    /// The largest absolute value of any coordinate.
+    fn magnitude(&self) -> F {
+        self.segments
+            .iter()
+            .flat_map(|segment| [segment.start, segment.end])
+            .chain(self.points.iter().copied())
+            .fold(F::zero(), |magnitude, coord| {
+                magnitude.max(coord.x.abs()).max(coord.y.abs())
+            })
+    }
+
+    fn vertices(&self) -> impl Iterator<Item = Coord<F>> + '_ {
+        self.segments
+            .iter()
+            .map(|segment| segment.start)
+            .chain(self.points.iter().copied())
+    }
+}
+
+/// The envelope of `line`, expanded by `tolerance` in every direction.
+fn expanded_envelope<F: GeoFloat>(line: &Line<F>, tolerance: F) -> AABB<Point<F>> {
+    let rect = line.bounding_rect();
+    AABB::from_corners(
+        Point::new(rect.min().x - tolerance, rect.min().y - tolerance),
+        Point::new(rect.max().x + tolerance, rect.max().y + tolerance),
+    )
+}
+
+fn intersects_with_tolerance<F: GeoFloat>(
+    a: &GeometryCow<F>,
+    b: &GeometryCow<F>,
+    epsilon: Epsilon<F>,
+) -> bool {
+    let (parts_a, parts_b) = (Parts::new(a), Parts::new(b));
+    if parts_a.is_empty() || parts_b.is_empty() {
+        return false;
+    }
+    let tolerance = epsilon.tolerance_at(parts_a.magnitude().max(parts_b.magnitude()));
+
+    // One geometry may lie entirely in the interior of the other
+    if parts_b
+        .vertices()
+        .any(|coord| a.coordinate_position(&coord) != CoordPos::Outside)
+        || parts_a
+            .vertices()
+            .any(|coord| b.coordinate_position(&coord) != CoordPos::Outside)
+    {
+        return true;
+    }
+
+    let tree_a = parts_a.segment_tree();
+    let near_a = |line: &Line<F>| {
+        tree_a
+            .locate_in_envelope_intersecting(&expanded_envelope(line, tolerance))
+            .any(|segment| Euclidean.distance(line, &**segment) <= tolerance)
+            || parts_a
+                .points
+                .iter()
+                .any(|point| Euclidean.distance(&Point(*point), line) <= tolerance)
+    };
+
+    parts_b.segments.iter().any(near_a)
+        || parts_b
+            .points
+            .iter()
+            .any(|point| near_a(&Line::new(*point, *point)))
+}
+
+fn contains_with_tolerance<F: GeoFloat>(
+    a: &GeometryCow<F>,
+    b: &GeometryCow<F>,
+    epsilon: Epsilon<F>,
+) -> bool {
+    let (parts_a, parts_b) = (Parts::new(a), Parts::new(b));
+    if parts_a.is_empty() || parts_b.is_empty() {
+        return false;
+    }
+    let tolerance = epsilon.tolerance_at(parts_a.magnitude().max(parts_b.magnitude()));
+    let tree_a = parts_a.segment_tree();
+
+    let point_is_covered = |coord: &Coord<F>| {
+        a.coordinate_position(coord) != CoordPos::Outside
+            || parts_a
+                .points
+                .iter()
+                .any(|point| Euclidean.distance(*point, *coord) <= tolerance)
+            || tree_a
+                .locate_in_envelope_intersecting(&expanded_envelope(
+                    &Line::new(*coord, *coord),
+                    tolerance,
+                ))
+                .any(|segment| Euclidean.distance(*coord, &**segment) <= tolerance)
+    };
+
+    let segment_is_covered = |line: &Line<F>| {
+        let nearby_segments: Vec<Line<F>> = tree_a
+            .locate_in_envelope_intersecting(&expanded_envelope(line, tolerance))
+            .map(|segment| **segment)
+            .collect();
+
+        // Parts of `line` which lie within `a` itself
+        let mut cuts = vec![F::zero(), F::one()];
+        for segment in &nearby_segments {
+            match line_intersection(*line, *segment) {
+                Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                    cuts.push(parameter_of(line, intersection))
+                }
+                Some(LineIntersection::Collinear { intersection }) => {
+                    cuts.push(parameter_of(line, intersection.start));
+                    cuts.push(parameter_of(line, intersection.end));
+                }
+                None => {}
+            }
+        }
+        cuts.sort_by(|a, b| a.total_cmp(b));
+        cuts.dedup();
+        let two = F::one() + F::one();
+        let mut intervals: Vec<(F, F)> = cuts
+            .windows(2)
+            .filter(|window| {
+                let midpoint = point_at(line, (window[0] + window[1]) / two);
+                a.coordinate_position(&midpoint) != CoordPos::Outside
+            })
+            .map(|window| (window[0], window[1]))
+            .collect();
+
+        // Parts of `line` which lie within `tolerance` of the boundary of `a`
+        intervals.extend(
+            nearby_segments
+                .iter()
+                .filter_map(|segment| capsule_interval(line, segment, tolerance)),
+        );
+        intervals.extend(
+            parts_a
+                .points
+                .iter()
+                .filter_map(|point| capsule_interval(line, &Line::new(*point, *point), tolerance)),
+        );
+
+        covers_unit_interval(intervals)
+    };
+
+    // Covering the boundary of `b` isn't enough: the interior of `b` must also avoid the holes
+    // of `a`, and can't reach around `a` either.
+    let tree_b = parts_b.segment_tree();
+    let interior_of_b_reaches = |coord: &Coord<F>| {
+        b.coordinate_position(coord) == CoordPos::Inside
+            && !tree_b
+                .locate_in_envelope_intersecting(&expanded_envelope(
+                    &Line::new(*coord, *coord),
+                    tolerance,
+                ))
+                .any(|segment| Euclidean.distance(*coord, &**segment) <= tolerance)
+    };
+
+    parts_b.points.iter().all(point_is_covered)
+        && parts_b.segments.iter().all(segment_is_covered)
+        && !parts_a.ring_vertices.iter().any(interior_of_b_reaches)
+}
+
+/// The parameter `t` of the projection of `coord` onto `line`, where `line.start` is at `t = 0`
+/// and `line.end` is at `t = 1`.
+fn parameter_of<F: GeoFloat>(line: &Line<F>, coord: Coord<F>) -> F {
+    let delta = line.delta();
+    let t = (coord - line.start).dot_product(delta) / delta.magnitude_squared();
+    t.max(F::zero()).min(F::one())
+}
+
+fn point_at<F: GeoFloat>(line: &Line<F>, t: F) -> Coord<F> {
+    line.start + line.delta() * t
+}
+
+/// The interval of parameters `t` along `line` which lie within `radius` of `segment`, clipped
+/// to `[0, 1]`.
+///
+/// The points within `radius` of a segment form a convex "capsule": the union of a rectangle
+/// around the segment and a disk around each of its end points. The intersection of a line with
+/// a convex shape is a single interval, which is the hull of the intervals of each component.
+fn capsule_interval<F: GeoFloat>(line: &Line<F>, segment: &Line<F>, radius: F) -> Option<(F, F)> {
+    let mut interval: Option<(F, F)> = None;
+    let mut add = |component: Option<(F, F)>| {
+        if let Some((lo, hi)) = component {
+            interval = Some(match interval {
+                None => (lo, hi),
+                Some((min, max)) => (min.min(lo), max.max(hi)),
+            });
+        }
+    };
+    add(disk_interval(line, segment.start, radius));
+    add(disk_interval(line, segment.end, radius));
+    if segment.start != segment.end {
+        add(rectangle_interval(line, segment, radius));
+    }
+
+    let (lo, hi) = interval?;
+    let (lo, hi) = (lo.max(F::zero()), hi.min(F::one()));
+    (lo <= hi).then_some((lo, hi))
+}
+
+fn disk_interval<F: GeoFloat>(line: &Line<F>, center: Coord<F>, radius: F) -> Option<(F, F)> {
+    let delta = line.delta();
+    let length_squared = delta.magnitude_squared();
+    // Measure the distance from `center` to the (infinite) line directly, rather than solving
+    // the quadratic, which suffers from cancellation when `radius` is tiny.
+    let closest = (center - line.start).dot_product(delta) / length_squared;
+    let distance_squared = (line.start + delta * closest - center).magnitude_squared();
+    if distance_squared > radius * radius {
+        return None;
+    }
+    let half_width = ((radius * radius - distance_squared) / length_squared).sqrt();
+    Some((closest - half_width, closest + half_width))
+}
+
+fn rectangle_interval<F: GeoFloat>(line: &Line<F>, segment: &Line<F>, radius: F) -> Option<(F, F)> {
+    let length = segment.delta().magnitude();
+    let along = segment.delta() / length;
+    let across = along.left();
+    let offset = line.start - segment.start;
+
+    // Each bound is a linear inequality `lo <= v0 + t·dv <= hi` in t
+    let mut interval = (F::neg_infinity(), F::infinity());
+    for (v0, dv, lo, hi) in [
+        (
+            offset.dot_product(along),
+            line.delta().dot_product(along),
+            F::zero(),
+            length,
+        ),
+        (
+            offset.dot_product(across),
+            line.delta().dot_product(across),
+            -radius,
+            radius,
+        ),
+    ] {
+        if dv == F::zero() {
+            if v0 < lo || v0 > hi {
+                return None;
+            }
+        } else {
+            let (t0, t1) = ((lo - v0) / dv, (hi - v0) / dv);
+            interval = (interval.0.max(t0.min(t1)), interval.1.min(t0.max(t1)));
+        }
+    }
+    (interval.0 <= interval.1).then_some(interval)
+}
+
+fn covers_unit_interval<F: GeoFloat>(mut intervals: Vec<(F, F)>) -> bool {
+    intervals.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut covered = F::zero();
+    for (lo, hi) in intervals {
+        if lo > covered {
+            return false;
+        }
+        covered = covered.max(hi);
+    }
+    covered >= F::one()
+}
+
+macro_rules! impl_tolerant_predicates {
+    ([$($lhs:ident),*], $rhs:tt) => {
+        $(
+            impl_tolerant_predicates!(@impl $lhs, $rhs);
+        )*
+    };
+    (@impl $lhs:ident, [$($rhs:ident),*]) => {
+        $(
+            impl<F: GeoFloat> IntersectsWithTolerance<$rhs<F>, F> for $lhs<F> {
+                fn intersects_with_tolerance(
+                    &self,
+                    rhs: &$rhs<F>,
+                    tolerance: impl Into<Epsilon<F>>,
+                ) -> bool {
+                    intersects_with_tolerance(&self.into(), &rhs.into(), tolerance.into())
+                }
+            }
+
+            impl<F: GeoFloat> ContainsWithTolerance<$rhs<F>, F> for $lhs<F> {
+                fn contains_with_tolerance(
+                    &self,
+                    rhs: &$rhs<F>,
+                    tolerance: impl Into<Epsilon<F>>,
+                ) -> bool {
+                    contains_with_tolerance(&self.into(), &rhs.into(), tolerance.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_tolerant_predicates!(
+    [
+        Point,
+        Line,
+        LineString,
+        Polygon,
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        Rect,
+        Triangle,
+        GeometryCollection,
+        Geometry
+    ],
+    [
+        Point,
+        Line,
+        LineString,
+        Polygon,
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        Rect,
+        Triangle,
+        GeometryCollection,
+        Geometry
+    ]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon, wkt, Contains, Intersects};
+
+    fn square() -> Polygon {
+        polygon![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)]
+    }
+
+    #[test]
+    fn zero_tolerance_matches_intersects() {
+        let square = square();
+        let candidates: Vec<Geometry> = vec![
+            point!(x: 5.0, y: 5.0).into(),
+            point!(x: 10.0, y: 5.0).into(),
+            point!(x: 11.0, y: 5.0).into(),
+            line_string![(x: -5.0, y: 5.0), (x: 15.0, y: 5.0)].into(),
+            line_string![(x: -5.0, y: -5.0), (x: -1.0, y: -1.0)].into(),
+            wkt!(POLYGON((2. 2.,3. 2.,3. 3.,2. 2.))).into(),
+            wkt!(POLYGON((-10. -10.,20. -10.,20. 20.,-10. -10.))).into(),
+        ];
+        for candidate in candidates {
+            assert_eq!(
+                square.intersects(&candidate),
+                square.intersects_with_tolerance(&candidate, 0.0),
+                "{candidate:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn intersects_within_tolerance() {
+        let a = square();
+        let b = wkt!(POLYGON((10.001 0.,20. 0.,20. 10.,10.001 10.,10.001 0.)));
+        assert!(!a.intersects(&b));
+        assert!(!a.intersects_with_tolerance(&b, 0.0001));
+        assert!(a.intersects_with_tolerance(&b, 0.01));
+        assert!(b.intersects_with_tolerance(&a, 0.01));
+
+        let empty: MultiPoint = MultiPoint::new(vec![]);
+        assert!(!a.intersects_with_tolerance(&empty, 100.0));
+    }
+
+    #[test]
+    fn contains_point_within_tolerance() {
+        let square = square();
+        assert!(square.contains_with_tolerance(&point!(x: 5.0, y: 5.0), 0.0));
+        // on the boundary
+        assert!(!square.contains(&point!(x: 10.0, y: 5.0)));
+        assert!(square.contains_with_tolerance(&point!(x: 10.0, y: 5.0), 0.0));
+        // just outside of a corner
+        assert!(square.contains_with_tolerance(&point!(x: 10.0 + 1e-7, y: 10.0 + 1e-7), 1e-6));
+        assert!(!square.contains_with_tolerance(&point!(x: 10.0 + 1e-6, y: 10.0 + 1e-6), 1e-6));
+
+        let line_string = line_string![(x: 0.0, y: 0.0), (x: 10.0, y: 0.0)];
+        assert!(line_string.contains_with_tolerance(&point!(x: 3.0, y: 1e-9), 1e-6));
+        assert!(!line_string.contains_with_tolerance(&point!(x: 3.0, y: 1e-3), 1e-6));
+    }
+
+    #[test]
+    fn contains_segment_dipping_outside() {
+        let square = square();
+        // both end points are inside, but the middle bulges out of the square
+        let bulging = line_string![(x: 5.0, y: 5.0), (x: 10.5, y: 6.0), (x: 5.0, y: 7.0)];
+        assert!(!square.contains_with_tolerance(&bulging, 0.1));
+        assert!(square.contains_with_tolerance(&bulging, 0.5));
+
+        // end points are within tolerance of the boundary, but the segment between them isn't
+        let notched = wkt!(POLYGON((0. 0.,10. 0.,10. 4.,5. 5.,10. 6.,10. 10.,0. 10.,0. 0.)));
+        let across_notch = wkt!(LINESTRING(10. 4.,10. 6.));
+        assert!(!notched.contains_with_tolerance(&across_notch, 0.1));
+        assert!(notched.contains_with_tolerance(&across_notch, 5.0));
+    }
+
+    #[test]
+    fn contains_polygon_with_noisy_shared_boundary() {
+        let square = square();
+        let noisy = wkt!(POLYGON((0. 0.,10.0000001 0.,10. 10.,0. 10.,0. 0.)));
+        assert!(!square.contains(&noisy));
+        assert!(square.contains_with_tolerance(&noisy, 1e-6));
+        assert!(noisy.is_within_with_tolerance(&square, 1e-6));
+        assert!(!noisy.is_within_with_tolerance(&square, 1e-9));
+    }
+
+    #[test]
+    fn contains_polygon_overlapping_hole() {
+        let holed =
+            wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),(4. 4.,6. 4.,6. 6.,4. 6.,4. 4.)));
+        let around_hole = wkt!(POLYGON((2. 2.,8. 2.,8. 8.,2. 8.,2. 2.)));
+        assert!(!holed.contains(&around_hole));
+        assert!(!holed.contains_with_tolerance(&around_hole, 1e-9));
+        // the hole is narrower than the tolerance
+        assert!(holed.contains_with_tolerance(&around_hole, 2.0));
+
+        let beside_hole = wkt!(POLYGON((1. 1.,3. 1.,3. 3.,1. 3.,1. 1.)));
+        assert!(holed.contains_with_tolerance(&beside_hole, 1e-9));
+    }
+
+    #[test]
+    fn nan_coordinates_do_not_panic() {
+        let square = square();
+        let nan = line_string![(x: 5.0, y: 5.0), (x: f64::NAN, y: 5.0)];
+        assert!(!square.contains_with_tolerance(&nan, 1e-6));
+    }
+
+    #[test]
+    fn nan_intervals_do_not_panic() {
+        assert!(covers_unit_interval(vec![(f64::NAN, 0.5), (0.0, 1.0)]));
+        assert!(!covers_unit_interval(vec![
+            (0.5, f64::NAN),
+            (0.0, 0.5),
+            (0.6, 1.0)
+        ]));
+        assert!(!covers_unit_interval(vec![(f64::NAN, f64::NAN)]));
+    }
+
+    #[test]
+    fn empty_is_never_contained() {
+        let empty: LineString = line_string![];
+        assert!(!square().contains_with_tolerance(&empty, 1.0));
+        assert!(!empty.contains_with_tolerance(&square(), 1.0));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{coord, CoordFloat, CoordsIter, Polygon, Triangle};
+use crate::{coord, CoordFloat, CoordsIter, Polygon, Rect, Triangle};
 
 /// Triangulate polygons using an [ear-cutting algorithm](https://www.geometrictools.com/Documentation/TriangulationByEarClipping.pdf).
 ///
@@ -132,6 +132,40 @@ impl<T: CoordFloat> TriangulateEarcut<T> for Polygon<T> {
     }
 }
 
+/// A `Triangle` is its own triangulation, unless it has no area.
+impl<T: CoordFloat> TriangulateEarcut<T> for Triangle<T> {
+    fn earcut_triangles_raw(&self) -> RawTriangulation<T> {
+        let Triangle(a, b, c) = *self;
+        let triangle_indices = if (b - a).x * (c - a).y == (b - a).y * (c - a).x {
+            vec![]
+        } else {
+            // `Iter` takes the indices from the end, so this yields the triangle itself
+            vec![2, 1, 0]
+        };
+        RawTriangulation {
+            vertices: vec![a.x, a.y, b.x, b.y, c.x, c.y],
+            triangle_indices,
+        }
+    }
+}
+
+/// A `Rect` is split along a diagonal into two triangles, unless it has no area.
+impl<T: CoordFloat> TriangulateEarcut<T> for Rect<T> {
+    fn earcut_triangles_raw(&self) -> RawTriangulation<T> {
+        let (min, max) = (self.min(), self.max());
+        let triangle_indices = if self.width() == T::zero() || self.height() == T::zero() {
+            vec![]
+        } else {
+            vec![0, 1, 2, 2, 3, 0]
+        };
+        RawTriangulation {
+            // In the order of `Rect::to_polygon`
+            vertices: vec![min.x, min.y, min.x, max.y, max.x, max.y, max.x, min.y],
+            triangle_indices,
+        }
+    }
+}
+
 /// The raw result of triangulating a polygon from `earcutr`.
 #[derive(Debug, PartialEq, Clone)]
 pub struct RawTriangulation<T: CoordFloat> {
@@ -258,4 +292,29 @@ mod test {
             triangles,
         );
     }
+
+    #[test]
+    fn test_rect_and_triangle() {
+        use crate::{Area, Rect};
+
+        let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 2. });
+        let triangles = rect.earcut_triangles();
+        assert_eq!(triangles.len(), 2);
+        assert_eq!(triangles.iter().map(|t| t.unsigned_area()).sum::<f64>(), 8.);
+        let flat = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 0. });
+        assert!(flat.earcut_triangles().is_empty());
+
+        let triangle = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 4., y: 0. },
+            coord! { x: 0., y: 2. },
+        );
+        assert_eq!(triangle.earcut_triangles(), vec![triangle]);
+        let flat = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 2., y: 2. },
+        );
+        assert!(flat.earcut_triangles().is_empty());
+    }
 }
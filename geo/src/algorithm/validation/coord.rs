@@ -3,6 +3,7 @@ use crate::{Coord, GeoFloat};
 
 use std::fmt;
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum InvalidCoord {
     /// A valid [`Coord`] must be finite.
@@ -4,6 +4,7 @@ use super::{
     InvalidMultiPoint, InvalidMultiPolygon, InvalidPoint, InvalidPolygon, InvalidRect,
     InvalidTriangle,
 };
+use crate::cancellation::{CancellationToken, Cancelled};
 use crate::{GeoFloat, Geometry};
 
 use crate::geometry_cow::GeometryCow;
@@ -11,6 +12,7 @@ use std::fmt;
 
 /// A [`Geometry`] is valid if its inner variant is valid.
 /// e.g. `Geometry::Polygon(polygon)` is valid if and only if `polygon` is valid.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum InvalidGeometry {
     InvalidPoint(InvalidPoint),
@@ -100,6 +102,54 @@ impl<F: GeoFloat> Validation for Geometry<F> {
         }
         Ok(())
     }
+
+    fn validation_errors_with_progress(
+        &self,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Vec<Self::Error> {
+        match self {
+            Geometry::MultiPolygon(g) => g
+                .validation_errors_with_progress(progress)
+                .into_iter()
+                .map(InvalidGeometry::InvalidMultiPolygon)
+                .collect(),
+            Geometry::GeometryCollection(g) => g
+                .validation_errors_with_progress(progress)
+                .into_iter()
+                .map(InvalidGeometry::InvalidGeometryCollection)
+                .collect(),
+            _ => {
+                let validation_errors = self.validation_errors();
+                progress(1, 1);
+                validation_errors
+            }
+        }
+    }
+
+    fn visit_validation_cancellable<T: From<Cancelled>>(
+        &self,
+        mut handle_validation_error: Box<dyn FnMut(Self::Error) -> Result<(), T> + '_>,
+        token: &impl CancellationToken,
+    ) -> Result<(), T> {
+        // Only collections check `token` while validating, other geometries need only be
+        // checked once up front
+        match self {
+            Geometry::MultiPolygon(g) => g.visit_validation_cancellable(
+                Box::new(|err| handle_validation_error(InvalidGeometry::InvalidMultiPolygon(err))),
+                token,
+            ),
+            Geometry::GeometryCollection(g) => g.visit_validation_cancellable(
+                Box::new(|err| {
+                    handle_validation_error(InvalidGeometry::InvalidGeometryCollection(err))
+                }),
+                token,
+            ),
+            _ => {
+                token.check_cancelled()?;
+                self.visit_validation(handle_validation_error)
+            }
+        }
+    }
 }
 
 impl<F: GeoFloat> Validation for GeometryCow<'_, F> {
@@ -1,9 +1,11 @@
 use super::{GeometryIndex, InvalidGeometry, Validation};
+use crate::cancellation::{CancellationToken, Cancelled};
 use crate::{GeoFloat, GeometryCollection};
 
 use std::fmt;
 
 /// A [`GeometryCollection`] is valid if all its elements are valid.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum InvalidGeometryCollection {
     /// Which element is invalid, and what was invalid about it.
@@ -42,6 +44,40 @@ impl<F: GeoFloat> Validation for GeometryCollection<F> {
         }
         Ok(())
     }
+
+    fn validation_errors_with_progress(
+        &self,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Vec<Self::Error> {
+        let mut validation_errors = Vec::new();
+        for (i, geometry) in self.0.iter().enumerate() {
+            validation_errors.extend(geometry.validation_errors().into_iter().map(|err| {
+                InvalidGeometryCollection::InvalidGeometry(GeometryIndex(i), Box::new(err))
+            }));
+            progress(i + 1, self.0.len());
+        }
+        validation_errors
+    }
+
+    fn visit_validation_cancellable<T: From<Cancelled>>(
+        &self,
+        mut handle_validation_error: Box<dyn FnMut(Self::Error) -> Result<(), T> + '_>,
+        token: &impl CancellationToken,
+    ) -> Result<(), T> {
+        for (i, geometry) in self.0.iter().enumerate() {
+            geometry.visit_validation_cancellable(
+                Box::new(&mut |geometry_err| {
+                    let err = InvalidGeometryCollection::InvalidGeometry(
+                        GeometryIndex(i),
+                        Box::new(geometry_err),
+                    );
+                    handle_validation_error(err)
+                }),
+                token,
+            )?;
+        }
+        Ok(())
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -26,9 +26,12 @@ pub use multi_point::InvalidMultiPoint;
 pub use multi_polygon::InvalidMultiPolygon;
 pub use point::InvalidPoint;
 pub use polygon::InvalidPolygon;
+#[cfg(feature = "multithreading")]
+pub(crate) use polygon::{ring_errors, rings, RingChecks};
 pub use rect::InvalidRect;
 pub use triangle::InvalidTriangle;
 
+use crate::cancellation::{CancellationToken, Cancelled};
 use std::boxed::Box;
 use std::fmt;
 
@@ -98,10 +101,99 @@ pub trait Validation {
         &self,
         handle_validation_error: Box<dyn FnMut(Self::Error) -> Result<(), T> + '_>,
     ) -> Result<(), T>;
+
+    /// Like [`validation_errors`](Self::validation_errors), but returns [`Cancelled`] if `token`
+    /// is set before validation completes.
+    fn validation_errors_cancellable(
+        &self,
+        token: &impl CancellationToken,
+    ) -> Result<Vec<Self::Error>, Cancelled> {
+        let mut validation_errors = Vec::new();
+
+        self.visit_validation_cancellable(
+            Box::new(|problem| {
+                validation_errors.push(problem);
+                Ok::<(), Cancelled>(())
+            }),
+            token,
+        )?;
+
+        Ok(validation_errors)
+    }
+
+    /// Like [`check_validation`](Self::check_validation), but returns [`Cancelled`] if `token`
+    /// is set before validation completes.
+    fn check_validation_cancellable(
+        &self,
+        token: &impl CancellationToken,
+    ) -> Result<Result<(), Self::Error>, Cancelled> {
+        match self.visit_validation_cancellable(
+            Box::new(|problem| Err(CheckValidation::Invalid(problem))),
+            token,
+        ) {
+            Ok(()) => Ok(Ok(())),
+            Err(CheckValidation::Invalid(problem)) => Ok(Err(problem)),
+            Err(CheckValidation::Cancelled) => Err(Cancelled),
+        }
+    }
+
+    /// Like [`validation_errors`](Self::validation_errors), but calls `progress` with the number
+    /// of items validated so far and the total number of items.
+    ///
+    /// Collections report progress after validating each of their members. Other geometries are
+    /// a single item, reported once validation is complete.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{wkt, Validation};
+    ///
+    /// let collection = wkt!(GEOMETRYCOLLECTION(POINT(0. 0.),LINESTRING(0. 0.,0. 0.),POINT(1. 1.)));
+    /// let mut reported = vec![];
+    /// let errors = collection.validation_errors_with_progress(|done, total| {
+    ///     reported.push((done, total))
+    /// });
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(reported, vec![(1, 3), (2, 3), (3, 3)]);
+    /// ```
+    fn validation_errors_with_progress(
+        &self,
+        mut progress: impl FnMut(usize, usize),
+    ) -> Vec<Self::Error> {
+        let validation_errors = self.validation_errors();
+        progress(1, 1);
+        validation_errors
+    }
+
+    /// Like [`visit_validation`](Self::visit_validation), but returns early if `token` is set.
+    ///
+    /// By default `token` is only checked before validating the geometry. Collections, whose
+    /// validation can take much longer, also check it before validating each of their members.
+    fn visit_validation_cancellable<T: From<Cancelled>>(
+        &self,
+        handle_validation_error: Box<dyn FnMut(Self::Error) -> Result<(), T> + '_>,
+        token: &impl CancellationToken,
+    ) -> Result<(), T> {
+        token.check_cancelled()?;
+        self.visit_validation(handle_validation_error)
+    }
+}
+
+/// Why [`Validation::check_validation_cancellable`] stopped.
+enum CheckValidation<E> {
+    Invalid(E),
+    Cancelled,
+}
+
+impl<E> From<Cancelled> for CheckValidation<E> {
+    fn from(_: Cancelled) -> Self {
+        CheckValidation::Cancelled
+    }
 }
 
 /// The role of a ring in a [`Polygon`](crate::Polygon).
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum RingRole {
     Exterior,
     Interior(usize),
@@ -117,10 +209,12 @@ impl fmt::Display for RingRole {
 }
 
 /// The position of the problem in a multi-geometry, starting at 0.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub struct GeometryIndex(pub usize);
 
 /// The index of the coordinate in the geometry
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct CoordIndex(pub usize);
 
@@ -1,4 +1,5 @@
 use super::{GeometryIndex, InvalidPolygon, Validation};
+use crate::cancellation::{CancellationToken, Cancelled};
 use crate::coordinate_position::CoordPos;
 use crate::dimensions::Dimensions;
 use crate::{GeoFloat, MultiPolygon, Relate};
@@ -9,6 +10,7 @@ use std::fmt;
 /// - [x] all its polygons are valid,
 /// - [x] elements do not overlaps (i.e. their interiors must not intersect)
 /// - [x] elements touch only at points
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum InvalidMultiPolygon {
     /// For a [`MultiPolygon`] to be valid, each member [`Polygon`](crate::Polygon) must be valid.
@@ -46,36 +48,77 @@ impl<F: GeoFloat> Validation for MultiPolygon<F> {
 
     fn visit_validation<T>(
         &self,
-        mut handle_validation_error: Box<dyn FnMut(Self::Error) -> Result<(), T> + '_>,
+        handle_validation_error: Box<dyn FnMut(Self::Error) -> Result<(), T> + '_>,
     ) -> Result<(), T> {
-        for (i, polygon) in self.0.iter().enumerate() {
-            polygon.visit_validation(Box::new(&mut |invalid_polygon| {
-                handle_validation_error(InvalidMultiPolygon::InvalidPolygon(
-                    GeometryIndex(i),
-                    invalid_polygon,
-                ))
-            }))?;
-
-            // Special case for MultiPolygon: elements must not overlap and must touch only at points
-            for (j, pol2) in self.0.iter().enumerate().skip(i + 1) {
-                let im = polygon.relate(pol2);
-                if im.get(CoordPos::Inside, CoordPos::Inside) == Dimensions::TwoDimensional {
-                    let err =
-                        InvalidMultiPolygon::ElementsOverlaps(GeometryIndex(i), GeometryIndex(j));
-                    handle_validation_error(err)?;
-                }
-                if im.get(CoordPos::OnBoundary, CoordPos::OnBoundary) == Dimensions::OneDimensional
-                {
-                    let err = InvalidMultiPolygon::ElementsTouchOnALine(
-                        GeometryIndex(i),
-                        GeometryIndex(j),
-                    );
-                    handle_validation_error(err)?;
-                }
+        visit_multi_polygon_validation(self, handle_validation_error, || Ok(()), |_, _| {})
+    }
+
+    fn validation_errors_with_progress(
+        &self,
+        progress: impl FnMut(usize, usize),
+    ) -> Vec<Self::Error> {
+        let mut validation_errors = Vec::new();
+        visit_multi_polygon_validation(
+            self,
+            Box::new(|problem| {
+                validation_errors.push(problem);
+                Ok::<(), Self::Error>(())
+            }),
+            || Ok(()),
+            progress,
+        )
+        .expect("no errors are returned");
+        validation_errors
+    }
+
+    fn visit_validation_cancellable<T: From<Cancelled>>(
+        &self,
+        handle_validation_error: Box<dyn FnMut(Self::Error) -> Result<(), T> + '_>,
+        token: &impl CancellationToken,
+    ) -> Result<(), T> {
+        visit_multi_polygon_validation(
+            self,
+            handle_validation_error,
+            || Ok(token.check_cancelled()?),
+            |_, _| {},
+        )
+    }
+}
+
+/// Validate `multi_polygon`, calling `check_cancelled` before each (pairwise) check, and
+/// `progress` once all the checks of each polygon are done.
+fn visit_multi_polygon_validation<F: GeoFloat, T>(
+    multi_polygon: &MultiPolygon<F>,
+    mut handle_validation_error: Box<dyn FnMut(InvalidMultiPolygon) -> Result<(), T> + '_>,
+    mut check_cancelled: impl FnMut() -> Result<(), T>,
+    mut progress: impl FnMut(usize, usize),
+) -> Result<(), T> {
+    for (i, polygon) in multi_polygon.0.iter().enumerate() {
+        check_cancelled()?;
+        polygon.visit_validation(Box::new(&mut |invalid_polygon| {
+            handle_validation_error(InvalidMultiPolygon::InvalidPolygon(
+                GeometryIndex(i),
+                invalid_polygon,
+            ))
+        }))?;
+
+        // Special case for MultiPolygon: elements must not overlap and must touch only at points
+        for (j, pol2) in multi_polygon.0.iter().enumerate().skip(i + 1) {
+            check_cancelled()?;
+            let im = polygon.relate(pol2);
+            if im.get(CoordPos::Inside, CoordPos::Inside) == Dimensions::TwoDimensional {
+                let err = InvalidMultiPolygon::ElementsOverlaps(GeometryIndex(i), GeometryIndex(j));
+                handle_validation_error(err)?;
+            }
+            if im.get(CoordPos::OnBoundary, CoordPos::OnBoundary) == Dimensions::OneDimensional {
+                let err =
+                    InvalidMultiPolygon::ElementsTouchOnALine(GeometryIndex(i), GeometryIndex(j));
+                handle_validation_error(err)?;
             }
         }
-        Ok(())
+        progress(i + 1, multi_polygon.0.len());
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -118,4 +161,69 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_multipolygon_validation_cancellable() {
+        let multi_polygon = wkt!(
+            MULTIPOLYGON (
+                ((0. 0., 2. 0., 2. 2., 0. 2., 0. 0.)),
+                ((1. 1., 3. 1., 3. 3., 1. 3., 1. 1.)),
+                ((5. 5., 6. 5., 6. 6., 5. 6., 5. 5.))
+            )
+        );
+        assert_eq!(
+            multi_polygon.check_validation_cancellable(&|| false),
+            Ok(Err(InvalidMultiPolygon::ElementsOverlaps(
+                GeometryIndex(0),
+                GeometryIndex(1)
+            )))
+        );
+        assert_eq!(
+            multi_polygon.validation_errors_cancellable(&|| false),
+            Ok(multi_polygon.validation_errors())
+        );
+
+        // cancelled part way through the pairwise checks
+        let checks = std::cell::Cell::new(0);
+        let token = || {
+            checks.set(checks.get() + 1);
+            checks.get() > 2
+        };
+        assert_eq!(
+            multi_polygon.validation_errors_cancellable(&token),
+            Err(Cancelled)
+        );
+        assert_eq!(checks.get(), 3);
+
+        let collection = crate::GeometryCollection::new_from(vec![multi_polygon.into()]);
+        assert_eq!(
+            collection.check_validation_cancellable(&|| true),
+            Err(Cancelled)
+        );
+        assert!(collection
+            .check_validation_cancellable(&|| false)
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn test_multipolygon_validation_with_progress() {
+        let multi_polygon = wkt!(
+            MULTIPOLYGON (
+                ((0. 0., 2. 0., 2. 2., 0. 2., 0. 0.)),
+                ((1. 1., 3. 1., 3. 3., 1. 3., 1. 1.)),
+                ((5. 5., 6. 5., 6. 6., 5. 6., 5. 5.))
+            )
+        );
+        let mut reported = vec![];
+        let errors = multi_polygon
+            .validation_errors_with_progress(|done, total| reported.push((done, total)));
+        assert_eq!(errors, multi_polygon.validation_errors());
+        assert_eq!(reported, vec![(1, 3), (2, 3), (3, 3)]);
+
+        let mut reported = vec![];
+        let geometry = crate::Geometry::MultiPolygon(multi_polygon);
+        geometry.validation_errors_with_progress(|done, total| reported.push((done, total)));
+        assert_eq!(reported, vec![(1, 3), (2, 3), (3, 3)]);
+    }
 }
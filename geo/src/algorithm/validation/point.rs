@@ -3,6 +3,7 @@ use crate::{GeoFloat, Point};
 
 use std::fmt;
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum InvalidPoint {
     /// A valid [`Point`] must be finite.
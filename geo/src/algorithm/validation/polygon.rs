@@ -1,7 +1,10 @@
 use super::{utils, CoordIndex, RingRole, Validation};
 use crate::coordinate_position::CoordPos;
 use crate::dimensions::Dimensions;
-use crate::{GeoFloat, HasDimensions, Polygon, Relate};
+use crate::{
+    BoundingRect, GeoFloat, HasDimensions, Intersects, LineString, Polygon, PreparedGeometry, Rect,
+    Relate,
+};
 
 use std::fmt;
 
@@ -13,6 +16,7 @@ use std::fmt;
 /// - [ ] the polygon interior is simply connected (i.e. the rings must not touch in a way that splits the polygon into more than one part)
 ///
 /// Note: the simple connectivity of the interior is not checked by this implementation.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum InvalidPolygon {
     /// A ring must have at least 4 points to be valid. Note that, in order to close the ring, the first and final points will be identical.
@@ -67,93 +71,180 @@ impl<F: GeoFloat> Validation for Polygon<F> {
             return Ok(());
         }
 
-        for (ring_idx, ring) in std::iter::once(self.exterior())
-            .chain(self.interiors().iter())
-            .enumerate()
-        {
-            if ring.is_empty() {
-                continue;
+        for (ring_role, ring) in rings(self) {
+            for error in ring_errors(ring_role, ring) {
+                handle_validation_error(error)?;
             }
+        }
+
+        let ring_checks = RingChecks::new(self);
+        let exterior = ring_checks.prepared_exterior();
+        for index in 0..ring_checks.len() {
+            for error in ring_checks.interior_errors(&exterior, index) {
+                handle_validation_error(error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The rings of a polygon, with their roles.
+pub(crate) fn rings<F: GeoFloat>(
+    polygon: &Polygon<F>,
+) -> impl Iterator<Item = (RingRole, &LineString<F>)> {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors().iter())
+        .enumerate()
+        .map(|(ring_idx, ring)| {
             let ring_role = if ring_idx == 0 {
                 RingRole::Exterior
             } else {
                 RingRole::Interior(ring_idx - 1)
             };
+            (ring_role, ring)
+        })
+}
 
-            // Perform the various checks
-            if utils::check_too_few_points(ring, true) {
-                handle_validation_error(InvalidPolygon::TooFewPointsInRing(ring_role))?;
-            }
+/// The errors of a single ring of a polygon.
+pub(crate) fn ring_errors<F: GeoFloat>(
+    ring_role: RingRole,
+    ring: &LineString<F>,
+) -> Vec<InvalidPolygon> {
+    let mut errors = Vec::new();
+    if ring.is_empty() {
+        return errors;
+    }
 
-            if utils::linestring_has_self_intersection(ring) {
-                handle_validation_error(InvalidPolygon::SelfIntersection(ring_role))?;
-            }
+    // Perform the various checks
+    if utils::check_too_few_points(ring, true) {
+        errors.push(InvalidPolygon::TooFewPointsInRing(ring_role));
+    }
+
+    if utils::linestring_has_self_intersection(ring) {
+        errors.push(InvalidPolygon::SelfIntersection(ring_role));
+    }
 
-            for (coord_idx, coord) in ring.0.iter().enumerate() {
-                if utils::check_coord_is_not_finite(coord) {
-                    handle_validation_error(InvalidPolygon::NonFiniteCoord(
-                        ring_role,
-                        CoordIndex(coord_idx),
-                    ))?;
+    for (coord_idx, coord) in ring.0.iter().enumerate() {
+        if utils::check_coord_is_not_finite(coord) {
+            errors.push(InvalidPolygon::NonFiniteCoord(
+                ring_role,
+                CoordIndex(coord_idx),
+            ));
+        }
+    }
+    errors
+}
+
+/// The checks between the rings of a polygon, which dominate the cost of validating polygons
+/// with many interior rings.
+pub(crate) struct RingChecks<F: GeoFloat> {
+    exterior: Polygon<F>,
+    interiors: Vec<Polygon<F>>,
+    // For each interior ring, the later interior rings whose bounding rects intersect its own.
+    // Rings with disjoint bounding rects can't intersect, so the other pairs needn't be checked.
+    candidates: Vec<Vec<usize>>,
+}
+
+impl<F: GeoFloat> RingChecks<F> {
+    pub(crate) fn new(polygon: &Polygon<F>) -> Self {
+        let interiors: Vec<_> = polygon
+            .interiors()
+            .iter()
+            .map(|interior| Polygon::new(interior.clone(), vec![]))
+            .collect();
+
+        // Sweep the bounding rects from west to east
+        let mut rects: Vec<(usize, Rect<F>)> = interiors
+            .iter()
+            .enumerate()
+            .filter_map(|(index, interior)| Some((index, interior.bounding_rect()?)))
+            .collect();
+        rects.sort_by(|(_, a), (_, b)| a.min().x.total_cmp(&b.min().x));
+        let mut candidates = vec![Vec::new(); interiors.len()];
+        for (position, (index_1, rect_1)) in rects.iter().enumerate() {
+            for (index_2, rect_2) in &rects[position + 1..] {
+                if rect_2.min().x > rect_1.max().x {
+                    break;
+                }
+                if rect_1.intersects(rect_2) {
+                    candidates[*index_1.min(index_2)].push(*index_1.max(index_2));
                 }
             }
         }
+        for candidates in &mut candidates {
+            candidates.sort_unstable();
+        }
 
-        let polygon_exterior = Polygon::new(self.exterior().clone(), vec![]);
+        Self {
+            exterior: Polygon::new(polygon.exterior().clone(), vec![]),
+            interiors,
+            candidates,
+        }
+    }
 
-        for (interior_1_idx, interior_1) in self.interiors().iter().enumerate() {
-            let ring_role_1 = RingRole::Interior(interior_1_idx);
-            if interior_1.is_empty() {
-                continue;
-            }
-            let exterior_vs_interior = polygon_exterior.relate(interior_1);
+    /// The number of interior rings.
+    pub(crate) fn len(&self) -> usize {
+        self.interiors.len()
+    }
 
-            if !exterior_vs_interior.is_contains() {
-                handle_validation_error(InvalidPolygon::InteriorRingNotContainedInExteriorRing(
-                    ring_role_1,
-                ))?;
-            }
+    pub(crate) fn prepared_exterior(&self) -> PreparedGeometry<'_, F> {
+        PreparedGeometry::from(&self.exterior)
+    }
 
-            // Interior ring and exterior ring may only touch at point (not as a line)
-            // and not cross
-            if exterior_vs_interior.get(CoordPos::OnBoundary, CoordPos::Inside)
-                == Dimensions::OneDimensional
+    /// The errors between the interior ring at `index` and the exterior ring, or the later
+    /// interior rings.
+    pub(crate) fn interior_errors(
+        &self,
+        exterior: &PreparedGeometry<F>,
+        index: usize,
+    ) -> Vec<InvalidPolygon> {
+        let mut errors = Vec::new();
+        let ring_role_1 = RingRole::Interior(index);
+        let interior_1 = &self.interiors[index];
+        if interior_1.exterior().is_empty() {
+            return errors;
+        }
+        let exterior_vs_interior = exterior.relate(interior_1.exterior());
+
+        if !exterior_vs_interior.is_contains() {
+            errors.push(InvalidPolygon::InteriorRingNotContainedInExteriorRing(
+                ring_role_1,
+            ));
+        }
+
+        // Interior ring and exterior ring may only touch at point (not as a line)
+        // and not cross
+        if exterior_vs_interior.get(CoordPos::OnBoundary, CoordPos::Inside)
+            == Dimensions::OneDimensional
+        {
+            errors.push(InvalidPolygon::IntersectingRingsOnALine(
+                RingRole::Exterior,
+                ring_role_1,
+            ));
+        }
+
+        for &index_2 in &self.candidates[index] {
+            let ring_role_2 = RingRole::Interior(index_2);
+            let intersection_matrix = interior_1.relate(&self.interiors[index_2]);
+
+            if intersection_matrix.get(CoordPos::Inside, CoordPos::Inside)
+                == Dimensions::TwoDimensional
             {
-                handle_validation_error(InvalidPolygon::IntersectingRingsOnALine(
-                    RingRole::Exterior,
+                errors.push(InvalidPolygon::IntersectingRingsOnAnArea(
                     ring_role_1,
-                ))?;
+                    ring_role_2,
+                ));
             }
-
-            // PERF: consider using PreparedGeometry
-            let interior_1_as_poly = Polygon::new(interior_1.clone(), vec![]);
-
-            for (interior_2_idx, interior_2) in
-                self.interiors().iter().enumerate().skip(interior_1_idx + 1)
+            if intersection_matrix.get(CoordPos::OnBoundary, CoordPos::OnBoundary)
+                == Dimensions::OneDimensional
             {
-                let ring_role_2 = RingRole::Interior(interior_2_idx);
-                let interior_2_as_poly = Polygon::new(interior_2.clone(), vec![]);
-                let intersection_matrix = interior_1_as_poly.relate(&interior_2_as_poly);
-
-                if intersection_matrix.get(CoordPos::Inside, CoordPos::Inside)
-                    == Dimensions::TwoDimensional
-                {
-                    handle_validation_error(InvalidPolygon::IntersectingRingsOnAnArea(
-                        ring_role_1,
-                        ring_role_2,
-                    ))?;
-                }
-                if intersection_matrix.get(CoordPos::OnBoundary, CoordPos::OnBoundary)
-                    == Dimensions::OneDimensional
-                {
-                    handle_validation_error(InvalidPolygon::IntersectingRingsOnALine(
-                        ring_role_1,
-                        ring_role_2,
-                    ))?;
-                }
+                errors.push(InvalidPolygon::IntersectingRingsOnALine(
+                    ring_role_1,
+                    ring_role_2,
+                ));
             }
         }
-        Ok(())
+        errors
     }
 }
 
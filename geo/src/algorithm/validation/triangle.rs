@@ -3,6 +3,7 @@ use crate::{CoordFloat, Triangle};
 
 use std::fmt;
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum InvalidTriangle {
     /// A valid [`Triangle`] must have finite coordinates.
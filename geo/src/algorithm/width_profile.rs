@@ -0,0 +1,147 @@
+use rstar::primitives::CachedEnvelope;
+use rstar::RTree;
+
+use crate::line_measures::{Distance, Euclidean, Length};
+use crate::triangulate_spade::SpadeTriangulationFloat;
+use crate::{Line, MedialAxis, Point, Polygon};
+
+/// Measures the width of an elongated polygon at stations along its centerline, e.g. for
+/// quality checks of road or river polygons.
+///
+/// The centerline is the trunk of the [medial axis](MedialAxis) of the polygon, with
+/// boundary points at most `max_spacing` apart, after pruning every side branch. Where holes
+/// leave several paths, the longest is used. Like the medial axis, the centerline stops short of
+/// the ends of the polygon by about half their width.
+///
+/// Stations are placed every `station_spacing` along the centerline, from one end of it. The
+/// width at a station is twice its distance to the boundary, i.e. the diameter of the largest
+/// circle around the station which fits within the polygon. Unlike a cross-section, this
+/// width isn't inflated where the section would cut across a bend or a side branch.
+///
+/// Returns each station and the width there, or an empty `Vec` if the polygon has no area.
+///
+/// # Panics
+///
+/// Panics if `max_spacing` or `station_spacing` isn't positive.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Polygon, WidthProfile};
+///
+/// // A river which narrows from 4 to 2 units wide
+/// let river: Polygon = wkt!(POLYGON((0. 0.,10. 0.,20. 1.,20. 3.,10. 4.,0. 4.,0. 0.)));
+///
+/// let profile = river.width_profile(0.1, 1.);
+/// let width_near = |x: f64| {
+///     let (_, width) = profile
+///         .iter()
+///         .min_by(|(a, _), (b, _)| (a.x() - x).abs().total_cmp(&(b.x() - x).abs()))
+///         .unwrap();
+///     *width
+/// };
+/// assert!((width_near(5.) - 4.).abs() < 0.1);
+/// assert!((width_near(18.) - 2.4).abs() < 0.1);
+/// ```
+pub trait WidthProfile<T: SpadeTriangulationFloat> {
+    fn width_profile(&self, max_spacing: T, station_spacing: T) -> Vec<(Point<T>, T)>;
+}
+
+impl<T: SpadeTriangulationFloat> WidthProfile<T> for Polygon<T> {
+    fn width_profile(&self, max_spacing: T, station_spacing: T) -> Vec<(Point<T>, T)> {
+        assert!(station_spacing > T::zero());
+        let skeleton = self.medial_axis(max_spacing, T::infinity());
+        let Some(centerline) = skeleton
+            .iter()
+            .max_by(|a, b| a.length(&Euclidean).total_cmp(&b.length(&Euclidean)))
+        else {
+            return Vec::new();
+        };
+
+        let boundary = RTree::bulk_load(
+            std::iter::once(self.exterior())
+                .chain(self.interiors())
+                .flat_map(|ring| ring.lines())
+                .map(CachedEnvelope::new)
+                .collect(),
+        );
+        let width_at = |station: Point<T>| {
+            let nearest = boundary
+                .nearest_neighbor(&station)
+                .expect("a polygon with area has a boundary");
+            let distance = Euclidean.distance(nearest as &Line<T>, &station);
+            (station, distance + distance)
+        };
+
+        let mut profile = Vec::new();
+        // The distance along the centerline to the start of `line`, and the index of the next
+        // station
+        let mut travelled = T::zero();
+        let mut station_idx = T::zero();
+        for line in centerline.lines() {
+            let length = line.length(&Euclidean);
+            loop {
+                let station_distance = station_spacing * station_idx;
+                if station_distance > travelled + length {
+                    break;
+                }
+                let ratio = if length > T::zero() {
+                    (station_distance - travelled) / length
+                } else {
+                    T::zero()
+                };
+                profile.push(width_at((line.start + line.delta() * ratio).into()));
+                station_idx = station_idx + T::one();
+            }
+            travelled = travelled + length;
+        }
+        profile
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, LineString};
+
+    #[test]
+    fn corridor() {
+        let corridor: Polygon = wkt!(POLYGON((0. 0.,20. 0.,20. 2.,0. 2.,0. 0.)));
+        let profile = corridor.width_profile(0.1, 0.7);
+        // the centerline runs from 1 to 19
+        assert_eq!(profile.len(), 26);
+        for (station, width) in profile {
+            assert!((station.y() - 1.).abs() < 1e-9);
+            assert!((width - 2.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn bend_and_hole() {
+        // an L-shaped corridor, which is wider across the bend
+        let bend: Polygon = wkt!(POLYGON((0. 0.,10. 0.,10. 10.,8. 10.,8. 2.,0. 2.,0. 0.)));
+        let profile = bend.width_profile(0.05, 0.5);
+        assert!(profile.len() > 30);
+        for (station, width) in profile {
+            if station.x() < 7.5 || station.y() > 2.5 {
+                assert!((width - 2.).abs() < 1e-6, "{width}");
+            } else {
+                assert!(width > 2. - 1e-6 && width < 2.4, "{width}");
+            }
+        }
+
+        // a ring, whose skeleton is a cycle, and which is wider across its corners
+        let ring: Polygon = wkt!(POLYGON(
+            (0. 0.,10. 0.,10. 10.,0. 10.,0. 0.),
+            (2. 2.,2. 8.,8. 8.,8. 2.,2. 2.)
+        ));
+        let profile = ring.width_profile(0.1, 1.);
+        assert!(profile.len() > 25);
+        assert!(profile
+            .iter()
+            .all(|(_, width)| *width > 2. - 1e-6 && *width < 2.4));
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert!(empty.width_profile(0.1, 1.).is_empty());
+    }
+}
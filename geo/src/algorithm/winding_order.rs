@@ -3,14 +3,18 @@ use crate::coords_iter::CoordsIter;
 use crate::utils::EitherIter;
 use crate::{CoordNum, GeoFloat, GeoNum, LineString, Point};
 use geo_types::{PointsIter, Triangle};
+use std::array;
 use std::iter::Rev;
 
 /// Iterates through a list of `Point`s
 #[allow(missing_debug_implementations)]
-pub struct Points<'a, T>(pub(crate) EitherIter<PointsIter<'a, T>, Rev<PointsIter<'a, T>>>)
+pub struct Points<'a, T>(pub(crate) EitherIter<LineStringPoints<'a, T>, TrianglePoints<T>>)
 where
     T: CoordNum + 'a;
 
+type LineStringPoints<'a, T> = EitherIter<PointsIter<'a, T>, Rev<PointsIter<'a, T>>>;
+type TrianglePoints<T> = array::IntoIter<Point<T>, 4>;
+
 impl<T> Iterator for Points<'_, T>
 where
     T: CoordNum,
@@ -55,10 +59,13 @@ impl WindingOrder {
     }
 }
 
-/// Determine and operate on how a [`LineString`] is
+/// Determine and operate on how a [`LineString`] or [`Triangle`] is
 /// wound. This functionality, and our implementation is
 /// based on [CGAL's Polygon_2::orientation].
 ///
+/// A [`Rect`](crate::Rect) has no winding order of its own: the exterior ring of
+/// [`Rect::to_polygon`](crate::Rect::to_polygon) is always clockwise.
+///
 /// [CGAL's Polygon_2::orientation]: //doc.cgal.org/latest/Polygon/classCGAL_1_1Polygon__2.html#a4ce8b4b8395406243ac16c2a120ffc15
 pub trait Winding {
     type Scalar: CoordNum;
@@ -181,8 +188,10 @@ where
     /// order, so that the resultant order makes it appear clockwise
     fn points_cw(&self) -> Points<Self::Scalar> {
         match self.winding_order() {
-            Some(WindingOrder::CounterClockwise) => Points(EitherIter::B(self.points().rev())),
-            _ => Points(EitherIter::A(self.points())),
+            Some(WindingOrder::CounterClockwise) => {
+                Points(EitherIter::A(EitherIter::B(self.points().rev())))
+            }
+            _ => Points(EitherIter::A(EitherIter::A(self.points()))),
         }
     }
 
@@ -192,8 +201,10 @@ where
     /// order, so that the resultant order makes it appear counter-clockwise
     fn points_ccw(&self) -> Points<Self::Scalar> {
         match self.winding_order() {
-            Some(WindingOrder::Clockwise) => Points(EitherIter::B(self.points().rev())),
-            _ => Points(EitherIter::A(self.points())),
+            Some(WindingOrder::Clockwise) => {
+                Points(EitherIter::A(EitherIter::B(self.points().rev())))
+            }
+            _ => Points(EitherIter::A(EitherIter::A(self.points()))),
         }
     }
 
@@ -212,27 +223,66 @@ where
     }
 }
 
-// This function can probably be converted into a trait implementation with a small refactoring of
-// the trait but this is not in scope of the PR it is added for.
-/// special cased algorithm for finding the winding of a triangle
-pub fn triangle_winding_order<T: GeoFloat>(tri: &Triangle<T>) -> Option<WindingOrder> {
-    let [a, b, c] = tri.to_array();
-    let ab = b - a;
-    let ac = c - a;
+/// The winding order of a [`Triangle`] is that of its closed ring of vertices, `[v1, v2, v3, v1]`.
+impl<T, K> Winding for Triangle<T>
+where
+    T: GeoNum<Ker = K>,
+    K: Kernel<T>,
+{
+    type Scalar = T;
+
+    fn winding_order(&self) -> Option<WindingOrder> {
+        match K::orient2d(self.0, self.1, self.2) {
+            Orientation::CounterClockwise => Some(WindingOrder::CounterClockwise),
+            Orientation::Clockwise => Some(WindingOrder::Clockwise),
+            Orientation::Collinear => None,
+        }
+    }
+
+    /// Iterate over the points of the closed ring of vertices in a clockwise order
+    fn points_cw(&self) -> Points<'_, Self::Scalar> {
+        let mut points = [self.0, self.1, self.2, self.0].map(Point);
+        if self.winding_order() == Some(WindingOrder::CounterClockwise) {
+            points.reverse();
+        }
+        Points(EitherIter::B(points.into_iter()))
+    }
 
-    let cross_prod = ab.x * ac.y - ab.y * ac.x;
+    /// Iterate over the points of the closed ring of vertices in a counter-clockwise order
+    fn points_ccw(&self) -> Points<'_, Self::Scalar> {
+        let mut points = [self.0, self.1, self.2, self.0].map(Point);
+        if self.winding_order() == Some(WindingOrder::Clockwise) {
+            points.reverse();
+        }
+        Points(EitherIter::B(points.into_iter()))
+    }
 
-    match cross_prod.total_cmp(&T::zero()) {
-        std::cmp::Ordering::Less => Some(WindingOrder::Clockwise),
-        std::cmp::Ordering::Equal => None,
-        std::cmp::Ordering::Greater => Some(WindingOrder::CounterClockwise),
+    /// Swap the last two vertices if needed, so that they are in clockwise winding order
+    fn make_cw_winding(&mut self) {
+        if let Some(WindingOrder::CounterClockwise) = self.winding_order() {
+            std::mem::swap(&mut self.1, &mut self.2);
+        }
+    }
+
+    /// Swap the last two vertices if needed, so that they are in counterclockwise winding order
+    fn make_ccw_winding(&mut self) {
+        if let Some(WindingOrder::Clockwise) = self.winding_order() {
+            std::mem::swap(&mut self.1, &mut self.2);
+        }
     }
 }
 
+/// special cased algorithm for finding the winding of a triangle
+///
+/// Equivalent to [`Winding::winding_order`], which is implemented for [`Triangle`].
+pub fn triangle_winding_order<T: GeoFloat>(tri: &Triangle<T>) -> Option<WindingOrder> {
+    tri.winding_order()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::Point;
+    use crate::{Coord, Point};
 
     #[test]
     fn robust_winding_float() {
@@ -273,4 +323,44 @@ mod test {
 
         assert_eq!(&ls.points_ccw().collect::<Vec<_>>(), &ccw_ls,);
     }
+
+    #[test]
+    fn triangle_winding() {
+        let mut triangle = Triangle::new(
+            Coord { x: 0, y: 0 },
+            Coord { x: 2, y: 0 },
+            Coord { x: 1, y: 2 },
+        );
+        assert!(triangle.is_ccw());
+        assert_eq!(
+            triangle.winding_order(),
+            triangle.to_polygon().exterior().winding_order()
+        );
+
+        let ccw_points: Vec<_> = triangle.points_ccw().collect();
+        assert_eq!(
+            ccw_points,
+            vec![
+                Point::new(0, 0),
+                Point::new(2, 0),
+                Point::new(1, 2),
+                Point::new(0, 0)
+            ]
+        );
+        let mut cw_points: Vec<_> = triangle.points_cw().collect();
+        cw_points.reverse();
+        assert_eq!(cw_points, ccw_points);
+
+        triangle.make_cw_winding();
+        assert!(triangle.is_cw());
+        assert_eq!(triangle.points_ccw().collect::<Vec<_>>().len(), 4);
+
+        let collinear = Triangle::new(
+            Coord { x: 0., y: 0. },
+            Coord { x: 1., y: 1. },
+            Coord { x: 2., y: 2. },
+        );
+        assert_eq!(collinear.winding_order(), None);
+        assert_eq!(triangle_winding_order(&collinear), None);
+    }
 }
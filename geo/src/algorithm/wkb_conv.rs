@@ -0,0 +1,1169 @@
+//! Reading and writing the [Well-Known Binary] (WKB) encoding of geometries.
+//!
+//! [`ToWkb`] writes any [`geo_traits`] geometry, including the `geo-types` geometries, as
+//! ISO WKB, or as the [EWKB] of PostGIS with an optional SRID. [`Wkb`](crate::wkb_conv::Wkb) reads
+//! either without copying the coordinates, as a [`geo_traits`] geometry which can be converted
+//! to `geo-types` with [`Wkb::to_geometry`](crate::wkb_conv::Wkb::to_geometry), or processed
+//! directly, e.g. to keep its Z and M values.
+//!
+//! `geo-types` can't represent empty points, which WKB writes as coordinates of `NaN`s, or Z
+//! and M values, which are dropped when converting to `geo-types`. A `Rect` is written as a
+//! `Polygon`, a `Triangle` as a `Polygon` of its closed ring, and a `Line` as a `LineString`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::wkb_conv::{Endianness, ToWkb, Wkb};
+//! use geo::{wkt, Geometry};
+//!
+//! let polygon = wkt!(POLYGON((0. 0.,4. 0.,4. 4.,0. 0.)));
+//! let ewkb = polygon.to_ewkb(Endianness::LittleEndian, Some(4326));
+//!
+//! let wkb = Wkb::try_new(&ewkb).unwrap();
+//! assert_eq!(wkb.srid(), Some(4326));
+//! assert_eq!(wkb.to_geometry().unwrap(), Geometry::Polygon(polygon));
+//! ```
+//!
+//! [Well-Known Binary]: https://libgeos.org/specifications/wkb/
+//! [EWKB]: https://postgis.net/docs/using_postgis_dbmanagement.html#EWKB_EWKT
+
+use std::{error, fmt};
+
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    RectTrait, TriangleTrait, UnimplementedLine, UnimplementedRect, UnimplementedTriangle,
+};
+
+use crate::{CoordNum, Geometry};
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// The deepest nesting of geometry collections which will be read, so that untrusted input
+/// can't overflow the stack.
+const MAX_NESTING_DEPTH: usize = 128;
+
+/// The byte order of the numbers of a WKB encoding.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Most significant byte first, marked by a `0` byte.
+    BigEndian,
+    /// Least significant byte first, marked by a `1` byte. The byte order of most platforms.
+    #[default]
+    LittleEndian,
+}
+
+impl Endianness {
+    fn read_u32(self, bytes: &[u8]) -> u32 {
+        let bytes = bytes[..4].try_into().unwrap();
+        match self {
+            Endianness::BigEndian => u32::from_be_bytes(bytes),
+            Endianness::LittleEndian => u32::from_le_bytes(bytes),
+        }
+    }
+
+    fn read_f64(self, bytes: &[u8]) -> f64 {
+        let bytes = bytes[..8].try_into().unwrap();
+        match self {
+            Endianness::BigEndian => f64::from_be_bytes(bytes),
+            Endianness::LittleEndian => f64::from_le_bytes(bytes),
+        }
+    }
+}
+
+/// An error reading WKB, with the byte offset at which it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WkbError {
+    /// The input ended before the geometry did.
+    UnexpectedEof { offset: usize },
+    /// The byte order marker is neither `0` nor `1`.
+    InvalidByteOrder { offset: usize, byte: u8 },
+    /// The geometry type is unknown, or isn't allowed, e.g. a polygon in a `MultiPoint`.
+    InvalidGeometryType { offset: usize, code: u32 },
+    /// The input continues after the geometry.
+    TrailingBytes { offset: usize },
+    /// The geometry has an empty point, which `geo-types` can't represent.
+    EmptyPoint,
+    /// Geometry collections are nested more deeply than can be read.
+    NestingTooDeep { offset: usize },
+}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WkbError::UnexpectedEof { offset } => {
+                write!(f, "unexpected end of WKB at byte {offset}")
+            }
+            WkbError::InvalidByteOrder { offset, byte } => {
+                write!(f, "invalid WKB byte order {byte} at byte {offset}")
+            }
+            WkbError::InvalidGeometryType { offset, code } => {
+                write!(f, "invalid WKB geometry type {code} at byte {offset}")
+            }
+            WkbError::TrailingBytes { offset } => {
+                write!(f, "unexpected data after WKB geometry at byte {offset}")
+            }
+            WkbError::EmptyPoint => write!(f, "geo-types does not support empty points"),
+            WkbError::NestingTooDeep { offset } => {
+                write!(
+                    f,
+                    "WKB geometry collections nested too deeply at byte {offset}"
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for WkbError {}
+
+/// Write a geometry as WKB.
+///
+/// This is implemented for every [`GeometryTrait`] geometry, so writes the Z and M values of
+/// geometries which have them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::wkb_conv::{Endianness, ToWkb};
+/// use geo::point;
+///
+/// let wkb = point!(x: 1., y: 2.).to_wkb(Endianness::LittleEndian);
+/// assert_eq!(wkb.len(), 21);
+/// assert_eq!(wkb[..5], [1, 1, 0, 0, 0]);
+/// ```
+pub trait ToWkb {
+    /// Write the geometry as ISO WKB, which marks Z and M values by adding `1000`, `2000` or
+    /// `3000` to the geometry type.
+    fn to_wkb(&self, endianness: Endianness) -> Vec<u8>;
+
+    /// Write the geometry as the Extended WKB of PostGIS, which marks Z and M values and the
+    /// SRID by flags in the high bits of the geometry type.
+    fn to_ewkb(&self, endianness: Endianness, srid: Option<i32>) -> Vec<u8>;
+}
+
+impl<T: CoordNum, G: GeometryTrait<T = T>> ToWkb for G {
+    fn to_wkb(&self, endianness: Endianness) -> Vec<u8> {
+        let mut writer = WkbWriter::new(endianness, false);
+        writer.write_geometry(self, None);
+        writer.bytes
+    }
+
+    fn to_ewkb(&self, endianness: Endianness, srid: Option<i32>) -> Vec<u8> {
+        let mut writer = WkbWriter::new(endianness, true);
+        writer.write_geometry(self, srid);
+        writer.bytes
+    }
+}
+
+// The dimensions written for a geometry, which must be known to WKB.
+fn wkb_dim(dim: Dimensions) -> Dimensions {
+    match dim {
+        Dimensions::Unknown(3) => Dimensions::Xyz,
+        Dimensions::Unknown(n) if n >= 4 => Dimensions::Xyzm,
+        Dimensions::Unknown(_) => Dimensions::Xy,
+        dim => dim,
+    }
+}
+
+struct WkbWriter {
+    bytes: Vec<u8>,
+    endianness: Endianness,
+    ewkb: bool,
+}
+
+impl WkbWriter {
+    fn new(endianness: Endianness, ewkb: bool) -> Self {
+        Self {
+            bytes: vec![],
+            endianness,
+            ewkb,
+        }
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        match self.endianness {
+            Endianness::BigEndian => self.bytes.extend(value.to_be_bytes()),
+            Endianness::LittleEndian => self.bytes.extend(value.to_le_bytes()),
+        }
+    }
+
+    fn write_f64(&mut self, value: f64) {
+        match self.endianness {
+            Endianness::BigEndian => self.bytes.extend(value.to_be_bytes()),
+            Endianness::LittleEndian => self.bytes.extend(value.to_le_bytes()),
+        }
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write_u32(len.try_into().expect("too many members for WKB"));
+    }
+
+    fn write_header(&mut self, code: u32, dim: Dimensions, srid: Option<i32>) {
+        self.bytes.push(match self.endianness {
+            Endianness::BigEndian => 0,
+            Endianness::LittleEndian => 1,
+        });
+        let (z, m) = match dim {
+            Dimensions::Xyz => (true, false),
+            Dimensions::Xym => (false, true),
+            Dimensions::Xyzm => (true, true),
+            _ => (false, false),
+        };
+        let code = if self.ewkb {
+            let mut code = code;
+            if z {
+                code |= EWKB_Z;
+            }
+            if m {
+                code |= EWKB_M;
+            }
+            if srid.is_some() {
+                code |= EWKB_SRID;
+            }
+            code
+        } else {
+            code + 1000 * (z as u32) + 2000 * (m as u32)
+        };
+        self.write_u32(code);
+        if let Some(srid) = srid {
+            self.write_u32(srid as u32);
+        }
+    }
+
+    fn write_coord<T: CoordNum>(&mut self, coord: &impl CoordTrait<T = T>, dim: Dimensions) {
+        for n in 0..dim.size() {
+            let value = coord.nth(n).and_then(|value| value.to_f64());
+            self.write_f64(value.unwrap_or(f64::NAN));
+        }
+    }
+
+    fn write_coords<T: CoordNum, C: CoordTrait<T = T>>(
+        &mut self,
+        coords: impl ExactSizeIterator<Item = C>,
+        dim: Dimensions,
+    ) {
+        self.write_len(coords.len());
+        for coord in coords {
+            self.write_coord(&coord, dim);
+        }
+    }
+
+    fn write_point<T: CoordNum>(&mut self, point: &impl PointTrait<T = T>, srid: Option<i32>) {
+        let dim = wkb_dim(point.dim());
+        self.write_header(POINT, dim, srid);
+        match point.coord() {
+            Some(coord) => self.write_coord(&coord, dim),
+            None => (0..dim.size()).for_each(|_| self.write_f64(f64::NAN)),
+        }
+    }
+
+    fn write_line_string<T: CoordNum>(
+        &mut self,
+        line_string: &impl LineStringTrait<T = T>,
+        srid: Option<i32>,
+    ) {
+        let dim = wkb_dim(line_string.dim());
+        self.write_header(LINE_STRING, dim, srid);
+        self.write_coords(line_string.coords(), dim);
+    }
+
+    fn write_polygon<T: CoordNum>(
+        &mut self,
+        polygon: &impl PolygonTrait<T = T>,
+        srid: Option<i32>,
+    ) {
+        let dim = wkb_dim(polygon.dim());
+        self.write_header(POLYGON, dim, srid);
+        match polygon.exterior() {
+            Some(exterior) => {
+                self.write_len(polygon.num_interiors() + 1);
+                self.write_coords(exterior.coords(), dim);
+                for interior in polygon.interiors() {
+                    self.write_coords(interior.coords(), dim);
+                }
+            }
+            None => self.write_u32(0),
+        }
+    }
+
+    fn write_geometry<T: CoordNum>(
+        &mut self,
+        geometry: &impl GeometryTrait<T = T>,
+        srid: Option<i32>,
+    ) {
+        let dim = wkb_dim(geometry.dim());
+        match geometry.as_type() {
+            GeometryType::Point(point) => self.write_point(point, srid),
+            GeometryType::LineString(line_string) => self.write_line_string(line_string, srid),
+            GeometryType::Polygon(polygon) => self.write_polygon(polygon, srid),
+            GeometryType::MultiPoint(multi_point) => {
+                self.write_header(MULTI_POINT, dim, srid);
+                self.write_len(multi_point.num_points());
+                for point in multi_point.points() {
+                    self.write_point(&point, None);
+                }
+            }
+            GeometryType::MultiLineString(multi_line_string) => {
+                self.write_header(MULTI_LINE_STRING, dim, srid);
+                self.write_len(multi_line_string.num_line_strings());
+                for line_string in multi_line_string.line_strings() {
+                    self.write_line_string(&line_string, None);
+                }
+            }
+            GeometryType::MultiPolygon(multi_polygon) => {
+                self.write_header(MULTI_POLYGON, dim, srid);
+                self.write_len(multi_polygon.num_polygons());
+                for polygon in multi_polygon.polygons() {
+                    self.write_polygon(&polygon, None);
+                }
+            }
+            GeometryType::GeometryCollection(collection) => {
+                self.write_header(GEOMETRY_COLLECTION, dim, srid);
+                self.write_len(collection.num_geometries());
+                for member in collection.geometries() {
+                    self.write_geometry(&member, None);
+                }
+            }
+            GeometryType::Rect(rect) => {
+                self.write_header(POLYGON, Dimensions::Xy, srid);
+                let (min, max) = (rect.min().x_y(), rect.max().x_y());
+                self.write_u32(1);
+                self.write_u32(5);
+                for (x, y) in [min, (min.0, max.1), max, (max.0, min.1), min] {
+                    self.write_coord(&crate::Coord { x, y }, Dimensions::Xy);
+                }
+            }
+            GeometryType::Triangle(triangle) => {
+                self.write_header(POLYGON, dim, srid);
+                self.write_u32(1);
+                self.write_u32(4);
+                for coord in [triangle.first(), triangle.second(), triangle.third()] {
+                    self.write_coord(&coord, dim);
+                }
+                self.write_coord(&triangle.first(), dim);
+            }
+            GeometryType::Line(line) => {
+                self.write_header(LINE_STRING, dim, srid);
+                self.write_u32(2);
+                self.write_coord(&line.start(), dim);
+                self.write_coord(&line.end(), dim);
+            }
+        }
+    }
+}
+
+/// A WKB or EWKB geometry, read without copying its coordinates.
+///
+/// It implements [`GeometryTrait`], so can be processed by generic code, or converted to a
+/// `geo-types` geometry with [`Wkb::to_geometry`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::wkb_conv::Wkb;
+/// use geo::{point, Geometry};
+///
+/// // SRID=4326;POINT(1 2), as written by PostGIS
+/// let ewkb = [
+///     0x01, 0x01, 0x00, 0x00, 0x20, 0xe6, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0xf0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+/// ];
+/// let wkb = Wkb::try_new(&ewkb).unwrap();
+/// assert_eq!(wkb.srid(), Some(4326));
+/// assert_eq!(wkb.to_geometry(), Ok(Geometry::Point(point!(x: 1., y: 2.))));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wkb<'a> {
+    geometry: WkbGeometry<'a>,
+    srid: Option<i32>,
+}
+
+impl<'a> Wkb<'a> {
+    /// Read the ISO WKB or EWKB geometry which is the whole of `bytes`.
+    pub fn try_new(bytes: &'a [u8]) -> Result<Self, WkbError> {
+        let mut reader = WkbReader {
+            bytes,
+            offset: 0,
+            depth: 0,
+        };
+        let (geometry, srid) = reader.read_geometry()?;
+        if reader.offset < bytes.len() {
+            return Err(WkbError::TrailingBytes {
+                offset: reader.offset,
+            });
+        }
+        Ok(Self { geometry, srid })
+    }
+
+    /// The SRID of an EWKB geometry which has one.
+    pub fn srid(&self) -> Option<i32> {
+        self.srid
+    }
+
+    /// The geometry.
+    pub fn geometry(&self) -> &WkbGeometry<'a> {
+        &self.geometry
+    }
+
+    /// Convert to a `geo-types` geometry, dropping any Z and M values.
+    pub fn to_geometry(&self) -> Result<Geometry<f64>, WkbError> {
+        self.geometry.try_to_geometry().ok_or(WkbError::EmptyPoint)
+    }
+}
+
+/// Read the ISO WKB or EWKB geometry which is the whole of `bytes` into a `geo-types` geometry,
+/// dropping any SRID and Z and M values.
+///
+/// See [`Wkb`] to keep them.
+pub fn read_wkb(bytes: &[u8]) -> Result<Geometry<f64>, WkbError> {
+    Wkb::try_new(bytes)?.to_geometry()
+}
+
+struct WkbHeader {
+    code: u32,
+    dim: Dimensions,
+    endianness: Endianness,
+    srid: Option<i32>,
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    /// The number of geometry collections being read
+    depth: usize,
+}
+
+impl<'a> WkbReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], WkbError> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(WkbError::UnexpectedEof {
+                offset: self.bytes.len(),
+            })?;
+        let bytes = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self, endianness: Endianness) -> Result<u32, WkbError> {
+        Ok(endianness.read_u32(self.take(4)?))
+    }
+
+    fn read_header(&mut self) -> Result<WkbHeader, WkbError> {
+        let offset = self.offset;
+        let endianness = match self.take(1)?[0] {
+            0 => Endianness::BigEndian,
+            1 => Endianness::LittleEndian,
+            byte => return Err(WkbError::InvalidByteOrder { offset, byte }),
+        };
+        let raw = self.read_u32(endianness)?;
+        let code = raw & 0x0fff_ffff;
+        let (iso_z, iso_m) = match code / 1000 {
+            0 => (false, false),
+            1 => (true, false),
+            2 => (false, true),
+            3 => (true, true),
+            _ => return Err(WkbError::InvalidGeometryType { offset, code: raw }),
+        };
+        let dim = match (iso_z || raw & EWKB_Z != 0, iso_m || raw & EWKB_M != 0) {
+            (false, false) => Dimensions::Xy,
+            (true, false) => Dimensions::Xyz,
+            (false, true) => Dimensions::Xym,
+            (true, true) => Dimensions::Xyzm,
+        };
+        let srid = if raw & EWKB_SRID != 0 {
+            Some(self.read_u32(endianness)? as i32)
+        } else {
+            None
+        };
+        Ok(WkbHeader {
+            code: code % 1000,
+            dim,
+            endianness,
+            srid,
+        })
+    }
+
+    // Read the header of a member of a multi geometry, which must be of type `code`.
+    fn read_member_header(&mut self, code: u32) -> Result<WkbHeader, WkbError> {
+        let offset = self.offset;
+        let header = self.read_header()?;
+        if header.code != code {
+            return Err(WkbError::InvalidGeometryType {
+                offset,
+                code: header.code,
+            });
+        }
+        Ok(header)
+    }
+
+    fn read_len(&mut self, endianness: Endianness) -> Result<usize, WkbError> {
+        Ok(self.read_u32(endianness)? as usize)
+    }
+
+    fn read_line_string_body(&mut self, header: &WkbHeader) -> Result<WkbLineString<'a>, WkbError> {
+        let num_coords = self.read_len(header.endianness)?;
+        let len = num_coords
+            .checked_mul(header.dim.size() * 8)
+            .ok_or(WkbError::UnexpectedEof {
+                offset: self.bytes.len(),
+            })?;
+        Ok(WkbLineString {
+            bytes: self.take(len)?,
+            num_coords,
+            dim: header.dim,
+            endianness: header.endianness,
+        })
+    }
+
+    fn read_point_body(&mut self, header: &WkbHeader) -> Result<WkbPoint<'a>, WkbError> {
+        Ok(WkbPoint {
+            coord: WkbCoord {
+                bytes: self.take(header.dim.size() * 8)?,
+                dim: header.dim,
+                endianness: header.endianness,
+            },
+        })
+    }
+
+    fn read_polygon_body(&mut self, header: &WkbHeader) -> Result<WkbPolygon<'a>, WkbError> {
+        let num_rings = self.read_len(header.endianness)?;
+        let rings = (0..num_rings)
+            .map(|_| self.read_line_string_body(header))
+            .collect::<Result<_, _>>()?;
+        Ok(WkbPolygon {
+            rings,
+            dim: header.dim,
+        })
+    }
+
+    fn read_members<M>(
+        &mut self,
+        header: &WkbHeader,
+        mut read_member: impl FnMut(&mut Self) -> Result<M, WkbError>,
+    ) -> Result<Vec<M>, WkbError> {
+        let len = self.read_len(header.endianness)?;
+        // not preallocated, as `len` may be corrupt
+        let mut members = vec![];
+        for _ in 0..len {
+            members.push(read_member(self)?);
+        }
+        Ok(members)
+    }
+
+    fn read_geometry(&mut self) -> Result<(WkbGeometry<'a>, Option<i32>), WkbError> {
+        let offset = self.offset;
+        let header = self.read_header()?;
+        let dim = header.dim;
+        let geometry = match header.code {
+            POINT => WkbGeometry::Point(self.read_point_body(&header)?),
+            LINE_STRING => WkbGeometry::LineString(self.read_line_string_body(&header)?),
+            POLYGON => WkbGeometry::Polygon(self.read_polygon_body(&header)?),
+            MULTI_POINT => {
+                let points = self.read_members(&header, |reader| {
+                    let header = reader.read_member_header(POINT)?;
+                    reader.read_point_body(&header)
+                })?;
+                WkbGeometry::MultiPoint(WkbMultiPoint { points, dim })
+            }
+            MULTI_LINE_STRING => {
+                let line_strings = self.read_members(&header, |reader| {
+                    let header = reader.read_member_header(LINE_STRING)?;
+                    reader.read_line_string_body(&header)
+                })?;
+                WkbGeometry::MultiLineString(WkbMultiLineString { line_strings, dim })
+            }
+            MULTI_POLYGON => {
+                let polygons = self.read_members(&header, |reader| {
+                    let header = reader.read_member_header(POLYGON)?;
+                    reader.read_polygon_body(&header)
+                })?;
+                WkbGeometry::MultiPolygon(WkbMultiPolygon { polygons, dim })
+            }
+            GEOMETRY_COLLECTION => {
+                if self.depth == MAX_NESTING_DEPTH {
+                    return Err(WkbError::NestingTooDeep { offset });
+                }
+                self.depth += 1;
+                let geometries = self.read_members(&header, |reader| {
+                    reader.read_geometry().map(|(geometry, _)| geometry)
+                })?;
+                self.depth -= 1;
+                WkbGeometry::GeometryCollection(WkbGeometryCollection { geometries, dim })
+            }
+            code => return Err(WkbError::InvalidGeometryType { offset, code }),
+        };
+        Ok((geometry, header.srid))
+    }
+}
+
+/// A geometry of a [`Wkb`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum WkbGeometry<'a> {
+    Point(WkbPoint<'a>),
+    LineString(WkbLineString<'a>),
+    Polygon(WkbPolygon<'a>),
+    MultiPoint(WkbMultiPoint<'a>),
+    MultiLineString(WkbMultiLineString<'a>),
+    MultiPolygon(WkbMultiPolygon<'a>),
+    GeometryCollection(WkbGeometryCollection<'a>),
+}
+
+/// A coordinate of a [`Wkb`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WkbCoord<'a> {
+    bytes: &'a [u8],
+    dim: Dimensions,
+    endianness: Endianness,
+}
+
+/// A point of a [`Wkb`], which is empty if its coordinates are `NaN`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WkbPoint<'a> {
+    coord: WkbCoord<'a>,
+}
+
+/// A line string, or polygon ring, of a [`Wkb`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WkbLineString<'a> {
+    bytes: &'a [u8],
+    num_coords: usize,
+    dim: Dimensions,
+    endianness: Endianness,
+}
+
+/// A polygon of a [`Wkb`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WkbPolygon<'a> {
+    rings: Vec<WkbLineString<'a>>,
+    dim: Dimensions,
+}
+
+/// A multi point of a [`Wkb`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WkbMultiPoint<'a> {
+    points: Vec<WkbPoint<'a>>,
+    dim: Dimensions,
+}
+
+/// A multi line string of a [`Wkb`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WkbMultiLineString<'a> {
+    line_strings: Vec<WkbLineString<'a>>,
+    dim: Dimensions,
+}
+
+/// A multi polygon of a [`Wkb`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WkbMultiPolygon<'a> {
+    polygons: Vec<WkbPolygon<'a>>,
+    dim: Dimensions,
+}
+
+/// A geometry collection of a [`Wkb`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WkbGeometryCollection<'a> {
+    geometries: Vec<WkbGeometry<'a>>,
+    dim: Dimensions,
+}
+
+impl CoordTrait for WkbCoord<'_> {
+    type T = f64;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn nth_or_panic(&self, n: usize) -> f64 {
+        self.endianness.read_f64(&self.bytes[n * 8..])
+    }
+
+    fn x(&self) -> f64 {
+        self.nth_or_panic(0)
+    }
+
+    fn y(&self) -> f64 {
+        self.nth_or_panic(1)
+    }
+}
+
+impl<'a> PointTrait for WkbPoint<'a> {
+    type T = f64;
+    type CoordType<'b>
+        = WkbCoord<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.coord.dim
+    }
+
+    fn coord(&self) -> Option<WkbCoord<'a>> {
+        let (x, y) = self.coord.x_y();
+        (!(x.is_nan() && y.is_nan())).then_some(self.coord)
+    }
+}
+
+impl<'a> LineStringTrait for WkbLineString<'a> {
+    type T = f64;
+    type CoordType<'b>
+        = WkbCoord<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn num_coords(&self) -> usize {
+        self.num_coords
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> WkbCoord<'a> {
+        let size = self.dim.size() * 8;
+        WkbCoord {
+            bytes: &self.bytes[i * size..(i + 1) * size],
+            dim: self.dim,
+            endianness: self.endianness,
+        }
+    }
+}
+
+impl<'a> PolygonTrait for WkbPolygon<'a> {
+    type T = f64;
+    type RingType<'b>
+        = WkbLineString<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn exterior(&self) -> Option<WkbLineString<'a>> {
+        self.rings.first().copied()
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.rings.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> WkbLineString<'a> {
+        self.rings[i + 1]
+    }
+}
+
+impl<'a> PolygonTrait for &WkbPolygon<'a> {
+    type T = f64;
+    type RingType<'b>
+        = WkbLineString<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn exterior(&self) -> Option<WkbLineString<'a>> {
+        (*self).exterior()
+    }
+
+    fn num_interiors(&self) -> usize {
+        (*self).num_interiors()
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> WkbLineString<'a> {
+        (*self).interior_unchecked(i)
+    }
+}
+
+impl<'a> MultiPointTrait for WkbMultiPoint<'a> {
+    type T = f64;
+    type PointType<'b>
+        = WkbPoint<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn num_points(&self) -> usize {
+        self.points.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> WkbPoint<'a> {
+        self.points[i]
+    }
+}
+
+impl<'a> MultiLineStringTrait for WkbMultiLineString<'a> {
+    type T = f64;
+    type LineStringType<'b>
+        = WkbLineString<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn num_line_strings(&self) -> usize {
+        self.line_strings.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> WkbLineString<'a> {
+        self.line_strings[i]
+    }
+}
+
+impl<'a> MultiPolygonTrait for WkbMultiPolygon<'a> {
+    type T = f64;
+    type PolygonType<'b>
+        = &'b WkbPolygon<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.polygons.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> &WkbPolygon<'a> {
+        &self.polygons[i]
+    }
+}
+
+impl<'a> GeometryCollectionTrait for WkbGeometryCollection<'a> {
+    type T = f64;
+    type GeometryType<'b>
+        = &'b WkbGeometry<'a>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> Dimensions {
+        self.dim
+    }
+
+    fn num_geometries(&self) -> usize {
+        self.geometries.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> &WkbGeometry<'a> {
+        &self.geometries[i]
+    }
+}
+
+// The geometry of the types implementing `GeometryTrait` by the same `WkbGeometry`.
+trait AsWkbGeometry<'a> {
+    fn wkb_geometry(&self) -> &WkbGeometry<'a>;
+}
+
+impl<'a> AsWkbGeometry<'a> for WkbGeometry<'a> {
+    fn wkb_geometry(&self) -> &WkbGeometry<'a> {
+        self
+    }
+}
+
+impl<'a> AsWkbGeometry<'a> for &WkbGeometry<'a> {
+    fn wkb_geometry(&self) -> &WkbGeometry<'a> {
+        self
+    }
+}
+
+impl<'a> AsWkbGeometry<'a> for Wkb<'a> {
+    fn wkb_geometry(&self) -> &WkbGeometry<'a> {
+        &self.geometry
+    }
+}
+
+macro_rules! impl_wkb_geometry_trait {
+    ($type:ty) => {
+        impl<'a> GeometryTrait for $type {
+            type T = f64;
+            type PointType<'b>
+                = WkbPoint<'a>
+            where
+                Self: 'b;
+            type LineStringType<'b>
+                = WkbLineString<'a>
+            where
+                Self: 'b;
+            type PolygonType<'b>
+                = WkbPolygon<'a>
+            where
+                Self: 'b;
+            type MultiPointType<'b>
+                = WkbMultiPoint<'a>
+            where
+                Self: 'b;
+            type MultiLineStringType<'b>
+                = WkbMultiLineString<'a>
+            where
+                Self: 'b;
+            type MultiPolygonType<'b>
+                = WkbMultiPolygon<'a>
+            where
+                Self: 'b;
+            type GeometryCollectionType<'b>
+                = WkbGeometryCollection<'a>
+            where
+                Self: 'b;
+            type RectType<'b>
+                = UnimplementedRect<f64>
+            where
+                Self: 'b;
+            type TriangleType<'b>
+                = UnimplementedTriangle<f64>
+            where
+                Self: 'b;
+            type LineType<'b>
+                = UnimplementedLine<f64>
+            where
+                Self: 'b;
+
+            fn dim(&self) -> Dimensions {
+                match self.wkb_geometry() {
+                    WkbGeometry::Point(g) => g.dim(),
+                    WkbGeometry::LineString(g) => g.dim,
+                    WkbGeometry::Polygon(g) => g.dim,
+                    WkbGeometry::MultiPoint(g) => g.dim,
+                    WkbGeometry::MultiLineString(g) => g.dim,
+                    WkbGeometry::MultiPolygon(g) => g.dim,
+                    WkbGeometry::GeometryCollection(g) => g.dim,
+                }
+            }
+
+            fn as_type(
+                &self,
+            ) -> GeometryType<
+                '_,
+                WkbPoint<'a>,
+                WkbLineString<'a>,
+                WkbPolygon<'a>,
+                WkbMultiPoint<'a>,
+                WkbMultiLineString<'a>,
+                WkbMultiPolygon<'a>,
+                WkbGeometryCollection<'a>,
+                UnimplementedRect<f64>,
+                UnimplementedTriangle<f64>,
+                UnimplementedLine<f64>,
+            > {
+                match self.wkb_geometry() {
+                    WkbGeometry::Point(g) => GeometryType::Point(g),
+                    WkbGeometry::LineString(g) => GeometryType::LineString(g),
+                    WkbGeometry::Polygon(g) => GeometryType::Polygon(g),
+                    WkbGeometry::MultiPoint(g) => GeometryType::MultiPoint(g),
+                    WkbGeometry::MultiLineString(g) => GeometryType::MultiLineString(g),
+                    WkbGeometry::MultiPolygon(g) => GeometryType::MultiPolygon(g),
+                    WkbGeometry::GeometryCollection(g) => GeometryType::GeometryCollection(g),
+                }
+            }
+        }
+    };
+}
+
+impl_wkb_geometry_trait!(WkbGeometry<'a>);
+impl_wkb_geometry_trait!(&WkbGeometry<'a>);
+impl_wkb_geometry_trait!(Wkb<'a>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{wkt, GeometryCollection, Rect, Triangle};
+
+    fn round_trip(geometry: Geometry<f64>) {
+        for endianness in [Endianness::BigEndian, Endianness::LittleEndian] {
+            let wkb = geometry.to_wkb(endianness);
+            assert_eq!(read_wkb(&wkb), Ok(geometry.clone()));
+            let ewkb = geometry.to_ewkb(endianness, Some(3857));
+            let read = Wkb::try_new(&ewkb).unwrap();
+            assert_eq!(read.srid(), Some(3857));
+            assert_eq!(read.to_geometry(), Ok(geometry.clone()));
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip(wkt!(POINT(1. 2.)).into());
+        round_trip(wkt!(LINESTRING(0. 0.,1. 2.,3. -4.)).into());
+        round_trip(wkt!(LINESTRING EMPTY).into());
+        round_trip(wkt!(POLYGON((0. 0.,10. 0.,10. 10.,0. 0.),(1. 1.,2. 1.,2. 2.,1. 1.))).into());
+        round_trip(wkt!(MULTIPOINT(0. 0.,1. 2.)).into());
+        round_trip(wkt!(MULTILINESTRING((0. 0.,1. 2.),(3. 3.,4. 4.))).into());
+        round_trip(
+            wkt!(MULTIPOLYGON(((0. 0.,1. 0.,1. 1.,0. 0.)),((5. 5.,6. 5.,6. 6.,5. 5.)))).into(),
+        );
+        round_trip(Geometry::GeometryCollection(GeometryCollection::new_from(
+            vec![
+                wkt!(POINT(1. 2.)).into(),
+                Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION(LINESTRING(0. 0.,1. 1.)))),
+            ],
+        )));
+        round_trip(Geometry::GeometryCollection(wkt!(GEOMETRYCOLLECTION EMPTY)));
+    }
+
+    #[test]
+    fn written_as_other_types() {
+        let rect = Rect::new((0., 0.), (2., 1.));
+        assert_eq!(
+            read_wkb(&rect.to_wkb(Endianness::LittleEndian)),
+            Ok(Geometry::Polygon(rect.to_polygon()))
+        );
+        let triangle = Triangle::from([(0., 0.), (2., 0.), (1., 1.)]);
+        assert_eq!(
+            read_wkb(&triangle.to_wkb(Endianness::BigEndian)),
+            Ok(Geometry::Polygon(triangle.to_polygon()))
+        );
+        let line = crate::Line::new((0., 0.), (1., 2.));
+        assert_eq!(
+            read_wkb(&line.to_wkb(Endianness::LittleEndian)),
+            Ok(wkt!(LINESTRING(0. 0.,1. 2.)).into())
+        );
+    }
+
+    #[test]
+    fn encoding() {
+        let point = wkt!(POINT(1. 2.));
+        let mut expected = vec![1, 1, 0, 0, 0];
+        expected.extend(1f64.to_le_bytes());
+        expected.extend(2f64.to_le_bytes());
+        assert_eq!(point.to_wkb(Endianness::LittleEndian), expected);
+
+        let mut expected = vec![0, 0, 0, 0, 1];
+        expected.extend(1f64.to_be_bytes());
+        expected.extend(2f64.to_be_bytes());
+        assert_eq!(point.to_wkb(Endianness::BigEndian), expected);
+        // without an SRID, EWKB of two dimensions is the same as WKB
+        assert_eq!(point.to_ewkb(Endianness::BigEndian, None), expected);
+    }
+
+    #[test]
+    fn z_and_m() {
+        // POINT Z (1 2 3) as ISO WKB, and POINT ZM (1 2 3 4) as EWKB with an SRID
+        let mut iso = vec![1];
+        iso.extend(1001u32.to_le_bytes());
+        [1f64, 2., 3.]
+            .iter()
+            .for_each(|v| iso.extend(v.to_le_bytes()));
+        let mut ewkb = vec![0];
+        ewkb.extend((POINT | EWKB_Z | EWKB_M | EWKB_SRID).to_be_bytes());
+        ewkb.extend(4326u32.to_be_bytes());
+        [1f64, 2., 3., 4.]
+            .iter()
+            .for_each(|v| ewkb.extend(v.to_be_bytes()));
+
+        let wkb = Wkb::try_new(&iso).unwrap();
+        assert_eq!(wkb.dim(), Dimensions::Xyz);
+        assert_eq!(wkb.srid(), None);
+        assert_eq!(wkb.to_geometry(), Ok(wkt!(POINT(1. 2.)).into()));
+        // the Z value is kept when written back out
+        assert_eq!(wkb.to_wkb(Endianness::LittleEndian), iso);
+
+        let wkb = Wkb::try_new(&ewkb).unwrap();
+        assert_eq!(wkb.dim(), Dimensions::Xyzm);
+        assert_eq!(wkb.srid(), Some(4326));
+        let (_, ordinates) = wkb.try_to_geometry_with_ordinates().unwrap();
+        assert_eq!(ordinates.z, Some(vec![3.]));
+        assert_eq!(ordinates.m, Some(vec![4.]));
+        assert_eq!(wkb.to_ewkb(Endianness::BigEndian, Some(4326)), ewkb);
+    }
+
+    #[test]
+    fn empty_point() {
+        let mut bytes = vec![1, 1, 0, 0, 0];
+        bytes.extend(f64::NAN.to_le_bytes());
+        bytes.extend(f64::NAN.to_le_bytes());
+        let wkb = Wkb::try_new(&bytes).unwrap();
+        assert_eq!(wkb.to_geometry(), Err(WkbError::EmptyPoint));
+        // written back out as NaNs
+        assert_eq!(
+            read_wkb(&wkb.to_wkb(Endianness::LittleEndian)),
+            Err(WkbError::EmptyPoint)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let wkb = wkt!(LINESTRING(0. 0.,1. 2.)).to_wkb(Endianness::LittleEndian);
+        assert_eq!(
+            read_wkb(&wkb[..wkb.len() - 1]),
+            Err(WkbError::UnexpectedEof { offset: 40 })
+        );
+        let mut trailing = wkb.clone();
+        trailing.push(0);
+        assert_eq!(
+            read_wkb(&trailing),
+            Err(WkbError::TrailingBytes { offset: 41 })
+        );
+        let mut byte_order = wkb.clone();
+        byte_order[0] = 2;
+        assert_eq!(
+            read_wkb(&byte_order),
+            Err(WkbError::InvalidByteOrder { offset: 0, byte: 2 })
+        );
+        let mut code = wkb.clone();
+        code[1] = 8;
+        assert_eq!(
+            read_wkb(&code),
+            Err(WkbError::InvalidGeometryType { offset: 0, code: 8 })
+        );
+        // a line string in a multi point
+        let mut multi_point = vec![1];
+        multi_point.extend(MULTI_POINT.to_le_bytes());
+        multi_point.extend(1u32.to_le_bytes());
+        multi_point.extend(&wkb);
+        assert_eq!(
+            read_wkb(&multi_point),
+            Err(WkbError::InvalidGeometryType { offset: 9, code: 2 })
+        );
+        // a huge number of coordinates
+        let mut huge = vec![1];
+        huge.extend(LINE_STRING.to_le_bytes());
+        huge.extend(u32::MAX.to_le_bytes());
+        assert_eq!(read_wkb(&huge), Err(WkbError::UnexpectedEof { offset: 9 }));
+    }
+
+    #[test]
+    fn deeply_nested_collections() {
+        let collection_header = |bytes: &mut Vec<u8>| {
+            bytes.push(1);
+            bytes.extend(GEOMETRY_COLLECTION.to_le_bytes());
+            bytes.extend(1u32.to_le_bytes());
+        };
+
+        let mut nested = vec![];
+        for _ in 0..MAX_NESTING_DEPTH - 1 {
+            collection_header(&mut nested);
+        }
+        nested.extend(GeometryCollection::<f64>::new_from(vec![]).to_wkb(Endianness::LittleEndian));
+        assert!(read_wkb(&nested).is_ok());
+
+        let mut too_deep = vec![];
+        for _ in 0..100_000 {
+            collection_header(&mut too_deep);
+        }
+        assert_eq!(
+            read_wkb(&too_deep),
+            Err(WkbError::NestingTooDeep {
+                offset: MAX_NESTING_DEPTH * 9
+            })
+        );
+    }
+}
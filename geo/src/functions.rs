@@ -0,0 +1,151 @@
+//! Free functions mirroring the trait-based algorithm API.
+//!
+//! Most algorithms in this crate are exposed as traits, e.g. `polygon.unsigned_area()` via
+//! [`Area`]. Method syntax is usually the most convenient, but free functions can be easier to
+//! use from generic code, or to wrap when generating bindings for other languages.
+//!
+//! Each function here simply delegates to the corresponding trait method, so both forms always
+//! give identical results.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::functions::{distance_euclidean, intersects, unsigned_area};
+//! use geo::{point, polygon};
+//!
+//! let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+//! let point = point!(x: 7., y: 4.);
+//!
+//! assert_eq!(unsigned_area(&polygon), 16.);
+//! assert!(!intersects(&polygon, &point));
+//! assert_eq!(distance_euclidean(&polygon, &point), 3.);
+//! ```
+
+use crate::relate::IntersectionMatrix;
+use crate::{
+    Area, BoundingRect, Centroid, Contains, ConvexHull, CoordFloat, CoordNum, Distance, Euclidean,
+    GeoFloat, Geodesic, Haversine, Intersects, Length, Point, Polygon, Relate, Simplify, Within,
+};
+use num_traits::FromPrimitive;
+
+/// The signed area of a geometry. See [`Area::signed_area`].
+pub fn signed_area<T: CoordNum>(geometry: &impl Area<T>) -> T {
+    geometry.signed_area()
+}
+
+/// The unsigned area of a geometry. See [`Area::unsigned_area`].
+pub fn unsigned_area<T: CoordNum>(geometry: &impl Area<T>) -> T {
+    geometry.unsigned_area()
+}
+
+/// The bounding rectangle of a geometry. See [`BoundingRect::bounding_rect`].
+pub fn bounding_rect<T: CoordNum, G: BoundingRect<T>>(geometry: &G) -> G::Output {
+    geometry.bounding_rect()
+}
+
+/// The centroid of a geometry. See [`Centroid::centroid`].
+pub fn centroid<G: Centroid>(geometry: &G) -> G::Output {
+    geometry.centroid()
+}
+
+/// The convex hull of a geometry. See [`ConvexHull::convex_hull`].
+pub fn convex_hull<'a, T, G: ConvexHull<'a, T>>(geometry: &'a G) -> Polygon<G::Scalar> {
+    geometry.convex_hull()
+}
+
+/// Simplify a geometry using the Ramer–Douglas–Peucker algorithm. See [`Simplify::simplify`].
+pub fn simplify<T: GeoFloat, G: Simplify<T>>(geometry: &G, epsilon: &T) -> G {
+    geometry.simplify(epsilon)
+}
+
+/// Whether `a` contains `b`. See [`Contains`].
+pub fn contains<A: Contains<B>, B>(a: &A, b: &B) -> bool {
+    a.contains(b)
+}
+
+/// Whether `a` intersects `b`. See [`Intersects`].
+pub fn intersects<A: Intersects<B>, B>(a: &A, b: &B) -> bool {
+    a.intersects(b)
+}
+
+/// Whether `a` lies within `b`. See [`Within`].
+pub fn within<A: Within<B>, B>(a: &A, b: &B) -> bool {
+    a.is_within(b)
+}
+
+/// The [DE-9IM] intersection matrix of `a` and `b`. See [`Relate`].
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub fn relate<F: GeoFloat>(a: &impl Relate<F>, b: &impl Relate<F>) -> IntersectionMatrix {
+    a.relate(b)
+}
+
+/// The minimum [Euclidean] distance between two geometries. See [`Distance`].
+pub fn distance_euclidean<'a, F, A, B>(a: &'a A, b: &'a B) -> F
+where
+    Euclidean: Distance<F, &'a A, &'a B>,
+{
+    Euclidean.distance(a, b)
+}
+
+/// The [Haversine] distance, in meters, between two lon/lat points. See [`Distance`].
+pub fn distance_haversine<F: CoordFloat + FromPrimitive>(a: Point<F>, b: Point<F>) -> F {
+    Haversine.distance(a, b)
+}
+
+/// The [Geodesic] distance, in meters, between two lon/lat points. See [`Distance`].
+pub fn distance_geodesic(a: Point<f64>, b: Point<f64>) -> f64 {
+    Geodesic.distance(a, b)
+}
+
+/// The [Euclidean] length of a linear geometry. See [`Length`].
+pub fn length_euclidean<F: CoordFloat>(geometry: &impl Length<F>) -> F {
+    geometry.length(&Euclidean)
+}
+
+/// The [Haversine] length, in meters, of a lon/lat linear geometry. See [`Length`].
+pub fn length_haversine<F: CoordFloat + FromPrimitive>(geometry: &impl Length<F>) -> F {
+    geometry.length(&Haversine)
+}
+
+/// The [Geodesic] length, in meters, of a lon/lat linear geometry. See [`Length`].
+pub fn length_geodesic(geometry: &impl Length<f64>) -> f64 {
+    geometry.length(&Geodesic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon, Line};
+
+    #[test]
+    fn functions_match_methods() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let line_string = line_string![(x: 2., y: 2.), (x: 6., y: 5.)];
+        let point = point!(x: 1., y: 1.);
+
+        assert_eq!(signed_area(&polygon), polygon.signed_area());
+        assert_eq!(unsigned_area(&polygon), polygon.unsigned_area());
+        assert_eq!(bounding_rect(&line_string), line_string.bounding_rect());
+        assert_eq!(centroid(&polygon), polygon.centroid());
+        assert_eq!(convex_hull(&line_string), line_string.convex_hull());
+        assert_eq!(simplify(&line_string, &1.0), line_string.simplify(&1.0));
+        assert!(contains(&polygon, &point));
+        assert!(within(&point, &polygon));
+        assert!(intersects(&polygon, &line_string));
+        assert!(relate(&polygon, &line_string).is_intersects());
+        assert_eq!(
+            distance_euclidean(&line_string, &point),
+            Euclidean.distance(&line_string, &point)
+        );
+        assert_eq!(length_euclidean(&line_string), 5.);
+        assert_eq!(
+            length_haversine(&Line::new(point, point!(x: 1., y: 2.))),
+            distance_haversine(point, point!(x: 1., y: 2.))
+        );
+        assert_eq!(
+            length_geodesic(&Line::new(point, point!(x: 1., y: 2.))),
+            distance_geodesic(point, point!(x: 1., y: 2.))
+        );
+    }
+}
@@ -2,16 +2,38 @@ use crate::{
     CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
     MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use geo_traits::to_geo::{ToGeoGeometry, ToGeoPoint};
+use geo_traits::{GeometryCollectionTrait, GeometryTrait, GeometryType, MultiPointTrait};
 use std::borrow::Cow;
 
 /// A `GeometryCow` is a "one of" enum, just like [`Geometry`], except it is possible for the inner
 /// type of a `GeometryCow` to be a reference rather than owned.
 ///
-/// This is a way to "upgrade" an inner type to something like a `Geometry` without `moving` it.
+/// This is a way to "upgrade" an inner type to something like a `Geometry` without `moving` it,
+/// e.g. so that a function can accept either owned or borrowed geometries of any type with an
+/// `impl Into<GeometryCow<'a, T>>` argument. It can be created [`From`] any geometry type, or a
+/// reference to one.
 ///
-/// As an example, see the [`Relate`] trait which uses `GeometryCow`.
+/// The algorithm traits it implements, such as [`Area`](crate::Area),
+/// [`BoundingRect`](crate::BoundingRect), [`Centroid`](crate::Centroid),
+/// [`Intersects`](crate::Intersects) and [`Relate`](crate::Relate), delegate to its inner
+/// geometry.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{wkt, Area, Geometry, GeometryCow};
+///
+/// fn total_area<'a>(geometries: impl IntoIterator<Item = GeometryCow<'a, f64>>) -> f64 {
+///     geometries.into_iter().map(|geometry| geometry.unsigned_area()).sum()
+/// }
+///
+/// let polygon = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)));
+/// let rect = Geometry::Rect(geo::Rect::new((0., 0.), (1., 3.)));
+/// assert_eq!(total_area([GeometryCow::from(&polygon), GeometryCow::from(rect)]), 7.);
+/// ```
 #[derive(PartialEq, Debug, Hash, Clone)]
-pub(crate) enum GeometryCow<'a, T>
+pub enum GeometryCow<'a, T>
 where
     T: CoordNum,
 {
@@ -182,3 +204,133 @@ impl<T: CoordNum> From<Geometry<T>> for GeometryCow<'_, T> {
         }
     }
 }
+
+impl<'a, T: CoordNum> From<&'a GeometryCow<'_, T>> for GeometryCow<'a, T> {
+    fn from(geometry: &'a GeometryCow<'_, T>) -> Self {
+        match geometry {
+            GeometryCow::Point(g) => GeometryCow::Point(Cow::Borrowed(g)),
+            GeometryCow::Line(g) => GeometryCow::Line(Cow::Borrowed(g)),
+            GeometryCow::LineString(g) => GeometryCow::LineString(Cow::Borrowed(g)),
+            GeometryCow::Polygon(g) => GeometryCow::Polygon(Cow::Borrowed(g)),
+            GeometryCow::MultiPoint(g) => GeometryCow::MultiPoint(Cow::Borrowed(g)),
+            GeometryCow::MultiLineString(g) => GeometryCow::MultiLineString(Cow::Borrowed(g)),
+            GeometryCow::MultiPolygon(g) => GeometryCow::MultiPolygon(Cow::Borrowed(g)),
+            GeometryCow::GeometryCollection(g) => GeometryCow::GeometryCollection(Cow::Borrowed(g)),
+            GeometryCow::Rect(g) => GeometryCow::Rect(Cow::Borrowed(g)),
+            GeometryCow::Triangle(g) => GeometryCow::Triangle(Cow::Borrowed(g)),
+        }
+    }
+}
+
+impl<T: CoordNum> From<GeometryCow<'_, T>> for Geometry<T> {
+    fn from(geometry: GeometryCow<'_, T>) -> Self {
+        match geometry {
+            GeometryCow::Point(g) => Geometry::Point(g.into_owned()),
+            GeometryCow::Line(g) => Geometry::Line(g.into_owned()),
+            GeometryCow::LineString(g) => Geometry::LineString(g.into_owned()),
+            GeometryCow::Polygon(g) => Geometry::Polygon(g.into_owned()),
+            GeometryCow::MultiPoint(g) => Geometry::MultiPoint(g.into_owned()),
+            GeometryCow::MultiLineString(g) => Geometry::MultiLineString(g.into_owned()),
+            GeometryCow::MultiPolygon(g) => Geometry::MultiPolygon(g.into_owned()),
+            GeometryCow::GeometryCollection(g) => Geometry::GeometryCollection(g.into_owned()),
+            GeometryCow::Rect(g) => Geometry::Rect(g.into_owned()),
+            GeometryCow::Triangle(g) => Geometry::Triangle(g.into_owned()),
+        }
+    }
+}
+
+impl<T: CoordNum> GeometryCow<'_, T> {
+    /// Whether the inner geometry is borrowed rather than owned.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(
+            self,
+            GeometryCow::Point(Cow::Borrowed(_))
+                | GeometryCow::Line(Cow::Borrowed(_))
+                | GeometryCow::LineString(Cow::Borrowed(_))
+                | GeometryCow::Polygon(Cow::Borrowed(_))
+                | GeometryCow::MultiPoint(Cow::Borrowed(_))
+                | GeometryCow::MultiLineString(Cow::Borrowed(_))
+                | GeometryCow::MultiPolygon(Cow::Borrowed(_))
+                | GeometryCow::GeometryCollection(Cow::Borrowed(_))
+                | GeometryCow::Rect(Cow::Borrowed(_))
+                | GeometryCow::Triangle(Cow::Borrowed(_))
+        )
+    }
+
+    /// Clone the inner geometry if it is borrowed, so the `GeometryCow` no longer borrows
+    /// anything.
+    pub fn into_owned(self) -> GeometryCow<'static, T> {
+        GeometryCow::from(Geometry::from(self))
+    }
+
+    /// Clone the inner geometry into a [`Geometry`].
+    pub fn to_geometry(&self) -> Geometry<T> {
+        Geometry::from(GeometryCow::from(self))
+    }
+
+    /// An owned `GeometryCow` of any `geo-traits` geometry. As `geo-types` can't represent
+    /// empty points, an empty point becomes an empty `MultiPoint`, and the empty points of a
+    /// `MultiPoint` are dropped.
+    pub(crate) fn from_geometry_trait(geometry: &impl GeometryTrait<T = T>) -> Self {
+        GeometryCow::from(geometry_from_trait(geometry))
+    }
+}
+
+fn geometry_from_trait<T: CoordNum>(geometry: &impl GeometryTrait<T = T>) -> Geometry<T> {
+    match geometry.as_type() {
+        GeometryType::Point(point) => match point.try_to_point() {
+            Some(point) => Geometry::Point(point),
+            None => Geometry::MultiPoint(MultiPoint::new(vec![])),
+        },
+        GeometryType::MultiPoint(multi_point) => Geometry::MultiPoint(
+            multi_point
+                .points()
+                .filter_map(|point| point.try_to_point())
+                .collect(),
+        ),
+        GeometryType::GeometryCollection(collection) => {
+            Geometry::GeometryCollection(GeometryCollection::new_from(
+                collection
+                    .geometries()
+                    .map(|member| geometry_from_trait(&member))
+                    .collect(),
+            ))
+        }
+        // only empty points fail to convert
+        _ => geometry.to_geometry(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{wkt, Area, BoundingRect, Centroid, Intersects, Relate};
+
+    #[test]
+    fn owned_and_borrowed() {
+        let polygon: Polygon = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)));
+        let borrowed = GeometryCow::from(&polygon);
+        assert!(borrowed.is_borrowed());
+        assert_eq!(borrowed.to_geometry(), Geometry::Polygon(polygon.clone()));
+
+        let owned = borrowed.clone().into_owned();
+        assert!(!owned.is_borrowed());
+        assert_eq!(owned, borrowed);
+        assert!(GeometryCow::from(&owned).is_borrowed());
+        assert_eq!(Geometry::from(owned), Geometry::Polygon(polygon));
+    }
+
+    #[test]
+    fn algorithms() {
+        let polygon: Polygon = wkt!(POLYGON((0. 0.,2. 0.,2. 2.,0. 2.,0. 0.)));
+        let geometry = GeometryCow::from(&polygon);
+        let point = GeometryCow::from(wkt!(POINT(1. 1.)));
+
+        assert_eq!(geometry.unsigned_area(), 4.);
+        assert_eq!(geometry.bounding_rect(), polygon.bounding_rect());
+        assert_eq!(geometry.centroid(), Some(wkt!(POINT(1. 1.))));
+        assert!(geometry.intersects(&Line::new((1., 1.), (3., 3.))));
+        assert!(geometry.relate(&point).is_contains());
+        assert!(point.relate(&polygon).is_within());
+    }
+}
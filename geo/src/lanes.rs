@@ -0,0 +1,82 @@
+//! Inner loops over long coordinate slices, accumulating into independent lanes so that the
+//! compiler can keep them in SIMD registers (enabled by the `simd` feature).
+//!
+//! Summing in lanes changes the order of the floating point additions, so results may differ
+//! from the scalar loops in their last bits.
+
+use crate::{Coord, CoordFloat, CoordNum};
+
+const LANES: usize = 4;
+
+/// Sum `f` of each pair of consecutive coordinates, with `LANES` independent accumulators.
+#[inline(always)]
+fn sum_segments<T: CoordNum>(coords: &[Coord<T>], f: impl Fn(Coord<T>, Coord<T>) -> T) -> T {
+    let mut lanes = [T::zero(); LANES];
+    // Each window of `LANES + 1` coordinates holds `LANES` segments, and shares its last
+    // coordinate with the next window.
+    let mut windows = coords.windows(LANES + 1).step_by(LANES);
+    let mut rest = coords;
+    for window in &mut windows {
+        for lane in 0..LANES {
+            lanes[lane] = lanes[lane] + f(window[lane], window[lane + 1]);
+        }
+        rest = &rest[LANES..];
+    }
+    for segment in rest.windows(2) {
+        lanes[0] = lanes[0] + f(segment[0], segment[1]);
+    }
+    (lanes[0] + lanes[1]) + (lanes[2] + lanes[3])
+}
+
+/// Twice the signed area enclosed by `coords`, shifted by `shift` to reduce numerical errors.
+pub(crate) fn twice_signed_ring_area<T: CoordNum>(coords: &[Coord<T>], shift: Coord<T>) -> T {
+    sum_segments(coords, |a, b| {
+        let (a, b) = (a - shift, b - shift);
+        a.x * b.y - a.y * b.x
+    })
+}
+
+/// The Euclidean length of the path through `coords`.
+pub(crate) fn euclidean_length<T: CoordFloat>(coords: &[Coord<T>]) -> T {
+    sum_segments(coords, |a, b| {
+        let delta = b - a;
+        (delta.x * delta.x + delta.y * delta.y).sqrt()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_scalar() {
+        // lengths around every multiple of LANES
+        for n in 0..20 {
+            let mut coords: Vec<Coord> = (0..n)
+                .map(|i| {
+                    let angle = i as f64 / n as f64 * std::f64::consts::TAU;
+                    Coord {
+                        x: 3. + angle.cos(),
+                        y: -2. + angle.sin(),
+                    }
+                })
+                .collect();
+            coords.extend(coords.first().copied());
+            let shift = coords.first().copied().unwrap_or_default();
+
+            let (mut length, mut twice_area) = (0., 0.);
+            for segment in coords.windows(2) {
+                let (a, b) = (segment[0] - shift, segment[1] - shift);
+                length += (b - a).x.hypot((b - a).y);
+                twice_area += a.x * b.y - a.y * b.x;
+            }
+
+            assert_relative_eq!(euclidean_length(&coords), length, max_relative = 1e-12);
+            assert_relative_eq!(
+                twice_signed_ring_area(&coords, shift),
+                twice_area,
+                max_relative = 1e-12
+            );
+        }
+    }
+}
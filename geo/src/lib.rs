@@ -23,6 +23,9 @@
 //! The preceding types are reexported from the [`geo-types`] crate. Consider using that crate
 //! if you only need access to these types and no other `geo` functionality.
 //!
+//! - **[`GeometryCow`]**: Like [`Geometry`], but holding either an owned or a borrowed geometry,
+//!   e.g. to accept both in an API
+//!
 //! ## Semantics
 //!
 //! The geospatial types provided here aim to adhere to the [OpenGIS Simple feature access][OGC-SFA]
@@ -38,8 +41,8 @@
 //! ### Metric Spaces
 //!
 //! - **[`Euclidean`]**: The [Euclidean plane] measures distance with the pythagorean formula. Not suitable for lon/lat geometries.
-//! - **[`Haversine`]**: The [Haversine Formula] measures distance on a sphere. Only suitable for lon/lat geometries.
-//! - **[`Geodesic`]**: Geodesic methods based on [Karney (2013)] more accurately reflect the shape of the Earth, but are slower than Haversine. Only suitable for lon/lat geometries.
+//! - **[`Haversine`]**: The [Haversine Formula] measures distance on a sphere. Only suitable for lon/lat geometries. Use [`HaversineMeasure`] to measure on a sphere with a radius other than the earth's.
+//! - **[`Geodesic`]**: Geodesic methods based on [Karney (2013)] more accurately reflect the shape of the Earth, but are slower than Haversine. Only suitable for lon/lat geometries. Use [`GeodesicMeasure`] to measure on an [`Ellipsoid`] other than WGS84.
 //! - **[`Rhumb`]**: [Rhumb line] (a.k.a. loxodrome) measures can be useful for navigation applications where maintaining a constant bearing or direction is important. Only suitable for lon/lat geometries.
 //!
 //! ### Operations on Metric Spaces
@@ -58,10 +61,13 @@
 //! - **[`VincentyDistance`]**: Calculate the minimum geodesic distance between geometries using Vincenty’s formula
 //! - **[`VincentyLength`]**: Calculate the geodesic length of a geometry using Vincenty’s formula
 //! - **[`FrechetDistance`]**: Calculate the similarity between [`LineString`]s using the Fréchet distance
+//! - **[`MaxDistance`]**: Calculate the maximum Euclidean distance between two geometries, and the points which are farthest apart
 //!
 //! ## Area
 //!
 //! - **[`Area`]**: Calculate the planar area of a geometry
+//! - **[`GeometryTraitExt`](geometry_trait_ext::GeometryTraitExt)**: Calculate the planar area and
+//!   bounding rectangle of any [`geo_traits`] geometry, e.g. WKB, from its coordinates
 //! - **[`ChamberlainDuquetteArea`]**: Calculate the geodesic area of a geometry on a sphere using the algorithm presented in _Some Algorithms for Polygons on a Sphere_ by Chamberlain and Duquette (2007)
 //! - **[`GeodesicArea`]**: Calculate the geodesic area and perimeter of a geometry on an ellipsoid using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
 //!
@@ -69,18 +75,32 @@
 //!
 //! - **[`BooleanOps`]**: Combine or split (Multi)Polygons using intersection, union, xor, or difference operations
 //! - **[`unary_union`]**: Efficient union of many [`Polygon`] or [`MultiPolygon`]s
+//! - **[`dissolve_by_key`]**: Union the polygons sharing each key, the GIS "dissolve" operation
+//! - **[`union_overlay`]**: Split many polygon layers into the faces of their arrangement, with the
+//!   layers covering each face
+//! - **[`ClipToCircle`]**: Clip a geometry to the parts of it within a distance of a point
 //!
 //! ## Outlier Detection
 //!
 //! - **[`OutlierDetection`]**: Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor)
+//!   or another [`OutlierMethod`], with Euclidean or Haversine distances ([`OutlierMetric`])
+//!
+//! ## Clustering
+//!
+//! - **[`HierarchicalClustering`]**: Cluster points by single, complete or average [`Linkage`],
+//!   cutting the [`Dendrogram`] at a distance or a number of clusters
 //!
 //! ## Simplification
 //!
 //! - **[`Simplify`]**: Simplify a geometry using the Ramer–Douglas–Peucker algorithm
 //! - **[`SimplifyIdx`]**: Calculate a simplified geometry using the Ramer–Douglas–Peucker algorithm, returning coordinate indices
+//! - **[`simplify_line_string_idx`]**: Calculate the coordinate indices retained by the Ramer–Douglas–Peucker algorithm for any `geo_traits::LineStringTrait` implementation
+//! - **[`SimplifyInto`]**: Calculate a simplified geometry using the Ramer–Douglas–Peucker algorithm, writing the coordinates to a reusable buffer
+//! - **[`simplify_chunked`]**: Simplify a stream of coordinates in chunks, using bounded memory
 //! - **[`SimplifyVw`]**: Simplify a geometry using the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwPreserve`]**: Simplify a geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwIdx`]**: Calculate a simplified geometry using the Visvalingam-Whyatt algorithm, returning coordinate indices
+//! - **[`SimplifyToNPoints`]**, **[`SimplifyVwToNPoints`]**: Simplify a geometry to a target number of coordinates, rather than to a tolerance
 //!
 //! ## Query
 //!
@@ -88,8 +108,17 @@
 //!   closest to a given point
 //! - **[`HaversineClosestPoint`]**: Find the point on a geometry
 //!   closest to a given point on a sphere using spherical coordinates and lines being great arcs
+//! - **[`NearestPoints`]**: Find the pair of nearest points between two geometries, one on each,
+//!   on the plane or on a sphere
+//! - **[`HeadingIndex`]**: Find the closest point on the nearest segment of a
+//!   [`MultiLineString`] whose direction is close to a heading, e.g. to snap vehicle positions
+//!   onto a road network
+//! - **[`IndexedFacetDistance`]**: Index the segments of a geometry to find the distance and
+//!   nearest point to it from many points quickly
 //! - **[`IsConvex`]**: Calculate the convexity of a
 //!   [`LineString`]
+//! - **[`IsSimple`]**, **[`IsRing`]**: Check whether a geometry has no self-intersections, or a
+//!   [`LineString`] is a closed, simple ring
 //! - **[`LineInterpolatePoint`]**:
 //!   Generates a point that lies a given fraction along the line
 //! - **[`LineLocatePoint`]**: Calculate the
@@ -107,11 +136,30 @@
 //! - **[`HasDimensions`]**: Determine the dimensions of a geometry
 //! - **[`Intersects`]**: Calculate if a geometry intersects
 //!   another geometry
+//! - **[`has_disjoint_bboxes`]**, **[`bbox_filtered`]**: Skip pairwise computations for geometries
+//!   whose bounding rectangles are disjoint
 //! - **[`line_intersection`]**: Calculates the
 //!   intersection, if any, between two lines
+//! - **[`Equals`]**: Calculate if two geometries are topologically equal
 //! - **[`Relate`]**: Topologically relate two geometries based on
-//!   [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics
+//!   [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics, optionally with another
+//!   [`BoundaryNodeRule`](relate::BoundaryNodeRule) for line endpoints, or any
+//!   [`geo_traits`] geometries with [`relate_geometries`](relate::relate_geometries)
 //! - **[`Within`]**: Calculate if a geometry lies completely within another geometry
+//! - **[`ContainsWithTolerance`]**, **[`IntersectsWithTolerance`]**, **[`WithinWithTolerance`]**:
+//!   Variants of the above predicates which tolerate small gaps in noisy data
+//! - **[`SphericalContains`]**: Calculate if a lon/lat polygon contains a point, using great
+//!   circle arcs on a sphere
+//! - **[`points_in_polygons`]**: Find the polygon containing each of many points, using an
+//!   R-tree of the polygons' bounding rectangles
+//! - **[`areal_interpolate`]**, **[`areal_interpolate_weighted`]**: Transfer values between
+//!   two sets of zones in proportion to the (weighted) areas of their intersections
+//! - **[`ContainmentMask`]**: Find which cells of a raster grid have their center inside a
+//!   polygon, as a bitset
+//! - **[`MonotoneChains`]**: Decompose a `LineString` into monotone chains, which speed up
+//!   spatial predicates on long `LineString`s
+//! - **[`MonotoneChainIndex`]**: Find the intersections between two sets of `LineString`s,
+//!   using an R-tree of their monotone chains
 //!
 //! ## Triangulation
 //!
@@ -120,6 +168,7 @@
 //! ## Winding
 //!
 //! - **[`Orient`]**: Apply a specified winding [`Direction`](orient::Direction) to a [`Polygon`]’s interior and exterior rings
+//! - **[`OrientInPlace`]**: Like [`Orient`], but mutating the geometry in place
 //! - **[`Winding`]**: Calculate and manipulate the [`WindingOrder`](winding_order::WindingOrder) of a [`LineString`]
 //!
 //! ## Iteration
@@ -129,6 +178,8 @@
 //!   in a geometry, returning a new geometry
 //! - **[`MapCoordsInPlace`]**: Map a function over all the
 //!   coordinates in a geometry in-place
+//! - **[`MapCoordsIndexed`]**: Map a function over all the coordinates
+//!   in a geometry, along with where each lies within the geometry
 //! - **[`LinesIter`]**: Iterate over lines of a geometry
 //!
 //! ## Boundary
@@ -138,37 +189,96 @@
 //! - **[`MinimumRotatedRect`]**: Calculate the
 //!   minimum bounding box of a geometry
 //! - **[`ConcaveHull`]**: Calculate the concave hull of a
-//!   geometry
+//!   geometry, optionally with the stop conditions of [`ConcaveHullParams`]
+//! - **[`AlphaShape`]**: Calculate the alpha shape of a geometry's
+//!   coordinates, a concave hull which may have holes and several parts
 //! - **[`ConvexHull`]**: Calculate the convex hull of a
-//!   geometry
+//!   geometry, optionally with a [`ConvexHullStrategy`]
 //! - **[`Extremes`]**: Calculate the extreme coordinates and
 //!   indices of a geometry
+//! - **[`ExtentSummary`]**: Calculate the bounding rectangle, extremes, coordinate count and
+//!   closedness of a geometry in a single pass
+//! - **[`GeometryStatistics`]**: Calculate the vertex, ring and part counts, segment lengths,
+//!   area, perimeter and bounding rectangle of a geometry in a single pass
+//! - **[`assemble_polygons`]**: Assemble polygons from closed rings, nesting shells and holes
+//!   by containment
+//! - **[`MedialAxis`]**: Approximate the medial axis of a polygon, a skeleton for centerlines
+//!   and widths
+//! - **[`WidthProfile`]**: Measure the width of an elongated polygon at stations along its
+//!   centerline
 //!
 //! ## Affine transformations
 //!
 //! - **[`Rotate`]**: Rotate a geometry around its centroid
-//! - **[`Scale`]**: Scale a geometry up or down by a factor
+//! - **[`Scale`]**: Scale a geometry up or down by a factor, around a point or per
+//!   member as chosen by [`ScaleOrigin`]
 //! - **[`Skew`]**: Skew a geometry by shearing angles along the `x` and `y` dimension
 //! - **[`Translate`]**: Translate a geometry along its axis
 //! - **[`AffineOps`]**: generalised composable affine operations
+//! - **[`TransformBuilder`]**: Build an [`AffineTransform`] from a chain of operations about
+//!   the origin or an anchor point
+//! - **[`DynCoordTransform`]**: Compose affine transforms and closures chosen at runtime into a
+//!   pipeline of coordinate transforms
 //!
 //! ## Conversion
 //!
 //! - **[`Convert`]**: Convert (infalliby) the numeric type of a geometry’s coordinate value
 //! - **[`TryConvert`]**: Convert (falliby) the numeric type of a geometry’s coordinate value
+//! - **[`TryConvertWith`]**: Convert the floating point coordinate values of a geometry to
+//!   integers, rounding them by a [`RoundingPolicy`] and failing on overflow
+//! - **[`ComputationFrame`]**: Translate and scale geometries into a well-conditioned local frame,
+//!   e.g. to run [`BooleanOps`] or [`Relate`] on coordinates far from the origin
+//! - **[`PromotedArea`]**, **[`PromotedBooleanOps`]**: Compute the area of, or boolean operations on,
+//!   `f32` geometries internally in `f64`
 //! - **[`ToDegrees`]**: Radians to degrees coordinate transforms for a given geometry
 //! - **[`ToRadians`]**: Degrees to radians coordinate transforms for a given geometry
+//! - **[`ToWkb`]**, **[`wkb_conv`]**: Write geometries as ISO WKB or PostGIS EWKB, and read them
+//!   without copying into [`geo_traits`] geometries, or into `geo-types` geometries
 //!
 //! ## Miscellaneous
 //!
 //! - **[`Centroid`]**: Calculate the centroid of a geometry
+//! - **[`WeightedCentroid`]**: Calculate the centroid of a collection whose members are weighted,
+//!   e.g. by population
+//! - **[`AreaAccumulator`]**, **[`BboxAccumulator`]**, **[`CentroidAccumulator`]**: Calculate the
+//!   area, bounding rectangle or centroid of a stream of geometries without storing them
+//! - **[`ConvexHullAccumulator`]**: Calculate the convex hull of a point set too large for memory,
+//!   batch by batch
+//! - **[`CachedMetrics`]**: Compute the area, length, bounding rectangle and centroid of a
+//!   geometry only once, until it's mutated
+//! - **[`CanonicalBytes`]**: Encode a geometry as deterministic bytes, which are the same
+//!   whatever the winding, starting vertex and order of its rings, e.g. as a cache key
+//! - **[`geohash`]**: Encode lon/lat geometries as geohashes, decode them, and find neighboring and
+//!   covering cells
+//! - **[`grid`]**: Generate square, hexagonal and triangular grids of polygons or points over
+//!   an extent, optionally clipped to a geometry
+//! - **[`discrete_grid`]**: Cover lon/lat geometries with the cells of discrete global grids,
+//!   such as geohash or H3 (H3 requires the `use-h3` feature)
+//! - **[`SphericalCells`]**: Split a lon/lat geometry into nearly planar cells with their
+//!   spherical areas, for approximate area-weighted statistics
+//! - **[`LayerSummary`]**: Summary statistics of a collection of geometries, such as counts by
+//!   type, vertex counts, extent and validity
 //! - **[`ChaikinSmoothing`]**: Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikin's algorithm
 //! - **[`proj`]**: Project geometries with the `proj` crate (requires the `use-proj` feature)
+//! - **[`geojson_conv`]**: Convert geometries to and from GeoJSON text, and iterate over the geometries
+//!   and properties of features (requires the `use-geojson` feature)
 //! - **[`LineStringSegmentize`]**: Segment a LineString into `n` segments
 //! - **[`LineStringSegmentizeHaversine`]**: Segment a LineString using Haversine distance
 //! - **[`Transform`]**: Transform a geometry using Proj
 //! - **[`RemoveRepeatedPoints`]**: Remove repeated points from a geometry
+//! - **[`CoerceDegenerate`]**: Collapse lines of zero length into points, and polygons of zero
+//!   area into lines or points
+//! - **[`RemoveRepeatedPointsWithin`]**: Remove points from a geometry which are repeated within
+//!   an [`Epsilon`]
+//! - **[`RemoveSpikes`]**: Remove spikes and gores, needle-like excursions of nearly zero area,
+//!   from the rings of polygons
+//! - **[`Normalize`]**: Put a geometry into a normal form, of ring winding and starting point and
+//!   member order, so that semantically identical geometries compare equal
+//! - **[`Epsilon`]**: A precision policy of absolute, relative and ULP tolerances, accepted by the
+//!   tolerant predicates and [`RemoveRepeatedPointsWithin`]
 //! - **[`Validation`]**: Checks if the geometry is well formed. Some algorithms may not work correctly with invalid geometries
+//! - **[`parallel`]**: Parallel versions of `Area`, `Length`, `BoundingRect`, `Densify`, `Simplify`
+//!   and `Validation` for collections of geometries (requires the `multithreading` feature)
 //!
 //! # Spatial Indexing
 //!
@@ -188,11 +298,32 @@
 //!     - Enables [network grid] support for the [`proj` crate]
 //!     - After enabling this feature, [further configuration][proj crate file download] is required to use the network grid.
 //!     - ☐ Disabled by default
+//! - `use-geojson`:
+//!     - Enables converting geometries to and from [GeoJSON][geojson crate] text, using the
+//!       [`geojson` crate][geojson crate]
+//!     - ☐ Disabled by default
+//! - `use-h3`:
+//!     - Enables covering geometries with [H3] cells, using the [`h3o` crate]
+//!     - ☐ Disabled by default
 //! - `use-proj`:
 //!     - Enables coordinate conversion and transformation of `Point` geometries using the [`proj` crate]
 //!     - ☐ Disabled by default
 //! - `use-serde`:
-//!     - Allows geometry types to be serialized and deserialized with [Serde]
+//!     - Allows geometry types, and the results of algorithms such as validation problems,
+//!       [`IntersectionMatrix`](relate::IntersectionMatrix), [`LineIntersection`] and
+//!       [`Closest`], to be serialized and deserialized with [Serde]
+//!     - ☐ Disabled by default
+//! - `strict-construct`:
+//!     - Checks that coordinates are finite and that rings have at least 4 coordinates when
+//!       constructing `LineString`s and `Polygon`s in debug builds, by activating the
+//!       `strict-construct` flag in `geo-types`
+//!     - ☐ Disabled by default
+//! - `simd`:
+//!     - Computes the [`Area`] of rings and the [`EuclideanLength`] of `LineString`s with loops
+//!       over several lanes, which the compiler can vectorize. Their results may differ from
+//!       the default ones in the last bits
+//!     - Only the deprecated [`EuclideanLength`] benefits: [`Length`] with the [`Euclidean`]
+//!       metric space still sums its segments one at a time
 //!     - ☐ Disabled by default
 //! - `multithreading`:
 //!     - Enables multithreading support (via Rayon), and activates the `multithreading` flag
@@ -215,6 +346,8 @@
 //! [`geo-types`]: https://crates.io/crates/geo-types
 //! [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula//
 //! [`proj` crate]: https://github.com/georust/proj
+//! [H3]: https://h3geo.org
+//! [`h3o` crate]: https://github.com/HydroniumLabs/h3o
 //! [geojson crate]: https://crates.io/crates/geojson
 //! [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
 //! [wkt crate]: https://crates.io/crates/wkt
@@ -248,11 +381,14 @@ pub use geometry::*;
 
 /// This module includes all the functions of geometric calculations
 pub mod algorithm;
+pub mod functions;
 mod geometry_cow;
+#[cfg(feature = "simd")]
+mod lanes;
 mod types;
 mod utils;
 use crate::kernels::{RobustKernel, SimpleKernel};
-pub(crate) use geometry_cow::GeometryCow;
+pub use geometry_cow::GeometryCow;
 
 #[cfg(test)]
 #[macro_use]